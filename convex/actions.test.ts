@@ -189,3 +189,75 @@ describe("actions:completeAction", () => {
     expect(action!.status).toBe("completed");
   });
 });
+
+describe("actions:scheduled", () => {
+  test("schedules, runs, and reschedules a recurring action", async () => {
+    const t = convexTest(schema, modules);
+    const { nodeId, orchestrationId } = await createFeatureFixture(
+      t,
+      "action-test",
+    );
+
+    const actionId = await t.mutation(api.actions.submitAction, {
+      nodeId,
+      orchestrationId,
+      type: "cleanup",
+      payload: "{}",
+      cronSchedule: "0 3 * * *",
+    });
+
+    const scheduled = await t.mutation(api.actions.scheduleAction, {
+      actionId,
+      runAt: 1000,
+    });
+    expect(scheduled.success).toBe(true);
+
+    const listed = await t.query(api.actions.scheduledActions, { nodeId });
+    expect(listed.map((a) => a._id)).toEqual([actionId]);
+
+    const started = await t.mutation(api.actions.startScheduledRun, {
+      actionId,
+      runAt: 1000,
+    });
+    expect(started.success).toBe(true);
+
+    await t.mutation(api.actions.completeAction, {
+      actionId,
+      result: "ok",
+      success: true,
+      nextRunAt: 2000,
+    });
+
+    const action = await t.run(async (ctx) => ctx.db.get(actionId));
+    expect(action!.status).toBe("scheduled");
+    expect(action!.runAt).toBe(2000);
+    expect(action!.lastRunAt).toBeTypeOf("number");
+  });
+
+  test("cancelled scheduled action cannot start", async () => {
+    const t = convexTest(schema, modules);
+    const { nodeId, orchestrationId } = await createFeatureFixture(
+      t,
+      "action-test",
+    );
+
+    const actionId = await t.mutation(api.actions.submitAction, {
+      nodeId,
+      orchestrationId,
+      type: "retry_phase",
+      payload: "{}",
+      runAt: 5000,
+    });
+    await t.mutation(api.actions.scheduleAction, { actionId, runAt: 5000 });
+
+    const cancelled = await t.mutation(api.actions.cancelAction, { actionId });
+    expect(cancelled.success).toBe(true);
+
+    const started = await t.mutation(api.actions.startScheduledRun, {
+      actionId,
+      runAt: 5000,
+    });
+    expect(started.success).toBe(false);
+    expect(started.reason).toBe("not_scheduled:cancelled");
+  });
+});
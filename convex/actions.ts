@@ -7,6 +7,8 @@ export const submitAction = mutation({
     orchestrationId: v.id("orchestrations"),
     type: v.string(),
     payload: v.string(),
+    runAt: v.optional(v.number()),
+    cronSchedule: v.optional(v.string()),
   },
   handler: async (ctx, args) => {
     return await ctx.db.insert("inboundActions", {
@@ -16,6 +18,8 @@ export const submitAction = mutation({
       payload: args.payload,
       status: "pending",
       createdAt: Date.now(),
+      runAt: args.runAt,
+      cronSchedule: args.cronSchedule,
     });
   },
 });
@@ -40,11 +44,89 @@ export const claimAction = mutation({
   },
 });
 
+/**
+ * Take ownership of a pending scheduled action (pending -> scheduled).
+ * The daemon supplies runAt so cron schedules are resolved in its local time.
+ */
+export const scheduleAction = mutation({
+  args: {
+    actionId: v.id("inboundActions"),
+    runAt: v.number(),
+  },
+  handler: async (ctx, args) => {
+    const action = await ctx.db.get(args.actionId);
+    if (!action) {
+      return { success: false, reason: "not_found" };
+    }
+    if (action.status !== "pending") {
+      return { success: false, reason: "already_claimed" };
+    }
+    await ctx.db.patch(args.actionId, {
+      status: "scheduled",
+      claimedAt: Date.now(),
+      runAt: args.runAt,
+    });
+    return { success: true };
+  },
+});
+
+/**
+ * Start a due scheduled run (scheduled -> claimed). Fails if the action was
+ * cancelled, is already running, or has moved to a different runAt.
+ */
+export const startScheduledRun = mutation({
+  args: {
+    actionId: v.id("inboundActions"),
+    // The runAt the daemon fired for; guards against stale timers.
+    runAt: v.number(),
+  },
+  handler: async (ctx, args) => {
+    const action = await ctx.db.get(args.actionId);
+    if (!action) {
+      return { success: false, reason: "not_found" };
+    }
+    if (action.status !== "scheduled") {
+      return { success: false, reason: `not_scheduled:${action.status}` };
+    }
+    if (action.runAt !== args.runAt) {
+      return { success: false, reason: "rescheduled" };
+    }
+    await ctx.db.patch(args.actionId, {
+      status: "claimed",
+      lastRunAt: Date.now(),
+    });
+    return { success: true };
+  },
+});
+
+/** Cancel a pending or scheduled action before it runs. */
+export const cancelAction = mutation({
+  args: {
+    actionId: v.id("inboundActions"),
+  },
+  handler: async (ctx, args) => {
+    const action = await ctx.db.get(args.actionId);
+    if (!action) {
+      return { success: false, reason: "not_found" };
+    }
+    if (action.status !== "pending" && action.status !== "scheduled") {
+      return { success: false, reason: `not_cancellable:${action.status}` };
+    }
+    await ctx.db.patch(args.actionId, {
+      status: "cancelled",
+      completedAt: Date.now(),
+    });
+    return { success: true };
+  },
+});
+
 export const completeAction = mutation({
   args: {
     actionId: v.id("inboundActions"),
     result: v.string(),
     success: v.boolean(),
+    // Recurring actions pass their next run time to return to "scheduled".
+    nextRunAt: v.optional(v.number()),
   },
   handler: async (ctx, args) => {
     const action = await ctx.db.get(args.actionId);
@@ -52,6 +134,14 @@ export const completeAction = mutation({
       throw new Error(`Action ${args.actionId} not found`);
     }
     const now = Date.now();
+    if (args.nextRunAt !== undefined) {
+      await ctx.db.patch(args.actionId, {
+        status: "scheduled",
+        result: args.result,
+        runAt: args.nextRunAt,
+      });
+      return;
+    }
     await ctx.db.patch(args.actionId, {
       status: args.success ? "completed" : "failed",
       result: args.result,
@@ -85,3 +175,17 @@ export const pendingActions = query({
       .collect();
   },
 });
+
+export const scheduledActions = query({
+  args: {
+    nodeId: v.id("nodes"),
+  },
+  handler: async (ctx, args) => {
+    return await ctx.db
+      .query("inboundActions")
+      .withIndex("by_node_status", (q) =>
+        q.eq("nodeId", args.nodeId).eq("status", "scheduled"),
+      )
+      .collect();
+  },
+});
@@ -8,6 +8,7 @@ export const recordCommit = mutation({
     sha: v.string(),
     shortSha: v.optional(v.string()),
     subject: v.optional(v.string()),
+    timestamp: v.optional(v.string()),
   },
   handler: async (ctx, args) => {
     // Check for duplicate by SHA
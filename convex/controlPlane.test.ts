@@ -2042,6 +2042,27 @@ describe("controlPlane:enqueueControlAction:taskEditValidation", () => {
     ).rejects.toThrow("Task 99 not found in phase 1");
   });
 
+  test("tags rejected task_edit actions with an error code", async () => {
+    const t = convexTest(schema, modules);
+    const { nodeId, orchestrationId } = await createFeatureFixture(t, "cp-feature");
+    const enqueue = (payload: Record<string, unknown>, idempotencyKey: string) =>
+      t.mutation(api.controlPlane.enqueueControlAction, {
+        orchestrationId,
+        nodeId,
+        actionType: "task_edit",
+        payload: JSON.stringify({ feature: "test", phaseNumber: "1", ...payload }),
+        requestedBy: "web-ui",
+        idempotencyKey,
+      });
+
+    await expect(
+      enqueue({ taskNumber: 1, revision: 1, model: "gpt-4" }, "task-edit-code-invalid"),
+    ).rejects.toMatchObject({ data: { code: "invalid_payload" } });
+    await expect(
+      enqueue({ taskNumber: 99, revision: 1, subject: "x" }, "task-edit-code-not-found"),
+    ).rejects.toMatchObject({ data: { code: "not_found" } });
+  });
+
   test("rejects task_edit when task status is not pending", async () => {
     const t = convexTest(schema, modules);
     const { nodeId, orchestrationId } = await createFeatureFixture(t, "cp-feature");
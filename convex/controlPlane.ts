@@ -1,7 +1,7 @@
 import { query, mutation } from "./_generated/server";
 import type { MutationCtx } from "./_generated/server";
 import type { Id } from "./_generated/dataModel";
-import { v } from "convex/values";
+import { ConvexError, v } from "convex/values";
 import { validateSpecForLaunch } from "./specValidation";
 import { policySnapshotValidator, hashPolicy } from "./policyPresets";
 import type { PolicySnapshot } from "./policyPresets";
@@ -19,6 +19,23 @@ const RUNTIME_ACTION_TYPES = [
   "task_set_model",
 ] as const;
 
+type ControlActionErrorCode = "invalid_payload" | "not_found" | "conflict";
+
+/**
+ * A rejected control action. Callers such as tina-daemon map `data.code`
+ * to a response status instead of parsing the message.
+ */
+class ControlActionError extends ConvexError<{
+  code: ControlActionErrorCode;
+  message: string;
+}> {
+  constructor(code: ControlActionErrorCode, message: string) {
+    super({ code, message });
+    // ConvexError would otherwise use the JSON of `data` as its message
+    this.message = message;
+  }
+}
+
 interface InsertControlActionParams {
   orchestrationId: Id<"orchestrations">;
   nodeId: Id<"nodes">;
@@ -36,11 +53,14 @@ function parseJsonWithFeature(
   try {
     parsed = JSON.parse(rawPayload);
   } catch {
-    throw new Error("Invalid payload: must be valid JSON");
+    throw new ControlActionError("invalid_payload", "Invalid payload: must be valid JSON");
   }
 
   if (typeof parsed.feature !== "string" || !parsed.feature) {
-    throw new Error(`Payload for "${actionType}" requires "feature" (string)`);
+    throw new ControlActionError(
+      "invalid_payload",
+      `Payload for "${actionType}" requires "feature" (string)`,
+    );
   }
 
   return parsed;
@@ -52,7 +72,10 @@ function validateRuntimePayload(actionType: string, rawPayload: string): void {
   const needsPhase = ["pause", "retry"];
   if (needsPhase.includes(actionType)) {
     if (typeof parsed.phase !== "string" || !parsed.phase) {
-      throw new Error(`Payload for "${actionType}" requires "phase" (string)`);
+      throw new ControlActionError(
+        "invalid_payload",
+        `Payload for "${actionType}" requires "phase" (string)`,
+      );
     }
   }
 }
@@ -61,7 +84,10 @@ function validateStartExecutionPayload(rawPayload: string): void {
   const parsed = parseJsonWithFeature(rawPayload, "start_execution");
 
   if (typeof parsed.phase !== "string" || !parsed.phase) {
-    throw new Error('Payload for "start_execution" requires "phase" (string)');
+    throw new ControlActionError(
+      "invalid_payload",
+      'Payload for "start_execution" requires "phase" (string)',
+    );
   }
 
   const hasPlan =
@@ -71,7 +97,8 @@ function validateStartExecutionPayload(rawPayload: string): void {
     (typeof parsed.spec_id === "string" && parsed.spec_id.length > 0) ||
     (typeof parsed.specId === "string" && parsed.specId.length > 0);
   if (!hasPlan && !hasSpecId) {
-    throw new Error(
+    throw new ControlActionError(
+      "invalid_payload",
       'Payload for "start_execution" requires "plan"/"planPath" or "spec_id"/"specId"',
     );
   }
@@ -88,7 +115,10 @@ const ALLOWED_ROLES = ["validator", "planner", "executor", "reviewer"] as const;
 
 function validateModelName(model: unknown): asserts model is string {
   if (typeof model !== "string" || !(ALLOWED_MODELS as readonly string[]).includes(model)) {
-    throw new Error(`Invalid model: "${model}". Allowed: ${ALLOWED_MODELS.join(", ")}`);
+    throw new ControlActionError(
+      "invalid_payload",
+      `Invalid model: "${model}". Allowed: ${ALLOWED_MODELS.join(", ")}`,
+    );
   }
 }
 
@@ -123,7 +153,10 @@ function parseBasePayload(rawPayload: string, actionType: string): Record<string
   const parsed = parseJsonWithFeature(rawPayload, actionType);
 
   if (typeof parsed.targetRevision !== "number") {
-    throw new Error(`Payload for "${actionType}" requires "targetRevision" (number)`);
+    throw new ControlActionError(
+      "invalid_payload",
+      `Payload for "${actionType}" requires "targetRevision" (number)`,
+    );
   }
 
   return parsed;
@@ -136,10 +169,16 @@ function validatePolicyPayload(rawPayload: string): PolicyPayload {
     const model = parsed.model as Record<string, unknown>;
     for (const [role, value] of Object.entries(model)) {
       if (!(ALLOWED_ROLES as readonly string[]).includes(role)) {
-        throw new Error(`Unknown model role: "${role}". Allowed: ${ALLOWED_ROLES.join(", ")}`);
+        throw new ControlActionError(
+          "invalid_payload",
+          `Unknown model role: "${role}". Allowed: ${ALLOWED_ROLES.join(", ")}`,
+        );
       }
       if (typeof value !== "string" || !(ALLOWED_MODELS as readonly string[]).includes(value)) {
-        throw new Error(`Invalid model for "${role}": "${value}". Allowed: ${ALLOWED_MODELS.join(", ")}`);
+        throw new ControlActionError(
+          "invalid_payload",
+          `Invalid model for "${role}": "${value}". Allowed: ${ALLOWED_MODELS.join(", ")}`,
+        );
       }
     }
   }
@@ -151,7 +190,10 @@ function validateRoleModelPayload(rawPayload: string): RoleModelPayload {
   const parsed = parseBasePayload(rawPayload, "orchestration_set_role_model");
 
   if (typeof parsed.role !== "string" || !(ALLOWED_ROLES as readonly string[]).includes(parsed.role)) {
-    throw new Error(`Invalid role: "${parsed.role}". Allowed: ${ALLOWED_ROLES.join(", ")}`);
+    throw new ControlActionError(
+      "invalid_payload",
+      `Invalid role: "${parsed.role}". Allowed: ${ALLOWED_ROLES.join(", ")}`,
+    );
   }
   validateModelName(parsed.model);
 
@@ -190,20 +232,30 @@ function validateTaskEditPayload(rawPayload: string): TaskEditPayload {
   const parsed = parseJsonWithFeature(rawPayload, "task_edit");
 
   if (typeof parsed.phaseNumber !== "string" || !parsed.phaseNumber) {
-    throw new Error('Payload for "task_edit" requires "phaseNumber" (string)');
+    throw new ControlActionError(
+      "invalid_payload",
+      'Payload for "task_edit" requires "phaseNumber" (string)',
+    );
   }
   if (typeof parsed.taskNumber !== "number") {
-    throw new Error('Payload for "task_edit" requires "taskNumber" (number)');
+    throw new ControlActionError(
+      "invalid_payload",
+      'Payload for "task_edit" requires "taskNumber" (number)',
+    );
   }
   if (typeof parsed.revision !== "number") {
-    throw new Error('Payload for "task_edit" requires "revision" (number)');
+    throw new ControlActionError(
+      "invalid_payload",
+      'Payload for "task_edit" requires "revision" (number)',
+    );
   }
 
   const hasSubject = typeof parsed.subject === "string";
   const hasDescription = typeof parsed.description === "string";
   const hasModel = typeof parsed.model === "string";
   if (!hasSubject && !hasDescription && !hasModel) {
-    throw new Error(
+    throw new ControlActionError(
+      "invalid_payload",
       'Payload for "task_edit" requires at least one edit field: "subject", "description", or "model"',
     );
   }
@@ -219,21 +271,29 @@ function validateTaskInsertPayload(rawPayload: string): TaskInsertPayload {
   const parsed = parseJsonWithFeature(rawPayload, "task_insert");
 
   if (typeof parsed.phaseNumber !== "string" || !parsed.phaseNumber) {
-    throw new Error(
+    throw new ControlActionError(
+      "invalid_payload",
       'Payload for "task_insert" requires "phaseNumber" (string)',
     );
   }
   if (typeof parsed.afterTask !== "number") {
-    throw new Error('Payload for "task_insert" requires "afterTask" (number)');
+    throw new ControlActionError(
+      "invalid_payload",
+      'Payload for "task_insert" requires "afterTask" (number)',
+    );
   }
   if (typeof parsed.subject !== "string" || !parsed.subject) {
-    throw new Error('Payload for "task_insert" requires "subject" (string)');
+    throw new ControlActionError(
+      "invalid_payload",
+      'Payload for "task_insert" requires "subject" (string)',
+    );
   }
   if (parsed.model !== undefined) {
     validateModelName(parsed.model);
   }
   if (parsed.dependsOn !== undefined && !Array.isArray(parsed.dependsOn)) {
-    throw new Error(
+    throw new ControlActionError(
+      "invalid_payload",
       'Payload for "task_insert" requires "dependsOn" to be an array',
     );
   }
@@ -245,17 +305,20 @@ function validateTaskSetModelPayload(rawPayload: string): TaskSetModelPayload {
   const parsed = parseJsonWithFeature(rawPayload, "task_set_model");
 
   if (typeof parsed.phaseNumber !== "string" || !parsed.phaseNumber) {
-    throw new Error(
+    throw new ControlActionError(
+      "invalid_payload",
       'Payload for "task_set_model" requires "phaseNumber" (string)',
     );
   }
   if (typeof parsed.taskNumber !== "number") {
-    throw new Error(
+    throw new ControlActionError(
+      "invalid_payload",
       'Payload for "task_set_model" requires "taskNumber" (number)',
     );
   }
   if (typeof parsed.revision !== "number") {
-    throw new Error(
+    throw new ControlActionError(
+      "invalid_payload",
       'Payload for "task_set_model" requires "revision" (number)',
     );
   }
@@ -270,10 +333,11 @@ async function checkAndIncrementRevision(
   targetRevision: number,
 ): Promise<void> {
   const orch = await ctx.db.get(orchestrationId);
-  if (!orch) throw new Error("Orchestration not found");
+  if (!orch) throw new ControlActionError("not_found", "Orchestration not found");
   const currentRevision = orch.policyRevision ?? 0;
   if (targetRevision !== currentRevision) {
-    throw new Error(
+    throw new ControlActionError(
+      "conflict",
       `Policy revision conflict: expected ${targetRevision}, current is ${currentRevision}. Reload and retry.`,
     );
   }
@@ -297,17 +361,20 @@ async function lookupPendingTaskWithRevision(
     )
     .first();
   if (!task) {
-    throw new Error(
+    throw new ControlActionError(
+      "not_found",
       `Task ${taskNumber} not found in phase ${phaseNumber}`,
     );
   }
   if (task.status !== "pending") {
-    throw new Error(
+    throw new ControlActionError(
+      "conflict",
       `Cannot modify task ${taskNumber}: status is "${task.status}" (must be "pending")`,
     );
   }
   if (task.revision !== revision) {
-    throw new Error(
+    throw new ControlActionError(
+      "conflict",
       `Task revision conflict: expected ${revision}, current is ${task.revision}. Reload and retry.`,
     );
   }
@@ -529,7 +596,8 @@ export const enqueueControlAction = mutation({
     if (
       !(RUNTIME_ACTION_TYPES as readonly string[]).includes(args.actionType)
     ) {
-      throw new Error(
+      throw new ControlActionError(
+        "invalid_payload",
         `Invalid actionType: "${args.actionType}". Allowed: ${RUNTIME_ACTION_TYPES.join(", ")}`,
       );
     }
@@ -572,7 +640,8 @@ export const enqueueControlAction = mutation({
           )
           .first();
         if (!afterTask) {
-          throw new Error(
+          throw new ControlActionError(
+            "not_found",
             `afterTask ${payload.afterTask} not found in phase ${payload.phaseNumber}`,
           );
         }
@@ -589,7 +658,8 @@ export const enqueueControlAction = mutation({
             )
             .first();
           if (!depTask) {
-            throw new Error(
+            throw new ControlActionError(
+              "not_found",
               `Dependency task ${dep} not found in phase ${payload.phaseNumber}`,
             );
           }
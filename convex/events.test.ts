@@ -217,3 +217,101 @@ describe("events:recordEvent - detail JSON parsing", () => {
     expect(parsedDetail.extra_field).toBe("extra_value");
   });
 });
+
+describe("events:listEventsPage", () => {
+  test("pages with stable cursors, filters before cutting pages, and sorts both ways", async () => {
+    const t = convexTest(schema, modules);
+    const { orchestrationId } = await createFeatureFixture(t, "auth-feature");
+    for (const [eventType, recordedAt] of [
+      ["phase_started", "2026-02-10T10:00:00Z"],
+      ["agent_shutdown", "2026-02-10T11:00:00Z"],
+      ["agent_shutdown", "2026-02-10T11:00:00Z"],
+      ["phase_completed", "2026-02-10T12:00:00Z"],
+      ["agent_shutdown", "2026-02-10T13:00:00Z"],
+    ]) {
+      await t.mutation(api.events.recordEvent, {
+        orchestrationId,
+        eventType,
+        source: "tina-session",
+        summary: `${eventType} at ${recordedAt}`,
+        recordedAt,
+      });
+    }
+
+    const first = await t.query(api.events.listEventsPage, {
+      orchestrationId,
+      limit: 2,
+    });
+    expect(first.items.map((e) => e.eventType)).toEqual([
+      "phase_started",
+      "agent_shutdown",
+    ]);
+    const second = await t.query(api.events.listEventsPage, {
+      orchestrationId,
+      limit: 2,
+      cursor: first.nextCursor!,
+    });
+    expect(second.items.map((e) => e.eventType)).toEqual([
+      "agent_shutdown",
+      "phase_completed",
+    ]);
+    const third = await t.query(api.events.listEventsPage, {
+      orchestrationId,
+      limit: 2,
+      cursor: second.nextCursor!,
+    });
+    expect(third.items.map((e) => e.recordedAt)).toEqual(["2026-02-10T13:00:00Z"]);
+    expect(third.nextCursor).toBeNull();
+
+    // A caught-up poller resumes from lastCursor and sees only what's new
+    const caughtUp = await t.query(api.events.listEventsPage, {
+      orchestrationId,
+      cursor: third.lastCursor!,
+    });
+    expect(caughtUp.items).toHaveLength(0);
+    expect(caughtUp.lastCursor).toBeNull();
+    await t.mutation(api.events.recordEvent, {
+      orchestrationId,
+      eventType: "phase_started",
+      source: "tina-session",
+      summary: "phase_started at 2026-02-10T13:00:00Z",
+      recordedAt: "2026-02-10T13:00:00Z",
+    });
+    const newer = await t.query(api.events.listEventsPage, {
+      orchestrationId,
+      cursor: third.lastCursor!,
+    });
+    expect(newer.items.map((e) => e.eventType)).toEqual(["phase_started"]);
+
+    const shutdowns = await t.query(api.events.listEventsPage, {
+      orchestrationId,
+      eventType: "agent_shutdown",
+      since: "2026-02-10T10:30:00Z",
+      until: "2026-02-10T13:00:00Z",
+      limit: 5,
+    });
+    expect(shutdowns.items).toHaveLength(2);
+    expect(shutdowns.nextCursor).toBeNull();
+
+    const newest = await t.query(api.events.listEventsPage, {
+      orchestrationId,
+      order: "desc",
+      limit: 2,
+    });
+    expect(newest.items.map((e) => e.recordedAt)).toEqual([
+      "2026-02-10T13:00:00Z",
+      "2026-02-10T12:00:00Z",
+    ]);
+    const older = await t.query(api.events.listEventsPage, {
+      orchestrationId,
+      order: "desc",
+      limit: 5,
+      cursor: newest.nextCursor!,
+    });
+    expect(older.items.map((e) => e.recordedAt)).toEqual([
+      "2026-02-10T11:00:00Z",
+      "2026-02-10T11:00:00Z",
+      "2026-02-10T10:00:00Z",
+    ]);
+  });
+});
@@ -47,3 +47,109 @@ export const listEvents = query({
     return events;
   },
 });
+
+const DEFAULT_EVENT_PAGE_SIZE = 100;
+const MAX_EVENT_PAGE_SIZE = 1000;
+
+export function encodeEventCursor(recordedAt: string, creationTime: number): string {
+  return JSON.stringify([recordedAt, creationTime]);
+}
+
+export function decodeEventCursor(cursor: string): { recordedAt: string; creationTime: number } {
+  const parsed = JSON.parse(cursor);
+  if (
+    !Array.isArray(parsed) ||
+    typeof parsed[0] !== "string" ||
+    typeof parsed[1] !== "number"
+  ) {
+    throw new Error(`Invalid event cursor: ${cursor}`);
+  }
+  return { recordedAt: parsed[0], creationTime: parsed[1] };
+}
+
+/**
+ * One page of an orchestration's events, oldest first unless `order` is
+ * "desc". Pages are keyed on (recordedAt, _creationTime) like
+ * `listOrchestrationsPage`, so a poller can keep its last cursor and only
+ * fetch what is new. `since`/`until` bound recordedAt (exclusive) and
+ * `eventType` matches exactly; unlike `listEvents`, the type filter is
+ * applied before the page is cut. `lastCursor` points just past the page's
+ * last item even on the final page, so a poller that has caught up can
+ * resume from it.
+ */
+export const listEventsPage = query({
+  args: {
+    orchestrationId: v.id("orchestrations"),
+    limit: v.optional(v.number()),
+    cursor: v.optional(v.string()),
+    eventType: v.optional(v.string()),
+    since: v.optional(v.string()),
+    until: v.optional(v.string()),
+    order: v.optional(v.union(v.literal("asc"), v.literal("desc"))),
+  },
+  handler: async (ctx, args) => {
+    const limit = Math.max(
+      1,
+      Math.min(Math.floor(args.limit ?? DEFAULT_EVENT_PAGE_SIZE), MAX_EVENT_PAGE_SIZE),
+    );
+    const after = args.cursor ? decodeEventCursor(args.cursor) : null;
+    const order = args.order ?? "asc";
+    const recordedBetween = (lower: string | undefined, upper: string | undefined) =>
+      ctx.db
+        .query("orchestrationEvents")
+        .withIndex("by_orchestration_recorded", (q) => {
+          const base = q.eq("orchestrationId", args.orchestrationId);
+          if (lower !== undefined && upper !== undefined) {
+            return base.gt("recordedAt", lower).lt("recordedAt", upper);
+          }
+          if (lower !== undefined) return base.gt("recordedAt", lower);
+          if (upper !== undefined) return base.lt("recordedAt", upper);
+          return base;
+        })
+        .order(order)
+        .filter((q) =>
+          args.eventType === undefined || q.eq(q.field("eventType"), args.eventType),
+        );
+
+    // Events recorded at the cursor's time come first, then the rest
+    let docs = after
+      ? await ctx.db
+          .query("orchestrationEvents")
+          .withIndex("by_orchestration_recorded", (q) => {
+            const tied = q
+              .eq("orchestrationId", args.orchestrationId)
+              .eq("recordedAt", after.recordedAt);
+            return order === "asc"
+              ? tied.gt("_creationTime", after.creationTime)
+              : tied.lt("_creationTime", after.creationTime);
+          })
+          .order(order)
+          .filter((q) =>
+            args.eventType === undefined || q.eq(q.field("eventType"), args.eventType),
+          )
+          .take(limit + 1)
+      : [];
+    if (docs.length <= limit) {
+      let lower = args.since;
+      let upper = args.until;
+      if (after && order === "asc" && (lower === undefined || after.recordedAt > lower)) {
+        lower = after.recordedAt;
+      }
+      if (after && order === "desc" && (upper === undefined || after.recordedAt < upper)) {
+        upper = after.recordedAt;
+      }
+      const rest = await recordedBetween(lower, upper).take(limit + 1 - docs.length);
+      docs = docs.concat(rest);
+    }
+
+    const hasMore = docs.length > limit;
+    const page = docs.slice(0, limit);
+    const last = page[page.length - 1];
+    const lastCursor = last ? encodeEventCursor(last.recordedAt, last._creationTime) : null;
+    return {
+      items: page,
+      nextCursor: hasMore ? lastCursor : null,
+      lastCursor,
+    };
+  },
+});
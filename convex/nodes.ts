@@ -40,16 +40,29 @@ export const registerNode = mutation({
 export const heartbeat = mutation({
   args: {
     nodeId: v.id("nodes"),
+    cpuLoad: v.optional(v.number()),
+    cpuCount: v.optional(v.number()),
+    memoryUsedBytes: v.optional(v.number()),
+    memoryTotalBytes: v.optional(v.number()),
+    diskFreeBytes: v.optional(v.number()),
+    diskTotalBytes: v.optional(v.number()),
+    tmuxSessions: v.optional(v.number()),
+    clockSkewMs: v.optional(v.number()),
   },
   handler: async (ctx, args) => {
-    const node = await ctx.db.get(args.nodeId);
+    const { nodeId, ...metrics } = args;
+    const node = await ctx.db.get(nodeId);
     if (!node) {
-      throw new Error(`Node ${args.nodeId} not found`);
+      throw new Error(`Node ${nodeId} not found`);
     }
-    await ctx.db.patch(args.nodeId, {
-      lastHeartbeat: Date.now(),
+    const now = Date.now();
+    await ctx.db.patch(nodeId, {
+      ...metrics,
+      lastHeartbeat: now,
       status: "online",
     });
+    // Server time lets the node measure its clock skew against Convex.
+    return { serverTime: now };
   },
 });
 
@@ -65,3 +78,12 @@ export const listNodes = query({
     }));
   },
 });
+
+// A mutation rather than a query so the timestamp is never served from the
+// query cache. Monitors use it to anchor elapsed times to server time.
+export const serverTime = mutation({
+  args: {},
+  handler: async () => {
+    return { serverTime: Date.now() };
+  },
+});
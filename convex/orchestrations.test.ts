@@ -4,7 +4,7 @@ import { api } from "./_generated/api";
 import schema from "./schema";
 
 const modules = import.meta.glob("./**/*.*s");
-import { createNode, createProject } from "./test_helpers";
+import { createNode, createOrchestration, createProject } from "./test_helpers";
 
 describe("orchestrations:upsertOrchestration", () => {
   test("accepts optional specId on insert", async () => {
@@ -162,3 +162,148 @@ describe("orchestrations:upsertOrchestration", () => {
     expect(detail!.specId).toBe(specId);
   });
 });
+
+describe("orchestrations:listOrchestrationsPage", () => {
+  test("pages newest first with stable cursors and a first-page total", async () => {
+    const t = convexTest(schema, modules);
+    const nodeId = await createNode(t);
+    for (const [featureName, startedAt] of [
+      ["alpha", "2026-02-01T10:00:00Z"],
+      ["beta", "2026-02-03T10:00:00Z"],
+      ["gamma", "2026-02-03T10:00:00Z"],
+      ["delta", "2026-02-02T10:00:00Z"],
+    ]) {
+      await createOrchestration(t, { nodeId, featureName, startedAt });
+    }
+
+    const first = await t.query(api.orchestrations.listOrchestrationsPage, {
+      limit: 2,
+      count: true,
+    });
+    expect(first.items.map((o) => o.featureName)).toEqual(["gamma", "beta"]);
+    expect(first.totalCount).toBe(4);
+
+    // Counting reads every row, so it is opt-in
+    const uncounted = await t.query(api.orchestrations.listOrchestrationsPage, {
+      limit: 2,
+    });
+    expect(uncounted.totalCount).toBeNull();
+    expect(first.nextCursor).not.toBeNull();
+
+    // A row started after the first page doesn't shift later pages
+    await createOrchestration(t, {
+      nodeId,
+      featureName: "epsilon",
+      startedAt: "2026-02-04T10:00:00Z",
+    });
+
+    const second = await t.query(api.orchestrations.listOrchestrationsPage, {
+      limit: 2,
+      cursor: first.nextCursor!,
+      count: true,
+    });
+    expect(second.items.map((o) => o.featureName)).toEqual(["delta", "alpha"]);
+    expect(second.totalCount).toBeNull();
+    expect(second.nextCursor).toBeNull();
+  });
+
+  test("filters by status, project, and start time, and sorts oldest first", async () => {
+    const t = convexTest(schema, modules);
+    const nodeId = await createNode(t);
+    const projectId = await createProject(t);
+    for (const [featureName, status, startedAt] of [
+      ["alpha", "complete", "2026-02-01T10:00:00Z"],
+      ["beta", "executing", "2026-02-02T10:00:00Z"],
+      ["gamma", "executing", "2026-02-03T10:00:00Z"],
+      ["delta", "executing", "2026-02-04T10:00:00Z"],
+    ]) {
+      await createOrchestration(t, { nodeId, featureName, status, startedAt });
+    }
+    await t.mutation(api.orchestrations.upsertOrchestration, {
+      nodeId,
+      projectId,
+      featureName: "epsilon",
+      specDocPath: "/docs/design.md",
+      branch: "tina/epsilon",
+      totalPhases: 2,
+      currentPhase: 1,
+      status: "executing",
+      startedAt: "2026-02-05T10:00:00Z",
+    });
+
+    const executing = await t.query(api.orchestrations.listOrchestrationsPage, {
+      status: "executing",
+      startedAfter: "2026-02-02T10:00:00Z",
+      order: "asc",
+      limit: 2,
+    });
+    expect(executing.items.map((o) => o.featureName)).toEqual(["gamma", "delta"]);
+    expect(executing.totalCount).toBe(3);
+    const rest = await t.query(api.orchestrations.listOrchestrationsPage, {
+      status: "executing",
+      startedAfter: "2026-02-02T10:00:00Z",
+      order: "asc",
+      limit: 2,
+      cursor: executing.nextCursor!,
+    });
+    expect(rest.items.map((o) => o.featureName)).toEqual(["epsilon"]);
+    expect(rest.nextCursor).toBeNull();
+
+    const inProject = await t.query(api.orchestrations.listOrchestrationsPage, {
+      projectId,
+    });
+    expect(inProject.items.map((o) => o.featureName)).toEqual(["epsilon"]);
+
+    const before = await t.query(api.orchestrations.listOrchestrationsPage, {
+      startedBefore: "2026-02-03T10:00:00Z",
+    });
+    expect(before.items.map((o) => o.featureName)).toEqual(["beta", "alpha"]);
+  });
+});
+
+describe("orchestrations:setArchived", () => {
+  test("archived orchestrations are left out of listings unless asked for", async () => {
+    const t = convexTest(schema, modules);
+    const nodeId = await createNode(t);
+    const oldId = await createOrchestration(t, {
+      nodeId,
+      featureName: "old-experiment",
+      startedAt: "2026-02-01T10:00:00Z",
+    });
+    await createOrchestration(t, {
+      nodeId,
+      featureName: "current",
+      startedAt: "2026-02-02T10:00:00Z",
+    });
+
+    expect(
+      await t.mutation(api.orchestrations.setArchived, {
+        orchestrationId: oldId,
+        archived: true,
+      }),
+    ).toEqual({ found: true });
+
+    const listed = await t.query(api.orchestrations.listOrchestrations, {});
+    expect(listed.map((o) => o.featureName)).toEqual(["current"]);
+    const page = await t.query(api.orchestrations.listOrchestrationsPage, {});
+    expect(page.items.map((o) => o.featureName)).toEqual(["current"]);
+    expect(page.totalCount).toBe(1);
+
+    const withArchived = await t.query(api.orchestrations.listOrchestrationsPage, {
+      includeArchived: true,
+    });
+    expect(withArchived.items.map((o) => o.featureName)).toEqual([
+      "current",
+      "old-experiment",
+    ]);
+    expect(withArchived.items[1].archived).toBe(true);
+
+    // Restoring brings it back
+    await t.mutation(api.orchestrations.setArchived, {
+      orchestrationId: oldId,
+      archived: false,
+    });
+    const restored = await t.query(api.orchestrations.listOrchestrations, {});
+    expect(restored.map((o) => o.featureName)).toEqual(["current", "old-experiment"]);
+  });
+});
@@ -1,5 +1,7 @@
 import { query, mutation } from "./_generated/server";
 import { v } from "convex/values";
+import type { FilterBuilder, NamedTableInfo } from "convex/server";
+import type { DataModel } from "./_generated/dataModel";
 import { deduplicateTaskEvents, loadTaskEventsForOrchestration } from "./tasks";
 import { deleteOrchestrationAssociationsStep } from "./deleteHelpers";
 
@@ -93,10 +95,21 @@ export const upsertOrchestration = mutation({
   },
 });
 
+function isListed(
+  orchestration: { archived?: boolean },
+  includeArchived: boolean | undefined,
+) {
+  return includeArchived === true || orchestration.archived !== true;
+}
+
 export const listOrchestrations = query({
-  args: {},
-  handler: async (ctx) => {
-    const orchestrations = await ctx.db.query("orchestrations").collect();
+  args: {
+    includeArchived: v.optional(v.boolean()),
+  },
+  handler: async (ctx, args) => {
+    const orchestrations = (await ctx.db.query("orchestrations").collect()).filter(
+      (orch) => isListed(orch, args.includeArchived),
+    );
 
     const results = await Promise.all(
       orchestrations.map(async (orch) => {
@@ -116,15 +129,152 @@ export const listOrchestrations = query({
   },
 });
 
+const DEFAULT_PAGE_SIZE = 50;
+const MAX_PAGE_SIZE = 500;
+
+interface OrchestrationCursor {
+  startedAt: string;
+  creationTime: number;
+}
+
+function encodeCursor(cursor: OrchestrationCursor): string {
+  return JSON.stringify([cursor.startedAt, cursor.creationTime]);
+}
+
+function decodeCursor(cursor: string): OrchestrationCursor {
+  const parsed = JSON.parse(cursor);
+  if (
+    !Array.isArray(parsed) ||
+    typeof parsed[0] !== "string" ||
+    typeof parsed[1] !== "number"
+  ) {
+    throw new Error(`Invalid orchestration cursor: ${cursor}`);
+  }
+  return { startedAt: parsed[0], creationTime: parsed[1] };
+}
+
+/**
+ * One page of orchestrations for lists too long to load at once, newest
+ * first unless `order` is "asc". Pages are keyed on (startedAt,
+ * _creationTime) rather than offsets, so rows inserted while a client
+ * scrolls don't shift later pages; a cursor only makes sense with the
+ * filters and order that produced it. `status` and `projectId` match
+ * exactly, and `startedAfter`/`startedBefore` bound startedAt (exclusive).
+ * `totalCount` counts every match, which reads the whole range, so it is
+ * only computed for a first page that asks for it with `count`.
+ * Archived orchestrations are skipped unless `includeArchived` is set.
+ */
+export const listOrchestrationsPage = query({
+  args: {
+    limit: v.optional(v.number()),
+    cursor: v.optional(v.string()),
+    includeArchived: v.optional(v.boolean()),
+    status: v.optional(v.string()),
+    projectId: v.optional(v.id("projects")),
+    startedAfter: v.optional(v.string()),
+    startedBefore: v.optional(v.string()),
+    order: v.optional(v.union(v.literal("asc"), v.literal("desc"))),
+    count: v.optional(v.boolean()),
+  },
+  handler: async (ctx, args) => {
+    const limit = Math.max(
+      1,
+      Math.min(Math.floor(args.limit ?? DEFAULT_PAGE_SIZE), MAX_PAGE_SIZE),
+    );
+    const after = args.cursor ? decodeCursor(args.cursor) : null;
+    const order = args.order ?? "desc";
+    const includeArchived = args.includeArchived === true;
+    const matches = (q: FilterBuilder<NamedTableInfo<DataModel, "orchestrations">>) =>
+      q.and(
+        includeArchived || q.neq(q.field("archived"), true),
+        args.status === undefined || q.eq(q.field("status"), args.status),
+        args.projectId === undefined || q.eq(q.field("projectId"), args.projectId),
+      );
+    const startedBetween = (lower: string | undefined, upper: string | undefined) =>
+      ctx.db
+        .query("orchestrations")
+        .withIndex("by_started_at", (q) => {
+          if (lower !== undefined && upper !== undefined) {
+            return q.gt("startedAt", lower).lt("startedAt", upper);
+          }
+          if (lower !== undefined) return q.gt("startedAt", lower);
+          if (upper !== undefined) return q.lt("startedAt", upper);
+          return q;
+        })
+        .order(order)
+        .filter(matches);
+
+    // Fetch one extra row to learn whether another page follows. Rows
+    // sharing the cursor's startedAt come first, then the rest of the range.
+    let docs = after
+      ? await ctx.db
+          .query("orchestrations")
+          .withIndex("by_started_at", (q) => {
+            const tied = q.eq("startedAt", after.startedAt);
+            return order === "desc"
+              ? tied.lt("_creationTime", after.creationTime)
+              : tied.gt("_creationTime", after.creationTime);
+          })
+          .order(order)
+          .filter(matches)
+          .take(limit + 1)
+      : [];
+    if (docs.length <= limit) {
+      let lower = args.startedAfter;
+      let upper = args.startedBefore;
+      if (after && order === "desc" && (upper === undefined || after.startedAt < upper)) {
+        upper = after.startedAt;
+      }
+      if (after && order === "asc" && (lower === undefined || after.startedAt > lower)) {
+        lower = after.startedAt;
+      }
+      const rest = await startedBetween(lower, upper).take(limit + 1 - docs.length);
+      docs = docs.concat(rest);
+    }
+
+    const hasMore = docs.length > limit;
+    const page = docs.slice(0, limit);
+    const items = await Promise.all(
+      page.map(async (orch) => {
+        const node = await ctx.db.get(orch.nodeId);
+        return {
+          ...normalizeLegacyOrchestration(orch),
+          nodeName: node?.name ?? "unknown",
+        };
+      }),
+    );
+
+    const last = page[page.length - 1];
+    const totalCount = after || args.count !== true
+      ? null
+      : (await startedBetween(args.startedAfter, args.startedBefore).collect()).length;
+
+    return {
+      items,
+      nextCursor:
+        hasMore && last
+          ? encodeCursor({
+              startedAt: last.startedAt,
+              creationTime: last._creationTime,
+            })
+          : null,
+      totalCount,
+    };
+  },
+});
+
 export const listByProject = query({
   args: {
     projectId: v.id("projects"),
+    includeArchived: v.optional(v.boolean()),
   },
   handler: async (ctx, args) => {
-    const orchestrations = await ctx.db
-      .query("orchestrations")
-      .withIndex("by_project", (q) => q.eq("projectId", args.projectId))
-      .collect();
+    const orchestrations = (
+      await ctx.db
+        .query("orchestrations")
+        .withIndex("by_project", (q) => q.eq("projectId", args.projectId))
+        .collect()
+    ).filter((orch) => isListed(orch, args.includeArchived));
 
     const results = await Promise.all(
       orchestrations.map(async (orch) => {
@@ -225,6 +375,25 @@ export const getOrchestrationDetail = query({
   },
 });
 
+/**
+ * Archive an orchestration, or restore it with `archived: false`. Unlike
+ * deleteOrchestration, everything recorded for it is kept.
+ */
+export const setArchived = mutation({
+  args: {
+    orchestrationId: v.id("orchestrations"),
+    archived: v.boolean(),
+  },
+  handler: async (ctx, args) => {
+    const orchestration = await ctx.db.get(args.orchestrationId);
+    if (!orchestration) {
+      return { found: false };
+    }
+    await ctx.db.patch(args.orchestrationId, { archived: args.archived });
+    return { found: true };
+  },
+});
+
 export const deleteOrchestration = mutation({
   args: {
     orchestrationId: v.id("orchestrations"),
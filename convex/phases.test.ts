@@ -0,0 +1,62 @@
+import { convexTest } from "convex-test";
+import { describe, expect, test } from "vitest";
+import { api } from "./_generated/api";
+import schema from "./schema";
+import { createFeatureFixture } from "./test_helpers";
+
+const modules = import.meta.glob("./**/*.*s");
+
+describe("phases", () => {
+  describe("recordPhaseRisk", () => {
+    test("stores the score and signals on the phase", async () => {
+      const t = convexTest(schema, modules);
+      const { orchestrationId } = await createFeatureFixture(t, "phase-risk-1");
+      await t.mutation(api.phases.upsertPhase, {
+        orchestrationId: orchestrationId as any,
+        phaseNumber: "1",
+        status: "reviewing",
+        gitRange: "abc..def",
+      });
+
+      await t.mutation(api.phases.recordPhaseRisk, {
+        orchestrationId: orchestrationId as any,
+        phaseNumber: "1",
+        riskScore: 62,
+        riskSignals: JSON.stringify({ lines_changed: 900 }),
+      });
+
+      const phase = await t.query(api.phases.getPhaseStatus, {
+        orchestrationId: orchestrationId as any,
+        phaseNumber: "1",
+      });
+      expect(phase!.riskScore).toBe(62);
+      expect(JSON.parse(phase!.riskSignals!)).toEqual({ lines_changed: 900 });
+
+      // Later phase syncs leave the risk in place
+      await t.mutation(api.phases.upsertPhase, {
+        orchestrationId: orchestrationId as any,
+        phaseNumber: "1",
+        status: "complete",
+      });
+      const synced = await t.query(api.phases.getPhaseStatus, {
+        orchestrationId: orchestrationId as any,
+        phaseNumber: "1",
+      });
+      expect(synced!.riskScore).toBe(62);
+    });
+
+    test("rejects unknown phases", async () => {
+      const t = convexTest(schema, modules);
+      const { orchestrationId } = await createFeatureFixture(t, "phase-risk-2");
+
+      await expect(
+        t.mutation(api.phases.recordPhaseRisk, {
+          orchestrationId: orchestrationId as any,
+          phaseNumber: "9",
+          riskScore: 10,
+          riskSignals: "{}",
+        }),
+      ).rejects.toThrow(/Phase 9 not found/);
+    });
+  });
+});
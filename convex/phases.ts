@@ -60,3 +60,34 @@ export const getPhaseStatus = query({
       .first();
   },
 });
+
+export const recordPhaseRisk = mutation({
+  args: {
+    orchestrationId: v.id("orchestrations"),
+    phaseNumber: v.string(),
+    riskScore: v.number(),
+    // JSON-encoded signals the score was computed from
+    riskSignals: v.string(),
+  },
+  handler: async (ctx, args) => {
+    const phase = await ctx.db
+      .query("phases")
+      .withIndex("by_orchestration_phase", (q) =>
+        q
+          .eq("orchestrationId", args.orchestrationId)
+          .eq("phaseNumber", args.phaseNumber),
+      )
+      .first();
+    if (!phase) {
+      throw new Error(
+        `Phase ${args.phaseNumber} not found for orchestration ${args.orchestrationId}`,
+      );
+    }
+
+    await ctx.db.patch(phase._id, {
+      riskScore: args.riskScore,
+      riskSignals: args.riskSignals,
+    });
+    return phase._id;
+  },
+});
@@ -65,6 +65,17 @@ async function deleteEntitiesWithComments(
     for (const comment of comments) {
       await ctx.db.delete(comment._id);
     }
+    if (table === "specs") {
+      const revisions = await ctx.db
+        .query("specRevisions")
+        .withIndex("by_spec_revision", (q) =>
+          q.eq("specId", entity._id as Id<"specs">),
+        )
+        .collect();
+      for (const revision of revisions) {
+        await ctx.db.delete(revision._id);
+      }
+    }
     await ctx.db.delete(entity._id);
   }
 }
@@ -250,4 +250,39 @@ describe("reviewChecks", () => {
       expect(checks).toHaveLength(0);
     });
   });
+
+  describe("listChecksByOrchestration", () => {
+    test("returns checks across every review of the orchestration", async () => {
+      const t = convexTest(schema, modules);
+      const { orchestrationId } = await createFeatureFixture(t, "chk-orch-1");
+      const firstReview = await createReview(t, { orchestrationId });
+      const secondReview = await createReview(t, { orchestrationId });
+      const other = await createFeatureFixture(t, "chk-orch-2");
+      const otherReview = await createReview(t, {
+        orchestrationId: other.orchestrationId,
+      });
+
+      await startReviewCheck(t, {
+        reviewId: firstReview,
+        orchestrationId,
+        name: "typecheck",
+      });
+      await startReviewCheck(t, {
+        reviewId: secondReview,
+        orchestrationId,
+        name: "test",
+      });
+      await startReviewCheck(t, {
+        reviewId: otherReview,
+        orchestrationId: other.orchestrationId,
+        name: "test",
+      });
+
+      const checks = await t.query(
+        api.reviewChecks.listChecksByOrchestration,
+        { orchestrationId: orchestrationId as any },
+      );
+      expect(checks.map((c) => c.name).sort()).toEqual(["test", "typecheck"]);
+    });
+  });
 });
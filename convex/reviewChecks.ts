@@ -93,3 +93,17 @@ export const listChecksByReview = query({
       .collect();
   },
 });
+
+export const listChecksByOrchestration = query({
+  args: {
+    orchestrationId: v.id("orchestrations"),
+  },
+  handler: async (ctx, args) => {
+    return await ctx.db
+      .query("reviewChecks")
+      .withIndex("by_orchestration", (q) =>
+        q.eq("orchestrationId", args.orchestrationId),
+      )
+      .collect();
+  },
+});
@@ -96,6 +96,121 @@ describe("reviewGates", () => {
     });
   });
 
+  describe("recordGateDecision", () => {
+    test("stays pending until the quorum of distinct approvers is met", async () => {
+      const t = convexTest(schema, modules);
+      const { orchestrationId } = await createFeatureFixture(t, "gate-quorum-1");
+      await upsertReviewGate(t, {
+        orchestrationId,
+        gateId: "finalize",
+        summary: "Ready to finalize",
+      });
+
+      const first = await t.mutation(api.reviewGates.recordGateDecision, {
+        orchestrationId: orchestrationId as any,
+        gateId: "finalize",
+        approver: "alice",
+        decision: "approved",
+        summary: "LGTM",
+        requiredApprovals: 2,
+      });
+      expect(first!.status).toBe("pending");
+      expect(first!.decidedAt).toBeUndefined();
+
+      // A repeat approval from the same person doesn't count twice
+      const repeat = await t.mutation(api.reviewGates.recordGateDecision, {
+        orchestrationId: orchestrationId as any,
+        gateId: "finalize",
+        approver: "alice",
+        decision: "approved",
+        summary: "Still LGTM",
+      });
+      expect(repeat!.status).toBe("pending");
+      expect(repeat!.decisions).toHaveLength(1);
+
+      const second = await t.mutation(api.reviewGates.recordGateDecision, {
+        orchestrationId: orchestrationId as any,
+        gateId: "finalize",
+        approver: "bob",
+        decision: "approved",
+        summary: "Ship it",
+      });
+      expect(second!.status).toBe("approved");
+      expect(second!.requiredApprovals).toBe(2);
+      expect(second!.decidedBy).toBe("alice, bob");
+      expect(second!.decidedAt).toBeDefined();
+    });
+
+    test("a block discards approvals collected so far", async () => {
+      const t = convexTest(schema, modules);
+      const { orchestrationId } = await createFeatureFixture(t, "gate-quorum-2");
+      const decide = (approver: string, decision: "approved" | "blocked") =>
+        t.mutation(api.reviewGates.recordGateDecision, {
+          orchestrationId: orchestrationId as any,
+          gateId: "review",
+          approver,
+          decision,
+          summary: decision,
+          requiredApprovals: 2,
+        });
+
+      await decide("alice", "approved");
+      const blocked = await decide("bob", "blocked");
+      expect(blocked!.status).toBe("blocked");
+      expect(blocked!.decidedBy).toBe("bob");
+      expect(blocked!.decisions).toHaveLength(1);
+
+      const oneApproval = await decide("alice", "approved");
+      expect(oneApproval!.status).toBe("pending");
+
+      const reopened = await decide("carol", "approved");
+      expect(reopened!.status).toBe("approved");
+      expect(reopened!.decidedBy).toBe("alice, carol");
+    });
+
+    test("reopening a decided gate clears earlier decisions", async () => {
+      const t = convexTest(schema, modules);
+      const { orchestrationId } = await createFeatureFixture(t, "gate-quorum-3");
+      await t.mutation(api.reviewGates.recordGateDecision, {
+        orchestrationId: orchestrationId as any,
+        gateId: "review",
+        approver: "alice",
+        decision: "blocked",
+        summary: "Needs tests",
+      });
+
+      await upsertReviewGate(t, {
+        orchestrationId,
+        gateId: "review",
+        status: "pending",
+        summary: "Fixes pushed",
+      });
+
+      const gate = await t.query(api.reviewGates.getGate, {
+        orchestrationId: orchestrationId as any,
+        gateId: "review",
+      });
+      expect(gate!.status).toBe("pending");
+      expect(gate!.decisions).toBeUndefined();
+    });
+
+    test("rejects a non-positive quorum", async () => {
+      const t = convexTest(schema, modules);
+      const { orchestrationId } = await createFeatureFixture(t, "gate-quorum-4");
+
+      await expect(
+        t.mutation(api.reviewGates.recordGateDecision, {
+          orchestrationId: orchestrationId as any,
+          gateId: "plan",
+          approver: "alice",
+          decision: "approved",
+          summary: "LGTM",
+          requiredApprovals: 0,
+        }),
+      ).rejects.toThrow(/positive integer/);
+    });
+  });
+
   describe("getGate", () => {
     test("returns null when gate does not exist", async () => {
       const t = convexTest(schema, modules);
@@ -1,6 +1,43 @@
 import { mutation, query } from "./_generated/server";
 import { v } from "convex/values";
 
+type GateStatus = "pending" | "blocked" | "approved";
+
+export interface GateDecision {
+  approver: string;
+  decision: "approved" | "blocked";
+  decidedAt: string;
+  summary?: string;
+}
+
+/**
+ * Derive a gate's status from its per-approver decisions.
+ *
+ * A block wins; otherwise the gate opens once `requiredApprovals` distinct
+ * approvers have approved.
+ */
+export function gateStatusFromDecisions(
+  decisions: GateDecision[],
+  requiredApprovals: number,
+): GateStatus {
+  if (decisions.some((d) => d.decision === "blocked")) {
+    return "blocked";
+  }
+  const approvals = decisions.filter((d) => d.decision === "approved").length;
+  return approvals >= requiredApprovals ? "approved" : "pending";
+}
+
+function validateRequiredApprovals(requiredApprovals: number | undefined) {
+  if (
+    requiredApprovals !== undefined &&
+    (!Number.isInteger(requiredApprovals) || requiredApprovals < 1)
+  ) {
+    throw new Error(
+      `requiredApprovals must be a positive integer, got ${requiredApprovals}`,
+    );
+  }
+}
+
 export const upsertGate = mutation({
   args: {
     orchestrationId: v.id("orchestrations"),
@@ -17,12 +54,14 @@ export const upsertGate = mutation({
     owner: v.string(),
     decidedBy: v.optional(v.string()),
     summary: v.string(),
+    requiredApprovals: v.optional(v.number()),
   },
   handler: async (ctx, args) => {
     const orchestration = await ctx.db.get(args.orchestrationId);
     if (!orchestration) {
       throw new Error(`Orchestration not found: ${args.orchestrationId}`);
     }
+    validateRequiredApprovals(args.requiredApprovals);
 
     const existing = await ctx.db
       .query("reviewGates")
@@ -38,12 +77,17 @@ export const upsertGate = mutation({
       args.status === "approved" || args.status === "blocked" ? now : undefined;
 
     if (existing) {
+      // Reopening a decided gate starts a fresh round of approvals
+      const reopened =
+        args.status === "pending" && existing.status !== "pending";
       await ctx.db.patch(existing._id, {
         status: args.status,
         owner: args.owner,
         decidedBy: args.decidedBy,
         decidedAt,
         summary: args.summary,
+        requiredApprovals: args.requiredApprovals ?? existing.requiredApprovals,
+        decisions: reopened ? undefined : existing.decisions,
       });
       return existing._id;
     }
@@ -56,7 +100,91 @@ export const upsertGate = mutation({
       decidedBy: args.decidedBy,
       decidedAt,
       summary: args.summary,
+      requiredApprovals: args.requiredApprovals,
+    });
+  },
+});
+
+/**
+ * Record one approver's decision on a gate.
+ *
+ * Each approver counts once: a later approval from the same approver
+ * replaces their earlier one, so a gate requiring two approvals stays
+ * pending until a second person approves. A block closes the gate and
+ * discards the approvals collected so far; the quorum then has to be met
+ * again from fresh approvals.
+ */
+export const recordGateDecision = mutation({
+  args: {
+    orchestrationId: v.id("orchestrations"),
+    gateId: v.union(
+      v.literal("plan"),
+      v.literal("review"),
+      v.literal("finalize"),
+    ),
+    approver: v.string(),
+    decision: v.union(v.literal("approved"), v.literal("blocked")),
+    summary: v.string(),
+    requiredApprovals: v.optional(v.number()),
+  },
+  handler: async (ctx, args) => {
+    const orchestration = await ctx.db.get(args.orchestrationId);
+    if (!orchestration) {
+      throw new Error(`Orchestration not found: ${args.orchestrationId}`);
+    }
+    validateRequiredApprovals(args.requiredApprovals);
+
+    const existing = await ctx.db
+      .query("reviewGates")
+      .withIndex("by_orchestration_gate", (q) =>
+        q
+          .eq("orchestrationId", args.orchestrationId)
+          .eq("gateId", args.gateId),
+      )
+      .first();
+
+    const now = new Date().toISOString();
+    const others =
+      args.decision === "blocked"
+        ? []
+        : (existing?.decisions ?? []).filter(
+            (d) => d.decision === "approved" && d.approver !== args.approver,
+          );
+    const decisions: GateDecision[] = [
+      ...others,
+      {
+        approver: args.approver,
+        decision: args.decision,
+        decidedAt: now,
+        summary: args.summary,
+      },
+    ];
+    const requiredApprovals =
+      args.requiredApprovals ?? existing?.requiredApprovals ?? 1;
+    const status = gateStatusFromDecisions(decisions, requiredApprovals);
+    const deciders = decisions.map((d) => d.approver).join(", ");
+
+    const fields = {
+      status,
+      decidedBy: status === "pending" ? undefined : deciders,
+      decidedAt: status === "pending" ? undefined : now,
+      summary: args.summary,
+      requiredApprovals,
+      decisions,
+    };
+
+    if (existing) {
+      await ctx.db.patch(existing._id, fields);
+      return await ctx.db.get(existing._id);
+    }
+
+    const id = await ctx.db.insert("reviewGates", {
+      orchestrationId: args.orchestrationId,
+      gateId: args.gateId,
+      owner: "human",
+      ...fields,
     });
+    return await ctx.db.get(id);
   },
 });
 
@@ -16,6 +16,16 @@ export default defineSchema({
     lastHeartbeat: v.number(),
     registeredAt: v.number(),
     authTokenHash: v.string(),
+    // Host resource metrics from the latest heartbeat.
+    cpuLoad: v.optional(v.number()),
+    cpuCount: v.optional(v.number()),
+    memoryUsedBytes: v.optional(v.number()),
+    memoryTotalBytes: v.optional(v.number()),
+    diskFreeBytes: v.optional(v.number()),
+    diskTotalBytes: v.optional(v.number()),
+    tmuxSessions: v.optional(v.number()),
+    // Node clock minus Convex server time, as measured by the node.
+    clockSkewMs: v.optional(v.number()),
   }).index("by_name_auth", ["name", "authTokenHash"]),
 
   orchestrations: defineTable({
@@ -28,10 +38,13 @@ export default defineSchema({
     projectId: v.optional(v.id("projects")),
     specId: v.optional(v.id("specs")),
     designId: v.optional(v.id("designs")),
+    // Archived orchestrations are left out of listings unless asked for
+    archived: v.optional(v.boolean()),
   })
     .index("by_feature", ["featureName"])
     .index("by_node", ["nodeId"])
-    .index("by_project", ["projectId"]),
+    .index("by_project", ["projectId"])
+    .index("by_started_at", ["startedAt"]),
 
   supervisorStates: defineTable({
     nodeId: v.id("nodes"),
@@ -54,6 +67,8 @@ export default defineSchema({
     reviewMins: v.optional(v.number()),
     startedAt: v.optional(v.string()),
     completedAt: v.optional(v.string()),
+    riskScore: v.optional(v.number()),
+    riskSignals: v.optional(v.string()),
   })
     .index("by_orchestration", ["orchestrationId"])
     .index("by_orchestration_phase", ["orchestrationId", "phaseNumber"]),
@@ -151,6 +166,11 @@ export default defineSchema({
     completedAt: v.optional(v.number()),
     controlActionId: v.optional(v.id("controlPlaneActions")),
     idempotencyKey: v.optional(v.string()),
+    // Scheduling: one-shot actions set runAt; recurring actions set
+    // cronSchedule and the daemon advances runAt after each run.
+    runAt: v.optional(v.number()),
+    cronSchedule: v.optional(v.string()),
+    lastRunAt: v.optional(v.number()),
   })
     .index("by_node_status", ["nodeId", "status"])
     .index("by_orchestration", ["orchestrationId"]),
@@ -289,6 +309,15 @@ export default defineSchema({
     .index("by_project_status", ["projectId", "status"])
     .index("by_key", ["specKey"]),
 
+  // Snapshot of a spec's title and markdown each time either changes.
+  specRevisions: defineTable({
+    specId: v.id("specs"),
+    revision: v.number(),
+    title: v.string(),
+    markdown: v.string(),
+    createdAt: v.string(),
+  }).index("by_spec_revision", ["specId", "revision"]),
+
   designs: defineTable({
     projectId: v.id("projects"),
     designKey: v.string(),
@@ -448,6 +477,19 @@ export default defineSchema({
     decidedBy: v.optional(v.string()),
     decidedAt: v.optional(v.string()),
     summary: v.string(),
+    // Distinct approvals needed before the gate opens (default 1)
+    requiredApprovals: v.optional(v.number()),
+    // Latest decision per approver, recorded by recordGateDecision
+    decisions: v.optional(
+      v.array(
+        v.object({
+          approver: v.string(),
+          decision: v.union(v.literal("approved"), v.literal("blocked")),
+          decidedAt: v.string(),
+          summary: v.optional(v.string()),
+        }),
+      ),
+    ),
   })
     .index("by_orchestration", ["orchestrationId"])
     .index("by_orchestration_gate", ["orchestrationId", "gateId"]),
@@ -367,6 +367,28 @@ describe("specs", () => {
     });
   });
 
+  describe("listSpecRevisions", () => {
+    test("records a revision on create and on each content change", async () => {
+      const t = convexTest(schema, modules);
+      const projectId = await createProject(t);
+
+      const specId = await t.mutation(api.specs.createSpec, {
+        projectId,
+        title: "Spec",
+        markdown: "# v1",
+      });
+      await t.mutation(api.specs.updateSpec, { specId, markdown: "# v2" });
+      await t.mutation(api.specs.updateSpec, { specId, title: "Spec" });
+      await t.mutation(api.specs.updateSpec, { specId, title: "Renamed" });
+
+      const revisions = await t.query(api.specs.listSpecRevisions, { specId });
+      expect(revisions.map((r) => r.revision)).toEqual([3, 2, 1]);
+      expect(revisions[0].title).toBe("Renamed");
+      expect(revisions[0].markdown).toBe("# v2");
+      expect(revisions[2].markdown).toBe("# v1");
+    });
+  });
+
   describe("transitionSpec", () => {
     test("transitions draft -> in_review", async () => {
       const t = convexTest(schema, modules);
@@ -1,9 +1,32 @@
 import { query, mutation } from "./_generated/server";
+import type { MutationCtx } from "./_generated/server";
+import type { Id } from "./_generated/dataModel";
 import { v } from "convex/values";
 import { allocateKey } from "./projectCounters";
 import { seedMarkersFromPreset, parsePhaseStructure } from "./specPresets";
 import type { ComplexityPreset } from "./specPresets";
 
+async function recordRevision(
+  ctx: MutationCtx,
+  specId: Id<"specs">,
+  title: string,
+  markdown: string,
+  createdAt: string,
+) {
+  const latest = await ctx.db
+    .query("specRevisions")
+    .withIndex("by_spec_revision", (q) => q.eq("specId", specId))
+    .order("desc")
+    .first();
+  await ctx.db.insert("specRevisions", {
+    specId,
+    revision: (latest?.revision ?? 0) + 1,
+    title,
+    markdown,
+    createdAt,
+  });
+}
+
 export const createSpec = mutation({
   args: {
     projectId: v.id("projects"),
@@ -21,11 +44,12 @@ export const createSpec = mutation({
     const specKey = `${project.name.toUpperCase()}-S${keyNumber}`;
     const now = new Date().toISOString();
 
+    let specId: Id<"specs">;
     if (args.complexityPreset) {
       const preset = args.complexityPreset as ComplexityPreset;
       const requiredMarkers = seedMarkersFromPreset(preset);
       const { phaseCount, phaseStructureValid } = parsePhaseStructure(args.markdown);
-      return await ctx.db.insert("specs", {
+      specId = await ctx.db.insert("specs", {
         projectId: args.projectId,
         specKey,
         title: args.title,
@@ -41,7 +65,7 @@ export const createSpec = mutation({
         validationUpdatedAt: now,
       });
     } else {
-      return await ctx.db.insert("specs", {
+      specId = await ctx.db.insert("specs", {
         projectId: args.projectId,
         specKey,
         title: args.title,
@@ -51,6 +75,8 @@ export const createSpec = mutation({
         updatedAt: now,
       });
     }
+    await recordRevision(ctx, specId, args.title, args.markdown, now);
+    return specId;
   },
 });
 
@@ -129,10 +155,29 @@ export const updateSpec = mutation({
     }
 
     await ctx.db.patch(args.specId, updates);
+
+    const title = args.title ?? spec.title;
+    const markdown = args.markdown ?? spec.markdown;
+    if (title !== spec.title || markdown !== spec.markdown) {
+      await recordRevision(ctx, args.specId, title, markdown, now);
+    }
     return args.specId;
   },
 });
 
+export const listSpecRevisions = query({
+  args: {
+    specId: v.id("specs"),
+  },
+  handler: async (ctx, args) => {
+    return await ctx.db
+      .query("specRevisions")
+      .withIndex("by_spec_revision", (q) => q.eq("specId", args.specId))
+      .order("desc")
+      .collect();
+  },
+});
+
 export const transitionSpec = mutation({
   args: {
     specId: v.id("specs"),
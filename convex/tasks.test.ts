@@ -1,5 +1,11 @@
+import { convexTest } from "convex-test";
 import { describe, expect, it, vi } from "vitest";
+import { api } from "./_generated/api";
+import schema from "./schema";
 import { deduplicateTaskEvents, loadTaskEventsForOrchestration } from "./tasks";
+import { createFeatureFixture } from "./test_helpers";
+
+const modules = import.meta.glob("./**/*.*s");
 
 describe("deduplicateTaskEvents", () => {
   it("keeps the latest event per task and phase scope", () => {
@@ -86,3 +92,82 @@ describe("loadTaskEventsForOrchestration", () => {
     expect(take).toHaveBeenCalledWith(1000);
   });
 });
+
+describe("tasks:listOrchestrationTaskEvents", () => {
+  it("pages task events oldest first across tasks", async () => {
+    const t = convexTest(schema, modules);
+    const { orchestrationId } = await createFeatureFixture(t, "replay-feature");
+
+    for (const [taskId, status, recordedAt] of [
+      ["2", "in_progress", "2026-02-10T10:05:00Z"],
+      ["1", "pending", "2026-02-10T10:00:00Z"],
+      ["1", "completed", "2026-02-10T10:10:00Z"],
+    ]) {
+      await t.mutation(api.tasks.recordTaskEvent, {
+        orchestrationId,
+        phaseNumber: "1",
+        taskId,
+        subject: `Task ${taskId}`,
+        status,
+        recordedAt,
+      });
+    }
+
+    const first = await t.query(api.tasks.listOrchestrationTaskEvents, {
+      orchestrationId,
+      limit: 2,
+    });
+    expect(first.map((e) => e.recordedAt)).toEqual([
+      "2026-02-10T10:00:00Z",
+      "2026-02-10T10:05:00Z",
+    ]);
+
+    const rest = await t.query(api.tasks.listOrchestrationTaskEvents, {
+      orchestrationId,
+      since: first[first.length - 1].recordedAt,
+    });
+    expect(rest).toHaveLength(1);
+    expect(rest[0].status).toBe("completed");
+  });
+});
+
+describe("tasks:listOrchestrationTaskEventsPage", () => {
+  it("keeps events that share a timestamp across pages", async () => {
+    const t = convexTest(schema, modules);
+    const { orchestrationId } = await createFeatureFixture(t, "replay-feature");
+
+    for (const [taskId, recordedAt] of [
+      ["1", "2026-02-10T10:00:00Z"],
+      ["2", "2026-02-10T10:05:00Z"],
+      ["3", "2026-02-10T10:05:00Z"],
+    ]) {
+      await t.mutation(api.tasks.recordTaskEvent, {
+        orchestrationId,
+        phaseNumber: "1",
+        taskId,
+        subject: `Task ${taskId}`,
+        status: "completed",
+        recordedAt,
+      });
+    }
+
+    const first = await t.query(api.tasks.listOrchestrationTaskEventsPage, {
+      orchestrationId,
+      limit: 2,
+    });
+    expect(first.items.map((e) => e.taskId)).toEqual(["1", "2"]);
+    const rest = await t.query(api.tasks.listOrchestrationTaskEventsPage, {
+      orchestrationId,
+      limit: 2,
+      cursor: first.nextCursor!,
+    });
+    expect(rest.items.map((e) => e.taskId)).toEqual(["3"]);
+    expect(rest.nextCursor).toBeNull();
+
+    const caughtUp = await t.query(api.tasks.listOrchestrationTaskEventsPage, {
+      orchestrationId,
+      cursor: rest.lastCursor!,
+    });
+    expect(caughtUp.items).toHaveLength(0);
+  });
+});
@@ -2,6 +2,7 @@ import { query, mutation } from "./_generated/server";
 import { v } from "convex/values";
 import type { QueryCtx } from "./_generated/server";
 import type { Id } from "./_generated/dataModel";
+import { decodeEventCursor, encodeEventCursor } from "./events";
 
 const ORCHESTRATOR_PHASE_KEY = "__orchestrator__";
 const TASK_EVENT_SCAN_LIMIT = 1000;
@@ -70,6 +71,96 @@ export const listTaskEvents = query({
   },
 });
 
+export const listOrchestrationTaskEvents = query({
+  args: {
+    orchestrationId: v.id("orchestrations"),
+    since: v.optional(v.string()),
+    limit: v.optional(v.number()),
+  },
+  handler: async (ctx, args) => {
+    // Oldest first so callers can page forward with `since`.
+    return await ctx.db
+      .query("taskEvents")
+      .withIndex("by_orchestration_recorded", (q) => {
+        const base = q.eq("orchestrationId", args.orchestrationId);
+        if (args.since) {
+          return base.gt("recordedAt", args.since);
+        }
+        return base;
+      })
+      .order("asc")
+      .take(args.limit ?? 100);
+  },
+});
+
+const DEFAULT_TASK_EVENT_PAGE_SIZE = 100;
+const MAX_TASK_EVENT_PAGE_SIZE = 1000;
+
+/**
+ * One page of an orchestration's task events, oldest first, keyed on
+ * (recordedAt, _creationTime) like `events:listEventsPage` so events that
+ * share a timestamp aren't skipped between pages. `since` bounds recordedAt
+ * (exclusive). `lastCursor` points just past the page's last item even on
+ * the final page, so a poller that has caught up can resume from it.
+ */
+export const listOrchestrationTaskEventsPage = query({
+  args: {
+    orchestrationId: v.id("orchestrations"),
+    limit: v.optional(v.number()),
+    cursor: v.optional(v.string()),
+    since: v.optional(v.string()),
+  },
+  handler: async (ctx, args) => {
+    const limit = Math.max(
+      1,
+      Math.min(
+        Math.floor(args.limit ?? DEFAULT_TASK_EVENT_PAGE_SIZE),
+        MAX_TASK_EVENT_PAGE_SIZE,
+      ),
+    );
+    const after = args.cursor ? decodeEventCursor(args.cursor) : null;
+
+    // Events recorded at the cursor's time come first, then the rest
+    let docs = after
+      ? await ctx.db
+          .query("taskEvents")
+          .withIndex("by_orchestration_recorded", (q) =>
+            q
+              .eq("orchestrationId", args.orchestrationId)
+              .eq("recordedAt", after.recordedAt)
+              .gt("_creationTime", after.creationTime),
+          )
+          .order("asc")
+          .take(limit + 1)
+      : [];
+    if (docs.length <= limit) {
+      let lower = args.since;
+      if (after && (lower === undefined || after.recordedAt > lower)) {
+        lower = after.recordedAt;
+      }
+      const rest = await ctx.db
+        .query("taskEvents")
+        .withIndex("by_orchestration_recorded", (q) => {
+          const base = q.eq("orchestrationId", args.orchestrationId);
+          return lower !== undefined ? base.gt("recordedAt", lower) : base;
+        })
+        .order("asc")
+        .take(limit + 1 - docs.length);
+      docs = docs.concat(rest);
+    }
+
+    const hasMore = docs.length > limit;
+    const page = docs.slice(0, limit);
+    const last = page[page.length - 1];
+    const lastCursor = last ? encodeEventCursor(last.recordedAt, last._creationTime) : null;
+    return {
+      items: page,
+      nextCursor: hasMore ? lastCursor : null,
+      lastCursor,
+    };
+  },
+});
+
 export const recordTaskEvent = mutation({
   args: {
     orchestrationId: v.id("orchestrations"),
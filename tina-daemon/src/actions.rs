@@ -7,12 +7,15 @@ use tracing::{error, info};
 
 use tina_data::{InboundAction, TinaConvexClient};
 
+use crate::schedule;
+
 /// Payload for inbound actions that include feature/phase context.
 #[derive(Debug, serde::Deserialize)]
 pub struct ActionPayload {
     pub feature: Option<String>,
     pub phase: Option<String>,
     pub feedback: Option<String>,
+    #[serde(alias = "reason")]
     pub issues: Option<String>,
     #[serde(alias = "planPath")]
     pub plan: Option<String>,
@@ -41,6 +44,10 @@ pub struct ActionPayload {
     pub revision: Option<u32>,
     #[serde(alias = "dependsOn")]
     pub depends_on: Option<Vec<u32>>,
+    // Agent nudge fields (send_text)
+    #[serde(alias = "paneId")]
+    pub pane_id: Option<String>,
+    pub text: Option<String>,
 }
 
 /// Machine-parseable error codes for action dispatch results.
@@ -115,13 +122,7 @@ pub async fn dispatch_action(
         .map_err(|e| anyhow::anyhow!("failed to parse action payload: {}", e))?;
 
     // Build and execute CLI command
-    let dispatch_result = match execute_action(&action.action_type, &payload).await {
-        Ok(output) => DispatchResult::ok(output),
-        Err(e) => {
-            let code = classify_error(&e);
-            DispatchResult::err(code, format!("{}", e))
-        }
-    };
+    let dispatch_result = run_action(&action.action_type, &payload).await;
 
     // Report result
     let mut client = client.lock().await;
@@ -142,8 +143,133 @@ pub async fn dispatch_action(
     Ok(())
 }
 
+/// Take ownership of a scheduled action (pending -> scheduled).
+///
+/// The action then shows up in the node's `scheduledActions` subscription and
+/// is fired by the timer wheel. Actions with an unparseable cron schedule are
+/// claimed and failed immediately.
+pub async fn schedule_action(
+    client: &Arc<Mutex<TinaConvexClient>>,
+    action: &InboundAction,
+) -> Result<()> {
+    let run_at = match schedule::initial_run_at(action, &chrono::Local::now()) {
+        Ok(run_at) => run_at,
+        Err(e) => {
+            let mut client = client.lock().await;
+            if client.claim_action(&action.id).await?.success {
+                let result = DispatchResult::err(DispatchErrorCode::PayloadInvalid, e.to_string());
+                client
+                    .complete_action(&action.id, &result.to_json(), false)
+                    .await?;
+            }
+            error!(action_id = %action.id, error = %e, "rejected scheduled action");
+            return Ok(());
+        }
+    };
+
+    let claim_result = {
+        let mut client = client.lock().await;
+        client.schedule_action(&action.id, run_at).await?
+    };
+    if claim_result.success {
+        info!(action_type = %action.action_type, action_id = %action.id, run_at, "action scheduled");
+    } else {
+        info!(
+            action_id = %action.id,
+            reason = ?claim_result.reason,
+            "scheduled action already claimed, skipping"
+        );
+    }
+    Ok(())
+}
+
+/// Run a due scheduled action.
+///
+/// One-shot actions are completed as usual; recurring actions record the run
+/// and return to `scheduled` at their next cron occurrence.
+pub async fn run_scheduled_action(
+    client: &Arc<Mutex<TinaConvexClient>>,
+    action: &InboundAction,
+) -> Result<()> {
+    let Some(run_at) = action.run_at else {
+        bail!("scheduled action {} has no runAt", action.id);
+    };
+    let claim_result = {
+        let mut client = client.lock().await;
+        client.start_scheduled_run(&action.id, run_at).await?
+    };
+    if !claim_result.success {
+        info!(
+            action_id = %action.id,
+            reason = ?claim_result.reason,
+            "scheduled action no longer due, skipping"
+        );
+        return Ok(());
+    }
+
+    let dispatch_result = match serde_json::from_str::<ActionPayload>(&action.payload) {
+        Ok(payload) => run_action(&action.action_type, &payload).await,
+        Err(e) => DispatchResult::err(
+            DispatchErrorCode::PayloadInvalid,
+            format!("failed to parse action payload: {}", e),
+        ),
+    };
+
+    let next_run_at = match &action.cron_schedule {
+        Some(expr) => Some(schedule::next_cron_run(expr, &chrono::Local::now())?),
+        None => None,
+    };
+
+    let mut client = client.lock().await;
+    match next_run_at {
+        Some(next) => {
+            client
+                .reschedule_action(
+                    &action.id,
+                    &dispatch_result.to_json(),
+                    dispatch_result.success,
+                    next.timestamp_millis() as f64,
+                )
+                .await?;
+            info!(action_id = %action.id, next_run = %next, "recurring action rescheduled");
+        }
+        None => {
+            client
+                .complete_action(
+                    &action.id,
+                    &dispatch_result.to_json(),
+                    dispatch_result.success,
+                )
+                .await?;
+        }
+    }
+
+    if dispatch_result.success {
+        info!(action_type = %action.action_type, action_id = %action.id, "scheduled action completed");
+    } else {
+        error!(action_type = %action.action_type, action_id = %action.id, error = %dispatch_result.message, "scheduled action failed");
+    }
+
+    Ok(())
+}
+
+/// Execute an action and wrap the outcome in a `DispatchResult`.
+async fn run_action(action_type: &str, payload: &ActionPayload) -> DispatchResult {
+    match execute_action(action_type, payload).await {
+        Ok(output) => DispatchResult::ok(output),
+        Err(e) => {
+            let code = classify_error(&e);
+            DispatchResult::err(code, format!("{}", e))
+        }
+    }
+}
+
 /// Execute the appropriate CLI command for an action type.
 async fn execute_action(action_type: &str, payload: &ActionPayload) -> Result<String> {
+    // Text for an agent goes straight to its pane, not through tina-session
+    if action_type == "send_text" {
+        return send_text(payload).await;
+    }
     let args = build_cli_args(action_type, payload)?;
 
     info!(action_type = %action_type, args = ?args, "executing tina-session command");
@@ -167,6 +293,23 @@ async fn execute_action(action_type: &str, payload: &ActionPayload) -> Result<St
     Ok(stdout)
 }
 
+/// Type a `send_text` action's text into the agent's pane and press Enter.
+async fn send_text(payload: &ActionPayload) -> Result<String> {
+    let pane = payload
+        .pane_id
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!("send_text payload missing 'pane_id' field"))?;
+    let text = payload
+        .text
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!("send_text payload missing 'text' field"))?;
+
+    info!(pane = %pane, chars = text.chars().count(), "sending text to agent pane");
+    let sent_to = pane.clone();
+    tokio::task::spawn_blocking(move || tina_session::tmux::send_keys(&pane, &text)).await??;
+    Ok(format!("sent text to {}", sent_to))
+}
+
 /// Classify an anyhow error into a deterministic error code.
 fn classify_error(err: &anyhow::Error) -> DispatchErrorCode {
     let msg = err.to_string();
@@ -247,13 +390,18 @@ pub fn build_cli_args(action_type: &str, payload: &ActionPayload) -> Result<Vec<
                 .phase
                 .as_deref()
                 .ok_or_else(|| anyhow::anyhow!("retry requires 'phase' in payload"))?;
-            Ok(vec![
+            let mut args = vec![
                 "orchestrate".to_string(),
                 "advance".to_string(),
                 feature.to_string(),
                 phase.to_string(),
                 "retry".to_string(),
-            ])
+            ];
+            if let Some(reason) = payload.issues.as_ref() {
+                args.push("--issues".to_string());
+                args.push(reason.to_string());
+            }
+            Ok(args)
         }
         "start_execution" => {
             let phase = payload
@@ -535,6 +683,8 @@ mod tests {
             description: None,
             revision: None,
             depends_on: None,
+            pane_id: None,
+            text: None,
         }
     }
 
@@ -574,6 +724,8 @@ mod tests {
             description: None,
             revision: None,
             depends_on: None,
+            pane_id: None,
+            text: None,
         };
         let args = build_cli_args("reject_plan", &p).unwrap();
         assert_eq!(
@@ -632,6 +784,25 @@ mod tests {
         assert_eq!(args, vec!["orchestrate", "advance", "auth", "2", "retry"]);
     }
 
+    #[test]
+    fn test_build_cli_args_retry_with_reason() {
+        let p: ActionPayload =
+            serde_json::from_str(r#"{"feature":"auth","phase":"2","reason":"flaky CI"}"#).unwrap();
+        let args = build_cli_args("retry", &p).unwrap();
+        assert_eq!(
+            args,
+            vec![
+                "orchestrate",
+                "advance",
+                "auth",
+                "2",
+                "retry",
+                "--issues",
+                "flaky CI"
+            ]
+        );
+    }
+
     #[test]
     fn test_build_cli_args_unknown_type() {
         let p = payload("auth", Some("1"));
@@ -669,6 +840,8 @@ mod tests {
             description: None,
             revision: None,
             depends_on: None,
+            pane_id: None,
+            text: None,
         };
         let result = build_cli_args("approve_plan", &p);
         assert!(result.is_err());
@@ -709,6 +882,8 @@ mod tests {
             description: None,
             revision: None,
             depends_on: None,
+            pane_id: None,
+            text: None,
         };
         let args = build_cli_args("reject_plan", &p).unwrap();
         assert_eq!(
@@ -750,6 +925,8 @@ mod tests {
             description: None,
             revision: None,
             depends_on: None,
+            pane_id: None,
+            text: None,
         }
     }
 
@@ -778,6 +955,8 @@ mod tests {
             description: None,
             revision: None,
             depends_on: None,
+            pane_id: None,
+            text: None,
         }
     }
 
@@ -1392,6 +1571,26 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("model"));
     }
 
+    #[tokio::test]
+    async fn test_send_text_requires_pane_and_text() {
+        let mut p: ActionPayload =
+            serde_json::from_str(r#"{"feature": "auth", "paneId": "%7"}"#).unwrap();
+        assert_eq!(p.pane_id.as_deref(), Some("%7"));
+
+        let result = run_action("send_text", &p).await;
+        assert!(!result.success);
+        assert!(matches!(
+            result.error_code,
+            Some(DispatchErrorCode::PayloadMissingField)
+        ));
+        assert!(result.message.contains("'text'"));
+
+        p.pane_id = None;
+        p.text = Some("continue".to_string());
+        let result = run_action("send_text", &p).await;
+        assert!(result.message.contains("'pane_id'"));
+    }
+
     // --- DispatchResult / DispatchErrorCode tests ---
 
     #[test]
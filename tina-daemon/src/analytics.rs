@@ -0,0 +1,292 @@
+//! Orchestration timing analytics.
+//!
+//! GET /api/orchestrations/{id}/analytics reduces an orchestration's phase
+//! records and task history to the numbers a dashboard charts: how long
+//! each phase spent planning, executing, and reviewing, the idle gaps
+//! between phases, and completed tasks per hour overall and per phase.
+//! Running phases are measured up to now. Token cost isn't recorded yet, so
+//! it isn't reported.
+
+use std::collections::HashMap;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use tina_data::humanize::parse_rfc3339;
+use tina_data::{OrchestrationDetailResponse, PhaseRecord};
+
+use crate::http::AppState;
+
+type ApiError = (StatusCode, String);
+
+/// Response body for GET /api/orchestrations/{id}/analytics.
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OrchestrationAnalytics {
+    pub orchestration_id: String,
+    pub elapsed_mins: Option<i64>,
+    /// Phases in the order they started
+    pub phases: Vec<PhaseTiming>,
+    pub gaps: Vec<PhaseGap>,
+    /// Sum of `gaps`
+    pub idle_mins: f64,
+    pub tasks_total: usize,
+    pub tasks_completed: usize,
+    /// Completed tasks per hour of elapsed time
+    pub tasks_per_hour: Option<f64>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PhaseTiming {
+    pub phase: String,
+    pub status: String,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub planning_mins: Option<f64>,
+    pub execution_mins: Option<f64>,
+    pub review_mins: Option<f64>,
+    /// Start to completion, or to now while running
+    pub total_mins: Option<f64>,
+    pub tasks_completed: usize,
+    /// Completed tasks per hour of execution
+    pub tasks_per_hour: Option<f64>,
+}
+
+/// Idle time between one phase finishing and the next starting.
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PhaseGap {
+    pub after_phase: String,
+    pub before_phase: String,
+    pub mins: f64,
+}
+
+fn mins_between(start: DateTime<Utc>, end: DateTime<Utc>) -> f64 {
+    round1(end.signed_duration_since(start).num_seconds().max(0) as f64 / 60.0)
+}
+
+fn round1(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+fn per_hour(count: usize, mins: Option<f64>) -> Option<f64> {
+    match mins {
+        Some(mins) if mins > 0.0 => Some(round1(count as f64 * 60.0 / mins)),
+        _ => None,
+    }
+}
+
+fn phase_timing(phase: &PhaseRecord, tasks_completed: usize, now: DateTime<Utc>) -> PhaseTiming {
+    let total_mins = phase
+        .started_at
+        .as_deref()
+        .and_then(parse_rfc3339)
+        .map(|start| {
+            let end = phase
+                .completed_at
+                .as_deref()
+                .and_then(parse_rfc3339)
+                .unwrap_or(now);
+            mins_between(start, end)
+        });
+    PhaseTiming {
+        phase: phase.phase_number.clone(),
+        status: phase.status.clone(),
+        started_at: phase.started_at.clone(),
+        completed_at: phase.completed_at.clone(),
+        planning_mins: phase.planning_mins,
+        execution_mins: phase.execution_mins,
+        review_mins: phase.review_mins,
+        total_mins,
+        tasks_completed,
+        tasks_per_hour: per_hour(tasks_completed, phase.execution_mins.or(total_mins)),
+    }
+}
+
+/// Analytics for one orchestration as of `now`.
+pub fn analyze(detail: &OrchestrationDetailResponse, now: DateTime<Utc>) -> OrchestrationAnalytics {
+    // `tasks` holds each task's latest event, so completed ones stay completed
+    let completed: Vec<_> = detail
+        .tasks
+        .iter()
+        .filter(|task| task.status == "completed")
+        .collect();
+    let mut completed_by_phase: HashMap<&str, usize> = HashMap::new();
+    for task in &completed {
+        if let Some(phase) = task.phase_number.as_deref() {
+            *completed_by_phase.entry(phase).or_default() += 1;
+        }
+    }
+
+    let mut phases: Vec<&PhaseRecord> = detail.phases.iter().collect();
+    // Unstarted phases sort last
+    phases.sort_by_key(|phase| {
+        let start = phase.started_at.as_deref().and_then(parse_rfc3339);
+        let number = phase.phase_number.parse::<u32>().unwrap_or(u32::MAX);
+        (start.is_none(), start, number)
+    });
+
+    let mut gaps = Vec::new();
+    let mut previous: Option<(&str, DateTime<Utc>)> = None;
+    for phase in &phases {
+        let Some(start) = phase.started_at.as_deref().and_then(parse_rfc3339) else {
+            continue;
+        };
+        if let Some((after_phase, end)) = previous {
+            if start > end {
+                gaps.push(PhaseGap {
+                    after_phase: after_phase.to_string(),
+                    before_phase: phase.phase_number.clone(),
+                    mins: mins_between(end, start),
+                });
+            }
+        }
+        let end = phase
+            .completed_at
+            .as_deref()
+            .and_then(parse_rfc3339)
+            .unwrap_or(now);
+        if previous.is_none_or(|(_, previous_end)| end > previous_end) {
+            previous = Some((&phase.phase_number, end));
+        }
+    }
+
+    let elapsed = detail.record.elapsed_mins(now);
+    OrchestrationAnalytics {
+        orchestration_id: detail.id.clone(),
+        elapsed_mins: elapsed,
+        phases: phases
+            .iter()
+            .map(|phase| {
+                let done = completed_by_phase
+                    .get(phase.phase_number.as_str())
+                    .copied()
+                    .unwrap_or(0);
+                phase_timing(phase, done, now)
+            })
+            .collect(),
+        idle_mins: round1(gaps.iter().map(|gap| gap.mins).sum()),
+        gaps,
+        tasks_total: detail.tasks.len(),
+        tasks_completed: completed.len(),
+        tasks_per_hour: per_hour(completed.len(), elapsed.map(|mins| mins as f64)),
+    }
+}
+
+/// GET /api/orchestrations/{id}/analytics handler.
+pub async fn get_analytics(
+    State(state): State<AppState>,
+    Path(orchestration_id): Path<String>,
+) -> Result<Json<OrchestrationAnalytics>, ApiError> {
+    let client = state.convex()?;
+
+    let detail = client
+        .lock()
+        .await
+        .get_orchestration_detail(&orchestration_id)
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("Orchestration not found: {}", orchestration_id),
+        ))?;
+    Ok(Json(analyze(&detail, Utc::now())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tina_data::test_util::list_entry;
+    use tina_data::TaskEventRecord;
+
+    fn phase(number: &str, started_at: &str, completed_at: Option<&str>) -> PhaseRecord {
+        PhaseRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: number.to_string(),
+            status: if completed_at.is_some() {
+                "complete"
+            } else {
+                "executing"
+            }
+            .to_string(),
+            plan_path: None,
+            git_range: None,
+            planning_mins: Some(10.0),
+            execution_mins: Some(30.0),
+            review_mins: None,
+            started_at: Some(started_at.to_string()),
+            completed_at: completed_at.map(str::to_string),
+            risk_score: None,
+            risk_signals: None,
+        }
+    }
+
+    fn task(id: &str, phase: &str, status: &str) -> TaskEventRecord {
+        TaskEventRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: Some(phase.to_string()),
+            task_id: id.to_string(),
+            subject: format!("Task {}", id),
+            description: None,
+            status: status.to_string(),
+            owner: None,
+            blocked_by: None,
+            metadata: None,
+            recorded_at: "2026-02-07T10:30:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_analyze_phases_gaps_and_throughput() {
+        let now = DateTime::parse_from_rfc3339("2026-02-07T13:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut record = list_entry("orch-1", "executing", "2026-02-07T10:00:00Z").record;
+        record.total_phases = 2.0;
+        record.current_phase = 2.0;
+        let detail = OrchestrationDetailResponse {
+            id: "orch-1".to_string(),
+            node_name: "laptop".to_string(),
+            record,
+            // Listed out of order; the analytics sort by start
+            phases: vec![
+                phase("2", "2026-02-07T12:00:00Z", None),
+                phase("1", "2026-02-07T10:00:00Z", Some("2026-02-07T11:30:00Z")),
+            ],
+            tasks: vec![
+                task("1", "1", "completed"),
+                task("2", "1", "completed"),
+                task("3", "2", "completed"),
+                task("4", "2", "in_progress"),
+            ],
+            team_members: vec![],
+        };
+
+        let analytics = analyze(&detail, now);
+        let phases: Vec<_> = analytics
+            .phases
+            .iter()
+            .map(|p| (p.phase.as_str(), p.total_mins, p.tasks_completed))
+            .collect();
+        assert_eq!(phases, [("1", Some(90.0), 2), ("2", Some(60.0), 1)]);
+        // Two tasks in 30 minutes of execution
+        assert_eq!(analytics.phases[0].tasks_per_hour, Some(4.0));
+        assert_eq!(
+            analytics.gaps,
+            [PhaseGap {
+                after_phase: "1".to_string(),
+                before_phase: "2".to_string(),
+                mins: 30.0,
+            }]
+        );
+        assert_eq!(analytics.idle_mins, 30.0);
+        assert_eq!(analytics.elapsed_mins, Some(180));
+        assert_eq!(analytics.tasks_total, 4);
+        assert_eq!(analytics.tasks_completed, 3);
+        assert_eq!(analytics.tasks_per_hour, Some(1.0));
+    }
+}
@@ -0,0 +1,414 @@
+//! Scoped API token checks for the HTTP server.
+//!
+//! Tokens come from `tina-session token create` (see
+//! [`tina_session::tokens`]). A request carrying `Authorization: Bearer
+//! <token>` is always checked: an unknown or expired token is rejected with
+//! 401, and a `read` token making a state-changing request with 403. Requests
+//! without a token are let through unless `http_require_token` is set, so the
+//! local web UI keeps working out of the box.
+//!
+//! Browsers can't set headers on a WebSocket, so a request without an
+//! `Authorization` header may pass the token as `?access_token=<token>`
+//! instead. Only the path is logged, so the token stays out of request logs.
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{header, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use chrono::Utc;
+use tina_session::tokens::{TokenScope, TokenStore};
+use tracing::warn;
+
+/// Token checking settings for the HTTP server.
+#[derive(Debug, Clone)]
+pub struct TokenAuth {
+    /// Token store written by `tina-session token`, re-read per request so
+    /// new and revoked tokens apply without a restart.
+    pub tokens_path: PathBuf,
+    /// Reject requests that carry no token.
+    pub require_token: bool,
+}
+
+/// The token a request authenticated with. Added to the request's
+/// extensions for inner layers and to the response's for outer ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenIdentity {
+    pub id: String,
+    pub scope: TokenScope,
+}
+
+/// Who made a request, for logs and rate limits: `token:<id>` when it
+/// authenticated with a token, otherwise `ip:<addr>`.
+pub fn actor(token: Option<&TokenIdentity>, client: Option<&ConnectInfo<SocketAddr>>) -> String {
+    match (token, client) {
+        (Some(token), _) => format!("token:{}", token.id),
+        (None, Some(ConnectInfo(addr))) => format!("ip:{}", addr.ip()),
+        (None, None) => "anonymous".to_string(),
+    }
+}
+
+/// Paths reachable without a token: health checks, the API docs, the git
+/// hooks from `tina-session hooks install`, and plan edits from
+/// tina-monitor, which only ask for a re-sync.
+fn is_exempt(method: &Method, path: &str) -> bool {
+    method == Method::OPTIONS
+        || path == "/health"
+        || path == "/api/openapi.json"
+        || path == "/api/docs"
+        || path.starts_with("/hooks/")
+}
+
+/// Paths that take a POST body but never change state.
+pub const READ_ONLY_POSTS: &[&str] = &["/api/graphql"];
+
+/// Scope a request needs. Reads need `read`; anything that changes state,
+/// including terminal WebSockets (which forward keystrokes), needs `operate`.
+/// Commands sent over the orchestration WebSocket are checked one by one
+/// with [`authorize_command`].
+pub fn required_scope(method: &Method, path: &str) -> TokenScope {
+    let read_only = method == Method::GET
+        || method == Method::HEAD
+        || (method == Method::POST && READ_ONLY_POSTS.contains(&path));
+    if read_only && !path.starts_with("/ws/terminal/") {
+        TokenScope::Read
+    } else {
+        TokenScope::Operate
+    }
+}
+
+fn bearer_token(request: &Request) -> Option<&str> {
+    let Some(value) = request.headers().get(header::AUTHORIZATION) else {
+        return query_token(request);
+    };
+    value.to_str().ok()?.strip_prefix("Bearer ").map(str::trim)
+}
+
+/// Token passed as `access_token` in the query string.
+fn query_token(request: &Request) -> Option<&str> {
+    request
+        .uri()
+        .query()?
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("access_token="))
+        .filter(|token| !token.is_empty())
+}
+
+async fn load_store(auth: &TokenAuth) -> anyhow::Result<TokenStore> {
+    match tokio::fs::read_to_string(&auth.tokens_path).await {
+        Ok(contents) => TokenStore::parse(&contents),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(TokenStore::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Check that a command sent over an open WebSocket may run, returning the
+/// token it runs as. The message's own `token` is used when it has one,
+/// otherwise the token the connection was opened with; either way it needs
+/// `operate`. Without any token the command is allowed only when
+/// `http_require_token` is off.
+pub async fn authorize_command(
+    auth: Option<&TokenAuth>,
+    connection: Option<&TokenIdentity>,
+    message_token: Option<&str>,
+) -> Result<Option<TokenIdentity>, (StatusCode, String)> {
+    let Some(auth) = auth else {
+        return Ok(connection.cloned());
+    };
+    let identity = match (message_token, connection) {
+        (Some(secret), _) => {
+            let store = load_store(auth).await.map_err(|e| {
+                warn!(path = %auth.tokens_path.display(), error = %e, "failed to read token store");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "failed to read token store".to_string(),
+                )
+            })?;
+            let token = store.authenticate(secret, Utc::now()).ok_or((
+                StatusCode::UNAUTHORIZED,
+                "invalid or expired API token".to_string(),
+            ))?;
+            TokenIdentity {
+                id: token.id.clone(),
+                scope: token.scope,
+            }
+        }
+        (None, Some(identity)) => identity.clone(),
+        (None, None) if auth.require_token => {
+            return Err((StatusCode::UNAUTHORIZED, "API token required".to_string()));
+        }
+        (None, None) => return Ok(None),
+    };
+    let scope = identity.scope;
+    if !scope.allows(TokenScope::Operate) {
+        return Err((
+            StatusCode::FORBIDDEN,
+            format!(
+                "token scope '{}' does not allow commands (needs 'operate')",
+                scope.as_str()
+            ),
+        ));
+    }
+    Ok(Some(identity))
+}
+
+/// Middleware enforcing token scopes (see the module docs).
+pub async fn enforce_token_scope(
+    State(auth): State<TokenAuth>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let path = request.uri().path();
+    if is_exempt(request.method(), path) {
+        return next.run(request).await;
+    }
+    let required = required_scope(request.method(), path);
+
+    let Some(secret) = bearer_token(&request) else {
+        if auth.require_token {
+            return (StatusCode::UNAUTHORIZED, "API token required").into_response();
+        }
+        return next.run(request).await;
+    };
+
+    let store = match load_store(&auth).await {
+        Ok(store) => store,
+        Err(e) => {
+            warn!(path = %auth.tokens_path.display(), error = %e, "failed to read token store");
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "failed to read token store",
+            )
+                .into_response();
+        }
+    };
+    let Some(token) = store.authenticate(secret, Utc::now()) else {
+        return (StatusCode::UNAUTHORIZED, "invalid or expired API token").into_response();
+    };
+    if !token.scope.allows(required) {
+        return (
+            StatusCode::FORBIDDEN,
+            format!(
+                "token scope '{}' does not allow this request (needs '{}')",
+                token.scope.as_str(),
+                required.as_str()
+            ),
+        )
+            .into_response();
+    }
+
+    let identity = TokenIdentity {
+        id: token.id.clone(),
+        scope: token.scope,
+    };
+    request.extensions_mut().insert(identity.clone());
+    let mut response = next.run(request).await;
+    response.extensions_mut().insert(identity);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{build_router_with_state, AppState};
+    use axum::body::Body;
+    use axum::Router;
+    use tempfile::TempDir;
+    use tower::ServiceExt;
+
+    fn router(dir: &TempDir, require_token: bool) -> Router {
+        build_router_with_state(AppState {
+            token_auth: Some(TokenAuth {
+                tokens_path: dir.path().join("tokens.json"),
+                require_token,
+            }),
+            ..AppState::default()
+        })
+    }
+
+    /// Create a token in the store under `dir` and return its secret.
+    fn create_token(dir: &TempDir, scope: TokenScope) -> String {
+        let path = dir.path().join("tokens.json");
+        let mut store = TokenStore::load(&path).unwrap();
+        let (_, secret) = store.create(Some("ci"), scope, None, Utc::now());
+        store.save(&path).unwrap();
+        secret
+    }
+
+    fn request(method: Method, uri: &str, token: Option<&str>) -> Request<Body> {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(token) = token {
+            builder = builder.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    #[test]
+    fn test_required_scope() {
+        assert_eq!(required_scope(&Method::GET, "/diff"), TokenScope::Read);
+        assert_eq!(required_scope(&Method::HEAD, "/file"), TokenScope::Read);
+        assert_eq!(
+            required_scope(&Method::POST, "/sessions"),
+            TokenScope::Operate
+        );
+        assert_eq!(
+            required_scope(&Method::DELETE, "/sessions/tina-auth"),
+            TokenScope::Operate
+        );
+        assert_eq!(
+            required_scope(&Method::GET, "/ws/terminal/%1"),
+            TokenScope::Operate
+        );
+        assert_eq!(
+            required_scope(&Method::POST, "/api/graphql"),
+            TokenScope::Read
+        );
+        assert_eq!(
+            required_scope(&Method::GET, "/ws/orchestrations/orch-1"),
+            TokenScope::Read
+        );
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_requests_allowed_unless_required() {
+        let dir = TempDir::new().unwrap();
+        let resp = router(&dir, false)
+            .oneshot(request(Method::GET, "/api/editor/context?path=/tmp", None))
+            .await
+            .unwrap();
+        // Reaches the handler, which has no Convex client
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let resp = router(&dir, true)
+            .oneshot(request(Method::GET, "/api/editor/context?path=/tmp", None))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let resp = router(&dir, true)
+            .oneshot(request(Method::GET, "/health", None))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp = router(&dir, true)
+            .oneshot(request(Method::GET, "/api/openapi.json", None))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_token_rejected() {
+        let dir = TempDir::new().unwrap();
+        create_token(&dir, TokenScope::Operate);
+        let resp = router(&dir, false)
+            .oneshot(request(Method::GET, "/diff", Some("tina_bogus")))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_read_token_cannot_operate() {
+        let dir = TempDir::new().unwrap();
+        let read = create_token(&dir, TokenScope::Read);
+        let operate = create_token(&dir, TokenScope::Operate);
+
+        let resp = router(&dir, true)
+            .oneshot(request(
+                Method::GET,
+                "/api/editor/context?path=/tmp",
+                Some(&read),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let resp = router(&dir, true)
+            .oneshot(request(Method::POST, "/sessions", Some(&read)))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let resp = router(&dir, true)
+            .oneshot(request(Method::POST, "/sessions", Some(&operate)))
+            .await
+            .unwrap();
+        // Reaches the handler, which rejects the empty body
+        assert_ne!(resp.status(), StatusCode::FORBIDDEN);
+        assert_ne!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_token_accepted_from_query_string() {
+        let dir = TempDir::new().unwrap();
+        let read = create_token(&dir, TokenScope::Read);
+
+        let uri = format!("/api/editor/context?path=/tmp&access_token={}", read);
+        let resp = router(&dir, true)
+            .oneshot(request(Method::GET, &uri, None))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let resp = router(&dir, true)
+            .oneshot(request(
+                Method::GET,
+                "/api/editor/context?access_token=tina_bogus",
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_authorize_command_checks_each_message() {
+        let dir = TempDir::new().unwrap();
+        let read = create_token(&dir, TokenScope::Read);
+        let operate = create_token(&dir, TokenScope::Operate);
+        let auth = TokenAuth {
+            tokens_path: dir.path().join("tokens.json"),
+            require_token: true,
+        };
+        let status = |result: Result<Option<TokenIdentity>, (StatusCode, String)>| {
+            result.err().map(|(s, _)| s)
+        };
+
+        assert_eq!(
+            status(authorize_command(Some(&auth), None, Some(&operate)).await),
+            None
+        );
+        assert_eq!(
+            status(authorize_command(Some(&auth), None, Some(&read)).await),
+            Some(StatusCode::FORBIDDEN)
+        );
+        assert_eq!(
+            status(authorize_command(Some(&auth), None, Some("tina_bogus")).await),
+            Some(StatusCode::UNAUTHORIZED)
+        );
+        assert_eq!(
+            status(authorize_command(Some(&auth), None, None).await),
+            Some(StatusCode::UNAUTHORIZED)
+        );
+
+        // A read-only connection can still send commands with an operate token
+        let reader = TokenIdentity {
+            id: "r".to_string(),
+            scope: TokenScope::Read,
+        };
+        assert_eq!(
+            status(authorize_command(Some(&auth), Some(&reader), None).await),
+            Some(StatusCode::FORBIDDEN)
+        );
+        let identity = authorize_command(Some(&auth), Some(&reader), Some(&operate))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(identity.scope, TokenScope::Operate);
+        assert_eq!(authorize_command(None, None, None).await, Ok(None));
+    }
+}
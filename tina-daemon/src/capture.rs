@@ -0,0 +1,164 @@
+//! Agent pane capture.
+//!
+//! GET /api/orchestrations/{id}/agents/{agent}/capture?lines=N returns the
+//! last N lines (default 100) of an agent's tmux pane, so a dashboard can
+//! show what the agent is doing without attaching a terminal. `agent` is the
+//! team member name; the pane is the one most recently recorded for it in
+//! Convex. Panes live on the orchestration's node, so other daemons answer
+//! 409 rather than capture an unrelated local pane with the same id.
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+
+use tina_data::TeamMemberRecord;
+
+use crate::http::AppState;
+
+type ApiError = (StatusCode, String);
+
+pub const DEFAULT_CAPTURE_LINES: u32 = 100;
+pub const MAX_CAPTURE_LINES: u32 = 2000;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CaptureParams {
+    pub lines: Option<u32>,
+}
+
+impl CaptureParams {
+    /// Requested line count, clamped to `1..=MAX_CAPTURE_LINES`.
+    pub fn line_count(&self) -> u32 {
+        self.lines
+            .unwrap_or(DEFAULT_CAPTURE_LINES)
+            .clamp(1, MAX_CAPTURE_LINES)
+    }
+}
+
+/// Response body for GET .../agents/{agent}/capture.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureResponse {
+    pub agent: String,
+    pub pane_id: String,
+    pub lines: u32,
+    pub output: String,
+}
+
+/// Pane most recently recorded for `agent` across the orchestration's teams.
+pub fn agent_pane<'a>(members: &'a [TeamMemberRecord], agent: &str) -> Option<&'a str> {
+    members
+        .iter()
+        .filter(|member| member.agent_name == agent && member.tmux_pane_id.is_some())
+        .max_by_key(|member| member.recorded_at.as_str())
+        .and_then(|member| member.tmux_pane_id.as_deref())
+}
+
+/// Refuse to capture panes for an orchestration owned by another node: pane
+/// ids like `%7` are only meaningful on their own tmux server.
+pub fn check_local_node(
+    orchestration_node: &str,
+    local_node: Option<&str>,
+) -> Result<(), ApiError> {
+    if local_node == Some(orchestration_node) {
+        return Ok(());
+    }
+    Err((
+        StatusCode::CONFLICT,
+        format!(
+            "Orchestration runs on node {}; capture its panes from that node's daemon",
+            orchestration_node
+        ),
+    ))
+}
+
+/// GET /api/orchestrations/{id}/agents/{agent}/capture handler.
+pub async fn get_capture(
+    State(state): State<AppState>,
+    Path((orchestration_id, agent)): Path<(String, String)>,
+    Query(params): Query<CaptureParams>,
+) -> Result<Json<CaptureResponse>, ApiError> {
+    let client = state.convex()?;
+
+    let detail = client
+        .lock()
+        .await
+        .get_orchestration_detail(&orchestration_id)
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("Orchestration not found: {}", orchestration_id),
+        ))?;
+    check_local_node(&detail.record.node_id, state.node_id.as_deref())?;
+    let pane_id = agent_pane(&detail.team_members, &agent)
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("No tmux pane recorded for {}", agent),
+        ))?
+        .to_string();
+
+    let lines = params.line_count();
+    let pane = pane_id.clone();
+    let output =
+        tokio::task::spawn_blocking(move || tina_session::tmux::capture_pane_lines(&pane, lines))
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            // Most often the pane has closed
+            .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+
+    Ok(Json(CaptureResponse {
+        agent,
+        pane_id,
+        lines,
+        output,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(name: &str, pane: Option<&str>, recorded_at: &str) -> TeamMemberRecord {
+        TeamMemberRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: "1".to_string(),
+            agent_name: name.to_string(),
+            agent_type: None,
+            model: None,
+            joined_at: None,
+            tmux_pane_id: pane.map(str::to_string),
+            recorded_at: recorded_at.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_capture_is_refused_for_other_nodes() {
+        assert!(check_local_node("node-1", Some("node-1")).is_ok());
+        let (status, message) = check_local_node("node-2", Some("node-1")).unwrap_err();
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert!(message.contains("node-2"));
+        assert!(check_local_node("node-1", None).is_err());
+    }
+
+    #[test]
+    fn test_agent_pane_is_the_latest_recorded() {
+        let members = [
+            member("executor-1", Some("%2"), "2026-02-13T10:00:00Z"),
+            member("executor-1", Some("%7"), "2026-02-13T11:00:00Z"),
+            member("executor-1", None, "2026-02-13T12:00:00Z"),
+            member("reviewer", None, "2026-02-13T10:00:00Z"),
+        ];
+        assert_eq!(agent_pane(&members, "executor-1"), Some("%7"));
+        assert_eq!(agent_pane(&members, "reviewer"), None);
+        assert_eq!(agent_pane(&members, "planner"), None);
+    }
+
+    #[test]
+    fn test_line_count_is_clamped() {
+        let params = |lines| CaptureParams { lines };
+        assert_eq!(params(None).line_count(), DEFAULT_CAPTURE_LINES);
+        assert_eq!(params(Some(0)).line_count(), 1);
+        assert_eq!(params(Some(50_000)).line_count(), MAX_CAPTURE_LINES);
+    }
+}
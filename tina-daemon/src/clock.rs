@@ -0,0 +1,70 @@
+//! Clock skew detection between this node and Convex.
+//!
+//! Elapsed-time and stuck-task calculations mix node timestamps with Convex
+//! server timestamps, so a skewed local clock silently corrupts them. Each
+//! heartbeat returns the server time; the skew is estimated against the
+//! midpoint of the round trip and reported on the next heartbeat, in the logs,
+//! and on the daemon's `/health` endpoint.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tina_data::clock::{estimate_skew_ms, exceeds_warn_threshold};
+use tokio::sync::watch;
+
+/// Latest clock skew measurement.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClockSkew {
+    /// Local clock minus server clock. Positive means the node is ahead.
+    pub skew_ms: i64,
+    /// Heartbeat round trip, which bounds the measurement error.
+    pub round_trip_ms: i64,
+    pub measured_at: DateTime<Utc>,
+}
+
+impl ClockSkew {
+    /// Estimate skew from local send/receive times and the server timestamp.
+    pub fn estimate(sent_ms: i64, received_ms: i64, server_ms: i64) -> Self {
+        Self {
+            skew_ms: estimate_skew_ms(sent_ms, received_ms, server_ms),
+            round_trip_ms: (received_ms - sent_ms).max(0),
+            measured_at: DateTime::from_timestamp_millis(received_ms).unwrap_or_else(Utc::now),
+        }
+    }
+
+    /// Whether the skew is beyond the warning threshold.
+    pub fn exceeds_threshold(&self) -> bool {
+        exceeds_warn_threshold(self.skew_ms)
+    }
+}
+
+/// Receiver for the latest measurement, shared with the HTTP server.
+pub type ClockSkewReceiver = watch::Receiver<Option<ClockSkew>>;
+
+/// Channel for publishing skew measurements from the heartbeat task.
+pub fn channel() -> (watch::Sender<Option<ClockSkew>>, ClockSkewReceiver) {
+    watch::channel(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tina_data::clock::CLOCK_SKEW_WARN_MS;
+
+    #[test]
+    fn test_estimate_uses_round_trip_midpoint() {
+        let skew = ClockSkew::estimate(10_000, 10_200, 10_100);
+        assert_eq!(skew.skew_ms, 0);
+        assert_eq!(skew.round_trip_ms, 200);
+
+        let ahead = ClockSkew::estimate(20_000, 20_100, 12_050);
+        assert_eq!(ahead.skew_ms, 8_000);
+        assert!(ahead.exceeds_threshold());
+    }
+
+    #[test]
+    fn test_exceeds_threshold_in_both_directions() {
+        assert!(ClockSkew::estimate(0, 0, 6_000).exceeds_threshold());
+        assert!(!ClockSkew::estimate(0, 0, 4_000).exceeds_threshold());
+        assert!(!ClockSkew::estimate(0, 0, -CLOCK_SKEW_WARN_MS).exceeds_threshold());
+    }
+}
@@ -3,6 +3,9 @@ use std::path::PathBuf;
 use anyhow::{bail, Context, Result};
 use serde::Deserialize;
 
+use crate::rate_limit::DEFAULT_MUTATIONS_PER_MINUTE;
+use crate::send::SendTextPolicy;
+
 /// Daemon configuration loaded from file and/or environment.
 #[derive(Debug, Clone)]
 pub struct DaemonConfig {
@@ -12,6 +15,57 @@ pub struct DaemonConfig {
     pub auth_token: String,
     pub node_name: String,
     pub http_port: u16,
+    /// Remote machines whose `~/.claude` state is mirrored over SSH and
+    /// synced as separate nodes.
+    pub remotes: Vec<RemoteNodeConfig>,
+    /// Maximum number of orchestrations synced concurrently.
+    pub sync_workers: usize,
+    /// Maximum git ref/plan/design paths registered with the OS file
+    /// watcher; further paths are polled.
+    pub max_watches: usize,
+    /// Reject HTTP requests without an API token (see `tina-session token`).
+    pub require_token: bool,
+    /// State-changing HTTP requests allowed per client IP per minute, and
+    /// per token unless `token_mutations_per_minute` says otherwise; 0
+    /// disables the limit.
+    pub mutations_per_minute: u32,
+    /// State-changing HTTP requests allowed per API token per minute; 0
+    /// disables the limit.
+    pub token_mutations_per_minute: u32,
+    /// Which agents the HTTP API may send text to.
+    pub send_text: SendTextPolicy,
+    /// Serve the GraphQL endpoint at `/api/graphql`.
+    pub graphql: bool,
+}
+
+/// Default size of the per-orchestration sync worker pool.
+pub const DEFAULT_SYNC_WORKERS: usize = 4;
+
+/// Default cap on dynamic OS file watches. Well under the common inotify
+/// default of 8192 so other tools keep headroom.
+pub const DEFAULT_MAX_WATCHES: usize = 512;
+
+/// A remote machine synced over SSH (`[[remotes]]` in the config file).
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub struct RemoteNodeConfig {
+    /// Node name registered in Convex for the remote machine.
+    pub name: String,
+    /// SSH destination, e.g. `desktop` or `me@desktop.local`.
+    pub host: String,
+    /// Claude state directory on the remote machine.
+    #[serde(default = "default_remote_claude_dir")]
+    pub claude_dir: String,
+    /// Seconds between mirror + sync passes.
+    #[serde(default = "default_remote_interval_secs")]
+    pub interval_secs: u64,
+}
+
+fn default_remote_claude_dir() -> String {
+    "~/.claude".to_string()
+}
+
+fn default_remote_interval_secs() -> u64 {
+    60
 }
 
 #[derive(Debug, Deserialize, Default, Clone)]
@@ -35,6 +89,31 @@ struct ConfigFile {
     active_env: Option<String>,
     prod: Option<ProfileConfig>,
     dev: Option<ProfileConfig>,
+
+    // Remote nodes (shared across profiles).
+    #[serde(default)]
+    remotes: Vec<RemoteNodeConfig>,
+
+    // Sync worker pool size (shared across profiles).
+    sync_workers: Option<usize>,
+
+    // Cap on dynamic OS file watches (shared across profiles).
+    max_watches: Option<usize>,
+
+    // Require an API token on HTTP requests (shared across profiles).
+    http_require_token: Option<bool>,
+
+    // Per-actor limit on state-changing HTTP requests (shared across profiles).
+    http_mutations_per_minute: Option<u32>,
+    http_token_mutations_per_minute: Option<u32>,
+
+    // Agents the HTTP API may send text to (shared across profiles).
+    #[serde(default)]
+    send_text_agents: Vec<String>,
+    send_text_require_confirm: Option<bool>,
+
+    // Serve the GraphQL API (shared across profiles).
+    http_graphql: Option<bool>,
 }
 
 /// Default config file location.
@@ -76,6 +155,15 @@ impl DaemonConfig {
             active_env,
             prod,
             dev,
+            remotes,
+            sync_workers,
+            max_watches,
+            http_require_token,
+            http_mutations_per_minute,
+            http_token_mutations_per_minute,
+            send_text_agents,
+            send_text_require_confirm,
+            http_graphql,
         } = file_config;
 
         let env = resolve_env(env_override, active_env.as_deref())?;
@@ -104,13 +192,31 @@ impl DaemonConfig {
             .or(http_port)
             .unwrap_or(7842);
 
-        Self::build(
+        let mut config = Self::build(
             env,
             resolved_convex_url,
             resolved_auth_token,
             resolved_node_name,
             resolved_http_port,
-        )
+        )?;
+        config.remotes = validate_remotes(remotes, &config.node_name)?;
+        config.sync_workers = match sync_workers {
+            Some(0) => bail!("sync_workers must be at least 1"),
+            Some(n) => n,
+            None => DEFAULT_SYNC_WORKERS,
+        };
+        config.max_watches = max_watches.unwrap_or(DEFAULT_MAX_WATCHES);
+        config.require_token = http_require_token.unwrap_or(false);
+        config.mutations_per_minute =
+            http_mutations_per_minute.unwrap_or(DEFAULT_MUTATIONS_PER_MINUTE);
+        config.token_mutations_per_minute =
+            http_token_mutations_per_minute.unwrap_or(config.mutations_per_minute);
+        config.send_text = SendTextPolicy {
+            allow_agents: send_text_agents,
+            require_confirm: send_text_require_confirm.unwrap_or(true),
+        };
+        config.graphql = http_graphql.unwrap_or(false);
+        Ok(config)
     }
 
     /// Build config from resolved option values (after file + env merging).
@@ -142,10 +248,46 @@ impl DaemonConfig {
             auth_token,
             node_name,
             http_port,
+            remotes: Vec::new(),
+            sync_workers: DEFAULT_SYNC_WORKERS,
+            max_watches: DEFAULT_MAX_WATCHES,
+            require_token: false,
+            mutations_per_minute: DEFAULT_MUTATIONS_PER_MINUTE,
+            token_mutations_per_minute: DEFAULT_MUTATIONS_PER_MINUTE,
+            send_text: SendTextPolicy {
+                allow_agents: Vec::new(),
+                require_confirm: true,
+            },
+            graphql: false,
         })
     }
 }
 
+fn validate_remotes(
+    remotes: Vec<RemoteNodeConfig>,
+    local_node_name: &str,
+) -> Result<Vec<RemoteNodeConfig>> {
+    let mut seen = std::collections::HashSet::new();
+    for remote in &remotes {
+        if remote.name.trim().is_empty() || remote.host.trim().is_empty() {
+            bail!("remotes entries require non-empty 'name' and 'host'");
+        }
+        if remote.name == local_node_name {
+            bail!(
+                "remote node name '{}' collides with the local node name",
+                remote.name
+            );
+        }
+        if !seen.insert(remote.name.as_str()) {
+            bail!("duplicate remote node name '{}'", remote.name);
+        }
+        if remote.interval_secs == 0 {
+            bail!("remote '{}' interval_secs must be > 0", remote.name);
+        }
+    }
+    Ok(remotes)
+}
+
 fn resolve_env(env_override: Option<&str>, active_env: Option<&str>) -> Result<String> {
     let raw = env_override
         .map(str::to_string)
@@ -394,6 +536,200 @@ node_name = "prod-node"
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_config_file_parsing_remotes_with_defaults() {
+        let toml_str = r#"
+convex_url = "https://test.convex.cloud"
+auth_token = "secret"
+
+[[remotes]]
+name = "desktop"
+host = "me@desktop.local"
+
+[[remotes]]
+name = "build-box"
+host = "build"
+claude_dir = "/home/ci/.claude"
+interval_secs = 15
+"#;
+        let file_config: ConfigFile = toml::from_str(toml_str).unwrap();
+        assert_eq!(file_config.remotes.len(), 2);
+        assert_eq!(file_config.remotes[0].claude_dir, "~/.claude");
+        assert_eq!(file_config.remotes[0].interval_secs, 60);
+        assert_eq!(file_config.remotes[1].claude_dir, "/home/ci/.claude");
+        assert_eq!(file_config.remotes[1].interval_secs, 15);
+    }
+
+    #[test]
+    fn test_sync_workers_default_and_override() {
+        let base = || ConfigFile {
+            convex_url: Some("https://example.convex.cloud".to_string()),
+            auth_token: Some("token".to_string()),
+            node_name: Some("laptop".to_string()),
+            ..ConfigFile::default()
+        };
+
+        let config = DaemonConfig::from_file_and_env(base(), Some("prod")).unwrap();
+        assert_eq!(config.sync_workers, DEFAULT_SYNC_WORKERS);
+
+        let file = ConfigFile {
+            sync_workers: Some(8),
+            ..base()
+        };
+        let config = DaemonConfig::from_file_and_env(file, Some("prod")).unwrap();
+        assert_eq!(config.sync_workers, 8);
+
+        let file = ConfigFile {
+            sync_workers: Some(0),
+            ..base()
+        };
+        assert!(DaemonConfig::from_file_and_env(file, Some("prod")).is_err());
+    }
+
+    #[test]
+    fn test_max_watches_default_and_override() {
+        let base = || ConfigFile {
+            convex_url: Some("https://example.convex.cloud".to_string()),
+            auth_token: Some("token".to_string()),
+            node_name: Some("laptop".to_string()),
+            ..ConfigFile::default()
+        };
+
+        let config = DaemonConfig::from_file_and_env(base(), Some("prod")).unwrap();
+        assert_eq!(config.max_watches, DEFAULT_MAX_WATCHES);
+
+        let file: ConfigFile = toml::from_str(
+            r#"
+convex_url = "https://example.convex.cloud"
+auth_token = "token"
+max_watches = 0
+"#,
+        )
+        .unwrap();
+        let config = DaemonConfig::from_file_and_env(file, Some("prod")).unwrap();
+        assert_eq!(config.max_watches, 0, "0 polls every dynamic path");
+    }
+
+    #[test]
+    fn test_http_access_settings() {
+        let file: ConfigFile = toml::from_str(
+            r#"
+convex_url = "https://example.convex.cloud"
+auth_token = "token"
+"#,
+        )
+        .unwrap();
+        let config = DaemonConfig::from_file_and_env(file, Some("prod")).unwrap();
+        assert!(!config.require_token);
+        assert_eq!(config.mutations_per_minute, DEFAULT_MUTATIONS_PER_MINUTE);
+        assert_eq!(
+            config.token_mutations_per_minute,
+            DEFAULT_MUTATIONS_PER_MINUTE
+        );
+
+        let file: ConfigFile = toml::from_str(
+            r#"
+convex_url = "https://example.convex.cloud"
+auth_token = "token"
+http_require_token = true
+http_mutations_per_minute = 0
+"#,
+        )
+        .unwrap();
+        let config = DaemonConfig::from_file_and_env(file, Some("prod")).unwrap();
+        assert!(config.require_token);
+        assert_eq!(config.mutations_per_minute, 0);
+        assert_eq!(config.token_mutations_per_minute, 0);
+
+        let file: ConfigFile = toml::from_str(
+            r#"
+convex_url = "https://example.convex.cloud"
+auth_token = "token"
+http_mutations_per_minute = 20
+http_token_mutations_per_minute = 300
+"#,
+        )
+        .unwrap();
+        let config = DaemonConfig::from_file_and_env(file, Some("prod")).unwrap();
+        assert_eq!(config.mutations_per_minute, 20);
+        assert_eq!(config.token_mutations_per_minute, 300);
+    }
+
+    #[test]
+    fn test_send_text_is_off_until_agents_are_allowed() {
+        let file: ConfigFile = toml::from_str(
+            r#"
+convex_url = "https://example.convex.cloud"
+auth_token = "token"
+"#,
+        )
+        .unwrap();
+        let config = DaemonConfig::from_file_and_env(file, Some("prod")).unwrap();
+        assert!(config.send_text.allow_agents.is_empty());
+        assert!(config.send_text.require_confirm);
+
+        let file: ConfigFile = toml::from_str(
+            r#"
+convex_url = "https://example.convex.cloud"
+auth_token = "token"
+send_text_agents = ["orchestrator", "executor-*"]
+send_text_require_confirm = false
+"#,
+        )
+        .unwrap();
+        let config = DaemonConfig::from_file_and_env(file, Some("prod")).unwrap();
+        assert!(config.send_text.allows("executor-3"));
+        assert!(!config.send_text.require_confirm);
+    }
+
+    #[test]
+    fn test_graphql_is_off_unless_enabled() {
+        let file: ConfigFile = toml::from_str(
+            r#"
+convex_url = "https://example.convex.cloud"
+auth_token = "token"
+"#,
+        )
+        .unwrap();
+        let config = DaemonConfig::from_file_and_env(file, Some("prod")).unwrap();
+        assert!(!config.graphql);
+
+        let file: ConfigFile = toml::from_str(
+            r#"
+convex_url = "https://example.convex.cloud"
+auth_token = "token"
+http_graphql = true
+"#,
+        )
+        .unwrap();
+        let config = DaemonConfig::from_file_and_env(file, Some("prod")).unwrap();
+        assert!(config.graphql);
+    }
+
+    #[test]
+    fn test_validate_remotes_rejects_local_name_collision() {
+        let remotes = vec![RemoteNodeConfig {
+            name: "laptop".to_string(),
+            host: "desktop".to_string(),
+            claude_dir: default_remote_claude_dir(),
+            interval_secs: 60,
+        }];
+        let err = validate_remotes(remotes, "laptop").unwrap_err();
+        assert!(err.to_string().contains("collides"));
+    }
+
+    #[test]
+    fn test_validate_remotes_rejects_duplicates() {
+        let remote = RemoteNodeConfig {
+            name: "desktop".to_string(),
+            host: "desktop".to_string(),
+            claude_dir: default_remote_claude_dir(),
+            interval_secs: 60,
+        };
+        let err = validate_remotes(vec![remote.clone(), remote], "laptop").unwrap_err();
+        assert!(err.to_string().contains("duplicate"));
+    }
+
     #[test]
     fn test_build_with_custom_http_port() {
         let config = DaemonConfig::build(
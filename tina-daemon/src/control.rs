@@ -0,0 +1,454 @@
+//! Orchestration control over a WebSocket.
+//!
+//! GET /ws/orchestrations/{id} sends the orchestration's events as JSON text
+//! frames, the same [`StreamEvent`]s as `.../events/stream`, and takes
+//! commands back on the same socket, so a dashboard doesn't have to read
+//! over a WebSocket and write over REST:
+//!
+//! ```text
+//! {"id": "1", "command": "pause", "phase": "2"}
+//! {"id": "2", "command": "resume"}
+//! {"id": "3", "command": "retry_phase", "phase": "2", "reason": "flaky test"}
+//! {"id": "4", "command": "approve_gate", "gate": "review", "decidedBy": "sam"}
+//! ```
+//!
+//! Every command is answered with an `ack` frame echoing its `id`, with the
+//! HTTP status the matching REST call would have returned. Each message is
+//! authorized on its own (see [`auth::authorize_command`]): it may carry a
+//! `token`, so a connection opened with a `read` token can still send
+//! commands signed by an `operate` one. Commands count against the same
+//! rate limits as state-changing HTTP requests, and acks carry the
+//! `requestId` of the request that opened the connection.
+
+use std::net::SocketAddr;
+use std::time::Instant;
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{ConnectInfo, Path, State};
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::Extension;
+use futures::stream::{self, BoxStream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tower_http::request_id::RequestId;
+use tracing::{debug, info, warn, Instrument, Span};
+
+use crate::auth::{self, TokenIdentity};
+use crate::gates::{self, ApproveRequest};
+use crate::http::AppState;
+use crate::request_log;
+use crate::stream::{orchestration_events, StreamEvent};
+
+type ApiError = (StatusCode, String);
+
+/// A command a client can send.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum Command {
+    /// Stop the orchestration at `phase`.
+    Pause { phase: String },
+    /// Pick up where a paused orchestration left off.
+    Resume,
+    /// Run `phase` again.
+    RetryPhase {
+        phase: String,
+        #[serde(default)]
+        reason: Option<String>,
+    },
+    /// Approve a review gate, as POST .../gates/{gate}/approve.
+    ApproveGate {
+        gate: String,
+        #[serde(flatten)]
+        request: ApproveRequest,
+    },
+}
+
+/// A client → server text frame.
+#[derive(Debug, Deserialize)]
+pub struct CommandMessage {
+    /// Echoed back in the ack
+    pub id: String,
+    /// Authorizes just this command, instead of the connection's token
+    #[serde(default)]
+    pub token: Option<String>,
+    #[serde(flatten)]
+    pub command: Command,
+}
+
+/// The server's answer to a command.
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(tag = "type", rename = "ack", rename_all = "camelCase")]
+pub struct Ack {
+    /// `None` when the frame was too malformed to have one
+    pub id: Option<String>,
+    pub ok: bool,
+    pub status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    /// Id of the request that opened the connection, for matching logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+impl Ack {
+    fn new(id: Option<String>, outcome: Result<(StatusCode, Value), ApiError>) -> Self {
+        match outcome {
+            Ok((status, result)) => Self {
+                id,
+                ok: true,
+                status: status.as_u16(),
+                error: None,
+                result: Some(result),
+                request_id: None,
+            },
+            Err((status, error)) => Self {
+                id,
+                ok: false,
+                status: status.as_u16(),
+                error: Some(error),
+                result: None,
+                request_id: None,
+            },
+        }
+    }
+}
+
+/// What the request that opened a connection carried.
+#[derive(Debug, Clone, Default)]
+struct Connection {
+    identity: Option<TokenIdentity>,
+    client: Option<ConnectInfo<SocketAddr>>,
+    request_id: Option<String>,
+}
+
+/// Queue a daemon action for the orchestration, like the web UI's buttons.
+async fn queue_action(
+    state: &AppState,
+    orchestration_id: &str,
+    action_type: &str,
+    phase: Option<&str>,
+    reason: Option<&str>,
+) -> Result<(StatusCode, Value), ApiError> {
+    let client = state.convex()?;
+
+    let mut client = client.lock().await;
+    let detail = client
+        .get_orchestration_detail(orchestration_id)
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("Orchestration not found: {}", orchestration_id),
+        ))?;
+    if let Some(phase) = phase {
+        if !detail.phases.iter().any(|p| p.phase_number == phase) {
+            return Err((StatusCode::NOT_FOUND, format!("Phase {} not found", phase)));
+        }
+    }
+
+    let payload = serde_json::json!({
+        "feature": detail.record.feature_name,
+        "phase": phase,
+        "reason": reason,
+    });
+    let action_id = client
+        .submit_action(
+            &detail.record.node_id,
+            orchestration_id,
+            action_type,
+            &payload.to_string(),
+        )
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+    info!(
+        orchestration_id = %orchestration_id,
+        action_type = %action_type,
+        action_id = %action_id,
+        "queued action from WebSocket"
+    );
+    Ok((
+        StatusCode::ACCEPTED,
+        serde_json::json!({ "actionId": action_id }),
+    ))
+}
+
+async fn run(
+    state: &AppState,
+    orchestration_id: &str,
+    identity: Option<&TokenIdentity>,
+    command: Command,
+) -> Result<(StatusCode, Value), ApiError> {
+    match command {
+        Command::Pause { phase } => {
+            queue_action(state, orchestration_id, "pause", Some(&phase), None).await
+        }
+        Command::Resume => queue_action(state, orchestration_id, "resume", None, None).await,
+        Command::RetryPhase { phase, reason } => {
+            queue_action(
+                state,
+                orchestration_id,
+                "retry",
+                Some(&phase),
+                reason.as_deref(),
+            )
+            .await
+        }
+        Command::ApproveGate { gate, request } => {
+            let decision = gates::decide(
+                state.clone(),
+                orchestration_id,
+                &gate,
+                identity,
+                &request.decided_by,
+                "approved",
+                &request.summary,
+            )
+            .await?;
+            let result = serde_json::to_value(decision.0)
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            Ok((StatusCode::OK, result))
+        }
+    }
+}
+
+/// Check the command's token, then take it from its actor's rate limit.
+/// Returns the identity the command runs as.
+async fn authorize(
+    state: &AppState,
+    connection: &Connection,
+    token: Option<&str>,
+) -> Result<Option<TokenIdentity>, ApiError> {
+    let identity = auth::authorize_command(
+        state.token_auth.as_ref(),
+        connection.identity.as_ref(),
+        token,
+    )
+    .await?;
+    if let Some(limiter) = &state.rate_limiter {
+        let actor = auth::actor(identity.as_ref(), connection.client.as_ref());
+        if let Err(wait) = limiter.check(&actor, Instant::now()) {
+            warn!(actor = %actor, "rate limit exceeded on WebSocket command");
+            return Err((
+                StatusCode::TOO_MANY_REQUESTS,
+                format!(
+                    "rate limit exceeded; retry in {}s",
+                    (wait.as_secs_f64().ceil() as u64).max(1)
+                ),
+            ));
+        }
+    }
+    Ok(identity)
+}
+
+/// Parse, authorize and run one text frame.
+async fn handle_message(
+    state: &AppState,
+    orchestration_id: &str,
+    connection: &Connection,
+    text: &str,
+) -> Ack {
+    let (id, outcome) = match serde_json::from_str::<CommandMessage>(text) {
+        Ok(message) => {
+            let outcome = match authorize(state, connection, message.token.as_deref()).await {
+                Ok(identity) => {
+                    run(state, orchestration_id, identity.as_ref(), message.command).await
+                }
+                Err(e) => Err(e),
+            };
+            (Some(message.id), outcome)
+        }
+        Err(e) => {
+            // Still echo the id if the frame has one, so the client can match it
+            let id = serde_json::from_str::<Value>(text)
+                .ok()
+                .and_then(|v| v.get("id")?.as_str().map(str::to_string));
+            (id, Err((StatusCode::BAD_REQUEST, e.to_string())))
+        }
+    };
+    Ack {
+        request_id: connection.request_id.clone(),
+        ..Ack::new(id, outcome)
+    }
+}
+
+async fn send_json<T: Serialize>(socket: &mut WebSocket, frame: &T) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(frame).unwrap_or_default();
+    socket.send(Message::Text(text.into())).await
+}
+
+async fn handle_socket(
+    mut socket: WebSocket,
+    state: AppState,
+    orchestration_id: String,
+    connection: Connection,
+) {
+    let mut events: BoxStream<'static, StreamEvent> = match &state.events {
+        Some(bus) => orchestration_events(bus.subscribe(), orchestration_id.clone()).boxed(),
+        None => stream::pending().boxed(),
+    };
+
+    loop {
+        tokio::select! {
+            message = socket.recv() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    let ack = handle_message(&state, &orchestration_id, &connection, &text).await;
+                    if send_json(&mut socket, &ack).await.is_err() {
+                        break;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | None => break,
+                Some(Ok(_)) => {}
+                Some(Err(e)) => {
+                    debug!(orchestration_id = %orchestration_id, error = %e, "WebSocket receive error");
+                    break;
+                }
+            },
+            Some(event) = events.next() => {
+                if send_json(&mut socket, &event).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// GET /ws/orchestrations/{id} handler.
+pub async fn ws_orchestration_handler(
+    State(state): State<AppState>,
+    Path(orchestration_id): Path<String>,
+    identity: Option<Extension<TokenIdentity>>,
+    client: Option<Extension<ConnectInfo<SocketAddr>>>,
+    request_id: Option<Extension<RequestId>>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    let connection = Connection {
+        identity: identity.map(|Extension(identity)| identity),
+        client: client.map(|Extension(client)| client),
+        request_id: request_id.and_then(|Extension(id)| request_log::request_id(&id)),
+    };
+    // Keep logging under the upgrade request's span once the socket is open
+    let span = Span::current();
+    ws.on_upgrade(move |socket| {
+        handle_socket(socket, state, orchestration_id, connection).instrument(span)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rate_limit::RateLimiter;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_parses_each_command() {
+        let parse = |text: &str| serde_json::from_str::<CommandMessage>(text).unwrap();
+
+        let message = parse(r#"{"id": "1", "command": "pause", "phase": "2"}"#);
+        assert_eq!(message.id, "1");
+        assert_eq!(message.token, None);
+        assert_eq!(
+            message.command,
+            Command::Pause {
+                phase: "2".to_string()
+            }
+        );
+        assert_eq!(
+            parse(r#"{"id": "2", "command": "resume", "token": "tina_x"}"#).command,
+            Command::Resume
+        );
+        assert_eq!(
+            parse(r#"{"id": "3", "command": "retry_phase", "phase": "1"}"#).command,
+            Command::RetryPhase {
+                phase: "1".to_string(),
+                reason: None
+            }
+        );
+        match parse(
+            r#"{"id": "4", "command": "approve_gate", "gate": "review", "decidedBy": "sam"}"#,
+        )
+        .command
+        {
+            Command::ApproveGate { gate, request } => {
+                assert_eq!(gate, "review");
+                assert_eq!(request.decided_by, "sam");
+                assert_eq!(request.summary, "Approved");
+            }
+            other => panic!("unexpected command: {:?}", other),
+        }
+        assert!(
+            serde_json::from_str::<CommandMessage>(r#"{"id": "5", "command": "merge"}"#).is_err()
+        );
+        assert!(
+            serde_json::from_str::<CommandMessage>(r#"{"id": "6", "command": "pause"}"#).is_err()
+        );
+    }
+
+    fn state(rate_limiter: Option<RateLimiter>) -> AppState {
+        AppState {
+            rate_limiter: rate_limiter.map(Arc::new),
+            ..AppState::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_acks_bad_frames_and_unavailable_convex() {
+        let state = state(None);
+        let connection = Connection::default();
+        let ack = handle_message(
+            &state,
+            "orch-1",
+            &connection,
+            r#"{"id": "7", "command": "nope"}"#,
+        )
+        .await;
+        assert_eq!(ack.id.as_deref(), Some("7"));
+        assert!(!ack.ok);
+        assert_eq!(ack.status, 400);
+
+        let ack = handle_message(&state, "orch-1", &connection, "not json").await;
+        assert_eq!(ack.id, None);
+        assert_eq!(ack.status, 400);
+
+        let ack = handle_message(
+            &state,
+            "orch-1",
+            &connection,
+            r#"{"id": "8", "command": "resume"}"#,
+        )
+        .await;
+        assert_eq!(
+            ack,
+            Ack {
+                id: Some("8".to_string()),
+                ok: false,
+                status: 503,
+                error: Some("Convex client not available".to_string()),
+                result: None,
+                request_id: None,
+            }
+        );
+        let frame = serde_json::to_value(&ack).unwrap();
+        assert_eq!(frame["type"], "ack");
+        assert!(frame.get("result").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_commands_share_the_rate_limit() {
+        let state = state(Some(RateLimiter::new(1)));
+        let connection = Connection {
+            request_id: Some("req-1".to_string()),
+            ..Connection::default()
+        };
+        let resume = r#"{"id": "1", "command": "resume"}"#;
+
+        let ack = handle_message(&state, "orch-1", &connection, resume).await;
+        assert_eq!(ack.status, 503);
+        assert_eq!(ack.request_id.as_deref(), Some("req-1"));
+        let ack = handle_message(&state, "orch-1", &connection, resume).await;
+        assert_eq!(ack.status, 429);
+        assert_eq!(serde_json::to_value(&ack).unwrap()["requestId"], "req-1");
+    }
+}
@@ -0,0 +1,425 @@
+//! Editor companion endpoints.
+//!
+//! Lookups keyed by local paths, so an editor extension can decorate the
+//! files it has open without knowing Convex IDs:
+//!
+//! GET /api/editor/context?path=  — repo root, branch, and matching orchestration
+//! GET /api/editor/tasks?path=    — tasks for the orchestration on the current branch
+//! GET /api/editor/findings?path= — review findings for one file, with line ranges
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use tina_data::{OrchestrationListEntry, ReviewThreadRecord, TaskEventRecord, TinaConvexClient};
+
+use crate::git;
+use crate::http::AppState;
+
+type ApiError = (StatusCode, String);
+
+#[derive(Debug, Deserialize)]
+pub struct EditorPathParams {
+    pub path: String,
+}
+
+/// Orchestration summary for editor status bars.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditorOrchestration {
+    pub id: String,
+    pub feature_name: String,
+    pub status: String,
+    pub branch: String,
+    pub worktree_path: Option<String>,
+    pub current_phase: f64,
+    pub total_phases: f64,
+}
+
+impl From<&OrchestrationListEntry> for EditorOrchestration {
+    fn from(entry: &OrchestrationListEntry) -> Self {
+        Self {
+            id: entry.id.clone(),
+            feature_name: entry.record.feature_name.clone(),
+            status: entry.record.status.clone(),
+            branch: entry.record.branch.clone(),
+            worktree_path: entry.record.worktree_path.clone(),
+            current_phase: entry.record.current_phase,
+            total_phases: entry.record.total_phases,
+        }
+    }
+}
+
+/// Response body for GET /api/editor/context.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditorContext {
+    pub repo_root: String,
+    pub branch: Option<String>,
+    /// Path of the requested file relative to `repo_root`, if it was a file.
+    pub relative_path: Option<String>,
+    pub orchestration: Option<EditorOrchestration>,
+}
+
+/// Response body for GET /api/editor/tasks.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditorTasks {
+    pub orchestration: Option<EditorOrchestration>,
+    pub tasks: Vec<EditorTask>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditorTask {
+    pub task_id: String,
+    pub phase_number: Option<String>,
+    pub subject: String,
+    pub status: String,
+    pub owner: Option<String>,
+    pub blocked_by: Option<String>,
+}
+
+impl From<TaskEventRecord> for EditorTask {
+    fn from(task: TaskEventRecord) -> Self {
+        Self {
+            task_id: task.task_id,
+            phase_number: task.phase_number,
+            subject: task.subject,
+            status: task.status,
+            owner: task.owner,
+            blocked_by: task.blocked_by,
+        }
+    }
+}
+
+/// Response body for GET /api/editor/findings.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditorFindings {
+    pub orchestration: Option<EditorOrchestration>,
+    pub relative_path: String,
+    pub findings: Vec<EditorFinding>,
+}
+
+/// A review finding anchored to a line range (1-based, inclusive).
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EditorFinding {
+    pub id: String,
+    pub start_line: i64,
+    pub end_line: i64,
+    pub severity: String,
+    pub status: String,
+    pub summary: String,
+    pub body: String,
+    pub source: String,
+    pub author: String,
+    pub commit_sha: String,
+}
+
+impl From<ReviewThreadRecord> for EditorFinding {
+    fn from(thread: ReviewThreadRecord) -> Self {
+        // Findings are recorded against a single line today.
+        let line = thread.line.max(1);
+        Self {
+            id: thread.id,
+            start_line: line,
+            end_line: line,
+            severity: thread.severity,
+            status: thread.status,
+            summary: thread.summary,
+            body: thread.body,
+            source: thread.source,
+            author: thread.author,
+            commit_sha: thread.commit_sha,
+        }
+    }
+}
+
+/// Repo location resolved from an editor path.
+struct Workspace {
+    repo_root: PathBuf,
+    branch: Option<String>,
+    relative_path: Option<String>,
+}
+
+/// Resolve an editor path off the async runtime: canonicalizing and the git
+/// lookups block.
+async fn resolve_workspace(raw: &str) -> Result<Workspace, ApiError> {
+    let raw = raw.to_string();
+    tokio::task::spawn_blocking(move || workspace_at(&raw))
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+}
+
+fn workspace_at(raw: &str) -> Result<Workspace, ApiError> {
+    let path = Path::new(raw);
+    if !path.is_absolute() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("path must be absolute: {}", raw),
+        ));
+    }
+    let path = std::fs::canonicalize(path)
+        .map_err(|_| (StatusCode::BAD_REQUEST, format!("path not found: {}", raw)))?;
+
+    let repo_root = git::resolve_toplevel(&path)
+        .and_then(|root| Ok(std::fs::canonicalize(root)?))
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                format!("not a git worktree: {}", e),
+            )
+        })?;
+    let branch = git::get_current_branch(&repo_root)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let relative_path = path
+        .is_file()
+        .then(|| path.strip_prefix(&repo_root).ok())
+        .flatten()
+        .map(|rel| rel.to_string_lossy().into_owned());
+
+    Ok(Workspace {
+        repo_root,
+        branch,
+        relative_path,
+    })
+}
+
+fn is_complete(entry: &OrchestrationListEntry) -> bool {
+    entry.record.status.eq_ignore_ascii_case("complete")
+}
+
+/// Pick the orchestration for a workspace.
+///
+/// An orchestration whose worktree is `repo_root` wins. Otherwise the most
+/// recently started active orchestration on `branch` is used, which covers
+/// editors opened on the main checkout rather than the tina worktree.
+pub fn match_orchestration<'a>(
+    entries: &'a [OrchestrationListEntry],
+    repo_root: &Path,
+    branch: Option<&str>,
+) -> Option<&'a OrchestrationListEntry> {
+    let by_worktree = entries.iter().find(|entry| {
+        entry.record.worktree_path.as_deref().is_some_and(|wt| {
+            let wt = Path::new(wt);
+            wt == repo_root || std::fs::canonicalize(wt).is_ok_and(|c| c == repo_root)
+        })
+    });
+    if by_worktree.is_some() {
+        return by_worktree;
+    }
+
+    let branch = branch?;
+    entries
+        .iter()
+        .filter(|entry| entry.record.branch == branch && !is_complete(entry))
+        .max_by(|a, b| a.record.started_at.cmp(&b.record.started_at))
+}
+
+/// Review findings for one file, ordered by line.
+pub fn findings_for_file(
+    threads: Vec<ReviewThreadRecord>,
+    relative_path: &str,
+) -> Vec<EditorFinding> {
+    let wanted = relative_path.trim_start_matches("./");
+    let mut findings: Vec<EditorFinding> = threads
+        .into_iter()
+        .filter(|thread| thread.file_path.trim_start_matches("./") == wanted)
+        .map(EditorFinding::from)
+        .collect();
+    findings.sort_by(|a, b| a.start_line.cmp(&b.start_line).then(a.id.cmp(&b.id)));
+    findings
+}
+
+async fn lookup_orchestration(
+    client: &Arc<Mutex<TinaConvexClient>>,
+    workspace: &Workspace,
+) -> Result<Option<EditorOrchestration>, ApiError> {
+    let entries = client
+        .lock()
+        .await
+        .list_orchestrations()
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+    // Matching canonicalizes every worktree path
+    let repo_root = workspace.repo_root.clone();
+    let branch = workspace.branch.clone();
+    tokio::task::spawn_blocking(move || {
+        match_orchestration(&entries, &repo_root, branch.as_deref()).map(EditorOrchestration::from)
+    })
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// GET /api/editor/context handler.
+pub async fn get_context(
+    State(state): State<AppState>,
+    Query(params): Query<EditorPathParams>,
+) -> Result<Json<EditorContext>, ApiError> {
+    let client = state.convex()?;
+    let workspace = resolve_workspace(&params.path).await?;
+    let orchestration = lookup_orchestration(&client, &workspace).await?;
+
+    Ok(Json(EditorContext {
+        repo_root: workspace.repo_root.display().to_string(),
+        branch: workspace.branch,
+        relative_path: workspace.relative_path,
+        orchestration,
+    }))
+}
+
+/// GET /api/editor/tasks handler.
+pub async fn get_tasks(
+    State(state): State<AppState>,
+    Query(params): Query<EditorPathParams>,
+) -> Result<Json<EditorTasks>, ApiError> {
+    let client = state.convex()?;
+    let workspace = resolve_workspace(&params.path).await?;
+    let Some(orchestration) = lookup_orchestration(&client, &workspace).await? else {
+        return Ok(Json(EditorTasks {
+            orchestration: None,
+            tasks: vec![],
+        }));
+    };
+
+    let detail = client
+        .lock()
+        .await
+        .get_orchestration_detail(&orchestration.id)
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+    let tasks = detail
+        .map(|d| d.tasks.into_iter().map(EditorTask::from).collect())
+        .unwrap_or_default();
+
+    Ok(Json(EditorTasks {
+        orchestration: Some(orchestration),
+        tasks,
+    }))
+}
+
+/// GET /api/editor/findings handler. `path` must be a file.
+pub async fn get_findings(
+    State(state): State<AppState>,
+    Query(params): Query<EditorPathParams>,
+) -> Result<Json<EditorFindings>, ApiError> {
+    let client = state.convex()?;
+    let workspace = resolve_workspace(&params.path).await?;
+    let relative_path = workspace.relative_path.clone().ok_or((
+        StatusCode::BAD_REQUEST,
+        format!("path is not a file in the worktree: {}", params.path),
+    ))?;
+
+    let orchestration = lookup_orchestration(&client, &workspace).await?;
+    let findings = match &orchestration {
+        Some(orch) => {
+            let threads = client
+                .lock()
+                .await
+                .list_review_threads(&orch.id)
+                .await
+                .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+            findings_for_file(threads, &relative_path)
+        }
+        None => vec![],
+    };
+
+    Ok(Json(EditorFindings {
+        orchestration,
+        relative_path,
+        findings,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tina_data::test_util::list_entry;
+
+    fn entry(
+        id: &str,
+        branch: &str,
+        worktree: Option<&str>,
+        started_at: &str,
+    ) -> OrchestrationListEntry {
+        let mut entry = list_entry(id, "executing", started_at);
+        entry.record.feature_name = id.to_string();
+        entry.record.branch = branch.to_string();
+        entry.record.worktree_path = worktree.map(String::from);
+        entry
+    }
+
+    fn thread(id: &str, file: &str, line: i64) -> ReviewThreadRecord {
+        ReviewThreadRecord {
+            id: id.to_string(),
+            review_id: "review-1".to_string(),
+            orchestration_id: "orch-1".to_string(),
+            file_path: file.to_string(),
+            line,
+            commit_sha: "abc123".to_string(),
+            summary: "summary".to_string(),
+            body: "body".to_string(),
+            severity: "p1".to_string(),
+            status: "unresolved".to_string(),
+            source: "agent".to_string(),
+            author: "reviewer".to_string(),
+            gate_impact: "review".to_string(),
+            created_at: "2026-02-10T10:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_match_orchestration_prefers_worktree_path() {
+        let entries = vec![
+            entry("by-branch", "tina/auth", None, "2026-02-11T00:00:00Z"),
+            entry(
+                "by-worktree",
+                "tina/other",
+                Some("/repo/.worktrees/auth"),
+                "2026-02-10T00:00:00Z",
+            ),
+        ];
+        let found = match_orchestration(
+            &entries,
+            Path::new("/repo/.worktrees/auth"),
+            Some("tina/auth"),
+        );
+        assert_eq!(found.map(|e| e.id.as_str()), Some("by-worktree"));
+    }
+
+    #[test]
+    fn test_match_orchestration_falls_back_to_latest_on_branch() {
+        let mut done = entry("done", "tina/auth", None, "2026-02-12T00:00:00Z");
+        done.record.status = "complete".to_string();
+        let entries = vec![
+            entry("older", "tina/auth", None, "2026-02-10T00:00:00Z"),
+            entry("newer", "tina/auth", None, "2026-02-11T00:00:00Z"),
+            done,
+        ];
+        let found = match_orchestration(&entries, Path::new("/repo"), Some("tina/auth"));
+        assert_eq!(found.map(|e| e.id.as_str()), Some("newer"));
+        assert!(match_orchestration(&entries, Path::new("/repo"), None).is_none());
+    }
+
+    #[test]
+    fn test_findings_for_file_filters_and_orders_by_line() {
+        let findings = findings_for_file(
+            vec![
+                thread("b", "src/sync.rs", 40),
+                thread("a", "./src/sync.rs", 12),
+                thread("c", "src/main.rs", 1),
+            ],
+            "src/sync.rs",
+        );
+        let ids: Vec<_> = findings.iter().map(|f| f.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+        assert_eq!((findings[0].start_line, findings[0].end_line), (12, 12));
+    }
+}
@@ -0,0 +1,118 @@
+//! Paginated orchestration event listing.
+//!
+//! GET /api/orchestrations/{id}/events?limit=&cursor=&event_type=&since=&until=&sort=
+//! returns one page of the events recorded for an orchestration, oldest
+//! first (`sort=newest` reverses it), with an opaque `nextCursor` for the
+//! following page. `since` and `until` are exclusive RFC 3339 bounds. The
+//! cursor is keyset based (see `listEventsPage` in `convex/events.ts`), so a
+//! poller can hold on to the last cursor it saw and fetch only what is new.
+//! For a live feed of what this daemon sees, use `/events/stream`.
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+
+use tina_data::{EventFilter, OrchestrationEventRecord};
+
+use crate::http::AppState;
+use crate::orchestrations::{utc_time, SortOrder};
+
+type ApiError = (StatusCode, String);
+
+pub const DEFAULT_EVENT_PAGE_SIZE: usize = 100;
+pub const MAX_EVENT_PAGE_SIZE: usize = 1000;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct EventPageParams {
+    pub limit: Option<usize>,
+    pub cursor: Option<String>,
+    pub event_type: Option<String>,
+    pub since: Option<String>,
+    pub until: Option<String>,
+    pub sort: Option<SortOrder>,
+}
+
+impl EventPageParams {
+    /// Requested page size, clamped to `1..=MAX_EVENT_PAGE_SIZE`.
+    pub fn page_size(&self) -> usize {
+        self.limit
+            .unwrap_or(DEFAULT_EVENT_PAGE_SIZE)
+            .clamp(1, MAX_EVENT_PAGE_SIZE)
+    }
+
+    /// The Convex filter these params ask for.
+    pub fn filter(&self) -> Result<EventFilter, ApiError> {
+        Ok(EventFilter {
+            event_type: self.event_type.clone(),
+            since: utc_time("since", self.since.as_ref())?,
+            until: utc_time("until", self.until.as_ref())?,
+            newest_first: self.sort == Some(SortOrder::Newest),
+        })
+    }
+}
+
+/// Response body for GET /api/orchestrations/{id}/events.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventPageResponse {
+    pub items: Vec<OrchestrationEventRecord>,
+    pub next_cursor: Option<String>,
+}
+
+/// GET /api/orchestrations/{id}/events handler.
+pub async fn get_events(
+    State(state): State<AppState>,
+    Path(orchestration_id): Path<String>,
+    Query(params): Query<EventPageParams>,
+) -> Result<Json<EventPageResponse>, ApiError> {
+    let client = state.convex()?;
+    let filter = params.filter()?;
+
+    let page = client
+        .lock()
+        .await
+        .list_events_page(
+            &orchestration_id,
+            params.page_size(),
+            params.cursor.as_deref(),
+            &filter,
+        )
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+    Ok(Json(EventPageResponse {
+        items: page.entries,
+        next_cursor: page.next_cursor,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_params_default_to_oldest_first() {
+        let params = EventPageParams::default();
+        assert_eq!(params.page_size(), DEFAULT_EVENT_PAGE_SIZE);
+        assert_eq!(params.filter().unwrap(), EventFilter::default());
+
+        let params: EventPageParams = serde_json::from_value(serde_json::json!({
+            "limit": 5000,
+            "event_type": "phase_started",
+            "since": "2026-02-10T10:00:00-05:00",
+            "sort": "newest",
+        }))
+        .unwrap();
+        assert_eq!(params.page_size(), MAX_EVENT_PAGE_SIZE);
+        let filter = params.filter().unwrap();
+        assert_eq!(filter.event_type.as_deref(), Some("phase_started"));
+        assert_eq!(filter.since.as_deref(), Some("2026-02-10T15:00:00+00:00"));
+        assert!(filter.newest_first);
+
+        let params = EventPageParams {
+            until: Some("soon".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(params.filter().unwrap_err().0, StatusCode::BAD_REQUEST);
+    }
+}
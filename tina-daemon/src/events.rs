@@ -0,0 +1,262 @@
+//! In-process event bus.
+//!
+//! The main loop turns watcher, git hook, reconciliation, and inbound action
+//! activity into typed [`DaemonEvent`]s and publishes them here. Subsystems
+//! (sync today; notifications, webhooks, metrics, retention as they land)
+//! implement [`Subscriber`] and run on their own task, so adding a new
+//! reaction to orchestration activity doesn't mean touching the main loop.
+
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+
+use crate::reconcile::ReconcileResult;
+use crate::watcher::WorktreeInfo;
+
+/// Events buffered per subscriber before the slowest one starts lagging.
+pub const DEFAULT_CAPACITY: usize = 1024;
+
+/// What moved a worktree's branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitTrigger {
+    /// The branch ref watcher fired.
+    RefWatcher,
+    /// A git hook posted to the daemon.
+    GitHook,
+}
+
+/// Something that happened to an orchestration the daemon tracks.
+#[derive(Debug, Clone)]
+pub enum DaemonEvent {
+    /// Team or task files changed and active worktrees were re-discovered.
+    ProjectionChanged,
+    /// A worktree's branch has new commits.
+    CommitsChanged {
+        worktree: WorktreeInfo,
+        trigger: CommitTrigger,
+    },
+    /// A plan file in a worktree changed.
+    PlanChanged {
+        worktree: WorktreeInfo,
+        plan_path: PathBuf,
+    },
+    /// A design file in a worktree changed.
+    DesignChanged {
+        worktree: WorktreeInfo,
+        path: PathBuf,
+    },
+    /// A reconciliation pass finished.
+    Reconciled(ReconcileResult),
+    /// An inbound (or scheduled) action was dispatched.
+    ActionFinished {
+        action_id: String,
+        action_type: String,
+        error: Option<String>,
+    },
+}
+
+/// Broadcasts [`DaemonEvent`]s to every subscriber.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    tx: broadcast::Sender<Arc<DaemonEvent>>,
+}
+
+impl EventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (tx, _) = broadcast::channel(capacity.max(1));
+        Self { tx }
+    }
+
+    /// Publish an event. Returns how many subscribers will see it.
+    pub fn publish(&self, event: DaemonEvent) -> usize {
+        match self.tx.send(Arc::new(event)) {
+            Ok(receivers) => receivers,
+            Err(broadcast::error::SendError(event)) => {
+                debug!(event = ?event, "event published with no subscribers");
+                0
+            }
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<DaemonEvent>> {
+        self.tx.subscribe()
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.tx.receiver_count()
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+/// A subsystem that reacts to daemon events.
+///
+/// Events are delivered one at a time, in publish order. A handler that
+/// does slow work should hand it off (e.g. to the sync pool) so it doesn't
+/// fall behind.
+pub trait Subscriber: Send + 'static {
+    /// Name used in logs.
+    fn name(&self) -> &'static str;
+
+    fn handle(&mut self, event: &DaemonEvent) -> impl Future<Output = ()> + Send;
+
+    /// Called when the subscriber fell behind and `missed` events were
+    /// dropped. Subscribers that must not miss anything should resync here.
+    fn lagged(&mut self, missed: u64) -> impl Future<Output = ()> + Send {
+        let _ = missed;
+        async {}
+    }
+}
+
+/// Run `subscriber` on its own task until `cancel` fires or the bus is
+/// dropped.
+///
+/// The subscription is taken before returning, so events published after
+/// this call are never missed.
+pub fn spawn_subscriber<S: Subscriber>(
+    bus: &EventBus,
+    mut subscriber: S,
+    cancel: CancellationToken,
+) -> JoinHandle<()> {
+    let mut rx = bus.subscribe();
+    tokio::spawn(async move {
+        loop {
+            let received = tokio::select! {
+                _ = cancel.cancelled() => break,
+                received = rx.recv() => received,
+            };
+            match received {
+                Ok(event) => subscriber.handle(&event).await,
+                Err(broadcast::error::RecvError::Lagged(missed)) => {
+                    warn!(
+                        subscriber = subscriber.name(),
+                        missed, "event subscriber lagged, resyncing"
+                    );
+                    subscriber.lagged(missed).await;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+        debug!(subscriber = subscriber.name(), "event subscriber stopped");
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::{mpsc, Mutex};
+
+    struct Recorder {
+        seen: mpsc::UnboundedSender<String>,
+    }
+
+    impl Subscriber for Recorder {
+        fn name(&self) -> &'static str {
+            "recorder"
+        }
+
+        async fn handle(&mut self, event: &DaemonEvent) {
+            let label = match event {
+                DaemonEvent::ProjectionChanged => "projection".to_string(),
+                DaemonEvent::ActionFinished { action_id, .. } => format!("action:{}", action_id),
+                other => format!("{:?}", other),
+            };
+            let _ = self.seen.send(label);
+        }
+
+        async fn lagged(&mut self, missed: u64) {
+            let _ = self.seen.send(format!("lagged:{}", missed));
+        }
+    }
+
+    fn action(id: &str) -> DaemonEvent {
+        DaemonEvent::ActionFinished {
+            action_id: id.to_string(),
+            action_type: "stop".to_string(),
+            error: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_every_subscriber_sees_events_in_order() {
+        let bus = EventBus::default();
+        let cancel = CancellationToken::new();
+        let (tx_a, mut rx_a) = mpsc::unbounded_channel();
+        let (tx_b, mut rx_b) = mpsc::unbounded_channel();
+        spawn_subscriber(&bus, Recorder { seen: tx_a }, cancel.clone());
+        spawn_subscriber(&bus, Recorder { seen: tx_b }, cancel.clone());
+        assert_eq!(bus.subscriber_count(), 2);
+
+        assert_eq!(bus.publish(DaemonEvent::ProjectionChanged), 2);
+        bus.publish(action("a1"));
+
+        for rx in [&mut rx_a, &mut rx_b] {
+            assert_eq!(rx.recv().await.unwrap(), "projection");
+            assert_eq!(rx.recv().await.unwrap(), "action:a1");
+        }
+        cancel.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_publish_without_subscribers_is_dropped() {
+        let bus = EventBus::new(4);
+        assert_eq!(bus.publish(DaemonEvent::ProjectionChanged), 0);
+    }
+
+    #[tokio::test]
+    async fn test_lagging_subscriber_is_told_how_many_it_missed() {
+        let bus = EventBus::new(2);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        // Hold the subscriber back until the buffer has overflowed
+        let gate = Arc::new(Mutex::new(()));
+        let guard = gate.clone().lock_owned().await;
+
+        struct Gated {
+            gate: Arc<Mutex<()>>,
+            inner: Recorder,
+        }
+        impl Subscriber for Gated {
+            fn name(&self) -> &'static str {
+                "gated"
+            }
+            async fn handle(&mut self, event: &DaemonEvent) {
+                let _open = self.gate.lock().await;
+                self.inner.handle(event).await;
+            }
+            async fn lagged(&mut self, missed: u64) {
+                self.inner.lagged(missed).await;
+            }
+        }
+
+        let cancel = CancellationToken::new();
+        spawn_subscriber(
+            &bus,
+            Gated {
+                gate: gate.clone(),
+                inner: Recorder { seen: tx },
+            },
+            cancel.clone(),
+        );
+        bus.publish(action("first"));
+        tokio::task::yield_now().await;
+        for id in ["2", "3", "4", "5"] {
+            bus.publish(action(id));
+        }
+        drop(guard);
+
+        assert_eq!(rx.recv().await.unwrap(), "action:first");
+        assert_eq!(rx.recv().await.unwrap(), "lagged:2");
+        assert_eq!(rx.recv().await.unwrap(), "action:4");
+        assert_eq!(rx.recv().await.unwrap(), "action:5");
+        cancel.cancel();
+    }
+}
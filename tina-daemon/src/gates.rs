@@ -0,0 +1,268 @@
+//! Review gate decisions.
+//!
+//! POST /api/orchestrations/{id}/gates/{gate}/approve
+//! POST /api/orchestrations/{id}/gates/{gate}/block
+//!
+//! The same decisions as `tina-session review gate approve/block`, so HITL
+//! gates can be cleared from the browser. Each distinct approver counts
+//! once toward the gate's quorum, read from the project config in the
+//! orchestration's worktree when this machine has it; a block discards the
+//! approvals collected so far. A request that authenticated with a token
+//! decides as `token:<id>`; only anonymous requests are taken at their
+//! `decidedBy`, so they can't approve a gate that needs more than one.
+
+use std::path::Path as FsPath;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use serde::{Deserialize, Serialize};
+
+use tina_session::project_config;
+
+use crate::auth::{self, TokenIdentity};
+use crate::http::AppState;
+
+type ApiError = (StatusCode, String);
+
+/// Gates an orchestration passes through.
+pub const GATES: [&str; 3] = ["plan", "review", "finalize"];
+
+fn default_decided_by() -> String {
+    "human".to_string()
+}
+
+fn default_summary() -> String {
+    "Approved".to_string()
+}
+
+/// Request body for POST .../gates/{gate}/approve.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ApproveRequest {
+    #[serde(default = "default_decided_by")]
+    pub decided_by: String,
+    #[serde(default = "default_summary")]
+    pub summary: String,
+}
+
+/// Request body for POST .../gates/{gate}/block.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockRequest {
+    #[serde(default = "default_decided_by")]
+    pub decided_by: String,
+    pub reason: String,
+}
+
+/// Response body for the gate endpoints.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GateDecisionResponse {
+    pub gate_id: String,
+    pub gate: String,
+    /// `pending` until enough approvals are in, then `approved`; or `blocked`
+    pub status: String,
+    pub approvals: usize,
+    pub required_approvals: u32,
+}
+
+fn check_gate(gate: &str) -> Result<(), ApiError> {
+    if GATES.contains(&gate) {
+        Ok(())
+    } else {
+        Err((
+            StatusCode::BAD_REQUEST,
+            format!("Unknown gate: {} (expected {})", gate, GATES.join(", ")),
+        ))
+    }
+}
+
+/// Who a decision is recorded for: the token that made it, or the
+/// `decidedBy` an anonymous caller gave.
+fn approver(identity: Option<&TokenIdentity>, decided_by: &str) -> String {
+    match identity {
+        Some(identity) => auth::actor(Some(identity), None),
+        None => decided_by.to_string(),
+    }
+}
+
+/// Anonymous callers can name themselves anything, so they can't count
+/// toward a quorum of more than one.
+fn check_anonymous_approval(
+    identity: Option<&TokenIdentity>,
+    decision: &str,
+    gate: &str,
+    required: u32,
+) -> Result<(), ApiError> {
+    if identity.is_none() && decision == "approved" && required > 1 {
+        return Err((
+            StatusCode::FORBIDDEN,
+            format!(
+                "The {} gate needs {} approvals; approve with a token so each approver counts once",
+                gate, required
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Record a `decision` (`approved` or `blocked`) on `gate` for the caller.
+/// Shared with the WebSocket `approve_gate` command.
+pub async fn decide(
+    state: AppState,
+    orchestration_id: &str,
+    gate: &str,
+    identity: Option<&TokenIdentity>,
+    decided_by: &str,
+    decision: &str,
+    summary: &str,
+) -> Result<Json<GateDecisionResponse>, ApiError> {
+    check_gate(gate)?;
+    if decided_by.trim().is_empty() || summary.trim().is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "decidedBy and the summary or reason must not be empty".to_string(),
+        ));
+    }
+    let client = state.convex()?;
+
+    let mut client = client.lock().await;
+    let orchestration = client
+        .get_orchestration_detail(orchestration_id)
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("Orchestration not found: {}", orchestration_id),
+        ))?;
+
+    // Keep the gate's current quorum when the worktree isn't on this machine
+    let required = match orchestration
+        .record
+        .worktree_path
+        .as_deref()
+        .map(FsPath::new)
+    {
+        Some(root) if root.is_dir() => Some(
+            project_config::required_approvals(root, gate)
+                .map_err(|e| (StatusCode::UNPROCESSABLE_ENTITY, e.to_string()))?,
+        ),
+        _ => None,
+    };
+    if identity.is_none() && decision == "approved" {
+        let required = match required {
+            Some(required) => required,
+            None => client
+                .list_review_gates(orchestration_id)
+                .await
+                .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?
+                .into_iter()
+                .find(|record| record.gate_id == gate)
+                .and_then(|record| record.required_approvals)
+                .unwrap_or(1),
+        };
+        check_anonymous_approval(identity, decision, gate, required)?;
+    }
+
+    let record = client
+        .record_gate_decision(
+            orchestration_id,
+            gate,
+            &approver(identity, decided_by),
+            decision,
+            summary,
+            required,
+        )
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+    Ok(Json(GateDecisionResponse {
+        approvals: record.approvals(),
+        required_approvals: record.required_approvals.unwrap_or(1),
+        gate_id: record.id,
+        gate: gate.to_string(),
+        status: record.status,
+    }))
+}
+
+/// POST /api/orchestrations/{id}/gates/{gate}/approve handler.
+pub async fn approve_gate(
+    State(state): State<AppState>,
+    Path((orchestration_id, gate)): Path<(String, String)>,
+    identity: Option<Extension<TokenIdentity>>,
+    Json(request): Json<ApproveRequest>,
+) -> Result<Json<GateDecisionResponse>, ApiError> {
+    decide(
+        state,
+        &orchestration_id,
+        &gate,
+        identity.as_ref().map(|Extension(identity)| identity),
+        &request.decided_by,
+        "approved",
+        &request.summary,
+    )
+    .await
+}
+
+/// POST /api/orchestrations/{id}/gates/{gate}/block handler.
+pub async fn block_gate(
+    State(state): State<AppState>,
+    Path((orchestration_id, gate)): Path<(String, String)>,
+    identity: Option<Extension<TokenIdentity>>,
+    Json(request): Json<BlockRequest>,
+) -> Result<Json<GateDecisionResponse>, ApiError> {
+    decide(
+        state,
+        &orchestration_id,
+        &gate,
+        identity.as_ref().map(|Extension(identity)| identity),
+        &request.decided_by,
+        "blocked",
+        &request.reason,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tina_session::tokens::TokenScope;
+
+    #[test]
+    fn test_requests_default_like_the_cli() {
+        let approve: ApproveRequest = serde_json::from_str("{}").unwrap();
+        assert_eq!(approve.decided_by, "human");
+        assert_eq!(approve.summary, "Approved");
+
+        let block: BlockRequest =
+            serde_json::from_str(r#"{"decidedBy": "alice", "reason": "tests fail"}"#).unwrap();
+        assert_eq!(block.decided_by, "alice");
+        assert!(serde_json::from_str::<BlockRequest>("{}").is_err());
+
+        assert!(check_gate("review").is_ok());
+        assert_eq!(check_gate("merge").unwrap_err().0, StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_tokens_decide_as_themselves() {
+        let identity = TokenIdentity {
+            id: "ci".to_string(),
+            scope: TokenScope::Operate,
+        };
+        assert_eq!(approver(Some(&identity), "alice"), "token:ci");
+        assert_eq!(approver(None, "alice"), "alice");
+    }
+
+    #[test]
+    fn test_anonymous_callers_cannot_fill_a_quorum() {
+        let identity = TokenIdentity {
+            id: "ci".to_string(),
+            scope: TokenScope::Operate,
+        };
+        let refused = check_anonymous_approval(None, "approved", "review", 2).unwrap_err();
+        assert_eq!(refused.0, StatusCode::FORBIDDEN);
+        assert!(check_anonymous_approval(None, "approved", "review", 1).is_ok());
+        assert!(check_anonymous_approval(None, "blocked", "review", 2).is_ok());
+        assert!(check_anonymous_approval(Some(&identity), "approved", "review", 2).is_ok());
+    }
+}
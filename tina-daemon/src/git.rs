@@ -17,11 +17,14 @@ pub struct GitCommit {
 /// Get new commits in a git repository since the given SHA.
 ///
 /// If `since_sha` is None, returns the last 10 commits.
+/// When `scope` is set, only commits touching that subtree are returned and
+/// their stats only count files under it.
 /// Returns commits in reverse chronological order (newest first).
 pub fn get_new_commits(
     repo_path: &Path,
     _branch: &str,
     since_sha: Option<&str>,
+    scope: Option<&Path>,
 ) -> Result<Vec<GitCommit>> {
     // Run:
     // - Incremental: git log <since_sha>..HEAD --numstat --format=...
@@ -29,32 +32,20 @@ pub fn get_new_commits(
     //
     // Using HEAD~10..HEAD fails on short histories; "-n 10 HEAD" works even
     // when fewer than 10 commits exist.
-    let output = if let Some(sha) = since_sha {
-        let range = format!("{}..HEAD", sha);
-        Command::new("git")
-            .current_dir(repo_path)
-            .args([
-                "log",
-                &range,
-                "--numstat",
-                "--format=%H|%h|%s|%an <%ae>|%aI",
-            ])
-            .output()
-            .context("Failed to run git log")?
-    } else {
-        Command::new("git")
-            .current_dir(repo_path)
-            .args([
-                "log",
-                "-n",
-                "10",
-                "HEAD",
-                "--numstat",
-                "--format=%H|%h|%s|%an <%ae>|%aI",
-            ])
-            .output()
-            .context("Failed to run git log")?
-    };
+    let mut args: Vec<String> = vec!["log".to_string()];
+    match since_sha {
+        Some(sha) => args.push(format!("{}..HEAD", sha)),
+        None => args.extend(["-n", "10", "HEAD"].map(String::from)),
+    }
+    args.push("--numstat".to_string());
+    args.push("--format=%H|%h|%s|%an <%ae>|%aI".to_string());
+    push_scope_pathspec(&mut args, scope);
+
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(&args)
+        .output()
+        .context("Failed to run git log")?;
 
     if !output.status.success() {
         anyhow::bail!(
@@ -67,6 +58,14 @@ pub fn get_new_commits(
     parse_git_log_output(&stdout)
 }
 
+/// Limit a git command to a monorepo subtree by appending `-- <scope>`.
+fn push_scope_pathspec(args: &mut Vec<String>, scope: Option<&Path>) {
+    if let Some(scope) = scope {
+        args.push("--".to_string());
+        args.push(scope.to_string_lossy().into_owned());
+    }
+}
+
 /// Resolve current HEAD SHA for the repository.
 pub fn get_head_sha(repo_path: &Path) -> Result<String> {
     let output = Command::new("git")
@@ -89,6 +88,54 @@ pub fn get_head_sha(repo_path: &Path) -> Result<String> {
     Ok(sha)
 }
 
+/// Resolve the top-level directory of the worktree containing `path`.
+///
+/// `path` may be a file; git is run from its parent directory.
+pub fn resolve_toplevel(path: &Path) -> Result<PathBuf> {
+    let dir = if path.is_file() {
+        path.parent().unwrap_or(path)
+    } else {
+        path
+    };
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(["rev-parse", "--show-toplevel"])
+        .output()
+        .context("Failed to run git rev-parse --show-toplevel")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "git rev-parse --show-toplevel failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let toplevel = String::from_utf8(output.stdout)?.trim().to_string();
+    if toplevel.is_empty() {
+        anyhow::bail!("git rev-parse returned empty toplevel");
+    }
+    Ok(PathBuf::from(toplevel))
+}
+
+/// Current branch name, or `None` when HEAD is detached.
+pub fn get_current_branch(repo_path: &Path) -> Result<Option<String>> {
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .output()
+        .context("Failed to run git rev-parse --abbrev-ref HEAD")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "git rev-parse --abbrev-ref HEAD failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let branch = String::from_utf8(output.stdout)?.trim().to_string();
+    Ok((!branch.is_empty() && branch != "HEAD").then_some(branch))
+}
+
 /// Resolve a repository's git directory, handling linked worktrees.
 pub fn resolve_git_dir(repo_path: &Path) -> Result<PathBuf> {
     let output = Command::new("git")
@@ -151,7 +198,8 @@ pub struct CommitDetailLookup {
     pub missing_shas: Vec<String>,
 }
 
-fn is_valid_sha(sha: &str) -> bool {
+/// Whether `sha` looks like a full or abbreviated commit SHA.
+pub fn is_valid_sha(sha: &str) -> bool {
     let len = sha.len();
     (4..=40).contains(&len) && sha.chars().all(|c| c.is_ascii_hexdigit())
 }
@@ -354,21 +402,40 @@ pub struct DiffLine {
 }
 
 /// Get the list of changed files between `base` and HEAD with stats.
+pub fn get_diff_file_list(
+    repo_path: &Path,
+    base: &str,
+    scope: Option<&Path>,
+) -> Result<Vec<DiffFileStat>> {
+    get_range_file_list(repo_path, &format!("{}...HEAD", base), scope)
+}
+
+/// Get the list of files changed in a revision range with stats.
 ///
 /// Runs `git diff --name-status` and `git diff --numstat` with
 /// `--diff-filter=ACDMR --find-renames` and joins the results.
-pub fn get_diff_file_list(repo_path: &Path, base: &str) -> Result<Vec<DiffFileStat>> {
-    let range = format!("{}...HEAD", base);
-
-    let name_status_output = Command::new("git")
-        .current_dir(repo_path)
-        .args([
+pub fn get_range_file_list(
+    repo_path: &Path,
+    range: &str,
+    scope: Option<&Path>,
+) -> Result<Vec<DiffFileStat>> {
+    let diff_args = |format: &str| {
+        let mut args: Vec<String> = [
             "diff",
-            "--name-status",
+            format,
             "--diff-filter=ACDMR",
             "--find-renames",
-            &range,
-        ])
+            range,
+        ]
+        .map(String::from)
+        .to_vec();
+        push_scope_pathspec(&mut args, scope);
+        args
+    };
+
+    let name_status_output = Command::new("git")
+        .current_dir(repo_path)
+        .args(diff_args("--name-status"))
         .output()
         .context("Failed to run git diff --name-status")?;
 
@@ -381,13 +448,7 @@ pub fn get_diff_file_list(repo_path: &Path, base: &str) -> Result<Vec<DiffFileSt
 
     let numstat_output = Command::new("git")
         .current_dir(repo_path)
-        .args([
-            "diff",
-            "--numstat",
-            "--diff-filter=ACDMR",
-            "--find-renames",
-            &range,
-        ])
+        .args(diff_args("--numstat"))
         .output()
         .context("Failed to run git diff --numstat")?;
 
@@ -664,6 +725,25 @@ mod tests {
         tmp
     }
 
+    #[test]
+    fn test_resolve_toplevel_and_branch_from_file() {
+        let repo = setup_commit_repo();
+        let file = repo.path().join("a.txt");
+
+        let toplevel = resolve_toplevel(&file).unwrap();
+        assert_eq!(
+            fs::canonicalize(toplevel).unwrap(),
+            fs::canonicalize(repo.path()).unwrap()
+        );
+        assert_eq!(
+            get_current_branch(repo.path()).unwrap().as_deref(),
+            Some("main")
+        );
+
+        run_git(repo.path(), &["checkout", "--detach", "HEAD"]);
+        assert_eq!(get_current_branch(repo.path()).unwrap(), None);
+    }
+
     #[test]
     fn test_parse_git_log_output() {
         let output = r#"abc123|abc1234|feat: add feature|John Doe <john@example.com>|2026-02-10T10:00:00Z
@@ -773,6 +853,32 @@ def456|def4567|fix: bug fix|Jane Smith <jane@example.com>|2026-02-10T11:00:00Z
         assert_eq!(head_from_helper, head_from_git);
     }
 
+    #[test]
+    fn test_get_new_commits_and_diff_respect_scope() {
+        let tmp = setup_commit_repo();
+        let dir = tmp.path();
+        let base = run_git(dir, &["rev-parse", "HEAD"]);
+
+        fs::create_dir_all(dir.join("svc")).unwrap();
+        fs::write(dir.join("svc/lib.rs"), "fn a() {}\n").unwrap();
+        run_git(dir, &["add", "svc/lib.rs"]);
+        run_git(dir, &["commit", "-m", "scoped change"]);
+        fs::write(dir.join("a.txt"), "a\nb\nc\n").unwrap();
+        run_git(dir, &["add", "a.txt"]);
+        run_git(dir, &["commit", "-m", "outside change"]);
+
+        let all = get_new_commits(dir, "main", Some(&base), None).unwrap();
+        assert_eq!(all.len(), 2);
+
+        let scoped = get_new_commits(dir, "main", Some(&base), Some(Path::new("svc"))).unwrap();
+        assert_eq!(scoped.len(), 1);
+        assert_eq!(scoped[0].subject, "scoped change");
+
+        let files = get_diff_file_list(dir, &base, Some(Path::new("svc"))).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, "svc/lib.rs");
+    }
+
     // -- Diff file list tests --
 
     #[test]
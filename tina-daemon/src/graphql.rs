@@ -0,0 +1,539 @@
+//! Optional GraphQL API.
+//!
+//! With `http_graphql = true` in the config file, POST /api/graphql answers
+//! read-only queries over projects, orchestrations, phases, tasks, events,
+//! and team members, so a page can load an orchestration and everything
+//! under it in one round trip instead of one REST call per resource.
+//! GET /api/graphql serves GraphiQL for exploring the schema.
+//!
+//! An orchestration's phases, tasks, and team members all come from one
+//! `getOrchestrationDetail` call, made the first time any of them is asked
+//! for and shared by the rest of the query.
+
+use std::sync::Arc;
+
+use async_graphql::http::GraphiQLSource;
+use async_graphql::{Context, EmptyMutation, EmptySubscription, Object, Result, Schema};
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::Html;
+use axum::Json;
+use tokio::sync::{Mutex, OnceCell};
+
+use tina_data::{
+    OrchestrationDetailResponse, OrchestrationEventRecord, OrchestrationFilter,
+    OrchestrationListEntry, OrchestrationRecord, PhaseRecord, ProjectRecord, TaskEventRecord,
+    TeamMemberRecord, TinaConvexClient,
+};
+
+use crate::http::AppState;
+use crate::orchestrations::{utc_time, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+type ApiError = (StatusCode, String);
+
+type Client = Arc<Mutex<TinaConvexClient>>;
+
+pub type TinaSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+/// Deepest selection accepted; enough for project → orchestration → phase
+/// → task with room to spare.
+pub const MAX_DEPTH: usize = 8;
+
+/// Most fields one query may select.
+pub const MAX_COMPLEXITY: usize = 1000;
+
+pub fn schema() -> TinaSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_depth(MAX_DEPTH)
+        .limit_complexity(MAX_COMPLEXITY)
+        .finish()
+}
+
+fn client<'a>(ctx: &Context<'a>) -> Result<&'a Client> {
+    ctx.data::<Client>()
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn projects(&self, ctx: &Context<'_>) -> Result<Vec<Project>> {
+        let projects = client(ctx)?.lock().await.list_projects().await?;
+        Ok(projects.into_iter().map(Project).collect())
+    }
+
+    async fn project(&self, ctx: &Context<'_>, id: String) -> Result<Option<Project>> {
+        let projects = client(ctx)?.lock().await.list_projects().await?;
+        Ok(projects.into_iter().find(|p| p.id == id).map(Project))
+    }
+
+    /// Unarchived orchestrations, newest first.
+    async fn orchestrations(
+        &self,
+        ctx: &Context<'_>,
+        status: Option<String>,
+        project_id: Option<String>,
+        #[graphql(default_with = "DEFAULT_PAGE_SIZE")] limit: usize,
+    ) -> Result<Vec<Orchestration>> {
+        list_orchestrations(client(ctx)?, status, project_id, limit).await
+    }
+
+    async fn orchestration(&self, ctx: &Context<'_>, id: String) -> Result<Option<Orchestration>> {
+        let detail = client(ctx)?
+            .lock()
+            .await
+            .get_orchestration_detail(&id)
+            .await?;
+        Ok(detail.map(Orchestration::from_detail))
+    }
+}
+
+async fn list_orchestrations(
+    client: &Client,
+    status: Option<String>,
+    project_id: Option<String>,
+    limit: usize,
+) -> Result<Vec<Orchestration>> {
+    let filter = OrchestrationFilter {
+        status,
+        project_id,
+        ..Default::default()
+    };
+    let page = client
+        .lock()
+        .await
+        .list_orchestrations_page(limit.clamp(1, MAX_PAGE_SIZE), None, &filter)
+        .await?;
+    Ok(page
+        .entries
+        .into_iter()
+        .map(Orchestration::from_entry)
+        .collect())
+}
+
+pub struct Project(ProjectRecord);
+
+#[Object]
+impl Project {
+    async fn id(&self) -> &str {
+        &self.0.id
+    }
+
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    async fn repo_path(&self) -> &str {
+        &self.0.repo_path
+    }
+
+    async fn created_at(&self) -> &str {
+        &self.0.created_at
+    }
+
+    async fn orchestrations(
+        &self,
+        ctx: &Context<'_>,
+        status: Option<String>,
+        #[graphql(default_with = "DEFAULT_PAGE_SIZE")] limit: usize,
+    ) -> Result<Vec<Orchestration>> {
+        list_orchestrations(client(ctx)?, status, Some(self.0.id.clone()), limit).await
+    }
+}
+
+pub struct Orchestration {
+    id: String,
+    node_name: String,
+    record: OrchestrationRecord,
+    detail: OnceCell<Arc<OrchestrationDetailResponse>>,
+}
+
+impl Orchestration {
+    fn from_entry(entry: OrchestrationListEntry) -> Self {
+        Self {
+            id: entry.id,
+            node_name: entry.node_name,
+            record: entry.record,
+            detail: OnceCell::new(),
+        }
+    }
+
+    fn from_detail(detail: OrchestrationDetailResponse) -> Self {
+        Self {
+            id: detail.id.clone(),
+            node_name: detail.node_name.clone(),
+            record: detail.record.clone(),
+            detail: OnceCell::new_with(Some(Arc::new(detail))),
+        }
+    }
+
+    async fn detail(&self, ctx: &Context<'_>) -> Result<&Arc<OrchestrationDetailResponse>> {
+        self.detail
+            .get_or_try_init(|| async {
+                let detail = client(ctx)?
+                    .lock()
+                    .await
+                    .get_orchestration_detail(&self.id)
+                    .await?
+                    .ok_or_else(|| format!("Orchestration not found: {}", self.id))?;
+                Ok(Arc::new(detail))
+            })
+            .await
+    }
+}
+
+#[Object]
+impl Orchestration {
+    async fn id(&self) -> &str {
+        &self.id
+    }
+
+    async fn node_name(&self) -> &str {
+        &self.node_name
+    }
+
+    async fn feature_name(&self) -> &str {
+        &self.record.feature_name
+    }
+
+    async fn status(&self) -> &str {
+        &self.record.status
+    }
+
+    async fn branch(&self) -> &str {
+        &self.record.branch
+    }
+
+    async fn worktree_path(&self) -> Option<&str> {
+        self.record.worktree_path.as_deref()
+    }
+
+    async fn spec_doc_path(&self) -> &str {
+        &self.record.spec_doc_path
+    }
+
+    async fn total_phases(&self) -> i32 {
+        self.record.total_phases as i32
+    }
+
+    async fn current_phase(&self) -> i32 {
+        self.record.current_phase as i32
+    }
+
+    async fn started_at(&self) -> &str {
+        &self.record.started_at
+    }
+
+    async fn completed_at(&self) -> Option<&str> {
+        self.record.completed_at.as_deref()
+    }
+
+    async fn total_elapsed_mins(&self) -> Option<f64> {
+        self.record.total_elapsed_mins
+    }
+
+    async fn project(&self, ctx: &Context<'_>) -> Result<Option<Project>> {
+        let Some(project_id) = &self.record.project_id else {
+            return Ok(None);
+        };
+        let projects = client(ctx)?.lock().await.list_projects().await?;
+        Ok(projects
+            .into_iter()
+            .find(|p| p.id == *project_id)
+            .map(Project))
+    }
+
+    async fn phases(&self, ctx: &Context<'_>) -> Result<Vec<Phase>> {
+        let detail = self.detail(ctx).await?;
+        Ok(detail
+            .phases
+            .iter()
+            .map(|phase| Phase {
+                record: phase.clone(),
+                detail: Arc::clone(detail),
+            })
+            .collect())
+    }
+
+    async fn phase(&self, ctx: &Context<'_>, number: String) -> Result<Option<Phase>> {
+        let detail = self.detail(ctx).await?;
+        Ok(detail
+            .phases
+            .iter()
+            .find(|phase| phase.phase_number == number)
+            .map(|phase| Phase {
+                record: phase.clone(),
+                detail: Arc::clone(detail),
+            }))
+    }
+
+    /// Each task's latest state.
+    async fn tasks(&self, ctx: &Context<'_>) -> Result<Vec<Task>> {
+        let detail = self.detail(ctx).await?;
+        Ok(detail.tasks.iter().cloned().map(Task).collect())
+    }
+
+    async fn team_members(&self, ctx: &Context<'_>) -> Result<Vec<TeamMember>> {
+        let detail = self.detail(ctx).await?;
+        Ok(detail
+            .team_members
+            .iter()
+            .cloned()
+            .map(TeamMember)
+            .collect())
+    }
+
+    /// Recorded events, oldest first. `since` is exclusive.
+    async fn events(
+        &self,
+        ctx: &Context<'_>,
+        event_type: Option<String>,
+        since: Option<String>,
+        #[graphql(default = 100)] limit: i64,
+    ) -> Result<Vec<Event>> {
+        let since = utc_time("since", since.as_ref()).map_err(|(_, message)| message)?;
+        let events = client(ctx)?
+            .lock()
+            .await
+            .list_events(
+                &self.id,
+                event_type.as_deref(),
+                since.as_deref(),
+                Some(limit.clamp(1, 1000)),
+            )
+            .await?;
+        Ok(events.into_iter().map(Event).collect())
+    }
+}
+
+pub struct Phase {
+    record: PhaseRecord,
+    detail: Arc<OrchestrationDetailResponse>,
+}
+
+#[Object]
+impl Phase {
+    async fn number(&self) -> &str {
+        &self.record.phase_number
+    }
+
+    async fn status(&self) -> &str {
+        &self.record.status
+    }
+
+    async fn plan_path(&self) -> Option<&str> {
+        self.record.plan_path.as_deref()
+    }
+
+    async fn git_range(&self) -> Option<&str> {
+        self.record.git_range.as_deref()
+    }
+
+    async fn planning_mins(&self) -> Option<f64> {
+        self.record.planning_mins
+    }
+
+    async fn execution_mins(&self) -> Option<f64> {
+        self.record.execution_mins
+    }
+
+    async fn review_mins(&self) -> Option<f64> {
+        self.record.review_mins
+    }
+
+    async fn started_at(&self) -> Option<&str> {
+        self.record.started_at.as_deref()
+    }
+
+    async fn completed_at(&self) -> Option<&str> {
+        self.record.completed_at.as_deref()
+    }
+
+    async fn risk_score(&self) -> Option<f64> {
+        self.record.risk_score
+    }
+
+    async fn tasks(&self) -> Vec<Task> {
+        self.detail
+            .tasks
+            .iter()
+            .filter(|task| task.phase_number.as_deref() == Some(&self.record.phase_number))
+            .cloned()
+            .map(Task)
+            .collect()
+    }
+
+    async fn team_members(&self) -> Vec<TeamMember> {
+        self.detail
+            .team_members
+            .iter()
+            .filter(|member| member.phase_number == self.record.phase_number)
+            .cloned()
+            .map(TeamMember)
+            .collect()
+    }
+}
+
+pub struct Task(TaskEventRecord);
+
+#[Object]
+impl Task {
+    async fn task_id(&self) -> &str {
+        &self.0.task_id
+    }
+
+    async fn phase_number(&self) -> Option<&str> {
+        self.0.phase_number.as_deref()
+    }
+
+    async fn subject(&self) -> &str {
+        &self.0.subject
+    }
+
+    async fn description(&self) -> Option<&str> {
+        self.0.description.as_deref()
+    }
+
+    async fn status(&self) -> &str {
+        &self.0.status
+    }
+
+    async fn owner(&self) -> Option<&str> {
+        self.0.owner.as_deref()
+    }
+
+    async fn blocked_by(&self) -> Option<&str> {
+        self.0.blocked_by.as_deref()
+    }
+
+    async fn recorded_at(&self) -> &str {
+        &self.0.recorded_at
+    }
+
+    /// Every recorded state of this task, oldest first.
+    async fn history(&self, ctx: &Context<'_>) -> Result<Vec<Task>> {
+        let events = client(ctx)?
+            .lock()
+            .await
+            .list_task_events(&self.0.orchestration_id, &self.0.task_id)
+            .await?;
+        Ok(events.into_iter().map(Task).collect())
+    }
+}
+
+pub struct Event(OrchestrationEventRecord);
+
+#[Object]
+impl Event {
+    async fn event_type(&self) -> &str {
+        &self.0.event_type
+    }
+
+    async fn phase_number(&self) -> Option<&str> {
+        self.0.phase_number.as_deref()
+    }
+
+    async fn source(&self) -> &str {
+        &self.0.source
+    }
+
+    async fn summary(&self) -> &str {
+        &self.0.summary
+    }
+
+    async fn detail(&self) -> Option<&str> {
+        self.0.detail.as_deref()
+    }
+
+    async fn recorded_at(&self) -> &str {
+        &self.0.recorded_at
+    }
+}
+
+pub struct TeamMember(TeamMemberRecord);
+
+#[Object]
+impl TeamMember {
+    async fn agent_name(&self) -> &str {
+        &self.0.agent_name
+    }
+
+    async fn agent_type(&self) -> Option<&str> {
+        self.0.agent_type.as_deref()
+    }
+
+    async fn model(&self) -> Option<&str> {
+        self.0.model.as_deref()
+    }
+
+    async fn phase_number(&self) -> &str {
+        &self.0.phase_number
+    }
+
+    async fn joined_at(&self) -> Option<&str> {
+        self.0.joined_at.as_deref()
+    }
+
+    async fn tmux_pane_id(&self) -> Option<&str> {
+        self.0.tmux_pane_id.as_deref()
+    }
+}
+
+fn enabled(state: &AppState) -> Result<&TinaSchema, ApiError> {
+    state.graphql.as_ref().ok_or((
+        StatusCode::NOT_FOUND,
+        "GraphQL is off; set http_graphql = true to enable it".to_string(),
+    ))
+}
+
+/// POST /api/graphql handler.
+pub async fn post_graphql(
+    State(state): State<AppState>,
+    Json(request): Json<async_graphql::Request>,
+) -> Result<Json<async_graphql::Response>, ApiError> {
+    let schema = enabled(&state)?;
+    let client = state.convex()?;
+    Ok(Json(schema.execute(request.data(client)).await))
+}
+
+/// GET /api/graphql handler.
+pub async fn get_graphiql(State(state): State<AppState>) -> Result<Html<String>, ApiError> {
+    enabled(&state)?;
+    Ok(Html(
+        GraphiQLSource::build().endpoint("/api/graphql").finish(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_nests_orchestration_resources() {
+        let sdl = schema().sdl();
+        for field in [
+            "projects: [Project!]!",
+            "orchestration(id: String!): Orchestration",
+            "phases: [Phase!]!",
+            "teamMembers: [TeamMember!]!",
+            "events(eventType: String, since: String, limit: Int! = 100): [Event!]!",
+            "history: [Task!]!",
+        ] {
+            assert!(sdl.contains(field), "schema is missing {}", field);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_overly_deep_queries_are_rejected() {
+        let query = "{ projects { orchestrations { phases { tasks { history { history { \
+                     history { history { taskId } } } } } } } } }";
+        let response = schema().execute(query).await;
+        assert!(response.errors[0].message.contains("nested too deep"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_client_is_a_query_error() {
+        let response = schema().execute("{ projects { id } }").await;
+        assert_eq!(response.errors.len(), 1);
+    }
+}
@@ -1,13 +1,17 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::Result;
 use sha2::{Digest, Sha256};
-use tokio::sync::Mutex;
+use tokio::sync::{watch, Mutex};
 use tokio_util::sync::CancellationToken;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-use tina_data::{NodeRegistration, TinaConvexClient};
+use tina_data::{NodeMetrics, NodeRegistration, TinaConvexClient};
+
+use crate::clock::ClockSkew;
+use crate::metrics;
 
 const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
 
@@ -37,14 +41,23 @@ pub async fn register_node(
 
 /// Spawn a background task that sends heartbeats every 30 seconds.
 ///
+/// Each heartbeat carries host resource metrics, with free disk measured on
+/// the volume holding the paths currently published on `worktree_paths`.
+///
+/// The clock skew against Convex server time is measured on every heartbeat,
+/// published on `clock_skew`, and reported with the following heartbeat.
+///
 /// Returns the JoinHandle for the heartbeat task. The task runs until the
 /// cancellation token is cancelled.
 pub fn spawn_heartbeat(
     client: Arc<Mutex<TinaConvexClient>>,
     node_id: String,
+    worktree_paths: watch::Receiver<Vec<PathBuf>>,
+    clock_skew: watch::Sender<Option<ClockSkew>>,
     cancel: CancellationToken,
 ) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
+        let mut last_skew: Option<ClockSkew> = None;
         loop {
             tokio::select! {
                 _ = cancel.cancelled() => {
@@ -52,9 +65,23 @@ pub fn spawn_heartbeat(
                     break;
                 }
                 _ = tokio::time::sleep(HEARTBEAT_INTERVAL) => {
+                    let paths = worktree_paths.borrow().clone();
+                    let metrics = collect_metrics(paths).await;
+                    let reported_skew = last_skew.as_ref().map(|s| s.skew_ms as f64);
                     let mut client = client.lock().await;
-                    if let Err(e) = client.heartbeat(&node_id).await {
-                        error!(error = %e, "heartbeat failed");
+                    let sent_ms = chrono::Utc::now().timestamp_millis();
+                    match client.heartbeat(&node_id, metrics.as_ref(), reported_skew).await {
+                        Ok(Some(server_ms)) => {
+                            let received_ms = chrono::Utc::now().timestamp_millis();
+                            let skew = ClockSkew::estimate(sent_ms, received_ms, server_ms as i64);
+                            log_skew_change(last_skew.as_ref(), &skew);
+                            clock_skew.send_replace(Some(skew.clone()));
+                            last_skew = Some(skew);
+                        }
+                        Ok(None) => {}
+                        Err(e) => {
+                            error!(error = %e, "heartbeat failed");
+                        }
                     }
                 }
             }
@@ -62,6 +89,31 @@ pub fn spawn_heartbeat(
     })
 }
 
+/// Warn while skew is over the threshold, and note when it recovers.
+fn log_skew_change(previous: Option<&ClockSkew>, current: &ClockSkew) {
+    if current.exceeds_threshold() {
+        warn!(
+            skew_ms = current.skew_ms,
+            round_trip_ms = current.round_trip_ms,
+            "local clock is skewed relative to Convex; elapsed times may be wrong"
+        );
+    } else if previous.is_some_and(ClockSkew::exceeds_threshold) {
+        info!(skew_ms = current.skew_ms, "clock skew back within threshold");
+    }
+}
+
+/// Collect host metrics off the async runtime. Returns `None` on failure so
+/// the heartbeat itself is still sent.
+async fn collect_metrics(worktree_paths: Vec<PathBuf>) -> Option<NodeMetrics> {
+    match tokio::task::spawn_blocking(move || metrics::collect_blocking(&worktree_paths)).await {
+        Ok(metrics) => Some(metrics),
+        Err(e) => {
+            warn!(error = %e, "failed to collect heartbeat metrics");
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
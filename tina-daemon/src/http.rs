@@ -1,25 +1,126 @@
+use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use axum::extract::Query;
-use axum::http::{HeaderValue, Method, StatusCode};
-use axum::routing::{delete, get, post};
+use axum::extract::{Form, Query, Request, State};
+use axum::http::{header, HeaderName, HeaderValue, Method, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{delete, get, patch, post};
 use axum::{Json, Router};
 use tokio::net::TcpListener;
-use tokio::sync::Mutex;
+use tokio::sync::{mpsc, Mutex};
 use tokio_util::sync::CancellationToken;
 use tower_http::cors::{Any, CorsLayer};
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
+use tower_http::trace::TraceLayer;
+use tina_data::clock::CLOCK_SKEW_WARN_MS;
 use tina_data::TinaConvexClient;
-use tracing::info;
-
+use tina_session::ignore::IgnoreRules;
+use tina_session::state::schema::SupervisorState;
+use tracing::{debug, info};
+
+use crate::analytics;
+use crate::auth::{self, TokenAuth};
+use crate::capture;
+use crate::clock::{self, ClockSkewReceiver};
+use crate::control;
+use crate::editor;
+use crate::event_log;
+use crate::events::EventBus;
+use crate::gates;
+use crate::graphql::{self, TinaSchema};
+use crate::openapi;
+use crate::orchestrations;
+use crate::phases;
+use crate::plans;
+use crate::rate_limit::{self, RateLimiter};
+use crate::request_log;
+use crate::reviews;
+use crate::send::{self, SendTextPolicy};
 use crate::git;
 use crate::sessions;
+use crate::stream;
+use crate::summary::{self, SummaryCache};
+use crate::tasks;
 use crate::terminal;
+use crate::watcher::{self, WatchStatsReceiver};
+use crate::webhooks::{self, WebhookRegistry};
 
 /// Shared application state for HTTP handlers.
 #[derive(Clone)]
 pub struct AppState {
     pub convex_client: Option<Arc<Mutex<TinaConvexClient>>>,
+    /// Node this daemon registered as; `None` without Convex.
+    pub node_id: Option<String>,
+    /// Latest clock skew measured by the heartbeat, reported on `/health`.
+    pub clock_skew: ClockSkewReceiver,
+    /// Worktree paths reported by git hooks, forwarded to the sync loop.
+    pub git_hooks: Option<mpsc::UnboundedSender<PathBuf>>,
+    /// Plan files edited outside the watcher's view (e.g. from tina-monitor),
+    /// forwarded to the sync loop.
+    pub plan_hooks: Option<mpsc::UnboundedSender<PathBuf>>,
+    /// File watch counts, reported on `/health`.
+    pub watch_stats: WatchStatsReceiver,
+    /// API token checks; `None` disables them.
+    pub token_auth: Option<TokenAuth>,
+    /// Limits on state-changing requests; `None` disables them.
+    pub rate_limiter: Option<Arc<RateLimiter>>,
+    /// Last `/api/summary` document, shared across dashboard pollers.
+    pub summary_cache: SummaryCache,
+    /// Daemon events, relayed by the SSE stream; `None` disables it.
+    pub events: Option<EventBus>,
+    /// Which agents `/agents/{agent}/send` may reach.
+    pub send_text: SendTextPolicy,
+    /// Outbound webhook registrations; `None` disables `/api/webhooks`.
+    pub webhooks: Option<Arc<WebhookRegistry>>,
+    /// GraphQL schema; `None` unless `http_graphql` is set.
+    pub graphql: Option<TinaSchema>,
+}
+
+/// No Convex, hooks, auth, limits, or optional APIs: what `build_router`
+/// serves, and a base for tests to override.
+impl Default for AppState {
+    fn default() -> Self {
+        Self {
+            convex_client: None,
+            node_id: None,
+            clock_skew: clock::channel().1,
+            git_hooks: None,
+            plan_hooks: None,
+            watch_stats: watcher::stats_channel().1,
+            token_auth: None,
+            rate_limiter: None,
+            summary_cache: SummaryCache::default(),
+            events: None,
+            send_text: SendTextPolicy::default(),
+            webhooks: None,
+            graphql: None,
+        }
+    }
+}
+
+impl AppState {
+    /// The Convex client, or 503 when the daemon runs without one.
+    pub fn convex(&self) -> Result<Arc<Mutex<TinaConvexClient>>, (StatusCode, String)> {
+        self.convex_client.clone().ok_or((
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Convex client not available".to_string(),
+        ))
+    }
+}
+
+/// Form body posted by the hooks from `tina-session hooks install`.
+#[derive(Debug, serde::Deserialize)]
+pub struct GitHookParams {
+    pub event: String,
+    pub worktree: String,
+}
+
+/// Form body posted by tina-monitor after editing a plan.
+#[derive(Debug, serde::Deserialize)]
+pub struct PlanHookParams {
+    pub plan: String,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -129,11 +230,18 @@ pub async fn get_diff_list(
     Query(params): Query<DiffListParams>,
 ) -> Result<Json<Vec<git::DiffFileStat>>, (StatusCode, String)> {
     let worktree = validate_worktree_path(&params.worktree)?;
-    tokio::task::spawn_blocking(move || git::get_diff_file_list(&worktree, &params.base))
-        .await
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
-        .map(Json)
-        .map_err(|e| map_git_error("diff list failed", e))
+    tokio::task::spawn_blocking(move || {
+        let scope = SupervisorState::local_scope(&worktree);
+        let mut files = git::get_diff_file_list(&worktree, &params.base, scope.as_deref())?;
+        // Generated files listed in .tina/ignore are left out of review
+        let ignore = IgnoreRules::load(&worktree);
+        files.retain(|f| !ignore.is_ignored(Path::new(&f.path), false));
+        Ok(files)
+    })
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .map(Json)
+    .map_err(|e| map_git_error("diff list failed", e))
 }
 
 pub async fn get_diff_file(
@@ -182,48 +290,241 @@ pub async fn get_commit_details(
     }))
 }
 
-async fn get_health() -> Json<serde_json::Value> {
-    Json(serde_json::json!({ "status": "ok" }))
+/// POST /hooks/git: a post-commit/post-checkout hook fired in a worktree.
+async fn post_git_hook(
+    State(state): State<AppState>,
+    Form(params): Form<GitHookParams>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let worktree = validate_worktree_path(&params.worktree)?;
+    let tx = state.git_hooks.as_ref().ok_or((
+        StatusCode::SERVICE_UNAVAILABLE,
+        "git hook sync not available".to_string(),
+    ))?;
+    debug!(event = %params.event, worktree = %worktree.display(), "git hook received");
+    tx.send(worktree).map_err(|_| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "sync loop is not running".to_string(),
+        )
+    })?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+/// POST /hooks/plan: a plan file was edited and should be re-synced now.
+async fn post_plan_hook(
+    State(state): State<AppState>,
+    Form(params): Form<PlanHookParams>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let plan = Path::new(&params.plan);
+    if !plan.is_absolute() || plan.extension().is_none_or(|ext| ext != "md") {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("plan must be an absolute path to a .md file: {}", params.plan),
+        ));
+    }
+    if !plan.is_file() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("plan not found: {}", params.plan),
+        ));
+    }
+    let tx = state.plan_hooks.as_ref().ok_or((
+        StatusCode::SERVICE_UNAVAILABLE,
+        "plan sync not available".to_string(),
+    ))?;
+    debug!(plan = %plan.display(), "plan hook received");
+    tx.send(plan.to_path_buf()).map_err(|_| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "sync loop is not running".to_string(),
+        )
+    })?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn get_health(State(state): State<AppState>) -> Json<serde_json::Value> {
+    let skew = state.clock_skew.borrow().clone();
+    let skew_warning = skew.as_ref().is_some_and(|s| s.exceeds_threshold());
+    Json(serde_json::json!({
+        "status": "ok",
+        "clock_skew": skew,
+        "clock_skew_warning": skew_warning,
+        "clock_skew_threshold_ms": CLOCK_SKEW_WARN_MS,
+        "watches": state.watch_stats.borrow().clone(),
+    }))
+}
+
+/// Browser origins the web UI is served from (Vite dev and preview).
+pub const ALLOWED_ORIGINS: [&str; 4] = [
+    "http://localhost:5173",
+    "http://127.0.0.1:5173",
+    "http://localhost:4173",
+    "http://127.0.0.1:4173",
+];
+
+/// CORS doesn't cover WebSocket upgrades, so a page on any other origin could
+/// open one. Refuse browser upgrades from origins outside the allowlist;
+/// native clients send no `Origin` and pass.
+async fn reject_foreign_origin(request: Request, next: Next) -> Response {
+    if let Some(origin) = request.headers().get(header::ORIGIN) {
+        if !ALLOWED_ORIGINS.iter().any(|allowed| origin == allowed) {
+            debug!(origin = ?origin, path = %request.uri().path(), "Rejected WebSocket origin");
+            return (StatusCode::FORBIDDEN, "Origin not allowed").into_response();
+        }
+    }
+    next.run(request).await
 }
 
 pub fn build_router() -> Router {
-    build_router_with_state(AppState {
-        convex_client: None,
-    })
+    build_router_with_state(AppState::default())
 }
 
 pub fn build_router_with_state(state: AppState) -> Router {
     let cors = CorsLayer::new()
-        .allow_origin([
-            HeaderValue::from_static("http://localhost:5173"),
-            HeaderValue::from_static("http://127.0.0.1:5173"),
-            HeaderValue::from_static("http://localhost:4173"),
-            HeaderValue::from_static("http://127.0.0.1:4173"),
-        ])
+        .allow_origin(ALLOWED_ORIGINS.map(HeaderValue::from_static))
         .allow_methods([
             Method::GET,
             Method::POST,
+            Method::PATCH,
             Method::DELETE,
             Method::OPTIONS,
         ])
-        .allow_headers(Any);
+        .allow_headers(Any)
+        .expose_headers([HeaderName::from_static("x-request-id")]);
 
-    Router::new()
+    let token_auth = state.token_auth.clone();
+    let rate_limiter = state.rate_limiter.clone();
+    let router = Router::new()
         .route("/health", get(get_health))
         .route("/diff", get(get_diff_list))
         .route("/diff/file", get(get_diff_file))
         .route("/file", get(get_file))
         .route("/commits", get(get_commit_details))
+        .route("/hooks/git", post(post_git_hook))
+        .route("/hooks/plan", post(post_plan_hook))
+        .route("/api/editor/context", get(editor::get_context))
+        .route("/api/editor/tasks", get(editor::get_tasks))
+        .route("/api/editor/findings", get(editor::get_findings))
+        .route("/api/openapi.json", get(openapi::get_openapi))
+        .route("/api/docs", get(openapi::get_docs))
+        .route("/api/summary", get(summary::get_summary))
+        .route("/api/orchestrations", get(orchestrations::get_orchestrations))
+        .route(
+            "/api/orchestrations/{id}",
+            delete(orchestrations::delete_orchestration),
+        )
+        .route("/api/orchestrations/{id}/tasks", post(tasks::insert_task))
+        .route(
+            "/api/orchestrations/{id}/tasks/{task}",
+            patch(tasks::edit_task),
+        )
+        .route(
+            "/api/orchestrations/{id}/tasks/{task}/model",
+            post(tasks::set_task_model),
+        )
+        .route(
+            "/api/orchestrations/{id}/gates/{gate}/approve",
+            post(gates::approve_gate),
+        )
+        .route(
+            "/api/orchestrations/{id}/gates/{gate}/block",
+            post(gates::block_gate),
+        )
+        .route(
+            "/api/orchestrations/{id}/reviews",
+            get(reviews::get_reviews),
+        )
+        .route(
+            "/api/orchestrations/{id}/findings",
+            get(reviews::get_findings),
+        )
+        .route("/api/findings/{id}/resolve", post(reviews::resolve_finding))
+        .route(
+            "/api/orchestrations/{id}/agents/{agent}/capture",
+            get(capture::get_capture),
+        )
+        .route(
+            "/api/orchestrations/{id}/agents/{agent}/send",
+            post(send::send_text),
+        )
+        .route(
+            "/api/orchestrations/{id}/phases/{phase}/commits",
+            get(phases::get_phase_commits),
+        )
+        .route(
+            "/api/orchestrations/{id}/phases/{phase}/diff",
+            get(phases::get_phase_diff),
+        )
+        .route(
+            "/api/orchestrations/{id}/phases/{phase}/plan",
+            get(plans::get_plan),
+        )
+        .route(
+            "/api/orchestrations/{id}/analytics",
+            get(analytics::get_analytics),
+        )
+        .route(
+            "/api/orchestrations/{id}/events",
+            get(event_log::get_events),
+        )
+        .route(
+            "/api/orchestrations/{id}/events/stream",
+            get(stream::get_event_stream),
+        )
+        .route(
+            "/api/graphql",
+            get(graphql::get_graphiql).post(graphql::post_graphql),
+        )
+        .route(
+            "/api/webhooks",
+            get(webhooks::list_webhooks).post(webhooks::create_webhook),
+        )
+        .route("/api/webhooks/{id}", delete(webhooks::delete_webhook))
+        .route(
+            "/api/webhooks/{id}/deliveries",
+            get(webhooks::list_deliveries),
+        )
         .route(
             "/ws/terminal/{paneId}",
-            get(terminal::ws_terminal_handler),
+            get(terminal::ws_terminal_handler).layer(middleware::from_fn(reject_foreign_origin)),
+        )
+        .route(
+            "/ws/orchestrations/{id}",
+            get(control::ws_orchestration_handler)
+                .layer(middleware::from_fn(reject_foreign_origin)),
         )
         .route("/sessions", post(sessions::create_session))
         .route(
             "/sessions/{sessionName}",
             delete(sessions::delete_session),
         )
-        .with_state(state)
+        .with_state(state);
+    // Layers added later run first: request ids, logging, then auth, then
+    // rate limits
+    let router = match rate_limiter {
+        Some(rate_limiter) => router.layer(middleware::from_fn_with_state(
+            rate_limiter,
+            rate_limit::enforce_rate_limit,
+        )),
+        None => router,
+    };
+    let router = match token_auth {
+        Some(token_auth) => router.layer(middleware::from_fn_with_state(
+            token_auth,
+            auth::enforce_token_scope,
+        )),
+        None => router,
+    };
+    router
+        .layer(middleware::from_fn(request_log::log_request))
+        // log_request already reports failures; the trace layer adds the span
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(request_log::make_span)
+                .on_failure(()),
+        )
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
         .layer(cors)
 }
 
@@ -231,23 +532,26 @@ pub async fn spawn_http_server(
     port: u16,
     cancel: CancellationToken,
 ) -> Result<tokio::task::JoinHandle<()>, anyhow::Error> {
-    spawn_http_server_with_client(port, cancel, None).await
+    spawn_http_server_with_state(port, cancel, AppState::default()).await
 }
 
-pub async fn spawn_http_server_with_client(
+pub async fn spawn_http_server_with_state(
     port: u16,
     cancel: CancellationToken,
-    convex_client: Option<Arc<Mutex<TinaConvexClient>>>,
+    state: AppState,
 ) -> Result<tokio::task::JoinHandle<()>, anyhow::Error> {
-    let router = build_router_with_state(AppState { convex_client });
+    let router = build_router_with_state(state);
     let listener = TcpListener::bind(format!("127.0.0.1:{}", port)).await?;
     info!(port = port, "HTTP server listening");
 
     let handle = tokio::spawn(async move {
-        axum::serve(listener, router)
-            .with_graceful_shutdown(async move { cancel.cancelled().await })
-            .await
-            .ok();
+        axum::serve(
+            listener,
+            router.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(async move { cancel.cancelled().await })
+        .await
+        .ok();
     });
 
     Ok(handle)
@@ -278,6 +582,236 @@ mod tests {
             .unwrap();
         let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
         assert_eq!(json["status"], "ok");
+        assert!(json["clock_skew"].is_null());
+        assert_eq!(json["clock_skew_warning"], false);
+        assert_eq!(json["watches"]["native"], 0);
+        assert_eq!(json["watches"]["os_limit_hit"], false);
+    }
+
+    #[tokio::test]
+    async fn test_responses_carry_a_request_id() {
+        let resp = test_router().oneshot(get("/health")).await.unwrap();
+        let generated = resp.headers()["x-request-id"].to_str().unwrap();
+        assert_eq!(generated.len(), 36);
+
+        // A client's own id is kept, so it can match its logs to ours
+        let request = Request::builder()
+            .uri("/health")
+            .header("x-request-id", "dash-42")
+            .body(Body::empty())
+            .unwrap();
+        let resp = test_router().oneshot(request).await.unwrap();
+        assert_eq!(resp.headers()["x-request-id"], "dash-42");
+    }
+
+    #[tokio::test]
+    async fn test_health_reports_clock_skew_warning() {
+        let (tx, rx) = clock::channel();
+        tx.send_replace(Some(clock::ClockSkew::estimate(20_000, 20_000, 10_000)));
+        let router = build_router_with_state(AppState {
+            clock_skew: rx,
+            ..AppState::default()
+        });
+
+        let resp = router.oneshot(get("/health")).await.unwrap();
+        let body = axum::body::to_bytes(resp.into_body(), 1_000_000)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["clock_skew"]["skew_ms"], 10_000);
+        assert_eq!(json["clock_skew_warning"], true);
+    }
+
+    #[tokio::test]
+    async fn test_editor_context_requires_convex_client() {
+        let resp = test_router()
+            .oneshot(get("/api/editor/context?path=/tmp"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_delete_orchestration_requires_convex_client() {
+        for uri in [
+            "/api/orchestrations/orch-1",
+            "/api/orchestrations/orch-1?hard=true",
+        ] {
+            let resp = test_router().oneshot(delete_req(uri)).await.unwrap();
+            assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_task_edits_require_convex_client() {
+        let resp = test_router()
+            .oneshot(post_json(
+                "/api/orchestrations/orch-1/tasks/2/model",
+                r#"{"phaseNumber": "1", "revision": 1, "model": "sonnet"}"#,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_gate_decisions_check_the_gate_before_convex() {
+        let resp = test_router()
+            .oneshot(post_json(
+                "/api/orchestrations/orch-1/gates/merge/approve",
+                "{}",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let resp = test_router()
+            .oneshot(post_json(
+                "/api/orchestrations/orch-1/gates/review/block",
+                r#"{"reason": "tests fail"}"#,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_review_endpoints_require_convex_client() {
+        let resp = test_router()
+            .oneshot(get(
+                "/api/orchestrations/orch-1/findings?severity=p0&resolved=false",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let resp = test_router()
+            .oneshot(post_json("/api/findings/t1/resolve", "{}"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_agent_capture_requires_convex_client() {
+        let resp = test_router()
+            .oneshot(get(
+                "/api/orchestrations/orch-1/agents/executor-1/capture?lines=20",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_send_text_is_refused_unless_the_agent_is_allowed() {
+        let body = r#"{"text": "continue", "confirm": true}"#;
+        let resp = test_router()
+            .oneshot(post_json(
+                "/api/orchestrations/orch-1/agents/executor-1/send",
+                body,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let router = build_router_with_state(AppState {
+            send_text: SendTextPolicy {
+                allow_agents: vec!["executor-*".to_string()],
+                require_confirm: true,
+            },
+            ..AppState::default()
+        });
+        let resp = router
+            .oneshot(post_json(
+                "/api/orchestrations/orch-1/agents/executor-1/send",
+                body,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_list_endpoints_validate_filters() {
+        let resp = test_router()
+            .oneshot(get("/api/orchestrations/orch-1/events?sort=newest"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let resp = test_router()
+            .oneshot(get("/api/orchestrations?sort=sideways"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_git_hook_forwards_worktree_to_sync_loop() {
+        let repo = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(repo.path().join(".git")).unwrap();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let router = build_router_with_state(AppState {
+            git_hooks: Some(tx),
+            ..AppState::default()
+        });
+
+        let body = format!(
+            "event=post-commit&worktree={}",
+            repo.path().display().to_string().replace('/', "%2F")
+        );
+        let req = Request::builder()
+            .method("POST")
+            .uri("/hooks/git")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from(body))
+            .unwrap();
+        let resp = router.oneshot(req).await.unwrap();
+
+        assert_eq!(resp.status(), StatusCode::ACCEPTED);
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            std::fs::canonicalize(repo.path()).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_plan_hook_forwards_plan_to_sync_loop() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let plan = dir.path().join("feature-phase-1.md");
+        std::fs::write(&plan, "# Plan").unwrap();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let router = build_router_with_state(AppState {
+            plan_hooks: Some(tx),
+            ..AppState::default()
+        });
+
+        let req = Request::builder()
+            .method("POST")
+            .uri("/hooks/plan")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from(format!(
+                "plan={}",
+                plan.display().to_string().replace('/', "%2F")
+            )))
+            .unwrap();
+        let resp = router.oneshot(req).await.unwrap();
+
+        assert_eq!(resp.status(), StatusCode::ACCEPTED);
+        assert_eq!(rx.recv().await.unwrap(), plan);
+    }
+
+    #[tokio::test]
+    async fn test_plan_hook_rejects_non_markdown() {
+        let req = Request::builder()
+            .method("POST")
+            .uri("/hooks/plan")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from("plan=%2Fetc%2Fpasswd"))
+            .unwrap();
+        let resp = test_router().oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
@@ -463,6 +997,28 @@ mod tests {
         assert!(paths.contains(&"new.txt"), "missing new.txt in {paths:?}");
     }
 
+    #[tokio::test]
+    async fn test_diff_list_skips_ignored_files() {
+        let repo = setup_test_repo();
+        std::fs::create_dir_all(repo.path().join(".tina")).unwrap();
+        std::fs::write(repo.path().join(".tina/ignore"), "new.txt\n").unwrap();
+        let worktree = repo.path().to_str().unwrap();
+        let uri = format!("/diff?worktree={}&base=main", urlencoding::encode(worktree));
+        let resp = test_router().oneshot(get(&uri)).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(resp.into_body(), 1_000_000)
+            .await
+            .unwrap();
+        let files: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+        let paths: Vec<&str> = files.iter().filter_map(|f| f["path"].as_str()).collect();
+        assert!(
+            paths.contains(&"hello.txt"),
+            "missing hello.txt in {paths:?}"
+        );
+        assert!(!paths.contains(&"new.txt"), "ignored new.txt in {paths:?}");
+    }
+
     #[tokio::test]
     async fn test_diff_file_returns_hunks() {
         let repo = setup_test_repo();
@@ -517,6 +1073,80 @@ mod tests {
         assert_ne!(resp.status(), StatusCode::NOT_FOUND);
     }
 
+    #[tokio::test]
+    async fn test_ws_orchestration_route_is_registered() {
+        let resp = test_router()
+            .oneshot(get("/ws/orchestrations/orch-1"))
+            .await
+            .unwrap();
+        assert_ne!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    fn ws_from(uri: &str, origin: &str) -> Request<Body> {
+        Request::builder()
+            .uri(uri)
+            .header("Origin", origin)
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_ws_upgrade_from_foreign_origin_is_forbidden() {
+        for uri in ["/ws/orchestrations/orch-1", "/ws/terminal/302"] {
+            let resp = test_router()
+                .oneshot(ws_from(uri, "http://evil.example"))
+                .await
+                .unwrap();
+            assert_eq!(resp.status(), StatusCode::FORBIDDEN, "{uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ws_upgrade_from_allowed_origin_reaches_handler() {
+        let resp = test_router()
+            .oneshot(ws_from(
+                "/ws/orchestrations/orch-1",
+                "http://localhost:5173",
+            ))
+            .await
+            .unwrap();
+        // Missing upgrade headers, so the extractor refuses it rather than
+        // the origin check
+        assert_ne!(resp.status(), StatusCode::FORBIDDEN);
+        assert_ne!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_event_stream_is_served_as_sse() {
+        let resp = test_router()
+            .oneshot(get("/api/orchestrations/orch-1/events/stream"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let bus = EventBus::default();
+        let router = build_router_with_state(AppState {
+            events: Some(bus.clone()),
+            ..AppState::default()
+        });
+        let resp = router
+            .oneshot(get("/api/orchestrations/orch-1/events/stream"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers()["content-type"], "text/event-stream");
+
+        bus.publish(crate::events::DaemonEvent::ProjectionChanged);
+        drop(bus);
+        let body = axum::body::to_bytes(resp.into_body(), 1_000_000)
+            .await
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(body.to_vec()).unwrap(),
+            "event: projection\ndata: {\"type\":\"projection\"}\n\n"
+        );
+    }
+
     #[tokio::test]
     async fn test_ws_terminal_route_not_found_for_missing_pane_id() {
         // No pane ID in path → 404 (route doesn't match).
@@ -1,11 +1,37 @@
 pub mod actions;
+pub mod analytics;
+pub mod auth;
+pub mod capture;
+pub mod clock;
 pub mod config;
+pub mod control;
+pub mod editor;
+pub mod event_log;
+pub mod events;
+pub mod gates;
 pub mod git;
+pub mod graphql;
 pub mod heartbeat;
 pub mod http;
+pub mod metrics;
+pub mod openapi;
+pub mod orchestrations;
+pub mod phases;
+pub mod plans;
+pub mod pool;
+pub mod rate_limit;
 pub mod reconcile;
+pub mod remote;
+pub mod request_log;
+pub mod reviews;
+pub mod schedule;
+pub mod send;
 pub mod sessions;
+pub mod stream;
+pub mod summary;
 pub mod sync;
+pub mod tasks;
 pub mod telemetry;
 pub mod terminal;
 pub mod watcher;
+pub mod webhooks;
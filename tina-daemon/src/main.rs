@@ -1,22 +1,35 @@
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use anyhow::Result;
 use clap::Parser;
 use futures::StreamExt;
-use tokio::sync::Mutex;
+use tokio::sync::{mpsc, watch, Mutex};
 use tokio_util::sync::CancellationToken;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 use tina_daemon::actions;
+use tina_daemon::auth::TokenAuth;
+use tina_daemon::clock;
 use tina_daemon::config::DaemonConfig;
+use tina_daemon::events::{self, CommitTrigger, DaemonEvent, EventBus, Subscriber};
 use tina_daemon::git;
+use tina_daemon::graphql;
 use tina_daemon::heartbeat;
 use tina_daemon::http;
+use tina_daemon::pool::SyncPool;
+use tina_daemon::rate_limit::RateLimiter;
 use tina_daemon::reconcile;
+use tina_daemon::remote;
+use tina_daemon::schedule::{self, TimerWheel};
+use tina_daemon::summary::SummaryCache;
 use tina_daemon::sync::{self, SyncCache};
 use tina_daemon::telemetry::DaemonTelemetry;
-use tina_daemon::watcher::{DaemonWatcher, WatchEvent};
+use tina_daemon::watcher::{
+    self as daemon_watcher, DaemonWatcher, WatchEvent, WatchStats, WorktreeInfo,
+};
+use tina_daemon::webhooks::{self, WebhookRegistry};
 
 use convex::{FunctionResult, Value};
 use tina_data::{InboundAction, TinaConvexClient};
@@ -37,12 +50,20 @@ struct Cli {
 }
 
 /// Refresh active worktree discovery and attach watchers.
+///
+/// The discovered worktree paths are published on `worktree_paths` so the
+/// heartbeat can report free disk on their volume. Watches left over from
+/// worktrees that are no longer active are removed, and the resulting watch
+/// counts are published on `watch_stats`.
 async fn refresh_worktrees(
     client: &Arc<Mutex<TinaConvexClient>>,
     cache: &mut SyncCache,
     watcher: &mut DaemonWatcher,
+    worktree_paths: &watch::Sender<Vec<PathBuf>>,
+    watch_stats: &watch::Sender<WatchStats>,
 ) -> Result<()> {
     let mut worktrees = sync::discover_worktrees(client).await?;
+    let mut active_watches: HashSet<PathBuf> = HashSet::new();
 
     for worktree in &mut worktrees {
         let git_dir_path = match git::resolve_git_dir(&worktree.worktree_path) {
@@ -84,6 +105,7 @@ async fn refresh_worktrees(
             if !ref_path.exists() {
                 continue;
             }
+            active_watches.insert(ref_path.clone());
             if let Err(e) = watcher.watch_git_ref(&ref_path) {
                 warn!(
                     feature = %worktree.feature,
@@ -94,16 +116,9 @@ async fn refresh_worktrees(
             }
         }
 
-        let mut plan_dirs = vec![worktree.worktree_path.join("docs").join("plans")];
-        if let Some(repo_root) = worktree.worktree_path.parent().and_then(|p| p.parent()) {
-            let repo_plans = repo_root.join("docs").join("plans");
-            if repo_plans != plan_dirs[0] {
-                plan_dirs.push(repo_plans);
-            }
-        }
-
-        for plans_dir in &plan_dirs {
+        for plans_dir in &worktree.plan_dirs() {
             if plans_dir.exists() {
+                active_watches.insert(plans_dir.clone());
                 if let Err(e) = watcher.watch_plan_dir(plans_dir) {
                     warn!(
                         feature = %worktree.feature,
@@ -121,6 +136,7 @@ async fn refresh_worktrees(
             .join("designs")
             .join("sets");
         if designs_dir.exists() {
+            active_watches.insert(designs_dir.clone());
             if let Err(e) = watcher.watch_design_dir(&designs_dir) {
                 warn!(
                     feature = %worktree.feature,
@@ -153,22 +169,167 @@ async fn refresh_worktrees(
         }
     }
 
+    let removed = watcher.retain_watches(&active_watches);
+    if removed > 0 {
+        info!(removed, "removed watches for inactive worktrees");
+    }
+    publish_watch_stats(watcher, watch_stats);
+
+    worktree_paths.send_replace(worktrees.iter().map(|w| w.worktree_path.clone()).collect());
     cache.set_worktrees(worktrees);
     Ok(())
 }
 
-async fn sync_designs_for_worktrees(
+/// Publish watch counts, logging whenever they change.
+fn publish_watch_stats(watcher: &DaemonWatcher, watch_stats: &watch::Sender<WatchStats>) {
+    let stats = watcher.stats();
+    watch_stats.send_if_modified(|current| {
+        if *current == stats {
+            return false;
+        }
+        info!(
+            native = stats.native,
+            polled = stats.polled,
+            git_refs = stats.git_refs,
+            plan_dirs = stats.plan_dirs,
+            design_dirs = stats.design_dirs,
+            max_watches = stats.max_watches,
+            os_limit_hit = stats.os_limit_hit,
+            "file watch counts changed"
+        );
+        if stats.polled > 0 && current.polled == 0 {
+            warn!(
+                polled = stats.polled,
+                "some paths are polled instead of watched; raise max_watches or the OS watch limit"
+            );
+        }
+        *current = stats.clone();
+        true
+    });
+}
+
+/// Keeps Convex in sync with local orchestration activity.
+///
+/// Commit, plan, and design syncs run on the worker pool; team/task
+/// projection runs on this subscriber's task.
+struct SyncSubscriber {
+    client: Arc<Mutex<TinaConvexClient>>,
+    cache: Arc<Mutex<SyncCache>>,
+    telemetry: Arc<DaemonTelemetry>,
+    pool: SyncPool,
+    teams_dir: PathBuf,
+    tasks_dir: PathBuf,
+}
+
+impl SyncSubscriber {
+    /// Re-project teams and tasks and refresh design metadata for every
+    /// active worktree.
+    async fn sync_projection(&mut self) {
+        let mut cache = self.cache.lock().await;
+        retain_active_lanes(&mut self.pool, &cache);
+        if let Err(e) = sync::sync_all(
+            &self.client,
+            &mut cache,
+            &self.teams_dir,
+            &self.tasks_dir,
+            Some(&self.telemetry),
+        )
+        .await
+        {
+            error!(error = %e, "sync failed");
+        }
+        sync_designs_for_worktrees(&mut self.pool, &self.client, &cache, &self.telemetry);
+    }
+}
+
+impl Subscriber for SyncSubscriber {
+    fn name(&self) -> &'static str {
+        "sync"
+    }
+
+    async fn handle(&mut self, event: &DaemonEvent) {
+        match event {
+            DaemonEvent::ProjectionChanged => self.sync_projection().await,
+            DaemonEvent::CommitsChanged { worktree, .. } => submit_commit_sync(
+                &mut self.pool,
+                &self.client,
+                &self.cache,
+                &self.telemetry,
+                worktree.clone(),
+            ),
+            DaemonEvent::PlanChanged {
+                worktree,
+                plan_path,
+            } => submit_plan_sync(
+                &mut self.pool,
+                &self.client,
+                &self.telemetry,
+                worktree.clone(),
+                plan_path.clone(),
+            ),
+            DaemonEvent::DesignChanged { worktree, .. } => submit_design_sync(
+                &mut self.pool,
+                &self.client,
+                &self.telemetry,
+                worktree.clone(),
+            ),
+            DaemonEvent::Reconciled(_) | DaemonEvent::ActionFinished { .. } => {}
+        }
+    }
+
+    /// Missed events may have been commits or plan edits, so sync everything.
+    async fn lagged(&mut self, _missed: u64) {
+        self.sync_projection().await;
+        let worktrees = self.cache.lock().await.worktrees.clone();
+        for worktree in worktrees {
+            submit_commit_sync(
+                &mut self.pool,
+                &self.client,
+                &self.cache,
+                &self.telemetry,
+                worktree,
+            );
+        }
+    }
+}
+
+/// Drop pool lanes for orchestrations that no longer have an active worktree.
+fn retain_active_lanes(pool: &mut SyncPool, cache: &SyncCache) {
+    let active: HashSet<String> = cache
+        .worktrees
+        .iter()
+        .map(|w| w.orchestration_id.clone())
+        .collect();
+    pool.retain_keys(&active);
+}
+
+/// Queue a design metadata sync for every active worktree.
+fn sync_designs_for_worktrees(
+    pool: &mut SyncPool,
     client: &Arc<Mutex<TinaConvexClient>>,
     cache: &SyncCache,
-    telemetry: &DaemonTelemetry,
+    telemetry: &Arc<DaemonTelemetry>,
 ) {
     for worktree in &cache.worktrees {
+        submit_design_sync(pool, client, telemetry, worktree.clone());
+    }
+}
+
+fn submit_design_sync(
+    pool: &mut SyncPool,
+    client: &Arc<Mutex<TinaConvexClient>>,
+    telemetry: &Arc<DaemonTelemetry>,
+    worktree: WorktreeInfo,
+) {
+    let client = Arc::clone(client);
+    let telemetry = Arc::clone(telemetry);
+    pool.submit(&worktree.orchestration_id.clone(), async move {
         if let Err(e) = sync::sync_design_metadata(
-            client,
+            &client,
             &worktree.orchestration_id,
             worktree.project_id.as_deref(),
             &worktree.worktree_path,
-            Some(telemetry),
+            Some(&telemetry),
         )
         .await
         {
@@ -179,6 +340,104 @@ async fn sync_designs_for_worktrees(
                 "failed to sync design metadata"
             );
         }
+    });
+}
+
+fn submit_plan_sync(
+    pool: &mut SyncPool,
+    client: &Arc<Mutex<TinaConvexClient>>,
+    telemetry: &Arc<DaemonTelemetry>,
+    worktree: WorktreeInfo,
+    plan_path: PathBuf,
+) {
+    let client = Arc::clone(client);
+    let telemetry = Arc::clone(telemetry);
+    pool.submit(&worktree.orchestration_id.clone(), async move {
+        if let Err(e) = sync::sync_plan(
+            &client,
+            &worktree.orchestration_id,
+            &plan_path,
+            Some(&telemetry),
+        )
+        .await
+        {
+            error!(
+                feature = %worktree.feature,
+                error = %e,
+                "failed to sync plan"
+            );
+        }
+    });
+}
+
+/// Queue a commit sync for one worktree.
+///
+/// The job works on a private cache holding only this orchestration's commit
+/// anchor, so the shared cache is never locked while git and Convex calls run.
+/// The new anchor and resolved phase are merged back when the job finishes.
+fn submit_commit_sync(
+    pool: &mut SyncPool,
+    client: &Arc<Mutex<TinaConvexClient>>,
+    cache: &Arc<Mutex<SyncCache>>,
+    telemetry: &Arc<DaemonTelemetry>,
+    worktree: WorktreeInfo,
+) {
+    let client = Arc::clone(client);
+    let cache = Arc::clone(cache);
+    let telemetry = Arc::clone(telemetry);
+    pool.submit(&worktree.orchestration_id.clone(), async move {
+        let orchestration_id = worktree.orchestration_id.clone();
+        let mut local = SyncCache::new();
+        if let Some(sha) = cache.lock().await.last_commit_sha.get(&orchestration_id) {
+            local
+                .last_commit_sha
+                .insert(orchestration_id.clone(), sha.clone());
+        }
+        local.set_worktrees(vec![worktree.clone()]);
+
+        if let Err(e) = sync::sync_commits(
+            &client,
+            &mut local,
+            &orchestration_id,
+            &worktree.current_phase,
+            &worktree.worktree_path,
+            &worktree.branch,
+            Some(&telemetry),
+        )
+        .await
+        {
+            error!(
+                feature = %worktree.feature,
+                error = %e,
+                "failed to sync commits"
+            );
+        }
+
+        merge_commit_state(&mut *cache.lock().await, &local, &orchestration_id);
+    });
+}
+
+/// Copy the commit anchor and resolved phase for one orchestration from a
+/// job-local cache back into the shared cache.
+fn merge_commit_state(shared: &mut SyncCache, local: &SyncCache, orchestration_id: &str) {
+    if let Some(sha) = local.last_commit_sha.get(orchestration_id) {
+        shared
+            .last_commit_sha
+            .insert(orchestration_id.to_string(), sha.clone());
+    }
+    let phase = local
+        .worktrees
+        .iter()
+        .find(|w| w.orchestration_id == orchestration_id)
+        .map(|w| w.current_phase.clone());
+    if let Some(phase) = phase {
+        if let Some(worktree) = shared
+            .worktrees
+            .iter_mut()
+            .find(|w| w.orchestration_id == orchestration_id)
+        {
+            worktree.current_phase = phase;
+        }
     }
 }
 
@@ -216,50 +475,118 @@ async fn main() -> Result<()> {
     let cancel = CancellationToken::new();
 
     // Initialize telemetry (best-effort, no orchestration context at daemon level)
-    let telemetry = DaemonTelemetry::new(Arc::clone(&client));
+    let telemetry = Arc::new(DaemonTelemetry::new(Arc::clone(&client)));
+
+    // Start heartbeat (worktree paths are published once discovery runs)
+    let (worktree_paths_tx, worktree_paths_rx) = watch::channel(Vec::new());
+    let (clock_skew_tx, clock_skew_rx) = clock::channel();
+    let heartbeat_handle = heartbeat::spawn_heartbeat(
+        Arc::clone(&client),
+        node_id.clone(),
+        worktree_paths_rx,
+        clock_skew_tx,
+        cancel.clone(),
+    );
 
-    // Start heartbeat
-    let heartbeat_handle =
-        heartbeat::spawn_heartbeat(Arc::clone(&client), node_id.clone(), cancel.clone());
+    // Sync and the HTTP event stream both react to events published by
+    // the main loop
+    let bus = EventBus::default();
+
+    let webhooks = Arc::new(WebhookRegistry::new(webhooks::webhooks_path()));
+    let webhook_handle =
+        webhooks::spawn_dispatcher(Arc::clone(&client), Arc::clone(&webhooks), cancel.clone());
 
     // Start HTTP server (with Convex client for session persistence)
     let http_cancel = cancel.clone();
-    let http_handle = http::spawn_http_server_with_client(
+    let (git_hook_tx, mut git_hook_rx) = mpsc::unbounded_channel();
+    let (plan_hook_tx, mut plan_hook_rx) = mpsc::unbounded_channel();
+    let (watch_stats_tx, watch_stats_rx) = daemon_watcher::stats_channel();
+    let http_handle = http::spawn_http_server_with_state(
         config.http_port,
         http_cancel,
-        Some(Arc::clone(&client)),
+        http::AppState {
+            convex_client: Some(Arc::clone(&client)),
+            node_id: Some(node_id.clone()),
+            clock_skew: clock_skew_rx,
+            git_hooks: Some(git_hook_tx),
+            plan_hooks: Some(plan_hook_tx),
+            watch_stats: watch_stats_rx,
+            token_auth: Some(TokenAuth {
+                tokens_path: tina_session::tokens::tokens_path(),
+                require_token: config.require_token,
+            }),
+            rate_limiter: (config.mutations_per_minute > 0
+                || config.token_mutations_per_minute > 0)
+                .then(|| {
+                    Arc::new(
+                        RateLimiter::new(config.mutations_per_minute)
+                            .with_token_limit(config.token_mutations_per_minute),
+                    )
+                }),
+            summary_cache: SummaryCache::default(),
+            events: Some(bus.clone()),
+            send_text: config.send_text.clone(),
+            webhooks: Some(webhooks),
+            graphql: config.graphql.then(graphql::schema),
+        },
     )
     .await?;
 
+    // Start remote node mirrors (SSH + rsync)
+    let remote_handles: Vec<_> = config
+        .remotes
+        .iter()
+        .map(|remote_config| {
+            info!(remote = %remote_config.name, host = %remote_config.host, "starting remote node sync");
+            remote::spawn_remote_sync(
+                Arc::clone(&client),
+                remote_config.clone(),
+                auth_token_hash.clone(),
+                cancel.clone(),
+            )
+        })
+        .collect();
+
     // Set up file watchers
     let home = dirs::home_dir().expect("could not determine home directory");
     let teams_dir = home.join(".claude").join("teams");
     let tasks_dir = home.join(".claude").join("tasks");
 
-    let mut watcher = DaemonWatcher::new(&teams_dir, &tasks_dir)?;
-
-    // Initialize sync cache before startup sync/watcher operations.
-    let mut cache = SyncCache::new();
+    let mut watcher = DaemonWatcher::with_max_watches(&teams_dir, &tasks_dir, config.max_watches)?;
+
+    // Initialize sync cache before startup sync/watcher operations. Sync
+    // reacts to events published by the main loop.
+    let cache = Arc::new(Mutex::new(SyncCache::new()));
+    let mut sync_subscriber = SyncSubscriber {
+        client: Arc::clone(&client),
+        cache: Arc::clone(&cache),
+        telemetry: Arc::clone(&telemetry),
+        pool: SyncPool::new(config.sync_workers),
+        teams_dir: teams_dir.clone(),
+        tasks_dir: tasks_dir.clone(),
+    };
+    info!(workers = config.sync_workers, "sync worker pool ready");
 
     // Discover active worktrees and attach watchers before initial projection sync.
     info!("discovering active worktrees");
-    if let Err(e) = refresh_worktrees(&client, &mut cache, &mut watcher).await {
-        error!(error = %e, "worktree discovery failed, git and plan watching may be incomplete");
-    }
-
-    // Initial full sync
-    if let Err(e) = sync::sync_all(
-        &client,
-        &mut cache,
-        &teams_dir,
-        &tasks_dir,
-        Some(&telemetry),
-    )
-    .await
     {
-        error!(error = %e, "initial sync failed");
+        let mut cache = cache.lock().await;
+        if let Err(e) = refresh_worktrees(
+            &client,
+            &mut cache,
+            &mut watcher,
+            &worktree_paths_tx,
+            &watch_stats_tx,
+        )
+        .await
+        {
+            error!(error = %e, "worktree discovery failed, git and plan watching may be incomplete");
+        }
     }
-    sync_designs_for_worktrees(&client, &cache, &telemetry).await;
+
+    // Initial full sync, then hand sync over to the event bus
+    sync_subscriber.sync_projection().await;
+    let sync_handle = events::spawn_subscriber(&bus, sync_subscriber, cancel.clone());
 
     // Run crash-recovery reconciliation: mark terminal sessions whose tmux
     // panes no longer exist as ended, and log team members with dead panes.
@@ -271,6 +598,7 @@ async fn main() -> Result<()> {
                 members_with_dead_panes = result.members_with_dead_panes,
                 "startup reconciliation complete"
             );
+            bus.publish(DaemonEvent::Reconciled(result));
         }
         Err(e) => {
             error!(error = %e, "startup reconciliation failed");
@@ -285,6 +613,13 @@ async fn main() -> Result<()> {
         client_guard.subscribe_pending_actions(&node_id).await?
     };
 
+    // Subscribe to scheduled actions owned by this node (drives the timer wheel)
+    let mut scheduled_sub = {
+        let mut client_guard = client.lock().await;
+        client_guard.subscribe_scheduled_actions(&node_id).await?
+    };
+    let mut timers = TimerWheel::new();
+
     info!("daemon started, entering main loop");
 
     // Periodic reconciliation timer (every 60 seconds)
@@ -316,6 +651,7 @@ async fn main() -> Result<()> {
                                 "periodic reconciliation complete"
                             );
                         }
+                        bus.publish(DaemonEvent::Reconciled(result));
                     }
                     Err(e) => {
                         warn!(error = %e, "periodic reconciliation failed");
@@ -327,41 +663,29 @@ async fn main() -> Result<()> {
             event = watcher.rx.recv() => {
                 match event {
                     Some(WatchEvent::Teams) | Some(WatchEvent::Tasks) => {
+                        let mut cache = cache.lock().await;
                         if let Err(e) =
-                            refresh_worktrees(&client, &mut cache, &mut watcher).await
+                            refresh_worktrees(
+                                &client, &mut cache, &mut watcher, &worktree_paths_tx, &watch_stats_tx,
+                            ).await
                         {
                             error!(error = %e, "worktree refresh failed");
                         }
-                        if let Err(e) = sync::sync_all(
-                            &client, &mut cache, &teams_dir, &tasks_dir, Some(&telemetry),
-                        ).await {
-                            error!(error = %e, "sync failed");
-                        }
-                        sync_designs_for_worktrees(&client, &cache, &telemetry).await;
+                        bus.publish(DaemonEvent::ProjectionChanged);
                     }
                     Some(WatchEvent::GitRef(ref_path)) => {
                         // Git ref changed - sync commits for this worktree
-                        if let Some(worktree) = cache.find_worktree_by_ref_path(&ref_path).cloned() {
+                        let worktree = cache.lock().await.find_worktree_by_ref_path(&ref_path).cloned();
+                        if let Some(worktree) = worktree {
                             info!(
                                 feature = %worktree.feature,
                                 branch = %worktree.branch,
                                 "git ref changed, syncing commits"
                             );
-                            if let Err(e) = sync::sync_commits(
-                                &client,
-                                &mut cache,
-                                &worktree.orchestration_id,
-                                &worktree.current_phase,
-                                &worktree.worktree_path,
-                                &worktree.branch,
-                                Some(&telemetry),
-                            ).await {
-                                error!(
-                                    feature = %worktree.feature,
-                                    error = %e,
-                                    "failed to sync commits"
-                                );
-                            }
+                            bus.publish(DaemonEvent::CommitsChanged {
+                                worktree,
+                                trigger: CommitTrigger::RefWatcher,
+                            });
                         } else {
                             warn!(
                                 path = %ref_path.display(),
@@ -371,24 +695,14 @@ async fn main() -> Result<()> {
                     }
                     Some(WatchEvent::Plan(plan_path)) => {
                         // Plan file changed - sync to Convex
-                        if let Some(worktree) = cache.find_worktree_by_plan_path(&plan_path).cloned() {
+                        let worktree = cache.lock().await.find_worktree_by_plan_path(&plan_path).cloned();
+                        if let Some(worktree) = worktree {
                             info!(
                                 feature = %worktree.feature,
                                 path = %plan_path.display(),
                                 "plan file changed, syncing to Convex"
                             );
-                            if let Err(e) = sync::sync_plan(
-                                &client,
-                                &worktree.orchestration_id,
-                                &plan_path,
-                                Some(&telemetry),
-                            ).await {
-                                error!(
-                                    feature = %worktree.feature,
-                                    error = %e,
-                                    "failed to sync plan"
-                                );
-                            }
+                            bus.publish(DaemonEvent::PlanChanged { worktree, plan_path });
                         } else {
                             warn!(
                                 path = %plan_path.display(),
@@ -398,25 +712,14 @@ async fn main() -> Result<()> {
                     }
                     Some(WatchEvent::Design(design_path)) => {
                         // Design file changed - sync metadata to Convex
-                        if let Some(worktree) = cache.find_worktree_by_design_path(&design_path).cloned() {
+                        let worktree = cache.lock().await.find_worktree_by_design_path(&design_path).cloned();
+                        if let Some(worktree) = worktree {
                             info!(
                                 feature = %worktree.feature,
                                 path = %design_path.display(),
                                 "design file changed, syncing metadata"
                             );
-                            if let Err(e) = sync::sync_design_metadata(
-                                &client,
-                                &worktree.orchestration_id,
-                                worktree.project_id.as_deref(),
-                                &worktree.worktree_path,
-                                Some(&telemetry),
-                            ).await {
-                                error!(
-                                    feature = %worktree.feature,
-                                    error = %e,
-                                    "failed to sync design metadata"
-                                );
-                            }
+                            bus.publish(DaemonEvent::DesignChanged { worktree, path: design_path });
                         } else {
                             warn!(
                                 path = %design_path.display(),
@@ -432,11 +735,86 @@ async fn main() -> Result<()> {
                 }
             }
 
+            // Git hooks (installed by `tina-session hooks install`) fire
+            // immediately, ahead of the ref watcher's debounce.
+            Some(worktree_path) = git_hook_rx.recv() => {
+                let worktree = cache.lock().await.find_worktree_by_path(&worktree_path).cloned();
+                if let Some(worktree) = worktree {
+                    info!(
+                        feature = %worktree.feature,
+                        branch = %worktree.branch,
+                        "git hook fired, syncing commits"
+                    );
+                    bus.publish(DaemonEvent::CommitsChanged {
+                        worktree,
+                        trigger: CommitTrigger::GitHook,
+                    });
+                } else {
+                    debug!(
+                        path = %worktree_path.display(),
+                        "git hook fired for a worktree without an active orchestration"
+                    );
+                }
+            }
+
+            // Plans edited from tina-monitor, re-synced without waiting on
+            // the file watcher
+            Some(plan_path) = plan_hook_rx.recv() => {
+                let worktree = cache.lock().await.find_worktree_by_plan_path(&plan_path).cloned();
+                if let Some(worktree) = worktree {
+                    info!(
+                        feature = %worktree.feature,
+                        path = %plan_path.display(),
+                        "plan resync requested, syncing to Convex"
+                    );
+                    bus.publish(DaemonEvent::PlanChanged { worktree, plan_path });
+                } else {
+                    debug!(
+                        path = %plan_path.display(),
+                        "plan resync requested for a plan outside any active worktree"
+                    );
+                }
+            }
+
+            // Scheduled actions that have come due
+            _ = schedule::sleep_until_deadline(timers.next_deadline()) => {
+                let now_ms = chrono::Utc::now().timestamp_millis();
+                for action in timers.pop_due(now_ms) {
+                    let result = actions::run_scheduled_action(&client, &action).await;
+                    if let Err(e) = &result {
+                        error!(action_id = %action.id, error = %e, "failed to run scheduled action");
+                    }
+                    bus.publish(action_finished(&action, &result));
+                }
+            }
+
+            // Scheduled action set from Convex subscription
+            result = scheduled_sub.next() => {
+                match result {
+                    Some(FunctionResult::Value(value)) => match parse_inbound_actions(&value) {
+                        Ok(scheduled) => {
+                            timers.replace_all(scheduled);
+                            info!(count = timers.len(), "scheduled actions updated");
+                        }
+                        Err(e) => error!(error = %e, "failed to parse scheduled actions"),
+                    },
+                    Some(FunctionResult::ErrorMessage(msg)) => {
+                        error!(error = %msg, "scheduled actions query error");
+                    }
+                    Some(FunctionResult::ConvexError(err)) => {
+                        error!(error = ?err, "scheduled actions convex error");
+                    }
+                    None => {
+                        warn!("scheduled action subscription ended, timers will not refresh");
+                    }
+                }
+            }
+
             // Inbound actions from Convex subscription
             result = action_sub.next() => {
                 match result {
                     Some(FunctionResult::Value(value)) => {
-                        if let Err(e) = handle_pending_actions(&client, &value).await {
+                        if let Err(e) = handle_pending_actions(&client, &bus, &value).await {
                             error!(error = %e, "failed to handle pending actions");
                         }
                     }
@@ -456,7 +834,12 @@ async fn main() -> Result<()> {
 
     // Clean shutdown
     heartbeat_handle.abort();
+    webhook_handle.abort();
     http_handle.abort();
+    sync_handle.abort();
+    for handle in remote_handles {
+        handle.abort();
+    }
     info!("daemon stopped");
     Ok(())
 }
@@ -464,17 +847,34 @@ async fn main() -> Result<()> {
 /// Parse pending actions from a Convex subscription result and dispatch each one.
 async fn handle_pending_actions(
     client: &Arc<Mutex<TinaConvexClient>>,
+    bus: &EventBus,
     value: &Value,
 ) -> Result<()> {
     let actions = parse_inbound_actions(value)?;
     for action in &actions {
-        if let Err(e) = actions::dispatch_action(client, action).await {
+        if action.is_scheduled() {
+            if let Err(e) = actions::schedule_action(client, action).await {
+                error!(action_id = %action.id, error = %e, "failed to schedule action");
+            }
+            continue;
+        }
+        let result = actions::dispatch_action(client, action).await;
+        if let Err(e) = &result {
             error!(action_id = %action.id, error = %e, "failed to dispatch action");
         }
+        bus.publish(action_finished(action, &result));
     }
     Ok(())
 }
 
+fn action_finished<T>(action: &InboundAction, result: &Result<T>) -> DaemonEvent {
+    DaemonEvent::ActionFinished {
+        action_id: action.id.clone(),
+        action_type: action.action_type.clone(),
+        error: result.as_ref().err().map(|e| e.to_string()),
+    }
+}
+
 /// Parse a Convex Value (expected array of objects) into InboundAction structs.
 fn parse_inbound_actions(value: &Value) -> Result<Vec<InboundAction>> {
     let Value::Array(items) = value else {
@@ -514,6 +914,15 @@ fn parse_inbound_actions(value: &Value) -> Result<Vec<InboundAction>> {
             Some(Value::Int64(i)) => *i as f64,
             _ => 0.0,
         };
+        let run_at = match map.get("runAt") {
+            Some(Value::Float64(f)) => Some(*f),
+            Some(Value::Int64(i)) => Some(*i as f64),
+            _ => None,
+        };
+        let cron_schedule = match map.get("cronSchedule") {
+            Some(Value::String(s)) => Some(s.clone()),
+            _ => None,
+        };
 
         actions.push(InboundAction {
             id,
@@ -523,6 +932,8 @@ fn parse_inbound_actions(value: &Value) -> Result<Vec<InboundAction>> {
             payload,
             status,
             created_at,
+            run_at,
+            cron_schedule,
         });
     }
     Ok(actions)
@@ -0,0 +1,152 @@
+//! Host resource metrics reported with each heartbeat.
+//!
+//! Collects CPU load, memory, free disk on the worktree volume, and the number
+//! of live `tina-*` tmux sessions so the dashboard can flag nodes that are
+//! running out of headroom mid-orchestration.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use sysinfo::{Disks, System};
+
+use tina_data::NodeMetrics;
+
+/// Prefix shared by orchestration and phase tmux sessions.
+const TINA_SESSION_PREFIX: &str = "tina-";
+
+/// Free and total space of one mounted volume.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeSpace {
+    pub mount_point: PathBuf,
+    pub free_bytes: u64,
+    pub total_bytes: u64,
+}
+
+/// Count tina sessions in `tmux list-sessions -F '#{session_name}'` output.
+pub fn count_tina_sessions(output: &str) -> usize {
+    output
+        .lines()
+        .filter(|line| line.trim().starts_with(TINA_SESSION_PREFIX))
+        .count()
+}
+
+/// Find the volume holding `path` (longest matching mount point).
+pub fn volume_for_path<'a>(volumes: &'a [VolumeSpace], path: &Path) -> Option<&'a VolumeSpace> {
+    volumes
+        .iter()
+        .filter(|v| path.starts_with(&v.mount_point))
+        .max_by_key(|v| v.mount_point.as_os_str().len())
+}
+
+/// Pick the most constrained volume among those holding `paths`.
+///
+/// Worktrees can be spread across volumes; the one with the least free space
+/// is the one that will fill up first.
+pub fn tightest_volume<'a>(
+    volumes: &'a [VolumeSpace],
+    paths: &[PathBuf],
+) -> Option<&'a VolumeSpace> {
+    paths
+        .iter()
+        .filter_map(|p| volume_for_path(volumes, p))
+        .min_by_key(|v| v.free_bytes)
+}
+
+fn list_volumes() -> Vec<VolumeSpace> {
+    Disks::new_with_refreshed_list()
+        .list()
+        .iter()
+        .map(|disk| VolumeSpace {
+            mount_point: disk.mount_point().to_path_buf(),
+            free_bytes: disk.available_space(),
+            total_bytes: disk.total_space(),
+        })
+        .collect()
+}
+
+/// Count live tina tmux sessions. Returns 0 when tmux is missing or not running.
+fn tina_session_count() -> usize {
+    match Command::new("tmux")
+        .args(["list-sessions", "-F", "#{session_name}"])
+        .output()
+    {
+        Ok(out) if out.status.success() => {
+            count_tina_sessions(&String::from_utf8_lossy(&out.stdout))
+        }
+        _ => 0,
+    }
+}
+
+/// Collect current host metrics (blocking — call from `spawn_blocking`).
+///
+/// Disk space is measured on the volume holding `worktree_paths`, falling back
+/// to the home directory when no worktrees are active.
+pub fn collect_blocking(worktree_paths: &[PathBuf]) -> NodeMetrics {
+    let mut system = System::new();
+    system.refresh_memory();
+
+    let paths = if worktree_paths.is_empty() {
+        dirs::home_dir().into_iter().collect()
+    } else {
+        worktree_paths.to_vec()
+    };
+    let volumes = list_volumes();
+    let (disk_free, disk_total) = tightest_volume(&volumes, &paths)
+        .map(|v| (v.free_bytes, v.total_bytes))
+        .unwrap_or((0, 0));
+
+    let cpu_count = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+
+    NodeMetrics {
+        cpu_load: System::load_average().one,
+        cpu_count: cpu_count as f64,
+        memory_used_bytes: system.used_memory() as f64,
+        memory_total_bytes: system.total_memory() as f64,
+        disk_free_bytes: disk_free as f64,
+        disk_total_bytes: disk_total as f64,
+        tmux_sessions: tina_session_count() as f64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn volume(mount: &str, free: u64) -> VolumeSpace {
+        VolumeSpace {
+            mount_point: PathBuf::from(mount),
+            free_bytes: free,
+            total_bytes: 1000,
+        }
+    }
+
+    #[test]
+    fn test_count_tina_sessions_ignores_other_sessions() {
+        let output = "tina-auth-orchestration\nmain\ntina-auth-phase-1\nscratch\n";
+        assert_eq!(count_tina_sessions(output), 2);
+        assert_eq!(count_tina_sessions(""), 0);
+    }
+
+    #[test]
+    fn test_volume_for_path_prefers_longest_mount() {
+        let volumes = vec![volume("/", 500), volume("/Volumes/work", 100)];
+        let v = volume_for_path(&volumes, Path::new("/Volumes/work/repo/.worktrees/feat")).unwrap();
+        assert_eq!(v.mount_point, PathBuf::from("/Volumes/work"));
+
+        let v = volume_for_path(&volumes, Path::new("/Users/me/repo")).unwrap();
+        assert_eq!(v.mount_point, PathBuf::from("/"));
+    }
+
+    #[test]
+    fn test_tightest_volume_picks_least_free_space() {
+        let volumes = vec![volume("/", 500), volume("/Volumes/work", 100)];
+        let paths = vec![
+            PathBuf::from("/Users/me/repo/.worktrees/a"),
+            PathBuf::from("/Volumes/work/repo/.worktrees/b"),
+        ];
+        assert_eq!(tightest_volume(&volumes, &paths).unwrap().free_bytes, 100);
+        assert!(tightest_volume(&volumes, &[]).is_none());
+    }
+}
@@ -0,0 +1,67 @@
+//! The HTTP API's OpenAPI description.
+//!
+//! GET /api/openapi.json serves `openapi.json` from the crate root, which is
+//! written by hand next to the handlers; GET /api/docs renders it with
+//! Swagger UI. Both are reachable without a token. A test checks that every
+//! route registered in [`crate::http`] is described, so adding an endpoint
+//! without documenting it fails the tests.
+
+use axum::http::header;
+use axum::response::{Html, IntoResponse};
+
+/// The OpenAPI 3.1 document for the routes in [`crate::http`].
+pub const OPENAPI_JSON: &str = include_str!("../openapi.json");
+
+const DOCS_HTML: &str = r##"<!doctype html>
+<html lang="en">
+<head>
+  <meta charset="utf-8">
+  <title>tina-daemon API</title>
+  <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css">
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js"></script>
+  <script>
+    SwaggerUIBundle({ url: "/api/openapi.json", dom_id: "#swagger-ui" });
+  </script>
+</body>
+</html>
+"##;
+
+/// GET /api/openapi.json handler.
+pub async fn get_openapi() -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, "application/json")], OPENAPI_JSON)
+}
+
+/// GET /api/docs handler.
+pub async fn get_docs() -> Html<&'static str> {
+    Html(DOCS_HTML)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_route_is_documented() {
+        let spec: serde_json::Value = serde_json::from_str(OPENAPI_JSON).unwrap();
+        assert_eq!(spec["openapi"], "3.1.0");
+        assert_eq!(spec["info"]["version"], env!("CARGO_PKG_VERSION"));
+
+        let route =
+            regex::Regex::new(r#"\.route\(\s*"([^"]+)",\s*(get|post|patch|delete)\("#).unwrap();
+        let source = include_str!("http.rs");
+        let routes: Vec<_> = route.captures_iter(source).collect();
+        assert!(routes.len() > 20, "route pattern no longer matches http.rs");
+        for captures in routes {
+            let (path, method) = (&captures[1], &captures[2]);
+            assert!(
+                spec["paths"][path][method].is_object(),
+                "{} {} is missing from openapi.json",
+                method.to_uppercase(),
+                path
+            );
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Paginated orchestration listing, archiving, and deletion.
+//!
+//! GET /api/orchestrations?limit=&cursor= returns one page, newest first,
+//! with an opaque `nextCursor` for the following page. Cursors are keyset
+//! based (see `listOrchestrationsPage` in `convex/orchestrations.ts`), so
+//! new orchestrations don't shift pages a client is already scrolling.
+//! Archived orchestrations are left out unless `include_archived=true`.
+//!
+//! Pages can be narrowed with `status`, `project_id`, and an exclusive
+//! `started_after`/`started_before` range (RFC 3339), and
+//! `sort=oldest` reverses the order. Keep the same filters and sort when
+//! following a cursor. `count=true` adds `totalCount` to the first page;
+//! counting reads every matching orchestration, so pollers should leave it
+//! off.
+//!
+//! DELETE /api/orchestrations/{id} archives an orchestration; with
+//! `?hard=true` it deletes it and everything recorded for it instead.
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use tina_data::humanize::{format_duration_mins, format_relative_rfc3339};
+use tina_data::{OrchestrationFilter, OrchestrationListEntry};
+
+use crate::http::AppState;
+
+type ApiError = (StatusCode, String);
+
+pub const DEFAULT_PAGE_SIZE: usize = 50;
+pub const MAX_PAGE_SIZE: usize = 500;
+
+/// Order of a listing by time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    Newest,
+    Oldest,
+}
+
+/// Parse an RFC 3339 time filter and restate it in UTC, the form times are
+/// stored in: Convex compares them as strings, so an offset like `+02:00`
+/// would otherwise filter the wrong range.
+pub fn utc_time(name: &str, value: Option<&String>) -> Result<Option<String>, ApiError> {
+    value
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|time| time.with_timezone(&Utc).to_rfc3339())
+                .map_err(|_| {
+                    (
+                        StatusCode::BAD_REQUEST,
+                        format!("{} must be an RFC 3339 time, got {:?}", name, value),
+                    )
+                })
+        })
+        .transpose()
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct PageParams {
+    pub limit: Option<usize>,
+    pub cursor: Option<String>,
+    pub include_archived: Option<bool>,
+    pub status: Option<String>,
+    pub project_id: Option<String>,
+    pub started_after: Option<String>,
+    pub started_before: Option<String>,
+    pub sort: Option<SortOrder>,
+    pub count: Option<bool>,
+}
+
+impl PageParams {
+    /// Requested page size, clamped to `1..=MAX_PAGE_SIZE`.
+    pub fn page_size(&self) -> usize {
+        self.limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE)
+    }
+
+    /// The Convex filter these params ask for.
+    pub fn filter(&self) -> Result<OrchestrationFilter, ApiError> {
+        let started_after = utc_time("started_after", self.started_after.as_ref())?;
+        let started_before = utc_time("started_before", self.started_before.as_ref())?;
+        Ok(OrchestrationFilter {
+            include_archived: self.include_archived.unwrap_or(false),
+            status: self.status.clone(),
+            project_id: self.project_id.clone(),
+            started_after,
+            started_before,
+            oldest_first: self.sort == Some(SortOrder::Oldest),
+            count: self.count.unwrap_or(false),
+        })
+    }
+}
+
+/// Response body for GET /api/orchestrations.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrchestrationPageResponse {
+    pub items: Vec<OrchestrationItem>,
+    pub next_cursor: Option<String>,
+    /// Total orchestrations; only present on the first page, with `count=true`.
+    pub total_count: Option<usize>,
+}
+
+/// A listed orchestration with human-readable times alongside the raw ones.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrchestrationItem {
+    #[serde(flatten)]
+    pub entry: OrchestrationListEntry,
+    /// Elapsed time, e.g. "2h 14m"
+    pub elapsed_human: Option<String>,
+    /// Start time relative to now, e.g. "3 hours ago"
+    pub started_at_human: Option<String>,
+}
+
+impl OrchestrationItem {
+    pub fn new(entry: OrchestrationListEntry, now: DateTime<Utc>) -> Self {
+        Self {
+            elapsed_human: entry.record.elapsed_mins(now).map(format_duration_mins),
+            started_at_human: format_relative_rfc3339(&entry.record.started_at, now),
+            entry,
+        }
+    }
+}
+
+/// GET /api/orchestrations handler.
+pub async fn get_orchestrations(
+    State(state): State<AppState>,
+    Query(params): Query<PageParams>,
+) -> Result<Json<OrchestrationPageResponse>, ApiError> {
+    let client = state.convex()?;
+    let filter = params.filter()?;
+
+    let page = client
+        .lock()
+        .await
+        .list_orchestrations_page(params.page_size(), params.cursor.as_deref(), &filter)
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+
+    let now = Utc::now();
+    Ok(Json(OrchestrationPageResponse {
+        items: page
+            .entries
+            .into_iter()
+            .map(|entry| OrchestrationItem::new(entry, now))
+            .collect(),
+        next_cursor: page.next_cursor,
+        total_count: page.total_count,
+    }))
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct DeleteParams {
+    /// Delete outright rather than archive
+    pub hard: Option<bool>,
+}
+
+/// Response body for DELETE /api/orchestrations/{id}.
+#[derive(Debug, Serialize)]
+pub struct DeleteResponse {
+    pub id: String,
+    /// `archived` or `deleted`
+    pub outcome: &'static str,
+}
+
+/// DELETE /api/orchestrations/{id} handler.
+pub async fn delete_orchestration(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(params): Query<DeleteParams>,
+) -> Result<Json<DeleteResponse>, ApiError> {
+    let client = state.convex()?;
+
+    let hard = params.hard.unwrap_or(false);
+    let mut client = client.lock().await;
+    let found = if hard {
+        client.delete_orchestration(&id).await
+    } else {
+        client.set_orchestration_archived(&id, true).await
+    }
+    .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+
+    if !found {
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!("Orchestration not found: {}", id),
+        ));
+    }
+    Ok(Json(DeleteResponse {
+        id,
+        outcome: if hard { "deleted" } else { "archived" },
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tina_data::test_util::list_entry;
+
+    #[test]
+    fn test_page_size_is_clamped() {
+        let params = |limit| PageParams {
+            limit,
+            ..Default::default()
+        };
+        assert_eq!(params(None).page_size(), DEFAULT_PAGE_SIZE);
+        assert_eq!(params(Some(0)).page_size(), 1);
+        assert_eq!(params(Some(20)).page_size(), 20);
+        assert_eq!(params(Some(10_000)).page_size(), MAX_PAGE_SIZE);
+    }
+
+    #[test]
+    fn test_filter_from_params() {
+        let params: PageParams = serde_json::from_value(serde_json::json!({
+            "status": "executing",
+            "started_after": "2026-02-01T00:00:00Z",
+            "sort": "oldest",
+            "count": true,
+        }))
+        .unwrap();
+        assert_eq!(
+            params.filter().unwrap(),
+            OrchestrationFilter {
+                status: Some("executing".to_string()),
+                started_after: Some("2026-02-01T00:00:00+00:00".to_string()),
+                oldest_first: true,
+                count: true,
+                ..Default::default()
+            }
+        );
+
+        let params = PageParams {
+            started_before: Some("2026-02-07T12:00:00+02:00".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            params.filter().unwrap().started_before.as_deref(),
+            Some("2026-02-07T10:00:00+00:00")
+        );
+
+        let params = PageParams {
+            started_before: Some("yesterday".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(params.filter().unwrap_err().0, StatusCode::BAD_REQUEST);
+        assert!(serde_json::from_value::<PageParams>(serde_json::json!({"sort": "up"})).is_err());
+    }
+
+    #[test]
+    fn test_item_adds_human_times() {
+        let now = DateTime::parse_from_rfc3339("2026-02-07T12:14:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut entry = list_entry("orch-1", "executing", "2026-02-07T10:00:00Z");
+
+        let json = serde_json::to_value(OrchestrationItem::new(entry.clone(), now)).unwrap();
+        assert_eq!(json["feature_name"], "auth");
+        assert_eq!(json["elapsedHuman"], "2h 14m");
+        assert_eq!(json["startedAtHuman"], "2 hours ago");
+
+        entry.record.completed_at = Some("2026-02-07T10:45:00Z".to_string());
+        entry.record.total_elapsed_mins = Some(45.0);
+        let item = OrchestrationItem::new(entry, now);
+        assert_eq!(item.elapsed_human.as_deref(), Some("45m"));
+    }
+}
@@ -0,0 +1,225 @@
+//! Per-phase commits and diffs.
+//!
+//! GET /api/orchestrations/{id}/phases/{phase}/commits
+//! GET /api/orchestrations/{id}/phases/{phase}/diff
+//!
+//! Commits come from what the daemon synced to Convex; when the worktree is
+//! on this machine each one also gets its author and line counts from git.
+//! The diff lists the files the phase changed, using the phase's recorded
+//! `git_range`, or the span of its synced commits before the range is
+//! recorded, so it needs the worktree locally and 404s elsewhere.
+
+use std::path::{Path as FsPath, PathBuf};
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Serialize;
+
+use tina_data::{CommitRecord, OrchestrationDetailResponse};
+use tina_session::ignore::IgnoreRules;
+use tina_session::state::schema::SupervisorState;
+
+use crate::git::{self, DiffFileStat};
+use crate::http::AppState;
+
+type ApiError = (StatusCode, String);
+
+/// A commit made during a phase.
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PhaseCommit {
+    pub sha: String,
+    pub short_sha: Option<String>,
+    pub subject: Option<String>,
+    pub timestamp: Option<String>,
+    /// Only known when the worktree is on this machine
+    pub author: Option<String>,
+    pub insertions: Option<u32>,
+    pub deletions: Option<u32>,
+}
+
+impl From<CommitRecord> for PhaseCommit {
+    fn from(commit: CommitRecord) -> Self {
+        Self {
+            sha: commit.sha,
+            short_sha: commit.short_sha,
+            subject: commit.subject,
+            timestamp: commit.timestamp,
+            author: None,
+            insertions: None,
+            deletions: None,
+        }
+    }
+}
+
+/// Response body for GET .../phases/{phase}/diff.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhaseDiffResponse {
+    /// Range the files were diffed over; `None` if the phase has no commits
+    pub range: Option<String>,
+    pub files: Vec<DiffFileStat>,
+    pub insertions: u32,
+    pub deletions: u32,
+}
+
+/// The revision range a phase covers: its recorded `git_range` when that is
+/// a plain `sha..sha`, otherwise from before its oldest synced commit to its
+/// newest. `commits` are newest first, as Convex lists them.
+pub fn phase_range(git_range: Option<&str>, commits: &[CommitRecord]) -> Option<String> {
+    if let Some((from, to)) = git_range.and_then(|range| range.split_once("..")) {
+        if git::is_valid_sha(from) && git::is_valid_sha(to) {
+            return Some(format!("{}..{}", from, to));
+        }
+    }
+    let newest = commits.first().filter(|c| git::is_valid_sha(&c.sha))?;
+    let oldest = commits.last().filter(|c| git::is_valid_sha(&c.sha))?;
+    Some(format!("{}^..{}", oldest.sha, newest.sha))
+}
+
+/// The orchestration's worktree, if it is on this machine.
+fn local_worktree(detail: &OrchestrationDetailResponse) -> Option<PathBuf> {
+    detail
+        .record
+        .worktree_path
+        .as_deref()
+        .map(FsPath::new)
+        .filter(|root| root.join(".git").exists())
+        .map(FsPath::to_path_buf)
+}
+
+async fn load(
+    state: &AppState,
+    orchestration_id: &str,
+    phase: &str,
+) -> Result<(OrchestrationDetailResponse, Vec<CommitRecord>), ApiError> {
+    let client = state.convex()?;
+
+    let mut client = client.lock().await;
+    let detail = client
+        .get_orchestration_detail(orchestration_id)
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("Orchestration not found: {}", orchestration_id),
+        ))?;
+    if !detail.phases.iter().any(|p| p.phase_number == phase) {
+        return Err((StatusCode::NOT_FOUND, format!("Phase {} not found", phase)));
+    }
+    let commits = client
+        .list_commits(orchestration_id, Some(phase))
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+    Ok((detail, commits))
+}
+
+/// GET /api/orchestrations/{id}/phases/{phase}/commits handler.
+pub async fn get_phase_commits(
+    State(state): State<AppState>,
+    Path((orchestration_id, phase)): Path<(String, String)>,
+) -> Result<Json<Vec<PhaseCommit>>, ApiError> {
+    let (detail, commits) = load(&state, &orchestration_id, &phase).await?;
+    let mut commits: Vec<PhaseCommit> = commits.into_iter().map(PhaseCommit::from).collect();
+
+    if let Some(worktree) = local_worktree(&detail) {
+        let shas: Vec<String> = commits
+            .iter()
+            .map(|c| c.sha.clone())
+            .filter(|sha| git::is_valid_sha(sha))
+            .collect();
+        let lookup =
+            tokio::task::spawn_blocking(move || git::get_commit_details_by_sha(&worktree, &shas))
+                .await
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        for commit in &mut commits {
+            if let Some(local) = lookup.commits.iter().find(|c| c.sha == commit.sha) {
+                commit.author = Some(local.author.clone());
+                commit.insertions = Some(local.insertions);
+                commit.deletions = Some(local.deletions);
+            }
+        }
+    }
+    Ok(Json(commits))
+}
+
+/// GET /api/orchestrations/{id}/phases/{phase}/diff handler.
+pub async fn get_phase_diff(
+    State(state): State<AppState>,
+    Path((orchestration_id, phase)): Path<(String, String)>,
+) -> Result<Json<PhaseDiffResponse>, ApiError> {
+    let (detail, commits) = load(&state, &orchestration_id, &phase).await?;
+    let worktree = local_worktree(&detail).ok_or((
+        StatusCode::NOT_FOUND,
+        "The orchestration's worktree is not on this machine".to_string(),
+    ))?;
+    let git_range = detail
+        .phases
+        .iter()
+        .find(|p| p.phase_number == phase)
+        .and_then(|p| p.git_range.as_deref());
+    let Some(range) = phase_range(git_range, &commits) else {
+        return Ok(Json(PhaseDiffResponse {
+            range: None,
+            files: Vec::new(),
+            insertions: 0,
+            deletions: 0,
+        }));
+    };
+
+    let diffed = range.clone();
+    let files = tokio::task::spawn_blocking(move || {
+        let scope = SupervisorState::local_scope(&worktree);
+        let mut files = git::get_range_file_list(&worktree, &diffed, scope.as_deref())?;
+        let ignore = IgnoreRules::load(&worktree);
+        files.retain(|f| !ignore.is_ignored(FsPath::new(&f.path), false));
+        anyhow::Ok(files)
+    })
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(PhaseDiffResponse {
+        range: Some(range),
+        insertions: files.iter().map(|f| f.insertions).sum(),
+        deletions: files.iter().map(|f| f.deletions).sum(),
+        files,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commit(sha: &str) -> CommitRecord {
+        CommitRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: "1".to_string(),
+            sha: sha.to_string(),
+            short_sha: None,
+            subject: None,
+            timestamp: None,
+        }
+    }
+
+    #[test]
+    fn test_phase_range_prefers_recorded_range() {
+        let commits = [commit("ccc333"), commit("bbb222"), commit("aaa111")];
+        assert_eq!(
+            phase_range(Some("abc123..def456"), &commits).as_deref(),
+            Some("abc123..def456")
+        );
+        // Anything but sha..sha is ignored rather than handed to git
+        assert_eq!(
+            phase_range(Some("--output=/tmp/x..HEAD"), &commits).as_deref(),
+            Some("aaa111^..ccc333")
+        );
+        assert_eq!(
+            phase_range(None, &commits).as_deref(),
+            Some("aaa111^..ccc333")
+        );
+        assert_eq!(phase_range(None, &[]), None);
+    }
+}
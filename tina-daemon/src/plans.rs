@@ -0,0 +1,278 @@
+//! Phase plans.
+//!
+//! GET /api/orchestrations/{id}/phases/{phase}/plan returns the plan the
+//! daemon last synced for a phase: its markdown as written, when it was
+//! synced, and the parts the web UI lays out itself, namely each
+//! `### Task N: Title` section with its model, review mode, and
+//! dependencies, and the rows of the `## Phase Estimates` table. Rows whose
+//! first cell names a task also set that task's `estimate`.
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use serde::Serialize;
+
+use crate::http::AppState;
+
+type ApiError = (StatusCode, String);
+
+/// The parts of a plan's markdown the UI renders as structure.
+#[derive(Debug, Default, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanStructure {
+    /// The `# ` heading
+    pub title: Option<String>,
+    pub goal: Option<String>,
+    pub tasks: Vec<PlanTask>,
+    pub estimates: Vec<PlanEstimate>,
+}
+
+#[derive(Debug, Default, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanTask {
+    pub number: String,
+    pub title: String,
+    pub model: Option<String>,
+    pub review: Option<String>,
+    /// Task numbers; empty for `none`
+    pub depends_on: Vec<String>,
+    pub estimate: Option<String>,
+}
+
+/// A row of the `## Phase Estimates` table.
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanEstimate {
+    pub item: String,
+    pub estimate: String,
+    /// Any further columns, joined with ` | `
+    pub notes: Option<String>,
+}
+
+/// Response body for GET .../phases/{phase}/plan.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanResponse {
+    pub orchestration_id: String,
+    pub phase: String,
+    pub plan_path: String,
+    pub markdown: String,
+    pub last_synced: Option<String>,
+    #[serde(flatten)]
+    pub structure: PlanStructure,
+}
+
+fn field<'a>(line: &'a str, name: &str) -> Option<&'a str> {
+    line.strip_prefix("**")?
+        .strip_prefix(name)?
+        .strip_prefix(":**")
+        .map(str::trim)
+}
+
+fn table_cells(line: &str) -> Option<Vec<&str>> {
+    let inner = line.strip_prefix('|')?;
+    let inner = inner.strip_suffix('|').unwrap_or(inner);
+    Some(inner.split('|').map(str::trim).collect())
+}
+
+/// Whether an estimate row's first cell, e.g. `Task 3: Wire routes`, names
+/// task `number`.
+fn names_task(item: &str, number: &str) -> bool {
+    item.strip_prefix("Task ")
+        .and_then(|rest| rest.strip_prefix(number))
+        .is_some_and(|rest| !rest.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '.'))
+}
+
+/// Pull the task list and estimates out of plan markdown. Anything inside
+/// fenced code blocks is skipped.
+pub fn parse_plan(markdown: &str) -> PlanStructure {
+    let mut plan = PlanStructure::default();
+    let mut in_fence = false;
+    let mut in_estimates = false;
+    let mut seen_header = false;
+
+    for line in markdown.lines() {
+        let line = line.trim();
+        if line.starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        if let Some(heading) = line.strip_prefix("## ") {
+            in_estimates = heading.trim() == "Phase Estimates";
+            seen_header = false;
+            continue;
+        }
+        if let Some(title) = line.strip_prefix("# ") {
+            plan.title.get_or_insert_with(|| title.trim().to_string());
+            continue;
+        }
+        if let Some(heading) = line.strip_prefix("### Task ") {
+            let (number, title) = heading.split_once(':').unwrap_or((heading, ""));
+            plan.tasks.push(PlanTask {
+                number: number.trim().to_string(),
+                title: title.trim().to_string(),
+                ..Default::default()
+            });
+            continue;
+        }
+
+        if in_estimates {
+            let Some(cells) = table_cells(line) else {
+                continue;
+            };
+            let separator = cells
+                .iter()
+                .all(|cell| !cell.is_empty() && cell.chars().all(|c| matches!(c, '-' | ':')));
+            if !seen_header {
+                seen_header = true;
+            } else if !separator && cells.len() >= 2 {
+                let notes = cells[2..].join(" | ");
+                plan.estimates.push(PlanEstimate {
+                    item: cells[0].to_string(),
+                    estimate: cells[1].to_string(),
+                    notes: (!notes.is_empty()).then_some(notes),
+                });
+            }
+            continue;
+        }
+
+        if let Some(goal) = field(line, "Goal") {
+            if plan.tasks.is_empty() {
+                plan.goal.get_or_insert_with(|| goal.to_string());
+            }
+        } else if let Some(task) = plan.tasks.last_mut() {
+            if let Some(model) = field(line, "Model") {
+                task.model = Some(model.to_string());
+            } else if let Some(review) = field(line, "review") {
+                task.review = Some(review.to_string());
+            } else if let Some(deps) = field(line, "Depends on") {
+                task.depends_on = deps
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|dep| !dep.is_empty() && !dep.eq_ignore_ascii_case("none"))
+                    .map(str::to_string)
+                    .collect();
+            }
+        }
+    }
+
+    for task in &mut plan.tasks {
+        task.estimate = plan
+            .estimates
+            .iter()
+            .find(|row| names_task(&row.item, &task.number))
+            .map(|row| row.estimate.clone());
+    }
+    plan
+}
+
+/// GET /api/orchestrations/{id}/phases/{phase}/plan handler.
+pub async fn get_plan(
+    State(state): State<AppState>,
+    Path((orchestration_id, phase)): Path<(String, String)>,
+) -> Result<Json<PlanResponse>, ApiError> {
+    let client = state.convex()?;
+
+    let plan = client
+        .lock()
+        .await
+        .get_plan(&orchestration_id, &phase)
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("No plan synced for phase {}", phase),
+        ))?;
+    Ok(Json(PlanResponse {
+        structure: parse_plan(&plan.content),
+        orchestration_id,
+        phase: plan.phase_number,
+        plan_path: plan.plan_path,
+        markdown: plan.content,
+        last_synced: plan.last_synced,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAN: &str = "\
+# Auth Phase 1: Sessions
+
+**Goal:** Persist sessions across restarts.
+
+### Task 1: Add the sessions table
+
+**Model:** opus
+
+**review:** full
+
+**Depends on:** none
+
+```md
+### Task 9: Not a task
+```
+
+### Task 2: Wire the login route
+
+**Model:** haiku
+
+**Depends on:** 1
+
+## Phase Estimates
+
+| Task | Estimated Time | Notes |
+|------|---------------|-------|
+| Task 1: Sessions table | 3 min | Single file |
+| Task 2: Login route | 5 min | |
+| Total | 8 min | |
+";
+
+    #[test]
+    fn test_parse_plan_tasks_and_estimates() {
+        let plan = parse_plan(PLAN);
+        assert_eq!(plan.title.as_deref(), Some("Auth Phase 1: Sessions"));
+        assert_eq!(
+            plan.goal.as_deref(),
+            Some("Persist sessions across restarts.")
+        );
+        assert_eq!(
+            plan.tasks,
+            [
+                PlanTask {
+                    number: "1".to_string(),
+                    title: "Add the sessions table".to_string(),
+                    model: Some("opus".to_string()),
+                    review: Some("full".to_string()),
+                    depends_on: vec![],
+                    estimate: Some("3 min".to_string()),
+                },
+                PlanTask {
+                    number: "2".to_string(),
+                    title: "Wire the login route".to_string(),
+                    model: Some("haiku".to_string()),
+                    review: None,
+                    depends_on: vec!["1".to_string()],
+                    estimate: Some("5 min".to_string()),
+                },
+            ]
+        );
+        assert_eq!(plan.estimates.len(), 3);
+        assert_eq!(plan.estimates[0].notes.as_deref(), Some("Single file"));
+        assert_eq!(plan.estimates[2].item, "Total");
+        assert_eq!(plan.estimates[2].notes, None);
+    }
+
+    #[test]
+    fn test_names_task_matches_whole_number() {
+        assert!(names_task("Task 1: Schema", "1"));
+        assert!(names_task("Task 1", "1"));
+        assert!(!names_task("Task 10: Docs", "1"));
+        assert!(!names_task("Total", "1"));
+    }
+}
@@ -0,0 +1,169 @@
+//! Bounded worker pool for per-orchestration sync work.
+//!
+//! Each orchestration gets its own lane: jobs submitted under the same key run
+//! one at a time, in submission order. Lanes for different orchestrations run
+//! concurrently, capped by a shared semaphore, so one slow orchestration (e.g.
+//! a huge git history) no longer delays sync for the others.
+
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use tokio::sync::{mpsc, Semaphore};
+use tracing::debug;
+
+/// A unit of sync work.
+pub type SyncJob = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// Worker pool keyed by orchestration ID.
+pub struct SyncPool {
+    permits: Arc<Semaphore>,
+    lanes: HashMap<String, mpsc::UnboundedSender<SyncJob>>,
+}
+
+impl SyncPool {
+    /// Create a pool that runs at most `workers` jobs at once.
+    pub fn new(workers: usize) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(workers.max(1))),
+            lanes: HashMap::new(),
+        }
+    }
+
+    /// Queue a job on the lane for `key`, starting the lane if needed.
+    pub fn submit<F>(&mut self, key: &str, job: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let mut job: SyncJob = Box::pin(job);
+        if let Some(tx) = self.lanes.get(key) {
+            match tx.send(job) {
+                Ok(()) => return,
+                // Lane task has exited (e.g. panicked); restart it below.
+                Err(mpsc::error::SendError(returned)) => job = returned,
+            }
+        }
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        tx.send(job).expect("receiver is held by the new lane");
+        self.spawn_lane(key.to_string(), rx);
+        self.lanes.insert(key.to_string(), tx);
+    }
+
+    fn spawn_lane(&self, key: String, mut rx: mpsc::UnboundedReceiver<SyncJob>) {
+        let permits = Arc::clone(&self.permits);
+        tokio::spawn(async move {
+            while let Some(job) = rx.recv().await {
+                let Ok(_permit) = permits.acquire().await else {
+                    break;
+                };
+                job.await;
+            }
+            debug!(orchestration = %key, "sync lane stopped");
+        });
+    }
+
+    /// Drop lanes for keys that are no longer active.
+    ///
+    /// Queued jobs on a dropped lane still run; the lane exits once drained.
+    pub fn retain_keys(&mut self, active: &HashSet<String>) {
+        self.lanes.retain(|key, _| active.contains(key));
+    }
+
+    /// Number of live lanes.
+    pub fn lane_count(&self) -> usize {
+        self.lanes.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use tokio::sync::oneshot;
+
+    #[tokio::test]
+    async fn test_jobs_for_same_key_run_in_order() {
+        let mut pool = SyncPool::new(4);
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        for i in 0..5 {
+            let log = Arc::clone(&log);
+            pool.submit("orch-1", async move {
+                // Earlier jobs sleep longer; ordering must still hold.
+                tokio::time::sleep(Duration::from_millis(10 * (5 - i))).await;
+                log.lock().unwrap().push(i);
+            });
+        }
+
+        let (tx, rx) = oneshot::channel();
+        pool.submit("orch-1", async move {
+            let _ = tx.send(());
+        });
+        rx.await.unwrap();
+
+        assert_eq!(*log.lock().unwrap(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_slow_key_does_not_block_other_keys() {
+        let mut pool = SyncPool::new(2);
+        let (release_tx, release_rx) = oneshot::channel::<()>();
+        pool.submit("slow", async move {
+            let _ = release_rx.await;
+        });
+
+        let (done_tx, done_rx) = oneshot::channel();
+        pool.submit("fast", async move {
+            let _ = done_tx.send(());
+        });
+
+        tokio::time::timeout(Duration::from_secs(1), done_rx)
+            .await
+            .expect("fast lane should not wait for slow lane")
+            .unwrap();
+        let _ = release_tx.send(());
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_is_bounded() {
+        let mut pool = SyncPool::new(2);
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let mut waits = Vec::new();
+
+        for i in 0..6 {
+            let running = Arc::clone(&running);
+            let peak = Arc::clone(&peak);
+            let (tx, rx) = oneshot::channel();
+            waits.push(rx);
+            pool.submit(&format!("orch-{}", i), async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                let _ = tx.send(());
+            });
+        }
+        for rx in waits {
+            rx.await.unwrap();
+        }
+
+        assert_eq!(pool.lane_count(), 6);
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+    }
+
+    #[tokio::test]
+    async fn test_retain_keys_drops_inactive_lanes() {
+        let mut pool = SyncPool::new(2);
+        pool.submit("a", async {});
+        pool.submit("b", async {});
+
+        let active: HashSet<String> = ["a".to_string()].into_iter().collect();
+        pool.retain_keys(&active);
+        assert_eq!(pool.lane_count(), 1);
+    }
+}
@@ -0,0 +1,202 @@
+//! Per-actor rate limits on state-changing HTTP routes.
+//!
+//! Each actor (API token, or client IP without one; see [`auth::actor`]) gets
+//! a token bucket holding `limit` requests that refills over a minute, so a
+//! runaway script can't flood the session and action endpoints while bursts
+//! from a person clicking around still go through. Tokens can be given a
+//! different limit from client IPs. Read requests are not limited; commands
+//! sent over `/ws/orchestrations/{id}` share the same buckets.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{header, HeaderValue, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use tracing::warn;
+
+use crate::auth::{self, TokenIdentity};
+
+/// Default state-changing requests per actor per minute.
+pub const DEFAULT_MUTATIONS_PER_MINUTE: u32 = 60;
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// Paths that are never limited: hooks only ask for a re-sync, and dropping
+/// them during a rebase would just delay it.
+const UNLIMITED_PATHS: &[&str] = &["/hooks/git", "/hooks/plan"];
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Token buckets keyed by actor.
+#[derive(Debug)]
+pub struct RateLimiter {
+    limit: u32,
+    token_limit: u32,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+    /// A limiter allowing `limit` requests per actor per minute.
+    pub fn new(limit: u32) -> Self {
+        Self {
+            limit,
+            token_limit: limit,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Give actors that authenticated with an API token their own limit.
+    pub fn with_token_limit(mut self, limit: u32) -> Self {
+        self.token_limit = limit;
+        self
+    }
+
+    /// Take one request from `actor`'s bucket, or return how long until one
+    /// is available. A limit of 0 lets the actor through.
+    pub fn check(&self, actor: &str, now: Instant) -> Result<(), Duration> {
+        let limit = if actor.starts_with("token:") {
+            self.token_limit
+        } else {
+            self.limit
+        };
+        if limit == 0 {
+            return Ok(());
+        }
+        let capacity = f64::from(limit);
+        let refill_per_sec = capacity / WINDOW.as_secs_f64();
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(actor.to_string()).or_insert(Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * refill_per_sec).min(capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            let wait = (1.0 - bucket.tokens) / refill_per_sec;
+            Err(Duration::from_secs_f64(wait))
+        }
+    }
+}
+
+/// Middleware rejecting state-changing requests over the actor's limit with
+/// 429 and a `Retry-After` header.
+pub async fn enforce_rate_limit(
+    State(limiter): State<Arc<RateLimiter>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let path = request.uri().path();
+    if request.method().is_safe()
+        || UNLIMITED_PATHS.contains(&path)
+        || auth::READ_ONLY_POSTS.contains(&path)
+    {
+        return next.run(request).await;
+    }
+
+    let actor = auth::actor(
+        request.extensions().get::<TokenIdentity>(),
+        request.extensions().get::<ConnectInfo<SocketAddr>>(),
+    );
+    if let Err(wait) = limiter.check(&actor, Instant::now()) {
+        let retry_after = (wait.as_secs_f64().ceil() as u64).max(1);
+        warn!(actor = %actor, path = %path, "rate limit exceeded");
+        let mut response = (StatusCode::TOO_MANY_REQUESTS, "rate limit exceeded").into_response();
+        response
+            .headers_mut()
+            .insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+        return response;
+    }
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{build_router_with_state, AppState};
+    use axum::body::Body;
+    use axum::http::Method;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_bucket_allows_burst_then_refills() {
+        let limiter = RateLimiter::new(3);
+        let start = Instant::now();
+        for _ in 0..3 {
+            assert!(limiter.check("ip:127.0.0.1", start).is_ok());
+        }
+        let wait = limiter.check("ip:127.0.0.1", start).unwrap_err();
+        assert_eq!(wait.as_secs(), 20);
+
+        // Other actors have their own bucket
+        assert!(limiter.check("token:abcd1234", start).is_ok());
+
+        // One request's worth refills after a third of the window
+        let later = start + Duration::from_secs(20);
+        assert!(limiter.check("ip:127.0.0.1", later).is_ok());
+        assert!(limiter.check("ip:127.0.0.1", later).is_err());
+    }
+
+    #[test]
+    fn test_tokens_get_their_own_limit() {
+        let limiter = RateLimiter::new(1).with_token_limit(2);
+        let now = Instant::now();
+        assert!(limiter.check("ip:127.0.0.1", now).is_ok());
+        assert!(limiter.check("ip:127.0.0.1", now).is_err());
+        assert!(limiter.check("token:abcd1234", now).is_ok());
+        assert!(limiter.check("token:abcd1234", now).is_ok());
+        assert!(limiter.check("token:abcd1234", now).is_err());
+
+        // 0 leaves that kind of actor unlimited
+        let limiter = RateLimiter::new(0).with_token_limit(1);
+        for _ in 0..100 {
+            assert!(limiter.check("ip:127.0.0.1", now).is_ok());
+        }
+        assert!(limiter.check("token:abcd1234", now).is_ok());
+        assert!(limiter.check("token:abcd1234", now).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_mutations_over_limit_get_429() {
+        let router = build_router_with_state(AppState {
+            rate_limiter: Some(Arc::new(RateLimiter::new(1))),
+            ..AppState::default()
+        });
+        let post = || {
+            Request::builder()
+                .method(Method::POST)
+                .uri("/sessions")
+                .body(Body::empty())
+                .unwrap()
+        };
+        let get = || {
+            Request::builder()
+                .uri("/health")
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let resp = router.clone().oneshot(post()).await.unwrap();
+        assert_ne!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        let resp = router.clone().oneshot(post()).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(resp.headers()[header::RETRY_AFTER], "60");
+
+        // Reads are never limited
+        let resp = router.oneshot(get()).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,242 @@
+//! Remote node sync over SSH.
+//!
+//! Mirrors `~/.claude/{teams,tasks}` from a remote machine into a local
+//! directory with rsync, registers the remote as its own node in Convex, and
+//! runs the regular team/task projection against the mirror on an interval.
+//! The remote node only heartbeats while the mirror succeeds, so an
+//! unreachable machine goes offline in the dashboard.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use tokio::process::Command;
+use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use tina_data::{NodeRegistration, TinaConvexClient};
+
+use crate::config::RemoteNodeConfig;
+use crate::sync::{self, SyncCache};
+
+/// Subdirectories of the remote Claude dir that are mirrored locally.
+const MIRRORED_SUBDIRS: [&str; 2] = ["teams", "tasks"];
+
+/// Local mirror root for a remote node: `~/.local/share/tina/remotes/{name}`.
+pub fn mirror_dir(remote_name: &str) -> PathBuf {
+    dirs::data_local_dir()
+        .expect("could not determine local data directory")
+        .join("tina")
+        .join("remotes")
+        .join(remote_name)
+}
+
+/// Build the rsync arguments that mirror one remote subdirectory into `dest`.
+///
+/// `--delete` keeps the mirror exact so removed team members and tasks are
+/// detected the same way as on a local node.
+pub fn build_rsync_args(remote: &RemoteNodeConfig, subdir: &str, dest: &Path) -> Vec<String> {
+    let source = format!(
+        "{}:{}/{}/",
+        remote.host,
+        remote.claude_dir.trim_end_matches('/'),
+        subdir
+    );
+    vec![
+        "-az".to_string(),
+        "--delete".to_string(),
+        "-e".to_string(),
+        "ssh -o BatchMode=yes -o ConnectTimeout=10".to_string(),
+        source,
+        format!("{}/", dest.display()),
+    ]
+}
+
+/// Map `uname -s` output to the `std::env::consts::OS` naming used by local nodes.
+pub fn normalize_remote_os(uname: &str) -> String {
+    match uname.trim().to_ascii_lowercase().as_str() {
+        "darwin" => "macos".to_string(),
+        "" => "unknown".to_string(),
+        other => other.to_string(),
+    }
+}
+
+async fn detect_remote_os(remote: &RemoteNodeConfig) -> String {
+    let output = Command::new("ssh")
+        .args(["-o", "BatchMode=yes", "-o", "ConnectTimeout=10"])
+        .arg(&remote.host)
+        .args(["uname", "-s"])
+        .output()
+        .await;
+    match output {
+        Ok(out) if out.status.success() => {
+            normalize_remote_os(&String::from_utf8_lossy(&out.stdout))
+        }
+        _ => "unknown".to_string(),
+    }
+}
+
+/// Mirror the remote teams/tasks directories into `mirror_root`.
+pub async fn mirror_remote(remote: &RemoteNodeConfig, mirror_root: &Path) -> Result<()> {
+    for subdir in MIRRORED_SUBDIRS {
+        let dest = mirror_root.join(subdir);
+        std::fs::create_dir_all(&dest)
+            .with_context(|| format!("creating mirror dir: {}", dest.display()))?;
+
+        let output = Command::new("rsync")
+            .args(build_rsync_args(remote, subdir, &dest))
+            .output()
+            .await
+            .context("failed to spawn rsync")?;
+        if !output.status.success() {
+            bail!(
+                "rsync of {}:{} exited with {}: {}",
+                remote.host,
+                subdir,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Register the remote machine as its own node and return the node ID.
+async fn register_remote_node(
+    client: &Arc<Mutex<TinaConvexClient>>,
+    remote: &RemoteNodeConfig,
+    auth_token_hash: &str,
+) -> Result<String> {
+    let reg = NodeRegistration {
+        name: remote.name.clone(),
+        os: detect_remote_os(remote).await,
+        auth_token_hash: auth_token_hash.to_string(),
+    };
+    let node_id = client.lock().await.register_node(&reg).await?;
+    info!(node_id = %node_id, name = %remote.name, host = %remote.host, "registered remote node");
+    Ok(node_id)
+}
+
+/// Run one team/task sync pass against a remote mirror.
+///
+/// Only teams whose local directory exists in the mirror are synced; the
+/// remaining active teams belong to other nodes.
+async fn sync_mirror(
+    client: &Arc<Mutex<TinaConvexClient>>,
+    cache: &mut SyncCache,
+    mirror_root: &Path,
+) -> Result<()> {
+    let teams_dir = mirror_root.join("teams");
+    let tasks_dir = mirror_root.join("tasks");
+
+    let mirrored: HashSet<String> = sync::list_team_names(&teams_dir)?.into_iter().collect();
+    let active_teams: Vec<_> = sync::fetch_active_teams(client)
+        .await?
+        .into_iter()
+        .filter(|team| mirrored.contains(&team.local_dir_name))
+        .collect();
+
+    for team in &active_teams {
+        if let Err(e) = sync::sync_team_members(client, cache, &teams_dir, team, None).await {
+            warn!(team = %team.team_name, error = %e, "failed to sync remote team");
+        }
+    }
+    sync::sync_tasks(client, cache, &active_teams, &tasks_dir, None).await
+}
+
+/// Spawn a background task that mirrors and syncs one remote node.
+///
+/// Registration is retried on each tick until it succeeds. The task runs
+/// until the cancellation token is cancelled.
+pub fn spawn_remote_sync(
+    client: Arc<Mutex<TinaConvexClient>>,
+    remote: RemoteNodeConfig,
+    auth_token_hash: String,
+    cancel: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mirror_root = mirror_dir(&remote.name);
+        let mut cache = SyncCache::new();
+        let mut node_id: Option<String> = None;
+        let mut interval = tokio::time::interval(Duration::from_secs(remote.interval_secs));
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    info!(remote = %remote.name, "remote sync task stopping");
+                    break;
+                }
+                _ = interval.tick() => {
+                    if let Err(e) = mirror_remote(&remote, &mirror_root).await {
+                        warn!(remote = %remote.name, error = %e, "remote mirror failed");
+                        continue;
+                    }
+
+                    if node_id.is_none() {
+                        match register_remote_node(&client, &remote, &auth_token_hash).await {
+                            Ok(id) => node_id = Some(id),
+                            Err(e) => {
+                                warn!(remote = %remote.name, error = %e, "remote node registration failed");
+                                continue;
+                            }
+                        }
+                    }
+                    if let Some(id) = &node_id {
+                        if let Err(e) = client.lock().await.heartbeat(id, None, None).await {
+                            warn!(remote = %remote.name, error = %e, "remote heartbeat failed");
+                        }
+                    }
+
+                    if let Err(e) = sync_mirror(&client, &mut cache, &mirror_root).await {
+                        warn!(remote = %remote.name, error = %e, "remote sync failed");
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn remote(claude_dir: &str) -> RemoteNodeConfig {
+        RemoteNodeConfig {
+            name: "desktop".to_string(),
+            host: "me@desktop.local".to_string(),
+            claude_dir: claude_dir.to_string(),
+            interval_secs: 60,
+        }
+    }
+
+    #[test]
+    fn test_build_rsync_args_mirrors_subdir_with_delete() {
+        let args = build_rsync_args(&remote("~/.claude"), "teams", Path::new("/tmp/m/teams"));
+        assert_eq!(args[0], "-az");
+        assert!(args.contains(&"--delete".to_string()));
+        assert_eq!(args[4], "me@desktop.local:~/.claude/teams/");
+        assert_eq!(args[5], "/tmp/m/teams/");
+    }
+
+    #[test]
+    fn test_build_rsync_args_trims_trailing_slash() {
+        let args = build_rsync_args(&remote("/home/me/.claude/"), "tasks", Path::new("/tmp/x"));
+        assert_eq!(args[4], "me@desktop.local:/home/me/.claude/tasks/");
+    }
+
+    #[test]
+    fn test_normalize_remote_os() {
+        assert_eq!(normalize_remote_os("Darwin\n"), "macos");
+        assert_eq!(normalize_remote_os("Linux"), "linux");
+        assert_eq!(normalize_remote_os(""), "unknown");
+    }
+
+    #[test]
+    fn test_mirror_dir_is_namespaced_by_remote() {
+        let dir = mirror_dir("desktop");
+        assert!(dir.ends_with("tina/remotes/desktop"));
+    }
+}
@@ -0,0 +1,60 @@
+//! Structured per-request logging for the HTTP server.
+//!
+//! Every request gets an id: the client's `X-Request-Id` header if it sent
+//! one, otherwise a fresh UUID, echoed back on the response. Handling runs
+//! inside a `request` span carrying that id, so each log line written for a
+//! request, including by a WebSocket it upgrades to, can be tied back to it.
+
+use std::net::SocketAddr;
+use std::time::Instant;
+
+use axum::extract::{ConnectInfo, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use tower_http::request_id::RequestId;
+use tracing::{info, info_span, warn, Span};
+
+use crate::auth::{self, TokenIdentity};
+
+/// The id the request-id layer gave a request.
+pub fn request_id(id: &RequestId) -> Option<String> {
+    id.header_value().to_str().ok().map(str::to_string)
+}
+
+/// Span for one request, for the trace layer.
+pub fn make_span<B>(request: &axum::http::Request<B>) -> Span {
+    let request_id = request
+        .extensions()
+        .get::<RequestId>()
+        .and_then(request_id)
+        .unwrap_or_default();
+    info_span!(target: "tina_daemon::http", "request", %request_id)
+}
+
+/// Middleware logging method, path, status, latency, and actor for each
+/// request. Server errors are logged at warn level.
+pub async fn log_request(request: Request, next: Next) -> Response {
+    let started = Instant::now();
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let client = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .cloned();
+
+    let response = next.run(request).await;
+
+    // The auth layer records the token on the response
+    let actor = auth::actor(
+        response.extensions().get::<TokenIdentity>(),
+        client.as_ref(),
+    );
+    let status = response.status().as_u16();
+    let latency_ms = started.elapsed().as_millis() as u64;
+    if response.status().is_server_error() {
+        warn!(target: "tina_daemon::http", %method, %path, status, latency_ms, %actor, "request");
+    } else {
+        info!(target: "tina_daemon::http", %method, %path, status, latency_ms, %actor, "request");
+    }
+    response
+}
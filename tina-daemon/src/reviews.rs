@@ -0,0 +1,278 @@
+//! Reviews and findings.
+//!
+//! GET  /api/orchestrations/{id}/reviews?phase=   — reviews, newest first
+//! GET  /api/orchestrations/{id}/findings?severity=&resolved=&review=&phase=
+//!                                                — findings, filtered
+//! POST /api/findings/{id}/resolve                — resolve a finding
+//!
+//! Backed by the reviews and review threads `tina-session review` records.
+//! `severity` takes a comma-separated list (`p0,p1`); `phase` keeps only
+//! findings from reviews of that phase. Resolutions are recorded for the
+//! caller's token or address, not a name from the request body.
+
+use std::collections::HashSet;
+use std::net::SocketAddr;
+
+use axum::extract::{ConnectInfo, Path, Query, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use serde::{Deserialize, Serialize};
+
+use tina_data::{ReviewRecord, ReviewThreadRecord};
+
+use crate::auth::{self, TokenIdentity};
+use crate::http::AppState;
+
+type ApiError = (StatusCode, String);
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ReviewParams {
+    pub phase: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct FindingParams {
+    pub severity: Option<String>,
+    pub resolved: Option<bool>,
+    pub review: Option<String>,
+    pub phase: Option<String>,
+}
+
+impl FindingParams {
+    /// Whether `thread` passes every filter. `phase_reviews` holds the ids
+    /// of the reviews in the requested phase, when one was requested.
+    pub fn matches(
+        &self,
+        thread: &ReviewThreadRecord,
+        phase_reviews: Option<&HashSet<String>>,
+    ) -> bool {
+        if let Some(severity) = &self.severity {
+            if !severity
+                .split(',')
+                .any(|s| s.trim().eq_ignore_ascii_case(&thread.severity))
+            {
+                return false;
+            }
+        }
+        if let Some(resolved) = self.resolved {
+            if (thread.status == "resolved") != resolved {
+                return false;
+            }
+        }
+        if let Some(review) = &self.review {
+            if &thread.review_id != review {
+                return false;
+            }
+        }
+        phase_reviews.is_none_or(|reviews| reviews.contains(&thread.review_id))
+    }
+}
+
+/// Request body for POST /api/findings/{id}/resolve.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveRequest {
+    /// Free-form note on who resolved it; the record names the caller itself
+    pub resolved_by: Option<String>,
+}
+
+/// Who a resolution is recorded for: the caller, with the body's name as a
+/// note when one was given.
+fn resolver(actor: &str, resolved_by: Option<&str>) -> String {
+    match resolved_by.map(str::trim).filter(|name| !name.is_empty()) {
+        Some(name) => format!("{} (for {})", actor, name),
+        None => actor.to_string(),
+    }
+}
+
+/// Response body for POST /api/findings/{id}/resolve.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveResponse {
+    pub id: String,
+    pub resolved_by: String,
+}
+
+fn in_phase(reviews: Vec<ReviewRecord>, phase: Option<&str>) -> Vec<ReviewRecord> {
+    match phase {
+        Some(phase) => reviews
+            .into_iter()
+            .filter(|review| review.phase_number.as_deref() == Some(phase))
+            .collect(),
+        None => reviews,
+    }
+}
+
+/// GET /api/orchestrations/{id}/reviews handler.
+pub async fn get_reviews(
+    State(state): State<AppState>,
+    Path(orchestration_id): Path<String>,
+    Query(params): Query<ReviewParams>,
+) -> Result<Json<Vec<ReviewRecord>>, ApiError> {
+    let client = state.convex()?;
+    let reviews = client
+        .lock()
+        .await
+        .list_reviews(&orchestration_id)
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+    Ok(Json(in_phase(reviews, params.phase.as_deref())))
+}
+
+/// GET /api/orchestrations/{id}/findings handler.
+pub async fn get_findings(
+    State(state): State<AppState>,
+    Path(orchestration_id): Path<String>,
+    Query(params): Query<FindingParams>,
+) -> Result<Json<Vec<ReviewThreadRecord>>, ApiError> {
+    let client = state.convex()?;
+    let mut client = client.lock().await;
+    let threads = client
+        .list_review_threads(&orchestration_id)
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+    let phase_reviews: Option<HashSet<String>> = match params.phase.as_deref() {
+        Some(phase) => {
+            let reviews = client
+                .list_reviews(&orchestration_id)
+                .await
+                .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+            Some(
+                in_phase(reviews, Some(phase))
+                    .into_iter()
+                    .map(|review| review.id)
+                    .collect(),
+            )
+        }
+        None => None,
+    };
+
+    Ok(Json(
+        threads
+            .into_iter()
+            .filter(|thread| params.matches(thread, phase_reviews.as_ref()))
+            .collect(),
+    ))
+}
+
+/// POST /api/findings/{id}/resolve handler.
+pub async fn resolve_finding(
+    State(state): State<AppState>,
+    Path(finding_id): Path<String>,
+    identity: Option<Extension<TokenIdentity>>,
+    client: Option<Extension<ConnectInfo<SocketAddr>>>,
+    Json(request): Json<ResolveRequest>,
+) -> Result<Json<ResolveResponse>, ApiError> {
+    let actor = auth::actor(
+        identity.as_ref().map(|Extension(identity)| identity),
+        client.as_ref().map(|Extension(client)| client),
+    );
+    let resolved_by = resolver(&actor, request.resolved_by.as_deref());
+    let client = state.convex()?;
+    client
+        .lock()
+        .await
+        .resolve_review_thread(&finding_id, &resolved_by)
+        .await
+        .map_err(|e| {
+            let message = e.to_string();
+            let status = if message.contains("already resolved") {
+                StatusCode::CONFLICT
+            } else if message.contains("not found") {
+                StatusCode::NOT_FOUND
+            } else {
+                StatusCode::BAD_GATEWAY
+            };
+            (status, message)
+        })?;
+    Ok(Json(ResolveResponse {
+        id: finding_id,
+        resolved_by,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thread(id: &str, review_id: &str, severity: &str, status: &str) -> ReviewThreadRecord {
+        ReviewThreadRecord {
+            id: id.to_string(),
+            review_id: review_id.to_string(),
+            orchestration_id: "orch-1".to_string(),
+            file_path: "src/lib.rs".to_string(),
+            line: 1,
+            commit_sha: "abc".to_string(),
+            summary: String::new(),
+            body: String::new(),
+            severity: severity.to_string(),
+            status: status.to_string(),
+            source: "agent".to_string(),
+            author: "reviewer".to_string(),
+            gate_impact: "review".to_string(),
+            created_at: "2026-02-13T10:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_findings_filters_combine() {
+        let threads = [
+            thread("t1", "r1", "p0", "unresolved"),
+            thread("t2", "r1", "p1", "resolved"),
+            thread("t3", "r2", "p2", "unresolved"),
+            thread("t4", "r2", "p1", "unresolved"),
+        ];
+        let ids = |params: FindingParams, phase_reviews: Option<&HashSet<String>>| {
+            threads
+                .iter()
+                .filter(|t| params.matches(t, phase_reviews))
+                .map(|t| t.id.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ids(FindingParams::default(), None),
+            ["t1", "t2", "t3", "t4"]
+        );
+        assert_eq!(
+            ids(
+                FindingParams {
+                    severity: Some("p0, P1".to_string()),
+                    resolved: Some(false),
+                    ..Default::default()
+                },
+                None
+            ),
+            ["t1", "t4"]
+        );
+        assert_eq!(
+            ids(
+                FindingParams {
+                    review: Some("r1".to_string()),
+                    ..Default::default()
+                },
+                None
+            ),
+            ["t1", "t2"]
+        );
+        let phase_two = HashSet::from(["r2".to_string()]);
+        assert_eq!(
+            ids(
+                FindingParams {
+                    resolved: Some(false),
+                    ..Default::default()
+                },
+                Some(&phase_two)
+            ),
+            ["t3", "t4"]
+        );
+    }
+    #[test]
+    fn test_resolver_is_the_caller() {
+        assert_eq!(resolver("token:ci", None), "token:ci");
+        assert_eq!(resolver("ip:127.0.0.1", Some(" ")), "ip:127.0.0.1");
+        assert_eq!(resolver("token:ci", Some("sam")), "token:ci (for sam)");
+        let request: ResolveRequest = serde_json::from_str("{}").unwrap();
+        assert!(request.resolved_by.is_none());
+    }
+}
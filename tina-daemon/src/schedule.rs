@@ -0,0 +1,250 @@
+//! Scheduled and recurring inbound actions.
+//!
+//! Actions submitted with a `runAt` timestamp or a `cronSchedule` are claimed
+//! into the `scheduled` state instead of running immediately. The daemon keeps
+//! every action it owns in a local [`TimerWheel`], rebuilt from the
+//! `scheduledActions` subscription, and fires each one when it comes due.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local, TimeZone, Utc};
+use cron::Schedule;
+
+use tina_data::InboundAction;
+
+/// Scheduled actions ordered by due time (epoch millis).
+#[derive(Debug, Default)]
+pub struct TimerWheel {
+    entries: BTreeMap<(i64, String), InboundAction>,
+}
+
+impl TimerWheel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace all timers with the given scheduled actions.
+    ///
+    /// The subscription always delivers the full set, so cancelled or
+    /// rescheduled actions drop out naturally. Actions without `run_at` are
+    /// ignored.
+    pub fn replace_all(&mut self, actions: Vec<InboundAction>) {
+        self.entries = actions
+            .into_iter()
+            .filter_map(|action| {
+                let run_at = action.run_at? as i64;
+                Some(((run_at, action.id.clone()), action))
+            })
+            .collect();
+    }
+
+    /// Due time of the earliest timer.
+    pub fn next_deadline(&self) -> Option<i64> {
+        self.entries.keys().next().map(|(run_at, _)| *run_at)
+    }
+
+    /// Remove and return every action due at or before `now_ms`.
+    pub fn pop_due(&mut self, now_ms: i64) -> Vec<InboundAction> {
+        let later = self.entries.split_off(&(now_ms + 1, String::new()));
+        std::mem::replace(&mut self.entries, later)
+            .into_values()
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Sleep until `deadline_ms`, or forever when there is no deadline.
+pub async fn sleep_until_deadline(deadline_ms: Option<i64>) {
+    match deadline_ms {
+        Some(deadline) => {
+            let wait = (deadline - Utc::now().timestamp_millis()).max(0) as u64;
+            tokio::time::sleep(Duration::from_millis(wait)).await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Parse a cron expression, accepting the standard 5-field form
+/// (`min hour dom month dow`, Sunday = 0 or 7) as well as the crate's own
+/// 6/7-field form with seconds (Sunday = 1).
+pub fn parse_cron(expr: &str) -> Result<Schedule> {
+    let trimmed = expr.trim();
+    let fields: Vec<&str> = trimmed.split_whitespace().collect();
+    let normalized = if let [minute, hour, dom, month, dow] = fields[..] {
+        let dow = crate_day_of_week(dow);
+        format!("0 {minute} {hour} {dom} {month} {dow}")
+    } else {
+        trimmed.to_string()
+    };
+    Schedule::from_str(&normalized).map_err(|e| anyhow!("invalid cron schedule '{}': {}", expr, e))
+}
+
+/// Renumber a standard day-of-week field (Sunday = 0 or 7) to the `cron`
+/// crate's (Sunday = 1 .. Saturday = 7). Names, `*` and `?` pass through.
+fn crate_day_of_week(field: &str) -> String {
+    let day = |n: u32| n % 7 + 1;
+    field
+        .split(',')
+        .map(|item| {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => (range, Some(step)),
+                None => (item, None),
+            };
+            let suffix = step.map(|s| format!("/{}", s)).unwrap_or_default();
+            let bounds = match range.split_once('-') {
+                Some((start, end)) => start.parse::<u32>().ok().zip(end.parse::<u32>().ok()),
+                None => range.parse::<u32>().ok().map(|n| (n, n)),
+            }
+            .filter(|&(start, end)| start <= end && end <= 7);
+            match bounds {
+                // Names, wildcards and out-of-range values are left for the
+                // crate to accept or reject
+                None => item.to_string(),
+                Some((n, m)) if n == m => format!("{}{}", day(n), suffix),
+                // Sunday as 7 ends the week, which the crate can't express as
+                // a range: split it off
+                Some((start, 7)) if start > 0 => {
+                    let step_hits_sunday = step
+                        .and_then(|s| s.parse::<u32>().ok())
+                        .is_none_or(|s| s > 0 && (7 - start) % s == 0);
+                    let sunday = if step_hits_sunday { ",1" } else { "" };
+                    format!("{}-7{}{}", day(start), suffix, sunday)
+                }
+                Some((start, end)) => format!("{}-{}{}", day(start), day(end.min(6)), suffix),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Next run of a cron schedule strictly after `after`, evaluated in the
+/// timezone of `after` (local time on the daemon).
+pub fn next_cron_run<Z: TimeZone>(expr: &str, after: &DateTime<Z>) -> Result<DateTime<Utc>> {
+    parse_cron(expr)?
+        .after(after)
+        .next()
+        .map(|next| next.with_timezone(&Utc))
+        .ok_or_else(|| anyhow!("cron schedule '{}' has no upcoming runs", expr))
+}
+
+/// First due time (epoch millis) for a newly claimed scheduled action.
+///
+/// An explicit `run_at` wins; otherwise the next cron occurrence is used.
+pub fn initial_run_at(action: &InboundAction, now: &DateTime<Local>) -> Result<f64> {
+    if let Some(run_at) = action.run_at {
+        return Ok(run_at);
+    }
+    match &action.cron_schedule {
+        Some(expr) => Ok(next_cron_run(expr, now)?.timestamp_millis() as f64),
+        None => Err(anyhow!("action {} has no runAt or cronSchedule", action.id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn action(id: &str, run_at: Option<f64>, cron: Option<&str>) -> InboundAction {
+        InboundAction {
+            id: id.to_string(),
+            node_id: "node-1".to_string(),
+            orchestration_id: "orch-1".to_string(),
+            action_type: "retry_phase".to_string(),
+            payload: "{}".to_string(),
+            status: "scheduled".to_string(),
+            created_at: 0.0,
+            run_at,
+            cron_schedule: cron.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_timer_wheel_orders_and_pops_due_actions() {
+        let mut wheel = TimerWheel::new();
+        wheel.replace_all(vec![
+            action("late", Some(3000.0), None),
+            action("early", Some(1000.0), None),
+            action("unscheduled", None, None),
+        ]);
+
+        assert_eq!(wheel.len(), 2);
+        assert_eq!(wheel.next_deadline(), Some(1000));
+
+        assert!(wheel.pop_due(999).is_empty());
+        let due = wheel.pop_due(1000);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].id, "early");
+        assert_eq!(wheel.next_deadline(), Some(3000));
+    }
+
+    #[test]
+    fn test_timer_wheel_replace_all_drops_cancelled() {
+        let mut wheel = TimerWheel::new();
+        wheel.replace_all(vec![action("a", Some(1000.0), None)]);
+        wheel.replace_all(vec![]);
+        assert!(wheel.is_empty());
+        assert_eq!(wheel.next_deadline(), None);
+    }
+
+    #[test]
+    fn test_next_cron_run_accepts_five_field_form() {
+        let after = Utc.with_ymd_and_hms(2026, 2, 7, 8, 30, 0).unwrap();
+        let next = next_cron_run("0 9 * * *", &after).unwrap();
+        assert_eq!(next, Utc.with_ymd_and_hms(2026, 2, 7, 9, 0, 0).unwrap());
+
+        let next = next_cron_run("0 9 * * *", &next).unwrap();
+        assert_eq!(next, Utc.with_ymd_and_hms(2026, 2, 8, 9, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_five_field_weekdays_run_monday_to_friday() {
+        // 2026-02-07 is a Saturday
+        let mut after = Utc.with_ymd_and_hms(2026, 2, 7, 10, 0, 0).unwrap();
+        let mut runs = Vec::new();
+        for _ in 0..6 {
+            after = next_cron_run("0 9 * * 1-5", &after).unwrap();
+            runs.push(after.format("%a %d").to_string());
+        }
+        assert_eq!(
+            runs,
+            ["Mon 09", "Tue 10", "Wed 11", "Thu 12", "Fri 13", "Mon 16"]
+        );
+    }
+
+    #[test]
+    fn test_five_field_sunday_is_zero_or_seven() {
+        let after = Utc.with_ymd_and_hms(2026, 2, 7, 10, 0, 0).unwrap();
+        let sunday = Utc.with_ymd_and_hms(2026, 2, 8, 0, 0, 0).unwrap();
+        assert_eq!(next_cron_run("* * * * 0", &after).unwrap(), sunday);
+        assert_eq!(next_cron_run("* * * * 7", &after).unwrap(), sunday);
+        // Friday through Sunday
+        assert_eq!(next_cron_run("0 0 * * 5-7", &after).unwrap(), sunday);
+    }
+
+    #[test]
+    fn test_parse_cron_rejects_garbage() {
+        assert!(parse_cron("every day at nine").is_err());
+    }
+
+    #[test]
+    fn test_initial_run_at_prefers_explicit_run_at() {
+        let now = Local::now();
+        assert_eq!(
+            initial_run_at(&action("a", Some(42.0), Some("0 9 * * *")), &now).unwrap(),
+            42.0
+        );
+        let from_cron = initial_run_at(&action("b", None, Some("*/5 * * * *")), &now).unwrap();
+        assert!(from_cron > now.timestamp_millis() as f64);
+        assert!(initial_run_at(&action("c", None, None), &now).is_err());
+    }
+}
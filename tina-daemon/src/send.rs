@@ -0,0 +1,250 @@
+//! Sending text to agents.
+//!
+//! POST /api/orchestrations/{id}/agents/{agent}/send queues a `send_text`
+//! inbound action for the orchestration's node; that node's daemon types the
+//! text into the agent's pane and presses Enter. Because this drives a live
+//! agent, it is off unless the config allows the agent:
+//!
+//! ```toml
+//! send_text_agents = ["orchestrator", "executor-*"]
+//! send_text_require_confirm = true
+//! ```
+//!
+//! With confirmation required (the default) the body must carry
+//! `"confirm": true`. Every request that passes is recorded as a
+//! `send_text_requested` orchestration event before it is queued, naming the
+//! caller by token (`token:<id>`) or address (`ip:<addr>`); the body's
+//! `requestedBy` is kept only as a note, since anyone can write anything
+//! there.
+
+use std::net::SocketAddr;
+
+use axum::extract::{ConnectInfo, Path, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use tina_data::OrchestrationEventRecord;
+
+use crate::auth::{self, TokenIdentity};
+use crate::capture::agent_pane;
+use crate::http::AppState;
+
+type ApiError = (StatusCode, String);
+
+/// Longest text accepted, in characters.
+pub const MAX_TEXT_CHARS: usize = 4000;
+
+/// Who may be sent text, and whether callers must confirm.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SendTextPolicy {
+    /// Agent names text may be sent to; a trailing `*` matches by prefix.
+    /// Empty disables sending.
+    pub allow_agents: Vec<String>,
+    /// Require `"confirm": true` in the request body.
+    pub require_confirm: bool,
+}
+
+impl SendTextPolicy {
+    pub fn allows(&self, agent: &str) -> bool {
+        self.allow_agents
+            .iter()
+            .any(|pattern| match pattern.strip_suffix('*') {
+                Some(prefix) => agent.starts_with(prefix),
+                None => pattern == agent,
+            })
+    }
+
+    /// Why `request` to `agent` may not be sent, if it may not.
+    pub fn check(&self, agent: &str, request: &SendTextRequest) -> Result<(), ApiError> {
+        if !self.allows(agent) {
+            return Err((
+                StatusCode::FORBIDDEN,
+                format!(
+                    "Sending text to {} is not allowed by send_text_agents",
+                    agent
+                ),
+            ));
+        }
+        if request.text.trim().is_empty() {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "text must not be empty".to_string(),
+            ));
+        }
+        if request.text.chars().count() > MAX_TEXT_CHARS {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("text must be at most {} characters", MAX_TEXT_CHARS),
+            ));
+        }
+        if self.require_confirm && !request.confirm {
+            return Err((
+                StatusCode::PRECONDITION_REQUIRED,
+                "Sending text requires \"confirm\": true".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn default_requested_by() -> String {
+    "human".to_string()
+}
+
+/// Request body for POST .../agents/{agent}/send.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendTextRequest {
+    pub text: String,
+    #[serde(default)]
+    pub confirm: bool,
+    /// Free-form note on who asked; the audit trail names the caller itself
+    #[serde(default = "default_requested_by")]
+    pub requested_by: String,
+}
+
+/// Response body for POST .../agents/{agent}/send.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendTextResponse {
+    pub action_id: String,
+    pub pane_id: String,
+}
+
+/// One-line summary of the audit event.
+fn audit_summary(agent: &str, actor: &str, requested_by: &str) -> String {
+    format!(
+        "Text for {} from {} (requested by {})",
+        agent, actor, requested_by
+    )
+}
+
+/// POST /api/orchestrations/{id}/agents/{agent}/send handler.
+pub async fn send_text(
+    State(state): State<AppState>,
+    Path((orchestration_id, agent)): Path<(String, String)>,
+    identity: Option<Extension<TokenIdentity>>,
+    client: Option<Extension<ConnectInfo<SocketAddr>>>,
+    Json(request): Json<SendTextRequest>,
+) -> Result<(StatusCode, Json<SendTextResponse>), ApiError> {
+    state.send_text.check(&agent, &request)?;
+    let actor = auth::actor(
+        identity.as_ref().map(|Extension(identity)| identity),
+        client.as_ref().map(|Extension(client)| client),
+    );
+    let client = state.convex()?;
+
+    let mut client = client.lock().await;
+    let detail = client
+        .get_orchestration_detail(&orchestration_id)
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("Orchestration not found: {}", orchestration_id),
+        ))?;
+    let pane_id = agent_pane(&detail.team_members, &agent)
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("No tmux pane recorded for {}", agent),
+        ))?
+        .to_string();
+
+    // Audit first, so nothing reaches an agent without a record of it
+    client
+        .record_event(&OrchestrationEventRecord {
+            orchestration_id: orchestration_id.clone(),
+            phase_number: None,
+            event_type: "send_text_requested".to_string(),
+            source: "tina-daemon".to_string(),
+            summary: audit_summary(&agent, &actor, &request.requested_by),
+            detail: Some(request.text.clone()),
+            recorded_at: chrono::Utc::now().to_rfc3339(),
+        })
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+
+    let payload = serde_json::json!({
+        "feature": detail.record.feature_name,
+        "agent": agent,
+        "paneId": pane_id,
+        "text": request.text,
+    });
+    let action_id = client
+        .submit_action(
+            &detail.record.node_id,
+            &orchestration_id,
+            "send_text",
+            &payload.to_string(),
+        )
+        .await
+        .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+    info!(
+        orchestration_id = %orchestration_id,
+        agent = %agent,
+        actor = %actor,
+        requested_by = %request.requested_by,
+        action_id = %action_id,
+        "queued send_text"
+    );
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(SendTextResponse { action_id, pane_id }),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(text: &str, confirm: bool) -> SendTextRequest {
+        SendTextRequest {
+            text: text.to_string(),
+            confirm,
+            requested_by: default_requested_by(),
+        }
+    }
+
+    #[test]
+    fn test_policy_gates_agents_text_and_confirmation() {
+        let policy = SendTextPolicy {
+            allow_agents: vec!["orchestrator".to_string(), "executor-*".to_string()],
+            require_confirm: true,
+        };
+        let status = |agent: &str, req: SendTextRequest| {
+            policy.check(agent, &req).err().map(|(status, _)| status)
+        };
+
+        assert_eq!(status("executor-2", request("continue", true)), None);
+        assert_eq!(status("orchestrator", request("continue", true)), None);
+        assert_eq!(
+            status("reviewer", request("continue", true)),
+            Some(StatusCode::FORBIDDEN)
+        );
+        assert_eq!(
+            status("executor-2", request("continue", false)),
+            Some(StatusCode::PRECONDITION_REQUIRED)
+        );
+        assert_eq!(
+            status("executor-2", request("  ", true)),
+            Some(StatusCode::BAD_REQUEST)
+        );
+        assert_eq!(
+            status("executor-2", request(&"x".repeat(MAX_TEXT_CHARS + 1), true)),
+            Some(StatusCode::BAD_REQUEST)
+        );
+
+        assert!(!SendTextPolicy::default().allows("orchestrator"));
+    }
+
+    #[test]
+    fn test_audit_names_the_caller_and_keeps_the_note() {
+        assert_eq!(
+            audit_summary("executor-2", "token:ci", "sam"),
+            "Text for executor-2 from token:ci (requested by sam)"
+        );
+    }
+}
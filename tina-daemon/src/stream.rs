@@ -0,0 +1,210 @@
+//! Server-Sent Events stream of an orchestration's daemon events.
+//!
+//! GET /api/orchestrations/{id}/events/stream relays what the daemon sees
+//! happen to one orchestration (commits, plan and design edits, team and
+//! task changes) straight off the [`EventBus`], for clients behind proxies
+//! that block WebSockets. Each SSE event is named after its `type` and
+//! carries the JSON body of a [`StreamEvent`]. A `lagged` event means the
+//! client fell behind and should refetch rather than trust the stream.
+
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures::stream::{self, Stream, StreamExt};
+use serde::Serialize;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::events::{CommitTrigger, DaemonEvent};
+use crate::http::AppState;
+
+type ApiError = (StatusCode, String);
+
+/// One event on the stream.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StreamEvent {
+    /// Team or task files changed somewhere; refetch teams and tasks.
+    Projection,
+    /// The orchestration's branch has new commits.
+    Commits {
+        branch: String,
+        /// `ref_watcher` or `git_hook`.
+        trigger: &'static str,
+    },
+    /// A plan file changed.
+    Plan { path: String },
+    /// A design file changed.
+    Design { path: String },
+    /// `missed` events were dropped because the client fell behind.
+    Lagged { missed: u64 },
+}
+
+impl StreamEvent {
+    /// What `event` means for orchestration `orchestration_id`, if anything.
+    pub fn for_orchestration(event: &DaemonEvent, orchestration_id: &str) -> Option<Self> {
+        match event {
+            // Projection changes aren't tied to one orchestration
+            DaemonEvent::ProjectionChanged => Some(Self::Projection),
+            DaemonEvent::CommitsChanged { worktree, trigger }
+                if worktree.orchestration_id == orchestration_id =>
+            {
+                Some(Self::Commits {
+                    branch: worktree.branch.clone(),
+                    trigger: match trigger {
+                        CommitTrigger::RefWatcher => "ref_watcher",
+                        CommitTrigger::GitHook => "git_hook",
+                    },
+                })
+            }
+            DaemonEvent::PlanChanged {
+                worktree,
+                plan_path,
+            } if worktree.orchestration_id == orchestration_id => Some(Self::Plan {
+                path: plan_path.display().to_string(),
+            }),
+            DaemonEvent::DesignChanged { worktree, path }
+                if worktree.orchestration_id == orchestration_id =>
+            {
+                Some(Self::Design {
+                    path: path.display().to_string(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Projection => "projection",
+            Self::Commits { .. } => "commits",
+            Self::Plan { .. } => "plan",
+            Self::Design { .. } => "design",
+            Self::Lagged { .. } => "lagged",
+        }
+    }
+}
+
+/// `rx`'s events for `orchestration_id`, ending when the bus closes.
+pub(crate) fn orchestration_events(
+    rx: broadcast::Receiver<Arc<DaemonEvent>>,
+    orchestration_id: String,
+) -> impl Stream<Item = StreamEvent> {
+    stream::unfold((rx, orchestration_id), |(mut rx, id)| async move {
+        loop {
+            let event = match rx.recv().await {
+                Ok(event) => match StreamEvent::for_orchestration(&event, &id) {
+                    Some(event) => event,
+                    None => continue,
+                },
+                Err(RecvError::Lagged(missed)) => StreamEvent::Lagged { missed },
+                Err(RecvError::Closed) => return None,
+            };
+            return Some((event, (rx, id)));
+        }
+    })
+}
+
+/// GET /api/orchestrations/{id}/events/stream handler.
+pub async fn get_event_stream(
+    State(state): State<AppState>,
+    Path(orchestration_id): Path<String>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    let bus = state.events.as_ref().ok_or((
+        StatusCode::SERVICE_UNAVAILABLE,
+        "Event bus not available".to_string(),
+    ))?;
+    let events = orchestration_events(bus.subscribe(), orchestration_id).map(|event| {
+        Ok(Event::default()
+            .event(event.name())
+            .data(serde_json::to_string(&event).unwrap_or_default()))
+    });
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventBus;
+    use crate::watcher::WorktreeInfo;
+    use std::path::PathBuf;
+
+    fn worktree(orchestration_id: &str) -> WorktreeInfo {
+        WorktreeInfo {
+            orchestration_id: orchestration_id.to_string(),
+            project_id: None,
+            feature: "auth".to_string(),
+            worktree_path: PathBuf::from("/tmp/auth"),
+            branch: "tina/auth".to_string(),
+            current_phase: "1".to_string(),
+            git_dir_path: None,
+            branch_ref_path: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_streams_only_the_orchestrations_events() {
+        let bus = EventBus::default();
+        let events = orchestration_events(bus.subscribe(), "orch-1".to_string());
+        bus.publish(DaemonEvent::CommitsChanged {
+            worktree: worktree("orch-2"),
+            trigger: CommitTrigger::GitHook,
+        });
+        bus.publish(DaemonEvent::PlanChanged {
+            worktree: worktree("orch-1"),
+            plan_path: PathBuf::from("/tmp/auth/plans/phase-1.md"),
+        });
+        bus.publish(DaemonEvent::ActionFinished {
+            action_id: "a1".to_string(),
+            action_type: "stop".to_string(),
+            error: None,
+        });
+        bus.publish(DaemonEvent::ProjectionChanged);
+        bus.publish(DaemonEvent::CommitsChanged {
+            worktree: worktree("orch-1"),
+            trigger: CommitTrigger::RefWatcher,
+        });
+        drop(bus);
+
+        let seen: Vec<StreamEvent> = events.collect().await;
+        assert_eq!(
+            seen,
+            vec![
+                StreamEvent::Plan {
+                    path: "/tmp/auth/plans/phase-1.md".to_string()
+                },
+                StreamEvent::Projection,
+                StreamEvent::Commits {
+                    branch: "tina/auth".to_string(),
+                    trigger: "ref_watcher"
+                },
+            ]
+        );
+        assert_eq!(
+            serde_json::to_value(&seen[2]).unwrap(),
+            serde_json::json!({"type": "commits", "branch": "tina/auth", "trigger": "ref_watcher"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_falling_behind_is_reported() {
+        let bus = EventBus::new(2);
+        let events = orchestration_events(bus.subscribe(), "orch-1".to_string());
+        for _ in 0..3 {
+            bus.publish(DaemonEvent::ProjectionChanged);
+        }
+        drop(bus);
+
+        let seen: Vec<StreamEvent> = events.collect().await;
+        assert_eq!(
+            seen,
+            vec![
+                StreamEvent::Lagged { missed: 1 },
+                StreamEvent::Projection,
+                StreamEvent::Projection
+            ]
+        );
+    }
+}
@@ -0,0 +1,283 @@
+//! Compact status summary for wall dashboards.
+//!
+//! GET /api/summary returns counts by status, active phases, alerts, and node
+//! health in one small document. It's built from two Convex queries and
+//! cached for [`SUMMARY_TTL`], so any number of dashboards polling every few
+//! seconds cost at most one refresh per TTL.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::sync::Mutex;
+
+use tina_data::humanize::{format_duration_mins, format_duration_secs, format_relative};
+use tina_data::{NodeRecord, OrchestrationListEntry};
+
+use crate::http::AppState;
+
+type ApiError = (StatusCode, String);
+
+/// How long a built summary is served before refreshing from Convex.
+pub const SUMMARY_TTL: Duration = Duration::from_secs(2);
+
+/// Nodes without a heartbeat for this long are reported offline. Matches
+/// `HEARTBEAT_TIMEOUT_MS` in `convex/nodes.ts`.
+const HEARTBEAT_TIMEOUT_MS: f64 = 60_000.0;
+
+/// Last built summary, shared across requests.
+pub type SummaryCache = Arc<Mutex<Option<(Instant, DashboardSummary)>>>;
+
+/// Response body for GET /api/summary.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DashboardSummary {
+    /// Epoch milliseconds when the summary was built.
+    pub generated_at: f64,
+    /// Orchestration counts keyed by status.
+    pub counts: BTreeMap<String, usize>,
+    /// Orchestrations that aren't complete, oldest first.
+    pub active: Vec<ActivePhase>,
+    pub alerts: Vec<SummaryAlert>,
+    pub nodes: Vec<NodeHealth>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivePhase {
+    pub feature: String,
+    pub node: String,
+    pub status: String,
+    pub current_phase: f64,
+    pub total_phases: f64,
+    pub started_at: String,
+    /// Time since the orchestration started, e.g. "2h 14m".
+    pub elapsed_human: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SummaryAlert {
+    /// `blocked` or `node_offline`.
+    pub kind: &'static str,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeHealth {
+    pub name: String,
+    pub online: bool,
+    /// Milliseconds since the node's last heartbeat.
+    pub heartbeat_age_ms: f64,
+    /// Last heartbeat relative to now, e.g. "5 minutes ago".
+    pub last_heartbeat_human: String,
+}
+
+fn is_complete(status: &str) -> bool {
+    status.eq_ignore_ascii_case("complete")
+}
+
+fn millis(epoch_ms: f64) -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(epoch_ms as i64).unwrap_or_default()
+}
+
+/// Build the summary from listed orchestrations and nodes.
+pub fn build_summary(
+    entries: &[OrchestrationListEntry],
+    nodes: &[NodeRecord],
+    now_ms: f64,
+) -> DashboardSummary {
+    let now = millis(now_ms);
+    let mut counts = BTreeMap::new();
+    for entry in entries {
+        *counts
+            .entry(entry.record.status.to_lowercase())
+            .or_insert(0) += 1;
+    }
+
+    let mut active: Vec<&OrchestrationListEntry> = entries
+        .iter()
+        .filter(|entry| !is_complete(&entry.record.status))
+        .collect();
+    active.sort_by(|a, b| a.record.started_at.cmp(&b.record.started_at));
+
+    let mut alerts: Vec<SummaryAlert> = active
+        .iter()
+        .filter(|entry| entry.record.status.eq_ignore_ascii_case("blocked"))
+        .map(|entry| SummaryAlert {
+            kind: "blocked",
+            message: format!(
+                "{} blocked at phase {}/{}",
+                entry.record.feature_name, entry.record.current_phase, entry.record.total_phases
+            ),
+        })
+        .collect();
+
+    let mut nodes: Vec<NodeHealth> = nodes
+        .iter()
+        .map(|node| {
+            let heartbeat_age_ms = (now_ms - node.last_heartbeat).max(0.0);
+            NodeHealth {
+                name: node.name.clone(),
+                online: heartbeat_age_ms <= HEARTBEAT_TIMEOUT_MS,
+                heartbeat_age_ms,
+                last_heartbeat_human: format_relative(millis(node.last_heartbeat), now),
+            }
+        })
+        .collect();
+    nodes.sort_by(|a, b| a.name.cmp(&b.name));
+    alerts.extend(nodes.iter().filter(|n| !n.online).map(|n| SummaryAlert {
+        kind: "node_offline",
+        message: format!(
+            "node {} offline for {}",
+            n.name,
+            format_duration_secs((n.heartbeat_age_ms / 1000.0).round() as i64)
+        ),
+    }));
+
+    DashboardSummary {
+        generated_at: now_ms,
+        counts,
+        active: active
+            .into_iter()
+            .map(|entry| ActivePhase {
+                feature: entry.record.feature_name.clone(),
+                node: entry.node_name.clone(),
+                status: entry.record.status.clone(),
+                current_phase: entry.record.current_phase,
+                total_phases: entry.record.total_phases,
+                started_at: entry.record.started_at.clone(),
+                elapsed_human: entry.record.elapsed_mins(now).map(format_duration_mins),
+            })
+            .collect(),
+        alerts,
+        nodes,
+    }
+}
+
+/// GET /api/summary handler.
+pub async fn get_summary(
+    State(state): State<AppState>,
+) -> Result<Json<DashboardSummary>, ApiError> {
+    let client = state.convex()?;
+
+    // Hold the cache lock across the refresh so concurrent pollers wait for
+    // one Convex round trip instead of each starting their own.
+    let mut cache = state.summary_cache.lock().await;
+    if let Some((built, summary)) = cache.as_ref() {
+        if built.elapsed() < SUMMARY_TTL {
+            return Ok(Json(summary.clone()));
+        }
+    }
+
+    let (entries, nodes) = {
+        let mut client = client.lock().await;
+        let entries = client
+            .list_orchestrations()
+            .await
+            .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+        let nodes = client
+            .list_nodes()
+            .await
+            .map_err(|e| (StatusCode::BAD_GATEWAY, e.to_string()))?;
+        (entries, nodes)
+    };
+    let now_ms = chrono::Utc::now().timestamp_millis() as f64;
+    let summary = build_summary(&entries, &nodes, now_ms);
+    *cache = Some((Instant::now(), summary.clone()));
+    Ok(Json(summary))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tina_data::test_util::list_entry;
+
+    fn entry(feature: &str, status: &str, started_at: &str) -> OrchestrationListEntry {
+        let mut entry = list_entry(&format!("orch-{}", feature), status, started_at);
+        entry.record.feature_name = feature.to_string();
+        entry.record.branch = format!("tina/{}", feature);
+        entry.record.current_phase = 2.0;
+        entry
+    }
+
+    fn node(name: &str, last_heartbeat: f64) -> NodeRecord {
+        NodeRecord {
+            id: format!("node-{}", name),
+            name: name.to_string(),
+            os: "darwin".to_string(),
+            status: "online".to_string(),
+            last_heartbeat,
+            registered_at: 0.0,
+            clock_skew_ms: None,
+        }
+    }
+
+    #[test]
+    fn test_build_summary() {
+        let now = 1_000_000.0;
+        let entries = vec![
+            entry("billing", "executing", "2026-02-02T00:00:00Z"),
+            entry("auth", "blocked", "2026-02-01T00:00:00Z"),
+            entry("search", "complete", "2026-01-01T00:00:00Z"),
+        ];
+        let nodes = vec![
+            node("laptop", now - 5_000.0),
+            node("desktop", now - 120_000.0),
+        ];
+
+        let summary = build_summary(&entries, &nodes, now);
+
+        assert_eq!(summary.counts["executing"], 1);
+        assert_eq!(summary.counts["blocked"], 1);
+        assert_eq!(summary.counts["complete"], 1);
+        let active: Vec<_> = summary.active.iter().map(|a| a.feature.as_str()).collect();
+        assert_eq!(active, vec!["auth", "billing"]);
+        assert_eq!(
+            summary.alerts,
+            vec![
+                SummaryAlert {
+                    kind: "blocked",
+                    message: "auth blocked at phase 2/3".to_string(),
+                },
+                SummaryAlert {
+                    kind: "node_offline",
+                    message: "node desktop offline for 2m".to_string(),
+                },
+            ]
+        );
+        assert!(!summary.nodes[0].online);
+        assert!(summary.nodes[1].online);
+        assert_eq!(summary.nodes[0].last_heartbeat_human, "2 minutes ago");
+        assert_eq!(summary.nodes[1].last_heartbeat_human, "just now");
+    }
+
+    #[test]
+    fn test_active_phase_elapsed_human() {
+        let started = millis(0.0) + chrono::Duration::minutes(10);
+        let now = started + chrono::Duration::minutes(134);
+        let summary = build_summary(
+            &[entry("auth", "executing", &started.to_rfc3339())],
+            &[],
+            now.timestamp_millis() as f64,
+        );
+        assert_eq!(summary.active[0].elapsed_human.as_deref(), Some("2h 14m"));
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["active"][0]["elapsedHuman"], "2h 14m");
+    }
+
+    #[test]
+    fn test_summary_serializes_compactly() {
+        let summary = build_summary(&[entry("auth", "executing", "2026-02-01")], &[], 0.0);
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["counts"]["executing"], 1);
+        assert_eq!(json["active"][0]["currentPhase"], 2.0);
+        assert!(json["alerts"].as_array().unwrap().is_empty());
+    }
+}
@@ -4,7 +4,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{anyhow, Context, Result};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use regex::Regex;
 use tokio::sync::Mutex;
 use tracing::{debug, error, info, warn};
@@ -13,7 +13,7 @@ use tina_data::{
     ActiveTeamRecord, CommitRecord, OrchestrationEventRecord, PlanRecord, TaskEventRecord,
     TeamMemberRecord, TinaConvexClient,
 };
-use tina_session::state::schema::{Agent, Task, Team};
+use tina_session::state::schema::{Agent, SupervisorState, Task, Team};
 
 use crate::git;
 use crate::telemetry::DaemonTelemetry;
@@ -34,6 +34,13 @@ pub struct SyncCache {
     pub last_commit_sha: HashMap<String, String>,
     /// Maps skip-event cache keys -> last emitted unix timestamp.
     pub skip_event_last_emitted: HashMap<String, i64>,
+    /// Maps `(orchestration_id, phase_key, task_id)` -> last task event known
+    /// to be in Convex, seeded from Convex so restarts don't re-emit tasks.
+    pub recorded_task_events: HashMap<(String, String, String), RecordedTaskEvent>,
+    /// Orchestrations whose recorded task events have been loaded.
+    pub seeded_task_orchestrations: HashSet<String>,
+    /// Task events suppressed as duplicates since the daemon started.
+    pub suppressed_duplicate_task_events: u64,
     /// Active worktrees discovered from Convex
     pub worktrees: Vec<WorktreeInfo>,
 }
@@ -49,6 +56,26 @@ pub struct TaskCacheEntry {
     pub metadata: Option<String>,
 }
 
+impl TaskCacheEntry {
+    fn from_event(event: &TaskEventRecord) -> Self {
+        Self {
+            status: event.status.clone(),
+            subject: event.subject.clone(),
+            description: event.description.clone().unwrap_or_default(),
+            owner: event.owner.clone(),
+            blocked_by: event.blocked_by.clone(),
+            metadata: event.metadata.clone(),
+        }
+    }
+}
+
+/// A task event known to be recorded in Convex.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedTaskEvent {
+    pub entry: TaskCacheEntry,
+    pub recorded_at: DateTime<Utc>,
+}
+
 const ORCHESTRATOR_PHASE_KEY: &str = "__orchestrator__";
 
 impl SyncCache {
@@ -60,6 +87,9 @@ impl SyncCache {
             team_dir_name_by_id: HashMap::new(),
             last_commit_sha: HashMap::new(),
             skip_event_last_emitted: HashMap::new(),
+            recorded_task_events: HashMap::new(),
+            seeded_task_orchestrations: HashSet::new(),
+            suppressed_duplicate_task_events: 0,
             worktrees: Vec::new(),
         }
     }
@@ -95,6 +125,15 @@ impl SyncCache {
         })
     }
 
+    /// Find the worktree rooted at `path` (compared canonically).
+    pub fn find_worktree_by_path(&self, path: &Path) -> Option<&WorktreeInfo> {
+        let wanted = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        self.worktrees.iter().find(|wt| {
+            wt.worktree_path == wanted
+                || std::fs::canonicalize(&wt.worktree_path).is_ok_and(|p| p == wanted)
+        })
+    }
+
     pub fn find_worktree_by_design_path(&self, design_path: &Path) -> Option<&WorktreeInfo> {
         self.worktrees.iter().find(|wt| {
             let designs_dir = wt
@@ -108,19 +147,9 @@ impl SyncCache {
 
     pub fn find_worktree_by_plan_path(&self, plan_path: &Path) -> Option<&WorktreeInfo> {
         self.worktrees.iter().find(|wt| {
-            // Primary location: worktree-local docs/plans.
-            let worktree_plans_dir = wt.worktree_path.join("docs").join("plans");
-            if plan_path.starts_with(&worktree_plans_dir) {
-                return true;
-            }
-
-            // Fallback location: repository-root docs/plans (common when planner
-            // is invoked from repo root while implementation runs in a worktree).
-            wt.worktree_path
-                .parent()
-                .and_then(|p| p.parent())
-                .map(|repo_root| plan_path.starts_with(repo_root.join("docs").join("plans")))
-                .unwrap_or(false)
+            wt.plan_dirs()
+                .iter()
+                .any(|plans_dir| plan_path.starts_with(plans_dir))
         })
     }
 }
@@ -156,6 +185,73 @@ fn should_emit_skip_event(cache: &mut SyncCache, key: String, now_unix: i64) ->
     }
 }
 
+/// A task re-emitting the exact state it was last recorded in within this
+/// window is a duplicate and isn't written again.
+const DUPLICATE_TASK_EVENT_WINDOW_SECS: i64 = 15 * 60;
+
+/// Whether `current` repeats the task's last recorded event (same status
+/// transition and fields) within [`DUPLICATE_TASK_EVENT_WINDOW_SECS`].
+fn is_duplicate_task_event(
+    last: Option<&RecordedTaskEvent>,
+    current: &TaskCacheEntry,
+    now: DateTime<Utc>,
+) -> bool {
+    last.is_some_and(|last| {
+        last.entry == *current
+            && (now - last.recorded_at).num_seconds() < DUPLICATE_TASK_EVENT_WINDOW_SECS
+    })
+}
+
+/// Load the latest recorded event per task for an orchestration, so tasks
+/// re-emitted after a daemon restart or cache reset can be recognized.
+async fn seed_recorded_task_events(
+    client: &Arc<Mutex<TinaConvexClient>>,
+    cache: &mut SyncCache,
+    orchestration_id: &str,
+) {
+    let detail = {
+        let mut client_guard = client.lock().await;
+        client_guard
+            .get_orchestration_detail(orchestration_id)
+            .await
+    };
+    let tasks = match detail {
+        Ok(detail) => detail.map(|d| d.tasks).unwrap_or_default(),
+        Err(e) => {
+            warn!(
+                orchestration_id = %orchestration_id,
+                error = %e,
+                "failed to load recorded task events"
+            );
+            return;
+        }
+    };
+
+    for event in &tasks {
+        let Ok(recorded_at) = DateTime::parse_from_rfc3339(&event.recorded_at) else {
+            continue;
+        };
+        let recorded = RecordedTaskEvent {
+            entry: TaskCacheEntry::from_event(event),
+            recorded_at: recorded_at.with_timezone(&Utc),
+        };
+        let key = task_cache_key(
+            orchestration_id,
+            event.phase_number.as_deref(),
+            &event.task_id,
+        );
+        match cache.recorded_task_events.get(&key) {
+            Some(existing) if existing.recorded_at >= recorded.recorded_at => {}
+            _ => {
+                cache.recorded_task_events.insert(key, recorded);
+            }
+        }
+    }
+    cache
+        .seeded_task_orchestrations
+        .insert(orchestration_id.to_string());
+}
+
 fn maybe_advance_last_commit_sha(
     cache: &mut SyncCache,
     orchestration_id: &str,
@@ -524,8 +620,14 @@ async fn sync_task_dir(
     telemetry: Option<&DaemonTelemetry>,
 ) -> Result<()> {
     let tasks = load_task_files(task_dir)?;
-    let now = Utc::now().to_rfc3339();
+    let now_utc = Utc::now();
+    let now = now_utc.to_rfc3339();
     let mut unchanged_count = 0usize;
+    let mut duplicate_count = 0usize;
+
+    if !cache.seeded_task_orchestrations.contains(orchestration_id) {
+        seed_recorded_task_events(client, cache, orchestration_id).await;
+    }
 
     for task in &tasks {
         let blocked_by_json = if task.blocked_by.is_empty() {
@@ -557,6 +659,17 @@ async fn sync_task_dir(
             continue;
         }
 
+        // Skip if the agent re-emitted the state Convex already has
+        if is_duplicate_task_event(
+            cache.recorded_task_events.get(&cache_key),
+            &current,
+            now_utc,
+        ) {
+            cache.task_state.insert(cache_key, current);
+            duplicate_count += 1;
+            continue;
+        }
+
         let event = TaskEventRecord {
             orchestration_id: orchestration_id.to_string(),
             phase_number: task_phase_number.clone(),
@@ -576,6 +689,13 @@ async fn sync_task_dir(
         };
         match record_result {
             Ok(_) => {
+                cache.recorded_task_events.insert(
+                    cache_key.clone(),
+                    RecordedTaskEvent {
+                        entry: current.clone(),
+                        recorded_at: now_utc,
+                    },
+                );
                 cache.task_state.insert(cache_key, current);
                 debug!(
                     task_id = %task.id,
@@ -606,6 +726,33 @@ async fn sync_task_dir(
         }
     }
 
+    if duplicate_count > 0 {
+        cache.suppressed_duplicate_task_events += duplicate_count as u64;
+        info!(
+            orchestration_id = %orchestration_id,
+            suppressed = duplicate_count,
+            total_suppressed = cache.suppressed_duplicate_task_events,
+            "suppressed duplicate task events"
+        );
+        if let Some(t) = telemetry {
+            let attrs = serde_json::json!({
+                "orchestration_id": orchestration_id,
+                "phase_number": phase_number,
+                "reason": "duplicate_task_event",
+                "suppressed_tasks": duplicate_count,
+                "total_suppressed": cache.suppressed_duplicate_task_events,
+            })
+            .to_string();
+            t.emit_event(
+                "projection.skip",
+                "info",
+                "duplicate task events suppressed",
+                Some(attrs),
+            )
+            .await;
+        }
+    }
+
     // Emit a throttled summary skip event for unchanged tasks instead of one
     // event per unchanged task (prevents telemetry row explosions).
     if unchanged_count > 0 {
@@ -790,7 +937,9 @@ pub async fn sync_commits(
         }
         return Ok(());
     };
-    let new_commits = git::get_new_commits(worktree_path, branch, Some(&last_sha))?;
+    let scope = SupervisorState::local_scope(worktree_path);
+    let new_commits =
+        git::get_new_commits(worktree_path, branch, Some(&last_sha), scope.as_deref())?;
 
     if new_commits.is_empty() {
         // Emit skip event for no new commits
@@ -850,6 +999,7 @@ pub async fn sync_commits(
             sha: commit.sha.clone(),
             short_sha: Some(commit.short_sha.clone()),
             subject: Some(commit.subject.clone()),
+            timestamp: Some(commit.timestamp.clone()),
         };
 
         let record_result = {
@@ -943,6 +1093,7 @@ pub async fn sync_plan(
         phase_number,
         plan_path: plan_path.to_string_lossy().to_string(),
         content,
+        last_synced: None,
     };
 
     let upsert_result = {
@@ -1746,6 +1897,40 @@ mod tests {
         assert_eq!(found.unwrap().feature, "test-feature");
     }
 
+    #[test]
+    fn test_find_worktree_by_plan_path_honors_project_layout() {
+        let temp = TempDir::new().unwrap();
+        let worktree_path = temp.path().join(".worktrees").join("test");
+        fs::create_dir_all(worktree_path.join(".tina")).unwrap();
+        fs::write(
+            worktree_path.join(".tina").join("config.toml"),
+            "[layout]\nplans_dir = \"design/plans\"\n",
+        )
+        .unwrap();
+
+        let mut cache = SyncCache::new();
+        cache.set_worktrees(vec![WorktreeInfo {
+            orchestration_id: "orch1".to_string(),
+            project_id: None,
+            feature: "test-feature".to_string(),
+            worktree_path: worktree_path.clone(),
+            branch: "tina/test-feature".to_string(),
+            current_phase: "1".to_string(),
+            git_dir_path: None,
+            branch_ref_path: None,
+        }]);
+
+        let configured = worktree_path.join("design/plans/2026-02-10-test-phase-1.md");
+        assert!(cache.find_worktree_by_plan_path(&configured).is_some());
+
+        let default_dir = worktree_path.join("docs/plans/2026-02-10-test-phase-1.md");
+        assert!(cache.find_worktree_by_plan_path(&default_dir).is_none());
+
+        // Repo root has no config, so its default plans dir still counts
+        let repo_plan = temp.path().join("docs/plans/2026-02-10-test-phase-1.md");
+        assert!(cache.find_worktree_by_plan_path(&repo_plan).is_some());
+    }
+
     #[test]
     fn test_find_worktree_by_design_path() {
         let mut cache = SyncCache::new();
@@ -1879,6 +2064,58 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_is_duplicate_task_event_within_window() {
+        let entry = TaskCacheEntry {
+            status: "in_progress".to_string(),
+            subject: "Write tests".to_string(),
+            description: String::new(),
+            owner: Some("worker".to_string()),
+            blocked_by: None,
+            metadata: None,
+        };
+        let recorded_at = DateTime::parse_from_rfc3339("2026-02-08T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let last = RecordedTaskEvent {
+            entry: entry.clone(),
+            recorded_at,
+        };
+
+        let soon = recorded_at + chrono::Duration::minutes(5);
+        assert!(is_duplicate_task_event(Some(&last), &entry, soon));
+
+        let later = recorded_at + chrono::Duration::minutes(30);
+        assert!(!is_duplicate_task_event(Some(&last), &entry, later));
+
+        let completed = TaskCacheEntry {
+            status: "completed".to_string(),
+            ..entry.clone()
+        };
+        assert!(!is_duplicate_task_event(Some(&last), &completed, soon));
+        assert!(!is_duplicate_task_event(None, &entry, soon));
+    }
+
+    #[test]
+    fn test_task_cache_entry_from_event_matches_synced_snapshot() {
+        let event = TaskEventRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: Some("1".to_string()),
+            task_id: "3".to_string(),
+            subject: "Write tests".to_string(),
+            description: None,
+            status: "pending".to_string(),
+            owner: None,
+            blocked_by: Some("[\"2\"]".to_string()),
+            metadata: None,
+            recorded_at: "2026-02-08T10:00:00Z".to_string(),
+        };
+        let entry = TaskCacheEntry::from_event(&event);
+        assert_eq!(entry.description, "");
+        assert_eq!(entry.blocked_by.as_deref(), Some("[\"2\"]"));
+        assert_eq!(entry.status, "pending");
+    }
+
     #[test]
     fn test_maybe_advance_last_commit_sha_updates_only_on_full_success() {
         let mut cache = SyncCache::new();
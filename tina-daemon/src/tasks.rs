@@ -0,0 +1,260 @@
+//! Execution task edits.
+//!
+//! PATCH /api/orchestrations/{id}/tasks/{task}        — edit a pending task
+//! POST  /api/orchestrations/{id}/tasks               — insert a task
+//! POST  /api/orchestrations/{id}/tasks/{task}/model  — override a task's model
+//!
+//! Each queues the same `task_edit`, `task_insert`, or `task_set_model`
+//! control action as `tina-session orchestrate task-edit/insert/set-model`.
+//! Convex only applies an edit while the task is still pending at the
+//! `revision` the client last saw, so a stale edit gets a 409 instead of
+//! overwriting newer changes.
+
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use tina_data::{ConvexRejection, TinaConvexClient};
+
+use crate::http::AppState;
+
+type ApiError = (StatusCode, String);
+
+const REQUESTED_BY: &str = "tina-daemon";
+
+/// Request body for PATCH /api/orchestrations/{id}/tasks/{task}.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskEditRequest {
+    pub phase_number: String,
+    pub revision: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Resubmitting a key returns the action it first created
+    #[serde(skip_serializing)]
+    pub idempotency_key: Option<String>,
+}
+
+/// Request body for POST /api/orchestrations/{id}/tasks.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskInsertRequest {
+    pub phase_number: String,
+    /// Task number to insert after; 0 for the beginning
+    pub after_task: u32,
+    pub subject: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<Vec<u32>>,
+    #[serde(skip_serializing)]
+    pub idempotency_key: Option<String>,
+}
+
+/// Request body for POST /api/orchestrations/{id}/tasks/{task}/model.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskModelRequest {
+    pub phase_number: String,
+    pub revision: u32,
+    pub model: String,
+    #[serde(skip_serializing)]
+    pub idempotency_key: Option<String>,
+}
+
+/// Response body for the task endpoints.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskActionResponse {
+    pub action_id: String,
+}
+
+/// Control action payload: the request plus the feature and task number
+/// Convex expects.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Payload<'a, T> {
+    feature: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task_number: Option<u32>,
+    #[serde(flatten)]
+    request: &'a T,
+}
+
+/// Map a failed enqueue to a status by the code Convex rejected it with;
+/// anything uncoded is Convex's problem, not the client's.
+fn enqueue_error(error: anyhow::Error) -> ApiError {
+    let Some(rejection) = error.downcast_ref::<ConvexRejection>() else {
+        return (StatusCode::BAD_GATEWAY, error.to_string());
+    };
+    let status = match rejection.code.as_str() {
+        "conflict" => StatusCode::CONFLICT,
+        "not_found" => StatusCode::NOT_FOUND,
+        "invalid_payload" => StatusCode::BAD_REQUEST,
+        _ => StatusCode::BAD_GATEWAY,
+    };
+    (status, rejection.message.clone())
+}
+
+async fn enqueue<T: Serialize>(
+    client: Arc<Mutex<TinaConvexClient>>,
+    orchestration_id: &str,
+    action_type: &str,
+    task_number: Option<u32>,
+    request: &T,
+    idempotency_key: Option<&str>,
+) -> Result<Json<TaskActionResponse>, ApiError> {
+    let mut client = client.lock().await;
+    let orchestration = client
+        .get_orchestration_detail(orchestration_id)
+        .await
+        .map_err(enqueue_error)?
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("Orchestration not found: {}", orchestration_id),
+        ))?;
+
+    let payload = serde_json::to_string(&Payload {
+        feature: &orchestration.record.feature_name,
+        task_number,
+        request,
+    })
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let idempotency_key = match idempotency_key {
+        Some(key) => key.to_string(),
+        None => format!("{}-{}", REQUESTED_BY, uuid::Uuid::new_v4()),
+    };
+    let action_id = client
+        .enqueue_control_action(
+            orchestration_id,
+            &orchestration.record.node_id,
+            action_type,
+            &payload,
+            REQUESTED_BY,
+            &idempotency_key,
+        )
+        .await
+        .map_err(enqueue_error)?;
+    Ok(Json(TaskActionResponse { action_id }))
+}
+
+/// PATCH /api/orchestrations/{id}/tasks/{task} handler.
+pub async fn edit_task(
+    State(state): State<AppState>,
+    Path((orchestration_id, task_number)): Path<(String, u32)>,
+    Json(request): Json<TaskEditRequest>,
+) -> Result<Json<TaskActionResponse>, ApiError> {
+    let client = state.convex()?;
+    if request.subject.is_none() && request.description.is_none() && request.model.is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Nothing to edit: set subject, description, or model".to_string(),
+        ));
+    }
+    enqueue(
+        client,
+        &orchestration_id,
+        "task_edit",
+        Some(task_number),
+        &request,
+        request.idempotency_key.as_deref(),
+    )
+    .await
+}
+
+/// POST /api/orchestrations/{id}/tasks handler.
+pub async fn insert_task(
+    State(state): State<AppState>,
+    Path(orchestration_id): Path<String>,
+    Json(request): Json<TaskInsertRequest>,
+) -> Result<Json<TaskActionResponse>, ApiError> {
+    let client = state.convex()?;
+    enqueue(
+        client,
+        &orchestration_id,
+        "task_insert",
+        None,
+        &request,
+        request.idempotency_key.as_deref(),
+    )
+    .await
+}
+
+/// POST /api/orchestrations/{id}/tasks/{task}/model handler.
+pub async fn set_task_model(
+    State(state): State<AppState>,
+    Path((orchestration_id, task_number)): Path<(String, u32)>,
+    Json(request): Json<TaskModelRequest>,
+) -> Result<Json<TaskActionResponse>, ApiError> {
+    let client = state.convex()?;
+    enqueue(
+        client,
+        &orchestration_id,
+        "task_set_model",
+        Some(task_number),
+        &request,
+        request.idempotency_key.as_deref(),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payload_matches_the_control_plane_shape() {
+        let request: TaskEditRequest = serde_json::from_str(
+            r#"{"phaseNumber": "2", "revision": 3, "model": "sonnet", "idempotencyKey": "k1"}"#,
+        )
+        .unwrap();
+        let payload = serde_json::to_value(Payload {
+            feature: "auth",
+            task_number: Some(4),
+            request: &request,
+        })
+        .unwrap();
+        assert_eq!(
+            payload,
+            serde_json::json!({
+                "feature": "auth",
+                "taskNumber": 4,
+                "phaseNumber": "2",
+                "revision": 3,
+                "model": "sonnet",
+            })
+        );
+    }
+
+    #[test]
+    fn test_rejected_edits_map_by_code() {
+        let status = |code: &str| {
+            let rejection = ConvexRejection {
+                code: code.to_string(),
+                message: "rejected".to_string(),
+            };
+            enqueue_error(rejection.into()).0
+        };
+        assert_eq!(status("conflict"), StatusCode::CONFLICT);
+        assert_eq!(status("not_found"), StatusCode::NOT_FOUND);
+        assert_eq!(status("invalid_payload"), StatusCode::BAD_REQUEST);
+        assert_eq!(status("rate_limited"), StatusCode::BAD_GATEWAY);
+
+        let uncoded = |message: &str| enqueue_error(anyhow::anyhow!("{}", message)).0;
+        assert_eq!(
+            uncoded("Convex error: Task 9 not found in phase 1"),
+            StatusCode::BAD_GATEWAY
+        );
+        assert_eq!(uncoded("connection reset"), StatusCode::BAD_GATEWAY);
+    }
+}
@@ -18,7 +18,7 @@ use axum::response::IntoResponse;
 use futures::{SinkExt, StreamExt};
 use portable_pty::{native_pty_system, CommandBuilder, PtySize};
 use tokio::sync::mpsc;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, warn, Instrument};
 
 /// Control messages sent from xterm.js via binary WebSocket frames.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -268,8 +268,9 @@ pub async fn ws_terminal_handler(
         warn!(pane_id = %pane_id, error = %e, "failed to disable mouse mode, continuing");
     }
 
-    // Upgrade to WebSocket.
-    ws.on_upgrade(move |socket| handle_terminal_session(socket, pane_id))
+    // Upgrade to WebSocket, keeping the request's span for the session logs.
+    let span = tracing::Span::current();
+    ws.on_upgrade(move |socket| handle_terminal_session(socket, pane_id).instrument(span))
         .into_response()
 }
 
@@ -1,11 +1,21 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::mpsc as std_mpsc;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock, Weak};
+use std::time::{Duration, SystemTime};
 
 use anyhow::{Context, Result};
 use notify::{Event, RecursiveMode, Watcher};
-use tokio::sync::mpsc;
-use tracing::info;
+use serde::Serialize;
+use tokio::sync::{mpsc, watch};
+use tracing::{debug, info, warn};
+
+use tina_session::project_config::ProjectLayout;
+
+use crate::config::DEFAULT_MAX_WATCHES;
+
+/// How often paths that fell back to polling are re-scanned.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
 
 /// Worktree information for watching git refs and plans.
 #[derive(Debug, Clone)]
@@ -20,6 +30,27 @@ pub struct WorktreeInfo {
     pub branch_ref_path: Option<PathBuf>,
 }
 
+impl WorktreeInfo {
+    /// Plan directories that belong to this worktree, per each project's
+    /// `.tina/config.toml` layout.
+    ///
+    /// The worktree's own plans dir comes first, followed by the repository
+    /// root's (planners are sometimes invoked from the repo root while
+    /// implementation runs in the worktree).
+    pub fn plan_dirs(&self) -> Vec<PathBuf> {
+        let mut dirs = vec![
+            ProjectLayout::load_or_default(&self.worktree_path).plans_dir(&self.worktree_path)
+        ];
+        if let Some(repo_root) = self.worktree_path.parent().and_then(|p| p.parent()) {
+            let repo_plans = ProjectLayout::load_or_default(repo_root).plans_dir(repo_root);
+            if repo_plans != dirs[0] {
+                dirs.push(repo_plans);
+            }
+        }
+        dirs
+    }
+}
+
 /// Categorized file-system event.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WatchEvent {
@@ -35,19 +66,96 @@ pub enum WatchEvent {
     Design(PathBuf),
 }
 
+/// What a dynamically watched path is, which decides how its changes are
+/// reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchKind {
+    GitRef,
+    Plan,
+    Design,
+}
+
+impl WatchKind {
+    fn recursive_mode(self) -> RecursiveMode {
+        match self {
+            WatchKind::Design => RecursiveMode::Recursive,
+            WatchKind::GitRef | WatchKind::Plan => RecursiveMode::NonRecursive,
+        }
+    }
+}
+
+/// How a dynamically added path is being observed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WatchMode {
+    /// Registered with the OS watcher (inotify/FSEvents).
+    Native,
+    /// Re-scanned every `POLL_INTERVAL` because the watch cap or the OS
+    /// limit was reached.
+    Polled,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct WatchEntry {
+    kind: WatchKind,
+    mode: WatchMode,
+}
+
+/// A polled path and the modification times seen on the last scan.
+#[derive(Debug)]
+struct PollState {
+    kind: WatchKind,
+    snapshot: HashMap<PathBuf, SystemTime>,
+}
+
+type PolledPaths = Arc<Mutex<HashMap<PathBuf, PollState>>>;
+
+/// Watch counts, published after each worktree refresh and reported on
+/// `/health`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct WatchStats {
+    /// Dynamic paths registered with the OS watcher.
+    pub native: usize,
+    /// Dynamic paths re-scanned by the polling fallback.
+    pub polled: usize,
+    pub git_refs: usize,
+    pub plan_dirs: usize,
+    pub design_dirs: usize,
+    /// Cap on native dynamic watches (teams/tasks dirs are not counted).
+    pub max_watches: usize,
+    /// Whether the OS refused a watch, forcing new paths onto polling.
+    pub os_limit_hit: bool,
+    /// Stale watches removed since startup.
+    pub removed_total: u64,
+}
+
+/// Receiver for the latest watch counts, shared with the HTTP server.
+pub type WatchStatsReceiver = watch::Receiver<WatchStats>;
+
+/// Channel for publishing watch counts from the main loop.
+pub fn stats_channel() -> (watch::Sender<WatchStats>, WatchStatsReceiver) {
+    watch::channel(WatchStats::default())
+}
+
 /// Async file watcher that monitors teams, tasks, git refs, and plans.
 ///
 /// Uses `std::sync::mpsc` internally (safe from any thread) with an async
 /// bridge to a `tokio::sync::mpsc` channel for the consumer.
+///
+/// Git ref, plan, and design watches are dynamic: `retain_watches` drops the
+/// ones no active worktree needs, at most `max_watches` are registered with
+/// the OS, and anything beyond that (or refused by the OS) is polled instead.
 pub struct DaemonWatcher {
     watcher: notify::RecommendedWatcher,
     pub rx: mpsc::Receiver<WatchEvent>,
     _bridge_handle: tokio::task::JoinHandle<()>,
+    _poll_handle: tokio::task::JoinHandle<()>,
     teams_dir: PathBuf,
     tasks_dir: PathBuf,
-    git_ref_paths: Vec<PathBuf>,
-    plan_dirs: Vec<PathBuf>,
-    design_dirs: Vec<PathBuf>,
+    watches: BTreeMap<PathBuf, WatchEntry>,
+    polled: PolledPaths,
+    max_watches: usize,
+    os_limit_hit: bool,
+    removed_total: u64,
     /// Shared with the notify callback so dynamically-added design dirs
     /// are visible to `classify_watch_path`.
     design_dirs_shared: Arc<RwLock<Vec<PathBuf>>>,
@@ -117,10 +225,112 @@ fn classify_watch_path(
     None
 }
 
+/// Modification times of the files a polled path covers.
+fn poll_snapshot(path: &Path, kind: WatchKind) -> HashMap<PathBuf, SystemTime> {
+    fn collect(dir: &Path, recursive: bool, out: &mut HashMap<PathBuf, SystemTime>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                if recursive {
+                    collect(&path, recursive, out);
+                }
+            } else if let Ok(modified) = metadata.modified() {
+                out.insert(path, modified);
+            }
+        }
+    }
+
+    let mut snapshot = HashMap::new();
+    match kind {
+        WatchKind::GitRef => {
+            if let Ok(modified) = std::fs::metadata(path).and_then(|m| m.modified()) {
+                snapshot.insert(path.to_path_buf(), modified);
+            }
+        }
+        WatchKind::Plan => collect(path, false, &mut snapshot),
+        WatchKind::Design => collect(path, true, &mut snapshot),
+    }
+    snapshot
+}
+
+/// Files added, modified, or removed between two snapshots.
+fn changed_paths(
+    old: &HashMap<PathBuf, SystemTime>,
+    new: &HashMap<PathBuf, SystemTime>,
+) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = new
+        .iter()
+        .filter(|(path, modified)| old.get(*path) != Some(*modified))
+        .map(|(path, _)| path.clone())
+        .chain(old.keys().filter(|path| !new.contains_key(*path)).cloned())
+        .collect();
+    changed.sort();
+    changed
+}
+
+/// Event for a change the polling fallback detected under a watched path.
+fn poll_event(kind: WatchKind, path: &Path) -> Option<WatchEvent> {
+    match kind {
+        WatchKind::GitRef => Some(WatchEvent::GitRef(path.to_path_buf())),
+        WatchKind::Plan => (path.extension() == Some(std::ffi::OsStr::new("md")))
+            .then(|| WatchEvent::Plan(path.to_path_buf())),
+        WatchKind::Design => is_design_file(path).then(|| WatchEvent::Design(path.to_path_buf())),
+    }
+}
+
+/// Re-scan polled paths until the watcher is dropped.
+async fn run_poller(
+    polled: Weak<Mutex<HashMap<PathBuf, PollState>>>,
+    tx: std_mpsc::Sender<WatchEvent>,
+) {
+    loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+        let Some(polled) = polled.upgrade() else {
+            break;
+        };
+        let mut events = Vec::new();
+        {
+            let mut polled = polled.lock().unwrap_or_else(|e| e.into_inner());
+            for (path, state) in polled.iter_mut() {
+                let snapshot = poll_snapshot(path, state.kind);
+                for changed in changed_paths(&state.snapshot, &snapshot) {
+                    events.extend(poll_event(state.kind, &changed));
+                }
+                state.snapshot = snapshot;
+            }
+        }
+        for event in events {
+            if tx.send(event).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+fn is_watch_limit_error(err: &notify::Error) -> bool {
+    matches!(err.kind, notify::ErrorKind::MaxFilesWatch)
+}
+
 impl DaemonWatcher {
     /// Create a watcher monitoring the given teams and tasks directories.
     ///
     pub fn new(teams_dir: &Path, tasks_dir: &Path) -> Result<Self> {
+        Self::with_max_watches(teams_dir, tasks_dir, DEFAULT_MAX_WATCHES)
+    }
+
+    /// Create a watcher that registers at most `max_watches` dynamic paths
+    /// with the OS and polls the rest.
+    pub fn with_max_watches(
+        teams_dir: &Path,
+        tasks_dir: &Path,
+        max_watches: usize,
+    ) -> Result<Self> {
         std::fs::create_dir_all(teams_dir)
             .with_context(|| format!("creating teams dir: {}", teams_dir.display()))?;
         std::fs::create_dir_all(tasks_dir)
@@ -135,6 +345,7 @@ impl DaemonWatcher {
         let tasks_prefix = tasks_dir.to_path_buf();
         let tp = teams_prefix.clone();
         let tkp = tasks_prefix.clone();
+        let poll_tx = std_tx.clone();
         let design_dirs_shared: Arc<RwLock<Vec<PathBuf>>> =
             Arc::new(RwLock::new(Vec::new()));
         let dd = design_dirs_shared.clone();
@@ -185,53 +396,212 @@ impl DaemonWatcher {
             }
         });
 
+        let polled: PolledPaths = Arc::new(Mutex::new(HashMap::new()));
+        let poll_handle = tokio::spawn(run_poller(Arc::downgrade(&polled), poll_tx));
+
         Ok(Self {
             watcher,
             rx: tokio_rx,
             _bridge_handle: bridge_handle,
+            _poll_handle: poll_handle,
             teams_dir: teams_dir.to_path_buf(),
             tasks_dir: tasks_dir.to_path_buf(),
-            git_ref_paths: Vec::new(),
-            plan_dirs: Vec::new(),
-            design_dirs: Vec::new(),
+            watches: BTreeMap::new(),
+            polled,
+            max_watches,
+            os_limit_hit: false,
+            removed_total: 0,
             design_dirs_shared,
         })
     }
 
-    /// Watch a specific git ref file (e.g., `.git/refs/heads/main`).
-    pub fn watch_git_ref(&mut self, ref_path: &Path) -> Result<()> {
-        if !self.git_ref_paths.contains(&ref_path.to_path_buf()) {
-            self.watcher
-                .watch(ref_path, RecursiveMode::NonRecursive)
-                .with_context(|| format!("watching git ref: {}", ref_path.display()))?;
-            self.git_ref_paths.push(ref_path.to_path_buf());
+    fn native_count(&self) -> usize {
+        self.watches
+            .values()
+            .filter(|entry| entry.mode == WatchMode::Native)
+            .count()
+    }
+
+    fn start_polling(&mut self, path: &Path, kind: WatchKind) {
+        let snapshot = poll_snapshot(path, kind);
+        self.polled
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(path.to_path_buf(), PollState { kind, snapshot });
+        self.watches.insert(
+            path.to_path_buf(),
+            WatchEntry {
+                kind,
+                mode: WatchMode::Polled,
+            },
+        );
+    }
+
+    /// Register `path` natively, or fall back to polling when the cap or
+    /// the OS watch limit is reached.
+    fn add_watch(&mut self, path: &Path, kind: WatchKind) -> Result<()> {
+        if self.watches.contains_key(path) {
+            return Ok(());
+        }
+
+        if self.os_limit_hit || self.native_count() >= self.max_watches {
+            debug!(path = %path.display(), "watch cap reached, polling instead");
+            self.start_polling(path, kind);
+        } else {
+            match self.watcher.watch(path, kind.recursive_mode()) {
+                Ok(()) => {
+                    self.watches.insert(
+                        path.to_path_buf(),
+                        WatchEntry {
+                            kind,
+                            mode: WatchMode::Native,
+                        },
+                    );
+                }
+                Err(e) if is_watch_limit_error(&e) => {
+                    warn!(
+                        path = %path.display(),
+                        native = self.native_count(),
+                        "OS file watch limit reached, falling back to polling"
+                    );
+                    self.os_limit_hit = true;
+                    self.start_polling(path, kind);
+                }
+                Err(e) => {
+                    return Err(e).with_context(|| format!("watching {}", path.display()));
+                }
+            }
+        }
+
+        if kind == WatchKind::Design {
+            if let Ok(mut shared) = self.design_dirs_shared.write() {
+                shared.push(path.to_path_buf());
+            }
         }
         Ok(())
     }
 
+    /// Drop dynamic watches for paths not in `keep` (e.g. worktrees of
+    /// completed orchestrations). Freed native capacity is handed to polled
+    /// paths. Returns the number of watches removed.
+    pub fn retain_watches(&mut self, keep: &HashSet<PathBuf>) -> usize {
+        let stale: Vec<PathBuf> = self
+            .watches
+            .keys()
+            .filter(|path| !keep.contains(*path))
+            .cloned()
+            .collect();
+
+        for path in &stale {
+            let Some(entry) = self.watches.remove(path) else {
+                continue;
+            };
+            match entry.mode {
+                WatchMode::Native => {
+                    // The path may already be gone with its worktree
+                    if let Err(e) = self.watcher.unwatch(path) {
+                        debug!(path = %path.display(), error = %e, "unwatch failed");
+                    }
+                }
+                WatchMode::Polled => {
+                    self.polled
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .remove(path);
+                }
+            }
+            if entry.kind == WatchKind::Design {
+                if let Ok(mut shared) = self.design_dirs_shared.write() {
+                    shared.retain(|dir| dir != path);
+                }
+            }
+        }
+        self.removed_total += stale.len() as u64;
+
+        if !stale.is_empty() {
+            self.promote_polled();
+        }
+        stale.len()
+    }
+
+    /// Move polled paths back to native watches while there is capacity.
+    fn promote_polled(&mut self) {
+        if self.os_limit_hit {
+            return;
+        }
+        let candidates: Vec<(PathBuf, WatchKind)> = self
+            .watches
+            .iter()
+            .filter(|(_, entry)| entry.mode == WatchMode::Polled)
+            .map(|(path, entry)| (path.clone(), entry.kind))
+            .collect();
+
+        for (path, kind) in candidates {
+            if self.native_count() >= self.max_watches {
+                break;
+            }
+            match self.watcher.watch(&path, kind.recursive_mode()) {
+                Ok(()) => {
+                    self.polled
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .remove(&path);
+                    self.watches.insert(
+                        path,
+                        WatchEntry {
+                            kind,
+                            mode: WatchMode::Native,
+                        },
+                    );
+                }
+                Err(e) => {
+                    if is_watch_limit_error(&e) {
+                        self.os_limit_hit = true;
+                    }
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Current watch counts.
+    pub fn stats(&self) -> WatchStats {
+        let mut stats = WatchStats {
+            max_watches: self.max_watches,
+            os_limit_hit: self.os_limit_hit,
+            removed_total: self.removed_total,
+            ..WatchStats::default()
+        };
+        for entry in self.watches.values() {
+            match entry.mode {
+                WatchMode::Native => stats.native += 1,
+                WatchMode::Polled => stats.polled += 1,
+            }
+            match entry.kind {
+                WatchKind::GitRef => stats.git_refs += 1,
+                WatchKind::Plan => stats.plan_dirs += 1,
+                WatchKind::Design => stats.design_dirs += 1,
+            }
+        }
+        stats
+    }
+
+    /// Watch a specific git ref file (e.g., `.git/refs/heads/main`).
+    pub fn watch_git_ref(&mut self, ref_path: &Path) -> Result<()> {
+        self.add_watch(ref_path, WatchKind::GitRef)
+            .with_context(|| format!("watching git ref: {}", ref_path.display()))
+    }
+
     /// Watch a specific plan directory (e.g., `docs/plans`).
     pub fn watch_plan_dir(&mut self, plan_dir: &Path) -> Result<()> {
-        if !self.plan_dirs.contains(&plan_dir.to_path_buf()) {
-            self.watcher
-                .watch(plan_dir, RecursiveMode::NonRecursive)
-                .with_context(|| format!("watching plan dir: {}", plan_dir.display()))?;
-            self.plan_dirs.push(plan_dir.to_path_buf());
-        }
-        Ok(())
+        self.add_watch(plan_dir, WatchKind::Plan)
+            .with_context(|| format!("watching plan dir: {}", plan_dir.display()))
     }
 
     /// Watch a specific design sets directory (e.g., `ui/designs/sets`).
     pub fn watch_design_dir(&mut self, design_dir: &Path) -> Result<()> {
-        if !self.design_dirs.contains(&design_dir.to_path_buf()) {
-            self.watcher
-                .watch(design_dir, RecursiveMode::Recursive)
-                .with_context(|| format!("watching design dir: {}", design_dir.display()))?;
-            self.design_dirs.push(design_dir.to_path_buf());
-            if let Ok(mut shared) = self.design_dirs_shared.write() {
-                shared.push(design_dir.to_path_buf());
-            }
-        }
-        Ok(())
+        self.add_watch(design_dir, WatchKind::Design)
+            .with_context(|| format!("watching design dir: {}", design_dir.display()))
     }
 
     /// Get the teams directory being watched.
@@ -379,6 +749,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_changed_paths_reports_added_modified_and_removed() {
+        let t0 = SystemTime::UNIX_EPOCH;
+        let t1 = t0 + Duration::from_secs(1);
+        let old = HashMap::from([
+            (PathBuf::from("/p/a.md"), t0),
+            (PathBuf::from("/p/b.md"), t0),
+            (PathBuf::from("/p/gone.md"), t0),
+        ]);
+        let new = HashMap::from([
+            (PathBuf::from("/p/a.md"), t0),
+            (PathBuf::from("/p/b.md"), t1),
+            (PathBuf::from("/p/c.md"), t0),
+        ]);
+
+        assert_eq!(
+            changed_paths(&old, &new),
+            vec![
+                PathBuf::from("/p/b.md"),
+                PathBuf::from("/p/c.md"),
+                PathBuf::from("/p/gone.md"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_poll_event_filters_by_kind() {
+        let plan = Path::new("/wt/docs/plans/phase-1.md");
+        let notes = Path::new("/wt/docs/plans/notes.txt");
+        let meta = Path::new("/wt/ui/designs/sets/a/meta.ts");
+
+        assert_eq!(
+            poll_event(WatchKind::Plan, plan),
+            Some(WatchEvent::Plan(plan.to_path_buf()))
+        );
+        assert_eq!(poll_event(WatchKind::Plan, notes), None);
+        assert_eq!(
+            poll_event(WatchKind::Design, meta),
+            Some(WatchEvent::Design(meta.to_path_buf()))
+        );
+        assert_eq!(poll_event(WatchKind::Design, plan), None);
+    }
+
+    #[test]
+    fn test_poll_snapshot_recurses_only_for_designs() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("top.md"), "x").unwrap();
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+        std::fs::write(dir.path().join("nested").join("meta.ts"), "x").unwrap();
+
+        assert_eq!(poll_snapshot(dir.path(), WatchKind::Plan).len(), 1);
+        assert_eq!(poll_snapshot(dir.path(), WatchKind::Design).len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_watch_cap_falls_back_to_polling_and_promotes_on_removal() {
+        let dir = TempDir::new().unwrap();
+        let plans_a = dir.path().join("a");
+        let plans_b = dir.path().join("b");
+        std::fs::create_dir_all(&plans_a).unwrap();
+        std::fs::create_dir_all(&plans_b).unwrap();
+
+        let mut watcher = DaemonWatcher::with_max_watches(
+            &dir.path().join("teams"),
+            &dir.path().join("tasks"),
+            1,
+        )
+        .unwrap();
+        watcher.watch_plan_dir(&plans_a).unwrap();
+        watcher.watch_plan_dir(&plans_b).unwrap();
+        watcher.watch_plan_dir(&plans_b).unwrap();
+
+        let stats = watcher.stats();
+        assert_eq!(stats.native, 1);
+        assert_eq!(stats.polled, 1);
+        assert_eq!(stats.plan_dirs, 2);
+
+        let keep = HashSet::from([plans_b.clone()]);
+        assert_eq!(watcher.retain_watches(&keep), 1);
+
+        let stats = watcher.stats();
+        assert_eq!(stats.native, 1, "polled path should take the freed slot");
+        assert_eq!(stats.polled, 0);
+        assert_eq!(stats.removed_total, 1);
+    }
+
+    #[tokio::test]
+    async fn test_retain_watches_drops_stale_design_dirs() {
+        let dir = TempDir::new().unwrap();
+        let designs = dir.path().join("designs");
+        std::fs::create_dir_all(&designs).unwrap();
+
+        let mut watcher =
+            DaemonWatcher::new(&dir.path().join("teams"), &dir.path().join("tasks")).unwrap();
+        watcher.watch_design_dir(&designs).unwrap();
+        assert_eq!(watcher.design_dirs_shared.read().unwrap().len(), 1);
+
+        assert_eq!(watcher.retain_watches(&HashSet::new()), 1);
+        assert!(watcher.design_dirs_shared.read().unwrap().is_empty());
+        assert_eq!(watcher.stats().design_dirs, 0);
+    }
+
     // Note: Integration tests for file change detection are omitted because
     // the `notify` crate's FSEvents backend on macOS aborts on process exit
     // when used with tokio test runtimes. The event categorization logic is
@@ -0,0 +1,760 @@
+//! Outbound webhooks.
+//!
+//! POST /api/webhooks registers a URL to be told about orchestration
+//! progress, GET /api/webhooks lists registrations, DELETE
+//! /api/webhooks/{id} removes one, and GET /api/webhooks/{id}/deliveries
+//! shows its most recent deliveries. Registrations live in
+//! `~/.config/tina/webhooks.json` and are re-read on every poll, so edits
+//! take effect without a restart.
+//!
+//! The dispatcher polls Convex for orchestration events (`phase_started`,
+//! `phase_completed`, ...) and task events (sent as `task_<status>`)
+//! recorded since its last pass, and POSTs each as a JSON [`WebhookEvent`]
+//! to every webhook whose `events` patterns match. Requests carry
+//! `X-Tina-Event`, `X-Tina-Delivery`, and `X-Tina-Signature: sha256=<hex>`,
+//! the HMAC-SHA256 of the body keyed with the webhook's secret. Errors and
+//! non-2xx responses are retried with backoff. Orchestrations already
+//! running when the first webhook is registered are followed from then on,
+//! and finished ones are skipped; orchestrations that show up later are
+//! followed from their first event. Reads resume from Convex's keyset
+//! cursors, so events that share a timestamp aren't lost between pages.
+
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::{Path as FsPath, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use tina_data::{EventFilter, OrchestrationEventRecord, TaskEventRecord, TinaConvexClient};
+
+use crate::http::AppState;
+
+type ApiError = (StatusCode, String);
+
+/// How often the dispatcher checks Convex for new activity.
+pub const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Waits between attempts after a failed delivery.
+pub const RETRY_DELAYS: [Duration; 3] = [
+    Duration::from_secs(5),
+    Duration::from_secs(30),
+    Duration::from_secs(120),
+];
+
+/// Deliveries kept per webhook for GET .../deliveries.
+pub const MAX_DELIVERIES_KEPT: usize = 50;
+
+/// Events fetched per orchestration per poll; the rest wait for the next.
+const POLL_PAGE_SIZE: usize = 100;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default webhook store location.
+pub fn webhooks_path() -> PathBuf {
+    dirs::config_dir()
+        .expect("Could not determine config directory")
+        .join("tina")
+        .join("webhooks.json")
+}
+
+/// A registered webhook.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Webhook {
+    pub id: String,
+    pub url: String,
+    /// HMAC key for `X-Tina-Signature`
+    pub secret: String,
+    /// Event names to send; a trailing `*` matches by prefix. Empty sends
+    /// everything.
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// Only send this orchestration's events
+    #[serde(default)]
+    pub orchestration_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    pub fn wants(&self, event: &WebhookEvent) -> bool {
+        let orchestration_matches = self
+            .orchestration_id
+            .as_ref()
+            .is_none_or(|id| *id == event.orchestration_id);
+        let event_matches = self.events.is_empty()
+            || self
+                .events
+                .iter()
+                .any(|pattern| match pattern.strip_suffix('*') {
+                    Some(prefix) => event.event.starts_with(prefix),
+                    None => *pattern == event.event,
+                });
+        orchestration_matches && event_matches
+    }
+}
+
+/// The webhook store file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WebhookStore {
+    #[serde(default)]
+    pub webhooks: Vec<Webhook>,
+}
+
+impl WebhookStore {
+    /// Load the store at `path`; a missing file is an empty store.
+    pub fn load(path: &FsPath) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)?;
+        serde_json::from_str(&contents)
+            .map_err(|e| anyhow::anyhow!("Invalid webhook store {}: {}", path.display(), e))
+    }
+
+    /// Write the store to `path`, readable only by the owner since it holds
+    /// the signing secrets.
+    pub fn save(&self, path: &FsPath) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+        }
+        Ok(())
+    }
+}
+
+/// The JSON body POSTed to a webhook.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEvent {
+    pub event: String,
+    pub orchestration_id: String,
+    pub phase_number: Option<String>,
+    pub recorded_at: String,
+    pub data: serde_json::Value,
+}
+
+impl From<OrchestrationEventRecord> for WebhookEvent {
+    fn from(record: OrchestrationEventRecord) -> Self {
+        Self {
+            event: record.event_type,
+            orchestration_id: record.orchestration_id,
+            phase_number: record.phase_number,
+            recorded_at: record.recorded_at,
+            data: serde_json::json!({
+                "source": record.source,
+                "summary": record.summary,
+                "detail": record.detail,
+            }),
+        }
+    }
+}
+
+impl From<TaskEventRecord> for WebhookEvent {
+    fn from(record: TaskEventRecord) -> Self {
+        Self {
+            event: format!("task_{}", record.status),
+            orchestration_id: record.orchestration_id,
+            phase_number: record.phase_number,
+            recorded_at: record.recorded_at,
+            data: serde_json::json!({
+                "taskId": record.task_id,
+                "subject": record.subject,
+                "status": record.status,
+                "owner": record.owner,
+            }),
+        }
+    }
+}
+
+/// `sha256=<hex>` HMAC of `body` keyed with `secret`.
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// The outcome of delivering one event to one webhook.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Delivery {
+    pub id: String,
+    pub event: String,
+    pub orchestration_id: String,
+    pub attempts: u32,
+    pub delivered: bool,
+    /// Response status of the last attempt, if one came back
+    pub status_code: Option<u16>,
+    pub error: Option<String>,
+    pub finished_at: String,
+}
+
+/// Registered webhooks plus their recent deliveries, shared by the HTTP
+/// handlers and the dispatcher.
+#[derive(Debug)]
+pub struct WebhookRegistry {
+    path: PathBuf,
+    /// Serializes read-modify-write of the store file
+    store_lock: Mutex<()>,
+    deliveries: std::sync::Mutex<HashMap<String, VecDeque<Delivery>>>,
+    http: reqwest::Client,
+}
+
+impl WebhookRegistry {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            store_lock: Mutex::new(()),
+            deliveries: Default::default(),
+            http: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn load(&self) -> Result<WebhookStore> {
+        WebhookStore::load(&self.path)
+    }
+
+    /// Load the store, apply `edit`, and save it.
+    async fn update<T>(&self, edit: impl FnOnce(&mut WebhookStore) -> T) -> Result<T> {
+        let _guard = self.store_lock.lock().await;
+        let mut store = self.load()?;
+        let result = edit(&mut store);
+        store.save(&self.path)?;
+        Ok(result)
+    }
+
+    fn record(&self, webhook_id: &str, delivery: Delivery) {
+        let mut deliveries = self.deliveries.lock().unwrap();
+        let log = deliveries.entry(webhook_id.to_string()).or_default();
+        if log.len() == MAX_DELIVERIES_KEPT {
+            log.pop_back();
+        }
+        log.push_front(delivery);
+    }
+
+    /// Recent deliveries to `webhook_id`, newest first.
+    pub fn deliveries(&self, webhook_id: &str) -> Vec<Delivery> {
+        self.deliveries
+            .lock()
+            .unwrap()
+            .get(webhook_id)
+            .map(|log| log.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// POST `event` to `webhook` and log the outcome. A failed attempt is
+    /// retried after each of `retry_delays` in turn (the dispatcher passes
+    /// [`RETRY_DELAYS`]).
+    pub async fn deliver(
+        &self,
+        webhook: &Webhook,
+        event: &WebhookEvent,
+        retry_delays: &[Duration],
+    ) {
+        let id = Uuid::new_v4().to_string();
+        let body = serde_json::to_vec(event).unwrap_or_default();
+        let signature = sign(&webhook.secret, &body);
+
+        let mut attempts = 0;
+        let (delivered, status_code, error) = loop {
+            attempts += 1;
+            let sent = self
+                .http
+                .post(&webhook.url)
+                .header("Content-Type", "application/json")
+                .header("X-Tina-Event", &event.event)
+                .header("X-Tina-Delivery", &id)
+                .header("X-Tina-Signature", &signature)
+                .body(body.clone())
+                .send()
+                .await;
+            let outcome = match sent {
+                Ok(response) if response.status().is_success() => {
+                    (true, Some(response.status().as_u16()), None)
+                }
+                Ok(response) => (
+                    false,
+                    Some(response.status().as_u16()),
+                    Some(format!("HTTP {}", response.status())),
+                ),
+                Err(e) => (false, None, Some(e.to_string())),
+            };
+            match retry_delays.get(attempts as usize - 1) {
+                Some(delay) if !outcome.0 => tokio::time::sleep(*delay).await,
+                _ => break outcome,
+            }
+        };
+
+        if delivered {
+            info!(webhook = %webhook.id, event = %event.event, attempts, "webhook delivered");
+        } else {
+            warn!(
+                webhook = %webhook.id,
+                event = %event.event,
+                attempts,
+                error = error.as_deref().unwrap_or_default(),
+                "webhook delivery failed"
+            );
+        }
+        self.record(
+            &webhook.id,
+            Delivery {
+                id,
+                event: event.event.clone(),
+                orchestration_id: event.orchestration_id.clone(),
+                attempts,
+                delivered,
+                status_code,
+                error,
+                finished_at: Utc::now().to_rfc3339(),
+            },
+        );
+    }
+}
+
+/// How far the dispatcher has read an orchestration's activity.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Watermark {
+    /// Only activity recorded after this, for orchestrations that were
+    /// already running when the first webhook was registered
+    since: Option<String>,
+    /// Convex cursors just past the last event and task event read
+    events: Option<String>,
+    tasks: Option<String>,
+    /// Read to the end of a finished orchestration; nothing more will come
+    done: bool,
+}
+
+impl Watermark {
+    /// Where to start on an orchestration first seen this pass. `first_pass`
+    /// is the time of the pass right after webhooks were registered: what is
+    /// running then is followed from that time and what has finished is
+    /// skipped. Orchestrations seen on later passes are read from the start.
+    fn start(first_pass: Option<&str>, finished: bool) -> Self {
+        match first_pass {
+            Some(_) if finished => Self {
+                done: true,
+                ..Self::default()
+            },
+            Some(now) => Self {
+                since: Some(now.to_string()),
+                ..Self::default()
+            },
+            None => Self::default(),
+        }
+    }
+}
+
+async fn poll(
+    client: &Arc<Mutex<TinaConvexClient>>,
+    watermarks: &mut HashMap<String, Watermark>,
+    first_pass: Option<&str>,
+) -> Result<Vec<WebhookEvent>> {
+    let orchestrations = client.lock().await.list_orchestrations().await?;
+    let mut events = Vec::new();
+
+    for entry in orchestrations {
+        let finished = entry.record.status.eq_ignore_ascii_case("complete");
+        let mark = watermarks
+            .entry(entry.id.clone())
+            .or_insert_with(|| Watermark::start(first_pass, finished));
+        if mark.done {
+            continue;
+        }
+
+        let filter = EventFilter {
+            since: mark.since.clone(),
+            ..EventFilter::default()
+        };
+        let mut client = client.lock().await;
+        let recorded = client
+            .list_events_page(&entry.id, POLL_PAGE_SIZE, mark.events.as_deref(), &filter)
+            .await?;
+        let tasks = client
+            .list_orchestration_task_events_page(
+                &entry.id,
+                POLL_PAGE_SIZE,
+                mark.tasks.as_deref(),
+                mark.since.as_deref(),
+            )
+            .await?;
+        drop(client);
+
+        if recorded.last_cursor.is_some() {
+            mark.events = recorded.last_cursor;
+        }
+        if tasks.last_cursor.is_some() {
+            mark.tasks = tasks.last_cursor;
+        }
+        // Once finished, stop after the pass that reads its last events
+        if finished && recorded.next_cursor.is_none() && tasks.next_cursor.is_none() {
+            mark.done = true;
+        }
+        events.extend(recorded.entries.into_iter().map(WebhookEvent::from));
+        events.extend(tasks.entries.into_iter().map(WebhookEvent::from));
+    }
+    events.sort_by(|a, b| a.recorded_at.cmp(&b.recorded_at));
+    Ok(events)
+}
+
+/// Poll Convex for orchestration activity and deliver it to registered
+/// webhooks until `cancel` fires. Each delivery runs on its own task so a
+/// slow endpoint doesn't hold up the rest.
+pub fn spawn_dispatcher(
+    client: Arc<Mutex<TinaConvexClient>>,
+    registry: Arc<WebhookRegistry>,
+    cancel: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut watermarks: HashMap<String, Watermark> = HashMap::new();
+        // Whether a pass has run since webhooks were registered
+        let mut primed = false;
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    info!("webhook dispatcher stopping");
+                    break;
+                }
+                _ = tokio::time::sleep(POLL_INTERVAL) => {}
+            }
+
+            let webhooks = match registry.load() {
+                Ok(store) => store.webhooks,
+                Err(e) => {
+                    warn!(error = %e, "failed to read webhook store");
+                    continue;
+                }
+            };
+            if webhooks.is_empty() {
+                // Forget positions so a new webhook doesn't get a backlog
+                watermarks.clear();
+                primed = false;
+                continue;
+            }
+
+            let now = Utc::now().to_rfc3339();
+            let first_pass = (!primed).then_some(now.as_str());
+            let events = match poll(&client, &mut watermarks, first_pass).await {
+                Ok(events) => {
+                    primed = true;
+                    events
+                }
+                Err(e) => {
+                    warn!(error = %e, "webhook poll failed");
+                    continue;
+                }
+            };
+            for event in events {
+                for webhook in webhooks.iter().filter(|w| w.wants(&event)) {
+                    let registry = Arc::clone(&registry);
+                    let webhook = webhook.clone();
+                    let event = event.clone();
+                    tokio::spawn(async move {
+                        registry.deliver(&webhook, &event, &RETRY_DELAYS).await;
+                    });
+                }
+            }
+        }
+    })
+}
+
+/// Request body for POST /api/webhooks.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWebhookRequest {
+    pub url: String,
+    #[serde(default)]
+    pub events: Vec<String>,
+    pub orchestration_id: Option<String>,
+    /// Generated when omitted
+    pub secret: Option<String>,
+}
+
+/// A webhook as the API shows it. The secret is only returned on creation.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookView {
+    pub id: String,
+    pub url: String,
+    pub events: Vec<String>,
+    pub orchestration_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
+
+impl From<Webhook> for WebhookView {
+    fn from(webhook: Webhook) -> Self {
+        Self {
+            id: webhook.id,
+            url: webhook.url,
+            events: webhook.events,
+            orchestration_id: webhook.orchestration_id,
+            created_at: webhook.created_at,
+            secret: None,
+        }
+    }
+}
+
+fn registry(state: &AppState) -> Result<&Arc<WebhookRegistry>, ApiError> {
+    state.webhooks.as_ref().ok_or((
+        StatusCode::SERVICE_UNAVAILABLE,
+        "Webhooks not available".to_string(),
+    ))
+}
+
+fn store_error(e: anyhow::Error) -> ApiError {
+    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+}
+
+/// POST /api/webhooks handler.
+pub async fn create_webhook(
+    State(state): State<AppState>,
+    Json(request): Json<CreateWebhookRequest>,
+) -> Result<(StatusCode, Json<WebhookView>), ApiError> {
+    let registry = registry(&state)?;
+    let url = reqwest::Url::parse(&request.url)
+        .ok()
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+        .ok_or((
+            StatusCode::BAD_REQUEST,
+            "url must be an http or https URL".to_string(),
+        ))?;
+    if request.secret.as_deref().is_some_and(str::is_empty) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "secret must not be empty".to_string(),
+        ));
+    }
+
+    let webhook = Webhook {
+        id: Uuid::new_v4().simple().to_string()[..12].to_string(),
+        url: url.to_string(),
+        secret: request
+            .secret
+            .unwrap_or_else(|| format!("whsec_{}", Uuid::new_v4().simple())),
+        events: request.events,
+        orchestration_id: request.orchestration_id,
+        created_at: Utc::now(),
+    };
+    registry
+        .update(|store| store.webhooks.push(webhook.clone()))
+        .await
+        .map_err(store_error)?;
+    info!(webhook = %webhook.id, url = %webhook.url, "webhook registered");
+
+    let secret = webhook.secret.clone();
+    Ok((
+        StatusCode::CREATED,
+        Json(WebhookView {
+            secret: Some(secret),
+            ..webhook.into()
+        }),
+    ))
+}
+
+/// GET /api/webhooks handler.
+pub async fn list_webhooks(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<WebhookView>>, ApiError> {
+    let store = registry(&state)?.load().map_err(store_error)?;
+    Ok(Json(
+        store.webhooks.into_iter().map(WebhookView::from).collect(),
+    ))
+}
+
+/// DELETE /api/webhooks/{id} handler.
+pub async fn delete_webhook(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let removed = registry(&state)?
+        .update(|store| {
+            let before = store.webhooks.len();
+            store.webhooks.retain(|w| w.id != id);
+            store.webhooks.len() < before
+        })
+        .await
+        .map_err(store_error)?;
+    if !removed {
+        return Err((StatusCode::NOT_FOUND, format!("Webhook not found: {}", id)));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/webhooks/{id}/deliveries handler.
+pub async fn list_deliveries(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<Delivery>>, ApiError> {
+    let registry = registry(&state)?;
+    let store = registry.load().map_err(store_error)?;
+    if !store.webhooks.iter().any(|w| w.id == id) {
+        return Err((StatusCode::NOT_FOUND, format!("Webhook not found: {}", id)));
+    }
+    Ok(Json(registry.deliveries(&id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Bytes;
+    use axum::http::HeaderMap;
+    use axum::routing::post;
+    use axum::Router;
+    use tempfile::TempDir;
+
+    fn event(name: &str, orchestration_id: &str) -> WebhookEvent {
+        WebhookEvent {
+            event: name.to_string(),
+            orchestration_id: orchestration_id.to_string(),
+            phase_number: Some("1".to_string()),
+            recorded_at: "2026-02-10T10:00:00Z".to_string(),
+            data: serde_json::json!({}),
+        }
+    }
+
+    fn webhook(url: &str, events: &[&str], orchestration_id: Option<&str>) -> Webhook {
+        Webhook {
+            id: "wh1".to_string(),
+            url: url.to_string(),
+            secret: "shh".to_string(),
+            events: events.iter().map(|e| e.to_string()).collect(),
+            orchestration_id: orchestration_id.map(str::to_string),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_sign_matches_known_hmac() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            sign("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_webhook_filters_events_and_orchestration() {
+        let all = webhook("http://x", &[], None);
+        assert!(all.wants(&event("task_completed", "orch-1")));
+
+        let phases = webhook("http://x", &["phase_*", "orchestration_completed"], None);
+        assert!(phases.wants(&event("phase_completed", "orch-1")));
+        assert!(phases.wants(&event("orchestration_completed", "orch-1")));
+        assert!(!phases.wants(&event("task_completed", "orch-1")));
+
+        let one = webhook("http://x", &[], Some("orch-2"));
+        assert!(!one.wants(&event("phase_started", "orch-1")));
+        assert!(one.wants(&event("phase_started", "orch-2")));
+    }
+
+    #[test]
+    fn test_only_the_first_pass_skips_history() {
+        let running = Watermark::start(Some("2026-02-10T10:00:00Z"), false);
+        assert_eq!(running.since.as_deref(), Some("2026-02-10T10:00:00Z"));
+        assert!(!running.done);
+        assert!(Watermark::start(Some("2026-02-10T10:00:00Z"), true).done);
+
+        // Discovered later: read from the beginning, even if already finished
+        assert_eq!(Watermark::start(None, false), Watermark::default());
+        assert_eq!(Watermark::start(None, true), Watermark::default());
+    }
+
+    #[test]
+    fn test_task_events_are_named_by_status() {
+        let event = WebhookEvent::from(TaskEventRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: Some("2".to_string()),
+            task_id: "7".to_string(),
+            subject: "Wire routes".to_string(),
+            description: None,
+            status: "completed".to_string(),
+            owner: Some("executor-1".to_string()),
+            blocked_by: None,
+            metadata: None,
+            recorded_at: "2026-02-10T10:00:00Z".to_string(),
+        });
+        assert_eq!(event.event, "task_completed");
+        assert_eq!(event.data["taskId"], "7");
+    }
+
+    #[tokio::test]
+    async fn test_deliver_signs_body_and_logs_outcome() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let app = Router::new().route(
+            "/hook",
+            post(move |headers: HeaderMap, body: Bytes| {
+                let tx = tx.clone();
+                async move {
+                    let signature = headers["x-tina-signature"].to_str().unwrap().to_string();
+                    tx.send((signature, body)).unwrap();
+                    StatusCode::NO_CONTENT
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let dir = TempDir::new().unwrap();
+        let registry = WebhookRegistry::new(dir.path().join("webhooks.json"));
+        let hook = webhook(&format!("http://{}/hook", addr), &[], None);
+        registry
+            .deliver(&hook, &event("phase_completed", "orch-1"), &[])
+            .await;
+
+        let (signature, body) = rx.recv().await.unwrap();
+        assert_eq!(signature, sign("shh", &body));
+        let sent: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(sent["event"], "phase_completed");
+
+        // Nothing listens on the discard port
+        let unreachable = webhook("http://127.0.0.1:9/hook", &[], None);
+        registry
+            .deliver(&unreachable, &event("phase_started", "orch-1"), &[])
+            .await;
+
+        let log = registry.deliveries("wh1");
+        assert_eq!(log.len(), 2);
+        assert!(!log[0].delivered && log[0].error.is_some());
+        assert!(log[1].delivered);
+        assert_eq!(log[1].status_code, Some(204));
+        assert_eq!(log[1].attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_store_round_trips_registrations() {
+        let dir = TempDir::new().unwrap();
+        let registry = WebhookRegistry::new(dir.path().join("tina").join("webhooks.json"));
+        assert!(registry.load().unwrap().webhooks.is_empty());
+
+        registry
+            .update(|store| store.webhooks.push(webhook("http://x", &["task_*"], None)))
+            .await
+            .unwrap();
+        let store = registry.load().unwrap();
+        assert_eq!(store.webhooks.len(), 1);
+        assert_eq!(store.webhooks[0].events, ["task_*"]);
+    }
+}
@@ -0,0 +1,42 @@
+//! Clock skew against Convex server time.
+//!
+//! The daemon measures its skew with each heartbeat and the monitor measures
+//! this machine's the same way; both use these so they agree on what counts
+//! as drifting.
+
+/// Skew (in either direction) above which a clock is reported as drifting.
+pub const CLOCK_SKEW_WARN_MS: i64 = 5_000;
+
+/// Local clock minus server clock, from local send/receive times and the
+/// server timestamp. The server stamped the request somewhere inside the
+/// round trip, so its time is compared against the local midpoint.
+pub fn estimate_skew_ms(sent_ms: i64, received_ms: i64, server_ms: i64) -> i64 {
+    let round_trip_ms = (received_ms - sent_ms).max(0);
+    sent_ms + round_trip_ms / 2 - server_ms
+}
+
+/// Whether a skew is beyond [`CLOCK_SKEW_WARN_MS`].
+pub fn exceeds_warn_threshold(skew_ms: i64) -> bool {
+    skew_ms.abs() > CLOCK_SKEW_WARN_MS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_uses_round_trip_midpoint() {
+        assert_eq!(estimate_skew_ms(10_000, 10_200, 10_100), 0);
+        assert_eq!(estimate_skew_ms(20_000, 20_100, 12_050), 8_000);
+        // A clock that went backwards mid-request doesn't skew the midpoint
+        assert_eq!(estimate_skew_ms(10_000, 9_000, 10_000), 0);
+    }
+
+    #[test]
+    fn test_warn_threshold_in_both_directions() {
+        assert!(exceeds_warn_threshold(6_000));
+        assert!(exceeds_warn_threshold(-6_000));
+        assert!(!exceeds_warn_threshold(CLOCK_SKEW_WARN_MS));
+        assert!(!exceeds_warn_threshold(-4_000));
+    }
+}
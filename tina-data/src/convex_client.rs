@@ -1,7 +1,7 @@
 use std::collections::BTreeMap;
 
-use anyhow::{bail, Result};
-use convex::{ConvexClient, FunctionResult, QuerySubscription, Value};
+use anyhow::{anyhow, bail, Result};
+use convex::{ConvexClient, ConvexError, FunctionResult, QuerySubscription, Value};
 
 use crate::types::*;
 
@@ -25,6 +25,28 @@ fn node_registration_to_args(reg: &NodeRegistration) -> BTreeMap<String, Value>
     args
 }
 
+fn heartbeat_to_args(
+    node_id: &str,
+    metrics: Option<&NodeMetrics>,
+    clock_skew_ms: Option<f64>,
+) -> BTreeMap<String, Value> {
+    let mut args = BTreeMap::new();
+    args.insert("nodeId".into(), Value::from(node_id));
+    if let Some(skew) = clock_skew_ms {
+        args.insert("clockSkewMs".into(), Value::from(skew));
+    }
+    if let Some(m) = metrics {
+        args.insert("cpuLoad".into(), Value::from(m.cpu_load));
+        args.insert("cpuCount".into(), Value::from(m.cpu_count));
+        args.insert("memoryUsedBytes".into(), Value::from(m.memory_used_bytes));
+        args.insert("memoryTotalBytes".into(), Value::from(m.memory_total_bytes));
+        args.insert("diskFreeBytes".into(), Value::from(m.disk_free_bytes));
+        args.insert("diskTotalBytes".into(), Value::from(m.disk_total_bytes));
+        args.insert("tmuxSessions".into(), Value::from(m.tmux_sessions));
+    }
+    args
+}
+
 pub fn orchestration_to_args(orch: &OrchestrationRecord) -> BTreeMap<String, Value> {
     let mut args = BTreeMap::new();
     args.insert("nodeId".into(), Value::from(orch.node_id.as_str()));
@@ -248,6 +270,9 @@ fn commit_to_args(commit: &CommitRecord) -> BTreeMap<String, Value> {
     if let Some(ref subject) = commit.subject {
         args.insert("subject".into(), Value::from(subject.as_str()));
     }
+    if let Some(ref timestamp) = commit.timestamp {
+        args.insert("timestamp".into(), Value::from(timestamp.as_str()));
+    }
     args
 }
 
@@ -445,13 +470,67 @@ pub fn rollup_to_args(rollup: &RollupRecord) -> BTreeMap<String, Value> {
     args
 }
 
+/// A Convex function that rejected its call with a coded
+/// `ConvexError({ code, message })`, e.g. a `conflict` from
+/// `controlPlane:enqueueControlAction`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConvexRejection {
+    pub code: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for ConvexRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Convex error: {}", self.message)
+    }
+}
+
+impl std::error::Error for ConvexRejection {}
+
+/// Convert a thrown `ConvexError`, keeping its code when it has one.
+fn convex_error(err: ConvexError) -> anyhow::Error {
+    if let Value::Object(data) = &err.data {
+        if let (Some(Value::String(code)), Some(Value::String(message))) =
+            (data.get("code"), data.get("message"))
+        {
+            return ConvexRejection {
+                code: code.clone(),
+                message: message.clone(),
+            }
+            .into();
+        }
+    }
+    anyhow!("Convex error: {:?}", err)
+}
+
 /// Extract a string ID from a Convex FunctionResult.
 fn extract_id(result: FunctionResult) -> Result<String> {
     match result {
         FunctionResult::Value(Value::String(id)) => Ok(id),
         FunctionResult::Value(other) => bail!("expected string ID, got: {:?}", other),
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
+    }
+}
+
+/// Batches `delete_orchestration` runs before giving up.
+const DELETE_MAX_STEPS: usize = 500;
+
+/// Parse one `deleteOrchestration` step: `None` while batches remain,
+/// then whether an orchestration was deleted.
+fn extract_delete_step(result: FunctionResult) -> Result<Option<bool>> {
+    match result {
+        FunctionResult::Value(Value::Object(obj)) => {
+            if value_as_opt_bool(&obj, "done") != Some(true) {
+                return Ok(None);
+            }
+            Ok(Some(value_as_opt_bool(&obj, "deleted").unwrap_or(false)))
+        }
+        FunctionResult::Value(other) => {
+            bail!("expected object for delete result, got: {:?}", other)
+        }
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -471,7 +550,7 @@ fn extract_claim_result(result: FunctionResult) -> Result<ClaimResult> {
         }
         FunctionResult::Value(other) => bail!("expected object for claim result, got: {:?}", other),
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -489,7 +568,7 @@ fn extract_optional_state_json(result: FunctionResult) -> Result<Option<String>>
             other
         ),
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -498,7 +577,18 @@ fn extract_unit(result: FunctionResult) -> Result<()> {
     match result {
         FunctionResult::Value(_) => Ok(()),
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
+    }
+}
+
+/// Extract `serverTime` from a heartbeat result. Older deployments return
+/// null, which maps to `None`.
+fn extract_server_time(result: FunctionResult) -> Result<Option<f64>> {
+    match result {
+        FunctionResult::Value(Value::Object(obj)) => Ok(value_as_opt_f64(&obj, "serverTime")),
+        FunctionResult::Value(_) => Ok(None),
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -601,6 +691,8 @@ fn extract_phase_from_obj(obj: &BTreeMap<String, Value>) -> PhaseRecord {
         review_mins: value_as_opt_f64(obj, "reviewMins"),
         started_at: value_as_opt_str(obj, "startedAt"),
         completed_at: value_as_opt_str(obj, "completedAt"),
+        risk_score: value_as_opt_f64(obj, "riskScore"),
+        risk_signals: value_as_opt_str(obj, "riskSignals"),
     }
 }
 
@@ -651,6 +743,7 @@ fn extract_commit_from_obj(obj: &BTreeMap<String, Value>) -> CommitRecord {
         sha: value_as_str(obj, "sha"),
         short_sha: value_as_opt_str(obj, "shortSha"),
         subject: value_as_opt_str(obj, "subject"),
+        timestamp: value_as_opt_str(obj, "timestamp"),
     }
 }
 
@@ -660,6 +753,107 @@ fn extract_plan_from_obj(obj: &BTreeMap<String, Value>) -> PlanRecord {
         phase_number: value_as_str(obj, "phaseNumber"),
         plan_path: value_as_str(obj, "planPath"),
         content: value_as_str(obj, "content"),
+        last_synced: value_as_opt_str(obj, "lastSynced"),
+    }
+}
+
+fn extract_execution_task_from_obj(obj: &BTreeMap<String, Value>) -> ExecutionTaskRecord {
+    ExecutionTaskRecord {
+        orchestration_id: value_as_id(obj, "orchestrationId"),
+        phase_number: value_as_str(obj, "phaseNumber"),
+        task_number: value_as_f64(obj, "taskNumber") as u32,
+        subject: value_as_str(obj, "subject"),
+        description: value_as_opt_str(obj, "description"),
+        status: value_as_str(obj, "status"),
+        model: value_as_opt_str(obj, "model"),
+        revision: value_as_f64(obj, "revision") as u32,
+    }
+}
+
+fn extract_review_from_obj(obj: &BTreeMap<String, Value>) -> ReviewRecord {
+    ReviewRecord {
+        id: value_as_id(obj, "_id"),
+        orchestration_id: value_as_id(obj, "orchestrationId"),
+        phase_number: value_as_opt_str(obj, "phaseNumber"),
+        state: value_as_str(obj, "state"),
+        reviewer_agent: value_as_str(obj, "reviewerAgent"),
+        started_at: value_as_str(obj, "startedAt"),
+        completed_at: value_as_opt_str(obj, "completedAt"),
+    }
+}
+
+fn extract_review_thread_from_obj(obj: &BTreeMap<String, Value>) -> ReviewThreadRecord {
+    ReviewThreadRecord {
+        id: value_as_id(obj, "_id"),
+        review_id: value_as_id(obj, "reviewId"),
+        orchestration_id: value_as_id(obj, "orchestrationId"),
+        file_path: value_as_str(obj, "filePath"),
+        line: value_as_f64(obj, "line") as i64,
+        commit_sha: value_as_str(obj, "commitSha"),
+        summary: value_as_str(obj, "summary"),
+        body: value_as_str(obj, "body"),
+        severity: value_as_str(obj, "severity"),
+        status: value_as_str(obj, "status"),
+        source: value_as_str(obj, "source"),
+        author: value_as_str(obj, "author"),
+        gate_impact: value_as_str(obj, "gateImpact"),
+        created_at: value_as_str(obj, "createdAt"),
+    }
+}
+
+fn extract_review_check_from_obj(obj: &BTreeMap<String, Value>) -> ReviewCheckRecord {
+    ReviewCheckRecord {
+        id: value_as_id(obj, "_id"),
+        review_id: value_as_id(obj, "reviewId"),
+        orchestration_id: value_as_id(obj, "orchestrationId"),
+        name: value_as_str(obj, "name"),
+        kind: value_as_str(obj, "kind"),
+        command: value_as_opt_str(obj, "command"),
+        status: value_as_str(obj, "status"),
+        comment: value_as_opt_str(obj, "comment"),
+        output: value_as_opt_str(obj, "output"),
+        started_at: value_as_str(obj, "startedAt"),
+        completed_at: value_as_opt_str(obj, "completedAt"),
+    }
+}
+
+fn extract_review_gate_from_obj(obj: &BTreeMap<String, Value>) -> ReviewGateRecord {
+    ReviewGateRecord {
+        id: value_as_id(obj, "_id"),
+        orchestration_id: value_as_id(obj, "orchestrationId"),
+        gate_id: value_as_str(obj, "gateId"),
+        status: value_as_str(obj, "status"),
+        owner: value_as_str(obj, "owner"),
+        decided_by: value_as_opt_str(obj, "decidedBy"),
+        decided_at: value_as_opt_str(obj, "decidedAt"),
+        summary: value_as_str(obj, "summary"),
+        required_approvals: value_as_opt_f64(obj, "requiredApprovals").map(|n| n as u32),
+        decisions: match obj.get("decisions") {
+            Some(Value::Array(items)) => items
+                .iter()
+                .filter_map(|item| match item {
+                    Value::Object(d) => Some(GateDecisionRecord {
+                        approver: value_as_str(d, "approver"),
+                        decision: value_as_str(d, "decision"),
+                        decided_at: value_as_str(d, "decidedAt"),
+                        summary: value_as_opt_str(d, "summary"),
+                    }),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        },
+    }
+}
+
+fn extract_review_gate(result: FunctionResult) -> Result<ReviewGateRecord> {
+    match result {
+        FunctionResult::Value(Value::Object(obj)) => Ok(extract_review_gate_from_obj(&obj)),
+        FunctionResult::Value(other) => {
+            bail!("expected object for review gate, got: {:?}", other)
+        }
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -675,7 +869,7 @@ fn extract_optional_feature_orchestration(
             bail!("expected object or null for getByFeature, got: {:?}", other)
         }
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -687,7 +881,7 @@ fn extract_optional_phase_record(result: FunctionResult) -> Result<Option<PhaseR
             bail!("expected object or null for phase status, got: {:?}", other)
         }
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -707,11 +901,42 @@ fn extract_orchestration_list(result: FunctionResult) -> Result<Vec<Orchestratio
             bail!("expected array for orchestration list, got: {:?}", other)
         }
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
-fn extract_orchestration_detail(
+/// Parse a `listOrchestrationsPage` result, as delivered by
+/// [`TinaConvexClient::subscribe_orchestrations_page`].
+pub fn extract_orchestration_page(result: FunctionResult) -> Result<OrchestrationPage> {
+    match result {
+        FunctionResult::Value(Value::Object(obj)) => {
+            let entries = match obj.get("items") {
+                Some(Value::Array(items)) => items
+                    .iter()
+                    .filter_map(|v| match v {
+                        Value::Object(o) => Some(extract_orchestration_from_obj(o)),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => vec![],
+            };
+            Ok(OrchestrationPage {
+                entries,
+                next_cursor: value_as_opt_str(&obj, "nextCursor"),
+                total_count: value_as_opt_f64(&obj, "totalCount").map(|n| n as usize),
+            })
+        }
+        FunctionResult::Value(other) => {
+            bail!("expected object for orchestration page, got: {:?}", other)
+        }
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
+    }
+}
+
+/// Parse a `getOrchestrationDetail` result, as delivered by
+/// [`TinaConvexClient::subscribe_orchestration_detail`].
+pub fn extract_orchestration_detail(
     result: FunctionResult,
 ) -> Result<Option<OrchestrationDetailResponse>> {
     match result {
@@ -763,7 +988,7 @@ fn extract_orchestration_detail(
             bail!("expected object for orchestration detail, got: {:?}", other)
         }
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -780,6 +1005,7 @@ fn extract_node_list(result: FunctionResult) -> Result<Vec<NodeRecord>> {
                         status: value_as_str(&obj, "status"),
                         last_heartbeat: value_as_f64(&obj, "lastHeartbeat"),
                         registered_at: value_as_f64(&obj, "registeredAt"),
+                        clock_skew_ms: value_as_opt_f64(&obj, "clockSkewMs"),
                     });
                 }
             }
@@ -788,7 +1014,7 @@ fn extract_node_list(result: FunctionResult) -> Result<Vec<NodeRecord>> {
         FunctionResult::Value(Value::Null) => Ok(vec![]),
         FunctionResult::Value(other) => bail!("expected array for node list, got: {:?}", other),
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -812,7 +1038,7 @@ fn extract_team_record(result: FunctionResult) -> Result<Option<TeamRecord>> {
         FunctionResult::Value(Value::Object(obj)) => Ok(Some(extract_team_record_from_obj(&obj))),
         FunctionResult::Value(other) => bail!("expected object for team record, got: {:?}", other),
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -844,7 +1070,7 @@ fn extract_active_team_list(result: FunctionResult) -> Result<Vec<ActiveTeamReco
             bail!("expected array for active team list, got: {:?}", other)
         }
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -872,7 +1098,7 @@ fn extract_active_terminal_sessions(
             )
         }
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -904,7 +1130,7 @@ fn extract_team_members_with_panes(
             )
         }
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -929,7 +1155,81 @@ fn extract_orchestration_event_list(
             )
         }
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
+    }
+}
+
+fn extract_orchestration_event_page(result: FunctionResult) -> Result<OrchestrationEventPage> {
+    match result {
+        FunctionResult::Value(Value::Object(obj)) => {
+            let entries = match obj.get("items") {
+                Some(Value::Array(items)) => items
+                    .iter()
+                    .filter_map(|v| match v {
+                        Value::Object(o) => Some(extract_orchestration_event_from_obj(o)),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => vec![],
+            };
+            Ok(OrchestrationEventPage {
+                entries,
+                next_cursor: value_as_opt_str(&obj, "nextCursor"),
+                last_cursor: value_as_opt_str(&obj, "lastCursor"),
+            })
+        }
+        FunctionResult::Value(other) => {
+            bail!("expected object for event page, got: {:?}", other)
+        }
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
+    }
+}
+
+fn extract_task_event_list(result: FunctionResult) -> Result<Vec<TaskEventRecord>> {
+    match result {
+        FunctionResult::Value(Value::Array(items)) => {
+            let mut events = Vec::new();
+            for item in items {
+                if let Value::Object(obj) = item {
+                    events.push(extract_task_event_from_obj(&obj));
+                }
+            }
+            Ok(events)
+        }
+        FunctionResult::Value(Value::Null) => Ok(vec![]),
+        FunctionResult::Value(other) => {
+            bail!("expected array for task event list, got: {:?}", other)
+        }
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
+    }
+}
+
+fn extract_task_event_page(result: FunctionResult) -> Result<TaskEventPage> {
+    match result {
+        FunctionResult::Value(Value::Object(obj)) => {
+            let entries = match obj.get("items") {
+                Some(Value::Array(items)) => items
+                    .iter()
+                    .filter_map(|v| match v {
+                        Value::Object(o) => Some(extract_task_event_from_obj(o)),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => vec![],
+            };
+            Ok(TaskEventPage {
+                entries,
+                next_cursor: value_as_opt_str(&obj, "nextCursor"),
+                last_cursor: value_as_opt_str(&obj, "lastCursor"),
+            })
+        }
+        FunctionResult::Value(other) => {
+            bail!("expected object for task event page, got: {:?}", other)
+        }
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -947,7 +1247,91 @@ fn extract_commit_list(result: FunctionResult) -> Result<Vec<CommitRecord>> {
         FunctionResult::Value(Value::Null) => Ok(vec![]),
         FunctionResult::Value(other) => bail!("expected array for commit list, got: {:?}", other),
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
+    }
+}
+
+fn extract_review_list(result: FunctionResult) -> Result<Vec<ReviewRecord>> {
+    match result {
+        FunctionResult::Value(Value::Array(items)) => Ok(items
+            .into_iter()
+            .filter_map(|item| match item {
+                Value::Object(obj) => Some(extract_review_from_obj(&obj)),
+                _ => None,
+            })
+            .collect()),
+        FunctionResult::Value(Value::Null) => Ok(vec![]),
+        FunctionResult::Value(other) => {
+            bail!("expected array for review list, got: {:?}", other)
+        }
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
+    }
+}
+
+fn extract_review_thread_list(result: FunctionResult) -> Result<Vec<ReviewThreadRecord>> {
+    match result {
+        FunctionResult::Value(Value::Array(items)) => Ok(items
+            .into_iter()
+            .filter_map(|item| match item {
+                Value::Object(obj) => Some(extract_review_thread_from_obj(&obj)),
+                _ => None,
+            })
+            .collect()),
+        FunctionResult::Value(Value::Null) => Ok(vec![]),
+        FunctionResult::Value(other) => {
+            bail!("expected array for review thread list, got: {:?}", other)
+        }
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
+    }
+}
+
+fn extract_review_check_list(result: FunctionResult) -> Result<Vec<ReviewCheckRecord>> {
+    match result {
+        FunctionResult::Value(Value::Array(items)) => Ok(items
+            .into_iter()
+            .filter_map(|item| match item {
+                Value::Object(obj) => Some(extract_review_check_from_obj(&obj)),
+                _ => None,
+            })
+            .collect()),
+        FunctionResult::Value(Value::Null) => Ok(vec![]),
+        FunctionResult::Value(other) => {
+            bail!("expected array for review check list, got: {:?}", other)
+        }
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
+    }
+}
+
+fn extract_review_gate_list(result: FunctionResult) -> Result<Vec<ReviewGateRecord>> {
+    match result {
+        FunctionResult::Value(Value::Array(items)) => Ok(items
+            .into_iter()
+            .filter_map(|item| match item {
+                Value::Object(obj) => Some(extract_review_gate_from_obj(&obj)),
+                _ => None,
+            })
+            .collect()),
+        FunctionResult::Value(Value::Null) => Ok(vec![]),
+        FunctionResult::Value(other) => {
+            bail!("expected array for review gate list, got: {:?}", other)
+        }
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
+    }
+}
+
+fn extract_optional_plan(result: FunctionResult) -> Result<Option<PlanRecord>> {
+    match result {
+        FunctionResult::Value(Value::Null) => Ok(None),
+        FunctionResult::Value(Value::Object(obj)) => Ok(Some(extract_plan_from_obj(&obj))),
+        FunctionResult::Value(other) => {
+            bail!("expected object or null for plan, got: {:?}", other)
+        }
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -965,7 +1349,25 @@ fn extract_plan_list(result: FunctionResult) -> Result<Vec<PlanRecord>> {
         FunctionResult::Value(Value::Null) => Ok(vec![]),
         FunctionResult::Value(other) => bail!("expected array for plan list, got: {:?}", other),
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
+    }
+}
+
+fn extract_execution_task_list(result: FunctionResult) -> Result<Vec<ExecutionTaskRecord>> {
+    match result {
+        FunctionResult::Value(Value::Array(items)) => Ok(items
+            .into_iter()
+            .filter_map(|item| match item {
+                Value::Object(obj) => Some(extract_execution_task_from_obj(&obj)),
+                _ => None,
+            })
+            .collect()),
+        FunctionResult::Value(Value::Null) => Ok(vec![]),
+        FunctionResult::Value(other) => {
+            bail!("expected array for execution task list, got: {:?}", other)
+        }
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -1016,7 +1418,7 @@ fn extract_design_list(result: FunctionResult) -> Result<Vec<DesignRecord>> {
         FunctionResult::Value(Value::Null) => Ok(vec![]),
         FunctionResult::Value(other) => bail!("expected array for design list, got: {:?}", other),
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -1049,7 +1451,7 @@ fn extract_variation_list(result: FunctionResult) -> Result<Vec<DesignVariationR
             bail!("expected array for variation list, got: {:?}", other)
         }
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -1075,7 +1477,7 @@ fn extract_optional_spec(result: FunctionResult) -> Result<Option<SpecRecord>> {
             bail!("expected object or null for spec, got: {:?}", other)
         }
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -1093,7 +1495,62 @@ fn extract_spec_list(result: FunctionResult) -> Result<Vec<SpecRecord>> {
         FunctionResult::Value(Value::Null) => Ok(vec![]),
         FunctionResult::Value(other) => bail!("expected array for spec list, got: {:?}", other),
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
+    }
+}
+
+fn extract_spec_revision_record(obj: &BTreeMap<String, Value>) -> SpecRevisionRecord {
+    SpecRevisionRecord {
+        id: value_as_id(obj, "_id"),
+        spec_id: value_as_id(obj, "specId"),
+        revision: value_as_f64(obj, "revision") as u32,
+        title: value_as_str(obj, "title"),
+        markdown: value_as_str(obj, "markdown"),
+        created_at: value_as_str(obj, "createdAt"),
+    }
+}
+
+fn extract_spec_revision_list(result: FunctionResult) -> Result<Vec<SpecRevisionRecord>> {
+    match result {
+        FunctionResult::Value(Value::Array(items)) => Ok(items
+            .iter()
+            .filter_map(|item| match item {
+                Value::Object(obj) => Some(extract_spec_revision_record(obj)),
+                _ => None,
+            })
+            .collect()),
+        FunctionResult::Value(Value::Null) => Ok(vec![]),
+        FunctionResult::Value(other) => {
+            bail!("expected array for spec revisions, got: {:?}", other)
+        }
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
+    }
+}
+
+fn extract_project_record(obj: &BTreeMap<String, Value>) -> ProjectRecord {
+    ProjectRecord {
+        id: value_as_id(obj, "_id"),
+        name: value_as_str(obj, "name"),
+        repo_path: value_as_str(obj, "repoPath"),
+        created_at: value_as_str(obj, "createdAt"),
+        orchestration_count: value_as_opt_f64(obj, "orchestrationCount").map(|n| n as u32),
+    }
+}
+
+fn extract_project_list(result: FunctionResult) -> Result<Vec<ProjectRecord>> {
+    match result {
+        FunctionResult::Value(Value::Array(items)) => Ok(items
+            .iter()
+            .filter_map(|item| match item {
+                Value::Object(obj) => Some(extract_project_record(obj)),
+                _ => None,
+            })
+            .collect()),
+        FunctionResult::Value(Value::Null) => Ok(vec![]),
+        FunctionResult::Value(other) => bail!("expected array for project list, got: {:?}", other),
+        FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -1123,7 +1580,7 @@ fn extract_optional_ticket(result: FunctionResult) -> Result<Option<TicketRecord
             bail!("expected object or null for ticket, got: {:?}", other)
         }
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -1141,7 +1598,7 @@ fn extract_ticket_list(result: FunctionResult) -> Result<Vec<TicketRecord>> {
         FunctionResult::Value(Value::Null) => Ok(vec![]),
         FunctionResult::Value(other) => bail!("expected array for ticket list, got: {:?}", other),
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -1173,7 +1630,7 @@ fn extract_comment_list(result: FunctionResult) -> Result<Vec<CommentRecord>> {
         FunctionResult::Value(Value::Null) => Ok(vec![]),
         FunctionResult::Value(other) => bail!("expected array for comment list, got: {:?}", other),
         FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
-        FunctionResult::ConvexError(err) => bail!("Convex error: {:?}", err),
+        FunctionResult::ConvexError(err) => Err(convex_error(err)),
     }
 }
 
@@ -1191,12 +1648,30 @@ impl TinaConvexClient {
         extract_id(result)
     }
 
-    /// Send a heartbeat for a node.
-    pub async fn heartbeat(&mut self, node_id: &str) -> Result<()> {
-        let mut args = BTreeMap::new();
-        args.insert("nodeId".into(), Value::from(node_id));
+    /// Send a heartbeat for a node, optionally with host resource metrics and
+    /// the clock skew measured on the previous heartbeat.
+    ///
+    /// Returns the Convex server time (epoch millis) when the deployment
+    /// reports it.
+    pub async fn heartbeat(
+        &mut self,
+        node_id: &str,
+        metrics: Option<&NodeMetrics>,
+        clock_skew_ms: Option<f64>,
+    ) -> Result<Option<f64>> {
+        let args = heartbeat_to_args(node_id, metrics, clock_skew_ms);
         let result = self.client.mutation("nodes:heartbeat", args).await?;
-        extract_unit(result)
+        extract_server_time(result)
+    }
+
+    /// Current Convex server time (epoch millis), if the deployment
+    /// reports it.
+    pub async fn server_time(&mut self) -> Result<Option<f64>> {
+        let result = self
+            .client
+            .mutation("nodes:serverTime", BTreeMap::new())
+            .await?;
+        extract_server_time(result)
     }
 
     /// Find or create a project by repo path.
@@ -1211,6 +1686,15 @@ impl TinaConvexClient {
         extract_id(result)
     }
 
+    /// List all projects, by name.
+    pub async fn list_projects(&mut self) -> Result<Vec<ProjectRecord>> {
+        let result = self
+            .client
+            .query("projects:listProjects", BTreeMap::new())
+            .await?;
+        extract_project_list(result)
+    }
+
     /// Create or update an orchestration record.
     pub async fn upsert_orchestration(&mut self, orch: &OrchestrationRecord) -> Result<String> {
         let args = orchestration_to_args(orch);
@@ -1228,6 +1712,23 @@ impl TinaConvexClient {
         extract_id(result)
     }
 
+    /// Attach a risk score (and the JSON signals behind it) to a phase.
+    pub async fn record_phase_risk(
+        &mut self,
+        orchestration_id: &str,
+        phase_number: &str,
+        risk_score: f64,
+        risk_signals: &str,
+    ) -> Result<String> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        args.insert("phaseNumber".into(), Value::from(phase_number));
+        args.insert("riskScore".into(), Value::from(risk_score));
+        args.insert("riskSignals".into(), Value::from(risk_signals));
+        let result = self.client.mutation("phases:recordPhaseRisk", args).await?;
+        extract_id(result)
+    }
+
     /// Record a task event (append-only).
     pub async fn record_task_event(&mut self, event: &TaskEventRecord) -> Result<String> {
         let args = task_event_to_args(event);
@@ -1281,6 +1782,51 @@ impl TinaConvexClient {
         extract_id(result)
     }
 
+    /// Queue a control-plane action (pause, resume, retry, ...) for the
+    /// orchestration's node and return the control action ID.
+    ///
+    /// `payload` is the action's JSON payload; resubmitting the same
+    /// `idempotency_key` returns the existing action.
+    pub async fn enqueue_control_action(
+        &mut self,
+        orchestration_id: &str,
+        node_id: &str,
+        action_type: &str,
+        payload: &str,
+        requested_by: &str,
+        idempotency_key: &str,
+    ) -> Result<String> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        args.insert("nodeId".into(), Value::from(node_id));
+        args.insert("actionType".into(), Value::from(action_type));
+        args.insert("payload".into(), Value::from(payload));
+        args.insert("requestedBy".into(), Value::from(requested_by));
+        args.insert("idempotencyKey".into(), Value::from(idempotency_key));
+        let result = self
+            .client
+            .mutation("controlPlane:enqueueControlAction", args)
+            .await?;
+        extract_id(result)
+    }
+
+    /// Queue an inbound action for a node's daemon and return its ID.
+    pub async fn submit_action(
+        &mut self,
+        node_id: &str,
+        orchestration_id: &str,
+        action_type: &str,
+        payload: &str,
+    ) -> Result<String> {
+        let mut args = BTreeMap::new();
+        args.insert("nodeId".into(), Value::from(node_id));
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        args.insert("type".into(), Value::from(action_type));
+        args.insert("payload".into(), Value::from(payload));
+        let result = self.client.mutation("actions:submitAction", args).await?;
+        extract_id(result)
+    }
+
     /// Claim an inbound action (atomic pending -> claimed transition).
     pub async fn claim_action(&mut self, action_id: &str) -> Result<ClaimResult> {
         let mut args = BTreeMap::new();
@@ -1304,6 +1850,61 @@ impl TinaConvexClient {
         extract_unit(result)
     }
 
+    /// Take ownership of a pending scheduled action (pending -> scheduled).
+    pub async fn schedule_action(&mut self, action_id: &str, run_at: f64) -> Result<ClaimResult> {
+        let mut args = BTreeMap::new();
+        args.insert("actionId".into(), Value::from(action_id));
+        args.insert("runAt".into(), Value::from(run_at));
+        let result = self.client.mutation("actions:scheduleAction", args).await?;
+        extract_claim_result(result)
+    }
+
+    /// Start a due scheduled action (scheduled -> claimed).
+    ///
+    /// `run_at` is the due time the timer fired for; the claim fails if the
+    /// action has since been rescheduled.
+    pub async fn start_scheduled_run(&mut self, action_id: &str, run_at: f64) -> Result<ClaimResult> {
+        let mut args = BTreeMap::new();
+        args.insert("actionId".into(), Value::from(action_id));
+        args.insert("runAt".into(), Value::from(run_at));
+        let result = self
+            .client
+            .mutation("actions:startScheduledRun", args)
+            .await?;
+        extract_claim_result(result)
+    }
+
+    /// Record a recurring action's run and return it to scheduled at `next_run_at`.
+    pub async fn reschedule_action(
+        &mut self,
+        action_id: &str,
+        result_msg: &str,
+        success: bool,
+        next_run_at: f64,
+    ) -> Result<()> {
+        let mut args = BTreeMap::new();
+        args.insert("actionId".into(), Value::from(action_id));
+        args.insert("result".into(), Value::from(result_msg));
+        args.insert("success".into(), Value::from(success));
+        args.insert("nextRunAt".into(), Value::from(next_run_at));
+        let result = self.client.mutation("actions:completeAction", args).await?;
+        extract_unit(result)
+    }
+
+    /// Subscribe to scheduled actions owned by a node.
+    pub async fn subscribe_scheduled_actions(
+        &mut self,
+        node_id: &str,
+    ) -> Result<QuerySubscription> {
+        let mut args = BTreeMap::new();
+        args.insert("nodeId".into(), Value::from(node_id));
+        let sub = self
+            .client
+            .subscribe("actions:scheduledActions", args)
+            .await?;
+        Ok(sub)
+    }
+
     /// Subscribe to pending actions for a node.
     /// Returns a raw QuerySubscription that the caller can stream.
     pub async fn subscribe_pending_actions(&mut self, node_id: &str) -> Result<QuerySubscription> {
@@ -1364,6 +1965,97 @@ impl TinaConvexClient {
         extract_orchestration_list(result)
     }
 
+    /// List one page of orchestrations matching `filter`, newest first
+    /// unless it asks otherwise. Pass the previous page's `next_cursor`,
+    /// with the same filter, to continue.
+    pub async fn list_orchestrations_page(
+        &mut self,
+        limit: usize,
+        cursor: Option<&str>,
+        filter: &OrchestrationFilter,
+    ) -> Result<OrchestrationPage> {
+        let mut args = BTreeMap::new();
+        // Convex v.number() validates as float64; send an f64 literal.
+        args.insert("limit".into(), Value::from(limit as f64));
+        if let Some(cursor) = cursor {
+            args.insert("cursor".into(), Value::from(cursor));
+        }
+        if filter.include_archived {
+            args.insert("includeArchived".into(), Value::from(true));
+        }
+        if let Some(status) = &filter.status {
+            args.insert("status".into(), Value::from(status.as_str()));
+        }
+        if let Some(project_id) = &filter.project_id {
+            args.insert("projectId".into(), Value::from(project_id.as_str()));
+        }
+        if let Some(started_after) = &filter.started_after {
+            args.insert("startedAfter".into(), Value::from(started_after.as_str()));
+        }
+        if let Some(started_before) = &filter.started_before {
+            args.insert("startedBefore".into(), Value::from(started_before.as_str()));
+        }
+        if filter.oldest_first {
+            args.insert("order".into(), Value::from("asc"));
+        }
+        if filter.count {
+            args.insert("count".into(), Value::from(true));
+        }
+        let result = self
+            .client
+            .query("orchestrations:listOrchestrationsPage", args)
+            .await?;
+        extract_orchestration_page(result)
+    }
+
+    /// Archive an orchestration, or restore it with `archived` false.
+    /// Returns false if there is no such orchestration.
+    pub async fn set_orchestration_archived(
+        &mut self,
+        orchestration_id: &str,
+        archived: bool,
+    ) -> Result<bool> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        args.insert("archived".into(), Value::from(archived));
+        let result = self
+            .client
+            .mutation("orchestrations:setArchived", args)
+            .await?;
+        match result {
+            FunctionResult::Value(Value::Object(obj)) => {
+                Ok(value_as_opt_bool(&obj, "found").unwrap_or(false))
+            }
+            FunctionResult::Value(other) => {
+                bail!("expected object for archive result, got: {:?}", other)
+            }
+            FunctionResult::ErrorMessage(msg) => bail!("Convex error: {}", msg),
+            FunctionResult::ConvexError(err) => Err(convex_error(err)),
+        }
+    }
+
+    /// Delete an orchestration and everything recorded for it. Convex
+    /// deletes in batches, so this repeats the mutation until it reports
+    /// done. Returns false if there is no such orchestration.
+    pub async fn delete_orchestration(&mut self, orchestration_id: &str) -> Result<bool> {
+        for _ in 0..DELETE_MAX_STEPS {
+            let mut args = BTreeMap::new();
+            args.insert("orchestrationId".into(), Value::from(orchestration_id));
+            let result = self
+                .client
+                .mutation("orchestrations:deleteOrchestration", args)
+                .await?;
+            if let Some(deleted) = extract_delete_step(result)? {
+                return Ok(deleted);
+            }
+        }
+        bail!(
+            "deleting orchestration {} did not finish after {} steps",
+            orchestration_id,
+            DELETE_MAX_STEPS
+        )
+    }
+
     /// Get full detail for an orchestration (phases, tasks, team members).
     pub async fn get_orchestration_detail(
         &mut self,
@@ -1378,6 +2070,32 @@ impl TinaConvexClient {
         extract_orchestration_detail(result)
     }
 
+    /// Subscribe to the first page of orchestrations, newest first.
+    /// Parse each result with [`extract_orchestration_page`].
+    pub async fn subscribe_orchestrations_page(
+        &mut self,
+        limit: usize,
+    ) -> Result<QuerySubscription> {
+        let mut args = BTreeMap::new();
+        args.insert("limit".into(), Value::from(limit as f64));
+        self.client
+            .subscribe("orchestrations:listOrchestrationsPage", args)
+            .await
+    }
+
+    /// Subscribe to an orchestration's detail (phases, tasks, team members).
+    /// Parse each result with [`extract_orchestration_detail`].
+    pub async fn subscribe_orchestration_detail(
+        &mut self,
+        orchestration_id: &str,
+    ) -> Result<QuerySubscription> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        self.client
+            .subscribe("orchestrations:getOrchestrationDetail", args)
+            .await
+    }
+
     /// List orchestration events for an orchestration, optionally filtered.
     pub async fn list_events(
         &mut self,
@@ -1402,6 +2120,104 @@ impl TinaConvexClient {
         extract_orchestration_event_list(result)
     }
 
+    /// List one page of an orchestration's events matching `filter`, oldest
+    /// first unless it asks otherwise. Pass the previous page's
+    /// `next_cursor`, with the same filter, to continue.
+    pub async fn list_events_page(
+        &mut self,
+        orchestration_id: &str,
+        limit: usize,
+        cursor: Option<&str>,
+        filter: &EventFilter,
+    ) -> Result<OrchestrationEventPage> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        // Convex v.number() validates as float64; send an f64 literal.
+        args.insert("limit".into(), Value::from(limit as f64));
+        if let Some(cursor) = cursor {
+            args.insert("cursor".into(), Value::from(cursor));
+        }
+        if let Some(event_type) = &filter.event_type {
+            args.insert("eventType".into(), Value::from(event_type.as_str()));
+        }
+        if let Some(since) = &filter.since {
+            args.insert("since".into(), Value::from(since.as_str()));
+        }
+        if let Some(until) = &filter.until {
+            args.insert("until".into(), Value::from(until.as_str()));
+        }
+        if filter.newest_first {
+            args.insert("order".into(), Value::from("desc"));
+        }
+        let result = self.client.query("events:listEventsPage", args).await?;
+        extract_orchestration_event_page(result)
+    }
+
+    /// List every recorded event for one task, oldest first.
+    pub async fn list_task_events(
+        &mut self,
+        orchestration_id: &str,
+        task_id: &str,
+    ) -> Result<Vec<TaskEventRecord>> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        args.insert("taskId".into(), Value::from(task_id));
+        let result = self.client.query("tasks:listTaskEvents", args).await?;
+        extract_task_event_list(result)
+    }
+
+    /// List task events across all tasks of an orchestration, oldest first.
+    ///
+    /// `since` is exclusive, so callers page forward by passing the last
+    /// `recorded_at` they received.
+    pub async fn list_orchestration_task_events(
+        &mut self,
+        orchestration_id: &str,
+        since: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<TaskEventRecord>> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        if let Some(since) = since {
+            args.insert("since".into(), Value::from(since));
+        }
+        if let Some(limit) = limit {
+            args.insert("limit".into(), Value::from(limit as f64));
+        }
+        let result = self
+            .client
+            .query("tasks:listOrchestrationTaskEvents", args)
+            .await?;
+        extract_task_event_list(result)
+    }
+
+    /// List one page of an orchestration's task events, oldest first, after
+    /// `cursor` (a previous page's `next_cursor` or `last_cursor`) and
+    /// recorded after `since`.
+    pub async fn list_orchestration_task_events_page(
+        &mut self,
+        orchestration_id: &str,
+        limit: usize,
+        cursor: Option<&str>,
+        since: Option<&str>,
+    ) -> Result<TaskEventPage> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        // Convex v.number() validates as float64; send an f64 literal.
+        args.insert("limit".into(), Value::from(limit as f64));
+        if let Some(cursor) = cursor {
+            args.insert("cursor".into(), Value::from(cursor));
+        }
+        if let Some(since) = since {
+            args.insert("since".into(), Value::from(since));
+        }
+        let result = self
+            .client
+            .query("tasks:listOrchestrationTaskEventsPage", args)
+            .await?;
+        extract_task_event_page(result)
+    }
+
     /// List all registered nodes.
     pub async fn list_nodes(&mut self) -> Result<Vec<NodeRecord>> {
         let args = BTreeMap::new();
@@ -1503,6 +2319,35 @@ impl TinaConvexClient {
         extract_plan_list(result)
     }
 
+    /// Get the plan synced for one phase of an orchestration.
+    pub async fn get_plan(
+        &mut self,
+        orchestration_id: &str,
+        phase_number: &str,
+    ) -> Result<Option<PlanRecord>> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        args.insert("phaseNumber".into(), Value::from(phase_number));
+        let result = self.client.query("plans:getPlan", args).await?;
+        extract_optional_plan(result)
+    }
+
+    /// List planned execution tasks for an orchestration phase.
+    pub async fn list_execution_tasks(
+        &mut self,
+        orchestration_id: &str,
+        phase_number: &str,
+    ) -> Result<Vec<ExecutionTaskRecord>> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        args.insert("phaseNumber".into(), Value::from(phase_number));
+        let result = self
+            .client
+            .query("executionTasks:listExecutionTasks", args)
+            .await?;
+        extract_execution_task_list(result)
+    }
+
     /// Record a telemetry span (dedups by spanId).
     pub async fn record_telemetry_span(&mut self, span: &SpanRecord) -> Result<String> {
         let args = span_to_args(span);
@@ -1572,6 +2417,14 @@ impl TinaConvexClient {
         extract_spec_list(result)
     }
 
+    /// List a spec's revisions, newest first.
+    pub async fn list_spec_revisions(&mut self, spec_id: &str) -> Result<Vec<SpecRevisionRecord>> {
+        let mut args = BTreeMap::new();
+        args.insert("specId".into(), Value::from(spec_id));
+        let result = self.client.query("specs:listSpecRevisions", args).await?;
+        extract_spec_revision_list(result)
+    }
+
     /// Update a spec.
     pub async fn update_spec(
         &mut self,
@@ -1915,6 +2768,17 @@ impl TinaConvexClient {
         extract_unit(result)
     }
 
+    /// List reviews for an orchestration, newest first.
+    pub async fn list_reviews(&mut self, orchestration_id: &str) -> Result<Vec<ReviewRecord>> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        let result = self
+            .client
+            .query("reviews:listReviewsByOrchestration", args)
+            .await?;
+        extract_review_list(result)
+    }
+
     /// Create a review thread (finding).
     pub async fn create_review_thread(
         &mut self,
@@ -1949,6 +2813,48 @@ impl TinaConvexClient {
         extract_id(result)
     }
 
+    /// List review threads (findings) for an orchestration.
+    pub async fn list_review_threads(
+        &mut self,
+        orchestration_id: &str,
+    ) -> Result<Vec<ReviewThreadRecord>> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        let result = self
+            .client
+            .query("reviewThreads:listThreadsByOrchestration", args)
+            .await?;
+        extract_review_thread_list(result)
+    }
+
+    /// List review check runs for an orchestration, oldest first.
+    pub async fn list_review_checks(
+        &mut self,
+        orchestration_id: &str,
+    ) -> Result<Vec<ReviewCheckRecord>> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        let result = self
+            .client
+            .query("reviewChecks:listChecksByOrchestration", args)
+            .await?;
+        extract_review_check_list(result)
+    }
+
+    /// List review gates (plan, review, finalize) for an orchestration.
+    pub async fn list_review_gates(
+        &mut self,
+        orchestration_id: &str,
+    ) -> Result<Vec<ReviewGateRecord>> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        let result = self
+            .client
+            .query("reviewGates:listGatesByOrchestration", args)
+            .await?;
+        extract_review_gate_list(result)
+    }
+
     /// Resolve a review thread.
     pub async fn resolve_review_thread(
         &mut self,
@@ -2038,6 +2944,36 @@ impl TinaConvexClient {
         extract_id(result)
     }
 
+    /// Record one approver's decision on a gate and return the gate with
+    /// its re-derived status.
+    ///
+    /// `required_approvals` updates the gate's quorum; `None` keeps the
+    /// current one.
+    pub async fn record_gate_decision(
+        &mut self,
+        orchestration_id: &str,
+        gate_id: &str,
+        approver: &str,
+        decision: &str,
+        summary: &str,
+        required_approvals: Option<u32>,
+    ) -> Result<ReviewGateRecord> {
+        let mut args = BTreeMap::new();
+        args.insert("orchestrationId".into(), Value::from(orchestration_id));
+        args.insert("gateId".into(), Value::from(gate_id));
+        args.insert("approver".into(), Value::from(approver));
+        args.insert("decision".into(), Value::from(decision));
+        args.insert("summary".into(), Value::from(summary));
+        if let Some(n) = required_approvals {
+            args.insert("requiredApprovals".into(), Value::from(n as f64));
+        }
+        let result = self
+            .client
+            .mutation("reviewGates:recordGateDecision", args)
+            .await?;
+        extract_review_gate(result)
+    }
+
     /// Create or update a terminal session record.
     pub async fn upsert_terminal_session(
         &mut self,
@@ -2114,6 +3050,74 @@ mod tests {
         assert_eq!(args.len(), 3);
     }
 
+    #[test]
+    fn test_heartbeat_to_args_with_metrics() {
+        let metrics = NodeMetrics {
+            cpu_load: 2.5,
+            cpu_count: 8.0,
+            memory_used_bytes: 4096.0,
+            memory_total_bytes: 16384.0,
+            disk_free_bytes: 1000.0,
+            disk_total_bytes: 5000.0,
+            tmux_sessions: 3.0,
+        };
+
+        let args = heartbeat_to_args("node-1", Some(&metrics), None);
+
+        assert_eq!(args.get("nodeId"), Some(&Value::from("node-1")));
+        assert_eq!(args.get("cpuLoad"), Some(&Value::from(2.5)));
+        assert_eq!(args.get("diskFreeBytes"), Some(&Value::from(1000.0)));
+        assert_eq!(args.get("tmuxSessions"), Some(&Value::from(3.0)));
+        assert_eq!(args.len(), 8);
+    }
+
+    #[test]
+    fn test_heartbeat_to_args_without_metrics() {
+        let args = heartbeat_to_args("node-1", None, None);
+        assert_eq!(args.len(), 1);
+    }
+
+    #[test]
+    fn test_heartbeat_to_args_with_clock_skew() {
+        let args = heartbeat_to_args("node-1", None, Some(-1250.0));
+        assert_eq!(args.get("clockSkewMs"), Some(&Value::from(-1250.0)));
+        assert_eq!(args.len(), 2);
+    }
+
+    #[test]
+    fn test_extract_server_time() {
+        let mut obj = BTreeMap::new();
+        obj.insert("serverTime".to_string(), Value::from(1_700_000_000_000.0));
+        let result = FunctionResult::Value(Value::Object(obj));
+        assert_eq!(extract_server_time(result).unwrap(), Some(1_700_000_000_000.0));
+        assert_eq!(
+            extract_server_time(FunctionResult::Value(Value::Null)).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_extract_node_list_reads_clock_skew() {
+        let node = |name: &str, skew: Option<f64>| {
+            let mut obj = BTreeMap::new();
+            obj.insert("_id".to_string(), Value::from(format!("id-{}", name)));
+            obj.insert("name".to_string(), Value::from(name));
+            obj.insert("lastHeartbeat".to_string(), Value::from(1_000.0));
+            if let Some(skew) = skew {
+                obj.insert("clockSkewMs".to_string(), Value::from(skew));
+            }
+            Value::Object(obj)
+        };
+        let result = FunctionResult::Value(Value::Array(vec![
+            node("a", Some(-7_500.0)),
+            node("b", None),
+        ]));
+
+        let nodes = extract_node_list(result).unwrap();
+        assert_eq!(nodes[0].clock_skew_ms, Some(-7_500.0));
+        assert_eq!(nodes[1].clock_skew_ms, None);
+    }
+
     #[test]
     fn test_orchestration_to_args_all_fields() {
         let orch = OrchestrationRecord {
@@ -2243,6 +3247,8 @@ mod tests {
             review_mins: Some(3.0),
             started_at: Some("2026-02-07T10:00:00Z".to_string()),
             completed_at: Some("2026-02-07T10:23:00Z".to_string()),
+            risk_score: None,
+            risk_signals: None,
         };
 
         let args = phase_to_args(&phase);
@@ -2271,6 +3277,8 @@ mod tests {
             review_mins: None,
             started_at: None,
             completed_at: None,
+            risk_score: None,
+            risk_signals: None,
         };
 
         let args = phase_to_args(&phase);
@@ -2288,6 +3296,7 @@ mod tests {
             sha: "abc123".to_string(),
             short_sha: None,
             subject: None,
+            timestamp: None,
         };
 
         let args = commit_to_args(&commit);
@@ -2523,6 +3532,36 @@ mod tests {
         assert!(err.to_string().contains("something went wrong"));
     }
 
+    #[test]
+    fn test_extract_id_keeps_convex_error_code() {
+        let data = BTreeMap::from([
+            ("code".to_string(), Value::from("conflict")),
+            ("message".to_string(), Value::from("Task revision conflict")),
+        ]);
+        let result = FunctionResult::ConvexError(ConvexError {
+            message: "Task revision conflict".into(),
+            data: Value::Object(data),
+        });
+        let err = extract_id(result).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ConvexRejection>(),
+            Some(&ConvexRejection {
+                code: "conflict".to_string(),
+                message: "Task revision conflict".to_string(),
+            })
+        );
+        assert_eq!(err.to_string(), "Convex error: Task revision conflict");
+
+        let result = FunctionResult::ConvexError(ConvexError {
+            message: "boom".into(),
+            data: Value::from("boom"),
+        });
+        assert!(extract_id(result)
+            .unwrap_err()
+            .downcast_ref::<ConvexRejection>()
+            .is_none());
+    }
+
     #[test]
     fn test_extract_claim_result_success() {
         let mut map = BTreeMap::new();
@@ -2553,6 +3592,113 @@ mod tests {
         assert!(err.to_string().contains("expected object"));
     }
 
+    #[test]
+    fn test_extract_delete_step() {
+        let step = |done: bool, deleted: bool| {
+            let mut map = BTreeMap::new();
+            map.insert("done".to_string(), Value::from(done));
+            map.insert("deleted".to_string(), Value::from(deleted));
+            FunctionResult::Value(Value::Object(map))
+        };
+
+        assert_eq!(extract_delete_step(step(false, false)).unwrap(), None);
+        assert_eq!(extract_delete_step(step(true, true)).unwrap(), Some(true));
+        assert_eq!(extract_delete_step(step(true, false)).unwrap(), Some(false));
+        assert!(extract_delete_step(FunctionResult::ErrorMessage("boom".into())).is_err());
+    }
+
+    #[test]
+    fn test_extract_orchestration_page() {
+        let mut item = BTreeMap::new();
+        item.insert("_id".to_string(), Value::from("orch-1"));
+        item.insert("nodeName".to_string(), Value::from("laptop"));
+        item.insert("featureName".to_string(), Value::from("auth"));
+        item.insert("startedAt".to_string(), Value::from("2026-02-08T10:00:00Z"));
+        let mut map = BTreeMap::new();
+        map.insert("items".to_string(), Value::Array(vec![Value::Object(item)]));
+        map.insert(
+            "nextCursor".to_string(),
+            Value::from("[\"2026-02-08T10:00:00Z\",1]"),
+        );
+        map.insert("totalCount".to_string(), Value::from(120.0f64));
+
+        let page = extract_orchestration_page(FunctionResult::Value(Value::Object(map))).unwrap();
+        assert_eq!(page.entries.len(), 1);
+        assert_eq!(page.entries[0].record.feature_name, "auth");
+        assert_eq!(
+            page.next_cursor.as_deref(),
+            Some("[\"2026-02-08T10:00:00Z\",1]")
+        );
+        assert_eq!(page.total_count, Some(120));
+
+        let mut last = BTreeMap::new();
+        last.insert("items".to_string(), Value::Array(vec![]));
+        last.insert("nextCursor".to_string(), Value::Null);
+        last.insert("totalCount".to_string(), Value::Null);
+        let page = extract_orchestration_page(FunctionResult::Value(Value::Object(last))).unwrap();
+        assert!(page.next_cursor.is_none());
+        assert!(page.total_count.is_none());
+    }
+
+    #[test]
+    fn test_extract_orchestration_event_page() {
+        let mut item = BTreeMap::new();
+        item.insert("orchestrationId".to_string(), Value::from("orch-1"));
+        item.insert("eventType".to_string(), Value::from("phase_started"));
+        item.insert("source".to_string(), Value::from("tina-session"));
+        item.insert("summary".to_string(), Value::from("Phase 1 started"));
+        item.insert(
+            "recordedAt".to_string(),
+            Value::from("2026-02-10T10:00:00Z"),
+        );
+        let mut map = BTreeMap::new();
+        map.insert("items".to_string(), Value::Array(vec![Value::Object(item)]));
+        map.insert(
+            "nextCursor".to_string(),
+            Value::from("[\"2026-02-10T10:00:00Z\",1]"),
+        );
+
+        let page =
+            extract_orchestration_event_page(FunctionResult::Value(Value::Object(map))).unwrap();
+        assert_eq!(page.entries.len(), 1);
+        assert_eq!(page.entries[0].event_type, "phase_started");
+        assert_eq!(
+            page.next_cursor.as_deref(),
+            Some("[\"2026-02-10T10:00:00Z\",1]")
+        );
+        assert_eq!(page.last_cursor, None);
+        assert!(extract_orchestration_event_page(FunctionResult::Value(Value::Null)).is_err());
+    }
+
+    #[test]
+    fn test_extract_task_event_page() {
+        let mut item = BTreeMap::new();
+        item.insert("orchestrationId".to_string(), Value::from("orch-1"));
+        item.insert("taskId".to_string(), Value::from("3"));
+        item.insert("subject".to_string(), Value::from("Add login"));
+        item.insert("status".to_string(), Value::from("completed"));
+        item.insert(
+            "recordedAt".to_string(),
+            Value::from("2026-02-10T10:05:00Z"),
+        );
+        let mut map = BTreeMap::new();
+        map.insert("items".to_string(), Value::Array(vec![Value::Object(item)]));
+        map.insert("nextCursor".to_string(), Value::Null);
+        map.insert(
+            "lastCursor".to_string(),
+            Value::from("[\"2026-02-10T10:05:00Z\",7]"),
+        );
+
+        let page = extract_task_event_page(FunctionResult::Value(Value::Object(map))).unwrap();
+        assert_eq!(page.entries.len(), 1);
+        assert_eq!(page.entries[0].task_id, "3");
+        assert_eq!(page.next_cursor, None);
+        assert_eq!(
+            page.last_cursor.as_deref(),
+            Some("[\"2026-02-10T10:05:00Z\",7]")
+        );
+    }
+
     #[test]
     fn test_extract_unit_success() {
         let result = FunctionResult::Value(Value::Null);
@@ -2565,6 +3711,134 @@ mod tests {
         assert!(extract_unit(result).is_err());
     }
 
+    #[test]
+    fn test_extract_review_list() {
+        let mut map = BTreeMap::new();
+        map.insert("_id".to_string(), Value::from("review-1"));
+        map.insert("orchestrationId".to_string(), Value::from("orch-1"));
+        map.insert("phaseNumber".to_string(), Value::from("2"));
+        map.insert("state".to_string(), Value::from("changes_requested"));
+        map.insert("reviewerAgent".to_string(), Value::from("reviewer"));
+        map.insert("startedAt".to_string(), Value::from("2026-02-13T10:00:00Z"));
+        let result = FunctionResult::Value(Value::Array(vec![Value::Object(map)]));
+
+        let reviews = extract_review_list(result).unwrap();
+        assert_eq!(reviews.len(), 1);
+        assert_eq!(reviews[0].phase_number.as_deref(), Some("2"));
+        assert_eq!(reviews[0].state, "changes_requested");
+        assert_eq!(reviews[0].completed_at, None);
+    }
+
+    #[test]
+    fn test_extract_review_thread_list() {
+        let mut map = BTreeMap::new();
+        map.insert("_id".to_string(), Value::from("thread-1"));
+        map.insert("orchestrationId".to_string(), Value::from("orch-1"));
+        map.insert("filePath".to_string(), Value::from("src/sync.rs"));
+        map.insert("line".to_string(), Value::from(118.0f64));
+        map.insert("severity".to_string(), Value::from("p1"));
+        map.insert("status".to_string(), Value::from("unresolved"));
+        let result = FunctionResult::Value(Value::Array(vec![Value::Object(map)]));
+
+        let threads = extract_review_thread_list(result).unwrap();
+        assert_eq!(threads.len(), 1);
+        assert_eq!(threads[0].id, "thread-1");
+        assert_eq!(threads[0].file_path, "src/sync.rs");
+        assert_eq!(threads[0].line, 118);
+        assert_eq!(threads[0].severity, "p1");
+    }
+
+    #[test]
+    fn test_extract_execution_task_list() {
+        let mut map = BTreeMap::new();
+        map.insert("orchestrationId".to_string(), Value::from("orch-1"));
+        map.insert("phaseNumber".to_string(), Value::from("2"));
+        map.insert("taskNumber".to_string(), Value::from(3.0f64));
+        map.insert("subject".to_string(), Value::from("Add retries"));
+        map.insert("status".to_string(), Value::from("pending"));
+        map.insert("model".to_string(), Value::from("sonnet"));
+        map.insert("revision".to_string(), Value::from(4.0f64));
+        let result = FunctionResult::Value(Value::Array(vec![Value::Object(map)]));
+
+        let tasks = extract_execution_task_list(result).unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].task_number, 3);
+        assert_eq!(tasks[0].subject, "Add retries");
+        assert_eq!(tasks[0].description, None);
+        assert_eq!(tasks[0].model.as_deref(), Some("sonnet"));
+        assert_eq!(tasks[0].revision, 4);
+    }
+
+    #[test]
+    fn test_extract_review_check_list() {
+        let mut map = BTreeMap::new();
+        map.insert("_id".to_string(), Value::from("check-1"));
+        map.insert("reviewId".to_string(), Value::from("review-1"));
+        map.insert("name".to_string(), Value::from("clippy"));
+        map.insert("kind".to_string(), Value::from("cli"));
+        map.insert("status".to_string(), Value::from("failed"));
+        map.insert("output".to_string(), Value::from("error: unused import"));
+        map.insert("startedAt".to_string(), Value::from("2026-02-13T10:00:00Z"));
+        let result = FunctionResult::Value(Value::Array(vec![Value::Object(map)]));
+
+        let checks = extract_review_check_list(result).unwrap();
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].name, "clippy");
+        assert_eq!(checks[0].status, "failed");
+        assert_eq!(checks[0].output.as_deref(), Some("error: unused import"));
+        assert_eq!(checks[0].completed_at, None);
+    }
+
+    #[test]
+    fn test_extract_review_gate_list() {
+        let mut map = BTreeMap::new();
+        map.insert("_id".to_string(), Value::from("gate-1"));
+        map.insert("orchestrationId".to_string(), Value::from("orch-1"));
+        map.insert("gateId".to_string(), Value::from("review"));
+        map.insert("status".to_string(), Value::from("pending"));
+        map.insert("owner".to_string(), Value::from("orchestrator"));
+        map.insert("summary".to_string(), Value::from("Awaiting review"));
+        let result = FunctionResult::Value(Value::Array(vec![Value::Object(map)]));
+
+        let gates = extract_review_gate_list(result).unwrap();
+        assert_eq!(gates.len(), 1);
+        assert_eq!(gates[0].gate_id, "review");
+        assert_eq!(gates[0].status, "pending");
+        assert_eq!(gates[0].decided_by, None);
+        assert_eq!(gates[0].required_approvals, None);
+        assert!(gates[0].decisions.is_empty());
+    }
+
+    #[test]
+    fn test_extract_review_gate_with_decisions() {
+        let decision = |approver: &str, decision: &str| {
+            let mut d = BTreeMap::new();
+            d.insert("approver".to_string(), Value::from(approver));
+            d.insert("decision".to_string(), Value::from(decision));
+            d.insert("decidedAt".to_string(), Value::from("2026-02-10T10:00:00Z"));
+            Value::Object(d)
+        };
+        let mut map = BTreeMap::new();
+        map.insert("_id".to_string(), Value::from("gate-1"));
+        map.insert("orchestrationId".to_string(), Value::from("orch-1"));
+        map.insert("gateId".to_string(), Value::from("finalize"));
+        map.insert("status".to_string(), Value::from("pending"));
+        map.insert("owner".to_string(), Value::from("human"));
+        map.insert("summary".to_string(), Value::from("LGTM"));
+        map.insert("requiredApprovals".to_string(), Value::from(2.0));
+        map.insert(
+            "decisions".to_string(),
+            Value::Array(vec![decision("alice", "approved")]),
+        );
+
+        let gate = extract_review_gate(FunctionResult::Value(Value::Object(map))).unwrap();
+        assert_eq!(gate.required_approvals, Some(2));
+        assert_eq!(gate.decisions.len(), 1);
+        assert_eq!(gate.decisions[0].approver, "alice");
+        assert_eq!(gate.decisions[0].summary, None);
+        assert_eq!(gate.approvals(), 1);
+    }
+
     // --- Team record extraction tests ---
 
     #[test]
@@ -2942,6 +4216,50 @@ mod tests {
         assert_eq!(spec.archived_at, Some("2026-02-11T12:00:00Z".to_string()));
     }
 
+    #[test]
+    fn test_extract_spec_revision_list() {
+        let mut obj = BTreeMap::new();
+        obj.insert("_id".to_string(), Value::from("rev-2"));
+        obj.insert("specId".to_string(), Value::from("spec-456"));
+        obj.insert("revision".to_string(), Value::from(2.0));
+        obj.insert("title".to_string(), Value::from("Test Spec"));
+        obj.insert("markdown".to_string(), Value::from("# v2"));
+        obj.insert("createdAt".to_string(), Value::from("2026-02-11T11:00:00Z"));
+
+        let revisions =
+            extract_spec_revision_list(FunctionResult::Value(Value::Array(vec![Value::Object(
+                obj,
+            )])))
+            .unwrap();
+
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].spec_id, "spec-456");
+        assert_eq!(revisions[0].revision, 2);
+        assert_eq!(revisions[0].markdown, "# v2");
+    }
+
+    #[test]
+    fn test_extract_project_list() {
+        let mut obj = BTreeMap::new();
+        obj.insert("_id".to_string(), Value::from("proj-123"));
+        obj.insert("name".to_string(), Value::from("acme"));
+        obj.insert("repoPath".to_string(), Value::from("/code/acme"));
+        obj.insert("createdAt".to_string(), Value::from("2026-02-11T10:00:00Z"));
+        obj.insert("orchestrationCount".to_string(), Value::from(2.0));
+
+        let projects =
+            extract_project_list(FunctionResult::Value(Value::Array(vec![Value::Object(
+                obj,
+            )])))
+            .unwrap();
+
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].id, "proj-123");
+        assert_eq!(projects[0].name, "acme");
+        assert_eq!(projects[0].repo_path, "/code/acme");
+        assert_eq!(projects[0].orchestration_count, Some(2));
+    }
+
     #[test]
     fn test_extract_ticket_record_from_obj() {
         let mut obj = BTreeMap::new();
@@ -3097,6 +4415,39 @@ mod tests {
         assert_eq!(designs[1].status, "archived");
     }
 
+    #[test]
+    fn test_extract_task_event_list_from_array() {
+        let event = |status: &str, owner: Option<&str>, at: &str| {
+            Value::Object({
+                let mut m = BTreeMap::new();
+                m.insert("orchestrationId".to_string(), Value::from("orch-1"));
+                m.insert("taskId".to_string(), Value::from("3"));
+                m.insert("subject".to_string(), Value::from("Add timeline"));
+                m.insert("status".to_string(), Value::from(status));
+                if let Some(owner) = owner {
+                    m.insert("owner".to_string(), Value::from(owner));
+                }
+                m.insert("recordedAt".to_string(), Value::from(at));
+                m
+            })
+        };
+
+        let result = FunctionResult::Value(Value::Array(vec![
+            event("pending", None, "2026-02-13T10:00:00Z"),
+            event("in_progress", Some("worker"), "2026-02-13T10:05:00Z"),
+        ]));
+        let events = extract_task_event_list(result).unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].status, "pending");
+        assert_eq!(events[0].owner, None);
+        assert_eq!(events[1].owner.as_deref(), Some("worker"));
+        assert_eq!(events[1].recorded_at, "2026-02-13T10:05:00Z");
+
+        let empty = extract_task_event_list(FunctionResult::Value(Value::Null)).unwrap();
+        assert!(empty.is_empty());
+    }
+
     #[test]
     fn test_extract_design_list_null_returns_empty() {
         let result = FunctionResult::Value(Value::Null);
@@ -0,0 +1,319 @@
+//! Human-friendly durations and times.
+//!
+//! Shared by the monitor TUI, CLI text output, and the daemon API (`*Human`
+//! fields) so the same span reads the same everywhere: "2h 14m" for a
+//! duration, "3 days ago" for a point in time, and a full timestamp where
+//! precision matters.
+//!
+//! Timestamps are stored in UTC and shown in the display timezone: the
+//! machine's local zone unless a tool installs another with
+//! [`init_display_timezone`], so a team spread across zones can agree on
+//! one clock when reviewing timelines together.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use chrono::{DateTime, FixedOffset, Local, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Timezone timestamps are displayed in: `"local"`, `"utc"`, or a fixed
+/// offset such as `"+05:30"` or `"UTC-8"`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum DisplayTimezone {
+    #[default]
+    Local,
+    Utc,
+    Fixed(FixedOffset),
+}
+
+impl DisplayTimezone {
+    /// Format `at` in this timezone with a chrono format string.
+    pub fn format(self, at: DateTime<Utc>, fmt: &str) -> String {
+        match self {
+            DisplayTimezone::Local => at.with_timezone(&Local).format(fmt).to_string(),
+            DisplayTimezone::Utc => at.format(fmt).to_string(),
+            DisplayTimezone::Fixed(offset) => at.with_timezone(&offset).format(fmt).to_string(),
+        }
+    }
+}
+
+impl FromStr for DisplayTimezone {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.to_ascii_lowercase().as_str() {
+            "" | "local" => return Ok(DisplayTimezone::Local),
+            "utc" | "z" => return Ok(DisplayTimezone::Utc),
+            _ => {}
+        }
+        let offset = s
+            .strip_prefix("UTC")
+            .or_else(|| s.strip_prefix("utc"))
+            .unwrap_or(s);
+        parse_offset(offset)
+            .map(DisplayTimezone::Fixed)
+            .ok_or_else(|| {
+                format!(
+                    "invalid timezone {:?}: expected \"local\", \"utc\", or an offset like \"+05:30\"",
+                    s
+                )
+            })
+    }
+}
+
+impl TryFrom<String> for DisplayTimezone {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<DisplayTimezone> for String {
+    fn from(tz: DisplayTimezone) -> Self {
+        tz.to_string()
+    }
+}
+
+impl fmt::Display for DisplayTimezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DisplayTimezone::Local => write!(f, "local"),
+            DisplayTimezone::Utc => write!(f, "utc"),
+            DisplayTimezone::Fixed(offset) => write!(f, "{}", offset),
+        }
+    }
+}
+
+/// Parse "+05:30", "-0800", or "+9" into an offset.
+fn parse_offset(s: &str) -> Option<FixedOffset> {
+    let (sign, rest) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    let (hours, mins) = match rest.split_once(':') {
+        Some((h, m)) => (h, m),
+        None if rest.len() == 4 => rest.split_at(2),
+        None => (rest, "0"),
+    };
+    let hours: i32 = hours.parse().ok()?;
+    let mins: i32 = mins.parse().ok()?;
+    if hours > 14 || mins >= 60 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + mins * 60))
+}
+
+static DISPLAY_TIMEZONE: OnceLock<DisplayTimezone> = OnceLock::new();
+
+/// Install the display timezone for this process. Only the first call
+/// takes effect.
+pub fn init_display_timezone(tz: DisplayTimezone) {
+    let _ = DISPLAY_TIMEZONE.set(tz);
+}
+
+/// The display timezone (local until `init_display_timezone` is called)
+pub fn display_timezone() -> DisplayTimezone {
+    DISPLAY_TIMEZONE.get().copied().unwrap_or_default()
+}
+
+/// Format `at` in the display timezone with a chrono format string.
+pub fn format_time(at: DateTime<Utc>, fmt: &str) -> String {
+    display_timezone().format(at, fmt)
+}
+
+/// Format a duration in minutes, keeping the two largest units: "45m",
+/// "2h 14m", "3d 4h". Negative durations format as zero.
+pub fn format_duration_mins(mins: i64) -> String {
+    let mins = mins.max(0);
+    match (mins / (24 * 60), mins / 60 % 24, mins % 60) {
+        (0, 0, m) => format!("{}m", m),
+        (0, h, 0) => format!("{}h", h),
+        (0, h, m) => format!("{}h {}m", h, m),
+        (d, 0, _) => format!("{}d", d),
+        (d, h, _) => format!("{}d {}h", d, h),
+    }
+}
+
+/// Format a duration in seconds: "45s" under a minute, otherwise as
+/// [`format_duration_mins`].
+pub fn format_duration_secs(secs: i64) -> String {
+    if secs < 60 {
+        format!("{}s", secs.max(0))
+    } else {
+        format_duration_mins(secs / 60)
+    }
+}
+
+/// Describe `then` relative to `now`: "just now", "5 minutes ago",
+/// "3 days ago", or "in 2 hours" for future times.
+pub fn format_relative(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let secs = now.signed_duration_since(then).num_seconds();
+    if secs.abs() < 45 {
+        return "just now".to_string();
+    }
+    let (amount, unit) = largest_unit(secs.abs());
+    let phrase = if amount == 1 {
+        format!("1 {}", unit)
+    } else {
+        format!("{} {}s", amount, unit)
+    };
+    if secs > 0 {
+        format!("{} ago", phrase)
+    } else {
+        format!("in {}", phrase)
+    }
+}
+
+/// An RFC 3339 timestamp in UTC; `None` if it doesn't parse.
+pub fn parse_rfc3339(at: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(at)
+        .ok()
+        .map(|at| at.with_timezone(&Utc))
+}
+
+/// [`format_relative`] for an RFC 3339 timestamp; `None` if it doesn't
+/// parse.
+pub fn format_relative_rfc3339(then: &str, now: DateTime<Utc>) -> Option<String> {
+    Some(format_relative(parse_rfc3339(then)?, now))
+}
+
+/// Full timestamp in the display timezone, for verbose output.
+pub fn format_absolute(at: DateTime<Utc>) -> String {
+    format_time(at, "%Y-%m-%d %H:%M:%S %Z")
+}
+
+/// [`format_absolute`] for an RFC 3339 timestamp; `None` if it doesn't
+/// parse.
+pub fn format_absolute_rfc3339(at: &str) -> Option<String> {
+    Some(format_absolute(parse_rfc3339(at)?))
+}
+
+/// Minutes an orchestration has run: its recorded total once it has
+/// completed, otherwise the time from `started_at` (RFC 3339) to `now`.
+/// `None` if `started_at` doesn't parse.
+pub fn elapsed_mins(
+    started_at: &str,
+    completed_at: Option<&str>,
+    total_elapsed_mins: Option<f64>,
+    now: DateTime<Utc>,
+) -> Option<i64> {
+    if let (Some(_), Some(total)) = (completed_at, total_elapsed_mins) {
+        return Some(total as i64);
+    }
+    let started = parse_rfc3339(started_at)?;
+    Some(now.signed_duration_since(started).num_minutes().max(0))
+}
+
+/// Largest whole unit in `secs`, rounding under a minute up to one minute.
+fn largest_unit(secs: i64) -> (i64, &'static str) {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    match secs {
+        s if s < HOUR => ((s / MINUTE).max(1), "minute"),
+        s if s < DAY => (s / HOUR, "hour"),
+        s if s < 30 * DAY => (s / DAY, "day"),
+        s if s < 365 * DAY => (s / (30 * DAY), "month"),
+        s => (s / (365 * DAY), "year"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_format_duration_mins_keeps_two_largest_units() {
+        assert_eq!(format_duration_mins(0), "0m");
+        assert_eq!(format_duration_mins(45), "45m");
+        assert_eq!(format_duration_mins(60), "1h");
+        assert_eq!(format_duration_mins(134), "2h 14m");
+        assert_eq!(format_duration_mins(24 * 60), "1d");
+        assert_eq!(format_duration_mins(3 * 24 * 60 + 4 * 60 + 59), "3d 4h");
+        assert_eq!(format_duration_mins(-5), "0m");
+    }
+
+    #[test]
+    fn test_format_duration_secs() {
+        assert_eq!(format_duration_secs(45), "45s");
+        assert_eq!(format_duration_secs(90), "1m");
+        assert_eq!(format_duration_secs(3_900), "1h 5m");
+        assert_eq!(format_duration_secs(-1), "0s");
+    }
+
+    #[test]
+    fn test_elapsed_mins_uses_total_once_complete() {
+        let now = DateTime::parse_from_rfc3339("2026-02-07T11:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let started = "2026-02-07T10:00:00Z";
+        assert_eq!(elapsed_mins(started, None, None, now), Some(90));
+        assert_eq!(elapsed_mins(started, None, Some(45.0), now), Some(90));
+        assert_eq!(
+            elapsed_mins(started, Some("2026-02-07T10:45:00Z"), Some(45.0), now),
+            Some(45)
+        );
+        // Never negative, even if the start is ahead of our clock
+        assert_eq!(
+            elapsed_mins("2026-02-07T12:00:00Z", None, None, now),
+            Some(0)
+        );
+        assert_eq!(elapsed_mins("soon", None, None, now), None);
+    }
+
+    #[test]
+    fn test_format_relative() {
+        let now = Utc::now();
+        let ago = |d: Duration| format_relative(now - d, now);
+        assert_eq!(ago(Duration::seconds(10)), "just now");
+        assert_eq!(ago(Duration::seconds(50)), "1 minute ago");
+        assert_eq!(ago(Duration::minutes(5)), "5 minutes ago");
+        assert_eq!(ago(Duration::hours(2)), "2 hours ago");
+        assert_eq!(ago(Duration::days(3)), "3 days ago");
+        assert_eq!(ago(Duration::days(65)), "2 months ago");
+        assert_eq!(ago(Duration::days(800)), "2 years ago");
+        assert_eq!(ago(Duration::hours(-2)), "in 2 hours");
+    }
+
+    #[test]
+    fn test_format_relative_rfc3339() {
+        let now = DateTime::parse_from_rfc3339("2026-02-07T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            format_relative_rfc3339("2026-02-07T09:00:00Z", now).as_deref(),
+            Some("3 hours ago")
+        );
+        assert_eq!(format_relative_rfc3339("yesterday", now), None);
+    }
+
+    #[test]
+    fn test_display_timezone_parses_names_and_offsets() {
+        let offset = |secs| DisplayTimezone::Fixed(FixedOffset::east_opt(secs).unwrap());
+        assert_eq!("local".parse(), Ok(DisplayTimezone::Local));
+        assert_eq!("UTC".parse(), Ok(DisplayTimezone::Utc));
+        assert_eq!("+05:30".parse(), Ok(offset(5 * 3600 + 30 * 60)));
+        assert_eq!("UTC-8".parse(), Ok(offset(-8 * 3600)));
+        assert_eq!("-0930".parse(), Ok(offset(-(9 * 3600 + 30 * 60))));
+        assert!("America/Chicago".parse::<DisplayTimezone>().is_err());
+        assert!("+25:00".parse::<DisplayTimezone>().is_err());
+        assert_eq!(offset(5 * 3600 + 30 * 60).to_string(), "+05:30");
+    }
+
+    #[test]
+    fn test_display_timezone_formats_in_zone() {
+        let at = DateTime::parse_from_rfc3339("2026-02-07T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let fmt = "%Y-%m-%d %H:%M %Z";
+        assert_eq!(DisplayTimezone::Utc.format(at, fmt), "2026-02-07 12:00 UTC");
+        let tokyo: DisplayTimezone = "+09:00".parse().unwrap();
+        assert_eq!(tokyo.format(at, fmt), "2026-02-07 21:00 +09:00");
+    }
+}
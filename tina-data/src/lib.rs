@@ -4,15 +4,20 @@
 //! for Convex orchestration data. Used by tina-daemon, tina-session,
 //! tina-monitor, and tina-harness.
 
+pub mod clock;
 pub mod convex_client;
+pub mod humanize;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
 pub mod types;
 pub mod generated {
     pub mod orchestration_core_fields;
 }
 
-pub use convex_client::TinaConvexClient;
+pub use convex_client::{ConvexRejection, TinaConvexClient};
 pub use convex_client::{
-    event_to_args, orchestration_event_to_args, orchestration_to_args, phase_to_args,
-    rollup_to_args, span_to_args, terminal_session_to_args,
+    event_to_args, extract_orchestration_detail, extract_orchestration_page,
+    orchestration_event_to_args, orchestration_to_args, phase_to_args, rollup_to_args,
+    span_to_args, terminal_session_to_args,
 };
 pub use types::*;
@@ -0,0 +1,34 @@
+//! Builders for tests in this and dependent crates (the `test-util`
+//! feature).
+
+use crate::{OrchestrationListEntry, OrchestrationRecord};
+
+/// An orchestration of feature `auth` on node `laptop`, at phase 1 of 3.
+/// Set any other fields the test cares about on the result.
+pub fn list_entry(id: &str, status: &str, started_at: &str) -> OrchestrationListEntry {
+    OrchestrationListEntry {
+        id: id.to_string(),
+        node_name: "laptop".to_string(),
+        record: OrchestrationRecord {
+            project_id: None,
+            spec_id: None,
+            node_id: "node-1".to_string(),
+            feature_name: "auth".to_string(),
+            spec_doc_path: "docs/auth.md".to_string(),
+            branch: "tina/auth".to_string(),
+            worktree_path: None,
+            total_phases: 3.0,
+            current_phase: 1.0,
+            status: status.to_string(),
+            started_at: started_at.to_string(),
+            completed_at: None,
+            total_elapsed_mins: None,
+            policy_snapshot: None,
+            policy_snapshot_hash: None,
+            preset_origin: None,
+            spec_only: None,
+            policy_revision: None,
+            updated_at: None,
+        },
+    }
+}
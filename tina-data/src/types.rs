@@ -1,5 +1,8 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::humanize;
+
 pub use crate::generated::orchestration_core_fields::OrchestrationRecord;
 
 /// Registration data for a node (laptop).
@@ -10,6 +13,24 @@ pub struct NodeRegistration {
     pub auth_token_hash: String,
 }
 
+/// Host resource metrics reported with each node heartbeat.
+///
+/// Numeric fields use `f64` to match Convex's `v.number()`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeMetrics {
+    /// 1-minute load average.
+    pub cpu_load: f64,
+    /// Number of logical CPUs, for interpreting `cpu_load`.
+    pub cpu_count: f64,
+    pub memory_used_bytes: f64,
+    pub memory_total_bytes: f64,
+    /// Free space on the volume holding the node's worktrees.
+    pub disk_free_bytes: f64,
+    pub disk_total_bytes: f64,
+    /// Number of live `tina-*` tmux sessions.
+    pub tmux_sessions: f64,
+}
+
 /// Phase record matching the Convex `phases` table.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PhaseRecord {
@@ -23,6 +44,12 @@ pub struct PhaseRecord {
     pub review_mins: Option<f64>,
     pub started_at: Option<String>,
     pub completed_at: Option<String>,
+    /// 0-100 risk score; written by `record_phase_risk`, never by `upsert_phase`.
+    #[serde(default)]
+    pub risk_score: Option<f64>,
+    /// JSON-encoded signals behind `risk_score`.
+    #[serde(default)]
+    pub risk_signals: Option<String>,
 }
 
 /// Task event record matching the Convex `taskEvents` table (append-only).
@@ -119,6 +146,18 @@ pub struct InboundAction {
     pub payload: String,
     pub status: String,
     pub created_at: f64,
+    /// Epoch millis at which a scheduled action should run.
+    pub run_at: Option<f64>,
+    /// Cron expression for recurring actions.
+    pub cron_schedule: Option<String>,
+}
+
+impl InboundAction {
+    /// Whether the action runs later (at `run_at` or on a cron schedule)
+    /// rather than immediately.
+    pub fn is_scheduled(&self) -> bool {
+        self.run_at.is_some() || self.cron_schedule.is_some()
+    }
 }
 
 /// Result of claiming an inbound action.
@@ -139,9 +178,24 @@ pub struct NodeRecord {
     pub status: String,
     pub last_heartbeat: f64,
     pub registered_at: f64,
+    /// Node clock minus Convex clock, as last reported by its heartbeat.
+    #[serde(default)]
+    pub clock_skew_ms: Option<f64>,
 }
 
 /// Orchestration list entry as returned by `listOrchestrations` query.
+impl OrchestrationRecord {
+    /// Minutes the orchestration has run, as [`humanize::elapsed_mins`].
+    pub fn elapsed_mins(&self, now: DateTime<Utc>) -> Option<i64> {
+        humanize::elapsed_mins(
+            &self.started_at,
+            self.completed_at.as_deref(),
+            self.total_elapsed_mins,
+            now,
+        )
+    }
+}
+
 /// Extends `OrchestrationRecord` with a resolved `node_name` and Convex `_id`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrchestrationListEntry {
@@ -151,6 +205,66 @@ pub struct OrchestrationListEntry {
     pub record: OrchestrationRecord,
 }
 
+/// One page of orchestrations as returned by `listOrchestrationsPage`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrchestrationPage {
+    pub entries: Vec<OrchestrationListEntry>,
+    /// Opaque cursor for the next page; `None` on the last page.
+    pub next_cursor: Option<String>,
+    /// Total orchestrations, only reported on a first page that asked for
+    /// it with [`OrchestrationFilter::count`].
+    pub total_count: Option<usize>,
+}
+
+/// Which orchestrations `listOrchestrationsPage` returns, and in what order.
+/// Times are RFC 3339 and exclusive.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrchestrationFilter {
+    pub include_archived: bool,
+    pub status: Option<String>,
+    pub project_id: Option<String>,
+    pub started_after: Option<String>,
+    pub started_before: Option<String>,
+    /// Oldest first instead of newest first.
+    pub oldest_first: bool,
+    /// Count every match on the first page. This reads the whole range, so
+    /// leave it off for anything that polls.
+    pub count: bool,
+}
+
+/// One page of an orchestration's events as returned by `listEventsPage`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrchestrationEventPage {
+    pub entries: Vec<OrchestrationEventRecord>,
+    /// Opaque cursor for the next page; `None` on the last page.
+    pub next_cursor: Option<String>,
+    /// Cursor just past the last entry, even on the last page, for polling
+    /// for newer events; `None` when the page is empty.
+    #[serde(default)]
+    pub last_cursor: Option<String>,
+}
+
+/// One page of an orchestration's task events as returned by
+/// `listOrchestrationTaskEventsPage`; cursors as in [`OrchestrationEventPage`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventPage {
+    pub entries: Vec<TaskEventRecord>,
+    pub next_cursor: Option<String>,
+    #[serde(default)]
+    pub last_cursor: Option<String>,
+}
+
+/// Which events `listEventsPage` returns, and in what order. Times are
+/// RFC 3339 and exclusive.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventFilter {
+    pub event_type: Option<String>,
+    pub since: Option<String>,
+    pub until: Option<String>,
+    /// Newest first instead of oldest first.
+    pub newest_first: bool,
+}
+
 /// Team record as returned by `teams:getByTeamName` query.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamRecord {
@@ -210,6 +324,10 @@ pub struct CommitRecord {
     pub sha: String,
     pub short_sha: Option<String>,
     pub subject: Option<String>,
+    /// When the commit was authored (RFC 3339), so replayed history keeps
+    /// its original place on the timeline.
+    #[serde(default)]
+    pub timestamp: Option<String>,
 }
 
 /// Plan record for Convex `plans` table.
@@ -219,6 +337,116 @@ pub struct PlanRecord {
     pub phase_number: String,
     pub plan_path: String,
     pub content: String,
+    /// Set by Convex on each upsert; ignored when writing
+    #[serde(default)]
+    pub last_synced: Option<String>,
+}
+
+/// Planned task from the Convex `executionTasks` table. Pending tasks can be
+/// edited through the control plane, which checks `revision` so concurrent
+/// edits don't overwrite each other.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionTaskRecord {
+    pub orchestration_id: String,
+    pub phase_number: String,
+    pub task_number: u32,
+    pub subject: String,
+    pub description: Option<String>,
+    /// pending, in_progress, completed, or skipped
+    pub status: String,
+    pub model: Option<String>,
+    pub revision: u32,
+}
+
+/// Review as returned by `reviews:listReviewsByOrchestration`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewRecord {
+    pub id: String,
+    pub orchestration_id: String,
+    /// `None` for reviews of the whole orchestration rather than one phase.
+    pub phase_number: Option<String>,
+    /// `open`, `changes_requested`, `approved`, or `superseded`.
+    pub state: String,
+    pub reviewer_agent: String,
+    pub started_at: String,
+    pub completed_at: Option<String>,
+}
+
+/// Review finding as returned by `reviewThreads:listThreadsByOrchestration`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewThreadRecord {
+    pub id: String,
+    pub review_id: String,
+    pub orchestration_id: String,
+    pub file_path: String,
+    pub line: i64,
+    pub commit_sha: String,
+    pub summary: String,
+    pub body: String,
+    pub severity: String,
+    pub status: String,
+    pub source: String,
+    pub author: String,
+    pub gate_impact: String,
+    pub created_at: String,
+}
+
+/// Review check run as returned by `reviewChecks:listChecksByOrchestration`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewCheckRecord {
+    pub id: String,
+    pub review_id: String,
+    pub orchestration_id: String,
+    pub name: String,
+    pub kind: String,
+    pub command: Option<String>,
+    /// `running`, `passed`, or `failed`.
+    pub status: String,
+    pub comment: Option<String>,
+    pub output: Option<String>,
+    pub started_at: String,
+    pub completed_at: Option<String>,
+}
+
+/// Review gate as returned by `reviewGates:listGatesByOrchestration`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewGateRecord {
+    pub id: String,
+    pub orchestration_id: String,
+    /// `plan`, `review`, or `finalize`.
+    pub gate_id: String,
+    /// `pending`, `blocked`, or `approved`.
+    pub status: String,
+    pub owner: String,
+    pub decided_by: Option<String>,
+    pub decided_at: Option<String>,
+    pub summary: String,
+    /// Distinct approvals needed to open the gate; `None` means one.
+    #[serde(default)]
+    pub required_approvals: Option<u32>,
+    /// Latest decision per approver.
+    #[serde(default)]
+    pub decisions: Vec<GateDecisionRecord>,
+}
+
+impl ReviewGateRecord {
+    /// Number of approvers currently approving the gate.
+    pub fn approvals(&self) -> usize {
+        self.decisions
+            .iter()
+            .filter(|d| d.decision == "approved")
+            .count()
+    }
+}
+
+/// One approver's decision on a review gate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateDecisionRecord {
+    pub approver: String,
+    /// `approved` or `blocked`.
+    pub decision: String,
+    pub decided_at: String,
+    pub summary: Option<String>,
 }
 
 /// Telemetry span record matching the Convex `telemetrySpans` table.
@@ -281,6 +509,18 @@ pub struct RollupRecord {
     pub max_duration_ms: Option<f64>,
 }
 
+/// Project record for Convex `projects` table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectRecord {
+    pub id: String,
+    pub name: String,
+    pub repo_path: String,
+    pub created_at: String,
+    /// Orchestrations in the project, as counted by `projects:listProjects`.
+    #[serde(default)]
+    pub orchestration_count: Option<u32>,
+}
+
 /// Spec record for Convex `specs` table.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpecRecord {
@@ -295,6 +535,17 @@ pub struct SpecRecord {
     pub archived_at: Option<String>,
 }
 
+/// Spec revision record for Convex `specRevisions` table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecRevisionRecord {
+    pub id: String,
+    pub spec_id: String,
+    pub revision: u32,
+    pub title: String,
+    pub markdown: String,
+    pub created_at: String,
+}
+
 /// Design record for Convex `designs` table.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DesignRecord {
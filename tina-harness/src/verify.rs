@@ -231,33 +231,17 @@ pub fn verify_orchestration_exists(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tina_data::test_util::list_entry;
     use tina_data::{
         OrchestrationDetailResponse, OrchestrationListEntry, OrchestrationRecord, PhaseRecord,
         TaskEventRecord, TeamMemberRecord,
     };
 
     fn make_orchestration_record(feature: &str) -> OrchestrationRecord {
-        OrchestrationRecord {
-            node_id: "node-1".to_string(),
-            project_id: None,
-            spec_id: None,
-            feature_name: feature.to_string(),
-            spec_doc_path: "design.md".to_string(),
-            branch: "tina/test".to_string(),
-            worktree_path: None,
-            total_phases: 1.0,
-            current_phase: 1.0,
-            status: "complete".to_string(),
-            started_at: "2026-02-08T10:00:00Z".to_string(),
-            completed_at: None,
-            total_elapsed_mins: None,
-            policy_snapshot: None,
-            policy_snapshot_hash: None,
-            preset_origin: None,
-            spec_only: None,
-            policy_revision: None,
-            updated_at: None,
-        }
+        let mut record = list_entry("orch-1", "complete", "2026-02-08T10:00:00Z").record;
+        record.feature_name = feature.to_string();
+        record.total_phases = 1.0;
+        record
     }
 
     fn make_phase(orch_id: &str, phase_num: &str) -> PhaseRecord {
@@ -272,6 +256,8 @@ mod tests {
             review_mins: None,
             started_at: None,
             completed_at: None,
+            risk_score: None,
+            risk_signals: None,
         }
     }
 
@@ -677,12 +663,14 @@ mod tests {
             sha: "abc123".to_string(),
             short_sha: Some("abc123".to_string()),
             subject: Some("feat: add".to_string()),
+            timestamp: None,
         }];
         let plans = vec![PlanRecord {
             orchestration_id: "orch-1".to_string(),
             phase_number: "1".to_string(),
             plan_path: "docs/plans/phase-1.md".to_string(),
             content: "# plan".to_string(),
+            last_synced: None,
         }];
         let events = vec![OrchestrationEventRecord {
             orchestration_id: "orch-1".to_string(),
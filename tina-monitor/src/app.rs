@@ -249,7 +249,8 @@ impl App {
         let worktree_path = &orchestration.state.worktree_path;
 
         // Gracefully handle errors - commits panel can be empty
-        match get_commits(worktree_path, &git_range) {
+        let scope = orchestration.state.scope.as_deref();
+        match get_commits(worktree_path, &git_range, scope) {
             Ok(summary) => {
                 self.grid.set_commits(summary.commits, summary.insertions, summary.deletions);
             }
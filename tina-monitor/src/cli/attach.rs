@@ -0,0 +1,162 @@
+//! Attach command handler
+//!
+//! Resolves an orchestration's tmux session, a phase's session, or an
+//! agent's pane from the local team configs and hands the terminal over to
+//! tmux, switching client instead when already inside tmux.
+
+use std::fs;
+use std::os::unix::process::CommandExt;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{anyhow, Context, Result};
+use tina_session::session::naming::{
+    orchestration_session_name, orchestration_team_name, session_name,
+};
+
+use crate::data::DataSource;
+use crate::types::Team;
+
+/// What `attach` jumps to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttachTarget<'a> {
+    /// The orchestrator's session
+    Orchestration,
+    /// A phase's session, by phase number
+    Phase(&'a str),
+    /// An agent's pane, by team member name
+    Agent(&'a str),
+}
+
+/// Handle `attach <feature> [--agent <name>|--phase <n>]`. Only returns if
+/// tmux couldn't be started.
+pub fn attach(feature: &str, target: AttachTarget) -> Result<i32> {
+    let teams_dir = DataSource::new(None).teams_dir();
+    let target = resolve(&teams_dir, feature, target)?;
+    let inside_tmux = std::env::var_os("TMUX").is_some();
+    let err = Command::new("tmux")
+        .args(tmux_args(&target, inside_tmux))
+        .exec();
+    Err(err).context("Failed to run tmux")
+}
+
+/// tmux target for `target`: a session name, or a pane id for an agent
+fn resolve(teams_dir: &Path, feature: &str, target: AttachTarget) -> Result<String> {
+    match target {
+        AttachTarget::Orchestration => Ok(orchestration_session_name(feature)),
+        AttachTarget::Phase(phase) => Ok(session_name(feature, phase)),
+        AttachTarget::Agent(agent) => find_agent_pane(teams_dir, feature, agent)
+            .ok_or_else(|| anyhow!("No tmux pane recorded for {} in {}", agent, feature)),
+    }
+}
+
+/// Pane of the most recently joined member named `agent` across the
+/// feature's orchestration and phase teams
+fn find_agent_pane(teams_dir: &Path, feature: &str, agent: &str) -> Option<String> {
+    let orchestration_team = orchestration_team_name(feature);
+    let phase_prefix = format!("{}-phase-", feature);
+    fs::read_dir(teams_dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name == orchestration_team.as_str() || name.starts_with(&phase_prefix)
+        })
+        .filter_map(|entry| fs::read_to_string(entry.path().join("config.json")).ok())
+        .filter_map(|content| serde_json::from_str::<Team>(&content).ok())
+        .flat_map(|team| team.members)
+        .filter(|member| member.name == agent && member.tmux_pane_id.is_some())
+        .max_by_key(|member| member.joined_at)
+        .and_then(|member| member.tmux_pane_id)
+}
+
+/// Arguments taking the terminal to `target`. A pane id as the target
+/// also selects the pane's window and the pane.
+fn tmux_args(target: &str, inside_tmux: bool) -> [&str; 3] {
+    if inside_tmux {
+        ["switch-client", "-t", target]
+    } else {
+        ["attach-session", "-t", target]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Agent;
+    use std::path::PathBuf;
+
+    fn write_team(dir: &Path, name: &str, members: &[(&str, i64, Option<&str>)]) {
+        let team = Team {
+            name: name.to_string(),
+            description: None,
+            created_at: 0,
+            lead_agent_id: String::new(),
+            lead_session_id: String::new(),
+            members: members
+                .iter()
+                .map(|(member, joined_at, pane)| Agent {
+                    agent_id: member.to_string(),
+                    name: member.to_string(),
+                    agent_type: None,
+                    model: "opus".to_string(),
+                    joined_at: *joined_at,
+                    tmux_pane_id: pane.map(str::to_string),
+                    cwd: PathBuf::new(),
+                    subscriptions: vec![],
+                })
+                .collect(),
+        };
+        let team_dir = dir.join(name);
+        fs::create_dir_all(&team_dir).unwrap();
+        fs::write(
+            team_dir.join("config.json"),
+            serde_json::to_string(&team).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_resolves_sessions_and_the_latest_agent_pane() {
+        let dir = tempfile::tempdir().unwrap();
+        write_team(
+            dir.path(),
+            "auth-orchestration",
+            &[("orchestrator", 1, Some("%1"))],
+        );
+        write_team(
+            dir.path(),
+            "auth-phase-1",
+            &[("executor-1", 2, Some("%2")), ("reviewer", 3, None)],
+        );
+        write_team(dir.path(), "auth-phase-2", &[("executor-1", 4, Some("%7"))]);
+        write_team(
+            dir.path(),
+            "billing-phase-1",
+            &[("reviewer", 5, Some("%9"))],
+        );
+
+        let resolve = |target| resolve(dir.path(), "auth", target);
+        assert_eq!(
+            resolve(AttachTarget::Orchestration).unwrap(),
+            "tina-auth-orchestration"
+        );
+        assert_eq!(
+            resolve(AttachTarget::Phase("1.5")).unwrap(),
+            "tina-auth-phase-1_5"
+        );
+        assert_eq!(resolve(AttachTarget::Agent("executor-1")).unwrap(), "%7");
+        assert_eq!(resolve(AttachTarget::Agent("orchestrator")).unwrap(), "%1");
+        assert!(resolve(AttachTarget::Agent("reviewer")).is_err());
+    }
+
+    #[test]
+    fn test_switches_client_inside_tmux() {
+        assert_eq!(tmux_args("%7", true), ["switch-client", "-t", "%7"]);
+        assert_eq!(
+            tmux_args("tina-auth-orchestration", false),
+            ["attach-session", "-t", "tina-auth-orchestration"]
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Capture command handler
+//!
+//! Looks up an agent's tmux pane through Convex and writes the pane's full
+//! history to a timestamped file.
+
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+
+use crate::config::Config;
+use crate::data::ConvexDataSource;
+use crate::tmux::export_pane_history;
+use crate::types::Agent;
+
+/// Pane of the most recently recorded member named `agent` that has one
+fn find_pane<'a>(members: &'a [Agent], agent: &str) -> Option<&'a str> {
+    members
+        .iter()
+        .rev()
+        .filter(|member| member.name == agent)
+        .find_map(|member| member.tmux_pane_id.as_deref())
+}
+
+/// Handle `capture <name> <agent>`: write the pane history under `dir`, or
+/// the configured capture directory, and print the file's path
+pub fn capture(name: &str, agent: &str, dir: Option<&Path>) -> Result<i32> {
+    let config = Config::load()?;
+    if config.convex.url.is_empty() {
+        return Err(anyhow!("Convex URL not configured in config.toml"));
+    }
+
+    let rt = tokio::runtime::Runtime::new()?;
+    let orchestration = rt.block_on(async {
+        let mut ds = ConvexDataSource::new(&config.convex.url).await?;
+        let orch = ds
+            .list_orchestrations()
+            .await?
+            .into_iter()
+            .find(|o| o.matches_name(name))
+            .ok_or_else(|| anyhow!("Orchestration not found: {}", name))?;
+        ds.get_orchestration_detail(&orch.id)
+            .await?
+            .ok_or_else(|| anyhow!("Orchestration not found: {}", name))
+    })?;
+
+    let pane_id = find_pane(&orchestration.members, agent)
+        .ok_or_else(|| anyhow!("No tmux pane recorded for {} in {}", agent, name))?;
+    let dir = match dir {
+        Some(dir) => dir.to_path_buf(),
+        None => config.capture.resolved_dir(),
+    };
+    let label = format!("{}-{}", orchestration.feature_name, agent);
+    let path = export_pane_history(pane_id, &label, &dir)?;
+    println!("{}", path.display());
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn member(name: &str, pane: Option<&str>) -> Agent {
+        Agent {
+            agent_id: format!("convex-{}", name),
+            name: name.to_string(),
+            agent_type: None,
+            model: "unknown".to_string(),
+            joined_at: 0,
+            tmux_pane_id: pane.map(str::to_string),
+            cwd: PathBuf::new(),
+            subscriptions: vec![],
+        }
+    }
+
+    #[test]
+    fn test_find_pane_prefers_latest_member_with_a_pane() {
+        let members = vec![
+            member("executor-1", Some("%1")),
+            member("reviewer", Some("%2")),
+            member("executor-1", Some("%5")),
+            member("executor-1", None),
+        ];
+        assert_eq!(find_pane(&members, "executor-1"), Some("%5"));
+        assert_eq!(find_pane(&members, "reviewer"), Some("%2"));
+        assert_eq!(find_pane(&members, "planner"), None);
+    }
+}
@@ -0,0 +1,371 @@
+//! Doctor command handler
+//!
+//! Checks the monitor's runtime dependencies — config, Convex, tmux, the
+//! terminal handler and tina-daemon — and prints a fix for each problem,
+//! in the same PASS/WARN/FAIL format as `tina-session doctor`.
+
+use std::path::Path;
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::config::Config;
+use crate::data::ConvexDataSource;
+use crate::terminal::{detect_terminal, get_handler};
+use crate::theme::{Theme, ThemeConfig};
+
+/// How long to wait for Convex to answer before calling it unreachable
+const CONVEX_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Oldest tmux whose pane capture the monitor relies on
+const MIN_TMUX_VERSION: (u32, u32) = (3, 0);
+
+/// Outcome of one diagnostic
+#[derive(Debug, PartialEq)]
+enum Check {
+    Pass(String),
+    /// Problem and how to fix it
+    Warn(String, String),
+    Fail(String, String),
+}
+
+fn check_config(path: &Path, loaded: &Result<Config>) -> Check {
+    match loaded {
+        Err(e) => Check::Fail(
+            format!("{} could not be loaded: {}", path.display(), e),
+            format!("Fix the TOML in {} or move it aside", path.display()),
+        ),
+        Ok(_) if !path.exists() => Check::Warn(
+            format!("No config file at {}; using defaults", path.display()),
+            "Run `tina-monitor setup` to create one".to_string(),
+        ),
+        Ok(_) => Check::Pass(format!("Config loaded from {}", path.display())),
+    }
+}
+
+fn check_theme(theme: &ThemeConfig) -> Check {
+    match Theme::from_config(theme) {
+        Ok(_) => Check::Pass(format!("Theme preset {:?} is valid", theme.preset)),
+        Err(e) => Check::Fail(
+            format!("Theme is invalid: {}", e),
+            "Fix the colors under [theme.overrides] in config.toml".to_string(),
+        ),
+    }
+}
+
+fn check_convex(url: &str, reached: Option<Result<usize, String>>) -> Check {
+    if url.is_empty() {
+        return Check::Fail(
+            "Convex URL is not configured".to_string(),
+            "Set `url` under [convex] in config.toml".to_string(),
+        );
+    }
+    match reached {
+        Some(Ok(count)) => Check::Pass(format!(
+            "Convex reachable at {} ({} orchestration(s))",
+            url, count
+        )),
+        Some(Err(e)) => Check::Fail(
+            format!("Convex at {} returned an error: {}", url, e),
+            "Check the URL and that the deployment is running".to_string(),
+        ),
+        None => Check::Fail(
+            format!(
+                "Convex at {} did not answer within {}s",
+                url,
+                CONVEX_TIMEOUT.as_secs()
+            ),
+            "Check your network connection and the [convex] url".to_string(),
+        ),
+    }
+}
+
+/// Major and minor version from `tmux -V` output like "tmux 3.4" or
+/// "tmux next-3.5a"
+fn parse_tmux_version(output: &str) -> Option<(u32, u32)> {
+    let version = output.split_whitespace().nth(1)?;
+    let version = version.trim_start_matches(|c: char| !c.is_ascii_digit());
+    let (major, rest) = version.split_once('.')?;
+    let minor: String = rest.chars().take_while(char::is_ascii_digit).collect();
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
+
+fn check_tmux(version_output: Option<&str>) -> Check {
+    let Some(output) = version_output else {
+        return Check::Fail(
+            "tmux is not installed or not on PATH".to_string(),
+            "Install tmux (e.g. `brew install tmux`); agent panes and logs need it".to_string(),
+        );
+    };
+    let output = output.trim();
+    match parse_tmux_version(output) {
+        Some(version) if version >= MIN_TMUX_VERSION => Check::Pass(format!("{} found", output)),
+        Some(_) => Check::Warn(
+            format!(
+                "{} is older than {}.{}",
+                output, MIN_TMUX_VERSION.0, MIN_TMUX_VERSION.1
+            ),
+            "Upgrade tmux if pane capture or log export misbehaves".to_string(),
+        ),
+        None => Check::Warn(
+            format!("Could not parse tmux version from {:?}", output),
+            "Check that `tmux -V` runs the tmux you expect".to_string(),
+        ),
+    }
+}
+
+/// `preferred` is the configured handler, `resolved` the terminal it names
+/// once "auto" is detected, and `available` whether that terminal can be
+/// controlled from here
+fn check_terminal(preferred: &str, resolved: Option<&str>, available: bool) -> Check {
+    match resolved {
+        Some("print") => Check::Pass("Terminal handler prints commands to run".to_string()),
+        Some(name) if available => Check::Pass(format!("Terminal handler {} is available", name)),
+        Some(name) => Check::Warn(
+            format!(
+                "Terminal handler {} is not available; commands will be printed instead",
+                name
+            ),
+            format!(
+                "Enable {} remote control, or set handler = \"print\" under [terminal]",
+                name
+            ),
+        ),
+        None if preferred == "auto" => Check::Warn(
+            "No supported terminal detected; commands will be printed instead".to_string(),
+            "Set handler under [terminal] to kitty, wezterm, iterm, alacritty or print".to_string(),
+        ),
+        None => Check::Fail(
+            format!("Unknown terminal handler {:?}", preferred),
+            "Set handler under [terminal] to auto, kitty, wezterm, iterm, alacritty or print"
+                .to_string(),
+        ),
+    }
+}
+
+fn check_daemon(port: u16, health: Result<String, String>) -> Check {
+    let body = match health {
+        Ok(body) => body,
+        Err(e) => {
+            return Check::Fail(
+                format!("tina-daemon is not answering on port {}: {}", port, e),
+                "Start it with `tina-daemon`, or set http_port under [daemon]".to_string(),
+            )
+        }
+    };
+    let health: serde_json::Value = serde_json::from_str(&body).unwrap_or_default();
+    if health.get("status").and_then(|s| s.as_str()) != Some("ok") {
+        return Check::Fail(
+            format!("Port {} answered but not as tina-daemon", port),
+            "Set http_port under [daemon] to the port tina-daemon listens on".to_string(),
+        );
+    }
+    if health.get("clock_skew_warning").and_then(|w| w.as_bool()) == Some(true) {
+        return Check::Warn(
+            format!(
+                "tina-daemon is running on port {} but reports clock skew",
+                port
+            ),
+            "Sync the system clock; event times in the monitor may be off".to_string(),
+        );
+    }
+    Check::Pass(format!("tina-daemon is running on port {}", port))
+}
+
+/// List orchestrations on a worker thread, giving up after `CONVEX_TIMEOUT`
+fn reach_convex(url: &str) -> Option<Result<usize, String>> {
+    let url = url.to_string();
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let result = tokio::runtime::Runtime::new()
+            .map_err(anyhow::Error::from)
+            .and_then(|rt| {
+                rt.block_on(async {
+                    let mut ds = ConvexDataSource::new(&url).await?;
+                    Ok(ds.list_orchestrations().await?.len())
+                })
+            })
+            .map_err(|e: anyhow::Error| e.to_string());
+        let _ = tx.send(result);
+    });
+    rx.recv_timeout(CONVEX_TIMEOUT).ok()
+}
+
+fn tmux_version() -> Option<String> {
+    let output = Command::new("tmux").arg("-V").output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn daemon_health(port: u16) -> Result<String, String> {
+    let output = Command::new("curl")
+        .args(["-fsS", "-m", "2"])
+        .arg(format!("http://127.0.0.1:{}/health", port))
+        .output()
+        .map_err(|e| format!("could not run curl: {}", e))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// Handle `doctor`: run every check, print the results and exit 1 if any
+/// failed
+pub fn doctor() -> Result<i32> {
+    println!("Running tina-monitor doctor...");
+
+    let path = Config::config_path();
+    let loaded = Config::load();
+    let mut checks = vec![check_config(&path, &loaded)];
+    let config = loaded.unwrap_or_default();
+
+    checks.push(check_theme(&config.theme));
+    let reached = (!config.convex.url.is_empty()).then(|| reach_convex(&config.convex.url));
+    checks.push(check_convex(&config.convex.url, reached.flatten()));
+    checks.push(check_tmux(tmux_version().as_deref()));
+
+    let preferred = config.terminal.handler.as_str();
+    let resolved = match preferred {
+        "auto" => detect_terminal(|name| std::env::var(name).ok()),
+        "kitty" | "wezterm" | "iterm" | "alacritty" | "print" => Some(preferred),
+        _ => None,
+    };
+    let available = resolved.is_some_and(|name| get_handler(name).is_available());
+    checks.push(check_terminal(preferred, resolved, available));
+
+    let port = config.daemon.http_port;
+    checks.push(check_daemon(port, daemon_health(port)));
+
+    let mut failures = 0;
+    let mut warnings = 0;
+    for check in &checks {
+        match check {
+            Check::Pass(message) => println!("PASS: {}", message),
+            Check::Warn(message, fix) => {
+                warnings += 1;
+                println!("WARN: {}", message);
+                println!("      Fix: {}", fix);
+            }
+            Check::Fail(message, fix) => {
+                failures += 1;
+                println!("FAIL: {}", message);
+                println!("      Fix: {}", fix);
+            }
+        }
+    }
+
+    if failures > 0 || warnings > 0 {
+        println!(
+            "Doctor summary: {} fail(s), {} warning(s)",
+            failures, warnings
+        );
+    }
+    if failures > 0 {
+        return Ok(1);
+    }
+    println!("PASS: Doctor checks passed");
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tmux_version() {
+        assert_eq!(parse_tmux_version("tmux 3.4"), Some((3, 4)));
+        assert_eq!(parse_tmux_version("tmux 3.3a\n"), Some((3, 3)));
+        assert_eq!(parse_tmux_version("tmux next-3.5"), Some((3, 5)));
+        assert_eq!(parse_tmux_version("tmux master"), None);
+    }
+
+    #[test]
+    fn test_check_tmux() {
+        assert!(matches!(check_tmux(None), Check::Fail(..)));
+        assert_eq!(
+            check_tmux(Some("tmux 3.4\n")),
+            Check::Pass("tmux 3.4 found".to_string())
+        );
+        assert!(matches!(check_tmux(Some("tmux 2.9")), Check::Warn(..)));
+    }
+
+    #[test]
+    fn test_check_convex_requires_url_and_answer() {
+        assert!(matches!(check_convex("", None), Check::Fail(..)));
+        assert!(matches!(
+            check_convex("https://x.convex.cloud", None),
+            Check::Fail(..)
+        ));
+        assert!(matches!(
+            check_convex("https://x.convex.cloud", Some(Ok(3))),
+            Check::Pass(..)
+        ));
+    }
+
+    #[test]
+    fn test_check_terminal() {
+        assert!(matches!(
+            check_terminal("print", Some("print"), true),
+            Check::Pass(..)
+        ));
+        assert!(matches!(
+            check_terminal("kitty", Some("kitty"), false),
+            Check::Warn(..)
+        ));
+        assert!(matches!(
+            check_terminal("auto", None, false),
+            Check::Warn(..)
+        ));
+        assert!(matches!(
+            check_terminal("konsole", None, false),
+            Check::Fail(..)
+        ));
+    }
+
+    #[test]
+    fn test_check_daemon_reads_health_body() {
+        assert!(matches!(
+            check_daemon(7842, Err("connection refused".to_string())),
+            Check::Fail(..)
+        ));
+        assert!(matches!(
+            check_daemon(7842, Ok("<html></html>".to_string())),
+            Check::Fail(..)
+        ));
+        assert!(matches!(
+            check_daemon(
+                7842,
+                Ok(r#"{"status":"ok","clock_skew_warning":true}"#.to_string())
+            ),
+            Check::Warn(..)
+        ));
+        assert_eq!(
+            check_daemon(7842, Ok(r#"{"status":"ok"}"#.to_string())),
+            Check::Pass("tina-daemon is running on port 7842".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check_config_warns_when_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        assert!(matches!(
+            check_config(&path, &Ok(Config::default())),
+            Check::Warn(..)
+        ));
+        std::fs::write(&path, "").unwrap();
+        assert!(matches!(
+            check_config(&path, &Ok(Config::default())),
+            Check::Pass(..)
+        ));
+        assert!(matches!(
+            check_config(&path, &Err(anyhow::anyhow!("bad toml"))),
+            Check::Fail(..)
+        ));
+    }
+}
@@ -0,0 +1,57 @@
+//! Export command handler
+//!
+//! Loads one orchestration with its commits, review findings, and gates,
+//! and renders it with [`crate::report`].
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+
+use crate::config::Config;
+use crate::data::ConvexDataSource;
+use crate::report::{self, ReportData, ReportFormat};
+
+/// Handle `export <name>`: print the report, or write it to `output`
+pub fn export(name: &str, format: ReportFormat, output: Option<&Path>) -> Result<i32> {
+    let config = Config::load()?;
+    if config.convex.url.is_empty() {
+        return Err(anyhow!("Convex URL not configured in config.toml"));
+    }
+
+    let rt = tokio::runtime::Runtime::new()?;
+    let data = rt.block_on(async {
+        let mut ds = ConvexDataSource::new(&config.convex.url).await?;
+        let orch = ds
+            .list_orchestrations()
+            .await?
+            .into_iter()
+            .find(|o| o.matches_name(name))
+            .ok_or_else(|| anyhow!("Orchestration not found: {}", name))?;
+
+        let orchestration = ds
+            .get_orchestration_detail(&orch.id)
+            .await?
+            .ok_or_else(|| anyhow!("Orchestration not found: {}", name))?;
+        let commits = ds.commits(&orch.id).await?;
+        let findings = ds.review_findings(&orch.id).await?;
+        let gates = ds.review_gates(&orch.id).await?;
+        anyhow::Ok(ReportData {
+            orchestration,
+            commits,
+            findings,
+            gates,
+        })
+    })?;
+
+    let rendered = report::render(&data, format);
+    match output {
+        Some(path) => {
+            fs::write(path, rendered)
+                .map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+            eprintln!("Wrote {}", path.display());
+        }
+        None => print!("{}", rendered),
+    }
+    Ok(0)
+}
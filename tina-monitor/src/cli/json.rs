@@ -0,0 +1,72 @@
+//! Versioned `--format json` and `--format jsonl` output
+//!
+//! Every JSON document the CLI prints is wrapped in an envelope carrying
+//! [`SCHEMA_VERSION`], so scripts can refuse output shapes they don't
+//! understand instead of misreading them.
+
+use anyhow::Result;
+use serde::Serialize;
+
+/// Version of the JSON output shapes. Bump it when a field is renamed,
+/// removed, or changes type; adding a field doesn't need a bump.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// A JSON document as printed by the CLI
+#[derive(Debug, Serialize)]
+pub struct Envelope<T> {
+    pub schema_version: u32,
+    pub data: T,
+}
+
+impl<T> Envelope<T> {
+    pub fn new(data: T) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            data,
+        }
+    }
+}
+
+/// `data` as one pretty-printed `--format json` document
+pub fn render<T: Serialize>(data: &T) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&Envelope::new(data))?)
+}
+
+/// `rows` as `--format jsonl`: one compact envelope per row, each on its
+/// own line
+pub fn render_lines<T: Serialize>(rows: &[T]) -> Result<String> {
+    let mut out = String::new();
+    for row in rows {
+        out.push_str(&serde_json::to_string(&Envelope::new(row))?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Row {
+        id: u32,
+    }
+
+    #[test]
+    fn test_json_is_wrapped_in_a_versioned_envelope() {
+        let value: serde_json::Value =
+            serde_json::from_str(&render(&vec![Row { id: 1 }, Row { id: 2 }]).unwrap()).unwrap();
+        assert_eq!(value["schema_version"], SCHEMA_VERSION);
+        assert_eq!(value["data"][1]["id"], 2);
+    }
+
+    #[test]
+    fn test_jsonl_has_one_envelope_per_row() {
+        let out = render_lines(&[Row { id: 1 }, Row { id: 2 }]).unwrap();
+        assert_eq!(
+            out,
+            "{\"schema_version\":1,\"data\":{\"id\":1}}\n{\"schema_version\":1,\"data\":{\"id\":2}}\n"
+        );
+        assert_eq!(render_lines::<Row>(&[]).unwrap(), "");
+    }
+}
@@ -1,7 +1,16 @@
 //! CLI command handlers
 
+pub mod attach;
+pub mod capture;
+pub mod doctor;
+pub mod export;
+pub mod json;
+pub mod record_fixture;
+pub mod setup;
 pub mod status;
+pub mod table;
 pub mod tasks;
 pub mod teams;
+pub mod watch;
 
 pub use status::*;
@@ -0,0 +1,217 @@
+//! Record-fixture command handler
+//!
+//! Snapshots an orchestration into the fixture directory layout that
+//! [`DataSource`](crate::data::DataSource) reads, so views can be developed
+//! and bugs reproduced without Convex or the original worktree:
+//!
+//! ```text
+//! {dir}/{feature}/.claude/tina/supervisor-state.json
+//! {dir}/.claude/teams/{feature}-orchestration/config.json
+//! {dir}/.claude/tasks/{feature}-orchestration/{id}.json
+//! ```
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::data::{ConvexDataSource, MonitorOrchestration, MonitorOrchestrationStatus};
+use crate::types::{OrchestrationStatus, SupervisorState, Team};
+
+/// Handle `record-fixture --feature <name> --output <dir>`
+pub fn record_fixture(feature: &str, output: &Path) -> Result<i32> {
+    let config = Config::load()?;
+    if config.convex.url.is_empty() {
+        return Err(anyhow!("Convex URL not configured in config.toml"));
+    }
+
+    let rt = tokio::runtime::Runtime::new()?;
+    let orchestration = rt.block_on(async {
+        let mut ds = ConvexDataSource::new(&config.convex.url).await?;
+        let orch = ds
+            .list_orchestrations()
+            .await?
+            .into_iter()
+            .find(|o| o.matches_name(feature))
+            .ok_or_else(|| anyhow!("Orchestration not found: {}", feature))?;
+        ds.get_orchestration_detail(&orch.id)
+            .await?
+            .ok_or_else(|| anyhow!("Orchestration not found: {}", feature))
+    })?;
+
+    // The worktree's own state file has phase and timing detail Convex
+    // doesn't, so keep it when this machine has the worktree
+    let state_path = orchestration
+        .worktree_path
+        .join(".claude")
+        .join("tina")
+        .join("supervisor-state.json");
+    let local_state = fs::read_to_string(&state_path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok());
+
+    write_fixture(&orchestration, local_state, output)?;
+    println!(
+        "Recorded {} ({} tasks, {} members) to {}",
+        orchestration.feature_name,
+        orchestration.tasks.len(),
+        orchestration.members.len(),
+        output.display()
+    );
+    Ok(0)
+}
+
+/// Write `orch` under `dir` in the fixture layout, using `local_state` as
+/// the supervisor state when there is one
+pub fn write_fixture(
+    orch: &MonitorOrchestration,
+    local_state: Option<SupervisorState>,
+    dir: &Path,
+) -> Result<()> {
+    let feature = &orch.feature_name;
+    let state = local_state.unwrap_or_else(|| supervisor_state(orch));
+    write_json(
+        &dir.join(feature)
+            .join(".claude")
+            .join("tina")
+            .join("supervisor-state.json"),
+        &state,
+    )?;
+
+    let team_name = format!("{}-orchestration", feature);
+    let team = Team {
+        name: team_name.clone(),
+        description: None,
+        created_at: state.orchestration_started_at.timestamp_millis(),
+        lead_agent_id: orch
+            .members
+            .first()
+            .map(|member| member.agent_id.clone())
+            .unwrap_or_default(),
+        // Fixture tasks are filed under the team name rather than a session
+        lead_session_id: team_name.clone(),
+        members: orch.members.clone(),
+    };
+    write_json(
+        &dir.join(".claude")
+            .join("teams")
+            .join(&team_name)
+            .join("config.json"),
+        &team,
+    )?;
+
+    let tasks_dir = dir.join(".claude").join("tasks").join(&team_name);
+    for task in &orch.tasks {
+        write_json(&tasks_dir.join(format!("{}.json", task.id)), task)?;
+    }
+    Ok(())
+}
+
+/// Supervisor state reconstructed from what Convex records
+fn supervisor_state(orch: &MonitorOrchestration) -> SupervisorState {
+    let mut state = SupervisorState::new(
+        &orch.feature_name,
+        orch.spec_doc_path.clone(),
+        orch.worktree_path.clone(),
+        &orch.branch,
+        orch.total_phases,
+    );
+    state.current_phase = orch.current_phase;
+    state.spec_id = orch.spec_id.clone();
+    state.status = match orch.status {
+        MonitorOrchestrationStatus::Planning | MonitorOrchestrationStatus::Idle => {
+            OrchestrationStatus::Planning
+        }
+        MonitorOrchestrationStatus::Executing => OrchestrationStatus::Executing,
+        MonitorOrchestrationStatus::Reviewing => OrchestrationStatus::Reviewing,
+        MonitorOrchestrationStatus::Complete => OrchestrationStatus::Complete,
+        MonitorOrchestrationStatus::Blocked => OrchestrationStatus::Blocked,
+    };
+    if let Ok(started) = DateTime::parse_from_rfc3339(&orch.started_at) {
+        state.orchestration_started_at = started.with_timezone(&Utc);
+    }
+    state
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    fs::write(path, serde_json::to_string_pretty(value)?)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::DataSource;
+    use crate::types::{Agent, Task, TaskStatus};
+    use std::path::PathBuf;
+    use tina_data::test_util::list_entry;
+
+    fn orchestration() -> MonitorOrchestration {
+        let mut entry = list_entry("orch-1", "blocked", "2026-02-07T10:00:00Z");
+        entry.record.worktree_path = Some("/nonexistent/auth".to_string());
+        entry.record.current_phase = 2.0;
+        let mut orch = MonitorOrchestration::from_list_entry(entry);
+        orch.tasks = ["1", "2"]
+            .iter()
+            .map(|id| Task {
+                id: id.to_string(),
+                subject: format!("Task {}", id),
+                description: String::new(),
+                active_form: None,
+                status: TaskStatus::Pending,
+                owner: None,
+                blocks: vec![],
+                blocked_by: vec![],
+                metadata: serde_json::Value::Null,
+            })
+            .collect();
+        orch.members = vec![Agent {
+            agent_id: "convex-executor-1".to_string(),
+            name: "executor-1".to_string(),
+            agent_type: None,
+            model: "opus".to_string(),
+            joined_at: 0,
+            tmux_pane_id: Some("%3".to_string()),
+            cwd: PathBuf::from("/nonexistent/auth"),
+            subscriptions: vec![],
+        }];
+        orch
+    }
+
+    #[test]
+    fn test_recorded_fixture_loads_through_the_data_source() {
+        let dir = tempfile::tempdir().unwrap();
+        write_fixture(&orchestration(), None, dir.path()).unwrap();
+
+        let mut ds = DataSource::new(Some(dir.path().to_path_buf()));
+        let summaries = ds.list_orchestrations().unwrap();
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].current_phase, 2);
+        assert_eq!(summaries[0].status, MonitorOrchestrationStatus::Blocked);
+
+        let loaded = ds.load_orchestration("auth").unwrap();
+        assert_eq!(loaded.state.branch, "tina/auth");
+        let ids: Vec<_> = loaded.tasks.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2"]);
+        let team = loaded.orchestrator_team.as_ref().unwrap();
+        assert_eq!(team.members[0].tmux_pane_id.as_deref(), Some("%3"));
+    }
+
+    #[test]
+    fn test_local_state_file_is_kept() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut local = supervisor_state(&orchestration());
+        local.total_phases = 7;
+        write_fixture(&orchestration(), Some(local), dir.path()).unwrap();
+
+        let mut ds = DataSource::new(Some(dir.path().to_path_buf()));
+        assert_eq!(ds.load_orchestration("auth").unwrap().state.total_phases, 7);
+    }
+}
@@ -0,0 +1,224 @@
+//! Setup wizard
+//!
+//! Asks for the settings a new install can't guess — which Tina environment
+//! to watch, its Convex URL, the terminal handler and whether sends need
+//! confirming — and writes them to config.toml. Runs on first launch of the
+//! TUI when no config exists, and again on demand with `tina-monitor setup`.
+
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use anyhow::Result;
+
+use crate::config::Config;
+use crate::terminal::detect_terminal;
+
+/// Values accepted for `[terminal] handler`
+const HANDLERS: [&str; 6] = ["auto", "kitty", "wezterm", "iterm", "alacritty", "print"];
+
+/// Whether the TUI should run the wizard before starting: there is no
+/// config yet and someone is at the keyboard to answer
+pub fn needs_first_run() -> bool {
+    !Config::config_path().exists() && io::stdin().is_terminal()
+}
+
+/// Walk through setup, starting from the current config, and save the
+/// result
+pub fn setup() -> Result<i32> {
+    let path = Config::config_path();
+    let base = Config::load()?;
+    let stdin = io::stdin();
+    let config = prompt_config(
+        &mut stdin.lock(),
+        &mut io::stdout(),
+        base,
+        &default_env(),
+        detect_terminal(|name| std::env::var(name).ok()),
+        tina_convex_url,
+    )?;
+    config.save()?;
+    println!("\nWrote {}", path.display());
+    Ok(0)
+}
+
+/// First-launch flow: explain why we're asking, then run setup
+pub fn run_first_time() -> Result<()> {
+    println!(
+        "No config found at {}. Let's create one.",
+        Config::config_path().display()
+    );
+    println!("Press Enter to accept the default shown in brackets.\n");
+    setup()?;
+    println!("Starting the monitor...");
+    Ok(())
+}
+
+/// `TINA_ENV` when set, else prod, matching tina-session
+fn default_env() -> String {
+    match std::env::var("TINA_ENV").ok().as_deref().map(str::trim) {
+        Some("dev") | Some("development") => "dev".to_string(),
+        _ => "prod".to_string(),
+    }
+}
+
+/// Convex URL of `env` from the shared Tina config, if tina-daemon has
+/// written one
+fn tina_convex_url(env: &str) -> Option<String> {
+    tina_session::config::load_config_for_env(Some(env))
+        .ok()
+        .and_then(|config| config.convex_url)
+}
+
+/// Ask one question, returning the trimmed answer or `default` when the
+/// answer is empty or input has ended, and whether input has ended
+fn read_answer(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    question: &str,
+    default: &str,
+) -> Result<(String, bool)> {
+    if default.is_empty() {
+        write!(output, "{}: ", question)?;
+    } else {
+        write!(output, "{} [{}]: ", question, default)?;
+    }
+    output.flush()?;
+    let mut line = String::new();
+    let ended = input.read_line(&mut line)? == 0;
+    let answer = line.trim();
+    let answer = if answer.is_empty() { default } else { answer };
+    Ok((answer.to_string(), ended))
+}
+
+fn ask(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    question: &str,
+    default: &str,
+) -> Result<String> {
+    Ok(read_answer(input, output, question, default)?.0)
+}
+
+/// Ask until the answer is one of `choices`
+fn ask_choice(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    question: &str,
+    choices: &[&str],
+    default: &str,
+) -> Result<String> {
+    loop {
+        let (answer, ended) = read_answer(input, output, question, default)?;
+        let answer = answer.to_ascii_lowercase();
+        if choices.contains(&answer.as_str()) {
+            return Ok(answer);
+        }
+        if ended {
+            anyhow::bail!("{:?} is not one of {}", answer, choices.join(", "));
+        }
+        writeln!(output, "  Choose one of: {}", choices.join(", "))?;
+    }
+}
+
+/// Run the questions against `base`, returning the updated config
+fn prompt_config(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    mut base: Config,
+    env_default: &str,
+    detected_terminal: Option<&str>,
+    convex_url_for_env: impl Fn(&str) -> Option<String>,
+) -> Result<Config> {
+    let env = ask_choice(
+        input,
+        output,
+        "Tina environment (prod/dev)",
+        &["prod", "dev"],
+        env_default,
+    )?;
+
+    let suggested_url = if base.convex.url.is_empty() {
+        convex_url_for_env(&env).unwrap_or_default()
+    } else {
+        base.convex.url.clone()
+    };
+    base.convex.url = ask(input, output, "Convex deployment URL", &suggested_url)?;
+    if base.convex.url.is_empty() {
+        writeln!(
+            output,
+            "  No URL set; the monitor will show no orchestrations until [convex] url is filled in"
+        )?;
+    }
+
+    let handler_default = match base.terminal.handler.as_str() {
+        // "print" is the built-in default, so prefer the terminal we're in
+        "print" => detected_terminal.unwrap_or("print"),
+        handler => handler,
+    };
+    let question = format!("Terminal handler ({})", HANDLERS.join(", "));
+    base.terminal.handler = ask_choice(input, output, &question, &HANDLERS, handler_default)?;
+
+    let confirm_default = if base.safety.confirm_send { "y" } else { "n" };
+    let confirm = ask_choice(
+        input,
+        output,
+        "Confirm before sending commands to agents? (y/n)",
+        &["y", "yes", "n", "no"],
+        confirm_default,
+    )?;
+    base.safety.confirm_send = confirm.starts_with('y');
+
+    Ok(base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(answers: &str, base: Config) -> (Config, String) {
+        let mut output = Vec::new();
+        let config = prompt_config(
+            &mut answers.as_bytes(),
+            &mut output,
+            base,
+            "prod",
+            Some("kitty"),
+            |env| Some(format!("https://{}.convex.cloud", env)),
+        )
+        .unwrap();
+        (config, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_defaults_accepted_with_enter() {
+        let (config, output) = run("\n\n\n\n", Config::default());
+        assert_eq!(config.convex.url, "https://prod.convex.cloud");
+        assert_eq!(config.terminal.handler, "kitty", "detected terminal");
+        assert!(config.safety.confirm_send);
+        assert!(output.contains("Convex deployment URL [https://prod.convex.cloud]: "));
+    }
+
+    #[test]
+    fn test_answers_override_defaults_and_invalid_choices_reprompt() {
+        let (config, output) = run("dev\n\nghostty\nwezterm\nno\n", Config::default());
+        assert_eq!(
+            config.convex.url, "https://dev.convex.cloud",
+            "env picks the URL"
+        );
+        assert_eq!(config.terminal.handler, "wezterm");
+        assert!(!config.safety.confirm_send);
+        assert!(output.contains("Choose one of: auto, kitty"));
+    }
+
+    #[test]
+    fn test_rerun_keeps_existing_settings() {
+        let mut base = Config::default();
+        base.convex.url = "https://mine.convex.cloud".to_string();
+        base.terminal.handler = "iterm".to_string();
+        base.safety.confirm_send = false;
+
+        let (config, _) = run("", base);
+        assert_eq!(config.convex.url, "https://mine.convex.cloud");
+        assert_eq!(config.terminal.handler, "iterm");
+        assert!(!config.safety.confirm_send);
+    }
+}
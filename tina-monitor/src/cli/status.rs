@@ -1,24 +1,204 @@
 //! Status command handlers
 
+use crate::cli::json;
+use crate::cli::table::{self, Column};
 use crate::config::Config;
-use crate::data::{ConvexDataSource, MonitorOrchestrationStatus, TaskSummary};
+use crate::data::{
+    ConvexDataSource, MonitorOrchestration, MonitorOrchestrationStatus, TaskSummary,
+};
 use crate::types::*;
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
 use serde::Serialize;
+use std::collections::BTreeSet;
+use tina_data::humanize::{format_absolute, format_duration_mins, format_relative};
 
 /// Output format for commands
 #[derive(Debug, Clone, Copy)]
 pub enum OutputFormat {
     Text,
+    /// Pretty-printed, in a versioned envelope (see [`json`])
     Json,
+    /// One enveloped JSON object per line; list commands only
+    Jsonl,
+    /// Aligned columns (see [`table`])
+    Table,
+    Csv,
 }
 
+/// `--check` exit code: the condition holds
+pub const CHECK_MET: i32 = 0;
+/// `--check` exit code: the condition doesn't hold (yet)
+pub const CHECK_NOT_MET: i32 = 1;
+/// `--check` exit code: the condition doesn't hold and the orchestration is
+/// blocked, so waiting won't help
+pub const CHECK_BLOCKED: i32 = 2;
+/// `--check` exit code: the condition names a phase or gate the
+/// orchestration doesn't have
+pub const CHECK_UNKNOWN: i32 = 3;
+
 /// Check condition for exit codes
-#[derive(Debug, Clone, Copy)]
+///
+/// Parsed from `complete`, `blocked`, `executing`, `phase-complete=N`,
+/// `gate-pending=GATE`, or `tasks-remaining` compared with `<`, `<=`, `=`,
+/// `>=`, or `>` against a count.
+#[derive(Debug, Clone, PartialEq)]
 pub enum CheckCondition {
     Complete,
     Blocked,
     Executing,
+    /// Phase N has finished
+    PhaseComplete(u32),
+    /// The named review gate (plan, review, finalize) is waiting
+    GatePending(String),
+    /// Tasks not yet completed, compared against a count
+    TasksRemaining(Comparison, usize),
+}
+
+/// Comparison operator in a `tasks-remaining` check
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    Lt,
+    Le,
+    Eq,
+    Ge,
+    Gt,
+}
+
+impl Comparison {
+    fn holds(self, left: usize, right: usize) -> bool {
+        match self {
+            Comparison::Lt => left < right,
+            Comparison::Le => left <= right,
+            Comparison::Eq => left == right,
+            Comparison::Ge => left >= right,
+            Comparison::Gt => left > right,
+        }
+    }
+}
+
+impl std::str::FromStr for CheckCondition {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "complete" => return Ok(Self::Complete),
+            "blocked" => return Ok(Self::Blocked),
+            "executing" => return Ok(Self::Executing),
+            _ => {}
+        }
+        if let Some(phase) = s.strip_prefix("phase-complete=") {
+            return phase
+                .parse()
+                .map(Self::PhaseComplete)
+                .map_err(|_| format!("invalid phase number: {}", phase));
+        }
+        if let Some(gate) = s.strip_prefix("gate-pending=") {
+            if gate.is_empty() {
+                return Err("gate-pending needs a gate name".to_string());
+            }
+            return Ok(Self::GatePending(gate.to_string()));
+        }
+        if let Some(rest) = s.strip_prefix("tasks-remaining") {
+            // Two-character operators first so "<=" isn't read as "<"
+            let (op, count) = [
+                ("<=", Comparison::Le),
+                (">=", Comparison::Ge),
+                ("<", Comparison::Lt),
+                (">", Comparison::Gt),
+                ("=", Comparison::Eq),
+            ]
+            .into_iter()
+            .find_map(|(token, op)| rest.strip_prefix(token).map(|count| (op, count)))
+            .ok_or_else(|| format!("tasks-remaining needs <, <=, =, >=, or >: {}", s))?;
+            return count
+                .parse()
+                .map(|count| Self::TasksRemaining(op, count))
+                .map_err(|_| format!("invalid task count: {}", count));
+        }
+        Err(format!(
+            "unknown check '{}' (expected complete, blocked, executing, \
+             phase-complete=N, gate-pending=GATE, or tasks-remaining<=N)",
+            s
+        ))
+    }
+}
+
+/// What a check is evaluated against
+struct CheckSubject<'a> {
+    /// Status the simple checks compare with
+    status: &'a str,
+    orch: &'a MonitorOrchestration,
+    summary: &'a TaskSummary,
+    /// Pending gate ids; only loaded for `gate-pending` checks
+    pending_gates: &'a BTreeSet<String>,
+}
+
+/// Whether `phase` of `orch` has finished
+fn phase_complete(orch: &MonitorOrchestration, phase: u32) -> bool {
+    orch.status == MonitorOrchestrationStatus::Complete
+        || phase < orch.current_phase
+        || orch
+            .phases
+            .iter()
+            .any(|p| p.phase_number == phase.to_string() && p.status == "complete")
+}
+
+/// Exit code for `condition`: [`CHECK_MET`], [`CHECK_NOT_MET`],
+/// [`CHECK_BLOCKED`], or [`CHECK_UNKNOWN`]
+fn evaluate_check(condition: &CheckCondition, subject: &CheckSubject) -> i32 {
+    let met = match condition {
+        CheckCondition::Complete => subject.status == "complete",
+        CheckCondition::Blocked => subject.status == "blocked",
+        CheckCondition::Executing => subject.status == "executing",
+        CheckCondition::PhaseComplete(phase) => {
+            if *phase == 0 || *phase > subject.orch.total_phases {
+                return CHECK_UNKNOWN;
+            }
+            phase_complete(subject.orch, *phase)
+        }
+        CheckCondition::GatePending(gate) => {
+            if !KNOWN_GATES.contains(&gate.as_str()) {
+                return CHECK_UNKNOWN;
+            }
+            subject.pending_gates.contains(gate)
+        }
+        CheckCondition::TasksRemaining(op, count) => {
+            let remaining = subject.summary.total - subject.summary.completed;
+            op.holds(remaining, *count)
+        }
+    };
+    if met {
+        CHECK_MET
+    } else if subject.orch.status == MonitorOrchestrationStatus::Blocked {
+        CHECK_BLOCKED
+    } else {
+        CHECK_NOT_MET
+    }
+}
+
+/// Review gates an orchestration can have
+const KNOWN_GATES: &[&str] = &["plan", "review", "finalize"];
+
+/// Pending gate ids for `orch`, fetched only when `condition` needs them
+fn pending_gates_for(
+    ds_url: &str,
+    condition: &CheckCondition,
+    orch: &MonitorOrchestration,
+) -> Result<BTreeSet<String>> {
+    if !matches!(condition, CheckCondition::GatePending(_)) {
+        return Ok(BTreeSet::new());
+    }
+    let rt = tokio::runtime::Runtime::new()?;
+    rt.block_on(async {
+        let mut ds = ConvexDataSource::new(ds_url).await?;
+        let gates = ds.review_gates(&orch.id).await?;
+        Ok(gates
+            .into_iter()
+            .filter(|gate| gate.status == "pending")
+            .map(|gate| gate.gate_id)
+            .collect())
+    })
 }
 
 /// Team status output for JSON format
@@ -30,11 +210,52 @@ pub struct TeamStatusOutput {
     pub blocked_reason: Option<String>,
 }
 
+const TEAM_STATUS_COLUMNS: &[Column<TeamStatusOutput>] = &[
+    Column {
+        name: "team",
+        value: |o| o.team_name.clone(),
+    },
+    Column {
+        name: "status",
+        value: |o| o.status.clone(),
+    },
+    Column {
+        name: "total",
+        value: |o| o.tasks.total.to_string(),
+    },
+    Column {
+        name: "completed",
+        value: |o| o.tasks.completed.to_string(),
+    },
+    Column {
+        name: "in_progress",
+        value: |o| o.tasks.in_progress.to_string(),
+    },
+    Column {
+        name: "pending",
+        value: |o| o.tasks.pending.to_string(),
+    },
+    Column {
+        name: "blocked",
+        value: |o| o.tasks.blocked.to_string(),
+    },
+    Column {
+        name: "blocked_reason",
+        value: |o| o.blocked_reason.clone().unwrap_or_default(),
+    },
+];
+
 /// Handle `status team <name>` command
 ///
 /// In the Convex model, "teams" map to orchestrations. We find the orchestration
 /// whose feature_name matches and derive team status from it.
-pub fn status_team(name: &str, format: OutputFormat, check: Option<CheckCondition>) -> Result<i32> {
+pub fn status_team(
+    name: &str,
+    format: OutputFormat,
+    columns: Option<&str>,
+    check: Option<CheckCondition>,
+) -> Result<i32> {
+    table::check_columns_format(format, columns)?;
     let config = Config::load()?;
     if config.convex.url.is_empty() {
         return Err(anyhow!("Convex URL not configured in config.toml"));
@@ -49,11 +270,7 @@ pub fn status_team(name: &str, format: OutputFormat, check: Option<CheckConditio
     // Find by feature name or team name pattern
     let orch = orchestrations
         .into_iter()
-        .find(|o| {
-            o.feature_name == name
-                || o.team_name() == name
-                || o.feature_name == name.trim_end_matches("-orchestration")
-        })
+        .find(|o| o.matches_name(name))
         .ok_or_else(|| anyhow!("Team/orchestration not found: {}", name))?;
 
     let summary = TaskSummary::from_tasks(&orch.tasks);
@@ -67,17 +284,24 @@ pub fn status_team(name: &str, format: OutputFormat, check: Option<CheckConditio
     };
 
     if let Some(condition) = check {
-        let matches = match condition {
-            CheckCondition::Complete => status == "complete",
-            CheckCondition::Blocked => status == "blocked",
-            CheckCondition::Executing => status == "executing",
-        };
-        return Ok(if matches { 0 } else { 1 });
+        let pending_gates = pending_gates_for(&config.convex.url, &condition, &orch)?;
+        return Ok(evaluate_check(
+            &condition,
+            &CheckSubject {
+                status: &status,
+                orch: &orch,
+                summary: &output.tasks,
+                pending_gates: &pending_gates,
+            },
+        ));
     }
 
     match format {
         OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", json::render(&output)?);
+        }
+        OutputFormat::Jsonl => {
+            print!("{}", json::render_lines(std::slice::from_ref(&output))?);
         }
         OutputFormat::Text => {
             println!("Team: {}", output.team_name);
@@ -94,6 +318,12 @@ pub fn status_team(name: &str, format: OutputFormat, check: Option<CheckConditio
                 println!("Blocked: {}", reason);
             }
         }
+        OutputFormat::Table | OutputFormat::Csv => {
+            print!(
+                "{}",
+                table::render(&[output], TEAM_STATUS_COLUMNS, columns, format)?
+            );
+        }
     }
 
     Ok(0)
@@ -135,14 +365,70 @@ pub struct OrchestrationStatusOutput {
     pub spec_doc_path: String,
     pub status: MonitorOrchestrationStatus,
     pub tasks: TaskSummary,
+    pub started_at: String,
+    pub elapsed_mins: Option<i64>,
+    /// `elapsed_mins` formatted, e.g. "2h 14m"
+    pub elapsed_human: Option<String>,
 }
 
+const ORCHESTRATION_STATUS_COLUMNS: &[Column<OrchestrationStatusOutput>] = &[
+    Column {
+        name: "feature",
+        value: |o| o.feature_name.clone(),
+    },
+    Column {
+        name: "status",
+        value: |o| o.status.to_string(),
+    },
+    Column {
+        name: "phase",
+        value: |o| format!("{}/{}", o.current_phase, o.total_phases),
+    },
+    Column {
+        name: "elapsed",
+        value: |o| o.elapsed_human.clone().unwrap_or_default(),
+    },
+    Column {
+        name: "total",
+        value: |o| o.tasks.total.to_string(),
+    },
+    Column {
+        name: "completed",
+        value: |o| o.tasks.completed.to_string(),
+    },
+    Column {
+        name: "in_progress",
+        value: |o| o.tasks.in_progress.to_string(),
+    },
+    Column {
+        name: "pending",
+        value: |o| o.tasks.pending.to_string(),
+    },
+    Column {
+        name: "blocked",
+        value: |o| o.tasks.blocked.to_string(),
+    },
+    Column {
+        name: "worktree",
+        value: |o| o.worktree_path.clone(),
+    },
+    Column {
+        name: "spec_doc",
+        value: |o| o.spec_doc_path.clone(),
+    },
+];
+
 /// Handle `status orchestration <name>` command
+///
+/// `verbose` adds absolute timestamps to the text output.
 pub fn status_orchestration(
     name: &str,
     format: OutputFormat,
+    columns: Option<&str>,
     check: Option<CheckCondition>,
+    verbose: bool,
 ) -> Result<i32> {
+    table::check_columns_format(format, columns)?;
     let config = Config::load()?;
     if config.convex.url.is_empty() {
         return Err(anyhow!("Convex URL not configured in config.toml"));
@@ -156,14 +442,12 @@ pub fn status_orchestration(
 
     let orch = orchestrations
         .into_iter()
-        .find(|o| {
-            o.feature_name == name
-                || o.team_name() == name
-                || o.feature_name == name.trim_end_matches("-orchestration")
-        })
+        .find(|o| o.matches_name(name))
         .ok_or_else(|| anyhow!("Orchestration not found: {}", name))?;
 
     let summary = TaskSummary::from_tasks(&orch.tasks);
+    let now = Utc::now();
+    let elapsed_mins = orch.elapsed_mins(now);
 
     let output = OrchestrationStatusOutput {
         feature_name: orch.feature_name.clone(),
@@ -173,22 +457,32 @@ pub fn status_orchestration(
         spec_doc_path: orch.spec_doc_path.display().to_string(),
         status: orch.status.clone(),
         tasks: summary.clone(),
+        started_at: orch.started_at.clone(),
+        elapsed_mins,
+        elapsed_human: elapsed_mins.map(format_duration_mins),
     };
 
     let status_str = orch.status.to_string();
 
     if let Some(condition) = check {
-        let matches = match condition {
-            CheckCondition::Complete => status_str == "complete",
-            CheckCondition::Blocked => status_str == "blocked",
-            CheckCondition::Executing => status_str == "executing",
-        };
-        return Ok(if matches { 0 } else { 1 });
+        let pending_gates = pending_gates_for(&config.convex.url, &condition, &orch)?;
+        return Ok(evaluate_check(
+            &condition,
+            &CheckSubject {
+                status: &status_str,
+                orch: &orch,
+                summary: &summary,
+                pending_gates: &pending_gates,
+            },
+        ));
     }
 
     match format {
         OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", json::render(&output)?);
+        }
+        OutputFormat::Jsonl => {
+            print!("{}", json::render_lines(std::slice::from_ref(&output))?);
         }
         OutputFormat::Text => {
             println!("Orchestration: {}", output.feature_name);
@@ -196,6 +490,22 @@ pub fn status_orchestration(
             println!("Phase: {}/{}", output.current_phase, output.total_phases);
             println!("Spec Doc: {}", output.spec_doc_path);
             println!("Status: {}", output.status);
+            let started = DateTime::parse_from_rfc3339(&output.started_at)
+                .ok()
+                .map(|at| at.with_timezone(&Utc));
+            if let Some(elapsed) = &output.elapsed_human {
+                match started {
+                    Some(at) => println!(
+                        "Elapsed: {} (started {})",
+                        elapsed,
+                        format_relative(at, now)
+                    ),
+                    None => println!("Elapsed: {}", elapsed),
+                }
+            }
+            if let (true, Some(at)) = (verbose, started) {
+                println!("Started At: {}", format_absolute(at));
+            }
             println!();
             println!("Tasks:");
             println!("  Total: {}", summary.total);
@@ -204,6 +514,12 @@ pub fn status_orchestration(
             println!("  Pending: {}", summary.pending);
             println!("  Blocked: {}", summary.blocked);
         }
+        OutputFormat::Table | OutputFormat::Csv => {
+            print!(
+                "{}",
+                table::render(&[output], ORCHESTRATION_STATUS_COLUMNS, columns, format)?
+            );
+        }
     }
 
     Ok(0)
@@ -221,8 +537,37 @@ pub struct TaskStatusOutput {
     pub metadata: serde_json::Value,
 }
 
+const TASK_STATUS_COLUMNS: &[Column<TaskStatusOutput>] = &[
+    Column {
+        name: "id",
+        value: |o| o.id.clone(),
+    },
+    Column {
+        name: "subject",
+        value: |o| o.subject.clone(),
+    },
+    Column {
+        name: "status",
+        value: |o| o.status.to_string(),
+    },
+    Column {
+        name: "owner",
+        value: |o| o.owner.clone().unwrap_or_default(),
+    },
+    Column {
+        name: "blocked_by",
+        value: |o| o.blocked_by.join(" "),
+    },
+];
+
 /// Handle `status task <team> <id>` command
-pub fn status_task(team_name: &str, task_id: &str, format: OutputFormat) -> Result<i32> {
+pub fn status_task(
+    team_name: &str,
+    task_id: &str,
+    format: OutputFormat,
+    columns: Option<&str>,
+) -> Result<i32> {
+    table::check_columns_format(format, columns)?;
     let config = Config::load()?;
     if config.convex.url.is_empty() {
         return Err(anyhow!("Convex URL not configured in config.toml"));
@@ -237,11 +582,7 @@ pub fn status_task(team_name: &str, task_id: &str, format: OutputFormat) -> Resu
     // Find orchestration by team name
     let orch = orchestrations
         .into_iter()
-        .find(|o| {
-            o.feature_name == team_name
-                || o.team_name() == team_name
-                || o.feature_name == team_name.trim_end_matches("-orchestration")
-        })
+        .find(|o| o.matches_name(team_name))
         .ok_or_else(|| anyhow!("Team/orchestration not found: {}", team_name))?;
 
     let task = orch
@@ -262,7 +603,10 @@ pub fn status_task(team_name: &str, task_id: &str, format: OutputFormat) -> Resu
 
     match format {
         OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", json::render(&output)?);
+        }
+        OutputFormat::Jsonl => {
+            print!("{}", json::render_lines(std::slice::from_ref(&output))?);
         }
         OutputFormat::Text => {
             println!("Task: {} - {}", output.id, output.subject);
@@ -277,6 +621,12 @@ pub fn status_task(team_name: &str, task_id: &str, format: OutputFormat) -> Resu
             println!("Description:");
             println!("{}", output.description);
         }
+        OutputFormat::Table | OutputFormat::Csv => {
+            print!(
+                "{}",
+                table::render(&[output], TASK_STATUS_COLUMNS, columns, format)?
+            );
+        }
     }
 
     Ok(0)
@@ -285,6 +635,7 @@ pub fn status_task(team_name: &str, task_id: &str, format: OutputFormat) -> Resu
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tina_data::test_util::list_entry;
 
     fn make_task(id: &str, status: TaskStatus, blocked_by: Vec<String>) -> Task {
         Task {
@@ -383,4 +734,92 @@ mod tests {
         assert!(json.contains("\"status\":\"executing\""));
         assert!(json.contains("\"total\":5"));
     }
+
+    fn make_orchestration(status: &str, current_phase: f64) -> MonitorOrchestration {
+        let mut entry = list_entry("orch-1", status, "2026-02-07T10:00:00Z");
+        entry.record.current_phase = current_phase;
+        MonitorOrchestration::from_list_entry(entry)
+    }
+
+    fn check(condition: &str, orch: &MonitorOrchestration, gates: &[&str]) -> i32 {
+        let tasks = vec![
+            make_task("1", TaskStatus::Completed, vec![]),
+            make_task("2", TaskStatus::InProgress, vec![]),
+            make_task("3", TaskStatus::Pending, vec![]),
+        ];
+        let summary = TaskSummary::from_tasks(&tasks);
+        let pending_gates = gates.iter().map(|g| g.to_string()).collect();
+        evaluate_check(
+            &condition.parse().unwrap(),
+            &CheckSubject {
+                status: &orch.status.to_string(),
+                orch,
+                summary: &summary,
+                pending_gates: &pending_gates,
+            },
+        )
+    }
+
+    #[test]
+    fn test_parse_check_conditions() {
+        assert_eq!("complete".parse(), Ok(CheckCondition::Complete));
+        assert_eq!(
+            "phase-complete=3".parse(),
+            Ok(CheckCondition::PhaseComplete(3))
+        );
+        assert_eq!(
+            "gate-pending=review".parse(),
+            Ok(CheckCondition::GatePending("review".to_string()))
+        );
+        assert_eq!(
+            "tasks-remaining<=0".parse(),
+            Ok(CheckCondition::TasksRemaining(Comparison::Le, 0))
+        );
+        assert_eq!(
+            "tasks-remaining>2".parse(),
+            Ok(CheckCondition::TasksRemaining(Comparison::Gt, 2))
+        );
+        assert!("phase-complete=x".parse::<CheckCondition>().is_err());
+        assert!("gate-pending=".parse::<CheckCondition>().is_err());
+        assert!("tasks-remaining~1".parse::<CheckCondition>().is_err());
+        assert!("done".parse::<CheckCondition>().is_err());
+    }
+
+    #[test]
+    fn test_phase_complete_check() {
+        let orch = make_orchestration("executing", 2.0);
+        assert_eq!(check("phase-complete=1", &orch, &[]), CHECK_MET);
+        assert_eq!(check("phase-complete=2", &orch, &[]), CHECK_NOT_MET);
+        assert_eq!(check("phase-complete=4", &orch, &[]), CHECK_UNKNOWN);
+
+        let done = make_orchestration("complete", 3.0);
+        assert_eq!(check("phase-complete=3", &done, &[]), CHECK_MET);
+    }
+
+    #[test]
+    fn test_gate_pending_check() {
+        let orch = make_orchestration("reviewing", 2.0);
+        assert_eq!(check("gate-pending=review", &orch, &["review"]), CHECK_MET);
+        assert_eq!(
+            check("gate-pending=plan", &orch, &["review"]),
+            CHECK_NOT_MET
+        );
+        assert_eq!(check("gate-pending=deploy", &orch, &[]), CHECK_UNKNOWN);
+    }
+
+    #[test]
+    fn test_tasks_remaining_check() {
+        let orch = make_orchestration("executing", 2.0);
+        assert_eq!(check("tasks-remaining=2", &orch, &[]), CHECK_MET);
+        assert_eq!(check("tasks-remaining<=0", &orch, &[]), CHECK_NOT_MET);
+        assert_eq!(check("tasks-remaining<3", &orch, &[]), CHECK_MET);
+    }
+
+    #[test]
+    fn test_unmet_check_on_blocked_orchestration() {
+        let orch = make_orchestration("blocked", 2.0);
+        assert_eq!(check("blocked", &orch, &[]), CHECK_MET);
+        assert_eq!(check("complete", &orch, &[]), CHECK_BLOCKED);
+        assert_eq!(check("tasks-remaining<=0", &orch, &[]), CHECK_BLOCKED);
+    }
 }
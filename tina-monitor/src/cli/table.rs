@@ -0,0 +1,191 @@
+//! Shared column formatter for `--format table` and `--format csv`
+
+use crate::cli::OutputFormat;
+use anyhow::{anyhow, bail, Result};
+
+/// A named column of a listing, with how to render a row's cell.
+pub struct Column<T> {
+    pub name: &'static str,
+    pub value: fn(&T) -> String,
+}
+
+/// Check that `--columns` is only used with a tabular format.
+pub fn check_columns_format(format: OutputFormat, columns: Option<&str>) -> Result<()> {
+    match (format, columns) {
+        (OutputFormat::Text | OutputFormat::Json | OutputFormat::Jsonl, Some(_)) => {
+            bail!("--columns requires --format table or --format csv")
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Resolve a comma-separated `--columns` list against `all`, keeping the
+/// requested order. `None` selects every column.
+pub fn select_columns<'a, T>(
+    all: &'a [Column<T>],
+    requested: Option<&str>,
+) -> Result<Vec<&'a Column<T>>> {
+    let Some(requested) = requested else {
+        return Ok(all.iter().collect());
+    };
+    let selected = requested
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            all.iter().find(|c| c.name == name).ok_or_else(|| {
+                let valid: Vec<_> = all.iter().map(|c| c.name).collect();
+                anyhow!("Unknown column '{}' (valid: {})", name, valid.join(", "))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if selected.is_empty() {
+        bail!("--columns must name at least one column");
+    }
+    Ok(selected)
+}
+
+/// Render `rows` as an aligned table or CSV with the selected columns.
+///
+/// Any format other than [`OutputFormat::Csv`] renders as a table.
+pub fn render<T>(
+    rows: &[T],
+    all: &[Column<T>],
+    columns: Option<&str>,
+    format: OutputFormat,
+) -> Result<String> {
+    let selected = select_columns(all, columns)?;
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| selected.iter().map(|c| (c.value)(row)).collect())
+        .collect();
+    let names: Vec<&str> = selected.iter().map(|c| c.name).collect();
+    Ok(match format {
+        OutputFormat::Csv => render_csv(&names, &cells),
+        _ => render_table(&names, &cells),
+    })
+}
+
+fn render_table(names: &[&str], rows: &[Vec<String>]) -> String {
+    let headers: Vec<String> = names.iter().map(|n| n.to_uppercase()).collect();
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: &[String]| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        padded.join("  ").trim_end().to_string()
+    };
+
+    let mut out = String::new();
+    out.push_str(&line(&headers));
+    out.push('\n');
+    let rules: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+    out.push_str(&line(&rules));
+    out.push('\n');
+    for row in rows {
+        out.push_str(&line(row));
+        out.push('\n');
+    }
+    out
+}
+
+fn render_csv(names: &[&str], rows: &[Vec<String>]) -> String {
+    let line = |cells: Vec<&str>| {
+        cells
+            .into_iter()
+            .map(csv_field)
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    let mut out = line(names.to_vec());
+    out.push('\n');
+    for row in rows {
+        out.push_str(&line(row.iter().map(String::as_str).collect()));
+        out.push('\n');
+    }
+    out
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Row {
+        id: u32,
+        name: &'static str,
+    }
+
+    const COLUMNS: &[Column<Row>] = &[
+        Column {
+            name: "id",
+            value: |r| r.id.to_string(),
+        },
+        Column {
+            name: "name",
+            value: |r| r.name.to_string(),
+        },
+    ];
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row {
+                id: 1,
+                name: "auth",
+            },
+            Row {
+                id: 12,
+                name: "say \"hi\", then leave",
+            },
+        ]
+    }
+
+    #[test]
+    fn test_select_columns_keeps_requested_order() {
+        let selected = select_columns(COLUMNS, Some("name, id")).unwrap();
+        let names: Vec<_> = selected.iter().map(|c| c.name).collect();
+        assert_eq!(names, vec!["name", "id"]);
+
+        assert_eq!(select_columns(COLUMNS, None).unwrap().len(), 2);
+
+        let err = select_columns(COLUMNS, Some("id,owner")).err().unwrap();
+        assert!(err.to_string().contains("valid: id, name"));
+        assert!(select_columns(COLUMNS, Some(" , ")).is_err());
+    }
+
+    #[test]
+    fn test_render_table_aligns_columns() {
+        let out = render(&rows()[..1], COLUMNS, None, OutputFormat::Table).unwrap();
+        assert_eq!(out, "ID  NAME\n--  ----\n1   auth\n");
+    }
+
+    #[test]
+    fn test_render_csv_quotes_fields() {
+        let out = render(&rows(), COLUMNS, Some("name,id"), OutputFormat::Csv).unwrap();
+        assert_eq!(out, "name,id\nauth,1\n\"say \"\"hi\"\", then leave\",12\n");
+    }
+
+    #[test]
+    fn test_columns_require_tabular_format() {
+        assert!(check_columns_format(OutputFormat::Json, Some("id")).is_err());
+        assert!(check_columns_format(OutputFormat::Csv, Some("id")).is_ok());
+        assert!(check_columns_format(OutputFormat::Text, None).is_ok());
+    }
+}
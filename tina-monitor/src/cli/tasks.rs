@@ -1,6 +1,7 @@
 //! Tasks command handler
 
-use crate::cli::OutputFormat;
+use crate::cli::table::{self, Column};
+use crate::cli::{json, OutputFormat};
 use crate::config::Config;
 use crate::data::ConvexDataSource;
 use crate::types::TaskStatus;
@@ -18,12 +19,49 @@ pub struct TaskListEntry {
     pub blocked_by: Vec<String>,
 }
 
+impl TaskListEntry {
+    /// Status as listed: pending tasks waiting on others show as blocked.
+    fn display_status(&self) -> &'static str {
+        match self.status {
+            TaskStatus::Pending if !self.blocked_by.is_empty() => "blocked",
+            TaskStatus::Pending => "pending",
+            TaskStatus::InProgress => "in_progress",
+            TaskStatus::Completed => "completed",
+        }
+    }
+}
+
+const TASK_COLUMNS: &[Column<TaskListEntry>] = &[
+    Column {
+        name: "id",
+        value: |e| e.id.clone(),
+    },
+    Column {
+        name: "status",
+        value: |e| e.display_status().to_string(),
+    },
+    Column {
+        name: "subject",
+        value: |e| e.subject.clone(),
+    },
+    Column {
+        name: "owner",
+        value: |e| e.owner.clone().unwrap_or_default(),
+    },
+    Column {
+        name: "blocked_by",
+        value: |e| e.blocked_by.join(" "),
+    },
+];
+
 /// List tasks for a team (orchestration in Convex model)
 pub fn list_tasks(
     team_name: &str,
     format: OutputFormat,
+    columns: Option<&str>,
     status_filter: Option<TaskStatusFilter>,
 ) -> Result<i32> {
+    table::check_columns_format(format, columns)?;
     let config = Config::load()?;
     if config.convex.url.is_empty() {
         return Err(anyhow!("Convex URL not configured in config.toml"));
@@ -69,7 +107,10 @@ pub fn list_tasks(
 
     match format {
         OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", json::render(&output)?);
+        }
+        OutputFormat::Jsonl => {
+            print!("{}", json::render_lines(&output)?);
         }
         OutputFormat::Text => {
             if output.is_empty() {
@@ -78,17 +119,7 @@ pub fn list_tasks(
                 println!("{:<6} {:<12} {:<40}", "ID", "STATUS", "SUBJECT");
                 println!("{:-<6} {:-<12} {:-<40}", "", "", "");
                 for entry in &output {
-                    let status_str = match entry.status {
-                        TaskStatus::Pending => {
-                            if entry.blocked_by.is_empty() {
-                                "pending"
-                            } else {
-                                "blocked"
-                            }
-                        }
-                        TaskStatus::InProgress => "in_progress",
-                        TaskStatus::Completed => "completed",
-                    };
+                    let status_str = entry.display_status();
                     // Truncate subject if too long
                     let subject = if entry.subject.len() > 40 {
                         format!("{}...", &entry.subject[..37])
@@ -99,6 +130,9 @@ pub fn list_tasks(
                 }
             }
         }
+        OutputFormat::Table | OutputFormat::Csv => {
+            print!("{}", table::render(&output, TASK_COLUMNS, columns, format)?);
+        }
     }
 
     Ok(0)
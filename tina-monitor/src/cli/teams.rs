@@ -1,6 +1,7 @@
 //! Teams command handler
 
-use crate::cli::OutputFormat;
+use crate::cli::table::{self, Column};
+use crate::cli::{json, OutputFormat};
 use crate::config::Config;
 use crate::data::ConvexDataSource;
 use crate::TeamFilter;
@@ -16,8 +17,42 @@ pub struct TeamListEntry {
     pub is_orchestration: bool,
 }
 
+impl TeamListEntry {
+    fn team_type(&self) -> &'static str {
+        if self.is_orchestration {
+            "orchestration"
+        } else {
+            "phase"
+        }
+    }
+}
+
+const TEAM_COLUMNS: &[Column<TeamListEntry>] = &[
+    Column {
+        name: "name",
+        value: |e| e.name.clone(),
+    },
+    Column {
+        name: "members",
+        value: |e| e.member_count.to_string(),
+    },
+    Column {
+        name: "type",
+        value: |e| e.team_type().to_string(),
+    },
+    Column {
+        name: "worktree",
+        value: |e| e.worktree_path.clone(),
+    },
+];
+
 /// List all teams (orchestrations in Convex model)
-pub fn list_teams(format: OutputFormat, filter: Option<TeamFilter>) -> Result<i32> {
+pub fn list_teams(
+    format: OutputFormat,
+    columns: Option<&str>,
+    filter: Option<TeamFilter>,
+) -> Result<i32> {
+    table::check_columns_format(format, columns)?;
     let config = Config::load()?;
     if config.convex.url.is_empty() {
         return Err(anyhow!("Convex URL not configured in config.toml"));
@@ -55,7 +90,10 @@ pub fn list_teams(format: OutputFormat, filter: Option<TeamFilter>) -> Result<i3
 
     match format {
         OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&output)?);
+            println!("{}", json::render(&output)?);
+        }
+        OutputFormat::Jsonl => {
+            print!("{}", json::render_lines(&output)?);
         }
         OutputFormat::Text => {
             if output.is_empty() {
@@ -64,18 +102,18 @@ pub fn list_teams(format: OutputFormat, filter: Option<TeamFilter>) -> Result<i3
                 println!("{:<30} {:>8} {:>12}", "NAME", "MEMBERS", "TYPE");
                 println!("{:-<30} {:->8} {:->12}", "", "", "");
                 for entry in &output {
-                    let team_type = if entry.is_orchestration {
-                        "orchestration"
-                    } else {
-                        "phase"
-                    };
                     println!(
                         "{:<30} {:>8} {:>12}",
-                        entry.name, entry.member_count, team_type
+                        entry.name,
+                        entry.member_count,
+                        entry.team_type()
                     );
                 }
             }
         }
+        OutputFormat::Table | OutputFormat::Csv => {
+            print!("{}", table::render(&output, TEAM_COLUMNS, columns, format)?);
+        }
     }
 
     Ok(0)
@@ -0,0 +1,149 @@
+//! Watch command handler
+//!
+//! Polls Convex without the TUI, printing each detected change and
+//! forwarding alerts as desktop notifications when configured. With
+//! `--format jsonl` every change is one JSON object per line, for piping
+//! into scripts and CI.
+
+use std::collections::{BTreeMap, HashSet};
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use chrono::{Local, Utc};
+use serde::Serialize;
+use tina_data::humanize::format_time;
+
+use crate::cli::json;
+use crate::config::Config;
+use crate::data::changes::{self, Change, ChangeTracker, Snapshots};
+use crate::data::{ConvexDataSource, MonitorOrchestrationStatus};
+use crate::desktop_notify::DesktopNotifier;
+
+/// Output format for `watch`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchFormat {
+    /// `HH:MM:SS  message` lines
+    Text,
+    /// One JSON object per change
+    Jsonl,
+}
+
+/// A change as printed by `--format jsonl`
+#[derive(Serialize)]
+struct WatchEvent<'a> {
+    /// See [`json::SCHEMA_VERSION`]
+    schema_version: u32,
+    /// RFC 3339 time the change was detected
+    time: String,
+    #[serde(flatten)]
+    change: &'a Change,
+}
+
+/// Poll for changes until interrupted.
+///
+/// `feature` limits the watch to one orchestration. `desktop` forces desktop
+/// notifications on even when the config leaves them off; per-event
+/// settings still apply.
+pub fn watch(
+    interval: Duration,
+    desktop: bool,
+    feature: Option<&str>,
+    format: WatchFormat,
+) -> Result<i32> {
+    let config = Config::load()?;
+    if config.convex.url.is_empty() {
+        return Err(anyhow!("Convex URL not configured in config.toml"));
+    }
+
+    let mut notifications = config.notifications.clone();
+    notifications.desktop |= desktop;
+    let notifier = DesktopNotifier::new(notifications);
+
+    let rt = tokio::runtime::Runtime::new()?;
+    let mut ds = rt.block_on(ConvexDataSource::new(&config.convex.url))?;
+    let mut tracker = ChangeTracker::default();
+    let mut active = HashSet::new();
+
+    loop {
+        // A failed poll keeps the previous baseline; try again next interval
+        match rt.block_on(poll(&mut ds, feature, &mut active)) {
+            Ok(snapshots) => {
+                for change in tracker.observe(snapshots) {
+                    print_change(&change, format)?;
+                    notifier.notify(change.kind, &change.message);
+                }
+            }
+            Err(e) => eprintln!("watch: refresh failed: {:#}", e),
+        }
+        std::thread::sleep(interval);
+    }
+}
+
+/// Load one round of snapshots.
+///
+/// Tasks and commits are loaded for orchestrations that are running, or
+/// were running at the previous poll (`active`), so the final tasks and
+/// commits of a completing orchestration are still reported.
+async fn poll(
+    ds: &mut ConvexDataSource,
+    feature: Option<&str>,
+    active: &mut HashSet<String>,
+) -> Result<Snapshots> {
+    let mut orchestrations = ds.list_orchestrations().await?;
+    if let Some(name) = feature {
+        orchestrations.retain(|o| o.matches_name(name));
+    }
+
+    let mut commits = BTreeMap::new();
+    let mut now_active = HashSet::new();
+    for orch in orchestrations.iter_mut() {
+        let running = orch.status != MonitorOrchestrationStatus::Complete;
+        if running {
+            now_active.insert(orch.id.clone());
+        }
+        if !running && !active.contains(&orch.id) {
+            continue;
+        }
+        if let Some(detail) = ds.get_orchestration_detail(&orch.id).await? {
+            *orch = detail;
+        }
+        let shas: BTreeMap<String, String> = ds
+            .commits(&orch.id)
+            .await?
+            .into_iter()
+            .map(|c| (c.sha, c.subject.unwrap_or_default()))
+            .collect();
+        commits.insert(orch.id.clone(), shas);
+    }
+    *active = now_active;
+
+    let gates = ds.pending_gates(&orchestrations).await;
+    let mut snapshots = changes::snapshot(&orchestrations, &gates);
+    for (id, shas) in commits {
+        if let Some(snapshot) = snapshots.get_mut(&id) {
+            snapshot.commits = Some(shas);
+        }
+    }
+    Ok(snapshots)
+}
+
+fn print_change(change: &Change, format: WatchFormat) -> Result<()> {
+    match format {
+        WatchFormat::Text => {
+            println!(
+                "{}  {}",
+                format_time(Utc::now(), "%H:%M:%S"),
+                change.message
+            );
+        }
+        WatchFormat::Jsonl => {
+            let event = WatchEvent {
+                schema_version: json::SCHEMA_VERSION,
+                time: Local::now().to_rfc3339(),
+                change,
+            };
+            println!("{}", serde_json::to_string(&event)?);
+        }
+    }
+    Ok(())
+}
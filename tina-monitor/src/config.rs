@@ -4,6 +4,11 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
+pub use crate::theme::ThemeConfig;
+
+use crate::data::changes::ChangeKind;
+use tina_data::humanize::DisplayTimezone;
+
 /// Main configuration structure
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
@@ -13,6 +18,155 @@ pub struct Config {
     pub safety: SafetyConfig,
     pub logging: LoggingConfig,
     pub convex: ConvexConfig,
+    pub theme: ThemeConfig,
+    pub notifications: NotificationsConfig,
+    pub display: DisplayConfig,
+    pub daemon: DaemonConfig,
+    pub list: ListConfig,
+    pub capture: CaptureConfig,
+    pub cost: CostConfig,
+}
+
+/// Orchestration list layout (`[list]`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListConfig {
+    /// Sort order, cycled with `o` in the list view and saved back here
+    pub sort: ListSort,
+    /// Columns to show, left to right
+    pub columns: Vec<ListColumn>,
+    /// Split the list into collapsible sections by Convex project
+    pub group_by_project: bool,
+}
+
+impl Default for ListConfig {
+    fn default() -> Self {
+        Self {
+            sort: ListSort::default(),
+            columns: vec![
+                ListColumn::Name,
+                ListColumn::Path,
+                ListColumn::Phase,
+                ListColumn::Progress,
+                ListColumn::Status,
+            ],
+            group_by_project: true,
+        }
+    }
+}
+
+/// Orchestration list sort order
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListSort {
+    /// As listed by Convex, newest first
+    #[default]
+    Started,
+    /// Longest running first
+    Elapsed,
+    /// Blocked first, complete last
+    Status,
+    /// Least phase progress first
+    Progress,
+    /// Most recently active first
+    Activity,
+}
+
+impl ListSort {
+    /// The next sort order in the cycle
+    pub fn next(self) -> Self {
+        match self {
+            Self::Started => Self::Elapsed,
+            Self::Elapsed => Self::Status,
+            Self::Status => Self::Progress,
+            Self::Progress => Self::Activity,
+            Self::Activity => Self::Started,
+        }
+    }
+
+    /// Name shown in the list title and written to the config file
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Started => "started",
+            Self::Elapsed => "elapsed",
+            Self::Status => "status",
+            Self::Progress => "progress",
+            Self::Activity => "activity",
+        }
+    }
+}
+
+/// A column of the orchestration list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListColumn {
+    Name,
+    Path,
+    Phase,
+    Progress,
+    Status,
+    Elapsed,
+    Activity,
+}
+
+/// How to reach the local tina-daemon (`[daemon]`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DaemonConfig {
+    /// HTTP port tina-daemon listens on
+    pub http_port: u16,
+}
+
+/// Default for [`DaemonConfig::http_port`], matching tina-daemon's default
+pub const DEFAULT_DAEMON_HTTP_PORT: u16 = 7842;
+
+impl Default for DaemonConfig {
+    fn default() -> Self {
+        Self {
+            http_port: DEFAULT_DAEMON_HTTP_PORT,
+        }
+    }
+}
+
+/// Where exported pane output is written (`[capture]`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CaptureConfig {
+    /// Directory for `<agent>-<timestamp>.log` files; `~` is expanded
+    pub dir: PathBuf,
+}
+
+impl Default for CaptureConfig {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from("~/.local/share/tina-monitor/captures"),
+        }
+    }
+}
+
+impl CaptureConfig {
+    /// `dir` with a leading `~` expanded
+    pub fn resolved_dir(&self) -> PathBuf {
+        PathBuf::from(shellexpand::tilde(&self.dir.to_string_lossy()).as_ref())
+    }
+}
+
+/// Agent-time budgets drawn as bars in the cost panel (`[cost]`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CostConfig {
+    /// Budget for a whole orchestration, in agent minutes
+    pub budget_mins: Option<f64>,
+    /// Budget for each phase, in agent minutes
+    pub phase_budget_mins: Option<f64>,
+}
+
+/// How times are shown in the TUI, CLI output, and reports (`[display]`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplayConfig {
+    /// "local" (default), "utc", or a fixed offset such as "+05:30"
+    pub timezone: DisplayTimezone,
 }
 
 /// Convex backend configuration
@@ -35,8 +189,16 @@ impl Default for ConvexConfig {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct TerminalConfig {
-    /// Preferred terminal handler: "kitty", "iterm", or "print"
+    /// Preferred terminal handler: "kitty", "wezterm", "iterm", "alacritty",
+    /// "print", or "auto" to detect the terminal tina-monitor runs in
     pub handler: String,
+    /// Editor used by the open-in-editor action (e.g. "code", "nvim").
+    /// Supports `{file}` and `{line}` placeholders. Falls back to
+    /// `$VISUAL`/`$EDITOR` when empty.
+    pub editor_command: String,
+    /// Command the open-git-tool action runs in a new tab at the
+    /// orchestration's worktree (e.g. "lazygit", "gitui", "$EDITOR .")
+    pub git_tool: String,
 }
 
 /// TUI refresh configuration
@@ -47,8 +209,14 @@ pub struct TuiConfig {
     pub refresh_interval: u64,
     /// Log polling interval in milliseconds
     pub log_poll_interval: u64,
+    /// In-progress tasks with no updates for this many minutes are flagged
+    /// as stuck
+    pub stuck_task_threshold_mins: i64,
 }
 
+/// Default for [`TuiConfig::stuck_task_threshold_mins`]
+pub const DEFAULT_STUCK_TASK_THRESHOLD_MINS: i64 = 30;
+
 /// Safety and confirmation settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -59,6 +227,39 @@ pub struct SafetyConfig {
     pub safe_commands: Vec<String>,
 }
 
+/// Desktop notification settings (`[notifications]`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationsConfig {
+    /// Show OS notifications (osascript on macOS, notify-send elsewhere)
+    pub desktop: bool,
+    /// Notify when a phase completes
+    pub phase_complete: bool,
+    /// Notify when an orchestration completes
+    pub orchestration_complete: bool,
+    /// Notify when an orchestration becomes blocked
+    pub blocked: bool,
+    /// Notify when a review gate starts waiting for approval
+    pub gate_pending: bool,
+}
+
+impl NotificationsConfig {
+    /// Whether a desktop notification should be shown for `kind`
+    pub fn enabled_for(&self, kind: ChangeKind) -> bool {
+        self.desktop
+            && match kind {
+                ChangeKind::PhaseComplete => self.phase_complete,
+                ChangeKind::OrchestrationComplete => self.orchestration_complete,
+                ChangeKind::Blocked => self.blocked,
+                ChangeKind::GatePending => self.gate_pending,
+                ChangeKind::StatusChanged
+                | ChangeKind::TaskComplete
+                | ChangeKind::NewCommit
+                | ChangeKind::Connection => false,
+            }
+    }
+}
+
 /// Logging configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -72,6 +273,8 @@ impl Default for TerminalConfig {
     fn default() -> Self {
         Self {
             handler: "print".to_string(),
+            editor_command: String::new(),
+            git_tool: "lazygit".to_string(),
         }
     }
 }
@@ -81,6 +284,7 @@ impl Default for TuiConfig {
         Self {
             refresh_interval: 1000,
             log_poll_interval: 500,
+            stuck_task_threshold_mins: DEFAULT_STUCK_TASK_THRESHOLD_MINS,
         }
     }
 }
@@ -94,6 +298,18 @@ impl Default for SafetyConfig {
     }
 }
 
+impl Default for NotificationsConfig {
+    fn default() -> Self {
+        Self {
+            desktop: false,
+            phase_complete: false,
+            orchestration_complete: true,
+            blocked: true,
+            gate_pending: true,
+        }
+    }
+}
+
 impl Default for LoggingConfig {
     fn default() -> Self {
         Self {
@@ -124,6 +340,51 @@ impl Config {
         let config: Config = toml::from_str(&contents)?;
         Ok(config)
     }
+
+    /// Write every setting to the config file, replacing what is there
+    pub fn save(&self) -> anyhow::Result<()> {
+        save_at(&Self::config_path(), self)
+    }
+
+    /// Set `key` in `[section]` of the config file, keeping every other
+    /// setting. Creates the file if it doesn't exist yet.
+    pub fn persist(section: &str, key: &str, value: toml::Value) -> anyhow::Result<()> {
+        persist_at(&Self::config_path(), section, key, value)
+    }
+}
+
+fn save_at(path: &std::path::Path, config: &Config) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, toml::to_string_pretty(config)?)?;
+    Ok(())
+}
+
+fn persist_at(
+    path: &std::path::Path,
+    section: &str,
+    key: &str,
+    value: toml::Value,
+) -> anyhow::Result<()> {
+    let mut table: toml::Table = match fs::read_to_string(path) {
+        Ok(contents) => toml::from_str(&contents)?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => toml::Table::new(),
+        Err(e) => return Err(e.into()),
+    };
+    let section = table
+        .entry(section)
+        .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+    let Some(section) = section.as_table_mut() else {
+        anyhow::bail!("config entry is not a table");
+    };
+    section.insert(key.to_string(), value);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, toml::to_string(&table)?)?;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -145,8 +406,11 @@ mod tests {
         let config = Config::default();
 
         assert_eq!(config.terminal.handler, "print");
+        assert_eq!(config.terminal.editor_command, "");
+        assert_eq!(config.terminal.git_tool, "lazygit");
         assert_eq!(config.tui.refresh_interval, 1000);
         assert_eq!(config.tui.log_poll_interval, 500);
+        assert_eq!(config.tui.stuck_task_threshold_mins, 30);
         assert_eq!(config.safety.confirm_send, true);
         assert_eq!(config.safety.safe_commands.len(), 3);
         assert_eq!(
@@ -161,10 +425,13 @@ mod tests {
         let toml_content = r#"
 [terminal]
 handler = "kitty"
+editor_command = "code"
+git_tool = "gitui"
 
 [tui]
 refresh_interval = 2000
 log_poll_interval = 1000
+stuck_task_threshold_mins = 45
 
 [safety]
 confirm_send = false
@@ -179,8 +446,11 @@ command_log = "/tmp/commands.log"
         let config: Config = toml::from_str(&contents).unwrap();
 
         assert_eq!(config.terminal.handler, "kitty");
+        assert_eq!(config.terminal.editor_command, "code");
+        assert_eq!(config.terminal.git_tool, "gitui");
         assert_eq!(config.tui.refresh_interval, 2000);
         assert_eq!(config.tui.log_poll_interval, 1000);
+        assert_eq!(config.tui.stuck_task_threshold_mins, 45);
         assert_eq!(config.safety.confirm_send, false);
         assert_eq!(config.safety.safe_commands.len(), 2);
         assert_eq!(
@@ -227,4 +497,157 @@ refresh_interval = 3000
         assert_eq!(config.tui.log_poll_interval, 500); // default value
         assert_eq!(config.safety.confirm_send, true); // default value (section missing)
     }
+
+    #[test]
+    fn test_theme_section() {
+        let config: Config = toml::from_str(
+            r#"
+[theme]
+preset = "light"
+accent = "magenta"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.theme.preset, crate::theme::ThemePreset::Light);
+        assert_eq!(
+            config.theme.overrides.get("accent").map(String::as_str),
+            Some("magenta")
+        );
+        assert_eq!(Config::default().theme, ThemeConfig::default());
+    }
+
+    #[test]
+    fn test_notifications_section() {
+        assert!(!Config::default()
+            .notifications
+            .enabled_for(ChangeKind::Blocked));
+
+        let config: Config = toml::from_str(
+            r#"
+[notifications]
+desktop = true
+gate_pending = false
+"#,
+        )
+        .unwrap();
+
+        assert!(config.notifications.enabled_for(ChangeKind::Blocked));
+        assert!(config
+            .notifications
+            .enabled_for(ChangeKind::OrchestrationComplete));
+        assert!(!config.notifications.enabled_for(ChangeKind::GatePending));
+        assert!(!config.notifications.enabled_for(ChangeKind::PhaseComplete));
+    }
+
+    #[test]
+    fn test_display_timezone() {
+        assert_eq!(Config::default().display.timezone, DisplayTimezone::Local);
+
+        let config: Config = toml::from_str("[display]\ntimezone = \"UTC\"\n").unwrap();
+        assert_eq!(config.display.timezone, DisplayTimezone::Utc);
+
+        let bad = toml::from_str::<Config>("[display]\ntimezone = \"Mars/Olympus\"\n");
+        assert!(bad.is_err(), "unknown timezones should fail to load");
+    }
+
+    #[test]
+    fn test_daemon_section() {
+        assert_eq!(Config::default().daemon.http_port, DEFAULT_DAEMON_HTTP_PORT);
+
+        let config: Config = toml::from_str("[daemon]\nhttp_port = 9000\n").unwrap();
+        assert_eq!(config.daemon.http_port, 9000);
+    }
+
+    #[test]
+    fn test_capture_section() {
+        let default_dir = Config::default().capture.resolved_dir();
+        assert!(default_dir.ends_with(".local/share/tina-monitor/captures"));
+        assert!(!default_dir.starts_with("~"));
+
+        let config: Config = toml::from_str("[capture]\ndir = \"/tmp/captures\"\n").unwrap();
+        assert_eq!(
+            config.capture.resolved_dir(),
+            PathBuf::from("/tmp/captures")
+        );
+    }
+
+    #[test]
+    fn test_cost_section() {
+        assert_eq!(Config::default().cost, CostConfig::default());
+
+        let config: Config = toml::from_str("[cost]\nbudget_mins = 600\n").unwrap();
+        assert_eq!(config.cost.budget_mins, Some(600.0));
+        assert_eq!(config.cost.phase_budget_mins, None);
+    }
+
+    #[test]
+    fn test_list_section() {
+        assert_eq!(Config::default().list.sort, ListSort::Started);
+
+        let config: Config =
+            toml::from_str("[list]\nsort = \"activity\"\ncolumns = [\"name\", \"elapsed\"]\n")
+                .unwrap();
+        assert_eq!(config.list.sort, ListSort::Activity);
+        assert!(config.list.group_by_project);
+        assert_eq!(
+            config.list.columns,
+            vec![ListColumn::Name, ListColumn::Elapsed]
+        );
+    }
+
+    #[test]
+    fn test_list_sort_cycles_through_every_key() {
+        let mut sort = ListSort::Started;
+        let mut seen = vec![sort];
+        loop {
+            sort = sort.next();
+            if sort == ListSort::Started {
+                break;
+            }
+            seen.push(sort);
+        }
+        assert_eq!(seen.len(), 5);
+    }
+
+    #[test]
+    fn test_persist_keeps_other_settings() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = create_test_config_file(&temp_dir, "[tui]\nrefresh_interval = 2000\n");
+
+        persist_at(&path, "list", "sort", toml::Value::from("status")).unwrap();
+
+        let config: Config = toml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(config.list.sort, ListSort::Status);
+        assert_eq!(config.tui.refresh_interval, 2000);
+    }
+
+    #[test]
+    fn test_persist_creates_missing_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("nested").join("config.toml");
+
+        persist_at(&path, "list", "sort", toml::Value::from("elapsed")).unwrap();
+
+        let config: Config = toml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(config.list.sort, ListSort::Elapsed);
+    }
+
+    #[test]
+    fn test_save_round_trips() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("nested").join("config.toml");
+        let mut config = Config::default();
+        config.convex.url = "https://example.convex.cloud".to_string();
+        config.terminal.handler = "wezterm".to_string();
+        config.safety.confirm_send = false;
+
+        save_at(&path, &config).unwrap();
+
+        let saved: Config = toml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved.convex.url, "https://example.convex.cloud");
+        assert_eq!(saved.terminal.handler, "wezterm");
+        assert!(!saved.safety.confirm_send);
+        assert_eq!(saved.tui.refresh_interval, config.tui.refresh_interval);
+    }
 }
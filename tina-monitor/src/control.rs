@@ -0,0 +1,360 @@
+//! Orchestration control actions for the quick-actions palette
+//!
+//! Gate decisions, stopping a phase, and cleanup shell out to
+//! `tina-session`, which already knows how to resolve a feature to its
+//! orchestration. Pause, resume, and
+//! retry are queued through the Convex control plane for the
+//! orchestration's node to pick up, the same way the web UI does.
+
+use std::collections::BTreeSet;
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+use tina_data::TinaConvexClient;
+
+use crate::data::{MonitorOrchestration, MonitorOrchestrationStatus};
+
+/// Review gates an orchestration can be held at
+pub const GATES: [&str; 3] = ["plan", "review", "finalize"];
+
+/// Who control-plane actions are recorded as requested by
+const REQUESTED_BY: &str = "tina-monitor";
+
+/// An action the palette can run against the selected orchestration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ControlAction {
+    ApproveGate(String),
+    BlockGate(String),
+    Pause,
+    Resume,
+    RetryPhase,
+    /// Kill a phase's tmux session; offered from the Members pane rather
+    /// than the palette
+    StopPhase(u32),
+    Cleanup,
+}
+
+impl ControlAction {
+    /// Actions that make sense for an orchestration in its current state.
+    /// Gate decisions are offered for pending gates only.
+    pub fn available(
+        orch: &MonitorOrchestration,
+        pending_gates: &BTreeSet<String>,
+    ) -> Vec<ControlAction> {
+        let mut actions = Vec::new();
+        for gate in GATES.iter().filter(|g| pending_gates.contains(**g)) {
+            actions.push(ControlAction::ApproveGate(gate.to_string()));
+            actions.push(ControlAction::BlockGate(gate.to_string()));
+        }
+        match orch.status {
+            MonitorOrchestrationStatus::Planning
+            | MonitorOrchestrationStatus::Executing
+            | MonitorOrchestrationStatus::Reviewing => actions.push(ControlAction::Pause),
+            MonitorOrchestrationStatus::Blocked => {
+                actions.push(ControlAction::Resume);
+                actions.push(ControlAction::RetryPhase);
+            }
+            MonitorOrchestrationStatus::Complete | MonitorOrchestrationStatus::Idle => {}
+        }
+        actions.push(ControlAction::Cleanup);
+        actions
+    }
+
+    /// Palette label, e.g. "Approve review gate"
+    pub fn label(&self, orch: &MonitorOrchestration) -> String {
+        match self {
+            ControlAction::ApproveGate(gate) => format!("Approve {} gate", gate),
+            ControlAction::BlockGate(gate) => format!("Block {} gate", gate),
+            ControlAction::Pause => format!("Pause phase {}", orch.current_phase),
+            ControlAction::Resume => "Resume orchestration".to_string(),
+            ControlAction::RetryPhase => format!("Retry phase {}", orch.current_phase),
+            ControlAction::StopPhase(phase) => format!("Stop phase {}", phase),
+            ControlAction::Cleanup => "Clean up orchestration state".to_string(),
+        }
+    }
+
+    /// Whether the palette asks for a second Enter before running it
+    pub fn needs_confirmation(&self) -> bool {
+        matches!(
+            self,
+            ControlAction::BlockGate(_) | ControlAction::StopPhase(_) | ControlAction::Cleanup
+        )
+    }
+
+    /// Run the action, returning a one-line summary of what happened
+    pub fn run(&self, orch: &MonitorOrchestration, convex_url: &str) -> Result<String> {
+        if let Some(args) = self.tina_session_args(orch) {
+            run_tina_session(&args)?;
+        } else if let Some((action_type, payload)) = self.control_payload(orch) {
+            enqueue(orch, convex_url, action_type, &payload)?;
+        }
+        Ok(format!("{}: {}", orch.feature_name, self.done_message()))
+    }
+
+    fn done_message(&self) -> String {
+        match self {
+            ControlAction::ApproveGate(gate) => format!("{} gate approved", gate),
+            ControlAction::BlockGate(gate) => format!("{} gate blocked", gate),
+            ControlAction::Pause => "pause requested".to_string(),
+            ControlAction::Resume => "resume requested".to_string(),
+            ControlAction::RetryPhase => "retry requested".to_string(),
+            ControlAction::StopPhase(phase) => format!("phase {} stopped", phase),
+            ControlAction::Cleanup => "state cleaned up".to_string(),
+        }
+    }
+
+    /// `tina-session` arguments for actions run locally
+    fn tina_session_args(&self, orch: &MonitorOrchestration) -> Option<Vec<String>> {
+        let feature = orch.feature_name.clone();
+        let phase;
+        let args: Vec<&str> = match self {
+            ControlAction::ApproveGate(gate) => vec![
+                "review",
+                "gate",
+                "approve",
+                "--feature",
+                &feature,
+                "--gate",
+                gate,
+            ],
+            ControlAction::BlockGate(gate) => vec![
+                "review",
+                "gate",
+                "block",
+                "--feature",
+                &feature,
+                "--gate",
+                gate,
+                "--reason",
+                "Blocked from tina-monitor",
+                "--decided-by",
+                "human",
+            ],
+            ControlAction::StopPhase(n) => {
+                phase = n.to_string();
+                vec!["stop", "--feature", &feature, "--phase", &phase]
+            }
+            ControlAction::Cleanup => vec!["cleanup", "--feature", &feature],
+            _ => return None,
+        };
+        Some(args.into_iter().map(String::from).collect())
+    }
+
+    /// Control-plane action type and JSON payload for queued actions
+    fn control_payload(&self, orch: &MonitorOrchestration) -> Option<(&'static str, String)> {
+        let action_type = match self {
+            ControlAction::Pause => "pause",
+            ControlAction::Resume => "resume",
+            ControlAction::RetryPhase => "retry",
+            _ => return None,
+        };
+        let mut payload = serde_json::json!({ "feature": orch.feature_name });
+        if *self != ControlAction::Resume {
+            payload["phase"] = orch.current_phase.to_string().into();
+        }
+        Some((action_type, payload.to_string()))
+    }
+}
+
+/// Queue a `task_edit` for the orchestration's node. Convex applies it
+/// only if the task is still pending at the revision in `payload`; the node
+/// then acknowledges it with `tina-session orchestrate task-edit`.
+pub fn edit_task(orch: &MonitorOrchestration, convex_url: &str, payload: &str) -> Result<()> {
+    enqueue(orch, convex_url, "task_edit", payload)
+}
+
+/// Queue a `retry` of `phase` for the orchestration's node, the same action
+/// the web's Retry button sends. The node runs `tina-session orchestrate
+/// advance <feature> <phase> retry`, recording `reason` when one is given.
+pub fn retry_phase(
+    orch: &MonitorOrchestration,
+    convex_url: &str,
+    phase: u32,
+    reason: &str,
+) -> Result<String> {
+    enqueue(
+        orch,
+        convex_url,
+        "retry",
+        &retry_payload(orch, phase, reason),
+    )?;
+    Ok(format!(
+        "{}: phase {} retry requested",
+        orch.feature_name, phase
+    ))
+}
+
+fn retry_payload(orch: &MonitorOrchestration, phase: u32, reason: &str) -> String {
+    let mut payload = serde_json::json!({
+        "feature": orch.feature_name,
+        "phase": phase.to_string(),
+    });
+    let reason = reason.trim();
+    if !reason.is_empty() {
+        payload["reason"] = reason.into();
+    }
+    payload.to_string()
+}
+
+/// Queue a `task_set_model` for the orchestration's node, applied only if
+/// the task is still pending at the revision in `payload`
+pub fn set_task_model(orch: &MonitorOrchestration, convex_url: &str, payload: &str) -> Result<()> {
+    enqueue(orch, convex_url, "task_set_model", payload)
+}
+
+fn run_tina_session(args: &[String]) -> Result<()> {
+    let output = Command::new("tina-session")
+        .args(args)
+        .output()
+        .context("Failed to run tina-session")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("{}", stderr.trim().trim_start_matches("Error: "));
+    }
+    Ok(())
+}
+
+fn enqueue(
+    orch: &MonitorOrchestration,
+    convex_url: &str,
+    action_type: &str,
+    payload: &str,
+) -> Result<()> {
+    if convex_url.is_empty() {
+        bail!("Convex URL is not configured");
+    }
+    let idempotency_key = format!(
+        "{}-{}-{}",
+        REQUESTED_BY,
+        std::process::id(),
+        chrono::Utc::now().timestamp_millis()
+    );
+    let rt = tokio::runtime::Runtime::new()?;
+    rt.block_on(async {
+        let mut client = TinaConvexClient::new(convex_url).await?;
+        client
+            .enqueue_control_action(
+                &orch.id,
+                &orch.node_id,
+                action_type,
+                payload,
+                REQUESTED_BY,
+                &idempotency_key,
+            )
+            .await
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tina_data::test_util::list_entry;
+
+    fn orch(status: MonitorOrchestrationStatus) -> MonitorOrchestration {
+        let mut entry = list_entry("orch-1", "executing", "2026-02-07T09:00:00Z");
+        entry.record.current_phase = 2.0;
+        let mut orch = MonitorOrchestration::from_list_entry(entry);
+        orch.status = status;
+        orch
+    }
+
+    #[test]
+    fn available_actions_follow_status_and_pending_gates() {
+        let none = BTreeSet::new();
+        assert_eq!(
+            ControlAction::available(&orch(MonitorOrchestrationStatus::Executing), &none),
+            vec![ControlAction::Pause, ControlAction::Cleanup]
+        );
+        assert_eq!(
+            ControlAction::available(&orch(MonitorOrchestrationStatus::Blocked), &none),
+            vec![
+                ControlAction::Resume,
+                ControlAction::RetryPhase,
+                ControlAction::Cleanup
+            ]
+        );
+
+        let pending = BTreeSet::from(["review".to_string()]);
+        let actions =
+            ControlAction::available(&orch(MonitorOrchestrationStatus::Complete), &pending);
+        assert_eq!(
+            actions,
+            vec![
+                ControlAction::ApproveGate("review".to_string()),
+                ControlAction::BlockGate("review".to_string()),
+                ControlAction::Cleanup
+            ]
+        );
+    }
+
+    #[test]
+    fn gate_and_cleanup_actions_shell_out_to_tina_session() {
+        let orch = orch(MonitorOrchestrationStatus::Reviewing);
+        let approve = ControlAction::ApproveGate("plan".to_string());
+        assert_eq!(
+            approve.tina_session_args(&orch).unwrap(),
+            [
+                "review",
+                "gate",
+                "approve",
+                "--feature",
+                "auth",
+                "--gate",
+                "plan"
+            ]
+        );
+        assert_eq!(
+            ControlAction::Cleanup.tina_session_args(&orch).unwrap(),
+            ["cleanup", "--feature", "auth"]
+        );
+        assert_eq!(
+            ControlAction::StopPhase(2)
+                .tina_session_args(&orch)
+                .unwrap(),
+            ["stop", "--feature", "auth", "--phase", "2"]
+        );
+        assert!(approve.control_payload(&orch).is_none());
+        assert!(ControlAction::Pause.tina_session_args(&orch).is_none());
+    }
+
+    #[test]
+    fn runtime_actions_queue_control_plane_payloads() {
+        let orch = orch(MonitorOrchestrationStatus::Blocked);
+        let (action_type, payload) = ControlAction::RetryPhase.control_payload(&orch).unwrap();
+        assert_eq!(action_type, "retry");
+        let payload: serde_json::Value = serde_json::from_str(&payload).unwrap();
+        assert_eq!(
+            payload,
+            serde_json::json!({"feature": "auth", "phase": "2"})
+        );
+
+        let (action_type, payload) = ControlAction::Resume.control_payload(&orch).unwrap();
+        assert_eq!(action_type, "resume");
+        assert_eq!(payload, r#"{"feature":"auth"}"#);
+    }
+
+    #[test]
+    fn retry_payload_names_the_phase_and_optional_reason() {
+        let orch = orch(MonitorOrchestrationStatus::Blocked);
+        let payload: serde_json::Value =
+            serde_json::from_str(&retry_payload(&orch, 1, "  flaky test runner ")).unwrap();
+        assert_eq!(
+            payload,
+            serde_json::json!({"feature": "auth", "phase": "1", "reason": "flaky test runner"})
+        );
+        assert_eq!(
+            retry_payload(&orch, 3, " "),
+            r#"{"feature":"auth","phase":"3"}"#
+        );
+    }
+
+    #[test]
+    fn destructive_actions_need_confirmation() {
+        assert!(ControlAction::Cleanup.needs_confirmation());
+        assert!(ControlAction::StopPhase(1).needs_confirmation());
+        assert!(ControlAction::BlockGate("plan".to_string()).needs_confirmation());
+        assert!(!ControlAction::ApproveGate("plan".to_string()).needs_confirmation());
+        assert!(!ControlAction::Pause.needs_confirmation());
+    }
+}
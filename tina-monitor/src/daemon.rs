@@ -0,0 +1,49 @@
+//! Requests to the local tina-daemon
+//!
+//! Shells out to `curl` in the background, the same way the git hooks from
+//! `tina-session hooks install` do. Delivery is best effort: the daemon's
+//! file watcher picks the change up anyway if it isn't running or the
+//! request is lost.
+
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Ask the daemon to re-sync a plan file to Convex now
+pub fn request_plan_sync(port: u16, plan_path: &Path) {
+    let mut command = plan_sync_command(port, plan_path);
+    command.stdout(Stdio::null()).stderr(Stdio::null());
+    // Reap the child off the UI thread
+    std::thread::spawn(move || {
+        let _ = command.status();
+    });
+}
+
+/// The `curl` invocation posting `plan_path` to `/hooks/plan`
+fn plan_sync_command(port: u16, plan_path: &Path) -> Command {
+    let mut command = Command::new("curl");
+    command
+        .args(["-fsS", "-m", "2", "-X", "POST", "--data-urlencode"])
+        .arg(format!("plan={}", plan_path.display()))
+        .arg(format!("http://127.0.0.1:{}/hooks/plan", port));
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plan_sync_command_posts_plan_path_to_daemon() {
+        let command = plan_sync_command(9000, Path::new("/repo/docs/plans/auth-phase-1.md"));
+        let args: Vec<String> = command
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(command.get_program(), "curl");
+        assert!(args.contains(&"plan=/repo/docs/plans/auth-phase-1.md".to_string()));
+        assert_eq!(
+            args.last().map(String::as_str),
+            Some("http://127.0.0.1:9000/hooks/plan")
+        );
+    }
+}
@@ -3,6 +3,7 @@
 //! Provides an htop-style status bar displaying feature name, status,
 //! current phase progress, and elapsed time.
 
+use crate::theme;
 use crate::types::{OrchestrationStatus, SupervisorState};
 use chrono::Utc;
 use ratatui::{
@@ -50,27 +51,18 @@ impl Dashboard {
 
     /// Format duration in minutes as a human-readable string
     pub fn format_duration(mins: i64) -> String {
-        if mins < 60 {
-            format!("{}m", mins)
-        } else {
-            let hours = mins / 60;
-            let remaining_mins = mins % 60;
-            if remaining_mins == 0 {
-                format!("{}h", hours)
-            } else {
-                format!("{}h{}m", hours, remaining_mins)
-            }
-        }
+        tina_data::humanize::format_duration_mins(mins)
     }
 
     /// Get color for status indicator
     fn status_color(&self) -> Color {
+        let theme = theme::current();
         match self.status {
-            OrchestrationStatus::Executing => Color::Green,
-            OrchestrationStatus::Planning => Color::Yellow,
-            OrchestrationStatus::Reviewing => Color::Cyan,
-            OrchestrationStatus::Complete => Color::Blue,
-            OrchestrationStatus::Blocked => Color::Red,
+            OrchestrationStatus::Executing => theme.status_executing,
+            OrchestrationStatus::Planning => theme.status_planning,
+            OrchestrationStatus::Reviewing => theme.status_reviewing,
+            OrchestrationStatus::Complete => theme.status_complete,
+            OrchestrationStatus::Blocked => theme.status_blocked,
         }
     }
 
@@ -106,6 +98,7 @@ impl Dashboard {
         // Left side: feature, status, phase, elapsed
         let phase_text = format!("Phase {}/{}", self.current_phase, self.total_phases);
         let duration_text = Self::format_duration(self.elapsed_mins);
+        let theme = theme::current();
         let status_color = self.status_color();
         let status_text = self.status_text();
 
@@ -113,15 +106,15 @@ impl Dashboard {
             // No feature loaded - show welcome message
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled("tina-monitor", Style::default().fg(Color::Cyan)),
+                Span::styled("tina-monitor", Style::default().fg(theme.accent)),
                 Span::raw("  Press "),
-                Span::styled("/", Style::default().fg(Color::Yellow)),
+                Span::styled("/", Style::default().fg(theme.highlight)),
                 Span::raw(" to find an orchestration"),
             ])
         } else {
             Line::from(vec![
                 Span::raw("  "),
-                Span::styled(self.feature.clone(), Style::default().fg(Color::White)),
+                Span::styled(self.feature.clone(), Style::default().fg(theme.bar_fg)),
                 Span::raw(" | "),
                 Span::styled(status_text, Style::default().fg(status_color)),
                 Span::raw(" | "),
@@ -133,7 +126,7 @@ impl Dashboard {
 
         let left_paragraph = Paragraph::new(left_content)
             .block(Block::default().borders(Borders::BOTTOM))
-            .style(Style::default().bg(Color::Black).fg(Color::White));
+            .style(Style::default().bg(theme.bar_bg).fg(theme.bar_fg));
 
         frame.render_widget(left_paragraph, left_area);
 
@@ -141,22 +134,22 @@ impl Dashboard {
         let right_content = if let Some(msg) = status_message {
             // Show status message (temporary feedback)
             Line::from(vec![
-                Span::styled(msg, Style::default().fg(Color::Green)),
+                Span::styled(msg, Style::default().fg(theme.success)),
                 Span::raw("  "),
             ])
         } else {
             // Show keybinding hints
             Line::from(vec![
-                Span::styled("[/]", Style::default().fg(Color::DarkGray)),
+                Span::styled("[/]", Style::default().fg(theme.muted)),
                 Span::raw(" Find  "),
-                Span::styled("[?]", Style::default().fg(Color::DarkGray)),
+                Span::styled("[?]", Style::default().fg(theme.muted)),
                 Span::raw(" Help  "),
             ])
         };
 
         let right_paragraph = Paragraph::new(right_content)
             .block(Block::default().borders(Borders::BOTTOM))
-            .style(Style::default().bg(Color::Black).fg(Color::White))
+            .style(Style::default().bg(theme.bar_bg).fg(theme.bar_fg))
             .alignment(ratatui::layout::Alignment::Right);
 
         frame.render_widget(right_paragraph, right_area);
@@ -200,7 +193,7 @@ mod tests {
 
     #[test]
     fn format_duration_one_hour_with_minutes() {
-        assert_eq!(Dashboard::format_duration(90), "1h30m");
+        assert_eq!(Dashboard::format_duration(90), "1h 30m");
     }
 
     #[test]
@@ -210,7 +203,7 @@ mod tests {
 
     #[test]
     fn format_duration_multiple_hours_with_minutes() {
-        assert_eq!(Dashboard::format_duration(150), "2h30m");
+        assert_eq!(Dashboard::format_duration(150), "2h 30m");
     }
 
     // ====================================================================
@@ -339,6 +332,7 @@ mod tests {
             timing: Default::default(),
             model_policy: Default::default(),
             review_policy: Default::default(),
+            scope: None,
         };
 
         dashboard.update(&state);
@@ -365,6 +359,7 @@ mod tests {
             timing: Default::default(),
             model_policy: Default::default(),
             review_policy: Default::default(),
+            scope: None,
         };
 
         dashboard.update(&state);
@@ -391,6 +386,7 @@ mod tests {
             timing: Default::default(),
             model_policy: Default::default(),
             review_policy: Default::default(),
+            scope: None,
         };
 
         dashboard.update(&state);
@@ -419,6 +415,7 @@ mod tests {
             timing: Default::default(),
             model_policy: Default::default(),
             review_policy: Default::default(),
+            scope: None,
         };
 
         dashboard.update(&state);
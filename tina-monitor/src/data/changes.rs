@@ -0,0 +1,424 @@
+//! Detect notable orchestration changes between two refreshes
+//!
+//! Each refresh is reduced to a small per-orchestration snapshot; diffing it
+//! against the previous one yields status transitions, phase completions,
+//! blocks, newly pending review gates, task completions, and new commits.
+//! The TUI turns the alert-worthy ones into toasts, and `tina-monitor watch`
+//! prints every change (as text or JSON lines) and can forward alerts as
+//! desktop notifications.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use serde::Serialize;
+
+use crate::data::{MonitorOrchestration, MonitorOrchestrationStatus};
+use crate::types::TaskStatus;
+
+/// What kind of change was detected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    PhaseComplete,
+    OrchestrationComplete,
+    Blocked,
+    GatePending,
+    StatusChanged,
+    TaskComplete,
+    NewCommit,
+    /// The monitor's own connection to Convex dropped; raised by the TUI,
+    /// never by the tracker
+    Connection,
+}
+
+impl ChangeKind {
+    /// Whether the change is worth interrupting someone for: these raise
+    /// TUI toasts and desktop notifications, the rest are only reported by
+    /// `watch`.
+    pub fn is_alert(self) -> bool {
+        matches!(
+            self,
+            Self::PhaseComplete | Self::OrchestrationComplete | Self::Blocked | Self::GatePending
+        )
+    }
+}
+
+/// One detected change
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub orchestration_id: String,
+    pub feature: String,
+    /// One-line description, e.g. "auth: phase 1 complete"
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<u32>,
+    /// Status before a status transition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_status: Option<MonitorOrchestrationStatus>,
+    /// Status after a status transition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_status: Option<MonitorOrchestrationStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha: Option<String>,
+}
+
+impl Change {
+    fn new(kind: ChangeKind, id: &str, feature: &str, message: String) -> Self {
+        Self {
+            kind,
+            orchestration_id: id.to_string(),
+            feature: feature.to_string(),
+            message,
+            phase: None,
+            from_status: None,
+            to_status: None,
+            gate: None,
+            task_id: None,
+            sha: None,
+        }
+    }
+}
+
+/// The parts of an orchestration that change detection looks at
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrchestrationSnapshot {
+    pub feature: String,
+    pub status: MonitorOrchestrationStatus,
+    pub current_phase: u32,
+    pub pending_gates: BTreeSet<String>,
+    /// Completed task ids and subjects (`None` when tasks weren't loaded)
+    pub completed_tasks: Option<BTreeMap<String, String>>,
+    /// Commit SHAs and subjects (`None` when commits weren't loaded)
+    pub commits: Option<BTreeMap<String, String>>,
+}
+
+/// Snapshots keyed by orchestration id
+pub type Snapshots = BTreeMap<String, OrchestrationSnapshot>;
+
+/// Reduce orchestrations (plus their pending gate ids) to snapshots
+pub fn snapshot(
+    orchestrations: &[MonitorOrchestration],
+    pending_gates: &HashMap<String, BTreeSet<String>>,
+) -> Snapshots {
+    orchestrations
+        .iter()
+        .map(|orch| {
+            // List entries carry no tasks; only detail loads fill them in
+            let completed_tasks = (!orch.tasks.is_empty()).then(|| {
+                orch.tasks
+                    .iter()
+                    .filter(|t| t.status == TaskStatus::Completed)
+                    .map(|t| (t.id.clone(), t.subject.clone()))
+                    .collect()
+            });
+            let snapshot = OrchestrationSnapshot {
+                feature: orch.feature_name.clone(),
+                status: orch.status.clone(),
+                current_phase: orch.current_phase,
+                pending_gates: pending_gates.get(&orch.id).cloned().unwrap_or_default(),
+                completed_tasks,
+                commits: None,
+            };
+            (orch.id.clone(), snapshot)
+        })
+        .collect()
+}
+
+/// Changes between two refreshes.
+///
+/// Orchestrations that were not in `before` are skipped, so newly discovered
+/// orchestrations don't announce their whole history. Likewise tasks and
+/// commits are only compared when both snapshots loaded them, so loading
+/// details for the first time isn't mistaken for new activity.
+pub fn detect_changes(before: &Snapshots, after: &Snapshots) -> Vec<Change> {
+    let mut changes = Vec::new();
+    for (id, now) in after {
+        let Some(then) = before.get(id) else {
+            continue;
+        };
+        let feature = &now.feature;
+
+        if now.status != then.status {
+            changes.push(Change {
+                phase: Some(now.current_phase),
+                from_status: Some(then.status.clone()),
+                to_status: Some(now.status.clone()),
+                ..Change::new(
+                    ChangeKind::StatusChanged,
+                    id,
+                    feature,
+                    format!("{}: {} -> {}", feature, then.status, now.status),
+                )
+            });
+        }
+
+        if now.status == MonitorOrchestrationStatus::Complete
+            && then.status != MonitorOrchestrationStatus::Complete
+        {
+            changes.push(Change::new(
+                ChangeKind::OrchestrationComplete,
+                id,
+                feature,
+                format!("{}: orchestration complete", feature),
+            ));
+        } else {
+            for phase in then.current_phase..now.current_phase {
+                changes.push(Change {
+                    phase: Some(phase),
+                    ..Change::new(
+                        ChangeKind::PhaseComplete,
+                        id,
+                        feature,
+                        format!("{}: phase {} complete", feature, phase),
+                    )
+                });
+            }
+        }
+
+        if now.status == MonitorOrchestrationStatus::Blocked
+            && then.status != MonitorOrchestrationStatus::Blocked
+        {
+            changes.push(Change {
+                phase: Some(now.current_phase),
+                ..Change::new(
+                    ChangeKind::Blocked,
+                    id,
+                    feature,
+                    format!("{}: blocked in phase {}", feature, now.current_phase),
+                )
+            });
+        }
+
+        for gate in now.pending_gates.difference(&then.pending_gates) {
+            changes.push(Change {
+                gate: Some(gate.clone()),
+                ..Change::new(
+                    ChangeKind::GatePending,
+                    id,
+                    feature,
+                    format!("{}: {} gate awaiting approval", feature, gate),
+                )
+            });
+        }
+
+        if let (Some(then_tasks), Some(now_tasks)) = (&then.completed_tasks, &now.completed_tasks) {
+            for (task_id, subject) in now_tasks {
+                if !then_tasks.contains_key(task_id) {
+                    changes.push(Change {
+                        task_id: Some(task_id.clone()),
+                        ..Change::new(
+                            ChangeKind::TaskComplete,
+                            id,
+                            feature,
+                            format!("{}: task {} complete: {}", feature, task_id, subject),
+                        )
+                    });
+                }
+            }
+        }
+
+        if let (Some(then_commits), Some(now_commits)) = (&then.commits, &now.commits) {
+            for (sha, subject) in now_commits {
+                if !then_commits.contains_key(sha) {
+                    let short: String = sha.chars().take(7).collect();
+                    changes.push(Change {
+                        sha: Some(sha.clone()),
+                        ..Change::new(
+                            ChangeKind::NewCommit,
+                            id,
+                            feature,
+                            format!("{}: commit {} {}", feature, short, subject),
+                        )
+                    });
+                }
+            }
+        }
+    }
+    changes
+}
+
+/// Tracks the previous snapshot so each new one can be diffed against it
+#[derive(Debug, Default)]
+pub struct ChangeTracker {
+    /// Snapshot from the previous refresh (`None` until the first one)
+    baseline: Option<Snapshots>,
+}
+
+impl ChangeTracker {
+    /// Record a fresh snapshot and return what changed since the previous
+    /// one. The first snapshot only sets the baseline.
+    pub fn observe(&mut self, snapshots: Snapshots) -> Vec<Change> {
+        let changes = self
+            .baseline
+            .as_ref()
+            .map(|before| detect_changes(before, &snapshots))
+            .unwrap_or_default();
+        self.baseline = Some(snapshots);
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snap(
+        status: MonitorOrchestrationStatus,
+        current_phase: u32,
+        gates: &[&str],
+    ) -> OrchestrationSnapshot {
+        OrchestrationSnapshot {
+            feature: "auth".to_string(),
+            status,
+            current_phase,
+            pending_gates: gates.iter().map(|g| g.to_string()).collect(),
+            completed_tasks: None,
+            commits: None,
+        }
+    }
+
+    fn snapshots(snapshot: OrchestrationSnapshot) -> Snapshots {
+        BTreeMap::from([("orch-1".to_string(), snapshot)])
+    }
+
+    fn summarize(changes: &[Change]) -> Vec<(ChangeKind, &str)> {
+        changes
+            .iter()
+            .map(|c| (c.kind, c.message.as_str()))
+            .collect()
+    }
+
+    fn entries(pairs: &[(&str, &str)]) -> Option<BTreeMap<String, String>> {
+        Some(
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn detect_changes_reports_phase_block_and_gate_changes() {
+        let before = snapshots(snap(MonitorOrchestrationStatus::Executing, 1, &[]));
+        let after = snapshots(snap(MonitorOrchestrationStatus::Blocked, 2, &["review"]));
+
+        let changes = detect_changes(&before, &after);
+        assert_eq!(
+            summarize(&changes),
+            vec![
+                (ChangeKind::StatusChanged, "auth: executing -> blocked"),
+                (ChangeKind::PhaseComplete, "auth: phase 1 complete"),
+                (ChangeKind::Blocked, "auth: blocked in phase 2"),
+                (
+                    ChangeKind::GatePending,
+                    "auth: review gate awaiting approval"
+                ),
+            ]
+        );
+        assert_eq!(
+            changes[0].from_status,
+            Some(MonitorOrchestrationStatus::Executing)
+        );
+        assert_eq!(changes[1].phase, Some(1));
+        assert_eq!(changes[3].gate.as_deref(), Some("review"));
+    }
+
+    #[test]
+    fn detect_changes_ignores_unchanged_and_new_orchestrations() {
+        let before = snapshots(snap(MonitorOrchestrationStatus::Blocked, 2, &["plan"]));
+        assert!(detect_changes(&before, &before.clone()).is_empty());
+
+        let mut after = before.clone();
+        after.insert(
+            "orch-2".to_string(),
+            snap(MonitorOrchestrationStatus::Blocked, 3, &["review"]),
+        );
+        assert!(detect_changes(&before, &after).is_empty());
+    }
+
+    #[test]
+    fn completion_replaces_phase_changes() {
+        let before = snapshots(snap(MonitorOrchestrationStatus::Reviewing, 3, &[]));
+        let after = snapshots(snap(MonitorOrchestrationStatus::Complete, 4, &[]));
+
+        let changes = detect_changes(&before, &after);
+        let alerts: Vec<_> = changes.iter().filter(|c| c.kind.is_alert()).collect();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, ChangeKind::OrchestrationComplete);
+    }
+
+    #[test]
+    fn detect_changes_reports_completed_tasks_and_new_commits() {
+        let mut before = snap(MonitorOrchestrationStatus::Executing, 1, &[]);
+        before.completed_tasks = entries(&[("1", "Add schema")]);
+        before.commits = entries(&[("aaaaaaa111", "Add schema")]);
+        let mut after = before.clone();
+        after.completed_tasks = entries(&[("1", "Add schema"), ("2", "Add login")]);
+        after.commits = entries(&[("aaaaaaa111", "Add schema"), ("bbbbbbb222", "Add login")]);
+
+        let changes = detect_changes(&snapshots(before), &snapshots(after));
+        assert_eq!(
+            summarize(&changes),
+            vec![
+                (ChangeKind::TaskComplete, "auth: task 2 complete: Add login"),
+                (ChangeKind::NewCommit, "auth: commit bbbbbbb Add login"),
+            ]
+        );
+        assert_eq!(changes[0].task_id.as_deref(), Some("2"));
+        assert_eq!(changes[1].sha.as_deref(), Some("bbbbbbb222"));
+        assert!(!changes[0].kind.is_alert());
+    }
+
+    #[test]
+    fn first_load_of_tasks_and_commits_is_not_a_change() {
+        let before = snap(MonitorOrchestrationStatus::Executing, 1, &[]);
+        let mut after = before.clone();
+        after.completed_tasks = entries(&[("1", "Add schema")]);
+        after.commits = entries(&[("aaaaaaa111", "Add schema")]);
+
+        assert!(detect_changes(&snapshots(before), &snapshots(after)).is_empty());
+    }
+
+    #[test]
+    fn change_serializes_as_flat_json() {
+        let before = snapshots(snap(MonitorOrchestrationStatus::Executing, 1, &[]));
+        let after = snapshots(snap(MonitorOrchestrationStatus::Executing, 1, &["plan"]));
+
+        let changes = detect_changes(&before, &after);
+        let json = serde_json::to_value(&changes[0]).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "kind": "gate_pending",
+                "orchestration_id": "orch-1",
+                "feature": "auth",
+                "message": "auth: plan gate awaiting approval",
+                "gate": "plan",
+            })
+        );
+    }
+
+    #[test]
+    fn first_observation_only_sets_baseline() {
+        let mut tracker = ChangeTracker::default();
+        let first = tracker.observe(snapshots(snap(MonitorOrchestrationStatus::Blocked, 1, &[])));
+        assert!(first.is_empty());
+
+        let changes = tracker.observe(snapshots(snap(
+            MonitorOrchestrationStatus::Executing,
+            2,
+            &[],
+        )));
+        assert_eq!(
+            summarize(&changes),
+            vec![
+                (ChangeKind::StatusChanged, "auth: blocked -> executing"),
+                (ChangeKind::PhaseComplete, "auth: phase 1 complete"),
+            ]
+        );
+    }
+}
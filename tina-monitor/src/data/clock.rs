@@ -0,0 +1,132 @@
+//! Clock anchoring for elapsed times.
+//!
+//! Orchestration and task timestamps are written on whichever node runs the
+//! orchestration, then compared against this machine's clock. Every node
+//! reports its skew against Convex with its heartbeat; measuring this
+//! machine's skew the same way translates node timestamps onto the local
+//! clock, so drift between machines doesn't show up as negative or inflated
+//! elapsed times.
+
+use std::collections::HashMap;
+
+use tina_data::clock::exceeds_warn_threshold;
+use tina_data::NodeRecord;
+
+/// Clock skews against Convex server time, for this machine and each node.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClockAnchor {
+    /// Local clock minus Convex clock; `None` when it couldn't be measured
+    pub local_skew_ms: Option<i64>,
+    /// Online nodes' clock minus Convex clock, by node id
+    nodes: HashMap<String, NodeSkew>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct NodeSkew {
+    name: String,
+    skew_ms: i64,
+}
+
+impl ClockAnchor {
+    /// Build from a local skew measurement and the skews nodes reported.
+    /// Offline nodes are ignored, as their last report may be stale.
+    pub fn new(local_skew_ms: Option<i64>, nodes: &[NodeRecord]) -> Self {
+        let nodes = nodes
+            .iter()
+            .filter(|node| node.status == "online")
+            .filter_map(|node| {
+                let skew = NodeSkew {
+                    name: node.name.clone(),
+                    skew_ms: node.clock_skew_ms? as i64,
+                };
+                Some((node.id.clone(), skew))
+            })
+            .collect();
+        Self {
+            local_skew_ms,
+            nodes,
+        }
+    }
+
+    /// How far `node_id`'s clock runs ahead of this machine's. Nodes that
+    /// haven't reported a skew are assumed to agree with Convex.
+    pub fn offset_for(&self, node_id: &str) -> i64 {
+        let node_skew = self.nodes.get(node_id).map_or(0, |node| node.skew_ms);
+        node_skew - self.local_skew_ms.unwrap_or(0)
+    }
+
+    /// One line per clock drifting beyond `tina_data::clock::CLOCK_SKEW_WARN_MS`.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if let Some(skew) = self.local_skew_ms.filter(|&s| exceeds_warn_threshold(s)) {
+            warnings.push(format!("local clock {}", describe_skew(skew)));
+        }
+        let mut nodes: Vec<&NodeSkew> = self
+            .nodes
+            .values()
+            .filter(|node| exceeds_warn_threshold(node.skew_ms))
+            .collect();
+        nodes.sort_by(|a, b| a.name.cmp(&b.name));
+        for node in nodes {
+            warnings.push(format!(
+                "{} clock {}",
+                node.name,
+                describe_skew(node.skew_ms)
+            ));
+        }
+        warnings
+    }
+}
+
+fn describe_skew(skew_ms: i64) -> String {
+    let direction = if skew_ms > 0 { "ahead" } else { "behind" };
+    format!("{}s {}", skew_ms.abs() / 1000, direction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str, status: &str, skew: Option<f64>) -> NodeRecord {
+        NodeRecord {
+            id: id.to_string(),
+            name: format!("{}-host", id),
+            os: "linux".to_string(),
+            status: status.to_string(),
+            last_heartbeat: 0.0,
+            registered_at: 0.0,
+            clock_skew_ms: skew,
+        }
+    }
+
+    #[test]
+    fn test_offset_combines_node_and_local_skew() {
+        let anchor = ClockAnchor::new(
+            Some(-2_000),
+            &[
+                node("fast", "online", Some(60_000.0)),
+                node("stale", "offline", Some(60_000.0)),
+            ],
+        );
+        assert_eq!(anchor.offset_for("fast"), 62_000);
+        assert_eq!(anchor.offset_for("stale"), 2_000);
+        assert_eq!(anchor.offset_for("unknown"), 2_000);
+        assert_eq!(ClockAnchor::default().offset_for("fast"), 0);
+    }
+
+    #[test]
+    fn test_warnings_only_for_large_skew() {
+        let anchor = ClockAnchor::new(
+            Some(7_000),
+            &[
+                node("a", "online", Some(-90_000.0)),
+                node("b", "online", Some(1_000.0)),
+            ],
+        );
+        assert_eq!(
+            anchor.warnings(),
+            vec!["local clock 7s ahead", "a-host clock 90s behind"]
+        );
+        assert!(ClockAnchor::new(Some(100), &[]).warnings().is_empty());
+    }
+}
@@ -3,34 +3,130 @@
 //! Replaces the file-based discovery, tasks, and teams modules that were
 //! previously provided by tina-data.
 
+use std::collections::{BTreeSet, HashMap};
 use std::path::PathBuf;
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use futures::stream::{BoxStream, StreamExt};
 use serde::Serialize;
 
+use tina_data::clock::estimate_skew_ms;
 use tina_data::{
-    OrchestrationListEntry, OrchestrationDetailResponse, PhaseRecord, TaskEventRecord,
+    extract_orchestration_detail, extract_orchestration_page, CommitRecord, ExecutionTaskRecord, OrchestrationEventRecord, NodeRecord, OrchestrationListEntry, OrchestrationDetailResponse, OrchestrationFilter,
+    PhaseRecord, ProjectRecord,
+    ReviewGateRecord, ReviewRecord, ReviewThreadRecord, SpecRecord, SpecRevisionRecord, TaskEventRecord,
     TeamMemberRecord, TinaConvexClient,
 };
 
+use super::clock::ClockAnchor;
+use super::review::ReviewStatus;
 use crate::types::{Agent, Task, TaskStatus};
 
+/// Events fetched per request when loading a replay
+const REPLAY_PAGE_SIZE: i64 = 500;
+/// Upper bound on events loaded per stream for one replay
+const REPLAY_EVENT_LIMIT: usize = 10_000;
+
+/// Shift orchestrations' node timestamps onto the local clock.
+fn anchor_to(clock: &ClockAnchor, orchestrations: &mut [MonitorOrchestration]) {
+    for orch in orchestrations {
+        orch.clock_offset_ms = clock.offset_for(&orch.node_id);
+    }
+}
+
+/// One page of the orchestration list
+#[derive(Debug, Clone)]
+pub struct OrchestrationPage {
+    pub orchestrations: Vec<MonitorOrchestration>,
+    /// Cursor for the next page; `None` on the last page
+    pub next_cursor: Option<String>,
+    /// Total orchestrations, only reported with the first page
+    pub total_count: Option<usize>,
+}
+
 /// Data source backed by Convex queries.
 pub struct ConvexDataSource {
     client: TinaConvexClient,
+    /// Measured on first use, then reused for the source's lifetime
+    clock: Option<ClockAnchor>,
 }
 
 impl ConvexDataSource {
     /// Create a new data source connected to a Convex deployment.
     pub async fn new(deployment_url: &str) -> Result<Self> {
         let client = TinaConvexClient::new(deployment_url).await?;
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            clock: None,
+        })
+    }
+
+    /// Clock skews of this machine and every node against Convex. A failed
+    /// measurement leaves timestamps uncorrected rather than erroring.
+    pub async fn clock_anchor(&mut self) -> ClockAnchor {
+        if let Some(clock) = &self.clock {
+            return clock.clone();
+        }
+        let sent_ms = Utc::now().timestamp_millis();
+        let server_ms = self.client.server_time().await.ok().flatten();
+        let received_ms = Utc::now().timestamp_millis();
+        let local_skew_ms =
+            server_ms.map(|server| estimate_skew_ms(sent_ms, received_ms, server as i64));
+        let nodes = self.client.list_nodes().await.unwrap_or_default();
+        let clock = ClockAnchor::new(local_skew_ms, &nodes);
+        self.clock = Some(clock.clone());
+        clock
+    }
+
+    /// Shift orchestrations' node timestamps onto the local clock.
+    async fn anchor(&mut self, orchestrations: &mut [MonitorOrchestration]) {
+        let clock = self.clock_anchor().await;
+        anchor_to(&clock, orchestrations);
     }
 
     /// List all orchestrations.
     pub async fn list_orchestrations(&mut self) -> Result<Vec<MonitorOrchestration>> {
         let entries = self.client.list_orchestrations().await?;
-        Ok(entries.into_iter().map(MonitorOrchestration::from_list_entry).collect())
+        let mut orchestrations: Vec<MonitorOrchestration> = entries
+            .into_iter()
+            .map(MonitorOrchestration::from_list_entry)
+            .collect();
+        self.anchor(&mut orchestrations).await;
+        Ok(orchestrations)
+    }
+
+    /// List one page of orchestrations, newest first. Pass the previous
+    /// page's `next_cursor` to continue.
+    pub async fn list_orchestrations_page(
+        &mut self,
+        limit: usize,
+        cursor: Option<&str>,
+    ) -> Result<OrchestrationPage> {
+        let page = self
+            .client
+            .list_orchestrations_page(
+                limit,
+                cursor,
+                // Loaded on start and refresh, not per update, so the header
+                // can afford the total
+                &OrchestrationFilter {
+                    count: true,
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let mut orchestrations: Vec<MonitorOrchestration> = page
+            .entries
+            .into_iter()
+            .map(MonitorOrchestration::from_list_entry)
+            .collect();
+        self.anchor(&mut orchestrations).await;
+        Ok(OrchestrationPage {
+            orchestrations,
+            next_cursor: page.next_cursor,
+            total_count: page.total_count,
+        })
     }
 
     /// Get full detail for an orchestration, populating tasks and members.
@@ -39,7 +135,245 @@ impl ConvexDataSource {
         orchestration_id: &str,
     ) -> Result<Option<MonitorOrchestration>> {
         let detail = self.client.get_orchestration_detail(orchestration_id).await?;
-        Ok(detail.map(MonitorOrchestration::from_detail))
+        let Some(mut orch) = detail.map(MonitorOrchestration::from_detail) else {
+            return Ok(None);
+        };
+        self.anchor(std::slice::from_mut(&mut orch)).await;
+        Ok(Some(orch))
+    }
+
+    /// Follow the first `limit` orchestrations, newest first. Yields the
+    /// page now and again whenever a row on it changes.
+    pub async fn watch_orchestrations_page(
+        &mut self,
+        limit: usize,
+    ) -> Result<BoxStream<'static, Result<OrchestrationPage>>> {
+        let clock = self.clock_anchor().await;
+        let subscription = self.client.subscribe_orchestrations_page(limit).await?;
+        Ok(subscription
+            .map(move |result| {
+                let page = extract_orchestration_page(result)?;
+                let mut orchestrations: Vec<MonitorOrchestration> = page
+                    .entries
+                    .into_iter()
+                    .map(MonitorOrchestration::from_list_entry)
+                    .collect();
+                anchor_to(&clock, &mut orchestrations);
+                Ok(OrchestrationPage {
+                    orchestrations,
+                    next_cursor: page.next_cursor,
+                    total_count: page.total_count,
+                })
+            })
+            .boxed())
+    }
+
+    /// Follow one orchestration's full detail. Yields it now and again
+    /// whenever its record, phases, tasks or members change; `None` once
+    /// it no longer exists.
+    pub async fn watch_orchestration_detail(
+        &mut self,
+        orchestration_id: &str,
+    ) -> Result<BoxStream<'static, Result<Option<MonitorOrchestration>>>> {
+        let clock = self.clock_anchor().await;
+        let subscription = self
+            .client
+            .subscribe_orchestration_detail(orchestration_id)
+            .await?;
+        Ok(subscription
+            .map(move |result| {
+                let Some(mut orch) =
+                    extract_orchestration_detail(result)?.map(MonitorOrchestration::from_detail)
+                else {
+                    return Ok(None);
+                };
+                anchor_to(&clock, std::slice::from_mut(&mut orch));
+                Ok(Some(orch))
+            })
+            .boxed())
+    }
+
+    /// Get every recorded event for one task, oldest first.
+    pub async fn task_events(
+        &mut self,
+        orchestration_id: &str,
+        task_id: &str,
+    ) -> Result<Vec<TaskEventRecord>> {
+        self.client.list_task_events(orchestration_id, task_id).await
+    }
+
+    /// Get every recorded task and orchestration event for an orchestration,
+    /// each oldest first, paging forward until both streams are exhausted.
+    pub async fn replay_events(
+        &mut self,
+        orchestration_id: &str,
+    ) -> Result<(Vec<TaskEventRecord>, Vec<OrchestrationEventRecord>)> {
+        let mut tasks: Vec<TaskEventRecord> = Vec::new();
+        loop {
+            let since = tasks.last().map(|e| e.recorded_at.clone());
+            let page = self
+                .client
+                .list_orchestration_task_events(
+                    orchestration_id,
+                    since.as_deref(),
+                    Some(REPLAY_PAGE_SIZE),
+                )
+                .await?;
+            let done = (page.len() as i64) < REPLAY_PAGE_SIZE;
+            tasks.extend(page);
+            if done || tasks.len() >= REPLAY_EVENT_LIMIT {
+                break;
+            }
+        }
+
+        let mut events: Vec<OrchestrationEventRecord> = Vec::new();
+        loop {
+            let since = events.last().map(|e| e.recorded_at.clone());
+            let page = self
+                .client
+                .list_events(
+                    orchestration_id,
+                    None,
+                    since.as_deref(),
+                    Some(REPLAY_PAGE_SIZE),
+                )
+                .await?;
+            let done = (page.len() as i64) < REPLAY_PAGE_SIZE;
+            events.extend(page);
+            if done || events.len() >= REPLAY_EVENT_LIMIT {
+                break;
+            }
+        }
+        Ok((tasks, events))
+    }
+
+    /// Get the planned execution tasks for one phase of an orchestration.
+    pub async fn execution_tasks(
+        &mut self,
+        orchestration_id: &str,
+        phase_number: &str,
+    ) -> Result<Vec<ExecutionTaskRecord>> {
+        self.client
+            .list_execution_tasks(orchestration_id, phase_number)
+            .await
+    }
+
+    /// Get the review gates (plan, review, finalize) for an orchestration.
+    pub async fn review_gates(&mut self, orchestration_id: &str) -> Result<Vec<ReviewGateRecord>> {
+        self.client.list_review_gates(orchestration_id).await
+    }
+
+    /// Get every review (one per reviewer pass) for an orchestration.
+    pub async fn reviews(&mut self, orchestration_id: &str) -> Result<Vec<ReviewRecord>> {
+        self.client.list_reviews(orchestration_id).await
+    }
+
+    /// Get every commit recorded for an orchestration.
+    pub async fn commits(&mut self, orchestration_id: &str) -> Result<Vec<CommitRecord>> {
+        self.client.list_commits(orchestration_id, None).await
+    }
+
+    /// Get the review findings (threads) for an orchestration.
+    pub async fn review_findings(
+        &mut self,
+        orchestration_id: &str,
+    ) -> Result<Vec<ReviewThreadRecord>> {
+        self.client.list_review_threads(orchestration_id).await
+    }
+
+    /// Get a spec document and its revisions, newest first.
+    pub async fn spec_with_revisions(
+        &mut self,
+        spec_id: &str,
+    ) -> Result<Option<(SpecRecord, Vec<SpecRevisionRecord>)>> {
+        let Some(spec) = self.client.get_spec(spec_id).await? else {
+            return Ok(None);
+        };
+        let revisions = self.client.list_spec_revisions(spec_id).await?;
+        Ok(Some((spec, revisions)))
+    }
+
+    /// Project names by project id. A failed lookup yields no names rather
+    /// than an error.
+    pub async fn project_names(&mut self) -> HashMap<String, String> {
+        self.client
+            .list_projects()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|project| (project.id, project.name))
+            .collect()
+    }
+
+    /// Every Convex project. A failed lookup yields no projects
+    /// rather than an error.
+    pub async fn projects(&mut self) -> Vec<ProjectRecord> {
+        self.client.list_projects().await.unwrap_or_default()
+    }
+
+    /// Create a project for `repo_path`, or find the one already registered
+    /// for it. Returns the project id.
+    pub async fn create_project(&mut self, name: &str, repo_path: &str) -> Result<String> {
+        self.client.find_or_create_project(name, repo_path).await
+    }
+
+    /// Registered nodes by node id. A failed lookup yields no nodes rather
+    /// than an error.
+    pub async fn nodes(&mut self) -> HashMap<String, NodeRecord> {
+        self.client
+            .list_nodes()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|node| (node.id.clone(), node))
+            .collect()
+    }
+
+    /// Review findings and gate state for each orchestration still in
+    /// progress. A failed lookup counts as no reviews, findings, or gates
+    /// rather than an error.
+    pub async fn review_status(
+        &mut self,
+        orchestrations: &[MonitorOrchestration],
+    ) -> HashMap<String, ReviewStatus> {
+        let mut statuses = HashMap::new();
+        for orch in orchestrations {
+            if orch.status == MonitorOrchestrationStatus::Complete {
+                continue;
+            }
+            let reviews = self.reviews(&orch.id).await.unwrap_or_default();
+            let threads = self.review_findings(&orch.id).await.unwrap_or_default();
+            let gates = self.review_gates(&orch.id).await.unwrap_or_default();
+            statuses.insert(
+                orch.id.clone(),
+                ReviewStatus::from_records(&reviews, &threads, &gates),
+            );
+        }
+        statuses
+    }
+
+    /// Pending review gate ids for each orchestration still in progress.
+    /// A failed lookup counts as no pending gates rather than an error.
+    pub async fn pending_gates(
+        &mut self,
+        orchestrations: &[MonitorOrchestration],
+    ) -> HashMap<String, BTreeSet<String>> {
+        let mut gates = HashMap::new();
+        for orch in orchestrations {
+            if orch.status == MonitorOrchestrationStatus::Complete {
+                continue;
+            }
+            let pending = self
+                .review_gates(&orch.id)
+                .await
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|gate| gate.status == "pending")
+                .map(|gate| gate.gate_id)
+                .collect();
+            gates.insert(orch.id.clone(), pending);
+        }
+        gates
     }
 }
 
@@ -105,6 +439,10 @@ pub struct MonitorOrchestration {
     pub current_phase: u32,
     pub total_phases: u32,
     pub spec_doc_path: PathBuf,
+    /// Linked spec document in Convex, if the orchestration was started from one
+    pub spec_id: Option<String>,
+    /// Convex project the orchestration belongs to
+    pub project_id: Option<String>,
     pub status: MonitorOrchestrationStatus,
     pub started_at: String,
     pub completed_at: Option<String>,
@@ -119,6 +457,12 @@ pub struct MonitorOrchestration {
     pub orchestrator_tasks: Vec<Task>,
     /// Team members
     pub members: Vec<Agent>,
+    /// When each task last changed (task id -> RFC 3339 `recorded_at`)
+    pub task_updated_at: HashMap<String, String>,
+    /// How far the clock of the node running this orchestration is ahead of
+    /// the local clock; node timestamps are shifted back by it
+    #[serde(skip)]
+    pub clock_offset_ms: i64,
 }
 
 impl MonitorOrchestration {
@@ -140,6 +484,8 @@ impl MonitorOrchestration {
             current_phase: entry.record.current_phase as u32,
             total_phases: entry.record.total_phases as u32,
             spec_doc_path: PathBuf::from(&entry.record.spec_doc_path),
+            spec_id: entry.record.spec_id,
+            project_id: entry.record.project_id,
             status: MonitorOrchestrationStatus::from_str(&entry.record.status),
             started_at: entry.record.started_at,
             completed_at: entry.record.completed_at,
@@ -149,6 +495,8 @@ impl MonitorOrchestration {
             tasks: vec![],
             orchestrator_tasks: vec![],
             members: vec![],
+            task_updated_at: HashMap::new(),
+            clock_offset_ms: 0,
         }
     }
 
@@ -173,6 +521,14 @@ impl MonitorOrchestration {
             .map(materialize_agent)
             .collect();
 
+        let mut task_updated_at: HashMap<String, String> = HashMap::new();
+        for event in &detail.tasks {
+            let latest = task_updated_at.entry(event.task_id.clone()).or_default();
+            if event.recorded_at > *latest {
+                *latest = event.recorded_at.clone();
+            }
+        }
+
         Self {
             id: detail.id,
             node_id: detail.record.node_id,
@@ -182,6 +538,8 @@ impl MonitorOrchestration {
             current_phase: detail.record.current_phase as u32,
             total_phases: detail.record.total_phases as u32,
             spec_doc_path: PathBuf::from(&detail.record.spec_doc_path),
+            spec_id: detail.record.spec_id,
+            project_id: detail.record.project_id,
             status: MonitorOrchestrationStatus::from_str(&detail.record.status),
             started_at: detail.record.started_at,
             completed_at: detail.record.completed_at,
@@ -191,9 +549,29 @@ impl MonitorOrchestration {
             tasks,
             orchestrator_tasks: vec![],
             members,
+            task_updated_at,
+            clock_offset_ms: 0,
         }
     }
 
+    /// Carry `previous`'s phases, tasks and members over to this row, for
+    /// list rows that arrive without them.
+    pub fn keep_details_from(&mut self, previous: MonitorOrchestration) {
+        self.phases = previous.phases;
+        self.tasks = previous.tasks;
+        self.orchestrator_tasks = previous.orchestrator_tasks;
+        self.members = previous.members;
+        self.task_updated_at = previous.task_updated_at;
+    }
+
+    /// Whether `name` refers to this orchestration, by feature name or
+    /// team name.
+    pub fn matches_name(&self, name: &str) -> bool {
+        self.feature_name == name
+            || self.team_name() == name
+            || self.feature_name == name.trim_end_matches("-orchestration")
+    }
+
     /// Count completed tasks.
     pub fn tasks_completed(&self) -> usize {
         self.tasks
@@ -207,6 +585,65 @@ impl MonitorOrchestration {
         self.tasks.len()
     }
 
+    /// Minutes since the orchestration started, or its recorded total once
+    /// it has completed.
+    pub fn elapsed_mins(&self, now: DateTime<Utc>) -> Option<i64> {
+        // Shift our clock onto the node's rather than each timestamp onto ours
+        tina_data::humanize::elapsed_mins(
+            &self.started_at,
+            self.completed_at.as_deref(),
+            self.total_elapsed_mins,
+            now + chrono::Duration::milliseconds(self.clock_offset_ms),
+        )
+    }
+
+    /// When anything last happened: the latest task change, completion, or
+    /// start, on the local clock.
+    pub fn last_activity(&self) -> Option<DateTime<Utc>> {
+        self.task_updated_at
+            .values()
+            .chain(self.completed_at.as_ref())
+            .chain(std::iter::once(&self.started_at))
+            .filter_map(|at| self.local_time(at))
+            .max()
+    }
+
+    /// Fraction of phases done, counting the current phase as in progress.
+    pub fn phase_progress(&self) -> f64 {
+        if self.status == MonitorOrchestrationStatus::Complete {
+            return 1.0;
+        }
+        if self.total_phases == 0 {
+            return 0.0;
+        }
+        f64::from(self.current_phase.saturating_sub(1)) / f64::from(self.total_phases)
+    }
+
+    /// Minutes since a task last changed, if its events are loaded.
+    pub fn task_idle_mins(&self, task_id: &str, now: DateTime<Utc>) -> Option<i64> {
+        let at = self.local_time(self.task_updated_at.get(task_id)?)?;
+        Some(now.signed_duration_since(at).num_minutes().max(0))
+    }
+
+    /// Parse an RFC 3339 timestamp written on the orchestration's node,
+    /// translated onto the local clock.
+    fn local_time(&self, timestamp: &str) -> Option<DateTime<Utc>> {
+        let at = DateTime::parse_from_rfc3339(timestamp).ok()?;
+        Some(at.with_timezone(&Utc) - chrono::Duration::milliseconds(self.clock_offset_ms))
+    }
+
+    /// In-progress tasks that haven't changed for at least `threshold_mins`.
+    pub fn stuck_tasks(&self, threshold_mins: i64, now: DateTime<Utc>) -> Vec<&Task> {
+        self.tasks
+            .iter()
+            .filter(|task| task.status == TaskStatus::InProgress)
+            .filter(|task| {
+                self.task_idle_mins(&task.id, now)
+                    .is_some_and(|idle| idle >= threshold_mins)
+            })
+            .collect()
+    }
+
     /// Get the path to a phase plan file.
     pub fn phase_plan_path(&self, phase: u32) -> PathBuf {
         self.worktree_path
@@ -380,6 +817,7 @@ impl TaskSummary {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tina_data::test_util::list_entry;
 
     fn make_task(id: &str, status: TaskStatus, blocked_by: Vec<String>) -> Task {
         Task {
@@ -425,31 +863,11 @@ mod tests {
 
     #[test]
     fn monitor_orchestration_from_list_entry() {
-        let entry = OrchestrationListEntry {
-            id: "orch-1".to_string(),
-            node_name: "macbook".to_string(),
-            record: tina_data::OrchestrationRecord {
-                node_id: "node-1".to_string(),
-                project_id: None,
-                feature_name: "auth-system".to_string(),
-                spec_doc_path: "docs/auth.md".to_string(),
-                branch: "tina/auth".to_string(),
-                worktree_path: Some("/path/to/worktree".to_string()),
-                total_phases: 3.0,
-                current_phase: 2.0,
-                status: "executing".to_string(),
-                started_at: "2026-02-07T10:00:00Z".to_string(),
-                completed_at: None,
-                total_elapsed_mins: Some(45.0),
-                spec_id: None,
-                policy_snapshot: None,
-                policy_snapshot_hash: None,
-                preset_origin: None,
-                spec_only: None,
-                policy_revision: None,
-                updated_at: None,
-            },
-        };
+        let mut entry = list_entry("orch-1", "executing", "2026-02-07T10:00:00Z");
+        entry.record.feature_name = "auth-system".to_string();
+        entry.record.worktree_path = Some("/path/to/worktree".to_string());
+        entry.record.current_phase = 2.0;
+        entry.record.total_elapsed_mins = Some(45.0);
 
         let orch = MonitorOrchestration::from_list_entry(entry);
         assert_eq!(orch.feature_name, "auth-system");
@@ -462,31 +880,10 @@ mod tests {
 
     #[test]
     fn tasks_completed_counts_correctly() {
-        let entry = OrchestrationListEntry {
-            id: "orch-1".to_string(),
-            node_name: "macbook".to_string(),
-            record: tina_data::OrchestrationRecord {
-                node_id: "node-1".to_string(),
-                project_id: None,
-                feature_name: "test".to_string(),
-                spec_doc_path: "docs/test.md".to_string(),
-                branch: "tina/test".to_string(),
-                worktree_path: None,
-                total_phases: 1.0,
-                current_phase: 1.0,
-                status: "executing".to_string(),
-                started_at: "2026-02-07T10:00:00Z".to_string(),
-                completed_at: None,
-                total_elapsed_mins: None,
-                spec_id: None,
-                policy_snapshot: None,
-                policy_snapshot_hash: None,
-                preset_origin: None,
-                spec_only: None,
-                policy_revision: None,
-                updated_at: None,
-            },
-        };
+        let mut entry = list_entry("orch-1", "executing", "2026-02-07T10:00:00Z");
+        entry.record.feature_name = "test".to_string();
+        entry.record.branch = "tina/test".to_string();
+        entry.record.total_phases = 1.0;
 
         let mut orch = MonitorOrchestration::from_list_entry(entry);
         orch.tasks = vec![
@@ -560,31 +957,11 @@ mod tests {
 
     #[test]
     fn orchestration_summary_from_monitor_orchestration() {
-        let entry = OrchestrationListEntry {
-            id: "orch-1".to_string(),
-            node_name: "macbook".to_string(),
-            record: tina_data::OrchestrationRecord {
-                node_id: "node-1".to_string(),
-                project_id: None,
-                feature_name: "auth-system".to_string(),
-                spec_doc_path: "docs/auth.md".to_string(),
-                branch: "tina/auth".to_string(),
-                worktree_path: Some("/path/to/worktree".to_string()),
-                total_phases: 3.0,
-                current_phase: 2.0,
-                status: "executing".to_string(),
-                started_at: "2026-02-07T10:00:00Z".to_string(),
-                completed_at: None,
-                total_elapsed_mins: Some(45.0),
-                spec_id: None,
-                policy_snapshot: None,
-                policy_snapshot_hash: None,
-                preset_origin: None,
-                spec_only: None,
-                policy_revision: None,
-                updated_at: None,
-            },
-        };
+        let mut entry = list_entry("orch-1", "executing", "2026-02-07T10:00:00Z");
+        entry.record.feature_name = "auth-system".to_string();
+        entry.record.worktree_path = Some("/path/to/worktree".to_string());
+        entry.record.current_phase = 2.0;
+        entry.record.total_elapsed_mins = Some(45.0);
 
         let orch = MonitorOrchestration::from_list_entry(entry);
         let summary = OrchestrationSummary::from(&orch);
@@ -593,4 +970,101 @@ mod tests {
         assert_eq!(summary.total_phases, 3);
         assert_eq!(summary.status, MonitorOrchestrationStatus::Executing);
     }
+
+    #[test]
+    fn elapsed_mins_uses_total_once_complete() {
+        let mut entry = list_entry("orch-1", "executing", "2026-02-07T10:00:00Z");
+        entry.record.feature_name = "test".to_string();
+        entry.record.branch = "tina/test".to_string();
+        entry.record.total_phases = 1.0;
+        let now = DateTime::parse_from_rfc3339("2026-02-07T11:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let mut orch = MonitorOrchestration::from_list_entry(entry);
+        assert_eq!(orch.elapsed_mins(now), Some(90));
+
+        orch.completed_at = Some("2026-02-07T10:45:00Z".to_string());
+        orch.total_elapsed_mins = Some(45.0);
+        assert_eq!(orch.elapsed_mins(now), Some(45));
+    }
+
+    #[test]
+    fn elapsed_mins_corrects_for_node_clock_offset() {
+        let mut entry = list_entry("orch-1", "executing", "2026-02-07T10:00:00Z");
+        entry.record.feature_name = "test".to_string();
+        entry.record.branch = "tina/test".to_string();
+        entry.record.total_phases = 1.0;
+        let now = DateTime::parse_from_rfc3339("2026-02-07T09:50:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        // Node clock 20 minutes ahead: the start would otherwise be in the future
+        let mut orch = MonitorOrchestration::from_list_entry(entry);
+        assert_eq!(orch.elapsed_mins(now), Some(0));
+        orch.clock_offset_ms = 20 * 60 * 1000;
+        assert_eq!(orch.elapsed_mins(now), Some(10));
+    }
+
+    #[test]
+    fn stuck_tasks_are_stale_in_progress_tasks() {
+        let mut entry = list_entry("orch-1", "executing", "2026-02-07T10:00:00Z");
+        entry.record.feature_name = "test".to_string();
+        entry.record.branch = "tina/test".to_string();
+        entry.record.total_phases = 1.0;
+        let now = DateTime::parse_from_rfc3339("2026-02-07T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let mut orch = MonitorOrchestration::from_list_entry(entry);
+        orch.tasks = vec![
+            make_task("1", TaskStatus::InProgress, vec![]),
+            make_task("2", TaskStatus::InProgress, vec![]),
+            make_task("3", TaskStatus::Completed, vec![]),
+            make_task("4", TaskStatus::InProgress, vec![]),
+        ];
+        for (id, at) in [
+            ("1", "2026-02-07T10:00:00Z"),
+            ("2", "2026-02-07T11:50:00Z"),
+            ("3", "2026-02-07T10:00:00Z"),
+        ] {
+            orch.task_updated_at.insert(id.to_string(), at.to_string());
+        }
+
+        let stuck: Vec<&str> = orch
+            .stuck_tasks(30, now)
+            .iter()
+            .map(|t| t.id.as_str())
+            .collect();
+        assert_eq!(stuck, vec!["1"]);
+        assert_eq!(orch.task_idle_mins("1", now), Some(120));
+        assert_eq!(orch.task_idle_mins("4", now), None);
+    }
+
+    #[test]
+    fn last_activity_is_latest_task_change_or_start() {
+        let mut entry = list_entry("orch-1", "executing", "2026-02-07T10:00:00Z");
+        entry.record.feature_name = "test".to_string();
+        entry.record.branch = "tina/test".to_string();
+        entry.record.total_phases = 4.0;
+        entry.record.current_phase = 3.0;
+        let at = |ts: &str| {
+            DateTime::parse_from_rfc3339(ts)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+
+        let mut orch = MonitorOrchestration::from_list_entry(entry);
+        assert_eq!(orch.last_activity(), Some(at("2026-02-07T10:00:00Z")));
+        assert_eq!(orch.phase_progress(), 0.5);
+
+        orch.task_updated_at
+            .insert("1".to_string(), "2026-02-07T11:30:00Z".to_string());
+        orch.task_updated_at
+            .insert("2".to_string(), "2026-02-07T10:30:00Z".to_string());
+        assert_eq!(orch.last_activity(), Some(at("2026-02-07T11:30:00Z")));
+
+        orch.status = MonitorOrchestrationStatus::Complete;
+        assert_eq!(orch.phase_progress(), 1.0);
+    }
 }
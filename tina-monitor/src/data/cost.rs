@@ -0,0 +1,108 @@
+//! Spend summary for the cost panel
+//!
+//! Token spend isn't recorded, so spend is measured in agent minutes, as in
+//! the export report's Cost section: each phase's planning, execution and
+//! review time, attributed to the role that does that stage.
+
+use tina_data::PhaseRecord;
+
+/// Roles in the order the panel lists them, with the stage each one runs
+pub const ROLES: [&str; 3] = ["planner", "executor", "reviewer"];
+
+/// Recorded stage times for one phase, in minutes
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseCost {
+    pub phase: String,
+    pub planning: f64,
+    pub execution: f64,
+    pub review: f64,
+}
+
+impl PhaseCost {
+    pub fn total(&self) -> f64 {
+        self.planning + self.execution + self.review
+    }
+}
+
+/// Spend per phase and per role for one orchestration
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CostSummary {
+    /// Phases in phase-number order
+    pub phases: Vec<PhaseCost>,
+}
+
+impl CostSummary {
+    /// Summarize recorded phases; stages without a recorded time count as
+    /// zero
+    pub fn from_phases(phases: &[PhaseRecord]) -> Self {
+        let mut phases: Vec<PhaseCost> = phases
+            .iter()
+            .map(|p| PhaseCost {
+                phase: p.phase_number.clone(),
+                planning: p.planning_mins.unwrap_or(0.0),
+                execution: p.execution_mins.unwrap_or(0.0),
+                review: p.review_mins.unwrap_or(0.0),
+            })
+            .collect();
+        phases.sort_by_key(|p| p.phase.parse::<u32>().unwrap_or(u32::MAX));
+        Self { phases }
+    }
+
+    /// Minutes per role, in [`ROLES`] order
+    pub fn by_role(&self) -> [(&'static str, f64); 3] {
+        let sum = |stage: fn(&PhaseCost) -> f64| self.phases.iter().map(stage).sum();
+        [
+            (ROLES[0], sum(|p| p.planning)),
+            (ROLES[1], sum(|p| p.execution)),
+            (ROLES[2], sum(|p| p.review)),
+        ]
+    }
+
+    /// Cumulative minutes across every phase
+    pub fn total(&self) -> f64 {
+        self.phases.iter().map(PhaseCost::total).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phase(number: &str, planning: Option<f64>, execution: Option<f64>) -> PhaseRecord {
+        PhaseRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: number.to_string(),
+            status: "complete".to_string(),
+            plan_path: None,
+            git_range: None,
+            planning_mins: planning,
+            execution_mins: execution,
+            review_mins: Some(5.0),
+            started_at: None,
+            completed_at: None,
+            risk_score: None,
+            risk_signals: None,
+        }
+    }
+
+    #[test]
+    fn summarizes_phases_and_roles() {
+        let summary = CostSummary::from_phases(&[
+            phase("10", Some(1.0), None),
+            phase("2", Some(10.0), Some(30.0)),
+        ]);
+        let order: Vec<&str> = summary.phases.iter().map(|p| p.phase.as_str()).collect();
+        assert_eq!(order, vec!["2", "10"]);
+        assert_eq!(summary.phases[0].total(), 45.0);
+        assert_eq!(
+            summary.phases[1].total(),
+            6.0,
+            "missing stages count as zero"
+        );
+        assert_eq!(
+            summary.by_role(),
+            [("planner", 11.0), ("executor", 30.0), ("reviewer", 10.0)]
+        );
+        assert_eq!(summary.total(), 51.0);
+    }
+}
@@ -0,0 +1,227 @@
+//! Live orchestration updates from Convex subscriptions
+//!
+//! A background thread follows the newest page of the orchestration list
+//! plus the full detail of every unfinished orchestration on it, and sends
+//! each change to the TUI as it lands. Only what changed is sent, so the
+//! TUI merges updates instead of reloading the list.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::Duration;
+
+use futures::future;
+use futures::stream::{BoxStream, StreamExt};
+
+use super::convex::{
+    ConvexDataSource, MonitorOrchestration, MonitorOrchestrationStatus, OrchestrationPage,
+};
+
+/// Wait before reconnecting after the subscription drops
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// A change delivered by a subscription
+#[derive(Debug)]
+pub enum LiveUpdate {
+    /// The newest page of the list changed
+    Page(OrchestrationPage),
+    /// An unfinished orchestration's record, tasks or members changed
+    Detail(Box<MonitorOrchestration>),
+    /// The connection failed or dropped; it is retried shortly
+    Lost(String),
+}
+
+/// Handle on the subscription thread. Dropping it stops the thread once
+/// its next update fails to send.
+pub struct LiveUpdates {
+    rx: Receiver<LiveUpdate>,
+    connected: bool,
+}
+
+type DetailStream = BoxStream<'static, anyhow::Result<Option<MonitorOrchestration>>>;
+type DetailResult = anyhow::Result<Option<Box<MonitorOrchestration>>>;
+
+impl LiveUpdates {
+    /// Subscribe to `url` on a background thread, following the first
+    /// `page_size` orchestrations
+    pub fn start(url: &str, page_size: usize) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let url = url.to_string();
+        std::thread::spawn(move || run(&url, page_size, tx));
+        Self {
+            rx,
+            connected: false,
+        }
+    }
+
+    /// Updates fed from a channel instead of Convex
+    #[cfg(test)]
+    pub(crate) fn from_receiver(rx: Receiver<LiveUpdate>) -> Self {
+        Self {
+            rx,
+            connected: false,
+        }
+    }
+
+    /// Whether updates are currently flowing; false until the first one
+    /// lands and after the connection drops
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    /// Every update received since the last call, oldest first
+    pub fn drain(&mut self) -> Vec<LiveUpdate> {
+        let updates: Vec<LiveUpdate> = self.rx.try_iter().collect();
+        if let Some(last) = updates.last() {
+            self.connected = !matches!(last, LiveUpdate::Lost(_));
+        }
+        updates
+    }
+}
+
+/// Keep a subscription open until the receiver goes away, reconnecting
+/// after failures
+fn run(url: &str, page_size: usize, tx: Sender<LiveUpdate>) {
+    let Ok(rt) = tokio::runtime::Runtime::new() else {
+        let _ = tx.send(LiveUpdate::Lost("could not start runtime".to_string()));
+        return;
+    };
+    loop {
+        let error = match rt.block_on(follow(url, page_size, &tx)) {
+            Ok(()) => "subscription closed".to_string(),
+            Err(e) => e.to_string(),
+        };
+        if tx.send(LiveUpdate::Lost(error)).is_err() {
+            return;
+        }
+        std::thread::sleep(RECONNECT_DELAY);
+    }
+}
+
+/// What woke the subscription loop
+enum Next {
+    Page(Option<anyhow::Result<OrchestrationPage>>),
+    Detail(String, Option<DetailResult>),
+}
+
+/// Orchestrations on `page` worth following in detail
+fn unfinished_ids(page: &OrchestrationPage) -> Vec<String> {
+    page.orchestrations
+        .iter()
+        .filter(|orch| orch.status != MonitorOrchestrationStatus::Complete)
+        .map(|orch| orch.id.clone())
+        .collect()
+}
+
+/// Forward updates until the connection drops or nobody is listening.
+/// Returns `Ok` only when the list subscription ends.
+async fn follow(url: &str, page_size: usize, tx: &Sender<LiveUpdate>) -> anyhow::Result<()> {
+    let mut ds = ConvexDataSource::new(url).await?;
+    let mut pages = ds.watch_orchestrations_page(page_size).await?;
+    let mut details: HashMap<String, DetailStream> = HashMap::new();
+
+    loop {
+        let next = tokio::select! {
+            page = pages.next() => Next::Page(page),
+            (id, detail) = next_detail(&mut details) => Next::Detail(id, detail),
+        };
+        let update = match next {
+            Next::Page(None) => return Ok(()),
+            Next::Page(Some(page)) => {
+                let page = page?;
+                let ids = unfinished_ids(&page);
+                details.retain(|id, _| ids.contains(id));
+                for id in ids {
+                    if let Entry::Vacant(entry) = details.entry(id) {
+                        let stream = ds.watch_orchestration_detail(entry.key()).await?;
+                        entry.insert(stream);
+                    }
+                }
+                LiveUpdate::Page(page)
+            }
+            Next::Detail(id, None) => {
+                details.remove(&id);
+                continue;
+            }
+            Next::Detail(_, Some(detail)) => match detail? {
+                Some(orch) => LiveUpdate::Detail(orch),
+                None => continue,
+            },
+        };
+        if tx.send(update).is_err() {
+            // The TUI has exited
+            return Ok(());
+        }
+    }
+}
+
+/// The next value from any followed detail, with the orchestration it is
+/// for. Never resolves while nothing is followed.
+async fn next_detail(
+    details: &mut HashMap<String, DetailStream>,
+) -> (String, Option<DetailResult>) {
+    if details.is_empty() {
+        return future::pending().await;
+    }
+    let nexts = details.iter_mut().map(|(id, stream)| {
+        Box::pin(async move {
+            let next = stream.next().await;
+            (
+                id.clone(),
+                next.map(|detail| detail.map(|orch| orch.map(Box::new))),
+            )
+        })
+    });
+    future::select_all(nexts).await.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tina_data::test_util::list_entry;
+
+    fn orchestration(id: &str, status: &str) -> MonitorOrchestration {
+        let mut entry = list_entry(id, status, "2026-02-07T10:00:00Z");
+        entry.record.feature_name = id.to_string();
+        entry.record.branch = format!("tina/{}", id);
+        entry.record.total_phases = 2.0;
+        MonitorOrchestration::from_list_entry(entry)
+    }
+
+    #[test]
+    fn follows_only_unfinished_orchestrations() {
+        let page = OrchestrationPage {
+            orchestrations: vec![
+                orchestration("a", "executing"),
+                orchestration("b", "complete"),
+                orchestration("c", "blocked"),
+            ],
+            next_cursor: None,
+            total_count: Some(3),
+        };
+        assert_eq!(unfinished_ids(&page), vec!["a", "c"]);
+    }
+
+    #[test]
+    fn drain_tracks_connection() {
+        let (tx, rx) = mpsc::channel();
+        let mut live = LiveUpdates::from_receiver(rx);
+        assert!(live.drain().is_empty());
+        assert!(!live.is_connected());
+
+        tx.send(LiveUpdate::Detail(Box::new(orchestration(
+            "a",
+            "executing",
+        ))))
+        .unwrap();
+        assert_eq!(live.drain().len(), 1);
+        assert!(live.is_connected());
+
+        assert!(live.drain().is_empty());
+        assert!(live.is_connected(), "quiet is not disconnected");
+
+        tx.send(LiveUpdate::Lost("closed".to_string())).unwrap();
+        live.drain();
+        assert!(!live.is_connected());
+    }
+}
@@ -3,14 +3,24 @@
 //! Provides the Convex-backed data layer for reading orchestration state,
 //! plus a local file-based data source for the panel-grid app shell.
 
+pub mod changes;
+pub mod clock;
 pub mod convex;
+pub mod cost;
+pub mod live;
 pub mod local;
+pub mod replay;
+pub mod review;
+pub mod task_graph;
+pub mod timeline;
 
+pub use clock::ClockAnchor;
 pub use convex::{
-    ConvexDataSource, MonitorOrchestration, MonitorOrchestrationStatus, OrchestrationSummary,
-    TaskSummary,
+    ConvexDataSource, MonitorOrchestration, MonitorOrchestrationStatus, OrchestrationPage,
+    OrchestrationSummary, TaskSummary,
 };
 pub use local::{DataSource, LoadedOrchestration};
+pub use review::ReviewStatus;
 
 /// Type alias for backward compatibility with app.rs
 pub type Orchestration = LoadedOrchestration;
@@ -0,0 +1,340 @@
+//! Orchestration replay
+//!
+//! Merges an orchestration's recorded task and orchestration events into one
+//! chronological stream, and folds any prefix of it back into the tasks,
+//! phases and agents as they stood at that point.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use tina_data::humanize::parse_rfc3339;
+use tina_data::{OrchestrationEventRecord, TaskEventRecord};
+
+/// One recorded event on the replay stream
+#[derive(Debug, Clone)]
+pub enum ReplayEvent {
+    Task(TaskEventRecord),
+    Orchestration(OrchestrationEventRecord),
+}
+
+impl ReplayEvent {
+    /// Phase the event belongs to, if recorded
+    pub fn phase(&self) -> Option<&str> {
+        match self {
+            ReplayEvent::Task(e) => e.phase_number.as_deref(),
+            ReplayEvent::Orchestration(e) => e.phase_number.as_deref(),
+        }
+    }
+
+    /// One-line description for the event log
+    pub fn describe(&self) -> String {
+        match self {
+            ReplayEvent::Task(e) => match &e.owner {
+                Some(owner) => format!("task {} {} ({})", e.task_id, e.status, owner),
+                None => format!("task {} {}", e.task_id, e.status),
+            },
+            ReplayEvent::Orchestration(e) => format!("{}: {}", e.event_type, e.summary),
+        }
+    }
+}
+
+/// A task as it stood at the replay cursor
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayTask {
+    pub phase: Option<String>,
+    pub task_id: String,
+    pub subject: String,
+    pub status: String,
+    pub owner: Option<String>,
+}
+
+/// An agent seen on the stream up to the replay cursor
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayAgent {
+    pub name: String,
+    pub last_seen: DateTime<Utc>,
+    /// Whether an `agent_shutdown` event has been recorded for it
+    pub shut_down: bool,
+}
+
+/// Orchestration state reconstructed at the replay cursor
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReplayState {
+    /// Most recently started phase
+    pub current_phase: Option<String>,
+    /// Phase number to "executing" or "complete"
+    pub phases: BTreeMap<String, String>,
+    /// Tasks in first-seen order
+    pub tasks: Vec<ReplayTask>,
+    pub agents: Vec<ReplayAgent>,
+}
+
+/// A scrubbable event stream for one orchestration
+#[derive(Debug, Clone)]
+pub struct Replay {
+    events: Vec<(DateTime<Utc>, ReplayEvent)>,
+    /// Index of the last applied event
+    cursor: usize,
+}
+
+fn agent_name(event: &OrchestrationEventRecord) -> Option<String> {
+    let detail: serde_json::Value = serde_json::from_str(event.detail.as_deref()?).ok()?;
+    detail.get("agent_name")?.as_str().map(str::to_string)
+}
+
+impl Replay {
+    /// Merge both streams by time, dropping events with unparseable
+    /// timestamps. The cursor starts on the first event.
+    pub fn new(tasks: Vec<TaskEventRecord>, events: Vec<OrchestrationEventRecord>) -> Self {
+        let mut merged: Vec<_> = tasks
+            .into_iter()
+            .filter_map(|e| parse_rfc3339(&e.recorded_at).map(|at| (at, ReplayEvent::Task(e))))
+            .chain(events.into_iter().filter_map(|e| {
+                parse_rfc3339(&e.recorded_at).map(|at| (at, ReplayEvent::Orchestration(e)))
+            }))
+            .collect();
+        // Stable, so same-instant events keep task-before-orchestration order.
+        merged.sort_by_key(|(at, _)| *at);
+        Self {
+            events: merged,
+            cursor: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Time of the event under the cursor
+    pub fn at(&self) -> Option<DateTime<Utc>> {
+        self.events.get(self.cursor).map(|(at, _)| *at)
+    }
+
+    /// Events up to and including the cursor, newest last
+    pub fn applied(&self) -> &[(DateTime<Utc>, ReplayEvent)] {
+        match self.events.len() {
+            0 => &[],
+            _ => &self.events[..=self.cursor],
+        }
+    }
+
+    /// Move the cursor by `delta` events, clamped to the stream
+    pub fn step(&mut self, delta: isize) {
+        let last = self.events.len().saturating_sub(1);
+        self.cursor = self.cursor.saturating_add_signed(delta).min(last);
+    }
+
+    pub fn to_start(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn to_end(&mut self) {
+        self.cursor = self.events.len().saturating_sub(1);
+    }
+
+    /// Jump to the next phase_started/phase_completed event after the
+    /// cursor, or before it when `forward` is false
+    pub fn jump_phase(&mut self, forward: bool) {
+        let is_boundary = |(_, e): &(DateTime<Utc>, ReplayEvent)| {
+            matches!(e, ReplayEvent::Orchestration(o)
+                if o.event_type == "phase_started" || o.event_type == "phase_completed")
+        };
+        let found = if forward {
+            self.events
+                .iter()
+                .enumerate()
+                .skip(self.cursor + 1)
+                .find(|(_, e)| is_boundary(e))
+        } else {
+            self.events
+                .iter()
+                .enumerate()
+                .take(self.cursor)
+                .rev()
+                .find(|(_, e)| is_boundary(e))
+        };
+        if let Some((index, _)) = found {
+            self.cursor = index;
+        }
+    }
+
+    /// Fold every event up to the cursor into the state at that point
+    pub fn state(&self) -> ReplayState {
+        let mut state = ReplayState::default();
+        let mut agents: BTreeMap<String, ReplayAgent> = BTreeMap::new();
+        let mut seen = |name: &str, at: DateTime<Utc>, shut_down: bool| {
+            let agent = agents.entry(name.to_string()).or_insert(ReplayAgent {
+                name: name.to_string(),
+                last_seen: at,
+                shut_down,
+            });
+            agent.last_seen = at;
+            agent.shut_down = shut_down;
+        };
+
+        for (at, event) in self.applied() {
+            match event {
+                ReplayEvent::Task(e) => {
+                    let existing = state
+                        .tasks
+                        .iter_mut()
+                        .find(|t| t.task_id == e.task_id && t.phase == e.phase_number);
+                    let task = ReplayTask {
+                        phase: e.phase_number.clone(),
+                        task_id: e.task_id.clone(),
+                        subject: e.subject.clone(),
+                        status: e.status.clone(),
+                        owner: e.owner.clone(),
+                    };
+                    match existing {
+                        Some(existing) => *existing = task,
+                        None => state.tasks.push(task),
+                    }
+                    if let Some(owner) = &e.owner {
+                        seen(owner, *at, false);
+                    }
+                }
+                ReplayEvent::Orchestration(e) => {
+                    let phase = e.phase_number.clone();
+                    match e.event_type.as_str() {
+                        "phase_started" => {
+                            if let Some(phase) = phase {
+                                state.phases.insert(phase.clone(), "executing".to_string());
+                                state.current_phase = Some(phase);
+                            }
+                        }
+                        "phase_completed" => {
+                            if let Some(phase) = phase {
+                                state.phases.insert(phase, "complete".to_string());
+                            }
+                        }
+                        "agent_shutdown" => {
+                            if let Some(name) = agent_name(e) {
+                                seen(&name, *at, true);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        state.agents = agents.into_values().collect();
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: &str, status: &str, owner: Option<&str>, at: &str) -> TaskEventRecord {
+        TaskEventRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: Some("1".to_string()),
+            task_id: id.to_string(),
+            subject: format!("Task {}", id),
+            description: None,
+            status: status.to_string(),
+            owner: owner.map(str::to_string),
+            blocked_by: None,
+            metadata: None,
+            recorded_at: at.to_string(),
+        }
+    }
+
+    fn event(event_type: &str, detail: Option<&str>, at: &str) -> OrchestrationEventRecord {
+        OrchestrationEventRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: Some("1".to_string()),
+            event_type: event_type.to_string(),
+            source: "tina-session".to_string(),
+            summary: event_type.to_string(),
+            detail: detail.map(str::to_string),
+            recorded_at: at.to_string(),
+        }
+    }
+
+    fn replay() -> Replay {
+        Replay::new(
+            vec![
+                task("1", "in_progress", Some("worker-1"), "2026-02-10T10:05:00Z"),
+                task("1", "pending", None, "2026-02-10T10:01:00Z"),
+                task("1", "completed", Some("worker-1"), "2026-02-10T10:20:00Z"),
+                task("2", "pending", None, "not a time"),
+            ],
+            vec![
+                event("phase_started", None, "2026-02-10T10:00:00Z"),
+                event(
+                    "agent_shutdown",
+                    Some(r#"{"agent_name":"worker-1"}"#),
+                    "2026-02-10T10:25:00Z",
+                ),
+                event("phase_completed", None, "2026-02-10T10:30:00Z"),
+            ],
+        )
+    }
+
+    #[test]
+    fn merges_streams_by_time() {
+        let replay = replay();
+        assert_eq!(replay.len(), 6, "unparseable timestamps are dropped");
+        let times: Vec<_> = replay.events.iter().map(|(at, _)| *at).collect();
+        assert!(times.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn state_reflects_events_up_to_cursor() {
+        let mut replay = replay();
+        let state = replay.state();
+        assert_eq!(state.current_phase.as_deref(), Some("1"));
+        assert_eq!(state.phases.get("1").map(String::as_str), Some("executing"));
+        assert!(state.tasks.is_empty());
+
+        replay.step(2);
+        let state = replay.state();
+        assert_eq!(state.tasks.len(), 1);
+        assert_eq!(state.tasks[0].status, "in_progress");
+        assert_eq!(state.agents[0].name, "worker-1");
+        assert!(!state.agents[0].shut_down);
+
+        replay.to_end();
+        let state = replay.state();
+        assert_eq!(state.tasks[0].status, "completed");
+        assert_eq!(state.phases.get("1").map(String::as_str), Some("complete"));
+        assert!(state.agents[0].shut_down);
+    }
+
+    #[test]
+    fn cursor_is_clamped() {
+        let mut replay = replay();
+        replay.step(-3);
+        assert_eq!(replay.cursor(), 0);
+        replay.step(100);
+        assert_eq!(replay.cursor(), replay.len() - 1);
+
+        let mut empty = Replay::new(vec![], vec![]);
+        empty.step(1);
+        assert_eq!(empty.cursor(), 0);
+        assert!(empty.applied().is_empty());
+        assert_eq!(empty.state(), ReplayState::default());
+    }
+
+    #[test]
+    fn jumps_between_phase_boundaries() {
+        let mut replay = replay();
+        replay.jump_phase(true);
+        assert_eq!(replay.cursor(), replay.len() - 1);
+        replay.jump_phase(true);
+        assert_eq!(replay.cursor(), replay.len() - 1, "no later boundary");
+        replay.jump_phase(false);
+        assert_eq!(replay.cursor(), 0);
+    }
+}
@@ -0,0 +1,169 @@
+//! Review findings and gate state, summarized for the Phases pane
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use tina_data::{ReviewGateRecord, ReviewRecord, ReviewThreadRecord};
+
+/// Unresolved findings by severity
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FindingCounts {
+    pub p0: usize,
+    pub p1: usize,
+    pub p2: usize,
+}
+
+/// What an orchestration's reviews are waiting on
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReviewStatus {
+    /// Gate status (`pending`, `blocked`, or `approved`) by gate id
+    pub gates: BTreeMap<String, String>,
+    /// Unresolved findings by phase number. Findings from reviews of the
+    /// whole orchestration aren't counted against any phase.
+    pub open_findings: HashMap<u32, FindingCounts>,
+}
+
+impl ReviewStatus {
+    pub fn from_records(
+        reviews: &[ReviewRecord],
+        threads: &[ReviewThreadRecord],
+        gates: &[ReviewGateRecord],
+    ) -> Self {
+        let review_phase: HashMap<&str, u32> = reviews
+            .iter()
+            .filter_map(|review| {
+                let phase = review.phase_number.as_deref()?.parse().ok()?;
+                Some((review.id.as_str(), phase))
+            })
+            .collect();
+
+        let mut open_findings: HashMap<u32, FindingCounts> = HashMap::new();
+        for thread in threads.iter().filter(|t| t.status == "unresolved") {
+            let Some(&phase) = review_phase.get(thread.review_id.as_str()) else {
+                continue;
+            };
+            let counts = open_findings.entry(phase).or_default();
+            match thread.severity.as_str() {
+                "p0" => counts.p0 += 1,
+                "p1" => counts.p1 += 1,
+                _ => counts.p2 += 1,
+            }
+        }
+
+        Self {
+            gates: gates
+                .iter()
+                .map(|gate| (gate.gate_id.clone(), gate.status.clone()))
+                .collect(),
+            open_findings,
+        }
+    }
+
+    /// Ids of the gates still pending
+    pub fn pending_gates(&self) -> BTreeSet<String> {
+        self.gates
+            .iter()
+            .filter(|(_, status)| status.as_str() == "pending")
+            .map(|(gate, _)| gate.clone())
+            .collect()
+    }
+
+    /// Gates not yet approved, as `(gate id, status)`
+    pub fn waiting_gates(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.gates
+            .iter()
+            .filter(|(_, status)| status.as_str() != "approved")
+            .map(|(gate, status)| (gate.as_str(), status.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn review(id: &str, phase: Option<&str>) -> ReviewRecord {
+        ReviewRecord {
+            id: id.to_string(),
+            orchestration_id: "orch-1".to_string(),
+            phase_number: phase.map(str::to_string),
+            state: "open".to_string(),
+            reviewer_agent: "reviewer".to_string(),
+            started_at: "2026-02-13T10:00:00Z".to_string(),
+            completed_at: None,
+        }
+    }
+
+    fn thread(review_id: &str, severity: &str, status: &str) -> ReviewThreadRecord {
+        ReviewThreadRecord {
+            id: format!("{}-{}", review_id, severity),
+            review_id: review_id.to_string(),
+            orchestration_id: "orch-1".to_string(),
+            file_path: "src/lib.rs".to_string(),
+            line: 1,
+            commit_sha: "abc".to_string(),
+            summary: String::new(),
+            body: String::new(),
+            severity: severity.to_string(),
+            status: status.to_string(),
+            source: "agent".to_string(),
+            author: "reviewer".to_string(),
+            gate_impact: "review".to_string(),
+            created_at: "2026-02-13T10:00:00Z".to_string(),
+        }
+    }
+
+    fn gate(gate_id: &str, status: &str) -> ReviewGateRecord {
+        ReviewGateRecord {
+            id: gate_id.to_string(),
+            orchestration_id: "orch-1".to_string(),
+            gate_id: gate_id.to_string(),
+            status: status.to_string(),
+            owner: "orchestrator".to_string(),
+            decided_by: None,
+            decided_at: None,
+            summary: String::new(),
+            required_approvals: None,
+            decisions: vec![],
+        }
+    }
+
+    #[test]
+    fn test_counts_open_findings_per_phase_and_gate_states() {
+        let status = ReviewStatus::from_records(
+            &[
+                review("r1", Some("1")),
+                review("r2", Some("2")),
+                review("r3", None),
+            ],
+            &[
+                thread("r1", "p1", "resolved"),
+                thread("r2", "p0", "unresolved"),
+                thread("r2", "p2", "unresolved"),
+                thread("r2", "p2", "unresolved"),
+                thread("r3", "p0", "unresolved"),
+            ],
+            &[
+                gate("plan", "approved"),
+                gate("review", "blocked"),
+                gate("finalize", "pending"),
+            ],
+        );
+
+        assert_eq!(status.open_findings.get(&1), None);
+        assert_eq!(
+            status.open_findings[&2],
+            FindingCounts {
+                p0: 1,
+                p1: 0,
+                p2: 2
+            }
+        );
+        assert_eq!(
+            status.pending_gates(),
+            BTreeSet::from(["finalize".to_string()])
+        );
+        assert_eq!(
+            status.waiting_gates().collect::<Vec<_>>(),
+            [("finalize", "pending"), ("review", "blocked")]
+        );
+    }
+}
@@ -0,0 +1,331 @@
+//! Layered layout of a phase's task dependencies
+//!
+//! Tasks are placed in columns by dependency depth, so every arrow points
+//! right. Dependencies that skip columns pass through a waypoint in each
+//! column they cross, which keeps every drawn segment between neighbouring
+//! columns. Within a column, nodes are ordered by the average position of
+//! what they depend on to cut down on crossings.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::types::{Task, TaskStatus};
+
+/// A slot in a column: a task box or a waypoint of a long dependency
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphNode {
+    /// Index into the tasks the graph was built from
+    Task(usize),
+    Waypoint,
+}
+
+/// A dependency drawn between neighbouring columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    /// `(column, row)` of the node the segment leaves
+    pub from: (usize, usize),
+    /// `(column, row)` of the node the segment enters
+    pub to: (usize, usize),
+    /// Whether the dependency is on the critical path
+    pub critical: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskGraph {
+    /// Columns left to right, each listing its nodes top to bottom
+    pub columns: Vec<Vec<GraphNode>>,
+    pub segments: Vec<Segment>,
+    /// Task indices on the longest chain of unfinished work, first to last
+    pub critical_path: Vec<usize>,
+    /// Pending tasks waiting on an unfinished task
+    pub blocked: HashSet<usize>,
+    /// Whether some dependencies form a cycle; those tasks go in a final
+    /// column and their cyclic arrows are not drawn
+    pub has_cycle: bool,
+}
+
+impl TaskGraph {
+    pub fn build(tasks: &[Task]) -> Self {
+        let index: HashMap<&str, usize> = tasks
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (t.id.as_str(), i))
+            .collect();
+
+        // Dependencies as (blocker, blocked) pairs, from either side
+        let mut deps: Vec<(usize, usize)> = Vec::new();
+        for (i, task) in tasks.iter().enumerate() {
+            for id in &task.blocked_by {
+                if let Some(&b) = index.get(id.as_str()) {
+                    deps.push((b, i));
+                }
+            }
+            for id in &task.blocks {
+                if let Some(&b) = index.get(id.as_str()) {
+                    deps.push((i, b));
+                }
+            }
+        }
+        deps.sort_unstable();
+        deps.dedup();
+        deps.retain(|(a, b)| a != b);
+
+        let blocked = tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| t.status == TaskStatus::Pending)
+            .filter(|(i, t)| {
+                let unknown_blocker = t
+                    .blocked_by
+                    .iter()
+                    .any(|id| !index.contains_key(id.as_str()));
+                unknown_blocker
+                    || deps
+                        .iter()
+                        .any(|(a, b)| b == i && tasks[*a].status != TaskStatus::Completed)
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        let (order, depth) = topological_depths(tasks.len(), &deps);
+        let has_cycle = order.len() < tasks.len();
+        let cycle_column = depth.iter().flatten().max().map_or(0, |d| d + 1);
+        let column_of: Vec<usize> = depth.iter().map(|d| d.unwrap_or(cycle_column)).collect();
+
+        let critical_path = critical_path(tasks, &deps, &order);
+        let critical_edges: HashSet<(usize, usize)> =
+            critical_path.windows(2).map(|w| (w[0], w[1])).collect();
+
+        let mut graph = Self {
+            critical_path,
+            blocked,
+            has_cycle,
+            ..Self::default()
+        };
+        graph.lay_out(&column_of, &deps, &critical_edges);
+        graph
+    }
+
+    /// Place nodes and waypoints into columns and connect them
+    fn lay_out(
+        &mut self,
+        column_of: &[usize],
+        deps: &[(usize, usize)],
+        critical_edges: &HashSet<(usize, usize)>,
+    ) {
+        let columns = column_of.iter().max().map_or(0, |c| c + 1);
+        // Nodes per column as (node, ids of the nodes feeding it), with the
+        // chain of node ids each drawn dependency passes through
+        let mut nodes: Vec<(usize, GraphNode)> = column_of
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| (c, GraphNode::Task(i)))
+            .collect();
+        let mut chains: Vec<(Vec<usize>, bool)> = Vec::new();
+        for &(a, b) in deps {
+            let (ca, cb) = (column_of[a], column_of[b]);
+            if ca >= cb {
+                continue;
+            }
+            let mut chain = vec![a];
+            for c in ca + 1..cb {
+                nodes.push((c, GraphNode::Waypoint));
+                chain.push(nodes.len() - 1);
+            }
+            chain.push(b);
+            chains.push((chain, critical_edges.contains(&(a, b))));
+        }
+
+        let mut feeders: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        for (chain, _) in &chains {
+            for w in chain.windows(2) {
+                feeders[w[1]].push(w[0]);
+            }
+        }
+
+        // Order each column by the mean row of its feeders in the column
+        // before; nodes without feeders keep their task order
+        let mut row_of = vec![0usize; nodes.len()];
+        let mut members: Vec<Vec<usize>> = vec![Vec::new(); columns];
+        for (id, (c, _)) in nodes.iter().enumerate() {
+            members[*c].push(id);
+        }
+        for column in members.iter_mut() {
+            let key = |id: &usize| -> f64 {
+                let f = &feeders[*id];
+                if f.is_empty() {
+                    *id as f64
+                } else {
+                    f.iter().map(|p| row_of[*p] as f64).sum::<f64>() / f.len() as f64
+                }
+            };
+            let mut keyed: Vec<(f64, usize)> = column.iter().map(|id| (key(id), *id)).collect();
+            keyed.sort_by(|x, y| x.0.total_cmp(&y.0).then(x.1.cmp(&y.1)));
+            *column = keyed.into_iter().map(|(_, id)| id).collect();
+            for (row, id) in column.iter().enumerate() {
+                row_of[*id] = row;
+            }
+        }
+
+        self.columns = members
+            .iter()
+            .map(|column| column.iter().map(|id| nodes[*id].1).collect())
+            .collect();
+        for (chain, critical) in &chains {
+            for w in chain.windows(2) {
+                self.segments.push(Segment {
+                    from: (nodes[w[0]].0, row_of[w[0]]),
+                    to: (nodes[w[1]].0, row_of[w[1]]),
+                    critical: *critical,
+                });
+            }
+        }
+    }
+}
+
+/// Tasks in dependency order, and each task's column (longest chain of
+/// blockers before it). Tasks on a cycle are left out of the order and
+/// have no depth.
+fn topological_depths(count: usize, deps: &[(usize, usize)]) -> (Vec<usize>, Vec<Option<usize>>) {
+    let mut incoming = vec![0usize; count];
+    for (_, b) in deps {
+        incoming[*b] += 1;
+    }
+    let mut depth: Vec<Option<usize>> = vec![None; count];
+    let mut ready: Vec<usize> = (0..count).filter(|i| incoming[*i] == 0).rev().collect();
+    for i in &ready {
+        depth[*i] = Some(0);
+    }
+    let mut order = Vec::new();
+    while let Some(i) = ready.pop() {
+        order.push(i);
+        for (_, b) in deps.iter().filter(|(a, _)| *a == i) {
+            let d = depth[i].unwrap_or(0) + 1;
+            depth[*b] = Some(depth[*b].map_or(d, |old| old.max(d)));
+            incoming[*b] -= 1;
+            if incoming[*b] == 0 {
+                ready.push(*b);
+            }
+        }
+    }
+    for (i, d) in depth.iter_mut().enumerate() {
+        if !order.contains(&i) {
+            *d = None;
+        }
+    }
+    (order, depth)
+}
+
+/// Longest chain of unfinished tasks, which bounds how soon the phase can
+/// finish
+fn critical_path(tasks: &[Task], deps: &[(usize, usize)], order: &[usize]) -> Vec<usize> {
+    let open = |i: usize| tasks[i].status != TaskStatus::Completed;
+    let mut length = vec![0usize; tasks.len()];
+    let mut previous: Vec<Option<usize>> = vec![None; tasks.len()];
+    for &i in order.iter().filter(|i| open(**i)) {
+        length[i] = 1;
+        for &(a, _) in deps.iter().filter(|(a, b)| *b == i && open(*a)) {
+            if length[a] + 1 > length[i] {
+                length[i] = length[a] + 1;
+                previous[i] = Some(a);
+            }
+        }
+    }
+    let Some(mut last) = (0..tasks.len())
+        .filter(|i| length[*i] > 0)
+        .max_by_key(|i| (length[*i], std::cmp::Reverse(*i)))
+    else {
+        return Vec::new();
+    };
+    let mut path = vec![last];
+    while let Some(p) = previous[last] {
+        path.push(p);
+        last = p;
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A task titled "Task {id}", shared with the graph view's tests.
+    pub(crate) fn task(id: &str, status: TaskStatus, blocked_by: &[&str]) -> Task {
+        Task {
+            id: id.to_string(),
+            subject: format!("Task {}", id),
+            description: String::new(),
+            active_form: None,
+            status,
+            owner: None,
+            blocks: vec![],
+            blocked_by: blocked_by.iter().map(|s| s.to_string()).collect(),
+            metadata: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn layers_tasks_by_dependency_depth() {
+        // 1 -> 2 -> 4, 1 -> 3, and 1 -> 4 skipping a column
+        let tasks = vec![
+            task("1", TaskStatus::Completed, &[]),
+            task("2", TaskStatus::InProgress, &["1"]),
+            task("3", TaskStatus::Pending, &["1"]),
+            task("4", TaskStatus::Pending, &["2", "1"]),
+        ];
+        let graph = TaskGraph::build(&tasks);
+
+        assert_eq!(graph.columns.len(), 3);
+        assert_eq!(graph.columns[0], vec![GraphNode::Task(0)]);
+        assert!(graph.columns[1].contains(&GraphNode::Task(1)));
+        assert!(graph.columns[1].contains(&GraphNode::Task(2)));
+        assert!(
+            graph.columns[1].contains(&GraphNode::Waypoint),
+            "1 -> 4 passes through column 1"
+        );
+        assert_eq!(graph.columns[2], vec![GraphNode::Task(3)]);
+        assert!(graph.segments.iter().all(|s| s.to.0 == s.from.0 + 1));
+        assert!(!graph.has_cycle);
+    }
+
+    #[test]
+    fn critical_path_follows_longest_unfinished_chain() {
+        let tasks = vec![
+            task("1", TaskStatus::Completed, &[]),
+            task("2", TaskStatus::InProgress, &["1"]),
+            task("3", TaskStatus::Pending, &["1"]),
+            task("4", TaskStatus::Pending, &["2"]),
+        ];
+        let graph = TaskGraph::build(&tasks);
+
+        assert_eq!(graph.critical_path, vec![1, 3]);
+        let critical: Vec<&Segment> = graph.segments.iter().filter(|s| s.critical).collect();
+        assert_eq!(critical.len(), 1);
+        assert_eq!(graph.blocked, HashSet::from([3]), "3's blocker is done");
+    }
+
+    #[test]
+    fn blocks_and_cycles_are_handled() {
+        let mut a = task("a", TaskStatus::Pending, &[]);
+        a.blocks = vec!["b".to_string()];
+        let tasks = vec![
+            a,
+            task("b", TaskStatus::Pending, &[]),
+            task("x", TaskStatus::Pending, &["y"]),
+            task("y", TaskStatus::Pending, &["x"]),
+        ];
+        let graph = TaskGraph::build(&tasks);
+
+        assert!(graph.has_cycle);
+        assert_eq!(
+            graph.columns.last().unwrap().len(),
+            2,
+            "cycle gets its own column"
+        );
+        assert!(
+            graph.blocked.contains(&1),
+            "b is blocked via a's blocks list"
+        );
+        assert_eq!(graph.critical_path, vec![0, 1]);
+    }
+}
@@ -0,0 +1,267 @@
+//! Task event timeline
+//!
+//! Folds a task's recorded events, plus the commits made while it was in
+//! progress, into one chronological list for the task inspector.
+
+use chrono::{DateTime, Utc};
+use tina_data::humanize::parse_rfc3339;
+use tina_data::TaskEventRecord;
+
+use crate::git::commits::TimedCommit;
+
+/// What happened at one point on a task's timeline
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimelineKind {
+    /// First recorded event for the task
+    Created {
+        status: String,
+    },
+    Status {
+        from: String,
+        to: String,
+    },
+    Owner {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    /// Model recorded in the task's metadata changed
+    Model {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    Commit {
+        short_hash: String,
+        subject: String,
+    },
+}
+
+/// One entry on a task's timeline
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineEntry {
+    pub at: DateTime<Utc>,
+    pub kind: TimelineKind,
+}
+
+fn model_of(event: &TaskEventRecord) -> Option<String> {
+    let metadata: serde_json::Value = serde_json::from_str(event.metadata.as_deref()?).ok()?;
+    metadata.get("model")?.as_str().map(str::to_string)
+}
+
+/// Events sorted by `recorded_at`, dropping any with unparseable timestamps
+fn sorted_events(events: &[TaskEventRecord]) -> Vec<(DateTime<Utc>, &TaskEventRecord)> {
+    let mut sorted: Vec<_> = events
+        .iter()
+        .filter_map(|event| parse_rfc3339(&event.recorded_at).map(|at| (at, event)))
+        .collect();
+    sorted.sort_by_key(|(at, _)| *at);
+    sorted
+}
+
+/// The span during which the task was being worked on: from its first
+/// `in_progress` event to its completion, or `now` if it hasn't finished.
+/// `None` if the task never started.
+pub fn work_window(
+    events: &[TaskEventRecord],
+    now: DateTime<Utc>,
+) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let sorted = sorted_events(events);
+    let start = sorted
+        .iter()
+        .find(|(_, event)| event.status == "in_progress")
+        .map(|(at, _)| *at)?;
+    let end = sorted
+        .iter()
+        .find(|(at, event)| *at >= start && event.status == "completed")
+        .map(|(at, _)| *at)
+        .unwrap_or(now);
+    Some((start, end))
+}
+
+/// Build the timeline from a task's events and the commits made during its
+/// work window. Events that change none of status, owner, or model (e.g. a
+/// description edit) are left out.
+pub fn build_timeline(events: &[TaskEventRecord], commits: &[TimedCommit]) -> Vec<TimelineEntry> {
+    let mut entries = Vec::new();
+    let mut previous: Option<&TaskEventRecord> = None;
+
+    for (at, event) in sorted_events(events) {
+        match previous {
+            None => entries.push(TimelineEntry {
+                at,
+                kind: TimelineKind::Created {
+                    status: event.status.clone(),
+                },
+            }),
+            Some(prev) => {
+                if prev.status != event.status {
+                    entries.push(TimelineEntry {
+                        at,
+                        kind: TimelineKind::Status {
+                            from: prev.status.clone(),
+                            to: event.status.clone(),
+                        },
+                    });
+                }
+                if prev.owner != event.owner {
+                    entries.push(TimelineEntry {
+                        at,
+                        kind: TimelineKind::Owner {
+                            from: prev.owner.clone(),
+                            to: event.owner.clone(),
+                        },
+                    });
+                }
+                let (from, to) = (model_of(prev), model_of(event));
+                if from != to {
+                    entries.push(TimelineEntry {
+                        at,
+                        kind: TimelineKind::Model { from, to },
+                    });
+                }
+            }
+        }
+        previous = Some(event);
+    }
+
+    entries.extend(commits.iter().map(|commit| TimelineEntry {
+        at: commit.committed_at,
+        kind: TimelineKind::Commit {
+            short_hash: commit.short_hash.clone(),
+            subject: commit.subject.clone(),
+        },
+    }));
+    // Stable, so same-instant entries keep event order ahead of commits
+    entries.sort_by_key(|entry| entry.at);
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(
+        status: &str,
+        owner: Option<&str>,
+        metadata: Option<&str>,
+        at: &str,
+    ) -> TaskEventRecord {
+        TaskEventRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: Some("1".to_string()),
+            task_id: "3".to_string(),
+            subject: "Add timeline".to_string(),
+            description: None,
+            status: status.to_string(),
+            owner: owner.map(str::to_string),
+            blocked_by: None,
+            metadata: metadata.map(str::to_string),
+            recorded_at: at.to_string(),
+        }
+    }
+
+    fn time(at: &str) -> DateTime<Utc> {
+        parse_rfc3339(at).unwrap()
+    }
+
+    #[test]
+    fn test_build_timeline_records_each_change() {
+        let events = vec![
+            event(
+                "in_progress",
+                Some("worker"),
+                Some(r#"{"model":"opus"}"#),
+                "2026-02-13T10:05:00Z",
+            ),
+            event("pending", None, None, "2026-02-13T10:00:00Z"),
+            event(
+                "in_progress",
+                Some("worker"),
+                Some(r#"{"model":"opus"}"#),
+                "2026-02-13T10:07:00Z",
+            ),
+            event(
+                "completed",
+                Some("worker"),
+                Some(r#"{"model":"opus"}"#),
+                "2026-02-13T10:30:00Z",
+            ),
+        ];
+        let commits = vec![TimedCommit {
+            short_hash: "abc1234".to_string(),
+            subject: "Add timeline".to_string(),
+            committed_at: time("2026-02-13T10:20:00Z"),
+        }];
+
+        let kinds: Vec<TimelineKind> = build_timeline(&events, &commits)
+            .into_iter()
+            .map(|entry| entry.kind)
+            .collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                TimelineKind::Created {
+                    status: "pending".to_string()
+                },
+                TimelineKind::Status {
+                    from: "pending".to_string(),
+                    to: "in_progress".to_string()
+                },
+                TimelineKind::Owner {
+                    from: None,
+                    to: Some("worker".to_string())
+                },
+                TimelineKind::Model {
+                    from: None,
+                    to: Some("opus".to_string())
+                },
+                TimelineKind::Commit {
+                    short_hash: "abc1234".to_string(),
+                    subject: "Add timeline".to_string()
+                },
+                TimelineKind::Status {
+                    from: "in_progress".to_string(),
+                    to: "completed".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_timeline_skips_unparseable_timestamps() {
+        let events = vec![
+            event("pending", None, None, "not a time"),
+            event("in_progress", None, None, "2026-02-13T10:00:00Z"),
+        ];
+        let timeline = build_timeline(&events, &[]);
+        assert_eq!(timeline.len(), 1);
+        assert_eq!(
+            timeline[0].kind,
+            TimelineKind::Created {
+                status: "in_progress".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_work_window() {
+        let now = time("2026-02-13T12:00:00Z");
+        let pending = vec![event("pending", None, None, "2026-02-13T10:00:00Z")];
+        assert_eq!(work_window(&pending, now), None);
+
+        let mut events = vec![
+            event("pending", None, None, "2026-02-13T10:00:00Z"),
+            event("in_progress", None, None, "2026-02-13T10:05:00Z"),
+        ];
+        assert_eq!(
+            work_window(&events, now),
+            Some((time("2026-02-13T10:05:00Z"), now))
+        );
+
+        events.push(event("completed", None, None, "2026-02-13T10:30:00Z"));
+        assert_eq!(
+            work_window(&events, now),
+            Some((time("2026-02-13T10:05:00Z"), time("2026-02-13T10:30:00Z")))
+        );
+    }
+}
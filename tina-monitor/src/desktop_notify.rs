@@ -0,0 +1,87 @@
+//! Desktop (OS) notifications for orchestration changes
+//!
+//! Shells out to `osascript` on macOS and `notify-send` elsewhere rather than
+//! binding to a notification daemon. Delivery is best effort: a missing
+//! `notify-send` never interrupts monitoring.
+
+use std::process::{Command, Stdio};
+
+use crate::config::NotificationsConfig;
+use crate::data::changes::ChangeKind;
+
+/// Title shown on every notification
+const TITLE: &str = "tina";
+
+/// Sends changes enabled in [`NotificationsConfig`] to the desktop
+#[derive(Debug, Clone)]
+pub struct DesktopNotifier {
+    config: NotificationsConfig,
+}
+
+impl DesktopNotifier {
+    pub fn new(config: NotificationsConfig) -> Self {
+        Self { config }
+    }
+
+    /// Show `message` if desktop notifications are on for `kind`
+    pub fn notify(&self, kind: ChangeKind, message: &str) {
+        if !self.config.enabled_for(kind) {
+            return;
+        }
+        let mut command = notification_command(TITLE, message);
+        command.stdout(Stdio::null()).stderr(Stdio::null());
+        // Reap the child off the UI thread
+        std::thread::spawn(move || {
+            let _ = command.status();
+        });
+    }
+}
+
+/// The platform command that shows a notification
+fn notification_command(title: &str, body: &str) -> Command {
+    if cfg!(target_os = "macos") {
+        let script = format!(
+            "display notification {} with title {}",
+            applescript_string(body),
+            applescript_string(title)
+        );
+        let mut command = Command::new("osascript");
+        command.arg("-e").arg(script);
+        command
+    } else {
+        let mut command = Command::new("notify-send");
+        command.arg("--app-name=tina-monitor").arg(title).arg(body);
+        command
+    }
+}
+
+/// Quote `s` as an AppleScript string literal
+pub(crate) fn applescript_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applescript_string_escapes_quotes_and_backslashes() {
+        assert_eq!(applescript_string("plain"), "\"plain\"");
+        assert_eq!(
+            applescript_string(r#"say "hi" \ bye"#),
+            r#""say \"hi\" \\ bye""#
+        );
+    }
+
+    #[test]
+    fn notification_command_passes_message_as_argument() {
+        let command = notification_command("tina", "auth: blocked in phase 2");
+        let args: Vec<String> = command
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+        assert!(args
+            .iter()
+            .any(|arg| arg.contains("auth: blocked in phase 2")));
+    }
+}
@@ -0,0 +1,191 @@
+//! Open-in-editor support
+//!
+//! Resolves the user's editor (`terminal.editor_command`, then `$VISUAL`,
+//! then `$EDITOR`) and builds the command line for opening a file at a line.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Editors that open their own window and should not suspend the TUI
+const GUI_EDITORS: &[&str] = &[
+    "code",
+    "code-insiders",
+    "codium",
+    "cursor",
+    "zed",
+    "subl",
+    "mate",
+    "idea",
+];
+
+/// Editors that take `-g file:line` (VS Code family)
+const GOTO_FLAG_EDITORS: &[&str] = &["code", "code-insiders", "codium", "cursor"];
+
+/// Editors that take `file:line` as a single argument
+const COLON_LINE_EDITORS: &[&str] = &["zed", "subl", "mate"];
+
+/// A file (and optional line) to open in the editor
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditorTarget {
+    pub path: PathBuf,
+    pub line: Option<u32>,
+}
+
+impl EditorTarget {
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            line: None,
+        }
+    }
+
+    pub fn at_line(path: impl Into<PathBuf>, line: u32) -> Self {
+        Self {
+            path: path.into(),
+            line: Some(line),
+        }
+    }
+}
+
+/// Resolve the editor command: config, then `$VISUAL`, then `$EDITOR`, then `vi`
+pub fn resolve_editor(configured: Option<&str>) -> String {
+    configured
+        .filter(|cmd| !cmd.trim().is_empty())
+        .map(str::to_string)
+        .or_else(|| std::env::var("VISUAL").ok().filter(|v| !v.is_empty()))
+        .or_else(|| std::env::var("EDITOR").ok().filter(|v| !v.is_empty()))
+        .unwrap_or_else(|| "vi".to_string())
+}
+
+fn program_name(editor: &str) -> &str {
+    let program = editor.split_whitespace().next().unwrap_or_default();
+    Path::new(program)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(program)
+}
+
+/// Whether the editor runs in its own window rather than in the terminal
+pub fn is_gui_editor(editor: &str) -> bool {
+    GUI_EDITORS.contains(&program_name(editor))
+}
+
+/// Build the argv for opening `target` with `editor`.
+///
+/// If the command contains `{file}` (and optionally `{line}`) placeholders they
+/// are substituted; otherwise the line is passed in the editor's own syntax.
+pub fn build_command(editor: &str, target: &EditorTarget) -> Vec<String> {
+    let file = target.path.display().to_string();
+    let mut argv: Vec<String> = editor.split_whitespace().map(str::to_string).collect();
+
+    if argv.iter().any(|arg| arg.contains("{file}")) {
+        let line = target.line.unwrap_or(1).to_string();
+        return argv
+            .into_iter()
+            .map(|arg| arg.replace("{file}", &file).replace("{line}", &line))
+            .collect();
+    }
+
+    let program = program_name(editor);
+    match target.line {
+        Some(line) if GOTO_FLAG_EDITORS.contains(&program) => {
+            argv.push("-g".to_string());
+            argv.push(format!("{}:{}", file, line));
+        }
+        Some(line) if COLON_LINE_EDITORS.contains(&program) => {
+            argv.push(format!("{}:{}", file, line));
+        }
+        Some(line) => {
+            argv.push(format!("+{}", line));
+            argv.push(file);
+        }
+        None => argv.push(file),
+    }
+    argv
+}
+
+/// Find the first `path:line` reference in free text (e.g. a review finding)
+pub fn parse_location(text: &str) -> Option<(String, u32)> {
+    text.split_whitespace().find_map(|token| {
+        let token = token.trim_matches(|c: char| "`'\"()[]<>,;".contains(c));
+        let mut parts = token.splitn(3, ':');
+        let path = parts.next()?;
+        let line: u32 = parts.next()?.parse().ok()?;
+        let looks_like_path = path.contains('/') || path.contains('.');
+        (looks_like_path && line > 0 && !path.contains("//")).then(|| (path.to_string(), line))
+    })
+}
+
+/// Run the editor. GUI editors are spawned detached; terminal editors block
+/// until they exit, so the caller must suspend the TUI first.
+pub fn launch(argv: &[String], gui: bool) -> Result<()> {
+    let (program, args) = argv.split_first().context("Empty editor command")?;
+    let mut command = Command::new(program);
+    command.args(args);
+
+    if gui {
+        command
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .with_context(|| format!("Failed to launch editor: {}", program))?;
+    } else {
+        let status = command
+            .status()
+            .with_context(|| format!("Failed to launch editor: {}", program))?;
+        if !status.success() {
+            anyhow::bail!("Editor exited with {}", status);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_editor_prefers_config() {
+        assert_eq!(resolve_editor(Some("code -w")), "code -w");
+    }
+
+    #[test]
+    fn test_build_command_vscode_uses_goto_flag() {
+        let argv = build_command("code", &EditorTarget::at_line("/repo/src/main.rs", 42));
+        assert_eq!(argv, vec!["code", "-g", "/repo/src/main.rs:42"]);
+    }
+
+    #[test]
+    fn test_build_command_terminal_editor_uses_plus_line() {
+        let argv = build_command("/usr/bin/nvim", &EditorTarget::at_line("plan.md", 7));
+        assert_eq!(argv, vec!["/usr/bin/nvim", "+7", "plan.md"]);
+
+        let argv = build_command("nvim", &EditorTarget::file("plan.md"));
+        assert_eq!(argv, vec!["nvim", "plan.md"]);
+    }
+
+    #[test]
+    fn test_build_command_substitutes_placeholders() {
+        let argv = build_command("hx {file}:{line}", &EditorTarget::file("/repo/design.md"));
+        assert_eq!(argv, vec!["hx", "/repo/design.md:1"]);
+    }
+
+    #[test]
+    fn test_is_gui_editor() {
+        assert!(is_gui_editor("code -g"));
+        assert!(is_gui_editor("/usr/local/bin/zed"));
+        assert!(!is_gui_editor("vim"));
+    }
+
+    #[test]
+    fn test_parse_location_finds_file_and_line() {
+        assert_eq!(
+            parse_location("Unchecked unwrap at `src/sync.rs:118`, please handle"),
+            Some(("src/sync.rs".to_string(), 118))
+        );
+        assert_eq!(parse_location("see https://example.com:443/x"), None);
+        assert_eq!(parse_location("ratio 3:2 is fine"), None);
+    }
+}
@@ -1,7 +1,8 @@
 //! Commit history and statistics
 
-use super::git_command;
+use super::{git_command, git_command_scoped};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use std::path::Path;
 
 /// A single commit in git history
@@ -23,13 +24,11 @@ pub struct CommitSummary {
     pub deletions: usize,
 }
 
-/// Get commits in the given range
-pub fn get_commits(cwd: &Path, range: &str) -> Result<CommitSummary> {
-    // Format: short_hash|hash|subject|author|relative_time
-    let format = "--pretty=format:%h|%H|%s|%an|%ar";
-    let output = git_command(cwd, &["log", format, range])?;
+/// Format: short_hash|hash|subject|author|relative_time
+const LOG_FORMAT: &str = "--pretty=format:%h|%H|%s|%an|%ar";
 
-    let commits: Vec<Commit> = output
+fn parse_log(output: &str) -> Vec<Commit> {
+    output
         .lines()
         .filter(|line| !line.is_empty())
         .map(|line| {
@@ -42,10 +41,25 @@ pub fn get_commits(cwd: &Path, range: &str) -> Result<CommitSummary> {
                 relative_time: parts.get(4).unwrap_or(&"").to_string(),
             }
         })
-        .collect();
+        .collect()
+}
+
+/// Get the most recent `limit` commits reachable from HEAD
+pub fn get_recent_commits(cwd: &Path, limit: usize) -> Result<Vec<Commit>> {
+    let max_count = format!("--max-count={}", limit);
+    let output = git_command(cwd, &["log", LOG_FORMAT, &max_count])?;
+    Ok(parse_log(&output))
+}
+
+/// Get commits in the given range, optionally only those touching a
+/// monorepo subtree
+pub fn get_commits(cwd: &Path, range: &str, scope: Option<&Path>) -> Result<CommitSummary> {
+    // Format: short_hash|hash|subject|author|relative_time
+    let output = git_command_scoped(cwd, &["log", LOG_FORMAT, range], scope)?;
+    let commits = parse_log(&output);
 
     let total_commits = commits.len();
-    let (insertions, deletions) = get_shortstat(cwd, range)?;
+    let (insertions, deletions) = get_shortstat(cwd, range, scope)?;
 
     Ok(CommitSummary {
         commits,
@@ -56,8 +70,8 @@ pub fn get_commits(cwd: &Path, range: &str) -> Result<CommitSummary> {
 }
 
 /// Get shortstat for a range (insertions/deletions)
-fn get_shortstat(cwd: &Path, range: &str) -> Result<(usize, usize)> {
-    let output = git_command(cwd, &["diff", "--shortstat", range])?;
+fn get_shortstat(cwd: &Path, range: &str, scope: Option<&Path>) -> Result<(usize, usize)> {
+    let output = git_command_scoped(cwd, &["diff", "--shortstat", range], scope)?;
 
     if output.trim().is_empty() {
         return Ok((0, 0));
@@ -83,6 +97,154 @@ fn get_shortstat(cwd: &Path, range: &str) -> Result<(usize, usize)> {
     Ok((insertions, deletions))
 }
 
+/// A commit with its committer time, for placing on a timeline
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimedCommit {
+    pub short_hash: String,
+    pub subject: String,
+    pub committed_at: DateTime<Utc>,
+}
+
+/// Format: short_hash|committer_unix_time|subject
+const TIMED_LOG_FORMAT: &str = "--pretty=format:%h|%ct|%s";
+
+fn parse_timed_log(output: &str) -> Vec<TimedCommit> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '|');
+            let short_hash = parts.next()?.to_string();
+            let secs = parts.next()?.parse().ok()?;
+            let subject = parts.next().unwrap_or("").to_string();
+            Some(TimedCommit {
+                short_hash,
+                subject,
+                committed_at: DateTime::from_timestamp(secs, 0)?,
+            })
+        })
+        .collect()
+}
+
+/// Get commits on HEAD committed between `since` and `until`, oldest first
+pub fn get_commits_between(
+    cwd: &Path,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+    scope: Option<&Path>,
+) -> Result<Vec<TimedCommit>> {
+    let since = format!("--since={}", since.to_rfc3339());
+    let until = format!("--until={}", until.to_rfc3339());
+    let output = git_command_scoped(
+        cwd,
+        &["log", "--reverse", TIMED_LOG_FORMAT, &since, &until],
+        scope,
+    )?;
+    Ok(parse_timed_log(&output))
+}
+
+/// A file touched by a single commit
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommitFile {
+    pub path: String,
+    /// Git status letter (A, M, D, T, ...)
+    pub status: char,
+    pub insertions: usize,
+    pub deletions: usize,
+    pub binary: bool,
+}
+
+/// Everything needed to show one commit in detail
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommitDetail {
+    pub hash: String,
+    pub short_hash: String,
+    pub author: String,
+    pub email: String,
+    pub date: String,
+    /// Full commit message, subject and body
+    pub message: String,
+    pub files: Vec<CommitFile>,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+/// Format: hash NUL short_hash NUL author NUL email NUL date NUL message
+const SHOW_FORMAT: &str = "--format=%H%x00%h%x00%an%x00%ae%x00%ai%x00%B";
+
+/// Get the full message, author, and per-file stats for one commit,
+/// optionally only listing files inside a monorepo subtree.
+///
+/// Merge commits are compared against their first parent; root commits
+/// against the empty tree.
+pub fn get_commit_detail(cwd: &Path, hash: &str, scope: Option<&Path>) -> Result<CommitDetail> {
+    let header = git_command(cwd, &["show", "--no-patch", SHOW_FORMAT, hash])?;
+    let mut parts = header.splitn(6, '\0');
+    let mut next = || parts.next().unwrap_or("").to_string();
+    let (hash, short_hash, author, email, date) = (next(), next(), next(), next(), next());
+    let message = next().trim_end().to_string();
+
+    let diff_tree = [
+        "diff-tree",
+        "-r",
+        "--root",
+        "--no-commit-id",
+        "--no-renames",
+        "-m",
+        "--first-parent",
+    ];
+    let mut numstat_args = diff_tree.to_vec();
+    numstat_args.extend(["--numstat", hash.as_str()]);
+    let numstat = git_command_scoped(cwd, &numstat_args, scope)?;
+    let mut status_args = diff_tree.to_vec();
+    status_args.extend(["--name-status", hash.as_str()]);
+    let name_status = git_command_scoped(cwd, &status_args, scope)?;
+
+    let files = parse_commit_files(&numstat, &name_status);
+    let insertions = files.iter().map(|f| f.insertions).sum();
+    let deletions = files.iter().map(|f| f.deletions).sum();
+
+    Ok(CommitDetail {
+        hash,
+        short_hash,
+        author,
+        email,
+        date,
+        message,
+        files,
+        insertions,
+        deletions,
+    })
+}
+
+/// Combine `--numstat` and `--name-status` output (same file order)
+fn parse_commit_files(numstat: &str, name_status: &str) -> Vec<CommitFile> {
+    let statuses: Vec<char> = name_status
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.chars().next().unwrap_or('M'))
+        .collect();
+
+    numstat
+        .lines()
+        .filter(|line| !line.is_empty())
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let mut fields = line.splitn(3, '\t');
+            let added = fields.next()?;
+            let removed = fields.next()?;
+            let path = fields.next()?.to_string();
+            let binary = added == "-" && removed == "-";
+            Some(CommitFile {
+                path,
+                status: statuses.get(i).copied().unwrap_or('M'),
+                insertions: added.parse().unwrap_or(0),
+                deletions: removed.parse().unwrap_or(0),
+                binary,
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,11 +257,20 @@ mod tests {
             .to_path_buf()
     }
 
+    #[test]
+    fn test_parse_timed_log() {
+        let commits = parse_timed_log("abc1234|1770976800|Add timeline | with pipe\nbad line\n");
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].short_hash, "abc1234");
+        assert_eq!(commits[0].subject, "Add timeline | with pipe");
+        assert_eq!(commits[0].committed_at.timestamp(), 1770976800);
+    }
+
     #[test]
     fn test_parse_commit_format() {
         // Test that we can parse the git log format correctly
         let repo = get_test_repo_path();
-        let result = get_commits(&repo, "HEAD~1..HEAD");
+        let result = get_commits(&repo, "HEAD~1..HEAD", None);
 
         assert!(result.is_ok(), "should parse commits successfully");
         let summary = result.unwrap();
@@ -135,7 +306,7 @@ mod tests {
     fn test_parse_shortstat() {
         // Test parsing insertion/deletion stats
         let repo = get_test_repo_path();
-        let result = get_commits(&repo, "HEAD~1..HEAD");
+        let result = get_commits(&repo, "HEAD~1..HEAD", None);
 
         assert!(result.is_ok(), "should get stats successfully");
         let summary = result.unwrap();
@@ -155,7 +326,7 @@ mod tests {
     fn test_empty_range() {
         // Test handling of empty commit range
         let repo = get_test_repo_path();
-        let result = get_commits(&repo, "HEAD..HEAD");
+        let result = get_commits(&repo, "HEAD..HEAD", None);
 
         assert!(result.is_ok(), "should handle empty range gracefully");
         let summary = result.unwrap();
@@ -164,4 +335,43 @@ mod tests {
         assert_eq!(summary.insertions, 0, "insertions should be 0");
         assert_eq!(summary.deletions, 0, "deletions should be 0");
     }
+
+    #[test]
+    fn test_get_recent_commits_respects_limit() {
+        let repo = get_test_repo_path();
+        let commits = get_recent_commits(&repo, 2).expect("should read recent commits");
+
+        assert!(!commits.is_empty(), "should have at least one commit");
+        assert!(commits.len() <= 2, "should honour the limit");
+        assert!(!commits[0].hash.is_empty(), "hash should not be empty");
+    }
+
+    #[test]
+    fn test_parse_commit_files_merges_status_and_counts() {
+        let numstat = "3\t1\tsrc/lib.rs\n-\t-\tlogo.png\n0\t9\told.rs\n";
+        let name_status = "M\tsrc/lib.rs\nA\tlogo.png\nD\told.rs\n";
+        let files = parse_commit_files(numstat, name_status);
+
+        assert_eq!(files.len(), 3);
+        assert_eq!(files[0].path, "src/lib.rs");
+        assert_eq!((files[0].insertions, files[0].deletions), (3, 1));
+        assert_eq!(files[1].status, 'A');
+        assert!(files[1].binary);
+        assert_eq!(files[2].status, 'D');
+        assert_eq!(files[2].deletions, 9);
+    }
+
+    #[test]
+    fn test_get_commit_detail_reads_head() {
+        let repo = get_test_repo_path();
+        let detail = get_commit_detail(&repo, "HEAD", None).expect("should read HEAD");
+
+        assert!(detail.hash.len() >= 40, "full hash should be present");
+        assert!(!detail.author.is_empty(), "author should not be empty");
+        assert!(!detail.message.is_empty(), "message should not be empty");
+        assert_eq!(
+            detail.insertions,
+            detail.files.iter().map(|f| f.insertions).sum::<usize>()
+        );
+    }
 }
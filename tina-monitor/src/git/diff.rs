@@ -1,6 +1,6 @@
 //! Diff statistics for git ranges
 
-use super::git_command;
+use super::{git_command, git_command_scoped};
 use anyhow::Result;
 use std::path::Path;
 
@@ -22,9 +22,23 @@ pub struct DiffStat {
     pub total_deletions: usize,
 }
 
-/// Get detailed diff statistics using --numstat
-pub fn get_diff_stats(cwd: &Path, range: &str) -> Result<DiffStat> {
-    let output = git_command(cwd, &["diff", "--numstat", range])?;
+impl DiffStat {
+    /// Keep only files matching `keep`, recomputing the totals. Returns the
+    /// number of files dropped.
+    pub fn retain_files(&mut self, mut keep: impl FnMut(&FileDiff) -> bool) -> usize {
+        let before = self.files.len();
+        self.files.retain(|f| keep(f));
+        self.files_changed = self.files.len();
+        self.total_insertions = self.files.iter().map(|f| f.insertions).sum();
+        self.total_deletions = self.files.iter().map(|f| f.deletions).sum();
+        before - self.files.len()
+    }
+}
+
+/// Get detailed diff statistics using --numstat, optionally limited to a
+/// monorepo subtree
+pub fn get_diff_stats(cwd: &Path, range: &str, scope: Option<&Path>) -> Result<DiffStat> {
+    let output = git_command_scoped(cwd, &["diff", "--numstat", range], scope)?;
 
     let mut files = Vec::new();
     let mut total_insertions = 0;
@@ -80,9 +94,20 @@ pub fn get_diff_stats(cwd: &Path, range: &str) -> Result<DiffStat> {
     })
 }
 
-/// Get full diff with summary using --stat
-pub fn get_full_diff(cwd: &Path, range: &str) -> Result<String> {
-    git_command(cwd, &["diff", "--stat", range])
+/// Get full diff (patch) with a --stat summary
+pub fn get_full_diff(cwd: &Path, range: &str, scope: Option<&Path>) -> Result<String> {
+    git_command_scoped(cwd, &["diff", "--stat", "--patch", range], scope)
+}
+
+/// Get full diff (patch) with a --stat summary for just `files`
+pub fn get_full_diff_for_files(cwd: &Path, range: &str, files: &[&str]) -> Result<String> {
+    if files.is_empty() {
+        // A bare `--` would diff everything
+        return Ok(String::new());
+    }
+    let mut args = vec!["diff", "--stat", "--patch", range, "--"];
+    args.extend_from_slice(files);
+    git_command(cwd, &args)
 }
 
 #[cfg(test)]
@@ -101,7 +126,7 @@ mod tests {
     fn test_parse_numstat() {
         // Test parsing git diff --numstat output
         let repo = get_test_repo_path();
-        let result = get_diff_stats(&repo, "HEAD~1..HEAD");
+        let result = get_diff_stats(&repo, "HEAD~1..HEAD", None);
 
         assert!(result.is_ok(), "should parse numstat successfully");
         let stats = result.unwrap();
@@ -140,7 +165,7 @@ mod tests {
     fn test_empty_range() {
         // Test handling of empty diff range
         let repo = get_test_repo_path();
-        let result = get_diff_stats(&repo, "HEAD..HEAD");
+        let result = get_diff_stats(&repo, "HEAD..HEAD", None);
 
         assert!(result.is_ok(), "should handle empty range gracefully");
         let stats = result.unwrap();
@@ -154,7 +179,7 @@ mod tests {
     fn test_full_diff_output() {
         // Test that full diff returns proper --stat output
         let repo = get_test_repo_path();
-        let result = get_full_diff(&repo, "HEAD~1..HEAD");
+        let result = get_full_diff(&repo, "HEAD~1..HEAD", None);
 
         assert!(result.is_ok(), "should get full diff successfully");
         let diff = result.unwrap();
@@ -164,6 +189,52 @@ mod tests {
         assert_eq!(diff, diff, "diff output should be valid string");
     }
 
+    #[test]
+    fn test_scope_limits_files() {
+        let repo = get_test_repo_path();
+        let scope = Path::new("tina-monitor");
+        let stats = get_diff_stats(&repo, "HEAD~1..HEAD", Some(scope)).unwrap();
+
+        assert!(
+            stats
+                .files
+                .iter()
+                .all(|f| f.path.starts_with("tina-monitor/")),
+            "scoped diff should only include files under the scope"
+        );
+    }
+
+    #[test]
+    fn test_retain_files_recomputes_totals() {
+        let file = |path: &str, insertions, deletions| FileDiff {
+            path: path.to_string(),
+            insertions,
+            deletions,
+            is_binary: false,
+        };
+        let mut stats = DiffStat {
+            files: vec![file("src/a.rs", 3, 1), file("gen/b.pb.rs", 500, 200)],
+            files_changed: 2,
+            total_insertions: 503,
+            total_deletions: 201,
+        };
+
+        let dropped = stats.retain_files(|f| !f.path.starts_with("gen/"));
+        assert_eq!(dropped, 1);
+        assert_eq!(stats.files_changed, 1);
+        assert_eq!(stats.total_insertions, 3);
+        assert_eq!(stats.total_deletions, 1);
+    }
+
+    #[test]
+    fn test_full_diff_for_no_files_is_empty() {
+        let repo = get_test_repo_path();
+        assert_eq!(
+            get_full_diff_for_files(&repo, "HEAD~1..HEAD", &[]).unwrap(),
+            ""
+        );
+    }
+
     #[test]
     fn test_binary_file_detection() {
         // Test that binary files are detected correctly from numstat output
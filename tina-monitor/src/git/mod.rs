@@ -5,6 +5,7 @@
 
 pub mod commits;
 pub mod diff;
+pub mod status;
 
 use anyhow::{Context, Result};
 use std::path::Path;
@@ -28,6 +29,16 @@ pub fn git_command(cwd: &Path, args: &[&str]) -> Result<String> {
     Ok(String::from_utf8_lossy(&output.stdout).to_string())
 }
 
+/// Execute a git command limited to a monorepo subtree (`-- <scope>`)
+pub fn git_command_scoped(cwd: &Path, args: &[&str], scope: Option<&Path>) -> Result<String> {
+    let scope = scope.map(|s| s.to_string_lossy());
+    let mut args = args.to_vec();
+    if let Some(scope) = scope.as_deref() {
+        args.extend(["--", scope]);
+    }
+    git_command(cwd, &args)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
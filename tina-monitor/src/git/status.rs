@@ -0,0 +1,210 @@
+//! Working-tree status: uncommitted and untracked files plus how far the
+//! branch is ahead of or behind its upstream
+
+use anyhow::Result;
+use std::path::Path;
+
+use super::git_command;
+
+/// One changed path, as git's two-letter status code
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusEntry {
+    /// Index (staged) state, `.` when unchanged
+    pub index: char,
+    /// Working-tree (unstaged) state, `.` when unchanged
+    pub worktree: char,
+    pub path: String,
+}
+
+impl StatusEntry {
+    pub fn is_untracked(&self) -> bool {
+        self.index == '?'
+    }
+
+    pub fn is_conflicted(&self) -> bool {
+        self.index == 'U' || self.worktree == 'U'
+    }
+
+    /// Short-format code, e.g. `M `, ` M`, `??`
+    pub fn code(&self) -> String {
+        [self.index, self.worktree]
+            .iter()
+            .map(|c| if *c == '.' { ' ' } else { *c })
+            .collect()
+    }
+}
+
+/// Branch tracking and changed files of one worktree
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WorktreeStatus {
+    /// Checked-out branch; `None` when HEAD is detached
+    pub branch: Option<String>,
+    pub upstream: Option<String>,
+    /// Commits not yet on the upstream
+    pub ahead: usize,
+    /// Upstream commits not yet merged
+    pub behind: usize,
+    pub entries: Vec<StatusEntry>,
+}
+
+impl WorktreeStatus {
+    /// Files with staged changes
+    pub fn staged(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| !e.is_untracked() && !e.is_conflicted() && e.index != '.')
+            .count()
+    }
+
+    /// Tracked files with unstaged changes
+    pub fn modified(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| !e.is_untracked() && !e.is_conflicted() && e.worktree != '.')
+            .count()
+    }
+
+    pub fn untracked(&self) -> usize {
+        self.entries.iter().filter(|e| e.is_untracked()).count()
+    }
+
+    pub fn conflicted(&self) -> usize {
+        self.entries.iter().filter(|e| e.is_conflicted()).count()
+    }
+
+    /// Whether there is nothing uncommitted or untracked
+    pub fn is_clean(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Parse `git status --porcelain=v2 --branch` output
+pub fn parse_status(output: &str) -> WorktreeStatus {
+    let mut status = WorktreeStatus::default();
+    for line in output.lines() {
+        if let Some(header) = line.strip_prefix("# ") {
+            let (key, value) = header.split_once(' ').unwrap_or((header, ""));
+            match key {
+                "branch.head" if value != "(detached)" => status.branch = Some(value.to_string()),
+                "branch.upstream" => status.upstream = Some(value.to_string()),
+                "branch.ab" => {
+                    for count in value.split_whitespace() {
+                        if let Some(n) = count.strip_prefix('+') {
+                            status.ahead = n.parse().unwrap_or(0);
+                        } else if let Some(n) = count.strip_prefix('-') {
+                            status.behind = n.parse().unwrap_or(0);
+                        }
+                    }
+                }
+                _ => {}
+            }
+            continue;
+        }
+        // Ordinary, renamed and unmerged entries put the path after a fixed
+        // number of fields; renames add "<path>\t<original path>"
+        let (fields, entry) = match line.split_once(' ') {
+            Some(("1", rest)) => (7, rest),
+            Some(("2", rest)) => (8, rest),
+            Some(("u", rest)) => (9, rest),
+            Some(("?", path)) => {
+                status.entries.push(StatusEntry {
+                    index: '?',
+                    worktree: '?',
+                    path: path.to_string(),
+                });
+                continue;
+            }
+            _ => continue,
+        };
+        let mut parts = entry.splitn(fields + 1, ' ');
+        let mut xy = parts.next().unwrap_or("..").chars();
+        let Some(path) = parts.nth(fields - 1) else {
+            continue;
+        };
+        let path = match path.split_once('\t') {
+            Some((path, from)) => format!("{} <- {}", path, from),
+            None => path.to_string(),
+        };
+        status.entries.push(StatusEntry {
+            index: xy.next().unwrap_or('.'),
+            worktree: xy.next().unwrap_or('.'),
+            path,
+        });
+    }
+    status
+}
+
+/// Status of the worktree at `cwd`, untracked files listed individually
+pub fn get_worktree_status(cwd: &Path) -> Result<WorktreeStatus> {
+    let output = git_command(
+        cwd,
+        &[
+            "status",
+            "--porcelain=v2",
+            "--branch",
+            "--untracked-files=all",
+        ],
+    )?;
+    Ok(parse_status(&output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str = "\
+# branch.oid 1234567890abcdef1234567890abcdef12345678
+# branch.head tina/auth
+# branch.upstream origin/tina/auth
+# branch.ab +2 -1
+1 M. N... 100644 100644 100644 aaaa bbbb src/lib.rs
+1 .M N... 100644 100644 100644 aaaa bbbb src/main file.rs
+1 MM N... 100644 100644 100644 aaaa bbbb Cargo.toml
+2 R. N... 100644 100644 100644 aaaa bbbb R100 src/new.rs\tsrc/old.rs
+u UU N... 100644 100644 100644 100644 aaaa bbbb cccc src/conflict.rs
+? notes/todo.md
+";
+
+    #[test]
+    fn test_parse_status_branch_and_counts() {
+        let status = parse_status(OUTPUT);
+        assert_eq!(status.branch.as_deref(), Some("tina/auth"));
+        assert_eq!(status.upstream.as_deref(), Some("origin/tina/auth"));
+        assert_eq!((status.ahead, status.behind), (2, 1));
+        assert_eq!(status.entries.len(), 6);
+        assert_eq!(status.staged(), 3);
+        assert_eq!(status.modified(), 2);
+        assert_eq!(status.untracked(), 1);
+        assert_eq!(status.conflicted(), 1);
+        assert!(!status.is_clean());
+    }
+
+    #[test]
+    fn test_parse_status_paths_and_codes() {
+        let status = parse_status(OUTPUT);
+        let entries: Vec<(String, &str)> = status
+            .entries
+            .iter()
+            .map(|e| (e.code(), e.path.as_str()))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                ("M ".to_string(), "src/lib.rs"),
+                (" M".to_string(), "src/main file.rs"),
+                ("MM".to_string(), "Cargo.toml"),
+                ("R ".to_string(), "src/new.rs <- src/old.rs"),
+                ("UU".to_string(), "src/conflict.rs"),
+                ("??".to_string(), "notes/todo.md"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_status_clean_detached() {
+        let status = parse_status("# branch.oid abc\n# branch.head (detached)\n");
+        assert_eq!(status.branch, None);
+        assert_eq!(status.upstream, None);
+        assert!(status.is_clean());
+    }
+}
@@ -8,8 +8,12 @@ pub mod actions;
 pub mod app;
 pub mod cli;
 pub mod config;
+pub mod control;
+pub mod daemon;
 pub mod dashboard;
 pub mod data;
+pub mod desktop_notify;
+pub mod editor;
 pub mod entity;
 pub mod git;
 pub mod layout;
@@ -17,11 +21,12 @@ pub mod logging;
 pub mod overlay;
 pub mod panel;
 pub mod panels;
+pub mod report;
 pub mod terminal;
+pub mod theme;
 pub mod tmux;
 pub mod tui;
 pub mod types;
-pub mod watcher;
 
 /// Filter for task status in task listings
 #[derive(Debug, Clone, Copy, ValueEnum)]
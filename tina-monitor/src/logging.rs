@@ -42,12 +42,79 @@ impl CommandLogger {
             .append(true)
             .open(&self.log_path)?;
 
-        // Format: ISO 8601 timestamp, target pane, command
+        // Format: ISO 8601 timestamp, target pane, command (one line)
         let timestamp = Utc::now().to_rfc3339();
-        let log_entry = format!("{} [{}] {}\n", timestamp, target, command);
+        let log_entry = format!("{} [{}] {}\n", timestamp, target, escape(command));
 
         file.write_all(log_entry.as_bytes())?;
 
         Ok(())
     }
+
+    /// Commands previously sent to `agent`, most recent first, without
+    /// duplicates and capped at [`HISTORY_LIMIT`]
+    pub fn agent_history(&self, agent: &str) -> Vec<String> {
+        let Ok(content) = fs::read_to_string(&self.log_path) else {
+            return Vec::new();
+        };
+        let mut history: Vec<String> = Vec::new();
+        for (target, command) in content.lines().rev().filter_map(parse_line) {
+            let sent_to = target.rsplit_once('@').map(|(name, _)| name);
+            if sent_to != Some(agent) {
+                continue;
+            }
+            let command = unescape(command);
+            if history.contains(&command) {
+                continue;
+            }
+            history.push(command);
+            if history.len() == HISTORY_LIMIT {
+                break;
+            }
+        }
+        history
+    }
+}
+
+/// Most commands recalled per agent
+pub const HISTORY_LIMIT: usize = 100;
+
+/// Log target for a command sent to `agent` in `pane_id`, so history can be
+/// recalled by agent even after its pane changes
+pub fn agent_target(agent: &str, pane_id: &str) -> String {
+    format!("{}@{}", agent, pane_id)
+}
+
+/// Split a log line into its target and command
+fn parse_line(line: &str) -> Option<(&str, &str)> {
+    let (_timestamp, rest) = line.split_once(' ')?;
+    rest.strip_prefix('[')?.split_once("] ")
+}
+
+/// Escape backslashes and newlines so a multi-line command stays on one
+/// log line
+fn escape(command: &str) -> String {
+    command.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+/// Reverse [`escape`]
+fn unescape(logged: &str) -> String {
+    let mut command = String::with_capacity(logged.len());
+    let mut chars = logged.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            command.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => command.push('\n'),
+            Some('\\') => command.push('\\'),
+            Some(other) => {
+                command.push('\\');
+                command.push(other);
+            }
+            None => command.push('\\'),
+        }
+    }
+    command
 }
@@ -1,7 +1,7 @@
 use clap::{Parser, Subcommand, ValueEnum};
 use std::process::ExitCode;
 
-use tina_monitor::{cli, config::Config, TaskStatusFilter, TeamFilter};
+use tina_monitor::{cli, config::Config, theme, TaskStatusFilter, TeamFilter};
 
 #[derive(Parser)]
 #[command(name = "tina-monitor")]
@@ -10,6 +10,9 @@ use tina_monitor::{cli, config::Config, TaskStatusFilter, TeamFilter};
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+    /// Render without colors, marking state with text (also set by NO_COLOR)
+    #[arg(long, global = true)]
+    no_color: bool,
 }
 
 #[derive(Subcommand)]
@@ -23,7 +26,10 @@ enum Commands {
     Teams {
         /// Output format
         #[arg(long, value_enum, default_value = "text")]
-        format: OutputFormat,
+        format: ListFormat,
+        /// Columns for table/csv output, comma-separated (e.g. id,status)
+        #[arg(long)]
+        columns: Option<String>,
         /// Filter by type
         #[arg(long, value_enum)]
         filter: Option<TeamFilter>,
@@ -34,11 +40,77 @@ enum Commands {
         team_name: String,
         /// Output format
         #[arg(long, value_enum, default_value = "text")]
-        format: OutputFormat,
+        format: ListFormat,
+        /// Columns for table/csv output, comma-separated (e.g. id,status)
+        #[arg(long)]
+        columns: Option<String>,
         /// Filter by status
         #[arg(long, value_enum)]
         status: Option<TaskStatusFilter>,
     },
+    /// Launch the TUI in the multi-orchestration dashboard (wall display)
+    Dashboard,
+    /// Watch for status changes, phase and task completions, commits, blocks,
+    /// and pending gates without the TUI
+    Watch {
+        /// Seconds between polls
+        #[arg(long, default_value_t = 5)]
+        interval: u64,
+        /// Send desktop notifications even if disabled in config.toml
+        #[arg(long)]
+        desktop: bool,
+        /// Only watch this orchestration (feature name)
+        #[arg(long)]
+        feature: Option<String>,
+        /// Output format
+        #[arg(long, value_enum, default_value = "text")]
+        format: WatchFormat,
+    },
+    /// Render an orchestration report (phases, tasks, team, commits, review, cost)
+    Export {
+        /// Orchestration (feature) name
+        name: String,
+        /// Report format
+        #[arg(long, value_enum, default_value = "markdown")]
+        format: ReportFormat,
+        /// Write to this file instead of stdout
+        #[arg(long, short)]
+        output: Option<std::path::PathBuf>,
+    },
+    /// Save an agent's full tmux pane history to a timestamped file
+    Capture {
+        /// Orchestration (feature) name
+        name: String,
+        /// Agent (team member) name
+        agent: String,
+        /// Directory to write to instead of `[capture] dir` from config.toml
+        #[arg(long)]
+        dir: Option<std::path::PathBuf>,
+    },
+    /// Jump to an orchestration's tmux session, a phase's, or an agent's pane
+    Attach {
+        /// Orchestration (feature) name
+        feature: String,
+        /// Agent (team member) name
+        #[arg(long, conflicts_with = "phase")]
+        agent: Option<String>,
+        /// Phase number, e.g. 2 or 1.5
+        #[arg(long)]
+        phase: Option<String>,
+    },
+    /// Snapshot an orchestration into a fixture directory for offline work
+    RecordFixture {
+        /// Orchestration (feature) name
+        #[arg(long)]
+        feature: String,
+        /// Fixture directory to write
+        #[arg(long, short)]
+        output: std::path::PathBuf,
+    },
+    /// Check config, Convex, tmux, terminal handler and daemon, with fixes
+    Doctor,
+    /// Set up config.toml interactively (runs automatically on first launch)
+    Setup,
 }
 
 #[derive(Subcommand)]
@@ -50,9 +122,14 @@ enum StatusEntity {
         /// Output format
         #[arg(long, value_enum, default_value = "text")]
         format: OutputFormat,
-        /// Check condition and exit with code
-        #[arg(long, value_enum)]
-        check: Option<CheckCondition>,
+        /// Columns for table/csv output, comma-separated (e.g. id,status)
+        #[arg(long)]
+        columns: Option<String>,
+        /// Exit 0 if the condition holds, 1 if not, 2 if not and blocked,
+        /// 3 if it names a missing phase or gate. One of complete, blocked,
+        /// executing, phase-complete=N, gate-pending=GATE, tasks-remaining<=N
+        #[arg(long)]
+        check: Option<cli::CheckCondition>,
     },
     /// Get orchestration status
     Orchestration {
@@ -61,9 +138,17 @@ enum StatusEntity {
         /// Output format
         #[arg(long, value_enum, default_value = "text")]
         format: OutputFormat,
-        /// Check condition and exit with code
-        #[arg(long, value_enum)]
-        check: Option<CheckCondition>,
+        /// Columns for table/csv output, comma-separated (e.g. id,status)
+        #[arg(long)]
+        columns: Option<String>,
+        /// Exit 0 if the condition holds, 1 if not, 2 if not and blocked,
+        /// 3 if it names a missing phase or gate. One of complete, blocked,
+        /// executing, phase-complete=N, gate-pending=GATE, tasks-remaining<=N
+        #[arg(long)]
+        check: Option<cli::CheckCondition>,
+        /// Show absolute timestamps alongside relative times
+        #[arg(long, short)]
+        verbose: bool,
     },
     /// Get task status
     Task {
@@ -74,6 +159,9 @@ enum StatusEntity {
         /// Output format
         #[arg(long, value_enum, default_value = "text")]
         format: OutputFormat,
+        /// Columns for table/csv output, comma-separated (e.g. id,status)
+        #[arg(long)]
+        columns: Option<String>,
     },
 }
 
@@ -81,13 +169,30 @@ enum StatusEntity {
 enum OutputFormat {
     Text,
     Json,
+    Table,
+    Csv,
 }
 
+/// `OutputFormat` plus `jsonl`, for commands that print a listing
 #[derive(Debug, Clone, Copy, ValueEnum)]
-enum CheckCondition {
-    Complete,
-    Blocked,
-    Executing,
+enum ListFormat {
+    Text,
+    Json,
+    Jsonl,
+    Table,
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum WatchFormat {
+    Text,
+    Jsonl,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum ReportFormat {
+    Markdown,
+    Html,
 }
 
 impl From<OutputFormat> for cli::OutputFormat {
@@ -95,16 +200,38 @@ impl From<OutputFormat> for cli::OutputFormat {
         match f {
             OutputFormat::Text => cli::OutputFormat::Text,
             OutputFormat::Json => cli::OutputFormat::Json,
+            OutputFormat::Table => cli::OutputFormat::Table,
+            OutputFormat::Csv => cli::OutputFormat::Csv,
         }
     }
 }
 
-impl From<CheckCondition> for cli::CheckCondition {
-    fn from(c: CheckCondition) -> Self {
-        match c {
-            CheckCondition::Complete => cli::CheckCondition::Complete,
-            CheckCondition::Blocked => cli::CheckCondition::Blocked,
-            CheckCondition::Executing => cli::CheckCondition::Executing,
+impl From<ListFormat> for cli::OutputFormat {
+    fn from(f: ListFormat) -> Self {
+        match f {
+            ListFormat::Text => cli::OutputFormat::Text,
+            ListFormat::Json => cli::OutputFormat::Json,
+            ListFormat::Jsonl => cli::OutputFormat::Jsonl,
+            ListFormat::Table => cli::OutputFormat::Table,
+            ListFormat::Csv => cli::OutputFormat::Csv,
+        }
+    }
+}
+
+impl From<WatchFormat> for cli::watch::WatchFormat {
+    fn from(f: WatchFormat) -> Self {
+        match f {
+            WatchFormat::Text => cli::watch::WatchFormat::Text,
+            WatchFormat::Jsonl => cli::watch::WatchFormat::Jsonl,
+        }
+    }
+}
+
+impl From<ReportFormat> for tina_monitor::report::ReportFormat {
+    fn from(f: ReportFormat) -> Self {
+        match f {
+            ReportFormat::Markdown => tina_monitor::report::ReportFormat::Markdown,
+            ReportFormat::Html => tina_monitor::report::ReportFormat::Html,
         }
     }
 }
@@ -120,35 +247,99 @@ fn main() -> ExitCode {
 }
 
 fn run() -> anyhow::Result<i32> {
-    // Load configuration at startup
-    let _config = Config::load()?;
-
     let cli_args = Cli::parse();
 
+    // Installed before the TUI resolves `[theme]`, so this wins over it
+    if cli_args.no_color || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+        theme::init(theme::Theme::plain());
+    }
+
+    // The TUI is unusable without a Convex URL, so ask for one on first
+    // launch rather than starting empty
+    if matches!(cli_args.command, None | Some(Commands::Dashboard)) && cli::setup::needs_first_run()
+    {
+        cli::setup::run_first_time()?;
+    }
+
+    // Load configuration at startup
+    let config = Config::load()?;
+    tina_data::humanize::init_display_timezone(config.display.timezone);
+
     match cli_args.command {
         Some(Commands::Status { entity }) => match entity {
             StatusEntity::Team {
                 name,
                 format,
+                columns,
                 check,
-            } => cli::status_team(&name, format.into(), check.map(Into::into)),
+            } => cli::status_team(&name, format.into(), columns.as_deref(), check),
             StatusEntity::Orchestration {
                 name,
                 format,
+                columns,
                 check,
-            } => cli::status_orchestration(&name, format.into(), check.map(Into::into)),
+                verbose,
+            } => {
+                cli::status_orchestration(&name, format.into(), columns.as_deref(), check, verbose)
+            }
             StatusEntity::Task {
                 team_name,
                 task_id,
                 format,
-            } => cli::status_task(&team_name, &task_id, format.into()),
+                columns,
+            } => cli::status_task(&team_name, &task_id, format.into(), columns.as_deref()),
         },
-        Some(Commands::Teams { format, filter }) => cli::teams::list_teams(format.into(), filter),
+        Some(Commands::Teams {
+            format,
+            columns,
+            filter,
+        }) => cli::teams::list_teams(format.into(), columns.as_deref(), filter),
         Some(Commands::Tasks {
             team_name,
             format,
+            columns,
             status,
-        }) => cli::tasks::list_tasks(&team_name, format.into(), status),
+        }) => cli::tasks::list_tasks(&team_name, format.into(), columns.as_deref(), status),
+        Some(Commands::Watch {
+            interval,
+            desktop,
+            feature,
+            format,
+        }) => cli::watch::watch(
+            std::time::Duration::from_secs(interval),
+            desktop,
+            feature.as_deref(),
+            format.into(),
+        ),
+        Some(Commands::Export {
+            name,
+            format,
+            output,
+        }) => cli::export::export(&name, format.into(), output.as_deref()),
+        Some(Commands::Capture { name, agent, dir }) => {
+            cli::capture::capture(&name, &agent, dir.as_deref())
+        }
+        Some(Commands::Attach {
+            feature,
+            agent,
+            phase,
+        }) => {
+            let target = match (agent.as_deref(), phase.as_deref()) {
+                (Some(agent), _) => cli::attach::AttachTarget::Agent(agent),
+                (None, Some(phase)) => cli::attach::AttachTarget::Phase(phase),
+                (None, None) => cli::attach::AttachTarget::Orchestration,
+            };
+            cli::attach::attach(&feature, target)
+        }
+        Some(Commands::RecordFixture { feature, output }) => {
+            cli::record_fixture::record_fixture(&feature, &output)
+        }
+        Some(Commands::Doctor) => cli::doctor::doctor(),
+        Some(Commands::Setup) => cli::setup::setup(),
+        Some(Commands::Dashboard) => {
+            tina_monitor::tui::run_dashboard().map_err(|e| anyhow::anyhow!("{}", e))?;
+            Ok(0)
+        }
         None => {
             // No command = launch TUI
             tina_monitor::tui::run().map_err(|e| anyhow::anyhow!("{}", e))?;
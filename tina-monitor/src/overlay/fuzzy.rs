@@ -1,9 +1,10 @@
-//! Fuzzy finder overlay for searching orchestrations
+//! Fuzzy finder overlays for searching orchestrations and loaded data
 
 use super::centered_rect;
 // Re-export for use by app.rs
+use crate::theme;
 pub use crate::types::OrchestrationSummary;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
@@ -93,7 +94,7 @@ pub fn render(state: &FuzzyState, frame: &mut Frame) {
                 .title(" Find Orchestration ")
                 .title_alignment(Alignment::Center),
         )
-        .style(Style::default().fg(Color::Yellow));
+        .style(Style::default().fg(theme::current().highlight));
     frame.render_widget(input, chunks[0]);
 
     // Results list
@@ -103,19 +104,19 @@ pub fn render(state: &FuzzyState, frame: &mut Frame) {
             ListItem::new(Line::from("")),
             ListItem::new(Line::from(Span::styled(
                 "  No orchestrations found",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme::current().muted),
             ))),
             ListItem::new(Line::from("")),
             ListItem::new(Line::from(Span::styled(
                 "  Start an orchestration with tina-session",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme::current().muted),
             ))),
         ]
     } else if state.filtered.is_empty() {
         // Filter returned no results
         vec![ListItem::new(Line::from(Span::styled(
             "  No matches",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme::current().muted),
         )))]
     } else {
         state
@@ -133,7 +134,7 @@ pub fn render(state: &FuzzyState, frame: &mut Frame) {
                     Span::styled(&item.feature, style),
                     Span::styled(
                         format!(" ({:?})", item.status),
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(theme::current().muted),
                     ),
                 ]))
             })
@@ -183,10 +184,283 @@ pub fn handle_key(state: &mut FuzzyState, key: KeyEvent) -> FuzzyResult {
     }
 }
 
+/// Kind of entity a global search result points at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchKind {
+    Orchestration,
+    Task,
+    Agent,
+    Commit,
+}
+
+impl SearchKind {
+    fn label(self) -> &'static str {
+        match self {
+            SearchKind::Orchestration => "orch",
+            SearchKind::Task => "task",
+            SearchKind::Agent => "agent",
+            SearchKind::Commit => "commit",
+        }
+    }
+
+    fn color(self) -> Color {
+        let theme = theme::current();
+        match self {
+            SearchKind::Orchestration => theme.accent,
+            SearchKind::Task => theme.success,
+            SearchKind::Agent => theme.secondary,
+            SearchKind::Commit => theme.highlight,
+        }
+    }
+}
+
+/// Where selecting a global search result should jump to
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchTarget {
+    Orchestration {
+        orch_index: usize,
+    },
+    Task {
+        orch_index: usize,
+        task_index: usize,
+    },
+    Agent {
+        orch_index: usize,
+        member_index: usize,
+    },
+    Commit {
+        orch_index: usize,
+        hash: String,
+        short_hash: String,
+        subject: String,
+    },
+}
+
+/// A single searchable entry in the global search overlay
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchItem {
+    pub kind: SearchKind,
+    /// Primary text that the query is matched against
+    pub label: String,
+    /// Secondary context (orchestration name, status, ...), also matched
+    pub detail: String,
+    pub target: SearchTarget,
+}
+
+/// State for the global search overlay (orchestrations, tasks, agents, commits)
+#[derive(Debug)]
+pub struct GlobalSearchState {
+    pub query: String,
+    pub selected: usize,
+    pub items: Vec<SearchItem>,
+    pub filtered: Vec<usize>, // Indices into items, best match first
+}
+
+impl GlobalSearchState {
+    pub fn new(items: Vec<SearchItem>) -> Self {
+        let filtered: Vec<usize> = (0..items.len()).collect();
+        Self {
+            query: String::new(),
+            selected: 0,
+            items,
+            filtered,
+        }
+    }
+
+    /// Re-rank items against the current query
+    pub fn update_filter(&mut self) {
+        self.selected = 0;
+        if self.query.is_empty() {
+            self.filtered = (0..self.items.len()).collect();
+            return;
+        }
+
+        let mut scored: Vec<(usize, i64)> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let label = fuzzy_score(&self.query, &item.label);
+                let detail = fuzzy_score(&self.query, &item.detail).map(|s| s - 50);
+                label.max(detail).map(|score| (i, score))
+            })
+            .collect();
+        // Stable sort keeps the original grouping for equal scores
+        scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+        self.filtered = scored.into_iter().map(|(i, _)| i).collect();
+    }
+
+    /// Get the currently selected item
+    pub fn selected_item(&self) -> Option<&SearchItem> {
+        self.filtered.get(self.selected).map(|&i| &self.items[i])
+    }
+}
+
+/// Score `text` against `query` as a case-insensitive subsequence match.
+///
+/// Returns `None` when the query characters do not all appear in order.
+/// Consecutive runs and matches at word starts score higher; gaps cost.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+
+    let mut score = 0i64;
+    let mut qi = 0;
+    let mut last_match: Option<usize> = None;
+    for (ti, &c) in text.iter().enumerate() {
+        if qi == query.len() {
+            break;
+        }
+        if c != query[qi] {
+            continue;
+        }
+        score += 10;
+        match last_match {
+            Some(prev) if prev + 1 == ti => score += 15,
+            Some(prev) => score -= (ti - prev - 1).min(10) as i64,
+            None => score -= ti.min(10) as i64,
+        }
+        if ti == 0 || !text[ti - 1].is_alphanumeric() {
+            score += 10;
+        }
+        last_match = Some(ti);
+        qi += 1;
+    }
+
+    (qi == query.len()).then_some(score)
+}
+
+/// Result of handling a key in the global search overlay
+#[derive(Debug, PartialEq)]
+pub enum GlobalSearchResult {
+    /// Close the overlay
+    Close,
+    /// Key was consumed but no action needed
+    Consumed,
+    /// Jump to the selected result
+    Jump(SearchTarget),
+}
+
+/// Render the global search overlay
+pub fn render_global(state: &GlobalSearchState, frame: &mut Frame) {
+    let area = centered_rect(70, 70, frame.area());
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Input
+            Constraint::Min(5),    // Results
+        ])
+        .split(area);
+
+    let input = Paragraph::new(format!("/ {}_", state.query))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Search orchestrations, tasks, agents, commits ")
+                .title_alignment(Alignment::Center),
+        )
+        .style(Style::default().fg(theme::current().highlight));
+    frame.render_widget(input, chunks[0]);
+
+    let items: Vec<ListItem> = if state.filtered.is_empty() {
+        let message = if state.items.is_empty() {
+            "  Nothing loaded to search"
+        } else {
+            "  No matches"
+        };
+        vec![ListItem::new(Line::from(Span::styled(
+            message,
+            Style::default().fg(theme::current().muted),
+        )))]
+    } else {
+        state
+            .filtered
+            .iter()
+            .enumerate()
+            .map(|(i, &idx)| {
+                let item = &state.items[idx];
+                let style = if i == state.selected {
+                    Style::default().add_modifier(Modifier::REVERSED)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<7}", item.kind.label()),
+                        Style::default().fg(item.kind.color()),
+                    ),
+                    Span::styled(item.label.as_str(), style),
+                    Span::styled(
+                        format!("  {}", item.detail),
+                        Style::default().fg(theme::current().muted),
+                    ),
+                ]))
+            })
+            .collect()
+    };
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} results ", state.filtered.len())),
+    );
+    frame.render_widget(list, chunks[1]);
+}
+
+/// Handle key input for the global search overlay.
+///
+/// Plain characters always go to the query, so navigation uses the arrow
+/// keys or Ctrl-n / Ctrl-p.
+pub fn handle_global_key(state: &mut GlobalSearchState, key: KeyEvent) -> GlobalSearchResult {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc => GlobalSearchResult::Close,
+        KeyCode::Enter => match state.selected_item() {
+            Some(item) => GlobalSearchResult::Jump(item.target.clone()),
+            None => GlobalSearchResult::Close,
+        },
+        KeyCode::Up => {
+            state.selected = state.selected.saturating_sub(1);
+            GlobalSearchResult::Consumed
+        }
+        KeyCode::Char('p') if ctrl => {
+            state.selected = state.selected.saturating_sub(1);
+            GlobalSearchResult::Consumed
+        }
+        KeyCode::Down => {
+            if state.selected + 1 < state.filtered.len() {
+                state.selected += 1;
+            }
+            GlobalSearchResult::Consumed
+        }
+        KeyCode::Char('n') if ctrl => {
+            if state.selected + 1 < state.filtered.len() {
+                state.selected += 1;
+            }
+            GlobalSearchResult::Consumed
+        }
+        KeyCode::Char(c) if !ctrl => {
+            state.query.push(c);
+            state.update_filter();
+            GlobalSearchResult::Consumed
+        }
+        KeyCode::Backspace => {
+            state.query.pop();
+            state.update_filter();
+            GlobalSearchResult::Consumed
+        }
+        _ => GlobalSearchResult::Consumed,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crossterm::event::KeyModifiers;
 
     fn make_key(code: KeyCode) -> KeyEvent {
         KeyEvent::new(code, KeyModifiers::NONE)
@@ -382,4 +656,121 @@ mod tests {
         assert!(result.is_ok());
         assert!(state.filtered.is_empty());
     }
+    fn create_search_items() -> Vec<SearchItem> {
+        vec![
+            SearchItem {
+                kind: SearchKind::Orchestration,
+                label: "auth-feature".to_string(),
+                detail: "Executing".to_string(),
+                target: SearchTarget::Orchestration { orch_index: 0 },
+            },
+            SearchItem {
+                kind: SearchKind::Task,
+                label: "Add login endpoint".to_string(),
+                detail: "auth-feature".to_string(),
+                target: SearchTarget::Task {
+                    orch_index: 0,
+                    task_index: 0,
+                },
+            },
+            SearchItem {
+                kind: SearchKind::Agent,
+                label: "worker-1".to_string(),
+                detail: "payment-system".to_string(),
+                target: SearchTarget::Agent {
+                    orch_index: 1,
+                    member_index: 0,
+                },
+            },
+            SearchItem {
+                kind: SearchKind::Commit,
+                label: "abc1234 Fix token refresh".to_string(),
+                detail: "auth-feature".to_string(),
+                target: SearchTarget::Commit {
+                    orch_index: 0,
+                    hash: "abc1234def".to_string(),
+                    short_hash: "abc1234".to_string(),
+                    subject: "Fix token refresh".to_string(),
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn fuzzy_score_matches_subsequence() {
+        assert!(fuzzy_score("afe", "auth-feature").is_some());
+        assert!(fuzzy_score("AUTH", "auth-feature").is_some());
+        assert!(fuzzy_score("xyz", "auth-feature").is_none());
+        assert!(fuzzy_score("tua", "auth").is_none());
+    }
+
+    #[test]
+    fn fuzzy_score_prefers_contiguous_matches() {
+        let contiguous = fuzzy_score("login", "Add login endpoint").unwrap();
+        let scattered = fuzzy_score("login", "long gap in name").unwrap();
+        assert!(contiguous > scattered);
+    }
+
+    #[test]
+    fn global_filter_ranks_label_matches_first() {
+        let mut state = GlobalSearchState::new(create_search_items());
+        state.query = "auth".to_string();
+        state.update_filter();
+
+        // Every item mentioning auth-feature matches, the label hit ranks first
+        assert_eq!(state.filtered.len(), 3);
+        assert_eq!(
+            state.selected_item().unwrap().kind,
+            SearchKind::Orchestration
+        );
+    }
+
+    #[test]
+    fn global_typing_j_and_k_goes_to_query() {
+        let mut state = GlobalSearchState::new(create_search_items());
+        handle_global_key(&mut state, make_key(KeyCode::Char('k')));
+        handle_global_key(&mut state, make_key(KeyCode::Char('j')));
+        assert_eq!(state.query, "kj");
+    }
+
+    #[test]
+    fn global_ctrl_n_moves_selection_and_enter_jumps() {
+        let mut state = GlobalSearchState::new(create_search_items());
+        let ctrl_n = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL);
+        handle_global_key(&mut state, ctrl_n);
+        handle_global_key(&mut state, make_key(KeyCode::Down));
+        assert_eq!(state.selected, 2);
+
+        assert_eq!(
+            handle_global_key(&mut state, make_key(KeyCode::Enter)),
+            GlobalSearchResult::Jump(SearchTarget::Agent {
+                orch_index: 1,
+                member_index: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn global_enter_with_no_matches_closes() {
+        let mut state = GlobalSearchState::new(create_search_items());
+        state.query = "zzzz".to_string();
+        state.update_filter();
+        assert_eq!(
+            handle_global_key(&mut state, make_key(KeyCode::Enter)),
+            GlobalSearchResult::Close
+        );
+    }
+
+    #[test]
+    fn render_global_does_not_panic() {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 40)).unwrap();
+        let state = GlobalSearchState::new(create_search_items());
+        assert!(terminal.draw(|frame| render_global(&state, frame)).is_ok());
+
+        let empty = GlobalSearchState::new(vec![]);
+        assert!(terminal.draw(|frame| render_global(&empty, frame)).is_ok());
+    }
 }
@@ -4,11 +4,12 @@ use super::centered_rect;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::Alignment,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
+use crate::theme;
 
 /// Render the help overlay
 pub fn render(frame: &mut Frame) {
@@ -22,7 +23,7 @@ pub fn render(frame: &mut Frame) {
                 .title(" Help ")
                 .title_alignment(Alignment::Center),
         )
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(theme::current().text));
 
     frame.render_widget(help, area);
 }
@@ -5,6 +5,7 @@
 
 pub mod fuzzy;
 pub mod help;
+pub mod palette;
 pub mod quicklook;
 pub mod send;
 
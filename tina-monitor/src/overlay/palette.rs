@@ -0,0 +1,262 @@
+//! Quick-actions palette (`:` or Ctrl-P)
+//!
+//! Lists the control actions available for the selected orchestration,
+//! narrowed by a fuzzy query. Destructive actions ask for a second Enter.
+
+use super::centered_rect;
+use super::fuzzy::fuzzy_score;
+use crate::control::ControlAction;
+use crate::theme;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+/// One palette entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteItem {
+    pub action: ControlAction,
+    pub label: String,
+}
+
+/// State for the quick-actions palette
+#[derive(Debug)]
+pub struct PaletteState {
+    /// Feature the actions apply to, shown in the title
+    pub feature: String,
+    pub query: String,
+    pub selected: usize,
+    pub items: Vec<PaletteItem>,
+    pub filtered: Vec<usize>, // Indices into items, best match first
+    /// Action waiting for a confirming Enter
+    pub confirming: Option<ControlAction>,
+}
+
+impl PaletteState {
+    pub fn new(feature: &str, items: Vec<PaletteItem>) -> Self {
+        let filtered: Vec<usize> = (0..items.len()).collect();
+        Self {
+            feature: feature.to_string(),
+            query: String::new(),
+            selected: 0,
+            items,
+            filtered,
+            confirming: None,
+        }
+    }
+
+    /// Re-rank items against the current query
+    pub fn update_filter(&mut self) {
+        self.selected = 0;
+        self.confirming = None;
+        let mut scored: Vec<(usize, i64)> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| fuzzy_score(&self.query, &item.label).map(|s| (i, s)))
+            .collect();
+        scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+        self.filtered = scored.into_iter().map(|(i, _)| i).collect();
+    }
+
+    /// Get the currently selected item
+    pub fn selected_item(&self) -> Option<&PaletteItem> {
+        self.filtered.get(self.selected).map(|&i| &self.items[i])
+    }
+
+    fn move_selection(&mut self, down: bool) {
+        self.confirming = None;
+        if down {
+            if self.selected + 1 < self.filtered.len() {
+                self.selected += 1;
+            }
+        } else {
+            self.selected = self.selected.saturating_sub(1);
+        }
+    }
+}
+
+/// Result of handling a key in the palette
+#[derive(Debug, PartialEq)]
+pub enum PaletteResult {
+    /// Close the overlay
+    Close,
+    /// Key was consumed but no action needed
+    Consumed,
+    /// Run the action and close the overlay
+    Run(ControlAction),
+}
+
+/// Handle key input for the palette.
+///
+/// Plain characters always go to the query, so navigation uses the arrow
+/// keys or Ctrl-n / Ctrl-p.
+pub fn handle_key(state: &mut PaletteState, key: KeyEvent) -> PaletteResult {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc => PaletteResult::Close,
+        KeyCode::Enter => {
+            let Some(action) = state.selected_item().map(|item| item.action.clone()) else {
+                return PaletteResult::Close;
+            };
+            if action.needs_confirmation() && state.confirming.as_ref() != Some(&action) {
+                state.confirming = Some(action);
+                return PaletteResult::Consumed;
+            }
+            PaletteResult::Run(action)
+        }
+        KeyCode::Up => {
+            state.move_selection(false);
+            PaletteResult::Consumed
+        }
+        KeyCode::Char('p') if ctrl => {
+            state.move_selection(false);
+            PaletteResult::Consumed
+        }
+        KeyCode::Down => {
+            state.move_selection(true);
+            PaletteResult::Consumed
+        }
+        KeyCode::Char('n') if ctrl => {
+            state.move_selection(true);
+            PaletteResult::Consumed
+        }
+        KeyCode::Char(c) if !ctrl => {
+            state.query.push(c);
+            state.update_filter();
+            PaletteResult::Consumed
+        }
+        KeyCode::Backspace => {
+            state.query.pop();
+            state.update_filter();
+            PaletteResult::Consumed
+        }
+        _ => PaletteResult::Consumed,
+    }
+}
+
+/// Render the palette overlay
+pub fn render(state: &PaletteState, frame: &mut Frame) {
+    let area = centered_rect(50, 40, frame.area());
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Input
+            Constraint::Min(3),    // Actions
+        ])
+        .split(area);
+
+    let input = Paragraph::new(format!(": {}_", state.query))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" Actions for {} ", state.feature))
+                .title_alignment(Alignment::Center),
+        )
+        .style(Style::default().fg(theme::current().highlight));
+    frame.render_widget(input, chunks[0]);
+
+    let items: Vec<ListItem> = if state.filtered.is_empty() {
+        vec![ListItem::new(Line::from(Span::styled(
+            "  No matching actions",
+            Style::default().fg(theme::current().muted),
+        )))]
+    } else {
+        state
+            .filtered
+            .iter()
+            .enumerate()
+            .map(|(i, &idx)| {
+                let item = &state.items[idx];
+                let style = if i == state.selected {
+                    Style::default().add_modifier(Modifier::REVERSED)
+                } else {
+                    Style::default()
+                };
+                let mut spans = vec![Span::styled(item.label.as_str(), style)];
+                if state.confirming.as_ref() == Some(&item.action) {
+                    spans.push(Span::styled(
+                        "  Enter again to confirm",
+                        Style::default().fg(theme::current().warning),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect()
+    };
+
+    let list = List::new(items).block(Block::default().borders(Borders::ALL));
+    frame.render_widget(list, chunks[1]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn palette() -> PaletteState {
+        let items = [
+            (ControlAction::Pause, "Pause phase 2"),
+            (ControlAction::RetryPhase, "Retry phase 2"),
+            (ControlAction::Cleanup, "Clean up orchestration state"),
+        ]
+        .into_iter()
+        .map(|(action, label)| PaletteItem {
+            action,
+            label: label.to_string(),
+        })
+        .collect();
+        PaletteState::new("auth", items)
+    }
+
+    #[test]
+    fn query_narrows_actions() {
+        let mut state = palette();
+        for c in "retry".chars() {
+            handle_key(&mut state, key(KeyCode::Char(c)));
+        }
+        assert_eq!(state.filtered.len(), 1);
+        assert_eq!(
+            handle_key(&mut state, key(KeyCode::Enter)),
+            PaletteResult::Run(ControlAction::RetryPhase)
+        );
+    }
+
+    #[test]
+    fn destructive_action_needs_second_enter() {
+        let mut state = palette();
+        handle_key(&mut state, key(KeyCode::Down));
+        handle_key(&mut state, key(KeyCode::Down));
+        assert_eq!(
+            handle_key(&mut state, key(KeyCode::Enter)),
+            PaletteResult::Consumed
+        );
+        assert_eq!(state.confirming, Some(ControlAction::Cleanup));
+        assert_eq!(
+            handle_key(&mut state, key(KeyCode::Enter)),
+            PaletteResult::Run(ControlAction::Cleanup)
+        );
+    }
+
+    #[test]
+    fn moving_selection_cancels_confirmation() {
+        let mut state = palette();
+        state.selected = 2;
+        handle_key(&mut state, key(KeyCode::Enter));
+        handle_key(&mut state, key(KeyCode::Up));
+        assert_eq!(state.confirming, None);
+        assert_eq!(
+            handle_key(&mut state, key(KeyCode::Esc)),
+            PaletteResult::Close
+        );
+    }
+}
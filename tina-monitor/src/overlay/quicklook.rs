@@ -5,11 +5,12 @@ use crate::entity::{Entity, EntityAction};
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::Alignment,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
+use crate::theme;
 
 /// State for the quicklook overlay
 #[derive(Debug)]
@@ -52,7 +53,7 @@ pub fn render(state: &QuicklookState, frame: &mut Frame) {
     if actions.is_empty() {
         lines.push(Line::from(Span::styled(
             "  (No actions available)",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme::current().muted),
         )));
     } else {
         for (key, label, _action) in &actions {
@@ -63,7 +64,7 @@ pub fn render(state: &QuicklookState, frame: &mut Frame) {
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
         "[Space/Esc] Close",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme::current().muted),
     )));
 
     let title = match &state.entity {
@@ -79,7 +80,7 @@ pub fn render(state: &QuicklookState, frame: &mut Frame) {
                 .title(title)
                 .title_alignment(Alignment::Center),
         )
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(theme::current().text));
 
     frame.render_widget(paragraph, area);
 }
@@ -127,7 +128,7 @@ fn render_commit_details(c: &crate::git::commits::Commit) -> Vec<Line<'static>>
 
 fn detail_line(label: &'static str, value: String) -> Line<'static> {
     Line::from(vec![
-        Span::styled(label, Style::default().fg(Color::DarkGray)),
+        Span::styled(label, Style::default().fg(theme::current().muted)),
         Span::raw(value),
     ])
 }
@@ -1,10 +1,11 @@
 //! Send dialog overlay for sending commands to tmux panes
 
 use super::centered_rect;
+use crate::theme;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::Alignment,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
@@ -47,20 +48,23 @@ pub fn render(state: &SendDialogState, frame: &mut Frame) {
     let lines = vec![
         Line::from(""),
         Line::from(vec![
-            Span::styled("Send to: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(&state.agent_name, Style::default().fg(Color::Cyan)),
+            Span::styled("Send to: ", Style::default().fg(theme::current().muted)),
+            Span::styled(
+                &state.agent_name,
+                Style::default().fg(theme::current().accent),
+            ),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("> ", Style::default().fg(Color::Yellow)),
+            Span::styled("> ", Style::default().fg(theme::current().highlight)),
             Span::raw(&state.input),
-            Span::styled("_", Style::default().fg(Color::White)),
+            Span::styled("_", Style::default().fg(theme::current().text)),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("[Enter] ", Style::default().fg(Color::Green)),
+            Span::styled("[Enter] ", Style::default().fg(theme::current().success)),
             Span::raw("Send  "),
-            Span::styled("[Esc] ", Style::default().fg(Color::Red)),
+            Span::styled("[Esc] ", Style::default().fg(theme::current().error)),
             Span::raw("Cancel"),
         ]),
     ];
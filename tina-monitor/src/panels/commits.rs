@@ -1,9 +1,10 @@
 use crate::git::commits::Commit;
 use crate::panel::{HandleResult, Panel};
 use crate::panels::{border_style, border_type, clamp_selection, handle_selectable_list_key};
+use crate::theme;
 use crossterm::event::KeyEvent;
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
 use ratatui::Frame;
@@ -67,7 +68,10 @@ impl Panel for CommitsPanel {
                 .iter()
                 .map(|commit| {
                     let line = Line::from(vec![
-                        Span::styled(&commit.short_hash, Style::default().fg(Color::Yellow)),
+                        Span::styled(
+                            &commit.short_hash,
+                            Style::default().fg(theme::current().highlight),
+                        ),
                         Span::raw(" "),
                         Span::raw(&commit.subject),
                     ]);
@@ -78,7 +82,7 @@ impl Panel for CommitsPanel {
 
         let list = List::new(items)
             .block(block)
-            .highlight_style(Style::default().bg(Color::DarkGray));
+            .highlight_style(theme::current().selection());
 
         let mut state = ListState::default();
         if !self.commits.is_empty() {
@@ -8,16 +8,17 @@ pub use team::TeamPanel;
 
 use crate::panel::{Direction, HandleResult};
 use crossterm::event::KeyCode;
-use ratatui::style::{Color, Style};
+use crate::theme;
+use ratatui::style::Style;
 use ratatui::widgets::BorderType;
 
 /// Returns the border style for a panel based on focus state.
-/// Focused panels get Cyan color, unfocused panels get DarkGray.
+/// Focused panels use the theme's focused border color.
 pub fn border_style(focused: bool) -> Style {
     if focused {
-        Style::default().fg(Color::Cyan)
+        Style::default().fg(theme::current().focused_border)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme::current().border)
     }
 }
 
@@ -75,6 +76,7 @@ mod tests {
     use crate::panel::{Direction, HandleResult, Panel};
     use crate::types::TeamMember;
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::style::Color;
     use std::path::PathBuf;
 
     fn make_key_event(code: KeyCode) -> KeyEvent {
@@ -1,9 +1,10 @@
 use crate::panel::{HandleResult, Panel};
 use crate::panels::{border_style, border_type, clamp_selection, handle_selectable_list_key};
+use crate::theme;
 use crate::types::{Task, TaskStatus};
 use crossterm::event::KeyEvent;
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
 use ratatui::Frame;
@@ -59,13 +60,13 @@ impl Panel for TasksPanel {
                 .map(|task| {
                     let status_icon = match task.status {
                         TaskStatus::Completed => {
-                            Span::styled("[x]", Style::default().fg(Color::Green))
+                            Span::styled("[x]", Style::default().fg(theme::current().success))
                         }
                         TaskStatus::InProgress => {
-                            Span::styled("[>]", Style::default().fg(Color::Yellow))
+                            Span::styled("[>]", Style::default().fg(theme::current().warning))
                         }
                         TaskStatus::Pending => {
-                            Span::styled("[ ]", Style::default().fg(Color::DarkGray))
+                            Span::styled("[ ]", Style::default().fg(theme::current().muted))
                         }
                     };
 
@@ -76,13 +77,16 @@ impl Panel for TasksPanel {
                         spans.push(Span::raw(" <- "));
                         spans.push(Span::styled(
                             owner.clone(),
-                            Style::default().fg(Color::Cyan),
+                            Style::default().fg(theme::current().accent),
                         ));
                     }
 
                     if !task.blocked_by.is_empty() {
                         spans.push(Span::raw(" "));
-                        spans.push(Span::styled("(blocked)", Style::default().fg(Color::Red)));
+                        spans.push(Span::styled(
+                            "(blocked)",
+                            Style::default().fg(theme::current().error),
+                        ));
                     }
 
                     ListItem::new(Line::from(spans))
@@ -92,7 +96,7 @@ impl Panel for TasksPanel {
 
         let list = List::new(items)
             .block(block)
-            .highlight_style(Style::default().bg(Color::DarkGray));
+            .highlight_style(theme::current().selection());
 
         let mut state = ListState::default();
         if !self.tasks.is_empty() {
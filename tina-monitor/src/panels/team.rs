@@ -1,9 +1,10 @@
 use crate::panel::{HandleResult, Panel};
 use crate::panels::{border_style, border_type, clamp_selection, handle_selectable_list_key};
+use crate::theme;
 use crate::types::TeamMember;
 use crossterm::event::KeyEvent;
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
 use ratatui::Frame;
@@ -87,7 +88,7 @@ impl Panel for TeamPanel {
 
         let list = List::new(items)
             .block(block)
-            .highlight_style(Style::default().bg(Color::DarkGray));
+            .highlight_style(theme::current().selection());
 
         let mut state = ListState::default();
         if !self.members.is_empty() {
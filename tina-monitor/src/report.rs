@@ -0,0 +1,472 @@
+//! Orchestration reports for sharing outside the TUI
+//!
+//! A report is built once as a list of [`Block`]s and rendered to Markdown
+//! (PR descriptions, retro docs) or a standalone HTML page, so both formats
+//! always carry the same sections.
+
+use tina_data::humanize::format_absolute_rfc3339;
+use tina_data::{CommitRecord, ReviewGateRecord, ReviewThreadRecord};
+
+use crate::dashboard::Dashboard;
+use crate::data::MonitorOrchestration;
+
+/// Output format for `tina-monitor export`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+/// Everything a report covers for one orchestration
+pub struct ReportData {
+    /// Orchestration with phases, tasks, and members loaded
+    pub orchestration: MonitorOrchestration,
+    pub commits: Vec<CommitRecord>,
+    pub findings: Vec<ReviewThreadRecord>,
+    pub gates: Vec<ReviewGateRecord>,
+}
+
+/// A format-neutral piece of a report
+#[derive(Debug, Clone, PartialEq)]
+enum Block {
+    Heading(u8, String),
+    Paragraph(String),
+    /// `(label, value)` pairs
+    Fields(Vec<(String, String)>),
+    Table {
+        headers: Vec<&'static str>,
+        rows: Vec<Vec<String>>,
+    },
+}
+
+/// Render `data` as a complete document in `format`
+pub fn render(data: &ReportData, format: ReportFormat) -> String {
+    let title = format!("Orchestration report: {}", data.orchestration.feature_name);
+    let blocks = build(data);
+    match format {
+        ReportFormat::Markdown => render_markdown(&title, &blocks),
+        ReportFormat::Html => render_html(&title, &blocks),
+    }
+}
+
+fn build(data: &ReportData) -> Vec<Block> {
+    let orch = &data.orchestration;
+    let mut blocks = vec![Block::Fields(vec![
+        ("Status".to_string(), orch.status.to_string()),
+        (
+            "Phase".to_string(),
+            format!("{}/{}", orch.current_phase, orch.total_phases),
+        ),
+        ("Branch".to_string(), orch.branch.clone()),
+        ("Spec".to_string(), orch.spec_doc_path.display().to_string()),
+        ("Started".to_string(), timestamp(&orch.started_at)),
+        (
+            "Completed".to_string(),
+            orch.completed_at
+                .as_deref()
+                .map_or_else(|| "-".to_string(), timestamp),
+        ),
+        ("Elapsed".to_string(), mins(orch.total_elapsed_mins)),
+    ])];
+
+    blocks.push(Block::Heading(2, "Phases".to_string()));
+    if orch.phases.is_empty() {
+        blocks.push(Block::Paragraph("No phases recorded.".to_string()));
+    } else {
+        blocks.push(Block::Table {
+            headers: vec![
+                "Phase",
+                "Status",
+                "Planning",
+                "Execution",
+                "Review",
+                "Risk",
+                "Git range",
+            ],
+            rows: orch
+                .phases
+                .iter()
+                .map(|p| {
+                    vec![
+                        p.phase_number.clone(),
+                        p.status.clone(),
+                        mins(p.planning_mins),
+                        mins(p.execution_mins),
+                        mins(p.review_mins),
+                        p.risk_score
+                            .map(|s| format!("{:.0}", s))
+                            .unwrap_or_else(|| "-".to_string()),
+                        p.git_range.clone().unwrap_or_else(|| "-".to_string()),
+                    ]
+                })
+                .collect(),
+        });
+    }
+
+    blocks.push(Block::Heading(2, "Tasks".to_string()));
+    if orch.tasks.is_empty() {
+        blocks.push(Block::Paragraph("No tasks recorded.".to_string()));
+    } else {
+        blocks.push(Block::Paragraph(format!(
+            "{} of {} tasks completed.",
+            orch.tasks_completed(),
+            orch.tasks_total()
+        )));
+        blocks.push(Block::Table {
+            headers: vec!["ID", "Subject", "Status", "Owner"],
+            rows: orch
+                .tasks
+                .iter()
+                .map(|t| {
+                    vec![
+                        t.id.clone(),
+                        t.subject.clone(),
+                        t.status.to_string(),
+                        t.owner.clone().unwrap_or_else(|| "-".to_string()),
+                    ]
+                })
+                .collect(),
+        });
+    }
+
+    blocks.push(Block::Heading(2, "Team".to_string()));
+    if orch.members.is_empty() {
+        blocks.push(Block::Paragraph("No team members recorded.".to_string()));
+    } else {
+        blocks.push(Block::Table {
+            headers: vec!["Agent", "Type", "Model"],
+            rows: orch
+                .members
+                .iter()
+                .map(|m| {
+                    vec![
+                        m.name.clone(),
+                        m.agent_type.clone().unwrap_or_else(|| "-".to_string()),
+                        m.model.clone(),
+                    ]
+                })
+                .collect(),
+        });
+    }
+
+    blocks.push(Block::Heading(2, "Commits".to_string()));
+    if data.commits.is_empty() {
+        blocks.push(Block::Paragraph("No commits recorded.".to_string()));
+    } else {
+        blocks.push(Block::Table {
+            headers: vec!["Phase", "Commit", "Subject"],
+            rows: data
+                .commits
+                .iter()
+                .map(|c| {
+                    vec![
+                        c.phase_number.clone(),
+                        c.short_sha
+                            .clone()
+                            .unwrap_or_else(|| c.sha.chars().take(7).collect()),
+                        c.subject.clone().unwrap_or_default(),
+                    ]
+                })
+                .collect(),
+        });
+    }
+
+    blocks.push(Block::Heading(2, "Review".to_string()));
+    if !data.gates.is_empty() {
+        blocks.push(Block::Table {
+            headers: vec!["Gate", "Status", "Decided by", "Summary"],
+            rows: data
+                .gates
+                .iter()
+                .map(|g| {
+                    vec![
+                        g.gate_id.clone(),
+                        g.status.clone(),
+                        g.decided_by.clone().unwrap_or_else(|| "-".to_string()),
+                        g.summary.clone(),
+                    ]
+                })
+                .collect(),
+        });
+    }
+    if data.findings.is_empty() {
+        blocks.push(Block::Paragraph("No review findings.".to_string()));
+    } else {
+        let open = data
+            .findings
+            .iter()
+            .filter(|f| f.status != "resolved")
+            .count();
+        blocks.push(Block::Paragraph(format!(
+            "{} findings, {} unresolved.",
+            data.findings.len(),
+            open
+        )));
+        blocks.push(Block::Table {
+            headers: vec!["Severity", "Status", "Location", "Summary"],
+            rows: data
+                .findings
+                .iter()
+                .map(|f| {
+                    vec![
+                        f.severity.clone(),
+                        f.status.clone(),
+                        format!("{}:{}", f.file_path, f.line),
+                        f.summary.clone(),
+                    ]
+                })
+                .collect(),
+        });
+    }
+
+    // Token spend isn't recorded, so cost is reported as agent time
+    let total = |stage: fn(&tina_data::PhaseRecord) -> Option<f64>| {
+        let sum: f64 = orch.phases.iter().filter_map(stage).sum();
+        Some(sum)
+    };
+    blocks.push(Block::Heading(2, "Cost".to_string()));
+    blocks.push(Block::Fields(vec![
+        ("Planning".to_string(), mins(total(|p| p.planning_mins))),
+        ("Execution".to_string(), mins(total(|p| p.execution_mins))),
+        ("Review".to_string(), mins(total(|p| p.review_mins))),
+        ("Wall clock".to_string(), mins(orch.total_elapsed_mins)),
+    ]));
+
+    blocks
+}
+
+/// An RFC 3339 timestamp in the display timezone, or as stored if it
+/// doesn't parse
+fn timestamp(at: &str) -> String {
+    format_absolute_rfc3339(at).unwrap_or_else(|| at.to_string())
+}
+
+fn mins(value: Option<f64>) -> String {
+    match value {
+        Some(m) => Dashboard::format_duration(m.round() as i64),
+        None => "-".to_string(),
+    }
+}
+
+fn render_markdown(title: &str, blocks: &[Block]) -> String {
+    let mut out = format!("# {}\n", md_escape(title));
+    for block in blocks {
+        out.push('\n');
+        match block {
+            Block::Heading(level, text) => {
+                out.push_str(&format!(
+                    "{} {}\n",
+                    "#".repeat(*level as usize),
+                    md_escape(text)
+                ));
+            }
+            Block::Paragraph(text) => out.push_str(&format!("{}\n", md_escape(text))),
+            Block::Fields(fields) => {
+                for (label, value) in fields {
+                    out.push_str(&format!("- **{}:** {}\n", label, md_escape(value)));
+                }
+            }
+            Block::Table { headers, rows } => {
+                out.push_str(&format!("| {} |\n", headers.join(" | ")));
+                out.push_str(&format!("|{}\n", "---|".repeat(headers.len())));
+                for row in rows {
+                    let cells: Vec<String> = row.iter().map(|c| md_cell(c)).collect();
+                    out.push_str(&format!("| {} |\n", cells.join(" | ")));
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Escape characters that would start Markdown formatting
+fn md_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']' | '<' | '>') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// A table cell: escaped, single-line, with pipes escaped
+fn md_cell(text: &str) -> String {
+    md_escape(text).replace('|', "\\|").replace('\n', " ")
+}
+
+const HTML_STYLE: &str = "body{font-family:-apple-system,BlinkMacSystemFont,sans-serif;\
+max-width:960px;margin:2em auto;padding:0 1em;color:#1f2328}\
+table{border-collapse:collapse;width:100%;margin:0.5em 0}\
+th,td{border:1px solid #d0d7de;padding:4px 8px;text-align:left;vertical-align:top}\
+th{background:#f6f8fa}ul{padding-left:1.2em}";
+
+fn render_html(title: &str, blocks: &[Block]) -> String {
+    let title = html_escape(title);
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+         <style>{}</style>\n</head>\n<body>\n<h1>{}</h1>\n",
+        title, HTML_STYLE, title
+    );
+    for block in blocks {
+        match block {
+            Block::Heading(level, text) => {
+                out.push_str(&format!("<h{0}>{1}</h{0}>\n", level, html_escape(text)));
+            }
+            Block::Paragraph(text) => out.push_str(&format!("<p>{}</p>\n", html_escape(text))),
+            Block::Fields(fields) => {
+                out.push_str("<ul>\n");
+                for (label, value) in fields {
+                    out.push_str(&format!(
+                        "<li><strong>{}:</strong> {}</li>\n",
+                        html_escape(label),
+                        html_escape(value)
+                    ));
+                }
+                out.push_str("</ul>\n");
+            }
+            Block::Table { headers, rows } => {
+                out.push_str("<table>\n<tr>");
+                for header in headers {
+                    out.push_str(&format!("<th>{}</th>", html_escape(header)));
+                }
+                out.push_str("</tr>\n");
+                for row in rows {
+                    out.push_str("<tr>");
+                    for cell in row {
+                        out.push_str(&format!("<td>{}</td>", html_escape(cell)));
+                    }
+                    out.push_str("</tr>\n");
+                }
+                out.push_str("</table>\n");
+            }
+        }
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn html_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::MonitorOrchestrationStatus;
+    use crate::types::{Task, TaskStatus};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use tina_data::PhaseRecord;
+
+    fn sample() -> ReportData {
+        let orchestration = MonitorOrchestration {
+            id: "orch-1".to_string(),
+            node_id: "node-1".to_string(),
+            node_name: "laptop".to_string(),
+            feature_name: "auth".to_string(),
+            worktree_path: PathBuf::from("/tmp/auth"),
+            current_phase: 2,
+            total_phases: 2,
+            spec_doc_path: PathBuf::from("docs/plans/auth.md"),
+            spec_id: None,
+            project_id: None,
+            status: MonitorOrchestrationStatus::Complete,
+            started_at: "2026-02-10T10:00:00Z".to_string(),
+            completed_at: Some("2026-02-10T12:30:00Z".to_string()),
+            total_elapsed_mins: Some(150.0),
+            branch: "tina/auth".to_string(),
+            phases: vec![PhaseRecord {
+                orchestration_id: "orch-1".to_string(),
+                phase_number: "1".to_string(),
+                status: "complete".to_string(),
+                plan_path: None,
+                git_range: Some("abc..def".to_string()),
+                planning_mins: Some(10.0),
+                execution_mins: Some(75.0),
+                review_mins: Some(5.0),
+                started_at: None,
+                completed_at: None,
+                risk_score: Some(42.0),
+                risk_signals: None,
+            }],
+            tasks: vec![Task {
+                id: "1".to_string(),
+                subject: "Add <login> | logout".to_string(),
+                description: String::new(),
+                active_form: None,
+                status: TaskStatus::Completed,
+                owner: Some("executor".to_string()),
+                blocks: vec![],
+                blocked_by: vec![],
+                metadata: serde_json::Value::Null,
+            }],
+            orchestrator_tasks: vec![],
+            members: vec![],
+            task_updated_at: HashMap::new(),
+            clock_offset_ms: 0,
+        };
+        ReportData {
+            orchestration,
+            commits: vec![CommitRecord {
+                orchestration_id: "orch-1".to_string(),
+                phase_number: "1".to_string(),
+                sha: "abcdef0123456789".to_string(),
+                short_sha: None,
+                subject: Some("Add login".to_string()),
+                timestamp: None,
+            }],
+            findings: vec![],
+            gates: vec![],
+        }
+    }
+
+    #[test]
+    fn markdown_report_has_every_section() {
+        let report = render(&sample(), ReportFormat::Markdown);
+
+        assert!(report.starts_with("# Orchestration report: auth\n"));
+        for heading in [
+            "## Phases",
+            "## Tasks",
+            "## Team",
+            "## Commits",
+            "## Review",
+            "## Cost",
+        ] {
+            assert!(report.contains(heading), "missing {}", heading);
+        }
+        assert!(report.contains("| 1 | complete | 10m | 1h 15m | 5m | 42 | abc..def |"));
+        assert!(report.contains("| 1 | abcdef0 | Add login |"));
+        assert!(report.contains("- **Execution:** 1h 15m"));
+        assert!(report.contains("No team members recorded."));
+        // Timestamps are shown in the display timezone, not as stored
+        assert!(report.contains("- **Started:** 2026-02-10 "));
+        assert!(!report.contains("2026-02-10T10:00:00Z"));
+        // Cells can't break the table
+        assert!(report.contains("Add \\<login\\> \\| logout"));
+    }
+
+    #[test]
+    fn html_report_escapes_content() {
+        let report = render(&sample(), ReportFormat::Html);
+
+        assert!(report.starts_with("<!DOCTYPE html>"));
+        assert!(report.contains("<h2>Commits</h2>"));
+        assert!(report.contains("<td>Add &lt;login&gt; | logout</td>"));
+        assert!(!report.contains("<login>"));
+        assert!(report.trim_end().ends_with("</html>"));
+    }
+}
@@ -0,0 +1,117 @@
+//! Alacritty terminal handler
+//!
+//! Opens windows in the running Alacritty instance with
+//! `alacritty msg create-window`. Alacritty has no tabs, so each action gets
+//! its own window.
+
+use std::path::Path;
+use std::process::Command;
+
+use super::{tmux_attach_command, TerminalHandler, TerminalResult};
+
+/// Handler for Alacritty
+pub struct AlacrittyHandler;
+
+impl AlacrittyHandler {
+    fn create_window_command(cwd: Option<&Path>, shell_command: Option<&str>) -> Command {
+        let mut command = Command::new("alacritty");
+        command.args(["msg", "create-window"]);
+        if let Some(cwd) = cwd {
+            command.arg("--working-directory").arg(cwd);
+        }
+        if let Some(shell_command) = shell_command {
+            command.args(["-e", "bash", "-c", shell_command]);
+        }
+        command
+    }
+
+    fn run(mut command: Command, action: &str) -> anyhow::Result<TerminalResult> {
+        let output = command.output()?;
+        if output.status.success() {
+            Ok(TerminalResult::Success)
+        } else {
+            anyhow::bail!(
+                "Failed to {}: {}",
+                action,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+}
+
+impl TerminalHandler for AlacrittyHandler {
+    fn is_available(&self) -> bool {
+        // `msg` talks to the instance behind ALACRITTY_SOCKET, which is only
+        // set inside Alacritty windows started with IPC enabled
+        std::env::var_os("ALACRITTY_SOCKET").is_some()
+            && Command::new("alacritty")
+                .arg("--version")
+                .output()
+                .map(|output| output.status.success())
+                .unwrap_or(false)
+    }
+
+    fn open_tab_at(&self, cwd: &Path) -> anyhow::Result<TerminalResult> {
+        Self::run(
+            Self::create_window_command(Some(cwd), None),
+            "open alacritty window",
+        )
+    }
+
+    fn run_at(&self, cwd: &Path, shell_command: &str) -> anyhow::Result<TerminalResult> {
+        Self::run(
+            Self::create_window_command(Some(cwd), Some(shell_command)),
+            "run command in alacritty window",
+        )
+    }
+
+    fn attach_tmux(
+        &self,
+        session_name: &str,
+        pane_id: Option<&str>,
+    ) -> anyhow::Result<TerminalResult> {
+        let tmux_cmd = tmux_attach_command(session_name, pane_id);
+        Self::run(
+            Self::create_window_command(None, Some(&tmux_cmd)),
+            "attach tmux session",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(command: &Command) -> Vec<String> {
+        command
+            .get_args()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_create_window_command_sets_working_directory() {
+        let command = AlacrittyHandler::create_window_command(Some(Path::new("/tmp/work")), None);
+        assert_eq!(command.get_program(), "alacritty");
+        assert_eq!(
+            args(&command),
+            vec!["msg", "create-window", "--working-directory", "/tmp/work"]
+        );
+    }
+
+    #[test]
+    fn test_create_window_command_runs_tmux_attach() {
+        let command = AlacrittyHandler::create_window_command(None, Some("tmux attach -t tina"));
+        assert_eq!(
+            args(&command),
+            vec![
+                "msg",
+                "create-window",
+                "-e",
+                "bash",
+                "-c",
+                "tmux attach -t tina"
+            ]
+        );
+    }
+}
@@ -2,7 +2,7 @@
 
 use std::path::Path;
 
-use super::{TerminalHandler, TerminalResult};
+use super::{tmux_attach_command, TerminalHandler, TerminalResult};
 
 /// Fallback handler that returns commands to user
 pub struct FallbackHandler;
@@ -20,19 +20,20 @@ impl TerminalHandler for FallbackHandler {
         })
     }
 
+    fn run_at(&self, cwd: &Path, shell_command: &str) -> anyhow::Result<TerminalResult> {
+        let cwd_str = cwd.display();
+        Ok(TerminalResult::ShowCommand {
+            command: format!("cd {} && {}", cwd_str, shell_command),
+            description: format!("Open a new terminal tab and run {}", shell_command),
+        })
+    }
+
     fn attach_tmux(
         &self,
         session_name: &str,
         pane_id: Option<&str>,
     ) -> anyhow::Result<TerminalResult> {
-        let command = if let Some(pane) = pane_id {
-            format!(
-                "tmux attach -t {} && tmux select-pane -t {}",
-                session_name, pane
-            )
-        } else {
-            format!("tmux attach -t {}", session_name)
-        };
+        let command = tmux_attach_command(session_name, pane_id);
 
         let description = if pane_id.is_some() {
             format!("Attach to tmux session '{}' and select pane", session_name)
@@ -85,6 +86,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_run_at_returns_show_command() {
+        let handler = FallbackHandler;
+
+        let result = handler.run_at(&PathBuf::from("/tmp/test-dir"), "lazygit");
+
+        match result.unwrap() {
+            TerminalResult::ShowCommand { command, .. } => {
+                assert_eq!(command, "cd /tmp/test-dir && lazygit");
+            }
+            TerminalResult::Success => panic!("Expected ShowCommand, got Success"),
+        }
+    }
+
     #[test]
     fn test_attach_tmux_with_pane_returns_show_command() {
         let handler = FallbackHandler;
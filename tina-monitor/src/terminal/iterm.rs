@@ -0,0 +1,94 @@
+//! iTerm2 terminal handler
+//!
+//! Drives iTerm2 through AppleScript: opens a tab in the front window (or a
+//! new window if none is open) and types the command into it.
+
+use std::path::Path;
+use std::process::Command;
+
+use super::{tmux_attach_command, TerminalHandler, TerminalResult};
+use crate::desktop_notify::applescript_string;
+use crate::tmux::stream::shell_quote;
+
+/// Handler for iTerm2 (macOS only)
+pub struct ItermHandler;
+
+impl ItermHandler {
+    /// AppleScript opening a tab and running `shell_command` in it
+    fn new_tab_script(shell_command: &str) -> String {
+        format!(
+            r#"tell application "iTerm2"
+    if (count of windows) = 0 then
+        create window with default profile
+    else
+        tell current window to create tab with default profile
+    end if
+    tell current session of current window to write text {}
+end tell"#,
+            applescript_string(shell_command)
+        )
+    }
+
+    fn run_in_new_tab(shell_command: &str) -> anyhow::Result<TerminalResult> {
+        let output = Command::new("osascript")
+            .arg("-e")
+            .arg(Self::new_tab_script(shell_command))
+            .output()?;
+
+        if output.status.success() {
+            Ok(TerminalResult::Success)
+        } else {
+            anyhow::bail!(
+                "Failed to open iTerm2 tab: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+}
+
+impl TerminalHandler for ItermHandler {
+    fn is_available(&self) -> bool {
+        cfg!(target_os = "macos")
+            && (std::env::var("TERM_PROGRAM").as_deref() == Ok("iTerm.app")
+                || Path::new("/Applications/iTerm.app").exists())
+    }
+
+    fn open_tab_at(&self, cwd: &Path) -> anyhow::Result<TerminalResult> {
+        Self::run_in_new_tab(&format!("cd {}", shell_quote(&cwd.to_string_lossy())))
+    }
+
+    fn run_at(&self, cwd: &Path, shell_command: &str) -> anyhow::Result<TerminalResult> {
+        Self::run_in_new_tab(&format!(
+            "cd {} && {}",
+            shell_quote(&cwd.to_string_lossy()),
+            shell_command
+        ))
+    }
+
+    fn attach_tmux(
+        &self,
+        session_name: &str,
+        pane_id: Option<&str>,
+    ) -> anyhow::Result<TerminalResult> {
+        Self::run_in_new_tab(&tmux_attach_command(session_name, pane_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_tab_script_writes_escaped_command() {
+        let script = ItermHandler::new_tab_script(r#"cd '/tmp/my "work"'"#);
+        assert!(script.starts_with(r#"tell application "iTerm2""#));
+        assert!(script.contains(r#"write text "cd '/tmp/my \"work\"'""#));
+    }
+
+    #[test]
+    fn test_is_unavailable_off_macos() {
+        if !cfg!(target_os = "macos") {
+            assert!(!ItermHandler.is_available());
+        }
+    }
+}
@@ -3,7 +3,7 @@
 use std::path::Path;
 use std::process::Command;
 
-use super::{TerminalHandler, TerminalResult};
+use super::{tmux_attach_command, TerminalHandler, TerminalResult};
 
 /// Handler for Kitty terminal
 pub struct KittyHandler;
@@ -45,19 +45,31 @@ impl TerminalHandler for KittyHandler {
         }
     }
 
+    fn run_at(&self, cwd: &Path, shell_command: &str) -> anyhow::Result<TerminalResult> {
+        let output = Command::new("kitty")
+            .args(["@", "launch", "--type=tab", "--cwd"])
+            .arg(cwd)
+            .arg("bash")
+            .arg("-c")
+            .arg(shell_command)
+            .output()?;
+
+        if output.status.success() {
+            Ok(TerminalResult::Success)
+        } else {
+            anyhow::bail!(
+                "Failed to run command in kitty tab: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+
     fn attach_tmux(
         &self,
         session_name: &str,
         pane_id: Option<&str>,
     ) -> anyhow::Result<TerminalResult> {
-        let tmux_cmd = if let Some(pane) = pane_id {
-            format!(
-                "tmux attach -t {} && tmux select-pane -t {}",
-                session_name, pane
-            )
-        } else {
-            format!("tmux attach -t {}", session_name)
-        };
+        let tmux_cmd = tmux_attach_command(session_name, pane_id);
 
         let output = Command::new("kitty")
             .args(["@", "launch", "--type=tab"])
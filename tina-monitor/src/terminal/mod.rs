@@ -1,8 +1,14 @@
+mod alacritty;
 mod fallback;
+mod iterm;
 mod kitty;
+mod wezterm;
 
+pub use alacritty::AlacrittyHandler;
 pub use fallback::FallbackHandler;
+pub use iterm::ItermHandler;
 pub use kitty::KittyHandler;
+pub use wezterm::WeztermHandler;
 
 use std::path::Path;
 
@@ -20,6 +26,8 @@ pub enum TerminalResult {
 pub trait TerminalHandler: Send + Sync {
     fn is_available(&self) -> bool;
     fn open_tab_at(&self, cwd: &Path) -> anyhow::Result<TerminalResult>;
+    /// Open a tab at `cwd` running `shell_command` through bash
+    fn run_at(&self, cwd: &Path, shell_command: &str) -> anyhow::Result<TerminalResult>;
     fn attach_tmux(
         &self,
         session_name: &str,
@@ -28,14 +36,59 @@ pub trait TerminalHandler: Send + Sync {
 }
 
 /// Get the appropriate terminal handler based on config
+///
+/// `preferred` is "kitty", "wezterm", "iterm", "alacritty", "print", or
+/// "auto" to pick the terminal tina-monitor is running in. Falls back to
+/// printing commands when the chosen terminal can't be controlled.
 pub fn get_handler(preferred: &str) -> Box<dyn TerminalHandler> {
-    if preferred == "kitty" {
-        let handler = KittyHandler::new();
-        if handler.is_available() {
-            return Box::new(handler);
-        }
+    let preferred = if preferred == "auto" {
+        detect_terminal(|name| std::env::var(name).ok()).unwrap_or("print")
+    } else {
+        preferred
+    };
+    let handler: Box<dyn TerminalHandler> = match preferred {
+        "kitty" => Box::new(KittyHandler::new()),
+        "wezterm" => Box::new(WeztermHandler),
+        "iterm" => Box::new(ItermHandler),
+        "alacritty" => Box::new(AlacrittyHandler),
+        _ => return Box::new(FallbackHandler),
+    };
+    if handler.is_available() {
+        handler
+    } else {
+        Box::new(FallbackHandler)
+    }
+}
+
+/// Name of the terminal handler matching the environment the process runs
+/// in, from the variables each terminal sets in its shells
+pub fn detect_terminal(env: impl Fn(&str) -> Option<String>) -> Option<&'static str> {
+    if env("KITTY_WINDOW_ID").is_some() {
+        return Some("kitty");
+    }
+    if env("WEZTERM_PANE").is_some() {
+        return Some("wezterm");
+    }
+    if env("ALACRITTY_SOCKET").is_some() {
+        return Some("alacritty");
+    }
+    match env("TERM_PROGRAM").as_deref() {
+        Some("WezTerm") => Some("wezterm"),
+        Some("iTerm.app") => Some("iterm"),
+        _ => None,
+    }
+}
+
+/// Shell command attaching to a tmux session, optionally selecting a pane
+fn tmux_attach_command(session_name: &str, pane_id: Option<&str>) -> String {
+    if let Some(pane) = pane_id {
+        format!(
+            "tmux attach -t {} && tmux select-pane -t {}",
+            session_name, pane
+        )
+    } else {
+        format!("tmux attach -t {}", session_name)
     }
-    Box::new(FallbackHandler)
 }
 
 #[cfg(test)]
@@ -54,4 +107,44 @@ mod tests {
         // Will return kitty if available, fallback otherwise
         assert!(handler.is_available());
     }
+
+    #[test]
+    fn test_get_handler_always_returns_available_handler() {
+        for name in ["wezterm", "iterm", "alacritty", "auto", "print"] {
+            assert!(get_handler(name).is_available(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_detect_terminal_from_environment() {
+        let detect = |vars: &[(&str, &str)]| {
+            let vars: Vec<(String, String)> = vars
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            detect_terminal(move |name| {
+                vars.iter().find(|(k, _)| k == name).map(|(_, v)| v.clone())
+            })
+        };
+
+        assert_eq!(detect(&[("KITTY_WINDOW_ID", "1")]), Some("kitty"));
+        assert_eq!(detect(&[("WEZTERM_PANE", "0")]), Some("wezterm"));
+        assert_eq!(detect(&[("TERM_PROGRAM", "WezTerm")]), Some("wezterm"));
+        assert_eq!(detect(&[("TERM_PROGRAM", "iTerm.app")]), Some("iterm"));
+        assert_eq!(
+            detect(&[("ALACRITTY_SOCKET", "/tmp/Alacritty-1.sock")]),
+            Some("alacritty")
+        );
+        assert_eq!(detect(&[("TERM_PROGRAM", "Apple_Terminal")]), None);
+        assert_eq!(detect(&[]), None);
+    }
+
+    #[test]
+    fn test_tmux_attach_command() {
+        assert_eq!(tmux_attach_command("tina", None), "tmux attach -t tina");
+        assert_eq!(
+            tmux_attach_command("tina", Some("%1")),
+            "tmux attach -t tina && tmux select-pane -t %1"
+        );
+    }
 }
@@ -0,0 +1,119 @@
+//! WezTerm terminal handler
+//!
+//! Opens tabs in the running WezTerm instance with `wezterm cli spawn`.
+
+use std::path::Path;
+use std::process::Command;
+
+use super::{tmux_attach_command, TerminalHandler, TerminalResult};
+
+/// Handler for WezTerm
+pub struct WeztermHandler;
+
+impl WeztermHandler {
+    fn spawn_tab_command(cwd: Option<&Path>, shell_command: Option<&str>) -> Command {
+        let mut command = Command::new("wezterm");
+        command.args(["cli", "spawn"]);
+        if let Some(cwd) = cwd {
+            command.arg("--cwd").arg(cwd);
+        }
+        if let Some(shell_command) = shell_command {
+            command.args(["--", "bash", "-c", shell_command]);
+        }
+        command
+    }
+
+    fn run(mut command: Command, action: &str) -> anyhow::Result<TerminalResult> {
+        let output = command.output()?;
+        if output.status.success() {
+            Ok(TerminalResult::Success)
+        } else {
+            anyhow::bail!(
+                "Failed to {}: {}",
+                action,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+}
+
+impl TerminalHandler for WeztermHandler {
+    fn is_available(&self) -> bool {
+        // `cli list` fails when no GUI instance is running to talk to
+        Command::new("wezterm")
+            .args(["cli", "list"])
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    }
+
+    fn open_tab_at(&self, cwd: &Path) -> anyhow::Result<TerminalResult> {
+        Self::run(Self::spawn_tab_command(Some(cwd), None), "open wezterm tab")
+    }
+
+    fn run_at(&self, cwd: &Path, shell_command: &str) -> anyhow::Result<TerminalResult> {
+        Self::run(
+            Self::spawn_tab_command(Some(cwd), Some(shell_command)),
+            "run command in wezterm tab",
+        )
+    }
+
+    fn attach_tmux(
+        &self,
+        session_name: &str,
+        pane_id: Option<&str>,
+    ) -> anyhow::Result<TerminalResult> {
+        let tmux_cmd = tmux_attach_command(session_name, pane_id);
+        Self::run(
+            Self::spawn_tab_command(None, Some(&tmux_cmd)),
+            "attach tmux session",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(command: &Command) -> Vec<String> {
+        command
+            .get_args()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_spawn_tab_command_sets_cwd() {
+        let command = WeztermHandler::spawn_tab_command(Some(Path::new("/tmp/work")), None);
+        assert_eq!(command.get_program(), "wezterm");
+        assert_eq!(args(&command), vec!["cli", "spawn", "--cwd", "/tmp/work"]);
+    }
+
+    #[test]
+    fn test_spawn_tab_command_runs_command_at_cwd() {
+        let command =
+            WeztermHandler::spawn_tab_command(Some(Path::new("/tmp/work")), Some("lazygit"));
+        assert_eq!(
+            args(&command),
+            vec![
+                "cli",
+                "spawn",
+                "--cwd",
+                "/tmp/work",
+                "--",
+                "bash",
+                "-c",
+                "lazygit"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_spawn_tab_command_runs_tmux_attach() {
+        let command = WeztermHandler::spawn_tab_command(None, Some("tmux attach -t tina"));
+        assert_eq!(
+            args(&command),
+            vec!["cli", "spawn", "--", "bash", "-c", "tmux attach -t tina"]
+        );
+    }
+}
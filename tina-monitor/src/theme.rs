@@ -0,0 +1,378 @@
+//! Semantic color themes for the TUI
+//!
+//! Views ask for colors by meaning (`focused_border`, `status_blocked`, ...)
+//! instead of hardcoding them. The theme is chosen from the `[theme]` config
+//! section: a built-in preset plus optional per-color overrides.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+
+use crate::data::MonitorOrchestrationStatus;
+
+/// Built-in theme presets
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemePreset {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+/// `[theme]` config section
+///
+/// ```toml
+/// [theme]
+/// preset = "light"
+/// focused_border = "magenta"
+/// status_blocked = "#ff5555"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    pub preset: ThemePreset,
+    /// Use the plain theme, ignoring the preset and overrides
+    pub no_color: bool,
+    /// Per-color overrides keyed by `Theme` field name. Values are color
+    /// names, `#rrggbb`, or 256-color indices.
+    #[serde(flatten)]
+    pub overrides: BTreeMap<String, String>,
+}
+
+/// Resolved semantic colors
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    /// Titles, headers, and the names of selected things
+    pub accent: Color,
+    /// Secondary accent (e.g. agent results in search)
+    pub secondary: Color,
+    pub focused_border: Color,
+    pub border: Color,
+    /// Body text in modals
+    pub text: Color,
+    /// Hints, labels, and other de-emphasized text
+    pub muted: Color,
+    /// Input prompts, commit hashes, owners
+    pub highlight: Color,
+    /// Background of the selected list row
+    pub selection_bg: Color,
+    /// Status/title bar colors
+    pub bar_fg: Color,
+    pub bar_bg: Color,
+    /// Partially filled progress bars
+    pub progress: Color,
+    pub status_executing: Color,
+    pub status_planning: Color,
+    pub status_reviewing: Color,
+    pub status_blocked: Color,
+    pub status_complete: Color,
+    pub status_idle: Color,
+    pub success: Color,
+    pub info: Color,
+    pub warning: Color,
+    pub error: Color,
+    /// Background behind words changed within an added/removed diff line
+    pub diff_added_bg: Color,
+    pub diff_removed_bg: Color,
+    /// No colors; views mark state with text instead
+    pub plain: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::preset(ThemePreset::Dark)
+    }
+}
+
+impl Theme {
+    /// Colors for a built-in preset
+    pub fn preset(preset: ThemePreset) -> Self {
+        match preset {
+            ThemePreset::Dark => Self {
+                accent: Color::Cyan,
+                secondary: Color::Magenta,
+                focused_border: Color::Cyan,
+                border: Color::DarkGray,
+                text: Color::White,
+                muted: Color::DarkGray,
+                highlight: Color::Yellow,
+                selection_bg: Color::DarkGray,
+                bar_fg: Color::White,
+                bar_bg: Color::Black,
+                progress: Color::Blue,
+                status_executing: Color::Green,
+                status_planning: Color::Yellow,
+                status_reviewing: Color::Cyan,
+                status_blocked: Color::Red,
+                status_complete: Color::Blue,
+                status_idle: Color::DarkGray,
+                success: Color::Green,
+                info: Color::Cyan,
+                warning: Color::Yellow,
+                error: Color::Red,
+                diff_added_bg: Color::Rgb(0, 95, 0),
+                diff_removed_bg: Color::Rgb(95, 0, 0),
+                plain: false,
+            },
+            ThemePreset::Light => {
+                // Yellow is unreadable on a light background
+                let amber = Color::Rgb(175, 95, 0);
+                Self {
+                    accent: Color::Blue,
+                    secondary: Color::Magenta,
+                    focused_border: Color::Blue,
+                    border: Color::Gray,
+                    text: Color::Black,
+                    muted: Color::DarkGray,
+                    highlight: amber,
+                    selection_bg: Color::Gray,
+                    bar_fg: Color::Black,
+                    bar_bg: Color::Gray,
+                    progress: Color::Blue,
+                    status_executing: Color::Green,
+                    status_planning: amber,
+                    status_reviewing: Color::Magenta,
+                    status_blocked: Color::Red,
+                    status_complete: Color::Blue,
+                    status_idle: Color::DarkGray,
+                    success: Color::Green,
+                    info: Color::Blue,
+                    warning: amber,
+                    error: Color::Red,
+                    diff_added_bg: Color::Rgb(190, 240, 190),
+                    diff_removed_bg: Color::Rgb(250, 200, 200),
+                    plain: false,
+                }
+            }
+            ThemePreset::HighContrast => Self {
+                accent: Color::LightCyan,
+                secondary: Color::LightMagenta,
+                focused_border: Color::LightYellow,
+                border: Color::White,
+                text: Color::White,
+                muted: Color::Gray,
+                highlight: Color::LightYellow,
+                selection_bg: Color::Blue,
+                bar_fg: Color::White,
+                bar_bg: Color::Black,
+                progress: Color::LightBlue,
+                status_executing: Color::LightGreen,
+                status_planning: Color::LightYellow,
+                status_reviewing: Color::LightCyan,
+                status_blocked: Color::LightRed,
+                status_complete: Color::LightBlue,
+                status_idle: Color::Gray,
+                success: Color::LightGreen,
+                info: Color::LightCyan,
+                warning: Color::LightYellow,
+                error: Color::LightRed,
+                diff_added_bg: Color::Green,
+                diff_removed_bg: Color::Red,
+                plain: false,
+            },
+        }
+    }
+
+    /// The colorless theme, for low-color terminals, screen readers, and
+    /// output piped to files
+    pub fn plain() -> Self {
+        let none = Color::Reset;
+        Self {
+            accent: none,
+            secondary: none,
+            focused_border: none,
+            border: none,
+            text: none,
+            muted: none,
+            highlight: none,
+            selection_bg: none,
+            bar_fg: none,
+            bar_bg: none,
+            progress: none,
+            status_executing: none,
+            status_planning: none,
+            status_reviewing: none,
+            status_blocked: none,
+            status_complete: none,
+            status_idle: none,
+            success: none,
+            info: none,
+            warning: none,
+            error: none,
+            diff_added_bg: none,
+            diff_removed_bg: none,
+            plain: true,
+        }
+    }
+
+    /// Resolve a `[theme]` section: the preset with overrides applied
+    pub fn from_config(config: &ThemeConfig) -> anyhow::Result<Self> {
+        if config.no_color {
+            return Ok(Self::plain());
+        }
+        let mut theme = Self::preset(config.preset);
+        for (name, value) in &config.overrides {
+            let color = Color::from_str(value)
+                .map_err(|_| anyhow::anyhow!("invalid color for theme.{}: {:?}", name, value))?;
+            let slot = theme
+                .slot_mut(name)
+                .ok_or_else(|| anyhow::anyhow!("unknown theme color: {}", name))?;
+            *slot = color;
+        }
+        Ok(theme)
+    }
+
+    fn slot_mut(&mut self, name: &str) -> Option<&mut Color> {
+        Some(match name {
+            "accent" => &mut self.accent,
+            "secondary" => &mut self.secondary,
+            "focused_border" => &mut self.focused_border,
+            "border" => &mut self.border,
+            "text" => &mut self.text,
+            "muted" => &mut self.muted,
+            "highlight" => &mut self.highlight,
+            "selection_bg" => &mut self.selection_bg,
+            "bar_fg" => &mut self.bar_fg,
+            "bar_bg" => &mut self.bar_bg,
+            "progress" => &mut self.progress,
+            "status_executing" => &mut self.status_executing,
+            "status_planning" => &mut self.status_planning,
+            "status_reviewing" => &mut self.status_reviewing,
+            "status_blocked" => &mut self.status_blocked,
+            "status_complete" => &mut self.status_complete,
+            "status_idle" => &mut self.status_idle,
+            "success" => &mut self.success,
+            "info" => &mut self.info,
+            "warning" => &mut self.warning,
+            "error" => &mut self.error,
+            "diff_added_bg" => &mut self.diff_added_bg,
+            "diff_removed_bg" => &mut self.diff_removed_bg,
+            _ => return None,
+        })
+    }
+
+    /// Color for an orchestration status
+    pub fn status(&self, status: &MonitorOrchestrationStatus) -> Color {
+        match status {
+            MonitorOrchestrationStatus::Executing => self.status_executing,
+            MonitorOrchestrationStatus::Planning => self.status_planning,
+            MonitorOrchestrationStatus::Reviewing => self.status_reviewing,
+            MonitorOrchestrationStatus::Blocked => self.status_blocked,
+            MonitorOrchestrationStatus::Complete => self.status_complete,
+            MonitorOrchestrationStatus::Idle => self.status_idle,
+        }
+    }
+
+    /// `glyph`, or `text` (e.g. `[BLOCKED]`) under the plain theme, for
+    /// states told apart only by color otherwise
+    pub fn marker<'a>(&self, glyph: &'a str, text: &'a str) -> &'a str {
+        if self.plain {
+            text
+        } else {
+            glyph
+        }
+    }
+
+    /// Style for the selected row: reversed under the plain theme, which
+    /// has no background color to show it with
+    pub fn selection(&self) -> Style {
+        if self.plain {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().bg(self.selection_bg)
+        }
+    }
+}
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Install the theme for this process. Only the first call takes effect.
+pub fn init(theme: Theme) {
+    let _ = THEME.set(theme);
+}
+
+/// The active theme (the dark preset until `init` is called)
+pub fn current() -> &'static Theme {
+    THEME.get_or_init(Theme::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_dark_preset() {
+        let theme = Theme::default();
+        assert_eq!(theme, Theme::preset(ThemePreset::Dark));
+        assert_eq!(theme.focused_border, Color::Cyan);
+        assert_eq!(theme.border, Color::DarkGray);
+    }
+
+    #[test]
+    fn test_from_config_applies_preset_and_overrides() {
+        let config: ThemeConfig = toml::from_str(
+            r##"
+preset = "high-contrast"
+focused_border = "magenta"
+status_blocked = "#ff5555"
+"##,
+        )
+        .unwrap();
+
+        let theme = Theme::from_config(&config).unwrap();
+        assert_eq!(theme.focused_border, Color::Magenta);
+        assert_eq!(theme.status_blocked, Color::Rgb(0xff, 0x55, 0x55));
+        assert_eq!(theme.accent, Color::LightCyan);
+    }
+
+    #[test]
+    fn test_from_config_rejects_unknown_names_and_bad_colors() {
+        let mut config = ThemeConfig::default();
+        config
+            .overrides
+            .insert("not_a_slot".to_string(), "red".to_string());
+        assert!(Theme::from_config(&config).is_err());
+
+        let mut config = ThemeConfig::default();
+        config
+            .overrides
+            .insert("accent".to_string(), "not-a-color".to_string());
+        assert!(Theme::from_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_no_color_gives_the_plain_theme() {
+        let config: ThemeConfig = toml::from_str(
+            r##"
+preset = "light"
+no_color = true
+accent = "red"
+"##,
+        )
+        .unwrap();
+
+        let theme = Theme::from_config(&config).unwrap();
+        assert_eq!(theme, Theme::plain());
+        assert_eq!(theme.accent, Color::Reset);
+        assert_eq!(theme.marker("✗", "[BLOCKED]"), "[BLOCKED]");
+        assert_eq!(
+            theme.selection(),
+            Style::default().add_modifier(Modifier::REVERSED)
+        );
+
+        let dark = Theme::default();
+        assert_eq!(dark.marker("✗", "[BLOCKED]"), "✗");
+        assert_eq!(dark.selection(), Style::default().bg(dark.selection_bg));
+    }
+
+    #[test]
+    fn test_light_preset_avoids_yellow() {
+        let theme = Theme::preset(ThemePreset::Light);
+        assert_ne!(theme.highlight, Color::Yellow);
+        assert_ne!(theme.warning, Color::Yellow);
+    }
+}
@@ -50,6 +50,17 @@ pub fn pane_exists(pane_id: &str) -> bool {
 
 /// Capture output from a tmux pane
 pub fn capture_pane(pane_id: &str, lines: usize) -> Result<String, CaptureError> {
+    capture_pane_from(pane_id, &format!("-{}", lines))
+}
+
+/// Capture a pane's entire scrollback history plus its visible content
+pub fn capture_pane_history(pane_id: &str) -> Result<String, CaptureError> {
+    capture_pane_from(pane_id, "-")
+}
+
+/// Run `capture-pane` starting at `start` (a negative line offset, or `-`
+/// for the beginning of history)
+fn capture_pane_from(pane_id: &str, start: &str) -> Result<String, CaptureError> {
     if !is_tmux_available() {
         return Err(CaptureError::TmuxNotFound(
             "tmux command not found".to_string(),
@@ -57,14 +68,7 @@ pub fn capture_pane(pane_id: &str, lines: usize) -> Result<String, CaptureError>
     }
 
     let output = Command::new("tmux")
-        .args([
-            "capture-pane",
-            "-t",
-            pane_id,
-            "-p",
-            "-S",
-            &format!("-{}", lines),
-        ])
+        .args(["capture-pane", "-t", pane_id, "-p", "-S", start])
         .output()
         .map_err(|e| CaptureError::CaptureFailed(format!("Failed to execute tmux: {}", e)))?;
 
@@ -0,0 +1,89 @@
+//! Export a pane's full output to a file
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+use thiserror::Error;
+
+use super::capture::{capture_pane_history, CaptureError};
+
+/// Errors that can occur while exporting pane output
+#[derive(Debug, Error)]
+pub enum ExportError {
+    #[error(transparent)]
+    Capture(#[from] CaptureError),
+
+    #[error("Failed to write {path}: {source}")]
+    WriteFailed {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+/// `<label>-<YYYYmmdd-HHMMSS>.log`, with anything outside `[A-Za-z0-9_.-]`
+/// in the label replaced so agent names can't escape the directory
+pub fn export_file_name(label: &str, now: DateTime<Local>) -> String {
+    let label: String = label
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("{}-{}.log", label, now.format("%Y%m%d-%H%M%S"))
+}
+
+/// Write `content` to a timestamped file under `dir`, creating it if needed
+pub fn write_export(dir: &Path, label: &str, content: &str) -> Result<PathBuf, ExportError> {
+    let path = dir.join(export_file_name(label, Local::now()));
+    fs::create_dir_all(dir)
+        .and_then(|_| fs::write(&path, content))
+        .map_err(|source| ExportError::WriteFailed {
+            path: path.clone(),
+            source,
+        })?;
+    Ok(path)
+}
+
+/// Capture the pane's entire history and write it under `dir`
+pub fn export_pane_history(pane_id: &str, label: &str, dir: &Path) -> Result<PathBuf, ExportError> {
+    let content = capture_pane_history(pane_id)?;
+    write_export(dir, label, &content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_export_file_name_is_timestamped_and_sanitized() {
+        let now = Local.with_ymd_and_hms(2026, 2, 10, 9, 5, 3).unwrap();
+        assert_eq!(
+            export_file_name("auth/executor 1", now),
+            "auth-executor-1-20260210-090503.log"
+        );
+    }
+
+    #[test]
+    fn test_write_export_creates_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("captures");
+        let path = write_export(&target, "auth-executor-1", "line 1\nline 2\n").unwrap();
+
+        assert!(path.starts_with(&target));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "line 1\nline 2\n");
+    }
+
+    #[test]
+    fn test_export_pane_history_with_invalid_pane_returns_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let result = export_pane_history("definitely-not-a-real-pane-id", "agent", dir.path());
+        assert!(matches!(result, Err(ExportError::Capture(_))));
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+}
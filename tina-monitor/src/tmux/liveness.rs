@@ -0,0 +1,170 @@
+//! Whether agents' tmux panes are still there, and how long they've been quiet
+//!
+//! One `tmux list-panes -a` call covers every pane, so the Members pane can
+//! check all agents without a tmux call per row per frame.
+
+use std::collections::HashMap;
+use std::process::Command;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use super::capture::CaptureError;
+
+/// Agents quiet for this long are shown as stalled
+pub const STALL_THRESHOLD_MINS: i64 = 10;
+
+/// A pane as listed by tmux
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaneActivity {
+    /// The pane's process has exited but the pane was kept open
+    pub dead: bool,
+    /// Last output in the pane's window
+    pub last_activity: Option<DateTime<Utc>>,
+}
+
+/// How an agent's pane is doing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Liveness {
+    /// Pane state couldn't be read, e.g. tmux isn't running here
+    Unknown,
+    /// The agent has no tmux pane recorded
+    NoPane,
+    /// The pane is closed or its process has exited
+    Gone,
+    /// Output within the stall threshold; holds the time since
+    Active(Duration),
+    /// No output for at least the stall threshold; holds the time since
+    Stalled(Duration),
+}
+
+impl Liveness {
+    /// Classify `pane_id` against a pane listing. `context_at` is when the
+    /// agent's statusline last recorded context metrics, which counts as
+    /// activity too.
+    pub fn of(
+        pane_id: Option<&str>,
+        panes: Option<&HashMap<String, PaneActivity>>,
+        context_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let Some(pane_id) = pane_id else {
+            return Liveness::NoPane;
+        };
+        let Some(panes) = panes else {
+            return Liveness::Unknown;
+        };
+        let pane = match panes.get(pane_id) {
+            Some(pane) if !pane.dead => pane,
+            _ => return Liveness::Gone,
+        };
+        let Some(last) = pane.last_activity.max(context_at) else {
+            return Liveness::Unknown;
+        };
+        let idle = (now - last).max(Duration::zero());
+        if idle >= Duration::minutes(STALL_THRESHOLD_MINS) {
+            Liveness::Stalled(idle)
+        } else {
+            Liveness::Active(idle)
+        }
+    }
+}
+
+/// Every pane tmux knows about, keyed by pane id (e.g. `%12`)
+pub fn list_pane_activity() -> Result<HashMap<String, PaneActivity>, CaptureError> {
+    let output = Command::new("tmux")
+        .args([
+            "list-panes",
+            "-a",
+            "-F",
+            "#{pane_id} #{pane_dead} #{window_activity}",
+        ])
+        .output()
+        .map_err(|e| CaptureError::TmuxNotFound(e.to_string()))?;
+    if !output.status.success() {
+        return Err(CaptureError::CaptureFailed(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_pane_activity(&stdout))
+}
+
+/// Parse `list-panes` lines of `<pane_id> <pane_dead> <window_activity>`
+pub fn parse_pane_activity(output: &str) -> HashMap<String, PaneActivity> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let id = fields.next()?;
+            let dead = fields.next()? == "1";
+            let last_activity = fields
+                .next()
+                .and_then(|s| s.parse::<i64>().ok())
+                .and_then(|secs| Utc.timestamp_opt(secs, 0).single());
+            Some((
+                id.to_string(),
+                PaneActivity {
+                    dead,
+                    last_activity,
+                },
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(secs, 0).unwrap()
+    }
+
+    #[test]
+    fn parses_list_panes_output() {
+        let panes = parse_pane_activity("%1 0 1770000000\n%2 1 1770000100\n\ngarbage\n");
+        assert_eq!(panes.len(), 2);
+        assert_eq!(
+            panes["%1"],
+            PaneActivity {
+                dead: false,
+                last_activity: Some(at(1_770_000_000)),
+            }
+        );
+        assert!(panes["%2"].dead);
+    }
+
+    #[test]
+    fn classifies_panes() {
+        let panes = parse_pane_activity("%1 0 1000\n%2 1 1000\n");
+        let now = at(1000 + 60 * STALL_THRESHOLD_MINS);
+
+        assert_eq!(
+            Liveness::of(None, Some(&panes), None, now),
+            Liveness::NoPane
+        );
+        assert_eq!(Liveness::of(Some("%1"), None, None, now), Liveness::Unknown);
+        assert_eq!(
+            Liveness::of(Some("%2"), Some(&panes), None, now),
+            Liveness::Gone
+        );
+        assert_eq!(
+            Liveness::of(Some("%9"), Some(&panes), None, now),
+            Liveness::Gone
+        );
+        assert_eq!(
+            Liveness::of(Some("%1"), Some(&panes), None, now),
+            Liveness::Stalled(Duration::minutes(STALL_THRESHOLD_MINS))
+        );
+        // A fresh statusline update counts as activity
+        assert_eq!(
+            Liveness::of(
+                Some("%1"),
+                Some(&panes),
+                Some(now - Duration::seconds(30)),
+                now
+            ),
+            Liveness::Active(Duration::seconds(30))
+        );
+    }
+}
@@ -1,7 +1,12 @@
 //! Tmux integration module
 
 pub mod capture;
+pub mod export;
+pub mod liveness;
 pub mod send;
+pub mod stream;
 
 pub use capture::{capture_pane, is_tmux_available, pane_exists, CaptureError};
-pub use send::{send_keys, send_keys_raw, SendError};
+pub use export::{export_pane_history, ExportError};
+pub use send::{send_keys, send_keys_raw, send_text, SendError};
+pub use stream::PaneStream;
@@ -1,6 +1,7 @@
 //! Tmux send keys functionality
 
-use std::process::Command;
+use std::io::Write;
+use std::process::{Command, Stdio};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -56,6 +57,86 @@ pub fn send_keys_raw(pane_id: &str, text: &str) -> Result<(), SendError> {
     send_keys_internal(pane_id, &[text])
 }
 
+/// Send text to a tmux pane followed by Enter, pasting multi-line text
+/// through a tmux buffer
+///
+/// `send-keys` would submit each line as its own Enter; a bracketed paste
+/// delivers the whole text as one input instead.
+pub fn send_text(pane_id: &str, text: &str) -> Result<(), SendError> {
+    if !text.contains('\n') {
+        return send_keys(pane_id, text);
+    }
+    if !is_tmux_available() {
+        return Err(SendError::TmuxNotFound(
+            "tmux command not found".to_string(),
+        ));
+    }
+
+    let buffer = buffer_name();
+    load_buffer(&buffer, text)?;
+    if let Err(e) = run_tmux(paste_buffer_command(&buffer, pane_id), "paste-buffer") {
+        // paste-buffer -d only deletes the buffer once it pastes
+        let _ = Command::new("tmux")
+            .args(["delete-buffer", "-b", &buffer])
+            .output();
+        return Err(e);
+    }
+    send_keys_internal(pane_id, &["Enter"])
+}
+
+/// Buffer name private to this process, so concurrent monitors don't
+/// paste each other's text
+fn buffer_name() -> String {
+    format!("tina-send-{}", std::process::id())
+}
+
+/// Load `text` into a named tmux buffer from stdin
+fn load_buffer(buffer: &str, text: &str) -> Result<(), SendError> {
+    let mut child = Command::new("tmux")
+        .args(["load-buffer", "-b", buffer, "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| SendError::SendFailed(format!("Failed to execute tmux: {}", e)))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(text.as_bytes())
+            .map_err(|e| SendError::SendFailed(format!("Failed to write buffer: {}", e)))?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| SendError::SendFailed(format!("Failed to execute tmux: {}", e)))?;
+    if !output.status.success() {
+        return Err(SendError::SendFailed(format!(
+            "tmux load-buffer failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+    Ok(())
+}
+
+/// Paste and delete a buffer, bracketed so the pane sees a single paste
+fn paste_buffer_command(buffer: &str, pane_id: &str) -> Command {
+    let mut command = Command::new("tmux");
+    command.args(["paste-buffer", "-d", "-p", "-b", buffer, "-t", pane_id]);
+    command
+}
+
+fn run_tmux(mut command: Command, action: &str) -> Result<(), SendError> {
+    let output = command
+        .output()
+        .map_err(|e| SendError::SendFailed(format!("Failed to execute tmux: {}", e)))?;
+    if !output.status.success() {
+        return Err(SendError::SendFailed(format!(
+            "tmux {} failed: {}",
+            action,
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,6 +169,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_send_text_returns_error_for_invalid_pane() {
+        let invalid_pane_id = "definitely-not-a-real-pane-id-multiline";
+        let result = send_text(invalid_pane_id, "first line\nsecond line");
+
+        match result {
+            Err(SendError::SendFailed(_)) | Err(SendError::TmuxNotFound(_)) => (),
+            Ok(_) => panic!("Expected error but got success"),
+        }
+    }
+
+    #[test]
+    fn test_paste_buffer_command_is_bracketed_and_deletes_buffer() {
+        let command = paste_buffer_command("tina-send-1", "%3");
+        let args: Vec<String> = command
+            .get_args()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(
+            args,
+            vec!["paste-buffer", "-d", "-p", "-b", "tina-send-1", "-t", "%3"]
+        );
+    }
+
     #[test]
     fn test_send_keys_handles_special_characters() {
         // This will fail with invalid pane, but we're testing that special chars
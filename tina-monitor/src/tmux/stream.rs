@@ -0,0 +1,245 @@
+//! Streaming pane output via `tmux pipe-pane`
+//!
+//! Polling `capture-pane` only sees what is still in the pane's scrollback
+//! and runs a tmux command every tick. A `PaneStream` instead seeds a log
+//! file with the pane's full history, asks tmux to append everything the
+//! pane prints afterwards, and tails that file.
+
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::capture::{is_tmux_available, CaptureError};
+
+/// Incrementally reads complete lines appended to a file
+#[derive(Debug)]
+pub struct LogTail {
+    path: PathBuf,
+    offset: u64,
+    partial: Vec<u8>,
+}
+
+impl LogTail {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            offset: 0,
+            partial: Vec::new(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Read lines appended since the last call. A trailing line without a
+    /// newline is held back until it is completed.
+    pub fn read_new_lines(&mut self) -> io::Result<Vec<String>> {
+        let mut file = File::open(&self.path)?;
+        let len = file.metadata()?.len();
+        if len < self.offset {
+            // Truncated underneath us; start over
+            self.offset = 0;
+            self.partial.clear();
+        }
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut buf = Vec::new();
+        file.read_to_end(&mut buf)?;
+        self.offset += buf.len() as u64;
+        self.partial.extend_from_slice(&buf);
+
+        let Some(last_newline) = self.partial.iter().rposition(|&b| b == b'\n') else {
+            return Ok(Vec::new());
+        };
+        let complete: Vec<u8> = self.partial.drain(..=last_newline).collect();
+        Ok(String::from_utf8_lossy(&complete)
+            .lines()
+            .map(clean_line)
+            .collect())
+    }
+}
+
+/// Strip terminal escape sequences from raw pane output and keep only what
+/// was last drawn after a carriage return
+pub fn clean_line(raw: &str) -> String {
+    let visible = raw.rsplit('\r').find(|s| !s.is_empty()).unwrap_or("");
+    let mut out = String::with_capacity(visible.len());
+    let mut chars = visible.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' => match chars.next() {
+                // CSI: parameters end at a byte in @..~
+                Some('[') => {
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                // OSC: terminated by BEL or ESC \
+                Some(']') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' {
+                            break;
+                        }
+                        if c == '\x1b' && chars.peek() == Some(&'\\') {
+                            chars.next();
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            },
+            c if c.is_control() && c != '\t' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// A pane whose output is being piped to a log file
+#[derive(Debug)]
+pub struct PaneStream {
+    pane_id: String,
+    tail: LogTail,
+}
+
+impl PaneStream {
+    /// Seed a log file with the pane's full history and start piping new
+    /// output into it
+    pub fn start(pane_id: &str) -> Result<Self, CaptureError> {
+        if !is_tmux_available() {
+            return Err(CaptureError::TmuxNotFound(
+                "tmux command not found".to_string(),
+            ));
+        }
+
+        let history = capture_full_history(pane_id)?;
+
+        let path = stream_path(pane_id);
+        fs::write(&path, history).map_err(|e| {
+            CaptureError::CaptureFailed(format!("Failed to create {}: {}", path.display(), e))
+        })?;
+
+        let command = format!("cat >> {}", shell_quote(&path.to_string_lossy()));
+        let output = Command::new("tmux")
+            .args(["pipe-pane", "-t", pane_id, &command])
+            .output()
+            .map_err(|e| CaptureError::CaptureFailed(format!("Failed to execute tmux: {}", e)))?;
+        if !output.status.success() {
+            let _ = fs::remove_file(&path);
+            return Err(CaptureError::CaptureFailed(format!(
+                "tmux pipe-pane failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+
+        Ok(Self {
+            pane_id: pane_id.to_string(),
+            tail: LogTail::new(path),
+        })
+    }
+
+    /// Lines printed since the last read (the full history on first read)
+    pub fn read_new_lines(&mut self) -> io::Result<Vec<String>> {
+        self.tail.read_new_lines()
+    }
+}
+
+impl Drop for PaneStream {
+    fn drop(&mut self) {
+        // pipe-pane without a command closes the pane's pipe
+        let _ = Command::new("tmux")
+            .args(["pipe-pane", "-t", &self.pane_id])
+            .output();
+        let _ = fs::remove_file(self.tail.path());
+    }
+}
+
+/// Capture the pane's entire scrollback (`-S -`)
+fn capture_full_history(pane_id: &str) -> Result<String, CaptureError> {
+    let output = Command::new("tmux")
+        .args(["capture-pane", "-t", pane_id, "-p", "-S", "-"])
+        .output()
+        .map_err(|e| CaptureError::CaptureFailed(format!("Failed to execute tmux: {}", e)))?;
+    if !output.status.success() {
+        return Err(CaptureError::CaptureFailed(format!(
+            "tmux capture-pane failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Per-process log file for a pane (`%12` -> `tina-monitor-pane-12-<pid>.log`)
+fn stream_path(pane_id: &str) -> PathBuf {
+    let safe: String = pane_id
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+    std::env::temp_dir().join(format!(
+        "tina-monitor-pane-{}-{}.log",
+        safe,
+        std::process::id()
+    ))
+}
+
+pub(crate) fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::OpenOptions;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_log_tail_reads_only_complete_new_lines() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("pane.log");
+        fs::write(&path, "one\ntwo\nthr").unwrap();
+
+        let mut tail = LogTail::new(path.clone());
+        assert_eq!(tail.read_new_lines().unwrap(), vec!["one", "two"]);
+        assert!(tail.read_new_lines().unwrap().is_empty());
+
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        write!(file, "ee\nfour\n").unwrap();
+        assert_eq!(tail.read_new_lines().unwrap(), vec!["three", "four"]);
+    }
+
+    #[test]
+    fn test_log_tail_restarts_after_truncation() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("pane.log");
+        fs::write(&path, "old line\n").unwrap();
+
+        let mut tail = LogTail::new(path.clone());
+        tail.read_new_lines().unwrap();
+        fs::write(&path, "new\n").unwrap();
+        assert_eq!(tail.read_new_lines().unwrap(), vec!["new"]);
+    }
+
+    #[test]
+    fn test_clean_line_strips_escapes_and_carriage_returns() {
+        assert_eq!(clean_line("\x1b[1;32mok\x1b[0m done"), "ok done");
+        assert_eq!(clean_line("\x1b]0;title\x07prompt"), "prompt");
+        assert_eq!(clean_line("progress 10%\rprogress 90%\r"), "progress 90%");
+        assert_eq!(clean_line("a\tb"), "a\tb");
+    }
+
+    #[test]
+    fn test_start_with_invalid_pane_returns_error() {
+        assert!(PaneStream::start("definitely-not-a-real-pane-id").is_err());
+    }
+
+    #[test]
+    fn test_stream_path_is_filesystem_safe() {
+        let path = stream_path("%12");
+        let name = path.file_name().unwrap().to_string_lossy();
+        assert!(name.starts_with("tina-monitor-pane-12-"));
+    }
+}
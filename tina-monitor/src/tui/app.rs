@@ -1,15 +1,98 @@
 //! TUI application state and event loop
 
+use anyhow::Context;
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use ratatui::{backend::Backend, Terminal};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::mpsc;
 use std::time::{Duration, Instant};
+use tina_data::{ExecutionTaskRecord, NodeRecord, ProjectRecord};
+use tina_session::project_config::ProjectLayout;
 
+use super::notifications::Notifications;
+use super::session::{SavedView, SessionState};
 use super::ui;
-use crate::config::Config;
-use crate::data::MonitorOrchestration;
+use super::views::bulk_tasks::{BulkModelEditor, BulkOutcome, BulkResult, BulkStage};
+use super::views::commit_detail::{CommitDetailState, CommitDetailView, TreeEntry};
+use super::views::commits_view::CommitsView;
+use super::views::diff_view::SideBySide;
+use super::views::orchestration_list;
+use super::views::phase_timeline::TaskSpan;
+use super::views::projects::{self, ProjectForm, ProjectFormResult};
+use super::views::replay::ReplayViewer;
+use super::views::spec_viewer::SpecViewer;
+use super::views::stuck_tasks::StuckTasks;
+use super::views::task_edit::{TaskEditResult, TaskEditor};
+use crate::config::{Config, CostConfig, ListConfig, ListSort};
+use crate::control::ControlAction;
+use crate::data::changes::{self, ChangeKind};
+use crate::data::live::{LiveUpdate, LiveUpdates};
+use crate::data::replay::Replay;
+use crate::data::timeline::{build_timeline, work_window, TimelineEntry};
+use crate::data::{
+    ConvexDataSource, MonitorOrchestration, MonitorOrchestrationStatus, OrchestrationPage,
+    ReviewStatus,
+};
+use crate::desktop_notify::DesktopNotifier;
+use crate::editor::{self, EditorTarget};
+use crate::git::commits::get_commits_between;
+use crate::git::status::WorktreeStatus;
+use crate::overlay::fuzzy::{
+    self, GlobalSearchResult, GlobalSearchState, SearchItem, SearchKind, SearchTarget,
+};
+use crate::overlay::palette::{self, PaletteItem, PaletteResult, PaletteState};
 use crate::terminal::{get_handler, TerminalResult};
-use crate::types::Team;
-use crate::watcher::DataWatcher;
+use crate::tmux::liveness::{Liveness, PaneActivity};
+use crate::types::{SupervisorState, Task, TaskStatus, Team};
+
+/// How many recent commits per worktree are offered in global search
+const SEARCH_COMMIT_LIMIT: usize = 30;
+
+/// Pending review gates per orchestration id
+type PendingGates = HashMap<String, BTreeSet<String>>;
+
+/// How often the dashboard reloads orchestration details
+const DASHBOARD_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often agents' tmux panes are re-listed while phase detail is open
+const PANE_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Orchestrations fetched per page of the list
+const LIST_PAGE_SIZE: usize = 100;
+
+/// Fetch the next page once the selection is this close to the end
+const LOAD_MORE_MARGIN: usize = 10;
+
+/// Orchestrations, pending gates, and paging state from one list load
+pub(crate) type LoadedList = (Vec<MonitorOrchestration>, PendingGates, ListPaging);
+
+/// A list load running on a background thread so slow Convex calls don't
+/// freeze the render loop
+pub(crate) struct PendingLoad {
+    pub(crate) rx: mpsc::Receiver<anyhow::Result<LoadedList>>,
+    /// Orchestration selected when the load started, reselected once it lands
+    pub(crate) selected_id: Option<String>,
+}
+
+/// Keyset paging state for the orchestration list
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ListPaging {
+    /// Cursor for the next unloaded page; `None` once everything is loaded
+    pub(crate) next_cursor: Option<String>,
+    /// Total orchestrations, reported with the first page
+    pub(crate) total_count: Option<usize>,
+    /// Clocks found drifting from Convex time on the last full load
+    pub(crate) clock_warnings: Vec<String>,
+    /// Project names by project id, for the list's project sections
+    pub(crate) project_names: HashMap<String, String>,
+    /// Every Convex project, for the Projects view
+    pub(crate) projects: Vec<ProjectRecord>,
+    /// Nodes by node id, for the status bar's daemon heartbeat
+    pub(crate) nodes: HashMap<String, NodeRecord>,
+    /// Review findings and gates by orchestration id, for the Phases pane
+    pub(crate) reviews: HashMap<String, ReviewStatus>,
+}
 
 /// Result type for TUI operations
 pub type AppResult<T> = Result<T, Box<dyn std::error::Error>>;
@@ -19,6 +102,15 @@ pub type AppResult<T> = Result<T, Box<dyn std::error::Error>>;
 pub enum ViewState {
     /// Main orchestration list view
     OrchestrationList,
+    /// Side-by-side cards for every active orchestration (wall display)
+    Dashboard,
+    /// Convex projects with their orchestration counts
+    Projects {
+        /// Selected row of [`projects::rows`]
+        selected: usize,
+        /// New project form, when open
+        form: Option<ProjectForm>,
+    },
     /// Phase detail view
     PhaseDetail {
         /// Which pane has focus
@@ -77,6 +169,10 @@ pub enum ViewState {
         range: String,
         /// Modal title
         title: String,
+        /// Selected commit index
+        selected: usize,
+        /// Open commit detail pane, if any
+        detail: Option<CommitDetailState>,
     },
     /// Diff view modal
     DiffView {
@@ -92,11 +188,81 @@ pub enum ViewState {
         show_full: bool,
         /// Scroll offset for full diff
         scroll: u16,
+        /// Side-by-side layout for the full diff
+        side_by_side: SideBySide,
+    },
+    /// Spec document from Convex, shown beside the phases
+    SpecViewer,
+    /// Step-through replay of the orchestration's recorded events
+    Replay,
+    /// Confirmation, with a reason prompt, before retrying a phase from the
+    /// Phases pane
+    ConfirmRetryPhase {
+        /// Phase to retry
+        phase: u32,
+        /// Reason recorded with the retry; may be left empty
+        reason: String,
+    },
+    /// Confirmation before stopping a phase from the Members pane
+    ConfirmStopPhase {
+        /// Phase whose session would be stopped
+        phase: u32,
+        /// Member selected when the stop was requested
+        member_index: usize,
+        /// Phase detail layout to return to
+        layout: PhaseDetailLayout,
+    },
+    /// Spend per phase and role, with budget bars
+    CostPanel {
+        /// Phase highlighted
+        selected_phase: u32,
+        /// Budgets from `[cost]`, read when the panel opened
+        budget: CostConfig,
+    },
+    /// Edit popup for a pending task in the Tasks pane
+    TaskEdit {
+        editor: TaskEditor,
+        /// Phase to return to
+        selected_phase: u32,
+    },
+    /// Model override for every task marked in the Tasks pane
+    BulkTaskModel {
+        editor: BulkModelEditor,
+        /// Phase to return to
+        selected_phase: u32,
+    },
+    /// Dependency graph of the phase's tasks
+    TaskGraph {
+        /// Phase whose tasks are drawn
+        selected_phase: u32,
+        /// Rows scrolled down
+        scroll_y: u16,
+        /// Columns scrolled right
+        scroll_x: u16,
+    },
+    /// Uncommitted and untracked changes in the orchestration's worktree
+    WorktreeStatus {
+        /// Worktree path
+        worktree_path: std::path::PathBuf,
+        /// Status when the modal opened or was refreshed, or why it failed
+        status: Result<WorktreeStatus, String>,
+        /// Scroll offset
+        scroll: u16,
+        /// Phase to return to
+        selected_phase: u32,
+    },
+    /// Phase timeline (Gantt) modal
+    PhaseTimeline {
+        /// Phase highlighted, and whose tasks are listed when shown
+        selected_phase: u32,
+        /// Whether task bars for the selected phase are shown
+        show_tasks: bool,
     },
 }
 
 /// Which pane has focus in PhaseDetail view
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum PaneFocus {
     /// Orchestrations pane (left column in Screen 1)
     Orchestrations,
@@ -111,7 +277,8 @@ pub enum PaneFocus {
 }
 
 /// Layout mode for PhaseDetail view
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum PhaseDetailLayout {
     /// Screen 1: Orchestrations | Phases | Tasks+Team
     #[default]
@@ -148,10 +315,6 @@ pub struct App {
     pub tick_rate: Duration,
     /// Whether to show the help modal
     pub show_help: bool,
-    /// File watcher for automatic refresh
-    pub(crate) watcher: Option<DataWatcher>,
-    /// Time of last refresh (for debouncing)
-    pub(crate) last_refresh: Instant,
     /// Current view state
     pub view_state: ViewState,
     /// Log viewer instance
@@ -162,43 +325,116 @@ pub struct App {
     pub(crate) command_logger: Option<crate::logging::CommandLogger>,
     /// Cached phase data for the selected phase (orch_index, phase_number, data)
     pub phase_cache: Option<(usize, u32, PhaseData)>,
+    /// Whether the focused pane (or log viewer) is maximized to the full terminal
+    pub zoomed: bool,
+    /// File queued to open in the editor on the next loop iteration
+    pub(crate) pending_editor: Option<EditorTarget>,
+    /// Global search overlay (`/`), captures all keys while open
+    pub(crate) global_search: Option<GlobalSearchState>,
+    /// When the dashboard last reloaded details (`None` forces a reload)
+    pub(crate) dashboard_refreshed_at: Option<Instant>,
+    /// Event timeline for the open task inspector (`None` when unavailable)
+    pub(crate) task_timeline: Option<Vec<TimelineEntry>>,
+    /// Task bars for the phase timeline's selected phase, loaded on demand
+    pub(crate) phase_timeline_tasks: Vec<TaskSpan>,
+    /// Status-change toasts and their history (`N`)
+    pub(crate) notifications: Notifications,
+    /// Stuck-task threshold and overlay (`S`)
+    pub(crate) stuck_tasks: StuckTasks,
+    /// Cursor and total for the partially loaded orchestration list
+    pub(crate) list_paging: ListPaging,
+    /// Pending review gates from the last load, offered in the palette
+    pub(crate) pending_gates: PendingGates,
+    /// Quick-actions palette (`:` / Ctrl-P), captures all keys while open
+    pub(crate) palette: Option<PaletteState>,
+    /// Spec loaded for the spec viewer (`V`)
+    pub(crate) spec_viewer: Option<SpecViewer>,
+    pub(crate) replay: Option<ReplayViewer>,
+    /// Orchestration list sort order and columns
+    pub(crate) list: ListConfig,
+    /// Whether setting changes (such as the list sort) are saved to the
+    /// config file
+    pub(crate) persist_settings: bool,
+    /// Project sections collapsed in the list (`None` is "no project")
+    pub(crate) collapsed_projects: BTreeSet<Option<String>>,
+    /// Ids of the tasks marked with Space in the Tasks pane, for bulk actions
+    pub(crate) task_marks: BTreeSet<String>,
+    /// `y` was pressed; the next key picks what to copy
+    pub(crate) pending_copy: bool,
+    /// tmux panes from the last listing, for agent liveness in the Members
+    /// pane (`None` before the first listing or without tmux)
+    pub(crate) panes: Option<HashMap<String, PaneActivity>>,
+    /// When `panes` was last listed
+    pub(crate) panes_read_at: Option<Instant>,
+    /// Background list load in flight, shown as "refreshing…"
+    pub(crate) pending_load: Option<PendingLoad>,
+    /// View saved by the previous run, restored once the first load lands
+    pub(crate) restore: Option<SessionState>,
+    /// Convex subscriptions pushing changes as they land (`None` without
+    /// a Convex URL)
+    pub(crate) live: Option<LiveUpdates>,
+    /// When orchestration data last arrived, by load or live update
+    pub(crate) refreshed_at: Option<Instant>,
+    /// Why the last list load failed; cleared by the next one to succeed
+    pub(crate) load_error: Option<String>,
 }
 
 impl App {
     /// Create a new App instance
     pub fn new() -> AppResult<Self> {
         let config = Config::load()?;
-        let watcher = DataWatcher::new(None).ok(); // Don't fail if watcher can't start
-
-        // Load orchestrations from Convex if URL is configured
-        let orchestrations = if !config.convex.url.is_empty() {
-            let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(async {
-                let mut ds = crate::data::ConvexDataSource::new(&config.convex.url).await?;
-                ds.list_orchestrations().await
-            })?
-        } else {
-            vec![]
-        };
+        let notifications =
+            Notifications::with_desktop(DesktopNotifier::new(config.notifications.clone()));
 
         let command_logger = Some(crate::logging::CommandLogger::new(
             config.logging.command_log,
         ));
 
-        Ok(Self {
+        let mut app = Self {
             should_quit: false,
-            orchestrations,
+            orchestrations: Vec::new(),
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger,
             phase_cache: None,
-        })
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications,
+            stuck_tasks: StuckTasks::new(config.tui.stuck_task_threshold_mins),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: true,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
+        };
+        app.list = config.list;
+        app.restore = SessionState::load();
+        // The list fills in once the first load lands
+        app.start_load(&config.convex.url);
+        if !config.convex.url.is_empty() {
+            app.live = Some(LiveUpdates::start(&config.convex.url, LIST_PAGE_SIZE));
+        }
+        Ok(app)
     }
 
     /// Create a new App instance for testing with provided orchestrations
@@ -212,13 +448,36 @@ impl App {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None, // Don't initialize for tests
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         }
     }
 
@@ -282,12 +541,26 @@ impl App {
             .unwrap_or(1)
     }
 
-    /// Move selection to next orchestration (wraps around)
+    /// Move selection to next orchestration (wraps around once the whole
+    /// list is loaded)
     pub fn next(&mut self) {
         if self.orchestrations.is_empty() {
             return;
         }
-        self.selected_index = (self.selected_index + 1) % self.orchestrations.len();
+        if self.list_paging.next_cursor.is_some()
+            && self.selected_index + LOAD_MORE_MARGIN >= self.orchestrations.len()
+        {
+            let _ = self.load_more_orchestrations();
+        }
+        let len = self.orchestrations.len();
+        let mut index = self.selected_index;
+        loop {
+            index = (index + 1) % len;
+            if index == self.selected_index || self.is_list_stop(index) {
+                break;
+            }
+        }
+        self.selected_index = index;
     }
 
     /// Move selection to previous orchestration (wraps around)
@@ -295,23 +568,230 @@ impl App {
         if self.orchestrations.is_empty() {
             return;
         }
-        if self.selected_index == 0 {
-            self.selected_index = self.orchestrations.len() - 1;
-        } else {
-            self.selected_index -= 1;
+        let len = self.orchestrations.len();
+        let mut index = self.selected_index;
+        loop {
+            index = (index + len - 1) % len;
+            if index == self.selected_index || self.is_list_stop(index) {
+                break;
+            }
+        }
+        self.selected_index = index;
+    }
+
+    /// Whether list navigation can land on `index`: inside a collapsed
+    /// project section only the section's first orchestration, which
+    /// stands for its header
+    fn is_list_stop(&self, index: usize) -> bool {
+        if !orchestration_list::is_grouped(self) {
+            return true;
+        }
+        let project = &self.orchestrations[index].project_id;
+        !self.collapsed_projects.contains(project) || self.project_section(index).start == index
+    }
+
+    /// Indices of the project section containing `index`
+    pub(crate) fn project_section(&self, index: usize) -> std::ops::Range<usize> {
+        let project = &self.orchestrations[index].project_id;
+        let same = |i: &usize| &self.orchestrations[*i].project_id == project;
+        let start = (0..index).rev().take_while(same).last().unwrap_or(index);
+        let end = (index..self.orchestrations.len())
+            .take_while(same)
+            .last()
+            .map_or(index, |last| last + 1);
+        start..end
+    }
+
+    /// Whether the selection sits on a collapsed project section
+    fn selected_section_collapsed(&self) -> bool {
+        orchestration_list::is_grouped(self)
+            && self
+                .orchestrations
+                .get(self.selected_index)
+                .is_some_and(|orch| self.collapsed_projects.contains(&orch.project_id))
+    }
+
+    /// Collapse or expand the selected orchestration's project section
+    fn toggle_project_section(&mut self) {
+        if !orchestration_list::is_grouped(self) {
+            return;
+        }
+        let Some(orch) = self.orchestrations.get(self.selected_index) else {
+            return;
+        };
+        let project = orch.project_id.clone();
+        if !self.collapsed_projects.remove(&project) {
+            self.selected_index = self.project_section(self.selected_index).start;
+            self.collapsed_projects.insert(project);
+        }
+    }
+
+    /// Fetch the next page of the orchestration list, if there is one
+    fn load_more_orchestrations(&mut self) -> AppResult<()> {
+        let Some(cursor) = self.list_paging.next_cursor.clone() else {
+            return Ok(());
+        };
+        let config = Config::load()?;
+        if config.convex.url.is_empty() {
+            return Ok(());
+        }
+        let rt = tokio::runtime::Runtime::new()?;
+        let (page, project_names) = rt.block_on(async {
+            let mut ds = ConvexDataSource::new(&config.convex.url).await?;
+            let page = fetch_page(&mut ds, Some(&cursor)).await?;
+            anyhow::Ok((page, ds.project_names().await))
+        })?;
+        let selected_id = self.selected_id();
+        self.orchestrations.extend(page.orchestrations);
+        self.list_paging.next_cursor = page.next_cursor;
+        self.list_paging.project_names = project_names;
+        self.sort_orchestrations(selected_id);
+        Ok(())
+    }
+
+    fn selected_id(&self) -> Option<String> {
+        self.orchestrations
+            .get(self.selected_index)
+            .map(|orch| orch.id.clone())
+    }
+
+    /// Order the list by the configured sort key (within project sections
+    /// when grouped), keeping `selected_id` selected if it is still listed
+    fn sort_orchestrations(&mut self, selected_id: Option<String>) {
+        orchestration_list::sort(&mut self.orchestrations, self.list.sort, chrono::Utc::now());
+        if self.list.group_by_project {
+            orchestration_list::group_by_project(
+                &mut self.orchestrations,
+                &self.list_paging.project_names,
+            );
+        }
+        if let Some(index) =
+            selected_id.and_then(|id| self.orchestrations.iter().position(|orch| orch.id == id))
+        {
+            self.selected_index = index;
+        }
+    }
+
+    /// Switch to the next list sort order and save it to the config file
+    fn cycle_list_sort(&mut self) {
+        self.list.sort = self.list.sort.next();
+        let selected_id = self.selected_id();
+        self.sort_orchestrations(selected_id);
+        self.phase_cache = None;
+        if self.persist_settings {
+            let _ = Config::persist("list", "sort", toml::Value::from(self.list.sort.label()));
+        }
+    }
+
+    /// Header title, with how much of a partially loaded list is shown and
+    /// any non-default sort order
+    pub(crate) fn list_title(&self) -> String {
+        let mut title = match self.list_paging.total_count {
+            Some(total) if total > self.orchestrations.len() => {
+                format!(
+                    "Orchestrations ({} of {})",
+                    self.orchestrations.len(),
+                    total
+                )
+            }
+            _ => "Orchestrations".to_string(),
+        };
+        if self.list.sort != ListSort::default() {
+            title.push_str(&format!(" · by {}", self.list.sort.label()));
+        }
+        title
+    }
+
+    /// Header warning naming the clocks drifting from Convex time
+    pub(crate) fn clock_warning(&self) -> Option<String> {
+        if self.list_paging.clock_warnings.is_empty() {
+            return None;
         }
+        Some(format!(
+            "clock skew: {}",
+            self.list_paging.clock_warnings.join(", ")
+        ))
     }
 
     /// Refresh orchestrations list from Convex
+    ///
+    /// The load runs in the background and lands on a later tick; a
+    /// refresh requested while one is in flight is dropped.
     pub fn refresh(&mut self) -> AppResult<()> {
         let config = Config::load()?;
-        if !config.convex.url.is_empty() {
-            let rt = tokio::runtime::Runtime::new()?;
-            self.orchestrations = rt.block_on(async {
-                let mut ds = crate::data::ConvexDataSource::new(&config.convex.url).await?;
-                ds.list_orchestrations().await
-            })?;
+        self.start_load(&config.convex.url);
+        Ok(())
+    }
+
+    /// Start loading the list on a background thread, unless no Convex URL
+    /// is configured or a load is already running
+    fn start_load(&mut self, url: &str) {
+        if url.is_empty() || self.pending_load.is_some() {
+            return;
+        }
+        let (tx, rx) = mpsc::channel();
+        let url = url.to_string();
+        let min_rows = self.orchestrations.len().max(LIST_PAGE_SIZE);
+        std::thread::spawn(move || {
+            let _ = tx.send(load_orchestrations(&url, min_rows));
+        });
+        self.pending_load = Some(PendingLoad {
+            rx,
+            selected_id: self.selected_id(),
+        });
+    }
+
+    /// Whether a background list load is in flight
+    pub(crate) fn is_refreshing(&self) -> bool {
+        self.pending_load.is_some()
+    }
+
+    /// Apply a finished background load, if there is one. A failed load
+    /// keeps showing the last data.
+    fn poll_load(&mut self) {
+        let Some(pending) = &self.pending_load else {
+            return;
+        };
+        let loaded = match pending.rx.try_recv() {
+            Ok(result) => result.map_err(|e| e.to_string()),
+            Err(mpsc::TryRecvError::Empty) => return,
+            Err(mpsc::TryRecvError::Disconnected) => Err("load thread exited".to_string()),
+        };
+        let Some(pending) = self.pending_load.take() else {
+            return;
+        };
+        match loaded {
+            Ok(loaded) => {
+                self.load_error = None;
+                self.refreshed_at = Some(Instant::now());
+                self.apply_load(loaded, pending.selected_id);
+            }
+            Err(e) => self.load_error = Some(e),
+        }
+    }
+
+    /// Swap in freshly loaded orchestrations, keeping the selection on the
+    /// same orchestration where it is still listed
+    fn apply_load(&mut self, loaded: LoadedList, selected_id: Option<String>) {
+        let (orchestrations, gates, list_paging) = loaded;
+        // Prefer what is selected now; the user may have moved meanwhile
+        let selected_id = self.selected_id().or(selected_id);
+        self.orchestrations = orchestrations;
+        self.list_paging = list_paging;
+        self.pending_gates = gates;
+        self.rows_changed(selected_id);
+        if let Some(state) = self.restore.take() {
+            self.restore_session(state);
         }
+    }
+
+    /// Re-sort after the rows changed, keeping `selected_id` selected,
+    /// raise toasts for status changes and drop data cached from the old
+    /// rows
+    fn rows_changed(&mut self, selected_id: Option<String>) {
+        self.sort_orchestrations(selected_id);
+        self.notifications
+            .observe(changes::snapshot(&self.orchestrations, &self.pending_gates));
         // Clamp selected_index to valid range
         if self.orchestrations.is_empty() {
             self.selected_index = 0;
@@ -320,31 +800,194 @@ impl App {
         }
         // Invalidate phase cache
         self.phase_cache = None;
-        Ok(())
     }
 
-    /// Check for file watcher events and refresh if needed
-    fn check_watcher(&mut self) {
-        let should_refresh = if let Some(ref watcher) = self.watcher {
-            watcher.has_changes() && self.last_refresh.elapsed() > Duration::from_millis(500)
-        } else {
-            false
+    /// Where the user is now, for the next run to restore
+    ///
+    /// Modals opened from phase detail save as phase detail; the rest save
+    /// as the list they were opened over.
+    pub fn session_state(&self) -> SessionState {
+        let view = match &self.view_state {
+            ViewState::OrchestrationList
+            | ViewState::CommandModal { .. }
+            | ViewState::PlanViewer { .. }
+            | ViewState::Projects { .. } => SavedView::List,
+            ViewState::Dashboard => SavedView::Dashboard,
+            ViewState::PhaseDetail {
+                focus,
+                layout,
+                selected_phase,
+                ..
+            } => SavedView::PhaseDetail {
+                phase: *selected_phase,
+                layout: *layout,
+                focus: *focus,
+            },
+            _ => SavedView::PhaseDetail {
+                phase: self.current_phase_or_default(),
+                layout: PhaseDetailLayout::OrchPhaseTasks,
+                focus: PaneFocus::Phases,
+            },
+        };
+        SessionState {
+            orchestration_id: self.selected_id(),
+            view,
+        }
+    }
+
+    /// Reselect the saved orchestration and reopen its view, unless the
+    /// user has already left the list or the orchestration is gone
+    fn restore_session(&mut self, state: SessionState) {
+        if self.view_state != ViewState::OrchestrationList {
+            return;
+        }
+        let Some(index) = state
+            .orchestration_id
+            .as_deref()
+            .and_then(|id| self.orchestrations.iter().position(|o| o.id == id))
+        else {
+            return;
+        };
+        self.selected_index = index;
+        match state.view {
+            SavedView::List => {}
+            SavedView::Dashboard => self.open_dashboard(),
+            SavedView::PhaseDetail {
+                phase,
+                layout,
+                focus,
+            } => {
+                let total = self.orchestrations[index].total_phases.max(1);
+                let phase = phase.clamp(1, total);
+                self.load_phase_data(phase);
+                self.set_phase_detail_state(PhaseDetailState {
+                    focus,
+                    task_index: 0,
+                    member_index: 0,
+                    layout,
+                    selected_phase: phase,
+                });
+            }
+        }
+    }
+
+    /// Merge changes pushed by the Convex subscriptions since the last tick
+    fn poll_live(&mut self) {
+        let Some(live) = &mut self.live else {
+            return;
         };
+        let was_connected = live.is_connected();
+        let updates = live.drain();
+        if was_connected && !live.is_connected() {
+            self.notifications.push(
+                ChangeKind::Connection,
+                "Live updates disconnected; reconnecting".to_string(),
+            );
+        }
+        if updates.is_empty() {
+            return;
+        }
+        if live.is_connected() {
+            self.refreshed_at = Some(Instant::now());
+        }
+        let selected_id = self.selected_id();
+        for update in updates {
+            match update {
+                LiveUpdate::Page(page) => self.merge_live_page(page),
+                LiveUpdate::Detail(orch) => self.merge_live_detail(*orch),
+                LiveUpdate::Lost(_) => {}
+            }
+        }
+        self.rows_changed(selected_id);
+    }
+
+    /// Fold a pushed list page into the loaded rows. Rows already loaded
+    /// take the new summary and keep their tasks and members; new rows are
+    /// added.
+    fn merge_live_page(&mut self, page: OrchestrationPage) {
+        for row in page.orchestrations {
+            match self.orchestrations.iter_mut().find(|o| o.id == row.id) {
+                Some(existing) => {
+                    let previous = std::mem::replace(existing, row);
+                    existing.keep_details_from(previous);
+                }
+                None => self.orchestrations.push(row),
+            }
+        }
+        if page.total_count.is_some() {
+            self.list_paging.total_count = page.total_count;
+        }
+    }
 
-        if should_refresh {
-            let _ = self.refresh();
-            self.last_refresh = Instant::now();
+    /// Replace a loaded row with its pushed detail
+    fn merge_live_detail(&mut self, orch: MonitorOrchestration) {
+        if let Some(existing) = self.orchestrations.iter_mut().find(|o| o.id == orch.id) {
+            *existing = orch;
         }
     }
 
     /// Handle a key event
     fn handle_key_event(&mut self, key: KeyEvent) {
+        // The search overlay takes every key, so typing `q` or `?` searches
+        if self.global_search.is_some() {
+            self.handle_global_search_key(key);
+            return;
+        }
+        if self.palette.is_some() {
+            self.handle_palette_key(key);
+            return;
+        }
+        // The task editor's subject takes typed `q` and `?` too, as does
+        // the retry reason
+        if let ViewState::TaskEdit { .. } = self.view_state {
+            self.handle_task_edit_key(key);
+            return;
+        }
+        if let ViewState::ConfirmRetryPhase { .. } = self.view_state {
+            self.handle_confirm_retry_phase_key(key);
+            return;
+        }
+        if let ViewState::Projects { form: Some(_), .. } = self.view_state {
+            self.handle_projects_key(key);
+            return;
+        }
+        // Likewise the log search prompt; `N` steps back through matches
+        // rather than opening alerts while a search is active
+        if let (ViewState::LogViewer { .. }, Some(viewer)) = (&self.view_state, &self.log_viewer) {
+            if viewer.search_input.is_some()
+                || (key.code == KeyCode::Char('N') && viewer.search.is_some())
+            {
+                self.handle_log_viewer_key(key);
+                return;
+            }
+        }
+
+        if self.pending_copy {
+            self.pending_copy = false;
+            if let KeyCode::Char(target) = key.code {
+                self.copy_identifier(target);
+            }
+            return;
+        }
+        if key.code == KeyCode::Char('y') && self.copy_prefix_allowed() {
+            self.pending_copy = true;
+            return;
+        }
+
         // Global keys work in all views
         match key.code {
             KeyCode::Char('?') => {
                 self.show_help = !self.show_help;
                 return;
             }
+            KeyCode::Char('N') => {
+                self.notifications.toggle_history();
+                return;
+            }
+            KeyCode::Char('S') => {
+                self.stuck_tasks.show = !self.stuck_tasks.show;
+                return;
+            }
             KeyCode::Char('q') => {
                 self.should_quit = true;
                 return;
@@ -364,21 +1007,187 @@ impl App {
             // Let view-specific handlers handle Esc for navigation
             // Only quit from OrchestrationList view
         }
+        if key.code == KeyCode::Esc && self.notifications.show_history {
+            self.notifications.close_history();
+            return;
+        }
+        if key.code == KeyCode::Esc && self.stuck_tasks.show {
+            self.stuck_tasks.show = false;
+            return;
+        }
 
         // Dispatch to view-specific handler
         match &self.view_state {
             ViewState::OrchestrationList => self.handle_orchestration_list_key(key),
+            ViewState::Dashboard => self.handle_dashboard_key(key),
+            ViewState::Projects { .. } => self.handle_projects_key(key),
             ViewState::PhaseDetail { .. } => self.handle_phase_detail_key(key),
             ViewState::TaskInspector { .. } => self.handle_task_inspector_key(key),
             ViewState::LogViewer { .. } => self.handle_log_viewer_key(key),
             ViewState::SendDialog { .. } => self.handle_send_dialog_key(key),
             ViewState::CommandModal { .. } => self.handle_command_modal_key(key),
             ViewState::PlanViewer { .. } => self.handle_plan_viewer_key(key),
+            ViewState::SpecViewer => self.handle_spec_viewer_key(key),
+            ViewState::Replay => self.handle_replay_key(key),
+            ViewState::ConfirmStopPhase { .. } => self.handle_confirm_stop_phase_key(key),
+            ViewState::ConfirmRetryPhase { .. } => self.handle_confirm_retry_phase_key(key),
             ViewState::CommitsView { .. } => self.handle_commits_view_key(key),
+            ViewState::PhaseTimeline { .. } => self.handle_phase_timeline_key(key),
+            ViewState::CostPanel { .. } => self.handle_cost_panel_key(key),
+            ViewState::WorktreeStatus { .. } => self.handle_worktree_status_key(key),
+            ViewState::TaskGraph { .. } => self.handle_task_graph_key(key),
+            ViewState::BulkTaskModel { .. } => self.handle_bulk_task_model_key(key),
+            ViewState::TaskEdit { .. } => self.handle_task_edit_key(key),
             ViewState::DiffView { .. } => self.handle_diff_view_key(key),
         }
     }
 
+    /// Whether `y` starts a copy here; dialogs use it to confirm or type
+    pub(crate) fn copy_prefix_allowed(&self) -> bool {
+        !matches!(
+            self.view_state,
+            ViewState::SendDialog { .. }
+                | ViewState::CommandModal { .. }
+                | ViewState::ConfirmStopPhase { .. }
+                | ViewState::ConfirmRetryPhase { .. }
+                | ViewState::TaskEdit { .. }
+                | ViewState::BulkTaskModel { .. }
+                | ViewState::Projects { form: Some(_), .. }
+        )
+    }
+
+    /// Copy what `target` names (`c` commit SHA, `t` task id, `p` worktree
+    /// path, `f` file path) to the clipboard, confirming with a toast
+    fn copy_identifier(&mut self, target: char) {
+        let (kind, message) = match self.copy_value(target) {
+            Ok(Some((label, value))) => match arboard::Clipboard::new()
+                .and_then(|mut clipboard| clipboard.set_text(&value))
+            {
+                Ok(()) => (
+                    ChangeKind::StatusChanged,
+                    format!("Copied {}: {}", label, value),
+                ),
+                Err(e) => (
+                    ChangeKind::Blocked,
+                    format!("Couldn't copy {}: {}", label, e),
+                ),
+            },
+            Ok(None) => return,
+            Err(why) => (ChangeKind::Blocked, why),
+        };
+        self.notifications.push(kind, message);
+    }
+
+    /// Label and value that `target` copies in the current view. `None`
+    /// for keys that aren't copy targets; an error when the view has
+    /// nothing of that kind selected.
+    pub(crate) fn copy_value(
+        &self,
+        target: char,
+    ) -> Result<Option<(&'static str, String)>, String> {
+        let value = match target {
+            'c' => ("commit SHA", self.selected_commit_sha()),
+            't' => ("task id", self.selected_task_id()),
+            'p' => ("worktree path", self.selected_worktree_path()),
+            'f' => ("file path", self.selected_file_path()),
+            _ => return Ok(None),
+        };
+        match value {
+            (label, Some(value)) => Ok(Some((label, value))),
+            (label, None) => Err(format!("No {} to copy here", label)),
+        }
+    }
+
+    fn selected_commit_sha(&self) -> Option<String> {
+        match &self.view_state {
+            ViewState::CommitsView {
+                detail: Some(detail),
+                ..
+            } => Some(detail.hash.clone()),
+            ViewState::CommitsView {
+                worktree_path,
+                range,
+                title,
+                selected,
+                ..
+            } => {
+                let mut view =
+                    CommitsView::new(worktree_path, range.clone(), title.clone()).ok()?;
+                view.select(*selected);
+                view.selected_commit().map(|commit| commit.hash.clone())
+            }
+            // Diffs opened from a commit cover `<sha>~1..<sha>`
+            ViewState::DiffView { range, .. } => {
+                let (base, head) = range.split_once("..")?;
+                (base == format!("{}~1", head)).then(|| head.to_string())
+            }
+            _ => None,
+        }
+    }
+
+    fn selected_task_id(&self) -> Option<String> {
+        let task_index = match &self.view_state {
+            ViewState::PhaseDetail {
+                focus: PaneFocus::Tasks,
+                task_index,
+                ..
+            }
+            | ViewState::TaskInspector { task_index } => *task_index,
+            _ => return None,
+        };
+        let orch = self.orchestrations.get(self.selected_index)?;
+        orch.tasks.get(task_index).map(|task| task.id.clone())
+    }
+
+    fn selected_worktree_path(&self) -> Option<String> {
+        let path = match &self.view_state {
+            ViewState::WorktreeStatus { worktree_path, .. }
+            | ViewState::CommitsView { worktree_path, .. }
+            | ViewState::DiffView { worktree_path, .. } => worktree_path.clone(),
+            _ => self
+                .orchestrations
+                .get(self.selected_index)?
+                .worktree_path
+                .clone(),
+        };
+        Some(path.display().to_string())
+    }
+
+    fn selected_file_path(&self) -> Option<String> {
+        match &self.view_state {
+            ViewState::DiffView {
+                worktree_path,
+                range,
+                title,
+                selected,
+                ..
+            } => {
+                let view = super::views::diff_view::DiffView::new(
+                    worktree_path,
+                    range.clone(),
+                    title.clone(),
+                )
+                .ok()?;
+                view.stats
+                    .files
+                    .get(*selected)
+                    .map(|file| file.path.clone())
+            }
+            ViewState::CommitsView {
+                worktree_path,
+                detail: Some(detail),
+                ..
+            } => {
+                let view = CommitDetailView::new(worktree_path, detail).ok()?;
+                match view.selected_row().map(|row| &row.entry)? {
+                    TreeEntry::File(index) => Some(view.detail.files[*index].path.clone()),
+                    TreeEntry::Dir(_) => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
     /// Handle key events in OrchestrationList view
     fn handle_orchestration_list_key(&mut self, key: KeyEvent) {
         match key.code {
@@ -393,46 +1202,277 @@ impl App {
             KeyCode::Char('g') => {
                 let _ = self.handle_goto();
             }
+            KeyCode::Char('G') => self.open_git_tool(),
+            KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_palette()
+            }
+            KeyCode::Char(':') => self.open_palette(),
             KeyCode::Char('p') => {
                 let _ = self.handle_view_plan();
             }
-            KeyCode::Enter => {
-                if !self.orchestrations.is_empty() {
-                    let current_phase = self.orchestrations[self.selected_index].current_phase;
-                    self.load_phase_data(current_phase);
-                    self.set_phase_detail_state(PhaseDetailState {
-                        focus: PaneFocus::Orchestrations,
-                        task_index: 0,
-                        member_index: 0,
-                        layout: PhaseDetailLayout::OrchPhaseTasks,
-                        selected_phase: current_phase,
-                    });
-                }
+            KeyCode::Char('/') => self.open_global_search(),
+            KeyCode::Char('w') => self.open_dashboard(),
+            KeyCode::Char('P') => self.open_projects(),
+            KeyCode::Char('o') => self.cycle_list_sort(),
+            KeyCode::Char(' ') => self.toggle_project_section(),
+            KeyCode::Char('e') => {
+                self.pending_editor = self
+                    .get_current_plan_path()
+                    .or_else(|| self.current_spec_path())
+                    .map(EditorTarget::file);
             }
+            KeyCode::Enter if self.selected_section_collapsed() => self.toggle_project_section(),
+            KeyCode::Enter => self.expand_selected(),
             _ => {}
         }
     }
 
-    /// Handle goto action - open terminal tab at orchestration's cwd
-    fn handle_goto(&mut self) -> AppResult<()> {
-        if self.orchestrations.is_empty() {
-            return Ok(());
+    /// Open the phase detail view for the selected orchestration
+    fn expand_selected(&mut self) {
+        if !self.orchestrations.is_empty() {
+            let current_phase = self.orchestrations[self.selected_index].current_phase;
+            self.load_phase_data(current_phase);
+            self.set_phase_detail_state(PhaseDetailState {
+                focus: PaneFocus::Orchestrations,
+                task_index: 0,
+                member_index: 0,
+                layout: PhaseDetailLayout::OrchPhaseTasks,
+                selected_phase: current_phase,
+            });
         }
+    }
 
-        let orch = &self.orchestrations[self.selected_index];
-        let config = Config::load()?;
-        let handler = get_handler(&config.terminal.handler);
-
-        match handler.open_tab_at(&orch.worktree_path)? {
-            TerminalResult::Success => {
-                // Terminal opened successfully
-                Ok(())
+    /// Indices of the orchestrations shown on the dashboard (everything not
+    /// yet complete)
+    pub(crate) fn dashboard_indices(&self) -> Vec<usize> {
+        self.orchestrations
+            .iter()
+            .enumerate()
+            .filter(|(_, orch)| orch.status != MonitorOrchestrationStatus::Complete)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Switch to the dashboard, refreshing on the next tick
+    pub fn open_dashboard(&mut self) {
+        self.view_state = ViewState::Dashboard;
+        let indices = self.dashboard_indices();
+        if !indices.contains(&self.selected_index) {
+            if let Some(&first) = indices.first() {
+                self.selected_index = first;
             }
-            TerminalResult::ShowCommand {
-                command,
-                description,
-            } => {
-                // Show command modal
+        }
+        self.dashboard_refreshed_at = None;
+    }
+
+    /// Move the dashboard selection by `delta` cards, wrapping around
+    fn move_dashboard_selection(&mut self, delta: isize) {
+        let indices = self.dashboard_indices();
+        if indices.is_empty() {
+            return;
+        }
+        let pos = indices
+            .iter()
+            .position(|&i| i == self.selected_index)
+            .unwrap_or(0) as isize;
+        let next = (pos + delta).rem_euclid(indices.len() as isize) as usize;
+        self.selected_index = indices[next];
+    }
+
+    /// Handle key events in Dashboard view
+    fn handle_dashboard_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('w') => {
+                self.view_state = ViewState::OrchestrationList;
+            }
+            KeyCode::Char('l') | KeyCode::Char('j') | KeyCode::Right | KeyCode::Down => {
+                self.move_dashboard_selection(1)
+            }
+            KeyCode::Char('h') | KeyCode::Char('k') | KeyCode::Left | KeyCode::Up => {
+                self.move_dashboard_selection(-1)
+            }
+            KeyCode::Char('r') => {
+                let _ = self.refresh_dashboard();
+            }
+            KeyCode::Char('/') => self.open_global_search(),
+            KeyCode::Char(':') => self.open_palette(),
+            KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_palette()
+            }
+            KeyCode::Enter => self.expand_selected(),
+            _ => {}
+        }
+    }
+
+    /// Switch to the Projects view, selecting the selected orchestration's
+    /// project
+    pub fn open_projects(&mut self) {
+        let project = self
+            .orchestrations
+            .get(self.selected_index)
+            .map(|orch| orch.project_id.clone());
+        let selected = projects::rows(self)
+            .iter()
+            .position(|row| Some(&row.id) == project.as_ref())
+            .unwrap_or(0);
+        self.view_state = ViewState::Projects {
+            selected,
+            form: None,
+        };
+    }
+
+    /// Handle key events in the Projects view and its new project form
+    fn handle_projects_key(&mut self, key: KeyEvent) {
+        if let ViewState::Projects {
+            form: Some(form), ..
+        } = &mut self.view_state
+        {
+            if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                self.should_quit = true;
+                return;
+            }
+            match form.handle_key(key) {
+                ProjectFormResult::Consumed => {}
+                ProjectFormResult::Cancel => self.close_project_form(),
+                ProjectFormResult::Submit => self.create_project(),
+            }
+            return;
+        }
+        let count = projects::rows(self).len();
+        let ViewState::Projects { selected, .. } = &mut self.view_state else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => self.view_state = ViewState::OrchestrationList,
+            KeyCode::Char('j') | KeyCode::Down if count > 0 => *selected = (*selected + 1) % count,
+            KeyCode::Char('k') | KeyCode::Up if count > 0 => {
+                *selected = (*selected + count - 1) % count
+            }
+            KeyCode::Char('n') => {
+                let cwd = std::env::current_dir().unwrap_or_default();
+                self.view_state = ViewState::Projects {
+                    selected: *selected,
+                    form: Some(ProjectForm::new(&cwd)),
+                };
+            }
+            KeyCode::Char('r') => {
+                let _ = self.refresh();
+            }
+            KeyCode::Char('/') => self.open_global_search(),
+            KeyCode::Char(':') => self.open_palette(),
+            KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_palette()
+            }
+            KeyCode::Enter => {
+                let selected = *selected;
+                self.show_project(selected);
+            }
+            _ => {}
+        }
+    }
+
+    /// Return to the orchestration list narrowed to the project in row
+    /// `row` of the Projects view: every other project section collapsed
+    /// and the project's first orchestration selected
+    fn show_project(&mut self, row: usize) {
+        let Some(project) = projects::rows(self).into_iter().nth(row) else {
+            return;
+        };
+        let Some(&first) = project.orchestrations.first() else {
+            self.notifications.push(
+                ChangeKind::StatusChanged,
+                format!("{} has no loaded orchestrations", project.name),
+            );
+            return;
+        };
+        if orchestration_list::is_grouped(self) {
+            self.collapsed_projects = self
+                .orchestrations
+                .iter()
+                .map(|orch| orch.project_id.clone())
+                .filter(|id| *id != project.id)
+                .collect();
+        }
+        self.selected_index = first;
+        self.view_state = ViewState::OrchestrationList;
+    }
+
+    /// Create the project described by the open form, keeping the form
+    /// open with the error if Convex refuses it
+    fn create_project(&mut self) {
+        let ViewState::Projects {
+            form: Some(form), ..
+        } = &mut self.view_state
+        else {
+            return;
+        };
+        let result = form
+            .values()
+            .map_err(anyhow::Error::msg)
+            .and_then(|(name, repo_path)| {
+                let config = Config::load()?;
+                if config.convex.url.is_empty() {
+                    anyhow::bail!("Convex URL not configured in config.toml");
+                }
+                let rt = tokio::runtime::Runtime::new()?;
+                rt.block_on(async {
+                    let mut ds = ConvexDataSource::new(&config.convex.url).await?;
+                    ds.create_project(name, repo_path).await
+                })
+            });
+        let id = match result {
+            Ok(id) => id,
+            Err(e) => {
+                form.error = Some(e.to_string());
+                return;
+            }
+        };
+        // Convex hands back the existing project for a repo it already knows
+        let message = match self.list_paging.projects.iter().find(|p| p.id == id) {
+            Some(existing) => format!(
+                "{} is already registered as project {}",
+                existing.repo_path, existing.name
+            ),
+            None => format!("Created project {}", form.name.trim()),
+        };
+        self.notifications.push(ChangeKind::StatusChanged, message);
+        self.close_project_form();
+        let _ = self.refresh();
+    }
+
+    fn close_project_form(&mut self) {
+        if let ViewState::Projects { form, .. } = &mut self.view_state {
+            *form = None;
+        }
+    }
+
+    /// Reload the orchestration list plus full details (tasks, members) for
+    /// every orchestration the dashboard shows
+    pub fn refresh_dashboard(&mut self) -> AppResult<()> {
+        self.dashboard_refreshed_at = Some(Instant::now());
+        self.refresh()
+    }
+
+    /// Handle goto action - open terminal tab at orchestration's cwd
+    fn handle_goto(&mut self) -> AppResult<()> {
+        if self.orchestrations.is_empty() {
+            return Ok(());
+        }
+
+        let orch = &self.orchestrations[self.selected_index];
+        let config = Config::load()?;
+        let handler = get_handler(&config.terminal.handler);
+
+        match handler.open_tab_at(&orch.worktree_path)? {
+            TerminalResult::Success => {
+                // Terminal opened successfully
+                Ok(())
+            }
+            TerminalResult::ShowCommand {
+                command,
+                description,
+            } => {
+                // Show command modal
                 self.view_state = ViewState::CommandModal {
                     command,
                     description,
@@ -443,6 +1483,47 @@ impl App {
         }
     }
 
+    /// Launch the configured `[terminal] git_tool` in a new tab at the
+    /// selected orchestration's worktree
+    fn open_git_tool(&mut self) {
+        let Some(orch) = self.orchestrations.get(self.selected_index) else {
+            return;
+        };
+        if !orch.worktree_path.is_dir() {
+            let message = format!(
+                "Worktree not found on this machine: {}",
+                orch.worktree_path.display()
+            );
+            self.notifications.push(ChangeKind::Blocked, message);
+            return;
+        }
+        let config = Config::load().unwrap_or_default();
+        if config.terminal.git_tool.trim().is_empty() {
+            self.notifications.push(
+                ChangeKind::Blocked,
+                "No git tool configured ([terminal] git_tool)".to_string(),
+            );
+            return;
+        }
+        let handler = get_handler(&config.terminal.handler);
+        match handler.run_at(&orch.worktree_path, &config.terminal.git_tool) {
+            Ok(TerminalResult::Success) => {}
+            Ok(TerminalResult::ShowCommand {
+                command,
+                description,
+            }) => {
+                self.view_state = ViewState::CommandModal {
+                    command,
+                    description,
+                    copied: false,
+                };
+            }
+            Err(e) => self
+                .notifications
+                .push(ChangeKind::Blocked, format!("Git tool: {}", e)),
+        }
+    }
+
     /// Handle attach action - attach to agent's tmux pane
     fn handle_attach_tmux(&mut self, agent_index: usize) -> AppResult<()> {
         if self.orchestrations.is_empty() {
@@ -451,6 +1532,21 @@ impl App {
 
         let orch = &self.orchestrations[self.selected_index];
 
+        // Don't offer to send into a pane that's gone
+        if let Some(member) = orch.members.get(agent_index) {
+            let liveness = Liveness::of(
+                member.tmux_pane_id.as_deref(),
+                self.panes.as_ref(),
+                None,
+                chrono::Utc::now(),
+            );
+            if liveness == Liveness::Gone {
+                let message = format!("{}'s tmux pane is gone; nothing to send to", member.name);
+                self.notifications.push(ChangeKind::Blocked, message);
+                return Ok(());
+            }
+        }
+
         // Load team config to get agent details
         let team_path = dirs::home_dir()
             .ok_or("Could not find home directory")?
@@ -535,12 +1631,19 @@ impl App {
         // Load config to get safety settings, fall back to defaults if not available
         let config = Config::load().unwrap_or_default();
 
+        let history = self
+            .command_logger
+            .as_ref()
+            .map(|logger| logger.agent_history(&agent_name))
+            .unwrap_or_default();
+
         // Create SendDialog instance
         let dialog = super::views::send_dialog::SendDialog::new(
             pane_id.clone(),
             agent_name.clone(),
             config.safety.confirm_send,
-        );
+        )
+        .with_history(history);
 
         self.send_dialog = Some(dialog);
         self.view_state = ViewState::SendDialog {
@@ -622,6 +1725,11 @@ impl App {
                     };
                 }
             }
+            KeyCode::Char('e') => {
+                if let ViewState::PlanViewer { plan_path, .. } = &self.view_state {
+                    self.pending_editor = Some(EditorTarget::file(plan_path.clone()));
+                }
+            }
             KeyCode::Esc => {
                 self.view_state = ViewState::OrchestrationList;
             }
@@ -660,25 +1768,26 @@ impl App {
 
         let orch = &self.orchestrations[self.selected_index];
 
-        // Plans are typically in ../docs/plans/ relative to the cwd
         let plan_name = format!(
             "{}-phase-{}.md",
             orch.spec_doc_path.file_stem()?.to_str()?,
             phase
         );
 
-        let plan_path = orch
-            .worktree_path
-            .parent()?
-            .join("docs")
-            .join("plans")
-            .join(plan_name);
-
-        if plan_path.exists() {
-            Some(plan_path)
-        } else {
-            None
-        }
+        // Plans live in the project's configured plans dir, either in the
+        // worktree itself or one level up
+        [
+            Some(orch.worktree_path.as_path()),
+            orch.worktree_path.parent(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|root| {
+            ProjectLayout::load_or_default(root)
+                .plans_dir(root)
+                .join(&plan_name)
+        })
+        .find(|plan_path| plan_path.exists())
     }
 
     /// Handle viewing plan for a specific phase
@@ -692,6 +1801,103 @@ impl App {
         Ok(())
     }
 
+    /// Get the spec document path for the selected orchestration, if it exists
+    fn current_spec_path(&self) -> Option<std::path::PathBuf> {
+        let orch = self.orchestrations.get(self.selected_index)?;
+        orch.spec_doc_path
+            .exists()
+            .then(|| orch.spec_doc_path.clone())
+    }
+
+    /// Resolve what the open-in-editor action targets for the focused pane
+    ///
+    /// Tasks open the first `file:line` finding in their description, falling
+    /// back to the task's JSON file. Phases open the phase plan, and the
+    /// orchestration pane opens the spec.
+    fn editor_target_for_detail(&self, detail: PhaseDetailState) -> Option<EditorTarget> {
+        let orch = self.orchestrations.get(self.selected_index)?;
+        match detail.focus {
+            PaneFocus::Orchestrations => self.current_spec_path().map(EditorTarget::file),
+            PaneFocus::Phases => self
+                .get_plan_path_for_phase(detail.selected_phase)
+                .or_else(|| self.current_spec_path())
+                .map(EditorTarget::file),
+            PaneFocus::Tasks | PaneFocus::Detail => {
+                let task = orch.tasks.get(detail.task_index)?;
+                if let Some((path, line)) = editor::parse_location(&task.description) {
+                    return Some(EditorTarget::at_line(orch.worktree_path.join(path), line));
+                }
+                self.task_file_path(&task.id).map(EditorTarget::file)
+            }
+            PaneFocus::Members => None,
+        }
+    }
+
+    /// Locate a task's JSON file under `~/.claude/tasks/{lead_session_id}/`
+    fn task_file_path(&self, task_id: &str) -> Option<std::path::PathBuf> {
+        let orch = self.orchestrations.get(self.selected_index)?;
+        let claude_dir = dirs::home_dir()?.join(".claude");
+        let team_path = claude_dir
+            .join("teams")
+            .join(orch.team_name())
+            .join("config.json");
+        let team: Team = serde_json::from_str(&std::fs::read_to_string(team_path).ok()?).ok()?;
+        let task_path = claude_dir
+            .join("tasks")
+            .join(team.lead_session_id)
+            .join(format!("{}.json", task_id));
+        task_path.exists().then_some(task_path)
+    }
+
+    /// Open a file in the configured editor, suspending the TUI for terminal editors
+    ///
+    /// Launches are recorded in the command log. If the editor cannot be
+    /// started, the command is shown in the command modal instead.
+    fn open_in_editor<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        target: EditorTarget,
+    ) -> AppResult<()> {
+        let config = Config::load().unwrap_or_default();
+        let editor_cmd = editor::resolve_editor(Some(&config.terminal.editor_command));
+        let argv = editor::build_command(&editor_cmd, &target);
+        let command_line = argv.join(" ");
+
+        if let Some(logger) = self.command_logger.as_ref() {
+            let _ = logger.log("editor", &command_line);
+        }
+
+        let result = if editor::is_gui_editor(&editor_cmd) {
+            editor::launch(&argv, true)
+        } else {
+            suspend_terminal()?;
+            let result = editor::launch(&argv, false);
+            resume_terminal()?;
+            terminal.clear()?;
+            // The edit is finished, so push the plan now rather than waiting
+            // on the daemon's watcher. GUI editors return before saving and
+            // are left to the watcher.
+            if result.is_ok() && self.is_viewing_plan(&target.path) {
+                crate::daemon::request_plan_sync(config.daemon.http_port, &target.path);
+            }
+            result
+        };
+
+        if let Err(e) = result {
+            self.view_state = ViewState::CommandModal {
+                command: command_line,
+                description: format!("Could not open editor: {}", e),
+                copied: false,
+            };
+        }
+        Ok(())
+    }
+
+    /// Whether the plan viewer is open on `path`
+    fn is_viewing_plan(&self, path: &std::path::Path) -> bool {
+        matches!(&self.view_state, ViewState::PlanViewer { plan_path, .. } if plan_path == path)
+    }
+
     /// Handle viewing the spec document
     fn handle_view_spec_doc(&mut self) -> AppResult<()> {
         if self.orchestrations.is_empty() {
@@ -710,6 +1916,137 @@ impl App {
         Ok(())
     }
 
+    /// Open the spec viewer on the orchestration's linked Convex spec
+    ///
+    /// Orchestrations without a linked spec, or a failed fetch, fall back
+    /// to the local design doc.
+    fn open_spec_viewer(&mut self) {
+        match self.load_spec_viewer() {
+            Ok(Some(viewer)) => {
+                self.spec_viewer = Some(viewer);
+                self.view_state = ViewState::SpecViewer;
+            }
+            Ok(None) | Err(_) => {
+                let _ = self.handle_view_spec_doc();
+            }
+        }
+    }
+
+    /// Fetch the selected orchestration's spec and its revisions from Convex
+    fn load_spec_viewer(&self) -> AppResult<Option<SpecViewer>> {
+        let Some(spec_id) = self
+            .orchestrations
+            .get(self.selected_index)
+            .and_then(|orch| orch.spec_id.clone())
+        else {
+            return Ok(None);
+        };
+        let config = Config::load()?;
+        if config.convex.url.is_empty() {
+            return Ok(None);
+        }
+
+        let rt = tokio::runtime::Runtime::new()?;
+        let loaded = rt.block_on(async {
+            let mut ds = crate::data::ConvexDataSource::new(&config.convex.url).await?;
+            ds.spec_with_revisions(&spec_id).await
+        })?;
+        Ok(loaded.map(|(spec, revisions)| SpecViewer::new(spec, revisions)))
+    }
+
+    /// Handle key events in SpecViewer view
+    fn handle_spec_viewer_key(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Esc {
+            self.spec_viewer = None;
+            self.set_phase_detail_state(PhaseDetailState {
+                focus: PaneFocus::Phases,
+                task_index: 0,
+                member_index: 0,
+                layout: PhaseDetailLayout::OrchPhaseTasks,
+                selected_phase: self.current_phase_or_default(),
+            });
+            return;
+        }
+        let Some(viewer) = self.spec_viewer.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => viewer.scroll_down(1),
+            KeyCode::Char('k') | KeyCode::Up => viewer.scroll_up(1),
+            KeyCode::Char('d') | KeyCode::PageDown => viewer.scroll_down(20),
+            KeyCode::Char('u') | KeyCode::PageUp => viewer.scroll_up(20),
+            KeyCode::Char('[') => viewer.older(),
+            KeyCode::Char(']') => viewer.newer(),
+            _ => {}
+        }
+    }
+
+    /// Open the replay view on the selected orchestration's event history,
+    /// reporting a failed fetch as a toast
+    fn open_replay(&mut self) {
+        match self.load_replay() {
+            Ok(Some(viewer)) => {
+                self.replay = Some(viewer);
+                self.view_state = ViewState::Replay;
+            }
+            Ok(None) => {}
+            Err(e) => self
+                .notifications
+                .push(ChangeKind::Blocked, format!("Replay failed to load: {}", e)),
+        }
+    }
+
+    /// Fetch the selected orchestration's task and orchestration events
+    fn load_replay(&self) -> AppResult<Option<ReplayViewer>> {
+        let Some(orch) = self.orchestrations.get(self.selected_index) else {
+            return Ok(None);
+        };
+        let config = Config::load()?;
+        if config.convex.url.is_empty() {
+            return Ok(None);
+        }
+
+        let rt = tokio::runtime::Runtime::new()?;
+        let (tasks, events) = rt.block_on(async {
+            let mut ds = crate::data::ConvexDataSource::new(&config.convex.url).await?;
+            ds.replay_events(&orch.id).await
+        })?;
+        Ok(Some(ReplayViewer::new(
+            orch.feature_name.clone(),
+            Replay::new(tasks, events),
+        )))
+    }
+
+    /// Handle key events in Replay view
+    fn handle_replay_key(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Esc {
+            self.replay = None;
+            self.set_phase_detail_state(PhaseDetailState {
+                focus: PaneFocus::Phases,
+                task_index: 0,
+                member_index: 0,
+                layout: PhaseDetailLayout::OrchPhaseTasks,
+                selected_phase: self.current_phase_or_default(),
+            });
+            return;
+        }
+        let Some(viewer) = self.replay.as_mut() else {
+            return;
+        };
+        let replay = &mut viewer.replay;
+        match key.code {
+            KeyCode::Char('l') | KeyCode::Right => replay.step(1),
+            KeyCode::Char('h') | KeyCode::Left => replay.step(-1),
+            KeyCode::Char('L') | KeyCode::PageDown => replay.step(10),
+            KeyCode::Char('H') | KeyCode::PageUp => replay.step(-10),
+            KeyCode::Char(']') => replay.jump_phase(true),
+            KeyCode::Char('[') => replay.jump_phase(false),
+            KeyCode::Char('g') | KeyCode::Home => replay.to_start(),
+            KeyCode::Char('G') | KeyCode::End => replay.to_end(),
+            _ => {}
+        }
+    }
+
     /// Handle view commits action
     fn handle_view_commits(&mut self) -> AppResult<()> {
         if let Some((worktree_path, range, title)) = self.get_current_phase_git_info() {
@@ -717,6 +2054,8 @@ impl App {
                 worktree_path,
                 range,
                 title,
+                selected: 0,
+                detail: None,
             };
         }
         Ok(())
@@ -734,54 +2073,251 @@ impl App {
                 selected: 0,
                 show_full: false,
                 scroll: 0,
+                side_by_side: SideBySide::default(),
             };
         }
         Ok(())
     }
 
-    /// Get the current phase git info (worktree path and git range)
-    fn get_current_phase_git_info(&self) -> Option<(std::path::PathBuf, String, String)> {
-        if self.orchestrations.is_empty() {
-            return None;
-        }
-
-        let orch = &self.orchestrations[self.selected_index];
-        let phase = orch.current_phase;
-
-        let worktree_path = orch.worktree_path.clone();
-
-        // Read handoff to get git range
-        let handoff_path = orch
-            .worktree_path
-            .join(".claude")
-            .join("tina")
-            .join(format!("phase-{}", phase))
-            .join("handoff.md");
-        let handoff_content = std::fs::read_to_string(&handoff_path).ok()?;
+    /// Collect everything the global search overlay can jump to
+    fn build_search_items(&self) -> Vec<SearchItem> {
+        let mut items = Vec::new();
 
-        // Extract git range from handoff (format: **Git Range**: `main...phase-branch`)
-        let range = handoff_content
-            .lines()
-            .find(|line| line.starts_with("**Git Range**:"))
-            .and_then(|line| line.split('`').nth(1).map(|s| s.to_string()))?;
+        for (orch_index, orch) in self.orchestrations.iter().enumerate() {
+            items.push(SearchItem {
+                kind: SearchKind::Orchestration,
+                label: orch.feature_name.clone(),
+                detail: format!("{:?}  {}", orch.status, orch.branch),
+                target: SearchTarget::Orchestration { orch_index },
+            });
+            for (task_index, task) in orch.tasks.iter().enumerate() {
+                items.push(SearchItem {
+                    kind: SearchKind::Task,
+                    label: task.subject.clone(),
+                    detail: format!("{}  #{} {:?}", orch.feature_name, task.id, task.status),
+                    target: SearchTarget::Task {
+                        orch_index,
+                        task_index,
+                    },
+                });
+            }
+            for (member_index, member) in orch.members.iter().enumerate() {
+                items.push(SearchItem {
+                    kind: SearchKind::Agent,
+                    label: member.name.clone(),
+                    detail: format!(
+                        "{}  {}",
+                        orch.feature_name,
+                        member.agent_type.as_deref().unwrap_or(&member.model)
+                    ),
+                    target: SearchTarget::Agent {
+                        orch_index,
+                        member_index,
+                    },
+                });
+            }
+            // Remote worktrees are not on disk, so they contribute no commits
+            if !orch.worktree_path.is_dir() {
+                continue;
+            }
+            let commits =
+                crate::git::commits::get_recent_commits(&orch.worktree_path, SEARCH_COMMIT_LIMIT)
+                    .unwrap_or_default();
+            for commit in commits {
+                items.push(SearchItem {
+                    kind: SearchKind::Commit,
+                    label: format!("{} {}", commit.short_hash, commit.subject),
+                    detail: format!("{}  {}", orch.feature_name, commit.author),
+                    target: SearchTarget::Commit {
+                        orch_index,
+                        hash: commit.hash,
+                        short_hash: commit.short_hash,
+                        subject: commit.subject,
+                    },
+                });
+            }
+        }
 
-        let title = format!("Phase {} Commits - {}", phase, orch.title());
+        items
+    }
 
-        Some((worktree_path, range, title))
+    fn open_global_search(&mut self) {
+        self.global_search = Some(GlobalSearchState::new(self.build_search_items()));
     }
 
-    /// Handle key events in PhaseDetail view
-    fn handle_phase_detail_key(&mut self, key: KeyEvent) {
-        let mut detail = match self.phase_detail_state() {
-            Some(state) => state,
-            None => return,
+    fn handle_global_search_key(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.should_quit = true;
+            return;
+        }
+        let Some(search) = self.global_search.as_mut() else {
+            return;
         };
+        match fuzzy::handle_global_key(search, key) {
+            GlobalSearchResult::Consumed => {}
+            GlobalSearchResult::Close => self.global_search = None,
+            GlobalSearchResult::Jump(target) => {
+                self.global_search = None;
+                self.jump_to_search_target(target);
+            }
+        }
+    }
 
-        // Handle global keys first
-        match key.code {
-            KeyCode::Esc => {
-                self.view_state = ViewState::OrchestrationList;
-                return;
+    /// Open the quick-actions palette for the selected orchestration
+    fn open_palette(&mut self) {
+        let Some(orch) = self.orchestrations.get(self.selected_index) else {
+            return;
+        };
+        let no_gates = BTreeSet::new();
+        let pending = self.pending_gates.get(&orch.id).unwrap_or(&no_gates);
+        let items = ControlAction::available(orch, pending)
+            .into_iter()
+            .map(|action| PaletteItem {
+                label: action.label(orch),
+                action,
+            })
+            .collect();
+        self.palette = Some(PaletteState::new(&orch.feature_name, items));
+    }
+
+    fn handle_palette_key(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.should_quit = true;
+            return;
+        }
+        let Some(state) = self.palette.as_mut() else {
+            return;
+        };
+        match palette::handle_key(state, key) {
+            PaletteResult::Consumed => {}
+            PaletteResult::Close => self.palette = None,
+            PaletteResult::Run(action) => {
+                self.palette = None;
+                self.run_control_action(action);
+            }
+        }
+    }
+
+    /// Run a palette action against the selected orchestration, reporting
+    /// the outcome as a toast and refreshing so the change shows up
+    fn run_control_action(&mut self, action: ControlAction) {
+        let Some(orch) = self.orchestrations.get(self.selected_index) else {
+            return;
+        };
+        let convex_url = Config::load()
+            .map(|config| config.convex.url)
+            .unwrap_or_default();
+        match action.run(orch, &convex_url) {
+            Ok(message) => {
+                self.notifications.push(ChangeKind::StatusChanged, message);
+                let _ = self.refresh();
+            }
+            Err(e) => {
+                let message = format!("{}: {}", action.label(orch), e);
+                self.notifications.push(ChangeKind::Blocked, message);
+            }
+        }
+    }
+
+    /// Switch to the view that best shows a global search result
+    fn jump_to_search_target(&mut self, target: SearchTarget) {
+        let orch_index = match &target {
+            SearchTarget::Orchestration { orch_index }
+            | SearchTarget::Task { orch_index, .. }
+            | SearchTarget::Agent { orch_index, .. }
+            | SearchTarget::Commit { orch_index, .. } => *orch_index,
+        };
+        // Data may have refreshed while the overlay was open
+        if orch_index >= self.orchestrations.len() {
+            return;
+        }
+        self.selected_index = orch_index;
+        self.show_help = false;
+
+        let current_phase = self.orchestrations[orch_index].current_phase;
+        self.load_phase_data(current_phase);
+        let mut detail = PhaseDetailState {
+            focus: PaneFocus::Orchestrations,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: current_phase,
+        };
+
+        match target {
+            SearchTarget::Orchestration { .. } => {}
+            SearchTarget::Task { task_index, .. } => {
+                detail.focus = PaneFocus::Tasks;
+                detail.task_index = task_index;
+                detail.layout = PhaseDetailLayout::TasksDetail;
+            }
+            SearchTarget::Agent { member_index, .. } => {
+                detail.focus = PaneFocus::Members;
+                detail.member_index = member_index;
+            }
+            SearchTarget::Commit {
+                hash,
+                short_hash,
+                subject,
+                ..
+            } => {
+                let orch = &self.orchestrations[orch_index];
+                self.view_state = ViewState::DiffView {
+                    worktree_path: orch.worktree_path.clone(),
+                    range: format!("{}~1..{}", hash, hash),
+                    title: format!("{} {} - {}", short_hash, subject, orch.title()),
+                    selected: 0,
+                    show_full: false,
+                    scroll: 0,
+                    side_by_side: SideBySide::default(),
+                };
+                return;
+            }
+        }
+
+        self.set_phase_detail_state(detail);
+    }
+
+    /// Get the current phase git info (worktree path and git range)
+    fn get_current_phase_git_info(&self) -> Option<(std::path::PathBuf, String, String)> {
+        if self.orchestrations.is_empty() {
+            return None;
+        }
+
+        let orch = &self.orchestrations[self.selected_index];
+        let phase = orch.current_phase;
+
+        let worktree_path = orch.worktree_path.clone();
+
+        // Read handoff to get git range
+        let handoff_path = ProjectLayout::load_or_default(&orch.worktree_path)
+            .handoff_path(&orch.worktree_path, &phase.to_string());
+        let handoff_content = std::fs::read_to_string(&handoff_path).ok()?;
+
+        // Extract git range from handoff (format: **Git Range**: `main...phase-branch`)
+        let range = handoff_content
+            .lines()
+            .find(|line| line.starts_with("**Git Range**:"))
+            .and_then(|line| line.split('`').nth(1).map(|s| s.to_string()))?;
+
+        let title = format!("Phase {} Commits - {}", phase, orch.title());
+
+        Some((worktree_path, range, title))
+    }
+
+    /// Handle key events in PhaseDetail view
+    fn handle_phase_detail_key(&mut self, key: KeyEvent) {
+        let mut detail = match self.phase_detail_state() {
+            Some(state) => state,
+            None => return,
+        };
+
+        // Handle global keys first
+        match key.code {
+            KeyCode::Esc => {
+                self.task_marks.clear();
+                self.view_state = ViewState::OrchestrationList;
+                return;
             }
             KeyCode::Char('r') => {
                 let _ = self.refresh();
@@ -795,6 +2331,64 @@ impl App {
                 let _ = self.handle_view_diff();
                 return;
             }
+            KeyCode::Char('V') => {
+                self.open_spec_viewer();
+                return;
+            }
+            KeyCode::Char('R') => {
+                self.open_replay();
+                return;
+            }
+            KeyCode::Char('T') => {
+                self.view_state = ViewState::PhaseTimeline {
+                    selected_phase: detail.selected_phase,
+                    show_tasks: false,
+                };
+                return;
+            }
+            KeyCode::Char('$') => {
+                self.view_state = ViewState::CostPanel {
+                    selected_phase: detail.selected_phase,
+                    budget: Config::load().map(|c| c.cost).unwrap_or_default(),
+                };
+                return;
+            }
+            KeyCode::Char('W') => {
+                self.open_worktree_status(detail.selected_phase);
+                return;
+            }
+            KeyCode::Char('G') => {
+                self.open_git_tool();
+                return;
+            }
+            KeyCode::Char('g') => {
+                self.view_state = ViewState::TaskGraph {
+                    selected_phase: detail.selected_phase,
+                    scroll_y: 0,
+                    scroll_x: 0,
+                };
+                return;
+            }
+            KeyCode::Char('z') => {
+                self.zoomed = !self.zoomed;
+                return;
+            }
+            KeyCode::Char('e') => {
+                self.pending_editor = self.editor_target_for_detail(detail);
+                return;
+            }
+            KeyCode::Char('/') => {
+                self.open_global_search();
+                return;
+            }
+            KeyCode::Char(':') => {
+                self.open_palette();
+                return;
+            }
+            KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_palette();
+                return;
+            }
             KeyCode::Tab => {
                 // Tab switches between Tasks and Members within Tasks+Team area
                 detail.focus = match detail.focus {
@@ -890,6 +2484,7 @@ impl App {
                         // Update selected_phase to match new orchestration's current phase
                         detail.selected_phase =
                             self.orchestrations[self.selected_index].current_phase;
+                        self.task_marks.clear();
                         self.set_phase_detail_state(detail);
                     }
                     KeyCode::Char('k') | KeyCode::Up => {
@@ -901,6 +2496,7 @@ impl App {
                         // Update selected_phase to match new orchestration's current phase
                         detail.selected_phase =
                             self.orchestrations[self.selected_index].current_phase;
+                        self.task_marks.clear();
                         self.set_phase_detail_state(detail);
                     }
                     _ => {}
@@ -922,6 +2518,7 @@ impl App {
                         };
                         // Load phase data and reset indices since task/member counts may differ
                         self.load_phase_data(new_phase);
+                        self.task_marks.clear();
                         detail.selected_phase = new_phase;
                         detail.task_index = 0;
                         detail.member_index = 0;
@@ -935,6 +2532,7 @@ impl App {
                         };
                         // Load phase data and reset indices since task/member counts may differ
                         self.load_phase_data(new_phase);
+                        self.task_marks.clear();
                         detail.selected_phase = new_phase;
                         detail.task_index = 0;
                         detail.member_index = 0;
@@ -946,6 +2544,7 @@ impl App {
                     KeyCode::Char('D') => {
                         let _ = self.handle_view_spec_doc();
                     }
+                    KeyCode::Char('F') => self.confirm_retry_phase(detail.selected_phase),
                     _ => {}
                 }
             }
@@ -973,6 +2572,36 @@ impl App {
                         };
                         self.set_phase_detail_state(detail);
                     }
+                    KeyCode::Char('i') => self.open_task_inspector(detail.task_index),
+                    KeyCode::Char('E') => {
+                        self.open_task_editor(detail.task_index, detail.selected_phase)
+                    }
+                    KeyCode::Char(' ') => {
+                        if let Some(task) = self
+                            .orchestrations
+                            .get(self.selected_index)
+                            .and_then(|o| o.tasks.get(detail.task_index))
+                        {
+                            if !self.task_marks.remove(&task.id) {
+                                self.task_marks.insert(task.id.clone());
+                            }
+                        }
+                    }
+                    KeyCode::Char('M') => {
+                        if self.task_marks.is_empty() {
+                            self.notifications.push(
+                                ChangeKind::Blocked,
+                                "Mark tasks with Space first".to_string(),
+                            );
+                        } else {
+                            self.view_state = ViewState::BulkTaskModel {
+                                editor: BulkModelEditor::new(
+                                    self.task_marks.iter().cloned().collect(),
+                                ),
+                                selected_phase: detail.selected_phase,
+                            };
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -1011,6 +2640,13 @@ impl App {
                     KeyCode::Char('s') => {
                         let _ = self.handle_open_send_dialog(detail.member_index);
                     }
+                    KeyCode::Char('x') => {
+                        self.view_state = ViewState::ConfirmStopPhase {
+                            phase: detail.selected_phase,
+                            member_index: detail.member_index,
+                            layout: detail.layout,
+                        };
+                    }
                     _ => {}
                 }
             }
@@ -1020,116 +2656,858 @@ impl App {
         }
     }
 
-    /// Handle key events in TaskInspector view
-    fn handle_task_inspector_key(&mut self, key: KeyEvent) {
-        if key.code == KeyCode::Esc {
-            let task_index = match &self.view_state {
-                ViewState::TaskInspector { task_index } => *task_index,
-                _ => 0,
-            };
-            self.set_phase_detail_state(PhaseDetailState {
-                focus: PaneFocus::Tasks,
-                task_index,
-                member_index: 0,
-                layout: PhaseDetailLayout::TasksDetail,
-                selected_phase: self.current_phase_or_default(),
-            });
+    /// Ask for a reason before retrying `phase`. Like the web's Retry
+    /// button, only blocked orchestrations can be retried.
+    fn confirm_retry_phase(&mut self, phase: u32) {
+        let Some(orch) = self.orchestrations.get(self.selected_index) else {
+            return;
+        };
+        if orch.status != MonitorOrchestrationStatus::Blocked {
+            let message = format!(
+                "{}: only a blocked orchestration can be retried",
+                orch.feature_name
+            );
+            self.notifications.push(ChangeKind::Blocked, message);
+            return;
         }
+        self.view_state = ViewState::ConfirmRetryPhase {
+            phase,
+            reason: String::new(),
+        };
     }
 
-    /// Handle key events in LogViewer view
-    fn handle_log_viewer_key(&mut self, key: KeyEvent) {
-        let agent_index = match self.view_state {
-            ViewState::LogViewer { agent_index, .. } => agent_index,
-            _ => return,
+    /// Handle key events in the retry-phase confirmation
+    fn handle_confirm_retry_phase_key(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.should_quit = true;
+            return;
+        }
+        let ViewState::ConfirmRetryPhase { phase, reason } = &mut self.view_state else {
+            return;
         };
-
-        match key.code {
-            KeyCode::Char('j') | KeyCode::Down => {
-                if let Some(viewer) = &mut self.log_viewer {
-                    viewer.scroll_down(1);
-                }
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if let Some(viewer) = &mut self.log_viewer {
-                    viewer.scroll_up(1);
-                }
+        let phase = *phase;
+        let confirmed = match key.code {
+            KeyCode::Enter => true,
+            KeyCode::Esc => false,
+            KeyCode::Char(c) => {
+                reason.push(c);
+                return;
             }
-            KeyCode::Char('d') | KeyCode::PageDown => {
-                if let Some(viewer) = &mut self.log_viewer {
-                    viewer.scroll_down(20);
-                }
+            KeyCode::Backspace => {
+                reason.pop();
+                return;
             }
-            KeyCode::Char('u') | KeyCode::PageUp => {
-                if let Some(viewer) = &mut self.log_viewer {
-                    viewer.scroll_up(20);
-                }
+            _ => return,
+        };
+        let reason = std::mem::take(reason);
+        self.set_phase_detail_state(PhaseDetailState {
+            focus: PaneFocus::Phases,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: phase,
+        });
+        if confirmed {
+            self.retry_phase(phase, &reason);
+        }
+    }
+
+    /// Queue a retry of `phase` and refresh so the new status shows at once
+    fn retry_phase(&mut self, phase: u32, reason: &str) {
+        let Some(orch) = self.orchestrations.get(self.selected_index) else {
+            return;
+        };
+        let convex_url = Config::load()
+            .map(|config| config.convex.url)
+            .unwrap_or_default();
+        match crate::control::retry_phase(orch, &convex_url, phase, reason) {
+            Ok(message) => {
+                self.notifications.push(ChangeKind::StatusChanged, message);
+                self.phase_cache = None;
+                let _ = self.refresh();
             }
-            KeyCode::Char('G') => {
-                if let Some(viewer) = &mut self.log_viewer {
-                    viewer.scroll_to_bottom();
-                }
+            Err(e) => {
+                let message = format!("{}: retry phase {}: {}", orch.feature_name, phase, e);
+                self.notifications.push(ChangeKind::Blocked, message);
             }
-            KeyCode::Char('f') => {
-                if let Some(viewer) = &mut self.log_viewer {
-                    viewer.toggle_follow();
+        }
+    }
+
+    /// Handle key events in the stop-phase confirmation
+    fn handle_confirm_stop_phase_key(&mut self, key: KeyEvent) {
+        let ViewState::ConfirmStopPhase {
+            phase,
+            member_index,
+            layout,
+        } = self.view_state
+        else {
+            return;
+        };
+        let confirmed = match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => true,
+            KeyCode::Char('n') | KeyCode::Esc => false,
+            _ => return,
+        };
+        self.set_phase_detail_state(PhaseDetailState {
+            focus: PaneFocus::Members,
+            task_index: 0,
+            member_index,
+            layout,
+            selected_phase: phase,
+        });
+        if confirmed {
+            self.stop_phase(phase);
+        }
+    }
+
+    /// Stop a phase's session with `tina-session stop`
+    ///
+    /// Convex only learns the phase is gone once the supervisor notices, so
+    /// the orchestration is shown as blocked, with its agents detached,
+    /// until the next refresh brings in the recorded status.
+    fn stop_phase(&mut self, phase: u32) {
+        let Some(orch) = self.orchestrations.get_mut(self.selected_index) else {
+            return;
+        };
+        let action = ControlAction::StopPhase(phase);
+        match action.run(orch, "") {
+            Ok(message) => {
+                orch.status = MonitorOrchestrationStatus::Blocked;
+                for member in orch.members.iter_mut() {
+                    member.tmux_pane_id = None;
                 }
+                self.phase_cache = None;
+                self.notifications.push(ChangeKind::StatusChanged, message);
             }
-            KeyCode::Char('a') => {
-                // TODO: Attach to tmux pane
-                // For now, do nothing as tmux attach functionality is not yet implemented
+            Err(e) => {
+                let message = format!("{}: {}", action.label(orch), e);
+                self.notifications.push(ChangeKind::Blocked, message);
             }
+        }
+    }
+
+    /// Handle key events in the phase timeline
+    fn handle_phase_timeline_key(&mut self, key: KeyEvent) {
+        let (mut selected_phase, mut show_tasks) = match self.view_state {
+            ViewState::PhaseTimeline {
+                selected_phase,
+                show_tasks,
+            } => (selected_phase, show_tasks),
+            _ => return,
+        };
+        let total_phases = self
+            .orchestrations
+            .get(self.selected_index)
+            .map(|orch| orch.total_phases.max(1))
+            .unwrap_or(1);
+
+        match key.code {
             KeyCode::Esc => {
-                self.log_viewer = None; // Clean up the viewer
+                self.phase_timeline_tasks.clear();
                 self.set_phase_detail_state(PhaseDetailState {
-                    focus: PaneFocus::Members,
+                    focus: PaneFocus::Phases,
                     task_index: 0,
-                    member_index: agent_index,
-                    layout: PhaseDetailLayout::TasksDetail,
-                    selected_phase: self.current_phase_or_default(),
+                    member_index: 0,
+                    layout: PhaseDetailLayout::OrchPhaseTasks,
+                    selected_phase,
                 });
+                return;
             }
-            KeyCode::Char('r') => {
-                if let Some(viewer) = &mut self.log_viewer {
-                    let _ = viewer.refresh();
-                }
+            KeyCode::Char('j') | KeyCode::Down => {
+                selected_phase = selected_phase % total_phases + 1;
             }
-            _ => {}
+            KeyCode::Char('k') | KeyCode::Up => {
+                selected_phase = if selected_phase <= 1 {
+                    total_phases
+                } else {
+                    selected_phase - 1
+                };
+            }
+            KeyCode::Char('t') => show_tasks = !show_tasks,
+            _ => return,
         }
+
+        self.phase_timeline_tasks = if show_tasks {
+            self.load_phase_task_spans(selected_phase)
+                .ok()
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        self.view_state = ViewState::PhaseTimeline {
+            selected_phase,
+            show_tasks,
+        };
     }
 
-    /// Handle key events in SendDialog view
-    fn handle_send_dialog_key(&mut self, key: KeyEvent) {
-        match key.code {
+    /// Handle key events in the cost panel
+    fn handle_cost_panel_key(&mut self, key: KeyEvent) {
+        let ViewState::CostPanel {
+            selected_phase,
+            budget,
+        } = &self.view_state
+        else {
+            return;
+        };
+        let (selected_phase, budget) = (*selected_phase, budget.clone());
+        let total_phases = self
+            .orchestrations
+            .get(self.selected_index)
+            .map(|orch| orch.total_phases.max(1))
+            .unwrap_or(1);
+
+        let selected_phase = match key.code {
             KeyCode::Esc => {
-                self.send_dialog = None; // Clean up the dialog
                 self.set_phase_detail_state(PhaseDetailState {
-                    focus: PaneFocus::Members,
+                    focus: PaneFocus::Phases,
                     task_index: 0,
                     member_index: 0,
-                    layout: PhaseDetailLayout::TasksDetail,
-                    selected_phase: self.current_phase_or_default(),
+                    layout: PhaseDetailLayout::OrchPhaseTasks,
+                    selected_phase,
                 });
+                return;
+            }
+            KeyCode::Char('j') | KeyCode::Down => selected_phase % total_phases + 1,
+            KeyCode::Char('k') | KeyCode::Up if selected_phase <= 1 => total_phases,
+            KeyCode::Char('k') | KeyCode::Up => selected_phase - 1,
+            _ => return,
+        };
+        self.view_state = ViewState::CostPanel {
+            selected_phase,
+            budget,
+        };
+    }
+
+    /// Open the working-tree status of the selected orchestration's worktree
+    fn open_worktree_status(&mut self, selected_phase: u32) {
+        let Some(orch) = self.orchestrations.get(self.selected_index) else {
+            return;
+        };
+        let worktree_path = orch.worktree_path.clone();
+        self.view_state = ViewState::WorktreeStatus {
+            status: Self::load_worktree_status(&worktree_path),
+            worktree_path,
+            scroll: 0,
+            selected_phase,
+        };
+    }
+
+    fn load_worktree_status(worktree_path: &std::path::Path) -> Result<WorktreeStatus, String> {
+        // Orchestrations running on another node have no local worktree
+        if !worktree_path.is_dir() {
+            return Err(format!(
+                "Worktree not found on this machine: {}",
+                worktree_path.display()
+            ));
+        }
+        crate::git::status::get_worktree_status(worktree_path).map_err(|e| e.to_string())
+    }
+
+    /// Handle key events in the working-tree status modal
+    fn handle_worktree_status_key(&mut self, key: KeyEvent) {
+        let ViewState::WorktreeStatus {
+            worktree_path,
+            status,
+            scroll,
+            selected_phase,
+        } = &mut self.view_state
+        else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => {
+                let selected_phase = *selected_phase;
+                self.set_phase_detail_state(PhaseDetailState {
+                    focus: PaneFocus::Phases,
+                    task_index: 0,
+                    member_index: 0,
+                    layout: PhaseDetailLayout::OrchPhaseTasks,
+                    selected_phase,
+                });
+            }
+            KeyCode::Char('j') | KeyCode::Down => *scroll = scroll.saturating_add(1),
+            KeyCode::Char('k') | KeyCode::Up => *scroll = scroll.saturating_sub(1),
+            KeyCode::Char('r') => *status = Self::load_worktree_status(worktree_path),
+            _ => {}
+        }
+    }
+
+    fn handle_task_graph_key(&mut self, key: KeyEvent) {
+        let ViewState::TaskGraph {
+            selected_phase,
+            scroll_y,
+            scroll_x,
+        } = &mut self.view_state
+        else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => {
+                let selected_phase = *selected_phase;
+                self.set_phase_detail_state(PhaseDetailState {
+                    focus: PaneFocus::Phases,
+                    task_index: 0,
+                    member_index: 0,
+                    layout: PhaseDetailLayout::OrchPhaseTasks,
+                    selected_phase,
+                });
+            }
+            KeyCode::Char('j') | KeyCode::Down => *scroll_y = scroll_y.saturating_add(1),
+            KeyCode::Char('k') | KeyCode::Up => *scroll_y = scroll_y.saturating_sub(1),
+            KeyCode::Char('l') | KeyCode::Right => *scroll_x = scroll_x.saturating_add(4),
+            KeyCode::Char('h') | KeyCode::Left => *scroll_x = scroll_x.saturating_sub(4),
+            _ => {}
+        }
+    }
+
+    /// When each task recorded in `phase` was in progress, from its Convex
+    /// event history
+    fn load_phase_task_spans(&self, phase: u32) -> AppResult<Vec<TaskSpan>> {
+        let Some(orch) = self.orchestrations.get(self.selected_index) else {
+            return Ok(Vec::new());
+        };
+        let config = Config::load()?;
+        if config.convex.url.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let phase = phase.to_string();
+        let now = chrono::Utc::now();
+        let rt = tokio::runtime::Runtime::new()?;
+        let spans = rt.block_on(async {
+            let mut ds = crate::data::ConvexDataSource::new(&config.convex.url).await?;
+            let mut spans = Vec::new();
+            for task in &orch.tasks {
+                let events = ds.task_events(&orch.id, &task.id).await?;
+                if !events
+                    .iter()
+                    .any(|e| e.phase_number.as_deref() == Some(phase.as_str()))
+                {
+                    continue;
+                }
+                if let Some((start, end)) = work_window(&events, now) {
+                    spans.push(TaskSpan {
+                        label: task.subject.clone(),
+                        start,
+                        end,
+                    });
+                }
+            }
+            anyhow::Ok(spans)
+        })?;
+        Ok(spans)
+    }
+
+    /// Open the edit popup for a pending task, loading its current revision
+    /// from Convex
+    fn open_task_editor(&mut self, task_index: usize, selected_phase: u32) {
+        match self.load_execution_task(task_index, selected_phase) {
+            Ok(task) => {
+                self.view_state = ViewState::TaskEdit {
+                    editor: TaskEditor::new(task, task_index),
+                    selected_phase,
+                };
+            }
+            Err(e) => self
+                .notifications
+                .push(ChangeKind::Blocked, format!("Can't edit task: {}", e)),
+        }
+    }
+
+    /// The planned execution task behind a Tasks pane row, matched by task
+    /// number and then by subject
+    fn load_execution_task(
+        &self,
+        task_index: usize,
+        phase: u32,
+    ) -> anyhow::Result<ExecutionTaskRecord> {
+        let orch = self
+            .orchestrations
+            .get(self.selected_index)
+            .context("no orchestration selected")?;
+        let task = orch.tasks.get(task_index).context("no task selected")?;
+        if task.status != TaskStatus::Pending {
+            anyhow::bail!(
+                "task {} is {}; only pending tasks can be edited",
+                task.id,
+                task.status
+            );
+        }
+        let config = Config::load()?;
+        if config.convex.url.is_empty() {
+            anyhow::bail!("Convex URL is not configured");
+        }
+
+        let rt = tokio::runtime::Runtime::new()?;
+        let planned = rt.block_on(async {
+            let mut ds = crate::data::ConvexDataSource::new(&config.convex.url).await?;
+            ds.execution_tasks(&orch.id, &phase.to_string()).await
+        })?;
+        let found = Self::planned_task(&planned, task)
+            .cloned()
+            .with_context(|| format!("task {} isn't in phase {}'s plan", task.id, phase))?;
+        if found.status != "pending" {
+            anyhow::bail!("task {} is already {}", found.task_number, found.status);
+        }
+        Ok(found)
+    }
+
+    /// The planned execution task behind a Tasks pane task, matched by
+    /// task number and then by subject
+    fn planned_task<'a>(
+        planned: &'a [ExecutionTaskRecord],
+        task: &Task,
+    ) -> Option<&'a ExecutionTaskRecord> {
+        let number = task.id.parse::<u32>().ok();
+        planned
+            .iter()
+            .find(|t| Some(t.task_number) == number)
+            .or_else(|| planned.iter().find(|t| t.subject == task.subject))
+    }
+
+    /// Handle key events in the bulk model popup
+    fn handle_bulk_task_model_key(&mut self, key: KeyEvent) {
+        let ViewState::BulkTaskModel {
+            editor,
+            selected_phase,
+        } = &mut self.view_state
+        else {
+            return;
+        };
+        let selected_phase = *selected_phase;
+        match editor.handle_key(key) {
+            BulkResult::Consumed => {}
+            BulkResult::Start => match self.resolve_marked_tasks(selected_phase) {
+                Ok((tasks, skipped)) => {
+                    if let ViewState::BulkTaskModel { editor, .. } = &mut self.view_state {
+                        editor.start(tasks, skipped);
+                    }
+                }
+                Err(e) => self
+                    .notifications
+                    .push(ChangeKind::Blocked, format!("Can't update tasks: {}", e)),
+            },
+            BulkResult::Close => {
+                if editor.stage == BulkStage::Done {
+                    let message = match self.orchestrations.get(self.selected_index) {
+                        Some(orch) => format!("{}: {}", orch.feature_name, editor.summary()),
+                        None => editor.summary(),
+                    };
+                    self.notifications.push(ChangeKind::StatusChanged, message);
+                    self.task_marks.clear();
+                    let _ = self.refresh();
+                }
+                self.set_phase_detail_state(PhaseDetailState {
+                    focus: PaneFocus::Tasks,
+                    task_index: 0,
+                    member_index: 0,
+                    layout: PhaseDetailLayout::OrchPhaseTasks,
+                    selected_phase,
+                });
+            }
+        }
+    }
+
+    /// Planned execution tasks for the marked pending tasks, and outcomes
+    /// for the marked tasks that can't be changed
+    fn resolve_marked_tasks(
+        &self,
+        phase: u32,
+    ) -> anyhow::Result<(Vec<ExecutionTaskRecord>, Vec<BulkOutcome>)> {
+        let orch = self
+            .orchestrations
+            .get(self.selected_index)
+            .context("no orchestration selected")?;
+        let config = Config::load()?;
+        if config.convex.url.is_empty() {
+            anyhow::bail!("Convex URL is not configured");
+        }
+        let rt = tokio::runtime::Runtime::new()?;
+        let planned = rt.block_on(async {
+            let mut ds = crate::data::ConvexDataSource::new(&config.convex.url).await?;
+            ds.execution_tasks(&orch.id, &phase.to_string()).await
+        })?;
+
+        let mut tasks = Vec::new();
+        let mut skipped = Vec::new();
+        for task in orch
+            .tasks
+            .iter()
+            .filter(|t| self.task_marks.contains(&t.id))
+        {
+            let why = match Self::planned_task(&planned, task) {
+                _ if task.status != TaskStatus::Pending => task.status.to_string(),
+                None => format!("not in phase {}'s plan", phase),
+                Some(found) if found.status != "pending" => found.status.clone(),
+                Some(found) => {
+                    tasks.push(found.clone());
+                    continue;
+                }
+            };
+            skipped.push(BulkOutcome {
+                task: task.id.clone(),
+                subject: task.subject.clone(),
+                result: Ok(Some(why)),
+            });
+        }
+        Ok((tasks, skipped))
+    }
+
+    /// Queue the next task of a running bulk model update, one per tick
+    fn advance_bulk_task_model(&mut self) {
+        let ViewState::BulkTaskModel { editor, .. } = &mut self.view_state else {
+            return;
+        };
+        if editor.stage != BulkStage::Running {
+            return;
+        }
+        let Some(orch) = self.orchestrations.get(self.selected_index) else {
+            return;
+        };
+        let Some((task, payload)) = editor.next_payload(&orch.feature_name) else {
+            return;
+        };
+        let convex_url = Config::load()
+            .map(|config| config.convex.url)
+            .unwrap_or_default();
+        let result =
+            crate::control::set_task_model(orch, &convex_url, &payload).map_err(|e| e.to_string());
+        editor.record(task, result);
+    }
+
+    /// Handle key events in the pending task editor
+    fn handle_task_edit_key(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.should_quit = true;
+            return;
+        }
+        let ViewState::TaskEdit {
+            editor,
+            selected_phase,
+        } = &mut self.view_state
+        else {
+            return;
+        };
+        let (task_index, selected_phase) = (editor.task_index, *selected_phase);
+        match editor.handle_key(key) {
+            TaskEditResult::Consumed => return,
+            TaskEditResult::Cancel => {}
+            TaskEditResult::Submit => {
+                let Some(orch) = self.orchestrations.get(self.selected_index) else {
+                    return;
+                };
+                if let Some(payload) = editor.payload(&orch.feature_name) {
+                    let convex_url = Config::load()
+                        .map(|config| config.convex.url)
+                        .unwrap_or_default();
+                    if let Err(e) = crate::control::edit_task(orch, &convex_url, &payload) {
+                        // Keep the popup open so a conflict can be read
+                        // before reloading
+                        editor.error = Some(e.to_string());
+                        return;
+                    }
+                    let message = format!(
+                        "{}: task {} updated",
+                        orch.feature_name, editor.task.task_number
+                    );
+                    self.notifications.push(ChangeKind::StatusChanged, message);
+                    let _ = self.refresh();
+                }
+            }
+        }
+        self.set_phase_detail_state(PhaseDetailState {
+            focus: PaneFocus::Tasks,
+            task_index,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase,
+        });
+    }
+
+    /// Open the task inspector, loading the task's event timeline
+    fn open_task_inspector(&mut self, task_index: usize) {
+        self.task_timeline = self.load_task_timeline(task_index).ok().flatten();
+        self.view_state = ViewState::TaskInspector { task_index };
+    }
+
+    /// Fetch a task's events from Convex and merge in the worktree commits
+    /// made while it was in progress. `None` without a Convex deployment.
+    fn load_task_timeline(&self, task_index: usize) -> AppResult<Option<Vec<TimelineEntry>>> {
+        let Some(orch) = self.orchestrations.get(self.selected_index) else {
+            return Ok(None);
+        };
+        let Some(task) = orch.tasks.get(task_index) else {
+            return Ok(None);
+        };
+        let config = Config::load()?;
+        if config.convex.url.is_empty() {
+            return Ok(None);
+        }
+
+        let rt = tokio::runtime::Runtime::new()?;
+        let events = rt.block_on(async {
+            let mut ds = crate::data::ConvexDataSource::new(&config.convex.url).await?;
+            ds.task_events(&orch.id, &task.id).await
+        })?;
+        let commits = work_window(&events, chrono::Utc::now())
+            .and_then(|(since, until)| {
+                let scope = SupervisorState::local_scope(&orch.worktree_path);
+                get_commits_between(&orch.worktree_path, since, until, scope.as_deref()).ok()
+            })
+            .unwrap_or_default();
+        Ok(Some(build_timeline(&events, &commits)))
+    }
+
+    /// Handle key events in TaskInspector view
+    fn handle_task_inspector_key(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Esc {
+            let task_index = match &self.view_state {
+                ViewState::TaskInspector { task_index } => *task_index,
+                _ => 0,
+            };
+            self.task_timeline = None;
+            self.set_phase_detail_state(PhaseDetailState {
+                focus: PaneFocus::Tasks,
+                task_index,
+                member_index: 0,
+                layout: PhaseDetailLayout::TasksDetail,
+                selected_phase: self.current_phase_or_default(),
+            });
+        }
+    }
+
+    /// Handle key events in LogViewer view
+    fn handle_log_viewer_key(&mut self, key: KeyEvent) {
+        let agent_index = match self.view_state {
+            ViewState::LogViewer { agent_index, .. } => agent_index,
+            _ => return,
+        };
+
+        // The search prompt takes every key while open
+        if let Some(viewer) = self.log_viewer.as_mut() {
+            if let Some(input) = viewer.search_input.as_mut() {
+                match key.code {
+                    KeyCode::Enter => viewer.commit_search(),
+                    KeyCode::Esc => viewer.cancel_search_input(),
+                    KeyCode::Backspace => {
+                        input.pop();
+                    }
+                    KeyCode::Char(c) => input.push(c),
+                    _ => {}
+                }
+                return;
+            }
+        }
+
+        match key.code {
+            KeyCode::Char('/') => {
+                if let Some(viewer) = &mut self.log_viewer {
+                    viewer.start_search();
+                }
             }
-            KeyCode::Char(c) if c.is_ascii_digit() && c == '1' => {
-                if let Some(dialog) = &mut self.send_dialog {
-                    dialog.set_quick_action(1);
-                }
-            }
-            KeyCode::Char(c) if c.is_ascii_digit() && c == '2' => {
-                if let Some(dialog) = &mut self.send_dialog {
-                    dialog.set_quick_action(2);
-                }
-            }
-            KeyCode::Char('y') => {
-                // Confirm send if in confirmation state
-                if let Some(dialog) = &self.send_dialog {
-                    if dialog.confirming {
-                        self.execute_send();
-                    }
-                }
-            }
             KeyCode::Char('n') => {
+                if let Some(viewer) = &mut self.log_viewer {
+                    viewer.next_match();
+                }
+            }
+            KeyCode::Char('N') => {
+                if let Some(viewer) = &mut self.log_viewer {
+                    viewer.previous_match();
+                }
+            }
+            KeyCode::Esc
+                if self.log_viewer.as_ref().is_some_and(|viewer| {
+                    viewer.search.is_some() || viewer.search_error.is_some()
+                }) =>
+            {
+                if let Some(viewer) = &mut self.log_viewer {
+                    viewer.clear_search();
+                }
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                if let Some(viewer) = &mut self.log_viewer {
+                    viewer.scroll_down(1);
+                }
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                if let Some(viewer) = &mut self.log_viewer {
+                    viewer.scroll_up(1);
+                }
+            }
+            KeyCode::Char('d') | KeyCode::PageDown => {
+                if let Some(viewer) = &mut self.log_viewer {
+                    viewer.scroll_down(20);
+                }
+            }
+            KeyCode::Char('u') | KeyCode::PageUp => {
+                if let Some(viewer) = &mut self.log_viewer {
+                    viewer.scroll_up(20);
+                }
+            }
+            KeyCode::Char('G') => {
+                if let Some(viewer) = &mut self.log_viewer {
+                    viewer.scroll_to_bottom();
+                }
+            }
+            KeyCode::Char('f') => {
+                if let Some(viewer) = &mut self.log_viewer {
+                    viewer.toggle_follow();
+                }
+            }
+            KeyCode::Char('w') => self.export_log_viewer(),
+            KeyCode::Char('z') => {
+                self.zoomed = !self.zoomed;
+            }
+            KeyCode::Char('a') => {
+                // TODO: Attach to tmux pane
+                // For now, do nothing as tmux attach functionality is not yet implemented
+            }
+            KeyCode::Esc => {
+                self.log_viewer = None; // Clean up the viewer
+                self.set_phase_detail_state(PhaseDetailState {
+                    focus: PaneFocus::Members,
+                    task_index: 0,
+                    member_index: agent_index,
+                    layout: PhaseDetailLayout::TasksDetail,
+                    selected_phase: self.current_phase_or_default(),
+                });
+            }
+            KeyCode::Char('r') => {
+                if let Some(viewer) = &mut self.log_viewer {
+                    let _ = viewer.refresh();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Write the log viewer's pane history to the capture directory,
+    /// reporting where it went (or why it failed) as a toast
+    fn export_log_viewer(&mut self) {
+        let Some(viewer) = &self.log_viewer else {
+            return;
+        };
+        let label = match self.orchestrations.get(self.selected_index) {
+            Some(orch) => format!("{}-{}", orch.feature_name, viewer.agent_name),
+            None => viewer.agent_name.clone(),
+        };
+        let dir = Config::load()
+            .map(|config| config.capture)
+            .unwrap_or_default()
+            .resolved_dir();
+        match crate::tmux::export_pane_history(&viewer.pane_id, &label, &dir) {
+            Ok(path) => self.notifications.push(
+                ChangeKind::StatusChanged,
+                format!("Saved {}", path.display()),
+            ),
+            Err(e) => self
+                .notifications
+                .push(ChangeKind::Blocked, format!("Export failed: {}", e)),
+        }
+    }
+
+    /// Handle key events in SendDialog view
+    fn handle_send_dialog_key(&mut self, key: KeyEvent) {
+        // Ctrl+R history search takes every key while open
+        if let Some(dialog) = &mut self.send_dialog {
+            if dialog.history.search.is_some() {
+                match key.code {
+                    KeyCode::Esc => dialog.close_history_search(),
+                    KeyCode::Enter => dialog.accept_history_search(),
+                    KeyCode::Up => dialog.search_move(-1),
+                    KeyCode::Down => dialog.search_move(1),
+                    KeyCode::Backspace => dialog.search_backspace(),
+                    KeyCode::Char(c) => dialog.search_char(c),
+                    _ => {}
+                }
+                return;
+            }
+        }
+
+        let (input_empty, confirming) = self
+            .send_dialog
+            .as_ref()
+            .map_or((false, false), |d| (d.input.is_empty(), d.confirming));
+        match key.code {
+            KeyCode::Esc => {
+                self.send_dialog = None; // Clean up the dialog
+                self.set_phase_detail_state(PhaseDetailState {
+                    focus: PaneFocus::Members,
+                    task_index: 0,
+                    member_index: 0,
+                    layout: PhaseDetailLayout::TasksDetail,
+                    selected_phase: self.current_phase_or_default(),
+                });
+            }
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Some(dialog) = &mut self.send_dialog {
+                    dialog.open_history_search();
+                }
+            }
+            KeyCode::Up => {
+                // Move up through multi-line input before recalling history
+                if let Some(dialog) = &mut self.send_dialog {
+                    if !dialog.cursor_up() {
+                        dialog.history_older();
+                    }
+                }
+            }
+            KeyCode::Down => {
+                if let Some(dialog) = &mut self.send_dialog {
+                    if !dialog.cursor_down() {
+                        dialog.history_newer();
+                    }
+                }
+            }
+            KeyCode::Left => {
+                if let Some(dialog) = &mut self.send_dialog {
+                    dialog.cursor_left();
+                }
+            }
+            KeyCode::Right => {
+                if let Some(dialog) = &mut self.send_dialog {
+                    dialog.cursor_right();
+                }
+            }
+            KeyCode::Home => {
+                if let Some(dialog) = &mut self.send_dialog {
+                    dialog.cursor_home();
+                }
+            }
+            KeyCode::End => {
+                if let Some(dialog) = &mut self.send_dialog {
+                    dialog.cursor_end();
+                }
+            }
+            KeyCode::Enter
+                if key
+                    .modifiers
+                    .intersects(KeyModifiers::ALT | KeyModifiers::SHIFT) =>
+            {
+                if let Some(dialog) = &mut self.send_dialog {
+                    dialog.insert_newline();
+                }
+            }
+            KeyCode::Char('j') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Some(dialog) = &mut self.send_dialog {
+                    dialog.insert_newline();
+                }
+            }
+            // Quick actions only replace an empty input, so digits can be typed
+            KeyCode::Char('1') if input_empty => {
+                if let Some(dialog) = &mut self.send_dialog {
+                    dialog.set_quick_action(1);
+                }
+            }
+            KeyCode::Char('2') if input_empty => {
+                if let Some(dialog) = &mut self.send_dialog {
+                    dialog.set_quick_action(2);
+                }
+            }
+            KeyCode::Char('y') if confirming => {
+                self.execute_send();
+            }
+            KeyCode::Char('n') if confirming => {
                 // Cancel confirmation
                 if let Some(dialog) = &mut self.send_dialog {
                     dialog.confirming = false;
@@ -1169,7 +3547,21 @@ impl App {
         }
     }
 
-    /// Execute send command (stub for now, will be implemented in Task 9)
+    /// Insert pasted text into the send dialog, or its history search
+    fn handle_paste(&mut self, text: &str) {
+        let Some(dialog) = &mut self.send_dialog else {
+            return;
+        };
+        if dialog.history.search.is_some() {
+            text.chars()
+                .filter(|c| !c.is_control())
+                .for_each(|c| dialog.search_char(c));
+        } else {
+            dialog.insert_str(text);
+        }
+    }
+
+    /// Send the dialog's command to the agent's pane
     fn execute_send(&mut self) {
         // Get command and pane_id from the dialog
         if let Some(dialog) = self.send_dialog.as_ref() {
@@ -1177,11 +3569,13 @@ impl App {
             let pane_id = dialog.pane_id.clone();
 
             // Send to tmux pane - ignore errors for now
-            let _ = crate::tmux::send_keys(&pane_id, &command);
+            let _ = crate::tmux::send_text(&pane_id, &command);
 
-            // Log command if logger is configured
+            // Log command if logger is configured; the log doubles as the
+            // agent's send history
             if let Some(logger) = self.command_logger.as_ref() {
-                let _ = logger.log(&pane_id, &command);
+                let target = crate::logging::agent_target(&dialog.agent_name, &pane_id);
+                let _ = logger.log(&target, &command);
             }
         }
 
@@ -1198,42 +3592,180 @@ impl App {
     }
 
     /// Handle key events in CommitsView
-    fn handle_commits_view_key(&mut self, _key: KeyEvent) {
-        // Navigation is handled by the CommitsView widget itself
-        // We only need to handle Esc to return to PhaseDetail
-        if _key.code == KeyCode::Esc {
-            self.set_phase_detail_state(PhaseDetailState {
-                focus: PaneFocus::Tasks,
-                task_index: 0,
-                member_index: 0,
-                layout: PhaseDetailLayout::TasksDetail,
-                selected_phase: self.current_phase_or_default(),
-            });
-        }
-    }
-
-    /// Handle key events in DiffView
-    fn handle_diff_view_key(&mut self, key: KeyEvent) {
-        // Extract current state
-        let (worktree_path, range, title, selected, show_full, scroll) = match &self.view_state {
-            ViewState::DiffView {
+    fn handle_commits_view_key(&mut self, key: KeyEvent) {
+        let (worktree_path, range, title, selected, detail) = match &self.view_state {
+            ViewState::CommitsView {
                 worktree_path,
                 range,
                 title,
                 selected,
-                show_full,
-                scroll,
+                detail,
             } => (
                 worktree_path.clone(),
                 range.clone(),
                 title.clone(),
                 *selected,
-                *show_full,
-                *scroll,
+                detail.clone(),
             ),
             _ => return,
         };
 
+        if let Some(mut state) = detail {
+            self.handle_commit_detail_key(key, &worktree_path, &mut state);
+            if let ViewState::CommitsView { detail, .. } = &mut self.view_state {
+                *detail = (key.code != KeyCode::Esc).then_some(state);
+            }
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc => {
+                self.set_phase_detail_state(PhaseDetailState {
+                    focus: PaneFocus::Tasks,
+                    task_index: 0,
+                    member_index: 0,
+                    layout: PhaseDetailLayout::TasksDetail,
+                    selected_phase: self.current_phase_or_default(),
+                });
+            }
+            KeyCode::Char('j')
+            | KeyCode::Down
+            | KeyCode::Char('k')
+            | KeyCode::Up
+            | KeyCode::Enter => {
+                // Create temporary view to get the commit list
+                let Ok(mut view) = CommitsView::new(&worktree_path, range.clone(), title.clone())
+                else {
+                    return;
+                };
+                view.select(selected);
+                let detail = match key.code {
+                    KeyCode::Enter => match view.selected_commit() {
+                        Some(commit) => Some(CommitDetailState::new(commit.hash.clone())),
+                        None => return,
+                    },
+                    KeyCode::Char('k') | KeyCode::Up => {
+                        view.select_previous();
+                        None
+                    }
+                    _ => {
+                        view.select_next();
+                        None
+                    }
+                };
+                self.view_state = ViewState::CommitsView {
+                    worktree_path,
+                    range,
+                    title,
+                    selected: view.selected(),
+                    detail,
+                };
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle key events in the commit detail pane. Enter on a directory
+    /// collapses or expands it; Enter on a file opens that commit's diff
+    /// with the file selected.
+    fn handle_commit_detail_key(
+        &mut self,
+        key: KeyEvent,
+        worktree_path: &std::path::Path,
+        state: &mut CommitDetailState,
+    ) {
+        let Ok(view) = CommitDetailView::new(worktree_path, state) else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => state.select_next(view.rows.len()),
+            KeyCode::Char('k') | KeyCode::Up => state.select_previous(view.rows.len()),
+            KeyCode::Enter => match view.selected_row().map(|row| &row.entry) {
+                Some(TreeEntry::Dir(path)) => state.toggle(path),
+                Some(TreeEntry::File(index)) => {
+                    let detail = &view.detail;
+                    let path = &detail.files[*index].path;
+                    let range = format!("{}~1..{}", detail.hash, detail.hash);
+                    let subject = detail.message.lines().next().unwrap_or_default();
+                    let title = format!("{} {}", detail.short_hash, subject);
+                    let selected = super::views::diff_view::DiffView::new(
+                        worktree_path,
+                        range.clone(),
+                        title.clone(),
+                    )
+                    .ok()
+                    .and_then(|diff| diff.stats.files.iter().position(|f| &f.path == path))
+                    .unwrap_or(0);
+                    self.view_state = ViewState::DiffView {
+                        worktree_path: worktree_path.to_path_buf(),
+                        range,
+                        title,
+                        selected,
+                        show_full: false,
+                        scroll: 0,
+                        side_by_side: SideBySide::default(),
+                    };
+                }
+                None => {}
+            },
+            _ => {}
+        }
+    }
+
+    /// Handle key events in DiffView
+    fn handle_diff_view_key(&mut self, key: KeyEvent) {
+        // Side-by-side layout keys only touch layout state
+        if let ViewState::DiffView {
+            show_full,
+            side_by_side,
+            ..
+        } = &mut self.view_state
+        {
+            match key.code {
+                KeyCode::Char('s') => {
+                    side_by_side.enabled = !side_by_side.enabled;
+                    *show_full |= side_by_side.enabled;
+                    return;
+                }
+                KeyCode::Tab if side_by_side.enabled => {
+                    side_by_side.focus_right = !side_by_side.focus_right;
+                    return;
+                }
+                KeyCode::Char('h') | KeyCode::Left if side_by_side.enabled => {
+                    side_by_side.scroll_left();
+                    return;
+                }
+                KeyCode::Char('l') | KeyCode::Right if side_by_side.enabled => {
+                    side_by_side.scroll_right();
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        // Extract current state
+        let (worktree_path, range, title, selected, show_full, scroll, side_by_side) =
+            match &self.view_state {
+                ViewState::DiffView {
+                    worktree_path,
+                    range,
+                    title,
+                    selected,
+                    show_full,
+                    scroll,
+                    side_by_side,
+                } => (
+                    worktree_path.clone(),
+                    range.clone(),
+                    title.clone(),
+                    *selected,
+                    *show_full,
+                    *scroll,
+                    *side_by_side,
+                ),
+                _ => return,
+            };
+
         match key.code {
             KeyCode::Esc => {
                 self.set_phase_detail_state(PhaseDetailState {
@@ -1266,6 +3798,7 @@ impl App {
                             selected: new_selected,
                             show_full,
                             scroll: new_scroll,
+                            side_by_side,
                         };
                     }
                 }
@@ -1301,6 +3834,7 @@ impl App {
                         selected: new_selected,
                         show_full,
                         scroll: new_scroll,
+                        side_by_side,
                     };
                 }
             }
@@ -1313,6 +3847,7 @@ impl App {
                     selected,
                     show_full: !show_full,
                     scroll,
+                    side_by_side,
                 };
             }
             _ => {}
@@ -1322,6 +3857,29 @@ impl App {
     /// Handle tick events - called periodically from the event loop
     /// Checks if log viewer needs refresh and refreshes if necessary
     pub fn on_tick(&mut self) -> AppResult<()> {
+        // Live updates keep the dashboard current; poll only without them
+        let live = self.live.as_ref().is_some_and(LiveUpdates::is_connected);
+        if self.view_state == ViewState::Dashboard
+            && !live
+            && self
+                .dashboard_refreshed_at
+                .is_none_or(|at| at.elapsed() >= DASHBOARD_REFRESH_INTERVAL)
+        {
+            // A failed refresh keeps showing the last data; retry next interval
+            let _ = self.refresh_dashboard();
+        }
+
+        self.advance_bulk_task_model();
+
+        if self.phase_detail_state().is_some()
+            && self
+                .panes_read_at
+                .is_none_or(|at| at.elapsed() >= PANE_REFRESH_INTERVAL)
+        {
+            self.panes = crate::tmux::liveness::list_pane_activity().ok();
+            self.panes_read_at = Some(Instant::now());
+        }
+
         // Only refresh if we're in LogViewer view
         if let ViewState::LogViewer { .. } = self.view_state {
             if let Some(viewer) = &mut self.log_viewer {
@@ -1338,15 +3896,18 @@ impl App {
     /// Run the application event loop
     pub fn run<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> AppResult<()> {
         while !self.should_quit {
+            self.poll_load();
+            self.poll_live();
             terminal.draw(|frame| ui::render(frame, self))?;
 
-            // Check for file watcher events
-            self.check_watcher();
-
             // Call on_tick to handle periodic updates (e.g., log viewer refresh)
             self.on_tick()?;
 
             self.handle_events()?;
+
+            if let Some(target) = self.pending_editor.take() {
+                self.open_in_editor(terminal, target)?;
+            }
         }
         Ok(())
     }
@@ -1354,22 +3915,104 @@ impl App {
     /// Handle terminal events
     fn handle_events(&mut self) -> AppResult<()> {
         if event::poll(self.tick_rate)? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
                     self.handle_key_event(key);
                 }
+                Event::Paste(text) => self.handle_paste(&text),
+                _ => {}
             }
         }
         Ok(())
     }
 }
 
+/// Load at least `min_rows` of the orchestration list from Convex (whole
+/// pages, newest first), plus pending gates
+fn load_orchestrations(url: &str, min_rows: usize) -> anyhow::Result<LoadedList> {
+    let rt = tokio::runtime::Runtime::new()?;
+    let loaded = rt.block_on(async {
+        let mut ds = ConvexDataSource::new(url).await?;
+        let mut orchestrations = Vec::new();
+        let mut paging = ListPaging::default();
+        loop {
+            let page = fetch_page(&mut ds, paging.next_cursor.as_deref()).await?;
+            if orchestrations.is_empty() {
+                paging.total_count = page.total_count;
+            }
+            orchestrations.extend(page.orchestrations);
+            paging.next_cursor = page.next_cursor;
+            if paging.next_cursor.is_none() || orchestrations.len() >= min_rows {
+                break;
+            }
+        }
+        paging.reviews = ds.review_status(&orchestrations).await;
+        let gates = paging
+            .reviews
+            .iter()
+            .map(|(id, review)| (id.clone(), review.pending_gates()))
+            .collect();
+        paging.clock_warnings = ds.clock_anchor().await.warnings();
+        paging.projects = ds.projects().await;
+        paging.project_names = paging
+            .projects
+            .iter()
+            .map(|project| (project.id.clone(), project.name.clone()))
+            .collect();
+        paging.nodes = ds.nodes().await;
+        anyhow::Ok((orchestrations, gates, paging))
+    })?;
+    Ok(loaded)
+}
+
+/// Fetch one page of the orchestration list, with full details (tasks,
+/// members) for every orchestration that isn't complete
+async fn fetch_page(
+    ds: &mut ConvexDataSource,
+    cursor: Option<&str>,
+) -> anyhow::Result<OrchestrationPage> {
+    let mut page = ds.list_orchestrations_page(LIST_PAGE_SIZE, cursor).await?;
+    for orch in page.orchestrations.iter_mut() {
+        if orch.status == MonitorOrchestrationStatus::Complete {
+            continue;
+        }
+        if let Some(detail) = ds.get_orchestration_detail(&orch.id).await? {
+            *orch = detail;
+        }
+    }
+    Ok(page)
+}
+
+/// Leave the alternate screen so a terminal editor can take over
+fn suspend_terminal() -> AppResult<()> {
+    crossterm::terminal::disable_raw_mode()?;
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::terminal::LeaveAlternateScreen,
+        crossterm::event::DisableMouseCapture,
+        crossterm::event::DisableBracketedPaste
+    )?;
+    Ok(())
+}
+
+/// Restore the TUI after a terminal editor exits
+fn resume_terminal() -> AppResult<()> {
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::terminal::EnterAlternateScreen,
+        crossterm::event::EnableMouseCapture,
+        crossterm::event::EnableBracketedPaste
+    )?;
+    crossterm::terminal::enable_raw_mode()?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::tui::views::log_viewer::LogViewer;
     use std::path::PathBuf;
-    use tina_data::OrchestrationListEntry;
+    use tina_data::test_util::list_entry;
 
     fn make_test_task(id: &str) -> crate::types::Task {
         crate::types::Task {
@@ -1386,31 +4029,11 @@ mod tests {
     }
 
     fn make_test_orchestration(title: &str) -> MonitorOrchestration {
-        let entry = OrchestrationListEntry {
-            id: format!("orch-{}", title),
-            node_name: "macbook".to_string(),
-            record: tina_data::OrchestrationRecord {
-                node_id: "node-1".to_string(),
-                project_id: None,
-                feature_name: title.to_string(),
-                spec_doc_path: "design.md".to_string(),
-                branch: format!("tina/{}", title),
-                worktree_path: Some("/test".to_string()),
-                total_phases: 3.0,
-                current_phase: 1.0,
-                status: "idle".to_string(),
-                started_at: "2026-02-07T10:00:00Z".to_string(),
-                completed_at: None,
-                total_elapsed_mins: None,
-                spec_id: None,
-                policy_snapshot: None,
-                policy_snapshot_hash: None,
-                preset_origin: None,
-                spec_only: None,
-                policy_revision: None,
-                updated_at: None,
-            },
-        };
+        let mut entry = list_entry(&format!("orch-{}", title), "idle", "2026-02-07T10:00:00Z");
+        entry.record.feature_name = title.to_string();
+        entry.record.spec_doc_path = "design.md".to_string();
+        entry.record.branch = format!("tina/{}", title);
+        entry.record.worktree_path = Some("/test".to_string());
         let mut orch = MonitorOrchestration::from_list_entry(entry);
         orch.tasks = vec![
             make_test_task("1"),
@@ -1479,6 +4102,70 @@ mod tests {
         assert!(!app.should_quit, "Should not quit");
     }
 
+    #[test]
+    fn test_stuck_tasks_overlay_toggles_and_closes_on_esc() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('S'), KeyModifiers::SHIFT));
+        assert!(
+            app.stuck_tasks.show,
+            "'S' should open the stuck-task overlay"
+        );
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(!app.stuck_tasks.show, "Esc should close the overlay");
+        assert!(
+            !app.should_quit,
+            "Esc should not quit while the overlay is open"
+        );
+    }
+
+    #[test]
+    fn test_list_title_shows_loaded_count_for_partial_lists() {
+        let mut app = App::new_with_orchestrations(vec![
+            make_test_orchestration("project-1"),
+            make_test_orchestration("project-2"),
+        ]);
+        assert_eq!(app.list_title(), "Orchestrations");
+
+        app.list_paging.total_count = Some(250);
+        assert_eq!(app.list_title(), "Orchestrations (2 of 250)");
+
+        app.list_paging.total_count = Some(2);
+        assert_eq!(app.list_title(), "Orchestrations");
+    }
+
+    #[test]
+    fn test_clock_warning_lists_drifting_clocks() {
+        let mut app = App::new_with_orchestrations(vec![]);
+        assert_eq!(app.clock_warning(), None);
+
+        app.list_paging.clock_warnings = vec![
+            "local clock 7s ahead".to_string(),
+            "laptop clock 90s behind".to_string(),
+        ];
+        assert_eq!(
+            app.clock_warning().as_deref(),
+            Some("clock skew: local clock 7s ahead, laptop clock 90s behind")
+        );
+    }
+
+    #[test]
+    fn test_notification_history_toggles_and_closes_on_esc() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('N'), KeyModifiers::SHIFT));
+        assert!(app.notifications.show_history, "'N' should open history");
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(!app.notifications.show_history, "Esc should close history");
+        assert!(
+            matches!(app.view_state, ViewState::OrchestrationList),
+            "View should remain OrchestrationList"
+        );
+        assert!(!app.should_quit, "Should not quit");
+    }
+
     #[test]
     fn test_navigation_keys_work_in_orchestration_list() {
         let mut app = App::new_with_orchestrations(vec![
@@ -1537,6 +4224,251 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_w_opens_dashboard_of_active_orchestrations() {
+        let mut done = make_test_orchestration("done");
+        done.status = MonitorOrchestrationStatus::Complete;
+        let mut blocked = make_test_orchestration("blocked");
+        blocked.status = MonitorOrchestrationStatus::Blocked;
+        let mut app =
+            App::new_with_orchestrations(vec![done, make_test_orchestration("active"), blocked]);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::NONE));
+
+        assert_eq!(app.view_state, ViewState::Dashboard);
+        assert_eq!(app.dashboard_indices(), vec![1, 2]);
+        assert_eq!(app.selected_index, 1, "selection skips completed work");
+        assert!(app.dashboard_refreshed_at.is_none(), "refresh is due");
+    }
+
+    #[test]
+    fn test_dashboard_navigation_wraps_over_active_cards() {
+        let mut done = make_test_orchestration("done");
+        done.status = MonitorOrchestrationStatus::Complete;
+        let mut app = App::new_with_orchestrations(vec![
+            make_test_orchestration("a"),
+            done,
+            make_test_orchestration("b"),
+        ]);
+        app.open_dashboard();
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('l'), KeyModifiers::NONE));
+        assert_eq!(app.selected_index, 2);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('l'), KeyModifiers::NONE));
+        assert_eq!(app.selected_index, 0);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE));
+        assert_eq!(app.selected_index, 2);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(matches!(app.view_state, ViewState::PhaseDetail { .. }));
+    }
+
+    #[test]
+    fn test_esc_leaves_dashboard() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("a")]);
+        app.open_dashboard();
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+
+        assert_eq!(app.view_state, ViewState::OrchestrationList);
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn test_plan_path_for_phase_uses_configured_plans_dir() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let worktree = temp.path().join("worktree");
+        std::fs::create_dir_all(worktree.join(".tina")).unwrap();
+        std::fs::write(
+            worktree.join(".tina").join("config.toml"),
+            "[layout]\nplans_dir = \"design/plans\"\n",
+        )
+        .unwrap();
+        let plans_dir = worktree.join("design").join("plans");
+        std::fs::create_dir_all(&plans_dir).unwrap();
+        std::fs::write(plans_dir.join("design-phase-2.md"), "# plan").unwrap();
+
+        let mut orch = make_test_orchestration("project-1");
+        orch.worktree_path = worktree;
+        let app = App::new_with_orchestrations(vec![orch]);
+
+        assert_eq!(
+            app.get_plan_path_for_phase(2),
+            Some(plans_dir.join("design-phase-2.md"))
+        );
+        assert_eq!(app.get_plan_path_for_phase(3), None);
+    }
+
+    #[test]
+    fn test_e_in_plan_viewer_queues_plan_for_editor() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::PlanViewer {
+            plan_path: PathBuf::from("/repo/docs/plans/design-phase-1.md"),
+            scroll_offset: 0,
+        };
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE));
+
+        assert_eq!(
+            app.pending_editor,
+            Some(EditorTarget::file("/repo/docs/plans/design-phase-1.md"))
+        );
+        assert!(app.is_viewing_plan(std::path::Path::new("/repo/docs/plans/design-phase-1.md")));
+    }
+
+    #[test]
+    fn test_e_on_task_opens_finding_location_in_worktree() {
+        let mut orch = make_test_orchestration("project-1");
+        orch.tasks[0].description = "Fix the unchecked unwrap in src/lib.rs:27".to_string();
+        let mut app = App::new_with_orchestrations(vec![orch]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Tasks,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::TasksDetail,
+            selected_phase: 1,
+        };
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE));
+
+        assert_eq!(
+            app.pending_editor,
+            Some(EditorTarget::at_line("/test/src/lib.rs", 27))
+        );
+    }
+
+    #[test]
+    fn test_z_toggles_zoom_in_phase_detail() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Members,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+        assert!(!app.zoomed);
+
+        let key = KeyEvent::new(KeyCode::Char('z'), KeyModifiers::NONE);
+        app.handle_key_event(key);
+        assert!(app.zoomed, "'z' should maximize the focused pane");
+        assert!(
+            matches!(app.view_state, ViewState::PhaseDetail { focus: PaneFocus::Members, .. }),
+            "Zooming should not change focus"
+        );
+
+        app.handle_key_event(key);
+        assert!(!app.zoomed, "Second 'z' should restore the layout");
+    }
+
+    fn type_query(app: &mut App, query: &str) {
+        for c in query.chars() {
+            app.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+    }
+
+    #[test]
+    fn test_slash_opens_global_search_that_captures_keys() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE));
+        assert!(app.global_search.is_some(), "'/' should open global search");
+
+        type_query(&mut app, "q?");
+        assert!(!app.should_quit, "'q' should be typed into the query");
+        assert!(!app.show_help, "'?' should be typed into the query");
+        assert_eq!(app.global_search.as_ref().unwrap().query, "q?");
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(
+            app.global_search.is_none(),
+            "Esc should close global search"
+        );
+        assert!(!app.should_quit, "Esc should not also quit the list");
+    }
+
+    #[test]
+    fn test_global_search_jumps_to_task() {
+        let mut app = App::new_with_orchestrations(vec![
+            make_test_orchestration("project-1"),
+            make_test_orchestration("project-2"),
+        ]);
+        app.orchestrations[1].tasks[2].subject = "Wire up billing webhooks".to_string();
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE));
+        type_query(&mut app, "billing");
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert!(app.global_search.is_none());
+        assert_eq!(app.selected_index, 1);
+        assert!(matches!(
+            app.view_state,
+            ViewState::PhaseDetail {
+                focus: PaneFocus::Tasks,
+                task_index: 2,
+                layout: PhaseDetailLayout::TasksDetail,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_colon_opens_palette_with_pending_gate_actions() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        let orch_id = app.orchestrations[0].id.clone();
+        app.pending_gates
+            .insert(orch_id, BTreeSet::from(["plan".to_string()]));
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char(':'), KeyModifiers::NONE));
+
+        let palette = app.palette.as_ref().expect("':' should open the palette");
+        let actions: Vec<&ControlAction> = palette.items.iter().map(|i| &i.action).collect();
+        assert_eq!(
+            actions,
+            [
+                &ControlAction::ApproveGate("plan".to_string()),
+                &ControlAction::BlockGate("plan".to_string()),
+                &ControlAction::Cleanup,
+            ]
+        );
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE));
+        assert!(!app.should_quit, "palette should capture 'q' as query text");
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(app.palette.is_none());
+    }
+
+    #[test]
+    fn test_ctrl_p_opens_palette_instead_of_plan() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        assert!(app.palette.is_some());
+        assert_eq!(app.view_state, ViewState::OrchestrationList);
+
+        let mut empty = App::new_with_orchestrations(vec![]);
+        empty.handle_key_event(KeyEvent::new(KeyCode::Char(':'), KeyModifiers::NONE));
+        assert!(empty.palette.is_none(), "no orchestration to act on");
+    }
+
+    #[test]
+    fn test_global_search_jumps_to_orchestration() {
+        let mut app = App::new_with_orchestrations(vec![
+            make_test_orchestration("alpha"),
+            make_test_orchestration("zulu-rewrite"),
+        ]);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE));
+        type_query(&mut app, "zulu");
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert_eq!(app.selected_index, 1);
+        assert!(matches!(
+            app.view_state,
+            ViewState::PhaseDetail {
+                focus: PaneFocus::Orchestrations,
+                ..
+            }
+        ));
+    }
+
     #[test]
     fn test_next_wraps_around_at_end() {
         let mut app = App {
@@ -1549,13 +4481,36 @@ mod tests {
             selected_index: 2, // Last item
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         app.next();
@@ -1574,13 +4529,36 @@ mod tests {
             selected_index: 0, // First item
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         app.previous();
@@ -1595,13 +4573,36 @@ mod tests {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         app.next();
@@ -1616,13 +4617,36 @@ mod tests {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         app.previous();
@@ -1637,13 +4661,36 @@ mod tests {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         let key = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
@@ -1659,13 +4706,36 @@ mod tests {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         let key = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
@@ -1684,13 +4754,36 @@ mod tests {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
@@ -1709,13 +4802,36 @@ mod tests {
             selected_index: 1,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         let key = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE);
@@ -1731,13 +4847,36 @@ mod tests {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         let key = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE);
@@ -1753,13 +4892,36 @@ mod tests {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         let key = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE);
@@ -1781,13 +4943,36 @@ mod tests {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: true,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         let key = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
@@ -1804,13 +4989,36 @@ mod tests {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         let key = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
@@ -1819,20 +5027,43 @@ mod tests {
     }
 
     #[test]
-    fn test_app_works_without_watcher() {
+    fn test_app_works_without_live_updates() {
         let app = App {
             should_quit: false,
             orchestrations: vec![make_test_orchestration("project-1")],
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         assert_eq!(app.orchestrations.len(), 1);
@@ -1840,24 +5071,47 @@ mod tests {
     }
 
     #[test]
-    fn test_check_watcher_handles_none_gracefully() {
+    fn test_poll_live_handles_none_gracefully() {
         let mut app = App {
             should_quit: false,
             orchestrations: vec![make_test_orchestration("project-1")],
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
-        // Should not panic when watcher is None
-        app.check_watcher();
+        // Should not panic without live updates
+        app.poll_live();
         assert!(!app.should_quit);
     }
 
@@ -1925,11 +5179,65 @@ mod tests {
             "test-agent".to_string(),
         ));
 
-        // Call on_tick - should not attempt refresh
-        let _ = app.on_tick();
+        // Call on_tick - should not attempt refresh
+        let _ = app.on_tick();
+
+        // App should remain in OrchestrationList
+        assert!(matches!(app.view_state, ViewState::OrchestrationList));
+    }
+
+    #[test]
+    fn test_log_viewer_search_keys() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        let mut viewer = LogViewer::new("test-pane".to_string(), "test-agent".to_string());
+        viewer.lines = vec![
+            "ok".into(),
+            "error: one".into(),
+            "ok".into(),
+            "Error two".into(),
+        ];
+        viewer.scroll_offset = viewer.lines.len();
+        app.log_viewer = Some(viewer);
+        app.view_state = ViewState::LogViewer {
+            agent_index: 0,
+            pane_id: "test-pane".to_string(),
+            agent_name: "test-agent".to_string(),
+        };
+
+        for c in "/error".chars() {
+            app.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        // Keys are typed into the prompt, not treated as commands
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE));
+        app.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        assert!(!app.should_quit);
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        let search = app.log_viewer.as_ref().unwrap().search.as_ref().unwrap();
+        assert_eq!(search.pattern, "error");
+        assert_eq!(search.matches.len(), 2);
+
+        let current = |app: &App| {
+            app.log_viewer
+                .as_ref()
+                .unwrap()
+                .search
+                .as_ref()
+                .unwrap()
+                .current
+        };
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE));
+        let next = current(&app);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('N'), KeyModifiers::NONE));
+        let previous = current(&app);
+        assert_ne!(next, previous);
+        assert!(!app.notifications.show_history);
 
-        // App should remain in OrchestrationList
-        assert!(matches!(app.view_state, ViewState::OrchestrationList));
+        // Esc clears the search first, then closes the viewer
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(app.log_viewer.as_ref().unwrap().search.is_none());
+        assert!(matches!(app.view_state, ViewState::LogViewer { .. }));
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(app.log_viewer.is_none());
     }
 
     #[test]
@@ -2103,39 +5411,275 @@ mod tests {
     }
 
     #[test]
-    fn test_right_arrow_from_tasks_switches_to_tasks_detail_layout() {
+    fn test_right_arrow_from_tasks_switches_to_tasks_detail_layout() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Tasks,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+
+        let key = KeyEvent::new(KeyCode::Right, KeyModifiers::NONE);
+        app.handle_key_event(key);
+
+        match app.view_state {
+            ViewState::PhaseDetail { focus, layout, .. } => {
+                assert_eq!(
+                    layout,
+                    PhaseDetailLayout::TasksDetail,
+                    "Right arrow from Tasks in OrchPhaseTasks should switch to TasksDetail layout"
+                );
+                assert_eq!(
+                    focus,
+                    PaneFocus::Tasks,
+                    "Focus should remain on Tasks when switching layouts"
+                );
+            }
+            _ => panic!("View state should still be PhaseDetail"),
+        }
+    }
+
+    #[test]
+    fn test_j_key_navigates_down_in_tasks_pane() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Tasks,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+
+        let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+        app.handle_key_event(key);
+
+        match app.view_state {
+            ViewState::PhaseDetail { task_index, .. } => {
+                assert_eq!(task_index, 1, "'j' should navigate down in tasks pane");
+            }
+            _ => panic!("View state should still be PhaseDetail"),
+        }
+    }
+
+    #[test]
+    fn test_k_key_navigates_up_in_tasks_pane() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Tasks,
+            task_index: 2,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+
+        let key = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE);
+        app.handle_key_event(key);
+
+        match app.view_state {
+            ViewState::PhaseDetail { task_index, .. } => {
+                assert_eq!(task_index, 1, "'k' should navigate up in tasks pane");
+            }
+            _ => panic!("View state should still be PhaseDetail"),
+        }
+    }
+
+    #[test]
+    fn test_j_key_wraps_around_at_end_of_tasks() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Tasks,
+            task_index: 2, // Assuming we'll wrap from 2 to 0
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+
+        let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+        app.handle_key_event(key);
+
+        match app.view_state {
+            ViewState::PhaseDetail { task_index, .. } => {
+                assert_eq!(
+                    task_index, 0,
+                    "'j' should wrap to beginning at end of tasks"
+                );
+            }
+            _ => panic!("View state should still be PhaseDetail"),
+        }
+    }
+
+    #[test]
+    fn test_k_key_wraps_around_at_beginning_of_tasks() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Tasks,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+
+        let key = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE);
+        app.handle_key_event(key);
+
+        match app.view_state {
+            ViewState::PhaseDetail { task_index, .. } => {
+                assert_eq!(
+                    task_index, 2,
+                    "'k' should wrap to end at beginning of tasks"
+                );
+            }
+            _ => panic!("View state should still be PhaseDetail"),
+        }
+    }
+
+    #[test]
+    fn test_down_arrow_navigates_down_in_tasks_pane() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Tasks,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+
+        let key = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+        app.handle_key_event(key);
+
+        match app.view_state {
+            ViewState::PhaseDetail { task_index, .. } => {
+                assert_eq!(
+                    task_index, 1,
+                    "Down arrow should navigate down in tasks pane"
+                );
+            }
+            _ => panic!("View state should still be PhaseDetail"),
+        }
+    }
+
+    #[test]
+    fn test_up_arrow_navigates_up_in_tasks_pane() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Tasks,
+            task_index: 1,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+
+        let key = KeyEvent::new(KeyCode::Up, KeyModifiers::NONE);
+        app.handle_key_event(key);
+
+        match app.view_state {
+            ViewState::PhaseDetail { task_index, .. } => {
+                assert_eq!(task_index, 0, "Up arrow should navigate up in tasks pane");
+            }
+            _ => panic!("View state should still be PhaseDetail"),
+        }
+    }
+
+    #[test]
+    fn test_j_key_navigates_down_in_members_pane() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Members,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+
+        let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+        app.handle_key_event(key);
+
+        match app.view_state {
+            ViewState::PhaseDetail { member_index, .. } => {
+                assert_eq!(member_index, 1, "'j' should navigate down in members pane");
+            }
+            _ => panic!("View state should still be PhaseDetail"),
+        }
+    }
+
+    #[test]
+    fn test_k_key_navigates_up_in_members_pane() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Members,
+            task_index: 0,
+            member_index: 1,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+
+        let key = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE);
+        app.handle_key_event(key);
+
+        match app.view_state {
+            ViewState::PhaseDetail { member_index, .. } => {
+                assert_eq!(member_index, 0, "'k' should navigate up in members pane");
+            }
+            _ => panic!("View state should still be PhaseDetail"),
+        }
+    }
+
+    #[test]
+    fn test_enter_on_tasks_does_nothing_in_new_layout() {
+        // In the new two-screen layout, Enter on Tasks does nothing
+        // Use 'l' key to navigate to TaskDetail screen instead
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Tasks,
+            task_index: 2,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        app.handle_key_event(key);
+
+        // Should still be in PhaseDetail with same state
+        match app.view_state {
+            ViewState::PhaseDetail {
+                focus, task_index, ..
+            } => {
+                assert_eq!(focus, PaneFocus::Tasks, "Focus should remain on Tasks");
+                assert_eq!(task_index, 2, "task_index should not change");
+            }
+            _ => panic!("View state should still be PhaseDetail"),
+        }
+    }
+
+    #[test]
+    fn test_i_on_tasks_opens_task_inspector() {
         let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
         app.view_state = ViewState::PhaseDetail {
             focus: PaneFocus::Tasks,
-            task_index: 0,
+            task_index: 2,
             member_index: 0,
             layout: PhaseDetailLayout::OrchPhaseTasks,
             selected_phase: 1,
         };
 
-        let key = KeyEvent::new(KeyCode::Right, KeyModifiers::NONE);
-        app.handle_key_event(key);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE));
+        assert_eq!(app.view_state, ViewState::TaskInspector { task_index: 2 });
 
-        match app.view_state {
-            ViewState::PhaseDetail { focus, layout, .. } => {
-                assert_eq!(
-                    layout,
-                    PhaseDetailLayout::TasksDetail,
-                    "Right arrow from Tasks in OrchPhaseTasks should switch to TasksDetail layout"
-                );
-                assert_eq!(
-                    focus,
-                    PaneFocus::Tasks,
-                    "Focus should remain on Tasks when switching layouts"
-                );
-            }
-            _ => panic!("View state should still be PhaseDetail"),
-        }
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(matches!(
+            app.view_state,
+            ViewState::PhaseDetail { task_index: 2, .. }
+        ));
+        assert!(app.task_timeline.is_none());
     }
 
     #[test]
-    fn test_j_key_navigates_down_in_tasks_pane() {
+    fn test_phase_timeline_opens_cycles_phases_and_closes() {
         let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        let total = app.orchestrations[0].total_phases;
         app.view_state = ViewState::PhaseDetail {
             focus: PaneFocus::Tasks,
             task_index: 0,
@@ -2144,206 +5688,700 @@ mod tests {
             selected_phase: 1,
         };
 
-        let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
-        app.handle_key_event(key);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('T'), KeyModifiers::NONE));
+        assert_eq!(
+            app.view_state,
+            ViewState::PhaseTimeline {
+                selected_phase: 1,
+                show_tasks: false
+            }
+        );
 
-        match app.view_state {
-            ViewState::PhaseDetail { task_index, .. } => {
-                assert_eq!(task_index, 1, "'j' should navigate down in tasks pane");
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE));
+        assert_eq!(
+            app.view_state,
+            ViewState::PhaseTimeline {
+                selected_phase: total,
+                show_tasks: false
             }
-            _ => panic!("View state should still be PhaseDetail"),
-        }
+        );
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(matches!(
+            app.view_state,
+            ViewState::PhaseDetail { selected_phase, .. } if selected_phase == total
+        ));
     }
 
     #[test]
-    fn test_k_key_navigates_up_in_tasks_pane() {
+    fn test_cost_panel_opens_cycles_phases_and_closes() {
         let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
         app.view_state = ViewState::PhaseDetail {
             focus: PaneFocus::Tasks,
-            task_index: 2,
+            task_index: 0,
             member_index: 0,
             layout: PhaseDetailLayout::OrchPhaseTasks,
-            selected_phase: 1,
+            selected_phase: 2,
         };
 
-        let key = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE);
-        app.handle_key_event(key);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('$'), KeyModifiers::NONE));
+        assert!(matches!(
+            app.view_state,
+            ViewState::CostPanel {
+                selected_phase: 2,
+                ..
+            }
+        ));
 
-        match app.view_state {
-            ViewState::PhaseDetail { task_index, .. } => {
-                assert_eq!(task_index, 1, "'k' should navigate up in tasks pane");
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        assert!(matches!(
+            app.view_state,
+            ViewState::CostPanel {
+                selected_phase: 3,
+                ..
             }
-            _ => panic!("View state should still be PhaseDetail"),
-        }
+        ));
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(matches!(
+            app.view_state,
+            ViewState::PhaseDetail {
+                selected_phase: 3,
+                ..
+            }
+        ));
     }
 
     #[test]
-    fn test_j_key_wraps_around_at_end_of_tasks() {
+    fn test_space_marks_tasks_and_shift_m_opens_bulk_model() {
         let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
         app.view_state = ViewState::PhaseDetail {
             focus: PaneFocus::Tasks,
-            task_index: 2, // Assuming we'll wrap from 2 to 0
+            task_index: 0,
             member_index: 0,
             layout: PhaseDetailLayout::OrchPhaseTasks,
-            selected_phase: 1,
+            selected_phase: 2,
         };
 
-        let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
-        app.handle_key_event(key);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('M'), KeyModifiers::NONE));
+        assert!(matches!(app.view_state, ViewState::PhaseDetail { .. }));
+        assert_eq!(
+            app.notifications.history().last().unwrap().message,
+            "Mark tasks with Space first"
+        );
 
-        match app.view_state {
-            ViewState::PhaseDetail { task_index, .. } => {
-                assert_eq!(
-                    task_index, 0,
-                    "'j' should wrap to beginning at end of tasks"
-                );
+        let space = KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE);
+        let down = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+        app.handle_key_event(space);
+        app.handle_key_event(down);
+        app.handle_key_event(space);
+        app.handle_key_event(down);
+        app.handle_key_event(space);
+        app.handle_key_event(space);
+        assert_eq!(
+            app.task_marks,
+            BTreeSet::from(["1".to_string(), "2".to_string()])
+        );
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('M'), KeyModifiers::NONE));
+        let ViewState::BulkTaskModel { editor, .. } = &app.view_state else {
+            panic!("expected bulk model popup, got {:?}", app.view_state);
+        };
+        assert_eq!(editor.task_ids, ["1", "2"]);
+
+        // Cancelling before applying keeps the marks
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(matches!(
+            app.view_state,
+            ViewState::PhaseDetail {
+                focus: PaneFocus::Tasks,
+                selected_phase: 2,
+                ..
             }
-            _ => panic!("View state should still be PhaseDetail"),
-        }
+        ));
+        assert_eq!(app.task_marks.len(), 2);
+
+        // Leaving the phase drops them
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(app.task_marks.is_empty());
     }
 
     #[test]
-    fn test_k_key_wraps_around_at_beginning_of_tasks() {
+    fn test_task_graph_opens_scrolls_and_closes() {
         let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
         app.view_state = ViewState::PhaseDetail {
             focus: PaneFocus::Tasks,
             task_index: 0,
             member_index: 0,
             layout: PhaseDetailLayout::OrchPhaseTasks,
-            selected_phase: 1,
+            selected_phase: 2,
         };
 
-        let key = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE);
-        app.handle_key_event(key);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE));
+        assert_eq!(
+            app.view_state,
+            ViewState::TaskGraph {
+                selected_phase: 2,
+                scroll_y: 0,
+                scroll_x: 0
+            }
+        );
 
-        match app.view_state {
-            ViewState::PhaseDetail { task_index, .. } => {
-                assert_eq!(
-                    task_index, 2,
-                    "'k' should wrap to end at beginning of tasks"
-                );
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('l'), KeyModifiers::NONE));
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE));
+        assert_eq!(
+            app.view_state,
+            ViewState::TaskGraph {
+                selected_phase: 2,
+                scroll_y: 0,
+                scroll_x: 4
             }
-            _ => panic!("View state should still be PhaseDetail"),
-        }
+        );
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(matches!(
+            app.view_state,
+            ViewState::PhaseDetail {
+                selected_phase: 2,
+                ..
+            }
+        ));
     }
 
     #[test]
-    fn test_down_arrow_navigates_down_in_tasks_pane() {
+    fn test_worktree_status_opens_scrolls_and_closes() {
         let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
         app.view_state = ViewState::PhaseDetail {
             focus: PaneFocus::Tasks,
             task_index: 0,
             member_index: 0,
             layout: PhaseDetailLayout::OrchPhaseTasks,
-            selected_phase: 1,
+            selected_phase: 2,
         };
 
-        let key = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
-        app.handle_key_event(key);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('W'), KeyModifiers::NONE));
+        let ViewState::WorktreeStatus { status, .. } = &app.view_state else {
+            panic!("expected worktree status, got {:?}", app.view_state);
+        };
+        assert!(
+            status.as_ref().is_err_and(|e| e.contains("not found")),
+            "test worktree doesn't exist locally"
+        );
 
-        match app.view_state {
-            ViewState::PhaseDetail { task_index, .. } => {
-                assert_eq!(
-                    task_index, 1,
-                    "Down arrow should navigate down in tasks pane"
-                );
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        assert!(matches!(
+            app.view_state,
+            ViewState::WorktreeStatus { scroll: 1, .. }
+        ));
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(matches!(
+            app.view_state,
+            ViewState::PhaseDetail {
+                selected_phase: 2,
+                ..
             }
-            _ => panic!("View state should still be PhaseDetail"),
-        }
+        ));
     }
 
     #[test]
-    fn test_up_arrow_navigates_up_in_tasks_pane() {
+    fn test_o_cycles_list_sort_and_keeps_selection() {
+        let mut early = make_test_orchestration("early");
+        early.started_at = "2026-02-07T09:00:00Z".to_string();
+        let mut blocked = make_test_orchestration("blocked");
+        blocked.started_at = "2026-02-07T11:00:00Z".to_string();
+        blocked.status = MonitorOrchestrationStatus::Blocked;
+        let mut app =
+            App::new_with_orchestrations(vec![make_test_orchestration("middle"), early, blocked]);
+        let names = |app: &App| -> Vec<String> {
+            app.orchestrations
+                .iter()
+                .map(|o| o.feature_name.clone())
+                .collect()
+        };
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE));
+        assert_eq!(app.list.sort, ListSort::Elapsed);
+        assert_eq!(app.list_title(), "Orchestrations · by elapsed");
+        assert_eq!(names(&app), vec!["early", "middle", "blocked"]);
+        assert_eq!(
+            app.orchestrations[app.selected_index].feature_name,
+            "middle"
+        );
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE));
+        assert_eq!(app.list.sort, ListSort::Status);
+        assert_eq!(names(&app), vec!["blocked", "early", "middle"]);
+        assert_eq!(
+            app.orchestrations[app.selected_index].feature_name,
+            "middle"
+        );
+    }
+
+    #[test]
+    fn test_project_sections_group_and_collapse() {
+        let in_project = |title: &str, project: Option<&str>| {
+            let mut orch = make_test_orchestration(title);
+            orch.project_id = project.map(str::to_string);
+            orch
+        };
+        let mut app = App::new_with_orchestrations(vec![
+            in_project("zeta-1", Some("p2")),
+            in_project("loose", None),
+            in_project("acme-1", Some("p1")),
+            in_project("zeta-2", Some("p2")),
+        ]);
+        app.list_paging.project_names = HashMap::from([
+            ("p1".to_string(), "acme".to_string()),
+            ("p2".to_string(), "zeta".to_string()),
+        ]);
+        app.sort_orchestrations(None);
+        let names: Vec<&str> = app
+            .orchestrations
+            .iter()
+            .map(|o| o.feature_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["acme-1", "zeta-1", "zeta-2", "loose"]);
+        assert_eq!(app.project_section(2), 1..3);
+
+        // Collapsing moves the selection to the section's header
+        app.selected_index = 2;
+        app.handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+        assert!(app.collapsed_projects.contains(&Some("p2".to_string())));
+        assert_eq!(app.selected_index, 1);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        assert_eq!(
+            app.selected_index, 3,
+            "collapsed orchestrations are skipped"
+        );
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE));
+        assert_eq!(app.selected_index, 1);
+
+        // Enter on a collapsed section expands it instead of opening it
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(app.collapsed_projects.is_empty());
+        assert!(matches!(app.view_state, ViewState::OrchestrationList));
+    }
+
+    #[test]
+    fn test_projects_view_drills_down_into_a_project() {
+        let in_project = |title: &str, project: Option<&str>| {
+            let mut orch = make_test_orchestration(title);
+            orch.project_id = project.map(str::to_string);
+            orch
+        };
+        let mut app = App::new_with_orchestrations(vec![
+            in_project("acme-1", Some("p1")),
+            in_project("zeta-1", Some("p2")),
+            in_project("zeta-2", Some("p2")),
+            in_project("loose", None),
+        ]);
+        app.list_paging.projects = [("p1", "acme"), ("p2", "zeta"), ("p3", "empty")]
+            .iter()
+            .map(|(id, name)| ProjectRecord {
+                id: id.to_string(),
+                name: name.to_string(),
+                repo_path: format!("/code/{}", name),
+                created_at: "2026-02-07T10:00:00Z".to_string(),
+                orchestration_count: None,
+            })
+            .collect();
+        let rows = projects::rows(&app);
+        let names: Vec<&str> = rows.iter().map(|row| row.name.as_str()).collect();
+        assert_eq!(names, vec!["acme", "empty", "zeta", "No project"]);
+        assert_eq!(rows[2].orchestrations, vec![1, 2]);
+
+        app.selected_index = 2;
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('P'), KeyModifiers::NONE));
+        assert_eq!(
+            app.view_state,
+            ViewState::Projects {
+                selected: 2,
+                form: None
+            }
+        );
+
+        // A project with nothing loaded stays put
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE));
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(matches!(app.view_state, ViewState::Projects { .. }));
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(app.view_state, ViewState::OrchestrationList);
+        assert_eq!(app.selected_index, 1);
+        assert_eq!(
+            app.collapsed_projects,
+            BTreeSet::from([Some("p1".to_string()), None])
+        );
+    }
+
+    #[test]
+    fn test_background_load_lands_on_poll_and_keeps_selection() {
+        let mut app = App::new_with_orchestrations(vec![
+            make_test_orchestration("alpha"),
+            make_test_orchestration("beta"),
+        ]);
+        app.selected_index = 1;
+        let (tx, rx) = mpsc::channel();
+        app.pending_load = Some(PendingLoad {
+            rx,
+            selected_id: app.selected_id(),
+        });
+
+        app.poll_load();
+        assert!(app.is_refreshing(), "nothing has arrived yet");
+
+        let loaded = vec![
+            make_test_orchestration("new"),
+            make_test_orchestration("alpha"),
+            make_test_orchestration("beta"),
+        ];
+        tx.send(Ok((loaded, PendingGates::new(), ListPaging::default())))
+            .unwrap();
+        app.poll_load();
+        assert!(!app.is_refreshing());
+        assert_eq!(app.orchestrations.len(), 3);
+        assert_eq!(app.orchestrations[app.selected_index].feature_name, "beta");
+    }
+
+    #[test]
+    fn test_failed_background_load_keeps_last_data() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("alpha")]);
+        let (tx, rx) = mpsc::channel();
+        app.pending_load = Some(PendingLoad {
+            rx,
+            selected_id: None,
+        });
+
+        tx.send(Err(anyhow::anyhow!("network down"))).unwrap();
+        app.poll_load();
+        assert!(!app.is_refreshing());
+        assert_eq!(app.orchestrations.len(), 1);
+    }
+
+    #[test]
+    fn test_first_load_restores_saved_view() {
+        let mut app = App::new_with_orchestrations(vec![]);
+        app.restore = Some(SessionState {
+            orchestration_id: Some("orch-beta".to_string()),
+            view: SavedView::PhaseDetail {
+                phase: 9,
+                layout: PhaseDetailLayout::TasksDetail,
+                focus: PaneFocus::Members,
+            },
+        });
+        let loaded = vec![
+            make_test_orchestration("alpha"),
+            make_test_orchestration("beta"),
+        ];
+        app.apply_load(
+            (loaded.clone(), PendingGates::new(), ListPaging::default()),
+            None,
+        );
+
+        assert!(app.restore.is_none());
+        assert_eq!(app.orchestrations[app.selected_index].feature_name, "beta");
+        assert_eq!(
+            app.view_state,
+            ViewState::PhaseDetail {
+                focus: PaneFocus::Members,
+                task_index: 0,
+                member_index: 0,
+                layout: PhaseDetailLayout::TasksDetail,
+                selected_phase: 3,
+            },
+            "phase is clamped to the orchestration's phases"
+        );
+        assert_eq!(
+            app.session_state().orchestration_id.as_deref(),
+            Some("orch-beta")
+        );
+
+        // Later loads leave the view alone
+        app.view_state = ViewState::OrchestrationList;
+        app.apply_load((loaded, PendingGates::new(), ListPaging::default()), None);
+        assert_eq!(app.view_state, ViewState::OrchestrationList);
+    }
+
+    #[test]
+    fn test_live_updates_merge_into_loaded_rows() {
+        let (tx, rx) = mpsc::channel();
+        let mut app = App::new_with_orchestrations(vec![
+            make_test_orchestration("alpha"),
+            make_test_orchestration("beta"),
+        ]);
+        app.selected_index = 1;
+        app.live = Some(LiveUpdates::from_receiver(rx));
+
+        // List rows carry no tasks; the loaded ones are kept
+        let mut alpha = make_test_orchestration("alpha");
+        alpha.tasks.clear();
+        alpha.status = MonitorOrchestrationStatus::Executing;
+        tx.send(LiveUpdate::Page(OrchestrationPage {
+            orchestrations: vec![alpha, make_test_orchestration("gamma")],
+            next_cursor: None,
+            total_count: Some(3),
+        }))
+        .unwrap();
+        app.poll_live();
+        assert_eq!(app.orchestrations.len(), 3, "new rows are added");
+        let alpha = app
+            .orchestrations
+            .iter()
+            .find(|o| o.id == "orch-alpha")
+            .unwrap();
+        assert_eq!(alpha.status, MonitorOrchestrationStatus::Executing);
+        assert_eq!(alpha.tasks.len(), 3);
+        assert_eq!(app.orchestrations[app.selected_index].id, "orch-beta");
+        assert_eq!(app.list_paging.total_count, Some(3));
+
+        // Details replace the row's tasks
+        let mut beta = make_test_orchestration("beta");
+        beta.tasks.truncate(1);
+        tx.send(LiveUpdate::Detail(Box::new(beta))).unwrap();
+        app.poll_live();
+        assert_eq!(app.orchestrations[app.selected_index].tasks.len(), 1);
+        assert!(app.live.as_ref().unwrap().is_connected());
+
+        tx.send(LiveUpdate::Lost("closed".to_string())).unwrap();
+        app.poll_live();
+        assert!(!app.live.as_ref().unwrap().is_connected());
+        assert!(app
+            .notifications
+            .history()
+            .iter()
+            .any(|toast| toast.message.contains("disconnected")));
+    }
+
+    #[test]
+    fn test_restore_skipped_when_user_moved_or_orchestration_gone() {
+        let saved = SessionState {
+            orchestration_id: Some("orch-beta".to_string()),
+            view: SavedView::Dashboard,
+        };
+        let loaded = || {
+            (
+                vec![
+                    make_test_orchestration("alpha"),
+                    make_test_orchestration("beta"),
+                ],
+                PendingGates::new(),
+                ListPaging::default(),
+            )
+        };
+
+        let mut app = App::new_with_orchestrations(vec![]);
+        app.restore = Some(saved.clone());
+        app.view_state = ViewState::Dashboard;
+        app.apply_load(loaded(), None);
+        assert_eq!(app.selected_index, 0);
+
+        let mut app = App::new_with_orchestrations(vec![]);
+        app.restore = Some(SessionState {
+            orchestration_id: Some("orch-deleted".to_string()),
+            ..saved
+        });
+        app.apply_load(loaded(), None);
+        assert_eq!(app.view_state, ViewState::OrchestrationList);
+    }
+
+    #[test]
+    fn test_session_state_saves_modals_as_their_base_view() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("alpha")]);
+        assert_eq!(app.session_state().view, SavedView::List);
+
+        app.view_state = ViewState::SpecViewer;
+        assert_eq!(
+            app.session_state().view,
+            SavedView::PhaseDetail {
+                phase: 1,
+                layout: PhaseDetailLayout::OrchPhaseTasks,
+                focus: PaneFocus::Phases,
+            }
+        );
+
+        app.view_state = ViewState::PlanViewer {
+            plan_path: "plan.md".into(),
+            scroll_offset: 0,
+        };
+        assert_eq!(app.session_state().view, SavedView::List);
+    }
+
+    #[test]
+    fn test_spec_viewer_switches_revisions_and_closes() {
+        use tina_data::{SpecRecord, SpecRevisionRecord};
+
+        let revision = |n: u32| SpecRevisionRecord {
+            id: format!("rev-{}", n),
+            spec_id: "spec-1".to_string(),
+            revision: n,
+            title: "Auth".to_string(),
+            markdown: format!("# Revision {}", n),
+            created_at: "2026-02-10T10:00:00Z".to_string(),
+        };
+        let spec = SpecRecord {
+            id: "spec-1".to_string(),
+            project_id: "proj-1".to_string(),
+            spec_key: "TINA-S1".to_string(),
+            title: "Auth".to_string(),
+            markdown: "# Revision 2".to_string(),
+            status: "approved".to_string(),
+            created_at: "2026-02-10T10:00:00Z".to_string(),
+            updated_at: "2026-02-10T10:00:00Z".to_string(),
+            archived_at: None,
+        };
         let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
-        app.view_state = ViewState::PhaseDetail {
-            focus: PaneFocus::Tasks,
-            task_index: 1,
-            member_index: 0,
-            layout: PhaseDetailLayout::OrchPhaseTasks,
-            selected_phase: 1,
+        app.spec_viewer = Some(SpecViewer::new(spec, vec![revision(2), revision(1)]));
+        app.view_state = ViewState::SpecViewer;
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('['), KeyModifiers::NONE));
+        assert_eq!(app.spec_viewer.as_ref().unwrap().markdown(), "# Revision 1");
+        app.handle_key_event(KeyEvent::new(KeyCode::Char(']'), KeyModifiers::NONE));
+        assert_eq!(app.spec_viewer.as_ref().unwrap().markdown(), "# Revision 2");
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(app.spec_viewer.is_none());
+        assert!(matches!(
+            app.view_state,
+            ViewState::PhaseDetail {
+                focus: PaneFocus::Phases,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_replay_steps_through_events_and_closes() {
+        use tina_data::TaskEventRecord;
+
+        let event = |status: &str, at: &str| TaskEventRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: Some("1".to_string()),
+            task_id: "1".to_string(),
+            subject: "Add login".to_string(),
+            description: None,
+            status: status.to_string(),
+            owner: None,
+            blocked_by: None,
+            metadata: None,
+            recorded_at: at.to_string(),
         };
+        let replay = Replay::new(
+            vec![
+                event("pending", "2026-02-10T10:00:00Z"),
+                event("in_progress", "2026-02-10T10:05:00Z"),
+                event("completed", "2026-02-10T10:20:00Z"),
+            ],
+            vec![],
+        );
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.replay = Some(ReplayViewer::new("project-1".to_string(), replay));
+        app.view_state = ViewState::Replay;
 
-        let key = KeyEvent::new(KeyCode::Up, KeyModifiers::NONE);
-        app.handle_key_event(key);
-
-        match app.view_state {
-            ViewState::PhaseDetail { task_index, .. } => {
-                assert_eq!(task_index, 0, "Up arrow should navigate up in tasks pane");
-            }
-            _ => panic!("View state should still be PhaseDetail"),
-        }
+        let status = |app: &App| {
+            app.replay.as_ref().unwrap().replay.state().tasks[0]
+                .status
+                .clone()
+        };
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('l'), KeyModifiers::NONE));
+        assert_eq!(status(&app), "in_progress");
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('G'), KeyModifiers::NONE));
+        assert_eq!(status(&app), "completed");
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE));
+        assert_eq!(status(&app), "in_progress");
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE));
+        assert_eq!(status(&app), "pending");
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(app.replay.is_none());
+        assert!(matches!(app.view_state, ViewState::PhaseDetail { .. }));
     }
 
     #[test]
-    fn test_j_key_navigates_down_in_members_pane() {
+    fn test_shift_f_on_phases_asks_for_retry_reason() {
         let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
-        app.view_state = ViewState::PhaseDetail {
-            focus: PaneFocus::Members,
+        let detail = ViewState::PhaseDetail {
+            focus: PaneFocus::Phases,
             task_index: 0,
             member_index: 0,
             layout: PhaseDetailLayout::OrchPhaseTasks,
-            selected_phase: 1,
+            selected_phase: 2,
         };
+        app.view_state = detail.clone();
+
+        // Only blocked orchestrations can be retried
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('F'), KeyModifiers::NONE));
+        assert_eq!(app.view_state, detail);
+        assert!(app
+            .notifications
+            .history()
+            .last()
+            .unwrap()
+            .message
+            .contains("only a blocked orchestration"));
 
-        let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
-        app.handle_key_event(key);
-
-        match app.view_state {
-            ViewState::PhaseDetail { member_index, .. } => {
-                assert_eq!(member_index, 1, "'j' should navigate down in members pane");
-            }
-            _ => panic!("View state should still be PhaseDetail"),
+        app.orchestrations[0].status = MonitorOrchestrationStatus::Blocked;
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('F'), KeyModifiers::NONE));
+        for c in "flaky?q".chars() {
+            app.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
         }
+        app.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        assert_eq!(
+            app.view_state,
+            ViewState::ConfirmRetryPhase {
+                phase: 2,
+                reason: "flaky?".to_string(),
+            }
+        );
+        assert!(!app.should_quit, "typed `q` goes to the reason");
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert_eq!(app.view_state, detail);
     }
 
     #[test]
-    fn test_k_key_navigates_up_in_members_pane() {
+    fn test_x_on_members_asks_before_stopping_phase() {
         let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
         app.view_state = ViewState::PhaseDetail {
             focus: PaneFocus::Members,
             task_index: 0,
             member_index: 1,
             layout: PhaseDetailLayout::OrchPhaseTasks,
-            selected_phase: 1,
+            selected_phase: 2,
         };
 
-        let key = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE);
-        app.handle_key_event(key);
-
-        match app.view_state {
-            ViewState::PhaseDetail { member_index, .. } => {
-                assert_eq!(member_index, 0, "'k' should navigate up in members pane");
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE));
+        assert_eq!(
+            app.view_state,
+            ViewState::ConfirmStopPhase {
+                phase: 2,
+                member_index: 1,
+                layout: PhaseDetailLayout::OrchPhaseTasks,
             }
-            _ => panic!("View state should still be PhaseDetail"),
-        }
-    }
-
-    #[test]
-    fn test_enter_on_tasks_does_nothing_in_new_layout() {
-        // In the new two-screen layout, Enter on Tasks does nothing
-        // Use 'l' key to navigate to TaskDetail screen instead
-        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
-        app.view_state = ViewState::PhaseDetail {
-            focus: PaneFocus::Tasks,
-            task_index: 2,
-            member_index: 0,
-            layout: PhaseDetailLayout::OrchPhaseTasks,
-            selected_phase: 1,
-        };
+        );
 
-        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
-        app.handle_key_event(key);
+        // Other keys leave the confirmation open
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        assert!(matches!(app.view_state, ViewState::ConfirmStopPhase { .. }));
 
-        // Should still be in PhaseDetail with same state
-        match app.view_state {
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE));
+        assert_eq!(
+            app.view_state,
             ViewState::PhaseDetail {
-                focus, task_index, ..
-            } => {
-                assert_eq!(focus, PaneFocus::Tasks, "Focus should remain on Tasks");
-                assert_eq!(task_index, 2, "task_index should not change");
+                focus: PaneFocus::Members,
+                task_index: 0,
+                member_index: 1,
+                layout: PhaseDetailLayout::OrchPhaseTasks,
+                selected_phase: 2,
             }
-            _ => panic!("View state should still be PhaseDetail"),
-        }
+        );
+        assert_ne!(
+            app.orchestrations[0].status,
+            MonitorOrchestrationStatus::Blocked
+        );
     }
 
     #[test]
@@ -2545,6 +6583,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_edit_refuses_tasks_that_are_not_pending() {
+        let mut orch = make_test_orchestration("project-1");
+        orch.tasks[1].status = crate::types::TaskStatus::InProgress;
+        let mut app = App::new_with_orchestrations(vec![orch]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Tasks,
+            task_index: 1,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('E'), KeyModifiers::NONE));
+
+        assert!(matches!(app.view_state, ViewState::PhaseDetail { .. }));
+        let toast = app.notifications.history().last().expect("toast");
+        assert!(toast.message.contains("only pending tasks can be edited"));
+    }
+
+    #[test]
+    fn test_task_editor_takes_typed_keys_and_closes_unchanged() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        let task = ExecutionTaskRecord {
+            orchestration_id: "orch-project-1".to_string(),
+            phase_number: "1".to_string(),
+            task_number: 2,
+            subject: "Task 2".to_string(),
+            description: None,
+            status: "pending".to_string(),
+            model: None,
+            revision: 1,
+        };
+        app.view_state = ViewState::TaskEdit {
+            editor: TaskEditor::new(task, 1),
+            selected_phase: 1,
+        };
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE));
+        assert!(!app.should_quit, "q is typed into the subject");
+        app.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+
+        // Nothing changed, so saving just closes the popup
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(matches!(
+            app.view_state,
+            ViewState::PhaseDetail {
+                focus: PaneFocus::Tasks,
+                task_index: 1,
+                ..
+            }
+        ));
+        assert!(app.notifications.history().is_empty());
+    }
+
     #[test]
     fn test_task_inspector_ignores_other_keys() {
         let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
@@ -2864,6 +6957,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_shift_g_reports_missing_worktree() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::OrchestrationList;
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('G'), KeyModifiers::NONE));
+
+        assert!(matches!(app.view_state, ViewState::OrchestrationList));
+        let toast = app.notifications.history().last().expect("toast");
+        assert!(toast.message.contains("Worktree not found"));
+    }
+
     #[test]
     fn test_esc_in_command_modal_returns_to_orchestration_list() {
         let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
@@ -3047,6 +7152,8 @@ mod tests {
             worktree_path: PathBuf::from("/test"),
             range: "main...branch".to_string(),
             title: "Test Commits".to_string(),
+            selected: 0,
+            detail: None,
         };
 
         let key = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
@@ -3074,6 +7181,8 @@ mod tests {
             worktree_path: PathBuf::from("/test"),
             range: "main...branch".to_string(),
             title: "Test Commits".to_string(),
+            selected: 0,
+            detail: None,
         };
 
         // Try various keys that should do nothing at the app level
@@ -3094,6 +7203,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_enter_in_commits_view_drills_into_commit_and_file_diff() {
+        let repo = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .to_path_buf();
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::CommitsView {
+            worktree_path: repo.clone(),
+            range: "HEAD~1..HEAD".to_string(),
+            title: "Test Commits".to_string(),
+            selected: 0,
+            detail: None,
+        };
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        let state = match &app.view_state {
+            ViewState::CommitsView {
+                detail: Some(state),
+                ..
+            } => state.clone(),
+            other => panic!("Enter should open commit detail, got {:?}", other),
+        };
+        assert!(state.hash.len() >= 40, "detail should hold the full hash");
+
+        // Esc closes the detail pane but stays in the commits view
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(matches!(
+            app.view_state,
+            ViewState::CommitsView { detail: None, .. }
+        ));
+
+        // Move to the first file row, then Enter opens its diff
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        let view = CommitDetailView::new(&repo, &state).unwrap();
+        let file_row = view
+            .rows
+            .iter()
+            .position(|row| matches!(row.entry, TreeEntry::File(_)))
+            .expect("commit should touch a file");
+        for _ in 0..file_row {
+            app.handle_key_event(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        }
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        match &app.view_state {
+            ViewState::DiffView { range, .. } => {
+                assert_eq!(range, &format!("{}~1..{}", state.hash, state.hash));
+            }
+            other => panic!("Enter on a file should open DiffView, got {:?}", other),
+        }
+    }
+
     // Task 7: 's' Key Handler in Members Pane tests
 
     #[test]
@@ -3229,6 +7390,45 @@ mod tests {
         assert!(!app.should_quit, "App should not quit on 'd' key");
     }
 
+    #[test]
+    fn test_side_by_side_keys_update_diff_layout() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::DiffView {
+            worktree_path: PathBuf::from("/test"),
+            range: "main...branch".to_string(),
+            title: "Test Diff".to_string(),
+            selected: 0,
+            show_full: false,
+            scroll: 0,
+            side_by_side: SideBySide::default(),
+        };
+
+        for code in [
+            KeyCode::Char('s'),
+            KeyCode::Tab,
+            KeyCode::Char('l'),
+            KeyCode::Char('l'),
+            KeyCode::Char('h'),
+        ] {
+            app.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+        }
+
+        match app.view_state {
+            ViewState::DiffView {
+                show_full,
+                side_by_side,
+                ..
+            } => {
+                assert!(show_full, "side-by-side should open the full diff");
+                assert!(side_by_side.enabled);
+                assert!(side_by_side.focus_right);
+                assert_eq!(side_by_side.left_scroll, 0);
+                assert_eq!(side_by_side.right_scroll, 8);
+            }
+            _ => panic!("should stay in DiffView"),
+        }
+    }
+
     #[test]
     fn test_esc_in_diff_view_returns_to_phase_detail() {
         let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
@@ -3239,6 +7439,7 @@ mod tests {
             selected: 0,
             show_full: false,
             scroll: 0,
+            side_by_side: SideBySide::default(),
         };
 
         let key = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
@@ -3306,6 +7507,42 @@ mod tests {
         assert_eq!(app.send_dialog.as_ref().unwrap().input, "hell");
     }
 
+    #[test]
+    fn test_send_dialog_edits_multiple_lines() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.send_dialog = Some(crate::tui::views::send_dialog::SendDialog::new(
+            "test-pane".to_string(),
+            "test-agent".to_string(),
+            false,
+        ));
+        app.view_state = ViewState::SendDialog {
+            pane_id: "test-pane".to_string(),
+            agent_name: "test-agent".to_string(),
+        };
+
+        for c in "fix 2 bugs".chars() {
+            app.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT));
+        app.handle_paste("then run\r\nthe tests");
+        assert_eq!(
+            app.send_dialog.as_ref().unwrap().input,
+            "fix 2 bugs\nthen run\nthe tests",
+            "Digits are typed once there is input, Alt+Enter and paste add lines"
+        );
+
+        // Up walks the lines before it would recall history
+        app.handle_key_event(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        app.handle_key_event(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        app.handle_key_event(KeyEvent::new(KeyCode::Home, KeyModifiers::NONE));
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        assert_eq!(
+            app.send_dialog.as_ref().unwrap().input,
+            "yfix 2 bugs\nthen run\nthe tests",
+            "'y' is typed when not confirming"
+        );
+    }
+
     #[test]
     fn test_1_key_sets_checkpoint_quick_action() {
         let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
@@ -3486,8 +7723,6 @@ mod tests {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::PhaseDetail {
                 focus: PaneFocus::Members,
                 task_index: 0,
@@ -3498,14 +7733,41 @@ mod tests {
             log_viewer: None,
             send_dialog: Some(crate::tui::views::send_dialog::SendDialog {
                 input: "echo test".to_string(),
+                cursor_from_end: 0,
                 pane_id: "invalid-pane-for-test".to_string(),
                 agent_name: "test-agent".to_string(),
                 quick_action: 0,
                 needs_confirmation: false,
                 confirming: false,
+                history: Default::default(),
             }),
             command_logger: Some(crate::logging::CommandLogger::new(log_path.clone())),
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         // Execute send - this will fail with invalid pane, but we verify it attempts to send
@@ -3527,6 +7789,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_send_dialog_recalls_and_searches_history() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.send_dialog = Some(
+            crate::tui::views::send_dialog::SendDialog::new(
+                "%1".to_string(),
+                "worker".to_string(),
+                false,
+            )
+            .with_history(vec!["/checkpoint".to_string(), "keep going".to_string()]),
+        );
+        app.view_state = ViewState::SendDialog {
+            pane_id: "%1".to_string(),
+            agent_name: "worker".to_string(),
+        };
+        let input = |app: &App| app.send_dialog.as_ref().unwrap().input.clone();
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        app.handle_key_event(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        assert_eq!(input(&app), "keep going");
+        app.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        assert_eq!(input(&app), "/checkpoint");
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        for c in "going".chars() {
+            app.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(input(&app), "keep going");
+        assert!(
+            matches!(app.view_state, ViewState::SendDialog { .. }),
+            "Enter in the search should only pick the entry, not send"
+        );
+    }
+
     #[test]
     fn test_execute_send_logs_command() {
         use std::io::Read;
@@ -3542,8 +7839,6 @@ mod tests {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::PhaseDetail {
                 focus: PaneFocus::Members,
                 task_index: 0,
@@ -3554,14 +7849,41 @@ mod tests {
             log_viewer: None,
             send_dialog: Some(crate::tui::views::send_dialog::SendDialog {
                 input: "echo logged command".to_string(),
+                cursor_from_end: 0,
                 pane_id: "test-pane-123".to_string(),
                 agent_name: "test-agent".to_string(),
                 quick_action: 0,
                 needs_confirmation: false,
                 confirming: false,
+                history: Default::default(),
             }),
             command_logger: Some(crate::logging::CommandLogger::new(log_path.clone())),
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         // Execute send
@@ -3598,8 +7920,6 @@ mod tests {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::PhaseDetail {
                 focus: PaneFocus::Tasks,
                 task_index: 2,
@@ -3610,14 +7930,41 @@ mod tests {
             log_viewer: None,
             send_dialog: Some(crate::tui::views::send_dialog::SendDialog {
                 input: "/checkpoint".to_string(),
+                cursor_from_end: 0,
                 pane_id: "test-pane-456".to_string(),
                 agent_name: "agent-x".to_string(),
                 quick_action: 1,
                 needs_confirmation: false,
                 confirming: false,
+                history: Default::default(),
             }),
             command_logger: Some(crate::logging::CommandLogger::new(log_path)),
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: Notifications::default(),
+            stuck_tasks: StuckTasks::default(),
+            list_paging: ListPaging::default(),
+            pending_gates: PendingGates::new(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: BTreeSet::new(),
+            task_marks: BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         };
 
         // Execute send
@@ -3769,4 +8116,182 @@ mod tests {
             _ => panic!("Should still be in PhaseDetail view"),
         }
     }
+
+    /// Everything a key press could change in these tests
+    fn key_fingerprint(app: &App) -> String {
+        format!(
+            "{:?}",
+            (
+                &app.view_state,
+                (app.show_help, app.notifications.show_history),
+                (app.stuck_tasks.show, app.should_quit),
+                app.notifications.history().len(),
+                app.selected_index,
+                app.zoomed,
+                &app.list,
+                &app.task_marks,
+                app.pending_editor.is_some(),
+                app.palette.is_some(),
+                app.global_search.is_some(),
+            )
+        )
+    }
+
+    #[test]
+    fn test_keys_missing_from_the_keymap_do_nothing() {
+        use crate::tui::keymap::KeyContext;
+
+        let detail = |focus| ViewState::PhaseDetail {
+            focus,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+        let views = vec![
+            ViewState::OrchestrationList,
+            ViewState::Dashboard,
+            ViewState::Projects {
+                selected: 0,
+                form: None,
+            },
+            detail(PaneFocus::Orchestrations),
+            detail(PaneFocus::Phases),
+            detail(PaneFocus::Tasks),
+            detail(PaneFocus::Members),
+            detail(PaneFocus::Detail),
+            ViewState::TaskInspector { task_index: 0 },
+            ViewState::PlanViewer {
+                plan_path: PathBuf::from("/test/plan.md"),
+                scroll_offset: 0,
+            },
+            ViewState::PhaseTimeline {
+                selected_phase: 1,
+                show_tasks: false,
+            },
+            ViewState::CostPanel {
+                selected_phase: 1,
+                budget: crate::config::CostConfig::default(),
+            },
+            ViewState::WorktreeStatus {
+                worktree_path: PathBuf::from("/test"),
+                status: Err("not here".to_string()),
+                scroll: 0,
+                selected_phase: 1,
+            },
+            ViewState::TaskGraph {
+                selected_phase: 1,
+                scroll_y: 0,
+                scroll_x: 0,
+            },
+            ViewState::ConfirmStopPhase {
+                phase: 1,
+                member_index: 0,
+                layout: PhaseDetailLayout::OrchPhaseTasks,
+            },
+            ViewState::CommandModal {
+                command: "tina-session status".to_string(),
+                description: "Status".to_string(),
+                copied: false,
+            },
+        ];
+
+        let mut keys: Vec<KeyEvent> = (' '..='~')
+            .map(|c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+            .chain(('a'..='z').map(|c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)))
+            .collect();
+        keys.extend(
+            [
+                KeyCode::Enter,
+                KeyCode::Esc,
+                KeyCode::Tab,
+                KeyCode::Backspace,
+                KeyCode::Up,
+                KeyCode::Down,
+                KeyCode::Left,
+                KeyCode::Right,
+                KeyCode::PageUp,
+                KeyCode::PageDown,
+                KeyCode::Home,
+                KeyCode::End,
+            ]
+            .map(|code| KeyEvent::new(code, KeyModifiers::NONE)),
+        );
+
+        for view in views {
+            let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+            app.view_state = view.clone();
+            let contexts = KeyContext::active(&app);
+            for key in &keys {
+                let listed = contexts
+                    .iter()
+                    .chain([&KeyContext::Global])
+                    .any(|context| context.binds(key));
+                if listed {
+                    continue;
+                }
+                let mut app =
+                    App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+                app.view_state = view.clone();
+                let before = key_fingerprint(&app);
+                app.handle_key_event(*key);
+                assert_eq!(
+                    key_fingerprint(&app),
+                    before,
+                    "{:?} in {:?} isn't in the keymap but changed something",
+                    key,
+                    contexts
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_y_prefix_copies_identifiers() {
+        let mut app = App::new_with_orchestrations(vec![make_test_orchestration("project-1")]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Tasks,
+            task_index: 1,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+        assert_eq!(app.copy_value('t'), Ok(Some(("task id", "2".to_string()))));
+        assert_eq!(
+            app.copy_value('p'),
+            Ok(Some(("worktree path", "/test".to_string())))
+        );
+        assert_eq!(
+            app.copy_value('c'),
+            Err("No commit SHA to copy here".to_string())
+        );
+        assert_eq!(app.copy_value('x'), Ok(None));
+
+        // The key after `y` is the target, even one that would quit
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        assert!(app.pending_copy);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE));
+        assert!(!app.pending_copy);
+        assert!(!app.should_quit);
+
+        // Clipboard access may fail here; either way a toast names the target
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE));
+        let toast = &app.notifications.history().last().unwrap().message;
+        assert!(toast.contains("task id"), "{}", toast);
+
+        app.view_state = ViewState::DiffView {
+            worktree_path: PathBuf::from("/test"),
+            range: "abc123~1..abc123".to_string(),
+            title: "abc123".to_string(),
+            selected: 0,
+            show_full: false,
+            scroll: 0,
+            side_by_side: SideBySide::default(),
+        };
+        assert_eq!(
+            app.copy_value('c'),
+            Ok(Some(("commit SHA", "abc123".to_string())))
+        );
+    }
 }
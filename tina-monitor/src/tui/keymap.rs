@@ -0,0 +1,524 @@
+//! Keybinding table behind the help overlay
+//!
+//! Every view's keys are listed here once. The help overlay (`?`) shows the
+//! entries for the view and pane in focus, and a test presses every key
+//! missing from a view's entries to check it does nothing, so bindings
+//! added to a handler have to be added here too.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::app::{App, PaneFocus, ViewState};
+
+/// A key as listed in the table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    /// A typed character; handlers match these whatever the modifiers
+    Char(char),
+    /// A character with Ctrl held
+    Ctrl(char),
+    Code(KeyCode),
+    /// The second key of a sequence, e.g. `c` after `y`
+    Then(char, char),
+}
+
+use Key::{Char, Code, Ctrl, Then};
+
+impl Key {
+    pub fn label(self) -> String {
+        match self {
+            Char(' ') => "Space".to_string(),
+            Char(c) => c.to_string(),
+            Ctrl(c) => format!("Ctrl+{}", c.to_ascii_uppercase()),
+            Then(first, second) => format!("{} {}", first, second),
+            Code(code) => match code {
+                KeyCode::Enter => "Enter",
+                KeyCode::Esc => "Esc",
+                KeyCode::Tab => "Tab",
+                KeyCode::Backspace => "Backspace",
+                KeyCode::Up => "Up",
+                KeyCode::Down => "Down",
+                KeyCode::Left => "Left",
+                KeyCode::Right => "Right",
+                KeyCode::PageUp => "PgUp",
+                KeyCode::PageDown => "PgDn",
+                KeyCode::Home => "Home",
+                KeyCode::End => "End",
+                _ => "?",
+            }
+            .to_string(),
+        }
+    }
+
+    pub fn matches(self, key: &KeyEvent) -> bool {
+        match self {
+            Char(c) => key.code == KeyCode::Char(c),
+            Ctrl(c) => {
+                key.code == KeyCode::Char(c) && key.modifiers.contains(KeyModifiers::CONTROL)
+            }
+            Code(code) => key.code == code,
+            // The first key is what the view itself handles
+            Then(first, _) => key.code == KeyCode::Char(first),
+        }
+    }
+}
+
+/// One line of help: the keys, and what they do
+#[derive(Debug)]
+pub struct Binding(pub &'static [Key], pub &'static str);
+
+impl Binding {
+    pub fn keys(&self) -> &'static [Key] {
+        self.0
+    }
+
+    pub fn action(&self) -> &'static str {
+        self.1
+    }
+
+    /// Keys joined for display, e.g. "j / k / Down / Up"
+    pub fn label(&self) -> String {
+        self.0
+            .iter()
+            .map(|key| key.label())
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+const UP_DOWN: &[Key] = &[Char('j'), Char('k'), Code(KeyCode::Down), Code(KeyCode::Up)];
+const PAGE: &[Key] = &[
+    Char('d'),
+    Char('u'),
+    Code(KeyCode::PageDown),
+    Code(KeyCode::PageUp),
+];
+const PALETTE: &[Key] = &[Char(':'), Ctrl('p')];
+
+/// Where a set of bindings applies: a view, or a pane of the phase detail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyContext {
+    List,
+    Dashboard,
+    Projects,
+    /// Keys that work whichever phase detail pane has focus
+    PhaseDetail,
+    OrchestrationsPane,
+    PhasesPane,
+    TasksPane,
+    MembersPane,
+    TaskInspector,
+    LogViewer,
+    LogSearch,
+    PlanViewer,
+    SpecViewer,
+    Replay,
+    Commits,
+    CommitDetail,
+    Diff,
+    PhaseTimeline,
+    Cost,
+    WorktreeStatus,
+    TaskGraph,
+    SendDialog,
+    ConfirmStop,
+    CommandModal,
+    BulkTaskModel,
+    /// `y` copy targets, in views that don't use `y` themselves
+    Copy,
+    /// Keys that work in every view
+    Global,
+}
+
+impl KeyContext {
+    pub fn title(self) -> &'static str {
+        match self {
+            KeyContext::List => "Orchestration List",
+            KeyContext::Dashboard => "Dashboard",
+            KeyContext::Projects => "Projects",
+            KeyContext::PhaseDetail => "Phase Detail",
+            KeyContext::OrchestrationsPane => "Orchestrations Pane",
+            KeyContext::PhasesPane => "Phases Pane",
+            KeyContext::TasksPane => "Tasks Pane",
+            KeyContext::MembersPane => "Team Members Pane",
+            KeyContext::TaskInspector => "Task Inspector",
+            KeyContext::LogViewer => "Log Viewer",
+            KeyContext::LogSearch => "Log Search",
+            KeyContext::PlanViewer => "Plan Viewer",
+            KeyContext::SpecViewer => "Spec Viewer",
+            KeyContext::Replay => "Replay",
+            KeyContext::Commits => "Commits Viewer",
+            KeyContext::CommitDetail => "Commit Details",
+            KeyContext::Diff => "Diff Viewer",
+            KeyContext::PhaseTimeline => "Phase Timeline",
+            KeyContext::Cost => "Cost",
+            KeyContext::WorktreeStatus => "Working Tree",
+            KeyContext::TaskGraph => "Task Graph",
+            KeyContext::SendDialog => "Send Dialog",
+            KeyContext::ConfirmStop => "Stop Phase",
+            KeyContext::CommandModal => "Command",
+            KeyContext::BulkTaskModel => "Set Model for Marked Tasks",
+            KeyContext::Copy => "Copy",
+            KeyContext::Global => "Global",
+        }
+    }
+
+    pub fn bindings(self) -> &'static [Binding] {
+        match self {
+            KeyContext::List => &[
+                Binding(UP_DOWN, "Navigate up/down"),
+                Binding(&[Code(KeyCode::Enter)], "Expand orchestration details"),
+                Binding(&[Char('g')], "Open terminal at worktree (goto)"),
+                Binding(
+                    &[Char('G')],
+                    "Open git tool at worktree ([terminal] git_tool)",
+                ),
+                Binding(&[Char('p')], "View current phase plan"),
+                Binding(&[Char('e')], "Open plan (or spec) in editor"),
+                Binding(&[Char('r')], "Refresh data"),
+                Binding(
+                    &[Char('/')],
+                    "Search orchestrations, tasks, agents, commits",
+                ),
+                Binding(&[Char('w')], "Dashboard of all active orchestrations"),
+                Binding(&[Char('P')], "Projects, with orchestration counts"),
+                Binding(
+                    &[Char('o')],
+                    "Cycle sort: started, elapsed, status, progress, activity",
+                ),
+                Binding(&[Char(' ')], "Collapse/expand the project section"),
+                Binding(PALETTE, "Quick actions (gates, pause, retry, cleanup)"),
+                Binding(&[Code(KeyCode::Esc)], "Quit"),
+            ],
+            KeyContext::Dashboard => &[
+                Binding(
+                    &[
+                        Char('h'),
+                        Char('j'),
+                        Char('k'),
+                        Char('l'),
+                        Code(KeyCode::Left),
+                        Code(KeyCode::Down),
+                        Code(KeyCode::Up),
+                        Code(KeyCode::Right),
+                    ],
+                    "Move between cards",
+                ),
+                Binding(&[Code(KeyCode::Enter)], "Expand orchestration details"),
+                Binding(&[Char('r')], "Refresh now (auto-refreshes every 5s)"),
+                Binding(&[Char('/')], "Global search"),
+                Binding(PALETTE, "Quick actions for the selected card"),
+                Binding(
+                    &[Char('w'), Code(KeyCode::Esc)],
+                    "Return to orchestration list",
+                ),
+            ],
+            KeyContext::Projects => &[
+                Binding(UP_DOWN, "Select project"),
+                Binding(
+                    &[Code(KeyCode::Enter)],
+                    "Show the project's orchestrations in the list",
+                ),
+                Binding(&[Char('n')], "New project"),
+                Binding(&[Char('r')], "Refresh data"),
+                Binding(&[Char('/')], "Global search"),
+                Binding(PALETTE, "Quick actions"),
+                Binding(&[Code(KeyCode::Esc)], "Return to orchestration list"),
+            ],
+            KeyContext::PhaseDetail => &[
+                Binding(
+                    &[
+                        Char('h'),
+                        Char('l'),
+                        Code(KeyCode::Left),
+                        Code(KeyCode::Right),
+                    ],
+                    "Move between panes and screens",
+                ),
+                Binding(
+                    &[Code(KeyCode::Tab)],
+                    "Switch between tasks and team members",
+                ),
+                Binding(&[Char('c')], "View commits for current phase"),
+                Binding(&[Char('d')], "View diff stats for current phase"),
+                Binding(&[Char('T')], "Phase timeline (Gantt)"),
+                Binding(&[Char('$')], "Cost per phase and role, against budgets"),
+                Binding(&[Char('W')], "Uncommitted changes in the worktree"),
+                Binding(&[Char('G')], "Open git tool at worktree"),
+                Binding(&[Char('g')], "Task dependency graph with critical path"),
+                Binding(&[Char('V')], "View spec from Convex"),
+                Binding(&[Char('R')], "Replay the orchestration's recorded history"),
+                Binding(&[Char('e')], "Open plan, spec, or task finding in editor"),
+                Binding(&[Char('z')], "Maximize/restore focused pane"),
+                Binding(&[Char('r')], "Refresh data"),
+                Binding(&[Char('/')], "Global search"),
+                Binding(PALETTE, "Quick actions for this orchestration"),
+                Binding(&[Code(KeyCode::Esc)], "Return to orchestration list"),
+            ],
+            KeyContext::OrchestrationsPane => &[Binding(UP_DOWN, "Select orchestration")],
+            KeyContext::PhasesPane => &[
+                Binding(UP_DOWN, "Select phase"),
+                Binding(&[Char('p'), Code(KeyCode::Enter)], "View the phase's plan"),
+                Binding(&[Char('D')], "View the design doc"),
+                Binding(&[Char('F')], "Retry the phase, with a reason"),
+            ],
+            KeyContext::TasksPane => &[
+                Binding(UP_DOWN, "Select task"),
+                Binding(&[Char('i')], "Open task inspector"),
+                Binding(&[Char('E')], "Edit pending task subject/model"),
+                Binding(&[Char(' ')], "Mark/unmark task for bulk actions"),
+                Binding(&[Char('M')], "Set model for all marked pending tasks"),
+            ],
+            KeyContext::MembersPane => &[
+                Binding(UP_DOWN, "Select member"),
+                Binding(&[Code(KeyCode::Enter)], "View agent logs"),
+                Binding(&[Char('a')], "Attach to agent's tmux pane"),
+                Binding(&[Char('s')], "Open send dialog"),
+                Binding(&[Char('x')], "Stop the phase session"),
+            ],
+            KeyContext::TaskInspector => &[Binding(&[Code(KeyCode::Esc)], "Close inspector")],
+            KeyContext::LogViewer => &[
+                Binding(UP_DOWN, "Scroll"),
+                Binding(PAGE, "Scroll page down/up"),
+                Binding(&[Char('f')], "Toggle follow mode"),
+                Binding(&[Char('G')], "Jump to bottom"),
+                Binding(&[Char('z')], "Maximize/restore log viewer"),
+                Binding(
+                    &[Char('/')],
+                    "Search (regex; case-insensitive unless uppercase)",
+                ),
+                Binding(&[Char('n'), Char('N')], "Next/previous match"),
+                Binding(
+                    &[Char('w')],
+                    "Save the pane's full history to the capture dir",
+                ),
+                Binding(&[Char('r')], "Refresh"),
+                Binding(&[Char('a')], "Attach to the pane (not yet available)"),
+                Binding(&[Code(KeyCode::Esc)], "Clear search, then close log viewer"),
+            ],
+            KeyContext::LogSearch => &[
+                Binding(&[Code(KeyCode::Enter)], "Search"),
+                Binding(&[Code(KeyCode::Backspace)], "Delete a character"),
+                Binding(&[Code(KeyCode::Esc)], "Cancel"),
+            ],
+            KeyContext::PlanViewer => &[
+                Binding(UP_DOWN, "Scroll"),
+                Binding(PAGE, "Scroll page down/up"),
+                Binding(&[Char('e')], "Open plan in editor"),
+                Binding(&[Code(KeyCode::Esc)], "Close plan"),
+            ],
+            KeyContext::SpecViewer => &[
+                Binding(UP_DOWN, "Scroll"),
+                Binding(PAGE, "Scroll page down/up"),
+                Binding(&[Char('['), Char(']')], "Older/newer revision"),
+                Binding(&[Code(KeyCode::Esc)], "Close spec"),
+            ],
+            KeyContext::Replay => &[
+                Binding(
+                    &[
+                        Char('h'),
+                        Char('l'),
+                        Code(KeyCode::Left),
+                        Code(KeyCode::Right),
+                    ],
+                    "Step one event back/forward",
+                ),
+                Binding(
+                    &[
+                        Char('H'),
+                        Char('L'),
+                        Code(KeyCode::PageUp),
+                        Code(KeyCode::PageDown),
+                    ],
+                    "Step ten events back/forward",
+                ),
+                Binding(
+                    &[Char('['), Char(']')],
+                    "Jump to previous/next phase start or end",
+                ),
+                Binding(
+                    &[
+                        Char('g'),
+                        Char('G'),
+                        Code(KeyCode::Home),
+                        Code(KeyCode::End),
+                    ],
+                    "Jump to first/last event",
+                ),
+                Binding(&[Code(KeyCode::Esc)], "Close replay"),
+            ],
+            KeyContext::Commits => &[
+                Binding(UP_DOWN, "Select commit"),
+                Binding(
+                    &[Code(KeyCode::Enter)],
+                    "Show commit details and changed files",
+                ),
+                Binding(&[Code(KeyCode::Esc)], "Close commits viewer"),
+            ],
+            KeyContext::CommitDetail => &[
+                Binding(UP_DOWN, "Select file or folder"),
+                Binding(
+                    &[Code(KeyCode::Enter)],
+                    "Open the diff at a file, or collapse/expand a folder",
+                ),
+                Binding(&[Code(KeyCode::Esc)], "Back to the commit list"),
+            ],
+            KeyContext::Diff => &[
+                Binding(UP_DOWN, "Select file, or scroll the full diff"),
+                Binding(&[Code(KeyCode::Enter)], "Toggle full diff view"),
+                Binding(&[Char('s')], "Toggle side-by-side layout"),
+                Binding(&[Code(KeyCode::Tab)], "Switch column (side-by-side)"),
+                Binding(
+                    &[
+                        Char('h'),
+                        Char('l'),
+                        Code(KeyCode::Left),
+                        Code(KeyCode::Right),
+                    ],
+                    "Scroll column horizontally (side-by-side)",
+                ),
+                Binding(&[Code(KeyCode::Esc)], "Close diff viewer"),
+            ],
+            KeyContext::PhaseTimeline => &[
+                Binding(UP_DOWN, "Select phase"),
+                Binding(&[Char('t')], "Show/hide tasks in the selected phase"),
+                Binding(&[Code(KeyCode::Esc)], "Close timeline"),
+            ],
+            KeyContext::Cost => &[
+                Binding(UP_DOWN, "Select phase"),
+                Binding(&[Code(KeyCode::Esc)], "Close cost panel"),
+            ],
+            KeyContext::WorktreeStatus => &[
+                Binding(UP_DOWN, "Scroll"),
+                Binding(&[Char('r')], "Refresh"),
+                Binding(&[Code(KeyCode::Esc)], "Close working tree status"),
+            ],
+            KeyContext::TaskGraph => &[
+                Binding(
+                    &[
+                        Char('h'),
+                        Char('j'),
+                        Char('k'),
+                        Char('l'),
+                        Code(KeyCode::Left),
+                        Code(KeyCode::Down),
+                        Code(KeyCode::Up),
+                        Code(KeyCode::Right),
+                    ],
+                    "Scroll",
+                ),
+                Binding(&[Code(KeyCode::Esc)], "Close task graph"),
+            ],
+            KeyContext::SendDialog => &[
+                Binding(
+                    &[Code(KeyCode::Enter)],
+                    "Send (asks first unless the command is safe)",
+                ),
+                Binding(&[Ctrl('j')], "New line (also Shift+Enter)"),
+                Binding(
+                    &[Code(KeyCode::Up), Code(KeyCode::Down)],
+                    "Move between lines, then through history",
+                ),
+                Binding(&[Ctrl('r')], "Search history"),
+                Binding(&[Char('1'), Char('2')], "Quick action (empty input)"),
+                Binding(&[Char('y'), Char('n')], "Confirm/cancel sending"),
+                Binding(&[Code(KeyCode::Esc)], "Close send dialog"),
+            ],
+            KeyContext::ConfirmStop => &[
+                Binding(&[Char('y'), Code(KeyCode::Enter)], "Stop the phase"),
+                Binding(&[Char('n'), Code(KeyCode::Esc)], "Cancel"),
+            ],
+            KeyContext::CommandModal => &[
+                Binding(&[Char('y')], "Copy command to clipboard"),
+                Binding(&[Code(KeyCode::Esc)], "Close"),
+            ],
+            KeyContext::BulkTaskModel => &[
+                Binding(
+                    &[
+                        Char('h'),
+                        Char('l'),
+                        Code(KeyCode::Left),
+                        Code(KeyCode::Right),
+                    ],
+                    "Choose model",
+                ),
+                Binding(&[Code(KeyCode::Enter)], "Apply, then close when done"),
+                Binding(&[Code(KeyCode::Esc)], "Cancel, or stop while running"),
+            ],
+            KeyContext::Copy => &[
+                Binding(&[Then('y', 'c')], "Copy commit SHA"),
+                Binding(&[Then('y', 't')], "Copy task id"),
+                Binding(&[Then('y', 'p')], "Copy worktree path"),
+                Binding(&[Then('y', 'f')], "Copy file path (diffs, commit files)"),
+            ],
+            KeyContext::Global => &[
+                Binding(&[Char('?')], "Toggle this help"),
+                Binding(&[Char('N')], "Notification history"),
+                Binding(&[Char('S')], "Stuck tasks across orchestrations"),
+                Binding(&[Char('q'), Ctrl('c')], "Quit"),
+            ],
+        }
+    }
+
+    /// Contexts whose keys work in `app`'s current view, most specific
+    /// first. Global keys aren't included.
+    pub fn active(app: &App) -> Vec<KeyContext> {
+        let mut contexts = match &app.view_state {
+            ViewState::OrchestrationList => vec![KeyContext::List],
+            ViewState::Dashboard => vec![KeyContext::Dashboard],
+            ViewState::Projects { form: None, .. } => vec![KeyContext::Projects],
+            ViewState::PhaseDetail { focus, .. } => {
+                let pane = match focus {
+                    PaneFocus::Orchestrations => Some(KeyContext::OrchestrationsPane),
+                    PaneFocus::Phases => Some(KeyContext::PhasesPane),
+                    PaneFocus::Tasks => Some(KeyContext::TasksPane),
+                    PaneFocus::Members => Some(KeyContext::MembersPane),
+                    PaneFocus::Detail => None,
+                };
+                pane.into_iter().chain([KeyContext::PhaseDetail]).collect()
+            }
+            ViewState::TaskInspector { .. } => vec![KeyContext::TaskInspector],
+            ViewState::LogViewer { .. } => {
+                let searching = app
+                    .log_viewer
+                    .as_ref()
+                    .is_some_and(|viewer| viewer.search_input.is_some());
+                if searching {
+                    vec![KeyContext::LogSearch]
+                } else {
+                    vec![KeyContext::LogViewer]
+                }
+            }
+            ViewState::PlanViewer { .. } => vec![KeyContext::PlanViewer],
+            ViewState::SpecViewer => vec![KeyContext::SpecViewer],
+            ViewState::Replay => vec![KeyContext::Replay],
+            ViewState::CommitsView {
+                detail: Some(_), ..
+            } => vec![KeyContext::CommitDetail],
+            ViewState::CommitsView { .. } => vec![KeyContext::Commits],
+            ViewState::DiffView { .. } => vec![KeyContext::Diff],
+            ViewState::PhaseTimeline { .. } => vec![KeyContext::PhaseTimeline],
+            ViewState::CostPanel { .. } => vec![KeyContext::Cost],
+            ViewState::WorktreeStatus { .. } => vec![KeyContext::WorktreeStatus],
+            ViewState::TaskGraph { .. } => vec![KeyContext::TaskGraph],
+            ViewState::SendDialog { .. } => vec![KeyContext::SendDialog],
+            ViewState::ConfirmStopPhase { .. } => vec![KeyContext::ConfirmStop],
+            ViewState::CommandModal { .. } => vec![KeyContext::CommandModal],
+            ViewState::BulkTaskModel { .. } => vec![KeyContext::BulkTaskModel],
+            // Text prompts take `?` as input, so help can't open over them
+            ViewState::TaskEdit { .. }
+            | ViewState::ConfirmRetryPhase { .. }
+            | ViewState::Projects { form: Some(_), .. } => Vec::new(),
+        };
+        if app.copy_prefix_allowed() {
+            contexts.push(KeyContext::Copy);
+        }
+        contexts
+    }
+
+    /// Whether `key` is listed for this context
+    pub fn binds(self, key: &KeyEvent) -> bool {
+        self.bindings()
+            .iter()
+            .any(|binding| binding.keys().iter().any(|k| k.matches(key)))
+    }
+}
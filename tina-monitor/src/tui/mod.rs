@@ -3,6 +3,9 @@
 //! Provides a terminal user interface for monitoring Tina orchestrations.
 
 mod app;
+pub mod keymap;
+pub mod notifications;
+pub mod session;
 pub mod ui;
 pub mod views;
 pub mod widgets;
@@ -12,7 +15,7 @@ pub use app::{App, AppResult, PaneFocus, PhaseDetailLayout, ViewState};
 use std::io;
 
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -23,23 +26,48 @@ use ratatui::{backend::CrosstermBackend, Terminal};
 /// Sets up the terminal, runs the application event loop,
 /// and restores the terminal on exit.
 pub fn run() -> AppResult<()> {
+    run_with(false)
+}
+
+/// Run the TUI starting in the multi-orchestration dashboard (wall display).
+pub fn run_dashboard() -> AppResult<()> {
+    run_with(true)
+}
+
+fn run_with(dashboard: bool) -> AppResult<()> {
+    // Resolve the theme before touching the terminal so config errors print
+    let config = crate::config::Config::load()?;
+    crate::theme::init(crate::theme::Theme::from_config(&config.theme)?);
+    tina_data::humanize::init_display_timezone(config.display.timezone);
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create and run app
     let mut app = App::new()?;
+    if dashboard {
+        app.open_dashboard();
+    }
     let result = app.run(&mut terminal);
+    // Losing the saved view isn't worth failing the exit over
+    let _ = app.session_state().save();
 
     // Restore terminal
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
 
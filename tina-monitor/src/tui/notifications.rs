@@ -0,0 +1,180 @@
+//! Toasts for orchestration status changes
+//!
+//! Changes detected between refreshes (see [`crate::data::changes`]) become
+//! transient toasts in the corner of the TUI. Every toast is also kept in a
+//! history that the `N` overlay lists, and optionally forwarded as a desktop
+//! notification.
+
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+
+use crate::data::changes::{ChangeKind, ChangeTracker, Snapshots};
+use crate::desktop_notify::DesktopNotifier;
+
+/// How long a toast stays on screen
+pub const TOAST_DURATION: Duration = Duration::from_secs(5);
+
+/// Most toasts stacked in the corner at once
+pub const MAX_VISIBLE_TOASTS: usize = 3;
+
+/// Oldest entries are dropped from the history past this many
+const HISTORY_LIMIT: usize = 200;
+
+/// A single notification
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub kind: ChangeKind,
+    pub message: String,
+    /// Monotonic time the toast was raised, for expiry
+    pub raised_at: Instant,
+    /// Wall-clock time the toast was raised, for the history overlay
+    pub time: DateTime<Utc>,
+}
+
+/// Active toasts and their history
+#[derive(Debug, Default)]
+pub struct Notifications {
+    tracker: ChangeTracker,
+    /// Every toast raised, oldest first
+    history: Vec<Toast>,
+    /// Forwards changes to the OS notification center when configured
+    desktop: Option<DesktopNotifier>,
+    /// Whether the history overlay is open
+    pub show_history: bool,
+    /// Toasts raised so far, including any dropped from the history; the
+    /// monitor's own connection notices aren't counted
+    raised: usize,
+    /// `raised` when the history overlay was last open
+    seen: usize,
+}
+
+impl Notifications {
+    /// Notifications that are also sent to the desktop
+    pub fn with_desktop(desktop: DesktopNotifier) -> Self {
+        Self {
+            desktop: Some(desktop),
+            ..Self::default()
+        }
+    }
+
+    /// Record a fresh snapshot, raising toasts for alert-worthy changes
+    /// since the previous one. The first snapshot only sets the baseline.
+    pub fn observe(&mut self, snapshots: Snapshots) {
+        for change in self.tracker.observe(snapshots) {
+            if !change.kind.is_alert() {
+                continue;
+            }
+            if let Some(desktop) = &self.desktop {
+                desktop.notify(change.kind, &change.message);
+            }
+            self.push(change.kind, change.message);
+        }
+    }
+
+    /// Raise a toast
+    pub fn push(&mut self, kind: ChangeKind, message: String) {
+        self.history.push(Toast {
+            kind,
+            message,
+            raised_at: Instant::now(),
+            time: Utc::now(),
+        });
+        if kind != ChangeKind::Connection {
+            self.raised += 1;
+        }
+        if self.history.len() > HISTORY_LIMIT {
+            self.history.remove(0);
+        }
+    }
+
+    /// Toasts still on screen at `now`, newest first
+    pub fn visible(&self, now: Instant) -> Vec<&Toast> {
+        self.history
+            .iter()
+            .rev()
+            .take_while(|toast| now.duration_since(toast.raised_at) < TOAST_DURATION)
+            .take(MAX_VISIBLE_TOASTS)
+            .collect()
+    }
+
+    /// Every toast raised so far, oldest first
+    pub fn history(&self) -> &[Toast] {
+        &self.history
+    }
+
+    /// Toasts raised since the history overlay was last open
+    pub fn unread(&self) -> usize {
+        self.raised - self.seen
+    }
+
+    /// Open or close the history overlay; either way, everything in it
+    /// counts as read
+    pub fn toggle_history(&mut self) {
+        self.show_history = !self.show_history;
+        self.seen = self.raised;
+    }
+
+    pub fn close_history(&mut self) {
+        self.show_history = false;
+        self.seen = self.raised;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::changes::OrchestrationSnapshot;
+    use crate::data::MonitorOrchestrationStatus;
+    use std::collections::BTreeMap;
+
+    fn snapshots(status: MonitorOrchestrationStatus, current_phase: u32) -> Snapshots {
+        let snapshot = OrchestrationSnapshot {
+            feature: "auth".to_string(),
+            status,
+            current_phase,
+            pending_gates: Default::default(),
+            completed_tasks: None,
+            commits: None,
+        };
+        BTreeMap::from([("orch-1".to_string(), snapshot)])
+    }
+
+    #[test]
+    fn observed_changes_become_toasts() {
+        let mut notifications = Notifications::default();
+        notifications.observe(snapshots(MonitorOrchestrationStatus::Executing, 1));
+        assert!(notifications.history().is_empty());
+
+        notifications.observe(snapshots(MonitorOrchestrationStatus::Blocked, 1));
+        assert_eq!(notifications.history().len(), 1);
+        assert_eq!(notifications.history()[0].kind, ChangeKind::Blocked);
+    }
+
+    #[test]
+    fn visible_toasts_expire_and_are_capped() {
+        let mut notifications = Notifications::default();
+        for i in 0..5 {
+            notifications.push(ChangeKind::PhaseComplete, format!("toast {}", i));
+        }
+
+        let now = Instant::now();
+        let visible = notifications.visible(now);
+        assert_eq!(visible.len(), MAX_VISIBLE_TOASTS);
+        assert_eq!(visible[0].message, "toast 4");
+
+        assert!(notifications.visible(now + TOAST_DURATION).is_empty());
+        assert_eq!(notifications.history().len(), 5);
+    }
+
+    #[test]
+    fn connection_notices_are_not_unread_alerts() {
+        let mut notifications = Notifications::default();
+        notifications.push(ChangeKind::Connection, "disconnected".to_string());
+        assert_eq!(notifications.history().len(), 1);
+        assert_eq!(notifications.unread(), 0);
+
+        notifications.push(ChangeKind::Blocked, "auth blocked".to_string());
+        assert_eq!(notifications.unread(), 1);
+    }
+}
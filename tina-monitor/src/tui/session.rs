@@ -0,0 +1,101 @@
+//! View state saved between runs
+//!
+//! On exit the TUI records which orchestration was selected and where in it
+//! the user was; the next launch restores that once the list has loaded.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::app::{PaneFocus, PhaseDetailLayout};
+
+/// Which top-level view was open
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(tag = "view", rename_all = "snake_case")]
+pub enum SavedView {
+    #[default]
+    List,
+    Dashboard,
+    PhaseDetail {
+        phase: u32,
+        layout: PhaseDetailLayout,
+        focus: PaneFocus,
+    },
+}
+
+/// The last view and selection, as written to the state file
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SessionState {
+    /// Convex id of the selected orchestration
+    pub orchestration_id: Option<String>,
+    #[serde(flatten)]
+    pub view: SavedView,
+}
+
+impl SessionState {
+    /// `~/.local/share/tina-monitor/state.json`
+    pub fn path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".local/share/tina-monitor/state.json"))
+    }
+
+    /// The saved state, or `None` if there is none or it can't be read
+    pub fn load() -> Option<Self> {
+        Self::load_from(&Self::path()?)
+    }
+
+    /// Write the state file, creating its directory if needed
+    pub fn save(&self) -> anyhow::Result<()> {
+        let path = Self::path().ok_or_else(|| anyhow::anyhow!("no home directory"))?;
+        self.save_to(&path)
+    }
+
+    fn load_from(path: &Path) -> Option<Self> {
+        let contents = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    fn save_to(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trips_through_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("state.json");
+        let state = SessionState {
+            orchestration_id: Some("orch-1".to_string()),
+            view: SavedView::PhaseDetail {
+                phase: 2,
+                layout: PhaseDetailLayout::TasksDetail,
+                focus: PaneFocus::Members,
+            },
+        };
+
+        state.save_to(&path).unwrap();
+        assert_eq!(SessionState::load_from(&path), Some(state));
+
+        let json = fs::read_to_string(&path).unwrap();
+        assert!(json.contains("\"view\": \"phase_detail\""));
+        assert!(json.contains("\"layout\": \"tasks_detail\""));
+    }
+
+    #[test]
+    fn test_missing_or_corrupt_file_loads_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        assert_eq!(SessionState::load_from(&path), None);
+
+        fs::write(&path, "not json").unwrap();
+        assert_eq!(SessionState::load_from(&path), None);
+    }
+}
@@ -2,17 +2,22 @@
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
 use super::app::{App, ViewState};
+use super::views::diff_view::SideBySide;
 use super::views::log_viewer;
+use super::views::notifications;
 use super::views::orchestration_list::render_orchestration_list;
 use super::views::phase_detail;
+use super::views::stuck_tasks;
 use super::views::task_inspector::render_task_inspector;
 use crate::overlay::centered_rect;
+use crate::theme;
 
 /// Render the application UI
 pub fn render(frame: &mut Frame, app: &mut App) {
@@ -22,16 +27,23 @@ pub fn render(frame: &mut Frame, app: &mut App) {
             Constraint::Length(3), // Header
             Constraint::Min(0),    // Main content
             Constraint::Length(1), // Footer
+            Constraint::Length(1), // Status bar
         ])
         .split(frame.area());
 
-    render_header(frame, chunks[0]);
+    render_header(frame, chunks[0], app);
 
     // Render the appropriate view based on current state
     match &app.view_state {
         ViewState::OrchestrationList => {
             render_orchestration_list(frame, chunks[1], app);
         }
+        ViewState::Dashboard => {
+            super::views::dashboard::render(frame, chunks[1], app);
+        }
+        ViewState::Projects { selected, form } => {
+            super::views::projects::render(frame, chunks[1], app, *selected, form.as_ref());
+        }
         ViewState::PhaseDetail { .. } => {
             phase_detail::render(frame, chunks[1], app);
         }
@@ -43,7 +55,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
                 let orchestration = &app.orchestrations[app.selected_index];
                 if *task_index < orchestration.tasks.len() {
                     let task = &orchestration.tasks[*task_index];
-                    render_task_inspector(frame, task);
+                    render_task_inspector(frame, task, app.task_timeline.as_deref());
                 }
             }
         }
@@ -52,7 +64,12 @@ pub fn render(frame: &mut Frame, app: &mut App) {
             phase_detail::render(frame, chunks[1], app);
             // Then render the log viewer modal on top
             if let Some(viewer) = &mut app.log_viewer {
-                let area = centered_rect(85, 85, frame.area());
+                let area = if app.zoomed {
+                    frame.area()
+                } else {
+                    centered_rect(85, 85, frame.area())
+                };
+                frame.render_widget(ratatui::widgets::Clear, area);
                 viewer.render(frame, area);
             } else {
                 // Fallback to placeholder if viewer is not initialized
@@ -88,10 +105,37 @@ pub fn render(frame: &mut Frame, app: &mut App) {
                 viewer.render(frame, area);
             }
         }
+        ViewState::ConfirmStopPhase { .. } => {
+            phase_detail::render(frame, chunks[1], app);
+            super::views::confirm_stop_phase::render(app, frame);
+        }
+        ViewState::ConfirmRetryPhase { .. } => {
+            phase_detail::render(frame, chunks[1], app);
+            super::views::confirm_retry_phase::render(app, frame);
+        }
+        ViewState::SpecViewer => {
+            // Keep the Phases pane visible beside the spec
+            phase_detail::render(frame, chunks[1], app);
+            if let Some(viewer) = &app.spec_viewer {
+                let panes = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+                    .split(chunks[1]);
+                frame.render_widget(ratatui::widgets::Clear, panes[1]);
+                viewer.render(frame, panes[1]);
+            }
+        }
+        ViewState::Replay => {
+            if let Some(viewer) = &app.replay {
+                viewer.render(frame, chunks[1]);
+            }
+        }
         ViewState::CommitsView {
             worktree_path,
             range,
             title,
+            selected,
+            detail,
         } => {
             // First render the PhaseDetail view as background
             phase_detail::render(frame, chunks[1], app);
@@ -101,9 +145,99 @@ pub fn render(frame: &mut Frame, app: &mut App) {
                 range.clone(),
                 title.clone(),
             ) {
+                commits_view.select(*selected);
                 let area = centered_rect(85, 85, frame.area());
                 frame.render_widget(ratatui::widgets::Clear, area);
-                commits_view.render(frame, area);
+                match detail.as_ref().and_then(|state| {
+                    super::views::commit_detail::CommitDetailView::new(worktree_path, state).ok()
+                }) {
+                    Some(mut detail_view) => {
+                        let panes = Layout::default()
+                            .direction(Direction::Horizontal)
+                            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+                            .split(area);
+                        commits_view.render(frame, panes[0]);
+                        detail_view.render(frame, panes[1]);
+                    }
+                    None => commits_view.render(frame, area),
+                }
+            }
+        }
+        ViewState::PhaseTimeline {
+            selected_phase,
+            show_tasks,
+        } => {
+            // First render the PhaseDetail view as background
+            phase_detail::render(frame, chunks[1], app);
+            // Then render the timeline modal on top
+            if let Some(orch) = app.orchestrations.get(app.selected_index) {
+                let tasks = show_tasks.then_some(app.phase_timeline_tasks.as_slice());
+                let view = super::views::phase_timeline::PhaseTimelineView::new(
+                    orch,
+                    *selected_phase,
+                    tasks,
+                    chrono::Utc::now(),
+                );
+                let area = centered_rect(85, 85, frame.area());
+                frame.render_widget(ratatui::widgets::Clear, area);
+                view.render(frame, area);
+            }
+        }
+        ViewState::CostPanel {
+            selected_phase,
+            budget,
+        } => {
+            phase_detail::render(frame, chunks[1], app);
+            if let Some(orch) = app.orchestrations.get(app.selected_index) {
+                let panel = super::views::cost_panel::CostPanel::new(orch, budget, *selected_phase);
+                let area = centered_rect(85, 85, frame.area());
+                frame.render_widget(ratatui::widgets::Clear, area);
+                panel.render(frame, area);
+            }
+        }
+        ViewState::TaskEdit { editor, .. } => {
+            phase_detail::render(frame, chunks[1], app);
+            editor.render(frame, centered_rect(60, 40, frame.area()));
+        }
+        ViewState::BulkTaskModel { editor, .. } => {
+            phase_detail::render(frame, chunks[1], app);
+            editor.render(frame, centered_rect(60, 60, frame.area()));
+        }
+        ViewState::TaskGraph {
+            selected_phase,
+            scroll_y,
+            scroll_x,
+        } => {
+            phase_detail::render(frame, chunks[1], app);
+            if let Some(orch) = app.orchestrations.get(app.selected_index) {
+                let (tasks, _) = phase_detail::phase_tasks_and_members(app, orch, *selected_phase);
+                let view = super::views::task_graph::TaskGraphView::new(
+                    tasks,
+                    *selected_phase,
+                    (*scroll_y, *scroll_x),
+                );
+                let area = centered_rect(85, 85, frame.area());
+                frame.render_widget(ratatui::widgets::Clear, area);
+                view.render(frame, area);
+            }
+        }
+        ViewState::WorktreeStatus {
+            worktree_path,
+            status,
+            scroll,
+            ..
+        } => {
+            phase_detail::render(frame, chunks[1], app);
+            if let Some(orch) = app.orchestrations.get(app.selected_index) {
+                let view = super::views::worktree_status::WorktreeStatusView::new(
+                    &orch.feature_name,
+                    worktree_path,
+                    status,
+                    *scroll,
+                );
+                let area = centered_rect(85, 85, frame.area());
+                frame.render_widget(ratatui::widgets::Clear, area);
+                view.render(frame, area);
             }
         }
         ViewState::DiffView {
@@ -113,6 +247,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
             selected,
             show_full,
             scroll,
+            side_by_side,
         } => {
             // First render the PhaseDetail view as background
             phase_detail::render(frame, chunks[1], app);
@@ -124,6 +259,10 @@ pub fn render(frame: &mut Frame, app: &mut App) {
                 diff_view.selected = *selected;
                 diff_view.show_full = *show_full;
                 diff_view.scroll = *scroll;
+                diff_view.side_by_side = *side_by_side;
+                if diff_view.show_full {
+                    let _ = diff_view.load_full_diff();
+                }
                 // Update list_state to match selected
                 if !diff_view.stats.files.is_empty() {
                     diff_view.list_state.select(Some(*selected));
@@ -137,17 +276,50 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     }
 
     render_footer(frame, chunks[2], app);
+    super::views::status_bar::render(frame, chunks[3], app);
+
+    let toasts = app.notifications.visible(std::time::Instant::now());
+    notifications::render_toasts(frame, chunks[1], &toasts);
+
+    if app.notifications.show_history {
+        notifications::render_history(frame, app.notifications.history());
+    }
+
+    if app.stuck_tasks.show {
+        stuck_tasks::render(frame, &app.orchestrations, app.stuck_tasks.threshold_mins);
+    }
 
     if app.show_help {
-        super::views::help::render_help(frame);
+        super::views::help::render_help(frame, app);
+    }
+
+    if let Some(search) = &app.global_search {
+        crate::overlay::fuzzy::render_global(search, frame);
+    }
+
+    if let Some(palette) = &app.palette {
+        crate::overlay::palette::render(palette, frame);
     }
 }
 
-fn render_header(frame: &mut Frame, area: Rect) {
-    let header = Paragraph::new("Orchestrations")
+fn render_header(frame: &mut Frame, area: Rect, app: &App) {
+    let mut spans = vec![Span::raw(app.list_title())];
+    if app.is_refreshing() {
+        spans.push(Span::styled(
+            "  refreshing…",
+            Style::default().fg(theme::current().muted),
+        ));
+    }
+    if let Some(warning) = app.clock_warning() {
+        spans.push(Span::styled(
+            format!("  ⚠ {}", warning),
+            Style::default().fg(theme::current().warning),
+        ));
+    }
+    let header = Paragraph::new(Line::from(spans))
         .style(
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme::current().accent)
                 .add_modifier(Modifier::BOLD),
         )
         .block(Block::default().borders(Borders::BOTTOM));
@@ -156,18 +328,38 @@ fn render_header(frame: &mut Frame, area: Rect) {
 
 fn render_footer(frame: &mut Frame, area: Rect, app: &App) {
     let footer_text = match &app.view_state {
-        ViewState::OrchestrationList => " j/k:nav  Enter:expand  g:goto  p:plan  r:refresh  q:quit  ?:help",
-        ViewState::PhaseDetail { .. } => " h/l:panes  Tab:tasks/team  j/k:nav  p:plan  D:design  c:commits  d:diff  Enter:logs  s:send  Esc:back  ?:help",
+        ViewState::OrchestrationList => " j/k:nav  Enter:expand  g:goto  p:plan  e:edit  r:refresh  q:quit  ?:help",
+        ViewState::Dashboard => " h/j/k/l:nav  Enter:expand  r:refresh  w/Esc:list  N:alerts  q:quit  ?:help",
+        ViewState::Projects { form: Some(_), .. } => " Tab:field  Enter:create  Esc:cancel",
+        ViewState::Projects { .. } => " j/k:nav  Enter:show in list  n:new project  r:refresh  Esc:list  ?:help",
+        ViewState::PhaseDetail { .. } => " h/l:panes  Tab:tasks/team  j/k:nav  p:plan  D:design  c:commits  d:diff  Enter:logs  s:send  z:zoom  Esc:back  ?:help",
         ViewState::TaskInspector { .. } => " Esc:back  ?:help",
-        ViewState::LogViewer { .. } => " j/k:scroll  Esc:back  ?:help",
-        ViewState::SendDialog { .. } => " Enter:send  Esc:cancel  ?:help",
+        ViewState::LogViewer { .. } => " j/k:scroll  z:zoom  Esc:back  ?:help",
+        ViewState::SendDialog { .. } => " Enter:send  Alt+Enter:newline  ↑/↓:history  ^R:search  Esc:cancel  ?:help",
         ViewState::CommandModal { .. } => " y:copy  Esc:close  ?:help",
-        ViewState::PlanViewer { .. } => " j/k:scroll  Esc:close  ?:help",
-        ViewState::CommitsView { .. } => " j/k:nav  Esc:close  ?:help",
-        ViewState::DiffView { .. } => " j/k:nav  Enter:toggle  Esc:close  ?:help",
+        ViewState::PlanViewer { .. } => " j/k:scroll  e:edit  Esc:close  ?:help",
+        ViewState::ConfirmStopPhase { .. } => " y:stop  n/Esc:cancel  ?:help",
+        ViewState::ConfirmRetryPhase { .. } => " type a reason  Enter:retry  Esc:cancel",
+        ViewState::SpecViewer => " j/k:scroll  [/]:revision  Esc:close  ?:help",
+        ViewState::Replay => " h/l:step  H/L:±10  [/]:phase  g/G:start/end  Esc:close  ?:help",
+        ViewState::CommitsView {
+            detail: Some(_), ..
+        } => " j/k:nav  Enter:diff/fold  Esc:commits  ?:help",
+        ViewState::CommitsView { .. } => " j/k:nav  Enter:details  Esc:close  ?:help",
+        ViewState::PhaseTimeline { .. } => " j/k:phase  t:tasks  Esc:close  ?:help",
+        ViewState::CostPanel { .. } => " j/k:phase  Esc:close  ?:help",
+        ViewState::WorktreeStatus { .. } => " j/k:scroll  r:refresh  Esc:close  ?:help",
+        ViewState::TaskGraph { .. } => " h/j/k/l:scroll  Esc:close  ?:help",
+        ViewState::TaskEdit { .. } => " Tab:field  ←/→:model  Enter:save  Esc:cancel",
+        ViewState::BulkTaskModel { .. } => " ←/→:model  Enter:apply  Esc:cancel/stop  ?:help",
+        ViewState::DiffView {
+            side_by_side: SideBySide { enabled: true, .. },
+            ..
+        } => " j/k:scroll  h/l:pan  Tab:column  s:unified  Esc:close  ?:help",
+        ViewState::DiffView { .. } => " j/k:nav  Enter:toggle  s:split  Esc:close  ?:help",
     };
 
-    let footer = Paragraph::new(footer_text).style(Style::default().fg(Color::DarkGray));
+    let footer = Paragraph::new(footer_text).style(Style::default().fg(theme::current().muted));
     frame.render_widget(footer, area);
 }
 
@@ -176,8 +368,8 @@ mod tests {
     use super::*;
     use crate::data::MonitorOrchestration;
     use ratatui::{backend::TestBackend, Terminal};
-    use std::time::{Duration, Instant};
-    use tina_data::OrchestrationListEntry;
+    use std::time::Duration;
+    use tina_data::test_util::list_entry;
 
     fn make_test_app() -> App {
         use crate::tui::app::ViewState;
@@ -187,43 +379,45 @@ mod tests {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: crate::tui::notifications::Notifications::default(),
+            stuck_tasks: crate::tui::views::stuck_tasks::StuckTasks::default(),
+            list_paging: Default::default(),
+            pending_gates: Default::default(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: crate::config::ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: std::collections::BTreeSet::new(),
+            task_marks: std::collections::BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         }
     }
 
     fn make_test_app_with_orchestrations() -> App {
         use crate::tui::app::ViewState;
-        let entry = OrchestrationListEntry {
-            id: "orch-1".to_string(),
-            node_name: "macbook".to_string(),
-            record: tina_data::OrchestrationRecord {
-                node_id: "node-1".to_string(),
-                project_id: None,
-                feature_name: "test-project".to_string(),
-                spec_doc_path: "design.md".to_string(),
-                branch: "tina/test-project".to_string(),
-                worktree_path: Some("/test".to_string()),
-                total_phases: 3.0,
-                current_phase: 1.0,
-                status: "idle".to_string(),
-                started_at: "2026-02-07T10:00:00Z".to_string(),
-                completed_at: None,
-                total_elapsed_mins: None,
-                spec_id: None,
-                policy_snapshot: None,
-                policy_snapshot_hash: None,
-                preset_origin: None,
-                spec_only: None,
-                policy_revision: None,
-                updated_at: None,
-            },
-        };
+        let mut entry = list_entry("orch-1", "idle", "2026-02-07T10:00:00Z");
+        entry.record.feature_name = "test-project".to_string();
+        entry.record.branch = "tina/test-project".to_string();
+        entry.record.worktree_path = Some("/test".to_string());
         let orchestration = MonitorOrchestration::from_list_entry(entry);
 
         App {
@@ -232,13 +426,36 @@ mod tests {
             selected_index: 0,
             tick_rate: Duration::from_millis(100),
             show_help: false,
-            watcher: None,
-            last_refresh: Instant::now(),
             view_state: ViewState::OrchestrationList,
             log_viewer: None,
             send_dialog: None,
             command_logger: None,
             phase_cache: None,
+            zoomed: false,
+            pending_editor: None,
+            global_search: None,
+            dashboard_refreshed_at: None,
+            task_timeline: None,
+            phase_timeline_tasks: Vec::new(),
+            notifications: crate::tui::notifications::Notifications::default(),
+            stuck_tasks: crate::tui::views::stuck_tasks::StuckTasks::default(),
+            list_paging: Default::default(),
+            pending_gates: Default::default(),
+            palette: None,
+            spec_viewer: None,
+            replay: None,
+            list: crate::config::ListConfig::default(),
+            persist_settings: false,
+            collapsed_projects: std::collections::BTreeSet::new(),
+            task_marks: std::collections::BTreeSet::new(),
+            pending_copy: false,
+            panes: None,
+            panes_read_at: None,
+            pending_load: None,
+            restore: None,
+            live: None,
+            refreshed_at: None,
+            load_error: None,
         }
     }
 
@@ -268,6 +485,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_render_dashboard_shows_orchestration_cards() {
+        let backend = TestBackend::new(120, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_test_app_with_orchestrations();
+        app.view_state = ViewState::Dashboard;
+
+        terminal.draw(|frame| render(frame, &mut app)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer.content().iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("test-project"));
+        assert!(text.contains("1/3"));
+    }
+
     #[test]
     fn test_layout_constraints_are_reasonable() {
         // Test that with a reasonable terminal size, the layout doesn't panic
@@ -337,6 +569,104 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_orchestration_list_shows_configured_columns() {
+        use crate::config::ListColumn;
+
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_test_app_with_orchestrations();
+        app.list.columns = vec![ListColumn::Status, ListColumn::Name];
+
+        terminal.draw(|frame| render(frame, &mut app)).unwrap();
+        let content = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect::<String>();
+
+        assert!(content.contains("idle      test-project"));
+        assert!(!content.contains("/test"), "path column is hidden");
+    }
+
+    #[test]
+    fn test_orchestration_list_shows_project_sections() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_test_app_with_orchestrations();
+        app.orchestrations[0].project_id = Some("p1".to_string());
+        app.list_paging
+            .project_names
+            .insert("p1".to_string(), "acme".to_string());
+
+        // The status bar names the selection too, so it's left out
+        let content = |terminal: &mut Terminal<TestBackend>, app: &mut App| {
+            terminal.draw(|frame| render(frame, app)).unwrap();
+            terminal.backend().buffer().content()[..80 * 23]
+                .iter()
+                .map(|c| c.symbol())
+                .collect::<String>()
+        };
+
+        let expanded = content(&mut terminal, &mut app);
+        assert!(expanded.contains("▾ acme (1)  1 idle"));
+        assert!(expanded.contains("test-project"));
+
+        app.collapsed_projects.insert(Some("p1".to_string()));
+        let collapsed = content(&mut terminal, &mut app);
+        assert!(collapsed.contains("▸ acme (1)"));
+        assert!(!collapsed.contains("test-project"));
+    }
+
+    #[test]
+    fn test_render_stop_phase_confirmation() {
+        let backend = TestBackend::new(100, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_test_app_with_orchestrations();
+        app.view_state = crate::tui::app::ViewState::ConfirmStopPhase {
+            phase: 1,
+            member_index: 0,
+            layout: crate::tui::app::PhaseDetailLayout::OrchPhaseTasks,
+        };
+
+        terminal.draw(|frame| render(frame, &mut app)).unwrap();
+        let content = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect::<String>();
+
+        assert!(content.contains("Stop phase 1 of test-project?"));
+        assert!(content.contains("y:stop"));
+    }
+
+    #[test]
+    fn test_header_shows_refreshing_while_loading() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_test_app_with_orchestrations();
+        let (_tx, rx) = std::sync::mpsc::channel();
+        app.pending_load = Some(crate::tui::app::PendingLoad {
+            rx,
+            selected_id: None,
+        });
+
+        terminal.draw(|frame| render(frame, &mut app)).unwrap();
+        let content = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect::<String>();
+
+        assert!(content.contains("refreshing…"));
+    }
+
     #[test]
     fn test_render_phase_detail_view() {
         let backend = TestBackend::new(80, 24);
@@ -0,0 +1,306 @@
+//! Bulk model override for the tasks marked in the Tasks pane
+//!
+//! The popup first asks for a model, then queues one `task_set_model`
+//! control-plane action per marked pending task, one per tick so progress
+//! shows as it goes. Each action carries the task's revision, so a task
+//! edited in the meantime fails on its own without stopping the rest.
+
+use std::collections::VecDeque;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use tina_data::ExecutionTaskRecord;
+
+use super::task_edit::MODELS;
+use crate::theme;
+
+/// Where the bulk operation is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkStage {
+    /// Picking the model
+    Choosing,
+    /// Queuing one task per tick
+    Running,
+    /// Every task has been tried
+    Done,
+}
+
+/// What a key press asks the app to do
+#[derive(Debug, PartialEq, Eq)]
+pub enum BulkResult {
+    Consumed,
+    /// Close the popup
+    Close,
+    /// Resolve the marked tasks and start queuing
+    Start,
+}
+
+/// How one marked task fared
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulkOutcome {
+    /// Task id as shown in the Tasks pane
+    pub task: String,
+    pub subject: String,
+    /// `Ok(None)` when updated, `Ok(Some(why))` when skipped
+    pub result: Result<Option<String>, String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BulkModelEditor {
+    /// Ids of the marked tasks
+    pub task_ids: Vec<String>,
+    /// Index into [`MODELS`]
+    pub model: usize,
+    pub stage: BulkStage,
+    /// Tasks still to update
+    pub queue: VecDeque<ExecutionTaskRecord>,
+    /// Tasks to try in total, skipped ones included
+    pub total: usize,
+    pub outcomes: Vec<BulkOutcome>,
+}
+
+impl BulkModelEditor {
+    pub fn new(task_ids: Vec<String>) -> Self {
+        Self {
+            task_ids,
+            model: 0,
+            stage: BulkStage::Choosing,
+            queue: VecDeque::new(),
+            total: 0,
+            outcomes: Vec::new(),
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> BulkResult {
+        match (self.stage, key.code) {
+            (BulkStage::Choosing, KeyCode::Right | KeyCode::Char('l')) => {
+                self.model = (self.model + 1) % MODELS.len();
+            }
+            (BulkStage::Choosing, KeyCode::Left | KeyCode::Char('h')) => {
+                self.model = (self.model + MODELS.len() - 1) % MODELS.len();
+            }
+            (BulkStage::Choosing, KeyCode::Enter) => return BulkResult::Start,
+            (BulkStage::Choosing | BulkStage::Done, KeyCode::Esc)
+            | (BulkStage::Done, KeyCode::Enter) => return BulkResult::Close,
+            // Stop early; what was already queued stays queued
+            (BulkStage::Running, KeyCode::Esc) => {
+                for task in std::mem::take(&mut self.queue) {
+                    self.outcomes.push(BulkOutcome {
+                        task: task.task_number.to_string(),
+                        subject: task.subject,
+                        result: Ok(Some("cancelled".to_string())),
+                    });
+                }
+                self.stage = BulkStage::Done;
+            }
+            _ => {}
+        }
+        BulkResult::Consumed
+    }
+
+    /// Begin queuing `tasks`, with `skipped` already settled
+    pub fn start(&mut self, tasks: Vec<ExecutionTaskRecord>, skipped: Vec<BulkOutcome>) {
+        self.total = tasks.len() + skipped.len();
+        self.queue = tasks.into();
+        self.outcomes = skipped;
+        self.stage = if self.queue.is_empty() {
+            BulkStage::Done
+        } else {
+            BulkStage::Running
+        };
+    }
+
+    /// `task_set_model` payload for the next queued task, which is taken off
+    /// the queue
+    pub fn next_payload(&mut self, feature: &str) -> Option<(ExecutionTaskRecord, String)> {
+        let task = self.queue.pop_front()?;
+        let payload = serde_json::json!({
+            "feature": feature,
+            "phaseNumber": task.phase_number,
+            "taskNumber": task.task_number,
+            "revision": task.revision,
+            "model": MODELS[self.model],
+        });
+        Some((task, payload.to_string()))
+    }
+
+    /// Record how the task last taken off the queue fared
+    pub fn record(&mut self, task: ExecutionTaskRecord, result: Result<(), String>) {
+        self.outcomes.push(BulkOutcome {
+            task: task.task_number.to_string(),
+            subject: task.subject,
+            result: result.map(|_| None),
+        });
+        if self.queue.is_empty() {
+            self.stage = BulkStage::Done;
+        }
+    }
+
+    /// Toast for the finished operation, e.g. "3 tasks set to haiku, 1 failed"
+    pub fn summary(&self) -> String {
+        let count = |f: fn(&BulkOutcome) -> bool| self.outcomes.iter().filter(|o| f(o)).count();
+        let updated = count(|o| matches!(o.result, Ok(None)));
+        let failed = count(|o| o.result.is_err());
+        let skipped = count(|o| matches!(o.result, Ok(Some(_))));
+        let mut summary = format!(
+            "{} task{} set to {}",
+            updated,
+            if updated == 1 { "" } else { "s" },
+            MODELS[self.model]
+        );
+        if failed > 0 {
+            summary.push_str(&format!(", {} failed", failed));
+        }
+        if skipped > 0 {
+            summary.push_str(&format!(", {} skipped", skipped));
+        }
+        summary
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let theme = theme::current();
+        let muted = Style::default().fg(theme.muted);
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!("{} marked task(s)", self.task_ids.len()),
+                muted,
+            )),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(
+                    "Model  ",
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("◀ {} ▶", MODELS[self.model]),
+                    Style::default().fg(theme.text),
+                ),
+            ]),
+            Line::from(""),
+        ];
+
+        if self.stage != BulkStage::Choosing {
+            let done = self.outcomes.len();
+            let width = 20;
+            let filled = (done * width).checked_div(self.total).unwrap_or(width);
+            lines.push(Line::from(vec![
+                Span::styled("█".repeat(filled), Style::default().fg(theme.progress)),
+                Span::styled("░".repeat(width - filled), muted),
+                Span::raw(format!(" {}/{}", done, self.total)),
+            ]));
+            for outcome in &self.outcomes {
+                let (glyph, color, note) = match &outcome.result {
+                    Ok(None) => ("✓", theme.success, String::new()),
+                    Ok(Some(why)) => ("–", theme.muted, format!(" ({})", why)),
+                    Err(e) => ("✗", theme.error, format!(": {}", e)),
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{} ", glyph), Style::default().fg(color)),
+                    Span::raw(format!("#{} {}", outcome.task, outcome.subject)),
+                    Span::styled(note, Style::default().fg(color)),
+                ]));
+            }
+            lines.push(Line::from(""));
+        }
+
+        let hint = match self.stage {
+            BulkStage::Choosing => "←/→: model  Enter: apply  Esc: cancel",
+            BulkStage::Running => "Esc: stop",
+            BulkStage::Done => "Enter/Esc: close",
+        };
+        lines.push(Line::from(Span::styled(hint, muted)));
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Set model for marked tasks ")
+            .border_style(Style::default().fg(theme.border));
+        frame.render_widget(Clear, area);
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn task(number: u32) -> ExecutionTaskRecord {
+        ExecutionTaskRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: "1".to_string(),
+            task_number: number,
+            subject: format!("Task {}", number),
+            description: None,
+            status: "pending".to_string(),
+            model: None,
+            revision: number,
+        }
+    }
+
+    fn press(editor: &mut BulkModelEditor, code: KeyCode) -> BulkResult {
+        editor.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn queues_one_payload_per_task_and_tracks_progress() {
+        let mut editor = BulkModelEditor::new(vec!["1".into(), "2".into(), "3".into()]);
+        press(&mut editor, KeyCode::Left);
+        assert_eq!(MODELS[editor.model], "gpt-5.3-codex-spark");
+        press(&mut editor, KeyCode::Right);
+        press(&mut editor, KeyCode::Right);
+        assert_eq!(press(&mut editor, KeyCode::Enter), BulkResult::Start);
+
+        let skipped = BulkOutcome {
+            task: "3".to_string(),
+            subject: "Task 3".to_string(),
+            result: Ok(Some("in progress".to_string())),
+        };
+        editor.start(vec![task(1), task(2)], vec![skipped]);
+        assert_eq!(editor.stage, BulkStage::Running);
+
+        let (first, payload) = editor.next_payload("auth").unwrap();
+        let payload: serde_json::Value = serde_json::from_str(&payload).unwrap();
+        assert_eq!(
+            payload,
+            serde_json::json!({
+                "feature": "auth",
+                "phaseNumber": "1",
+                "taskNumber": 1,
+                "revision": 1,
+                "model": "sonnet",
+            })
+        );
+        editor.record(first, Ok(()));
+        assert_eq!(editor.stage, BulkStage::Running);
+
+        let (second, _) = editor.next_payload("auth").unwrap();
+        editor.record(second, Err("revision conflict".to_string()));
+        assert_eq!(editor.stage, BulkStage::Done);
+        assert!(editor.next_payload("auth").is_none());
+        assert_eq!(
+            editor.summary(),
+            "1 task set to sonnet, 1 failed, 1 skipped"
+        );
+        assert_eq!(press(&mut editor, KeyCode::Enter), BulkResult::Close);
+    }
+
+    #[test]
+    fn esc_while_running_cancels_the_rest() {
+        let mut editor = BulkModelEditor::new(vec!["1".into(), "2".into()]);
+        editor.start(vec![task(1), task(2)], Vec::new());
+        let (first, _) = editor.next_payload("auth").unwrap();
+        editor.record(first, Ok(()));
+
+        assert_eq!(press(&mut editor, KeyCode::Esc), BulkResult::Consumed);
+        assert_eq!(editor.stage, BulkStage::Done);
+        assert_eq!(editor.summary(), "1 task set to opus, 1 skipped");
+    }
+}
@@ -1,11 +1,12 @@
 //! Command modal for showing fallback commands
 
 use crate::overlay::centered_rect;
+use crate::theme;
 use crate::tui::app::{App, ViewState};
 use arboard::Clipboard;
 use ratatui::{
     layout::Alignment,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
@@ -56,7 +57,10 @@ pub fn render(app: &App, frame: &mut Frame) {
 
     let mut lines = vec![
         Line::from(""),
-        Line::from(Span::styled("Command:", Style::default().fg(Color::Yellow))),
+        Line::from(Span::styled(
+            "Command:",
+            Style::default().fg(theme::current().highlight),
+        )),
         Line::from(command.as_str()),
         Line::from(""),
         Line::from(description.as_str()),
@@ -66,12 +70,12 @@ pub fn render(app: &App, frame: &mut Frame) {
     if *copied {
         lines.push(Line::from(Span::styled(
             "✓ Copied to clipboard",
-            Style::default().fg(Color::Green),
+            Style::default().fg(theme::current().success),
         )));
     } else {
         lines.push(Line::from(Span::styled(
             "[y] Copy  [Esc] Close",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme::current().muted),
         )));
     }
 
@@ -82,7 +86,7 @@ pub fn render(app: &App, frame: &mut Frame) {
                 .title(" Command ")
                 .title_alignment(Alignment::Center),
         )
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(theme::current().text));
 
     frame.render_widget(paragraph, area);
 }
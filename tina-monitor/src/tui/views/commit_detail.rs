@@ -0,0 +1,330 @@
+//! Commit detail pane
+//!
+//! Opened from the commits view: shows one commit's full message, author,
+//! and stats above a collapsible tree of the files it changed.
+
+use crate::git::commits::{get_commit_detail, CommitDetail, CommitFile};
+use crate::theme;
+use crate::types::SupervisorState;
+use anyhow::Result;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// Lines of header (hash, author, date, stats, blank) above the message
+const HEADER_LINES: u16 = 5;
+
+/// Navigation state for the detail pane, kept in `ViewState`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommitDetailState {
+    /// Full hash of the commit being shown
+    pub hash: String,
+    /// Selected row in the file tree
+    pub selected: usize,
+    /// Directory paths whose children are hidden
+    pub collapsed: BTreeSet<String>,
+}
+
+impl CommitDetailState {
+    pub fn new(hash: String) -> Self {
+        Self {
+            hash,
+            ..Self::default()
+        }
+    }
+
+    pub fn select_next(&mut self, rows: usize) {
+        if rows > 0 {
+            self.selected = (self.selected + 1) % rows;
+        }
+    }
+
+    pub fn select_previous(&mut self, rows: usize) {
+        if rows > 0 {
+            self.selected = self.selected.checked_sub(1).unwrap_or(rows - 1);
+        }
+    }
+
+    /// Collapse or expand a directory
+    pub fn toggle(&mut self, dir: &str) {
+        if !self.collapsed.remove(dir) {
+            self.collapsed.insert(dir.to_string());
+        }
+    }
+}
+
+/// What a file tree row points at
+#[derive(Debug, Clone, PartialEq)]
+pub enum TreeEntry {
+    /// Directory, by its full path
+    Dir(String),
+    /// File, by its index in `CommitDetail::files`
+    File(usize),
+}
+
+/// One visible row of the file tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeRow {
+    pub depth: usize,
+    pub name: String,
+    pub entry: TreeEntry,
+}
+
+/// Flatten changed files into tree rows, sorted by path, skipping the
+/// contents of collapsed directories
+pub fn build_tree(files: &[CommitFile], collapsed: &BTreeSet<String>) -> Vec<TreeRow> {
+    let mut order: Vec<usize> = (0..files.len()).collect();
+    order.sort_by(|&a, &b| files[a].path.cmp(&files[b].path));
+
+    let mut rows = Vec::new();
+    let mut prev_dirs: Vec<&str> = Vec::new();
+    for index in order {
+        let parts: Vec<&str> = files[index].path.split('/').collect();
+        let (name, dirs) = parts.split_last().expect("split yields at least one part");
+        let common = prev_dirs
+            .iter()
+            .zip(dirs)
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        // Rows at `depth` are hidden when any ancestor directory is collapsed
+        let hidden = |depth: usize| (0..depth).any(|d| collapsed.contains(&dirs[..=d].join("/")));
+
+        for depth in common..dirs.len() {
+            if !hidden(depth) {
+                rows.push(TreeRow {
+                    depth,
+                    name: dirs[depth].to_string(),
+                    entry: TreeEntry::Dir(dirs[..=depth].join("/")),
+                });
+            }
+        }
+        if !hidden(dirs.len()) {
+            rows.push(TreeRow {
+                depth: dirs.len(),
+                name: name.to_string(),
+                entry: TreeEntry::File(index),
+            });
+        }
+        prev_dirs = dirs.to_vec();
+    }
+    rows
+}
+
+/// Detail pane for a single commit
+pub struct CommitDetailView {
+    pub detail: CommitDetail,
+    pub rows: Vec<TreeRow>,
+    collapsed: BTreeSet<String>,
+    list_state: ListState,
+}
+
+impl CommitDetailView {
+    /// Load the commit named in `state`, honouring the worktree's monorepo scope
+    pub fn new<P: AsRef<Path>>(worktree_path: P, state: &CommitDetailState) -> Result<Self> {
+        let scope = SupervisorState::local_scope(worktree_path.as_ref());
+        let detail = get_commit_detail(worktree_path.as_ref(), &state.hash, scope.as_deref())?;
+        let rows = build_tree(&detail.files, &state.collapsed);
+        let mut list_state = ListState::default();
+        if !rows.is_empty() {
+            list_state.select(Some(state.selected.min(rows.len() - 1)));
+        }
+
+        Ok(Self {
+            detail,
+            rows,
+            collapsed: state.collapsed.clone(),
+            list_state,
+        })
+    }
+
+    pub fn selected_row(&self) -> Option<&TreeRow> {
+        self.list_state.selected().and_then(|i| self.rows.get(i))
+    }
+
+    /// Render the detail pane
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let theme = theme::current();
+        let muted = Style::default().fg(theme.muted);
+        let detail = &self.detail;
+
+        let mut header = vec![
+            Line::from(Span::styled(
+                detail.hash.clone(),
+                Style::default().fg(theme.accent),
+            )),
+            Line::from(vec![
+                Span::styled("Author: ", muted),
+                Span::raw(format!("{} <{}>", detail.author, detail.email)),
+            ]),
+            Line::from(vec![
+                Span::styled("Date:   ", muted),
+                Span::raw(detail.date.clone()),
+            ]),
+            Line::from(vec![
+                Span::styled("Stats:  ", muted),
+                Span::raw(format!("{} files, ", detail.files.len())),
+                Span::styled(
+                    format!("+{}", detail.insertions),
+                    Style::default().fg(theme.success),
+                ),
+                Span::raw(" "),
+                Span::styled(
+                    format!("-{}", detail.deletions),
+                    Style::default().fg(theme.error),
+                ),
+            ]),
+            Line::raw(""),
+        ];
+        header.extend(detail.message.lines().map(|l| Line::raw(l.to_string())));
+
+        let header_height = (header.len() as u16 + 2)
+            .min(area.height / 2)
+            .max(HEADER_LINES);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(header_height), Constraint::Min(3)])
+            .split(area);
+
+        let header = Paragraph::new(header).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", detail.short_hash)),
+        );
+        frame.render_widget(header, chunks[0]);
+
+        let items: Vec<ListItem> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let indent = "  ".repeat(row.depth);
+                match &row.entry {
+                    TreeEntry::Dir(path) => {
+                        let marker = if self.collapsed.contains(path) {
+                            "▸"
+                        } else {
+                            "▾"
+                        };
+                        ListItem::new(Line::from(Span::styled(
+                            format!("{}{} {}/", indent, marker, row.name),
+                            Style::default().fg(theme.accent),
+                        )))
+                    }
+                    TreeEntry::File(index) => {
+                        let file = &detail.files[*index];
+                        let mut spans = vec![
+                            Span::raw(indent),
+                            Span::styled(format!("{} ", file.status), muted),
+                            Span::raw(row.name.clone()),
+                            Span::raw("  "),
+                        ];
+                        if file.binary {
+                            spans.push(Span::styled("binary", muted));
+                        } else {
+                            spans.push(Span::styled(
+                                format!("+{}", file.insertions),
+                                Style::default().fg(theme.success),
+                            ));
+                            spans.push(Span::raw(" "));
+                            spans.push(Span::styled(
+                                format!("-{}", file.deletions),
+                                Style::default().fg(theme.error),
+                            ));
+                        }
+                        ListItem::new(Line::from(spans))
+                    }
+                }
+            })
+            .collect();
+
+        let tree = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(" Files "))
+            .highlight_style(theme.selection().add_modifier(Modifier::BOLD));
+        frame.render_stateful_widget(tree, chunks[1], &mut self.list_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn file(path: &str) -> CommitFile {
+        CommitFile {
+            path: path.to_string(),
+            status: 'M',
+            insertions: 1,
+            deletions: 0,
+            binary: false,
+        }
+    }
+
+    fn names(rows: &[TreeRow]) -> Vec<String> {
+        rows.iter()
+            .map(|r| format!("{}{}", "  ".repeat(r.depth), r.name))
+            .collect()
+    }
+
+    #[test]
+    fn test_build_tree_groups_files_by_directory() {
+        let files = vec![
+            file("src/tui/app.rs"),
+            file("README.md"),
+            file("src/main.rs"),
+        ];
+        let rows = build_tree(&files, &BTreeSet::new());
+
+        assert_eq!(
+            names(&rows),
+            vec!["README.md", "src", "  main.rs", "  tui", "    app.rs"]
+        );
+        assert_eq!(rows[0].entry, TreeEntry::File(1));
+        assert_eq!(rows[3].entry, TreeEntry::Dir("src/tui".to_string()));
+    }
+
+    #[test]
+    fn test_build_tree_hides_collapsed_directories() {
+        let files = vec![file("src/tui/app.rs"), file("src/main.rs")];
+        let collapsed = BTreeSet::from(["src/tui".to_string()]);
+        let rows = build_tree(&files, &collapsed);
+        assert_eq!(names(&rows), vec!["src", "  main.rs", "  tui"]);
+
+        let collapsed = BTreeSet::from(["src".to_string()]);
+        assert_eq!(names(&build_tree(&files, &collapsed)), vec!["src"]);
+    }
+
+    #[test]
+    fn test_state_navigation_wraps_and_toggles() {
+        let mut state = CommitDetailState::new("abc".to_string());
+        state.select_previous(3);
+        assert_eq!(state.selected, 2);
+        state.select_next(3);
+        assert_eq!(state.selected, 0);
+        state.select_next(0);
+        assert_eq!(state.selected, 0);
+
+        state.toggle("src");
+        assert!(state.collapsed.contains("src"));
+        state.toggle("src");
+        assert!(state.collapsed.is_empty());
+    }
+
+    #[test]
+    fn test_view_loads_head_commit() {
+        let repo = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .to_path_buf();
+        let view = CommitDetailView::new(&repo, &CommitDetailState::new("HEAD".to_string()))
+            .expect("should load HEAD");
+
+        assert!(!view.detail.files.is_empty(), "HEAD should touch files");
+        assert!(view.selected_row().is_some());
+    }
+}
@@ -1,12 +1,15 @@
 //! Commits view modal
 //!
-//! Displays git commits in a range with summary statistics.
+//! Displays git commits in a range with summary statistics. Enter opens a
+//! detail pane for the selected commit (see `commit_detail`).
 
-use crate::git::commits::{get_commits, CommitSummary};
+use crate::git::commits::{get_commits, Commit, CommitSummary};
+use crate::theme;
+use crate::types::SupervisorState;
 use anyhow::Result;
 use ratatui::{
     layout::{Constraint, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
@@ -26,7 +29,8 @@ pub struct CommitsView {
 impl CommitsView {
     /// Create a new CommitsView
     pub fn new<P: AsRef<Path>>(worktree_path: P, range: String, title: String) -> Result<Self> {
-        let summary = get_commits(worktree_path.as_ref(), &range)?;
+        let scope = SupervisorState::local_scope(worktree_path.as_ref());
+        let summary = get_commits(worktree_path.as_ref(), &range, scope.as_deref())?;
         let mut list_state = ListState::default();
         if !summary.commits.is_empty() {
             list_state.select(Some(0));
@@ -41,6 +45,25 @@ impl CommitsView {
         })
     }
 
+    /// Index of the selected commit
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// The selected commit, if any
+    pub fn selected_commit(&self) -> Option<&Commit> {
+        self.summary.commits.get(self.selected)
+    }
+
+    /// Select the commit at `index`, clamped to the list
+    pub fn select(&mut self, index: usize) {
+        if self.summary.commits.is_empty() {
+            return;
+        }
+        self.selected = index.min(self.summary.commits.len() - 1);
+        self.list_state.select(Some(self.selected));
+    }
+
     /// Select next commit
     pub fn select_next(&mut self) {
         if self.summary.commits.is_empty() {
@@ -88,11 +111,7 @@ impl CommitsView {
                     .borders(Borders::ALL)
                     .title(self.title.clone()),
             )
-            .highlight_style(
-                Style::default()
-                    .bg(Color::DarkGray)
-                    .add_modifier(Modifier::BOLD),
-            );
+            .highlight_style(theme::current().selection().add_modifier(Modifier::BOLD));
 
         frame.render_stateful_widget(commits_list, chunks[0], &mut self.list_state);
 
@@ -104,7 +123,7 @@ impl CommitsView {
 
         let summary = Paragraph::new(Line::from(vec![Span::styled(
             summary_text,
-            Style::default().fg(Color::Cyan),
+            Style::default().fg(theme::current().accent),
         )]))
         .block(Block::default().borders(Borders::ALL).title("Summary"));
 
@@ -201,6 +220,20 @@ mod tests {
         assert_eq!(view.selected, 0, "Should stay at 0 with empty list");
     }
 
+    #[test]
+    fn test_select_clamps_to_last_commit() {
+        let repo = get_test_repo_path();
+        let mut view =
+            CommitsView::new(&repo, "HEAD~2..HEAD".to_string(), "Test".to_string()).unwrap();
+
+        view.select(usize::MAX);
+        assert_eq!(view.selected(), view.summary.commits.len() - 1);
+        assert_eq!(
+            view.selected_commit().map(|c| &c.hash),
+            view.summary.commits.last().map(|c| &c.hash)
+        );
+    }
+
     #[test]
     fn test_select_previous_with_empty_commits() {
         let repo = get_test_repo_path();
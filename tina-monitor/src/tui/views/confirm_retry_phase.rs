@@ -0,0 +1,61 @@
+//! Confirmation modal for retrying a phase from the Phases pane
+
+use crate::overlay::centered_rect;
+use crate::theme;
+use crate::tui::app::{App, ViewState};
+use ratatui::{
+    layout::Alignment,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render the retry-phase confirmation with its reason prompt
+pub fn render(app: &App, frame: &mut Frame) {
+    let (phase, reason) = match &app.view_state {
+        ViewState::ConfirmRetryPhase { phase, reason } => (*phase, reason),
+        _ => return,
+    };
+    let Some(orch) = app.orchestrations.get(app.selected_index) else {
+        return;
+    };
+    let theme = theme::current();
+
+    let area = centered_rect(60, 30, frame.area());
+    frame.render_widget(Clear, area);
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("Retry phase {} of {}?", phase, orch.feature_name),
+            Style::default()
+                .fg(theme.warning)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from("Queues a retry for the orchestration's node, like the web's Retry button."),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Reason: ", Style::default().fg(theme.muted)),
+            Span::raw(format!("{}█", reason)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "[Enter] Retry  [Esc] Cancel",
+            Style::default().fg(theme.muted),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Retry Phase ")
+                .title_alignment(Alignment::Center),
+        )
+        .wrap(Wrap { trim: false })
+        .style(Style::default().fg(theme.text));
+
+    frame.render_widget(paragraph, area);
+}
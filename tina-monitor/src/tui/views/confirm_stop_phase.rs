@@ -0,0 +1,66 @@
+//! Confirmation modal for stopping a phase from the Members pane
+
+use crate::overlay::centered_rect;
+use crate::theme;
+use crate::tui::app::{App, ViewState};
+use ratatui::{
+    layout::Alignment,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render the stop-phase confirmation
+pub fn render(app: &App, frame: &mut Frame) {
+    let (phase, member_index) = match &app.view_state {
+        ViewState::ConfirmStopPhase {
+            phase,
+            member_index,
+            ..
+        } => (*phase, *member_index),
+        _ => return,
+    };
+    let Some(orch) = app.orchestrations.get(app.selected_index) else {
+        return;
+    };
+    let theme = theme::current();
+
+    let area = centered_rect(60, 30, frame.area());
+    frame.render_widget(Clear, area);
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("Stop phase {} of {}?", phase, orch.feature_name),
+            Style::default()
+                .fg(theme.warning)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from("Kills the phase's tmux session with `tina-session stop`."),
+    ];
+    if let Some(member) = orch.members.get(member_index) {
+        lines.push(Line::from(format!(
+            "Every agent in it stops, including {}.",
+            member.name
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "[y] Stop  [n/Esc] Cancel",
+        Style::default().fg(theme.muted),
+    )));
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Stop Phase ")
+                .title_alignment(Alignment::Center),
+        )
+        .wrap(Wrap { trim: false })
+        .style(Style::default().fg(theme.text));
+
+    frame.render_widget(paragraph, area);
+}
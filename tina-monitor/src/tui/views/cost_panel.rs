@@ -0,0 +1,246 @@
+//! Cost panel modal
+//!
+//! Summarizes an orchestration's spend per phase, per role, and in total,
+//! with bars against the `[cost]` budgets when they are configured.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::config::CostConfig;
+use crate::dashboard::Dashboard;
+use crate::data::cost::CostSummary;
+use crate::data::MonitorOrchestration;
+use crate::theme;
+
+/// Cells in a budget or share bar
+const BAR_CELLS: usize = 20;
+/// Share of a budget at which its bar turns yellow
+const BUDGET_WARN_PCT: f64 = 80.0;
+
+pub struct CostPanel<'a> {
+    orchestration: &'a MonitorOrchestration,
+    summary: CostSummary,
+    budget: &'a CostConfig,
+    selected_phase: u32,
+}
+
+fn mins(value: f64) -> String {
+    Dashboard::format_duration(value.round() as i64)
+}
+
+/// `used` of `limit` as a filled bar and percentage. Bars are colored by
+/// how close they are to the limit when `budget` is set.
+fn bar(used: f64, limit: f64, budget: bool) -> Vec<Span<'static>> {
+    let theme = theme::current();
+    let pct = if limit > 0.0 {
+        used / limit * 100.0
+    } else {
+        0.0
+    };
+    let filled = ((pct.min(100.0) / 100.0) * BAR_CELLS as f64).round() as usize;
+    let color = match budget {
+        true if pct > 100.0 => theme.error,
+        true if pct >= BUDGET_WARN_PCT => theme.warning,
+        true => theme.success,
+        false => theme.progress,
+    };
+    vec![
+        Span::styled("█".repeat(filled), Style::default().fg(color)),
+        Span::styled(
+            "░".repeat(BAR_CELLS - filled),
+            Style::default().fg(theme.muted),
+        ),
+        Span::styled(format!(" {:>3.0}%", pct), Style::default().fg(color)),
+    ]
+}
+
+fn heading(text: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        text.to_string(),
+        Style::default().add_modifier(Modifier::BOLD),
+    ))
+}
+
+impl<'a> CostPanel<'a> {
+    pub fn new(
+        orchestration: &'a MonitorOrchestration,
+        budget: &'a CostConfig,
+        selected_phase: u32,
+    ) -> Self {
+        Self {
+            orchestration,
+            summary: CostSummary::from_phases(&orchestration.phases),
+            budget,
+            selected_phase,
+        }
+    }
+
+    fn lines(&self) -> Vec<Line<'static>> {
+        let theme = theme::current();
+        let muted = Style::default().fg(theme.muted);
+        let mut lines = vec![
+            Line::from(Span::styled(
+                "Agent minutes per stage (token spend isn't recorded)",
+                muted,
+            )),
+            Line::from(""),
+            heading("Per phase"),
+        ];
+
+        if self.summary.phases.is_empty() {
+            lines.push(Line::from(Span::styled("  No phases recorded", muted)));
+        }
+        let selected = self.selected_phase.to_string();
+        for phase in &self.summary.phases {
+            let style = if phase.phase == selected {
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            let mut spans = vec![
+                Span::styled(format!("  Phase {:<4}", phase.phase), style),
+                Span::styled(
+                    format!(
+                        "plan {:>6}  exec {:>6}  review {:>6}  ",
+                        mins(phase.planning),
+                        mins(phase.execution),
+                        mins(phase.review)
+                    ),
+                    muted,
+                ),
+                Span::styled(format!("{:>7}  ", mins(phase.total())), style),
+            ];
+            if let Some(limit) = self.budget.phase_budget_mins {
+                spans.extend(bar(phase.total(), limit, true));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        let total = self.summary.total();
+        lines.push(Line::from(""));
+        lines.push(heading("Per role"));
+        for (role, spent) in self.summary.by_role() {
+            let mut spans = vec![
+                Span::styled(format!("  {:<10}", role), Style::default().fg(theme.text)),
+                Span::styled(
+                    format!("{:>7}  ", mins(spent)),
+                    Style::default().fg(theme.text),
+                ),
+            ];
+            spans.extend(bar(spent, total, false));
+            lines.push(Line::from(spans));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(heading("Cumulative"));
+        let mut spans = vec![Span::styled(
+            format!("  {:<10}{:>7}  ", "total", mins(total)),
+            Style::default().fg(theme.text),
+        )];
+        match self.budget.budget_mins {
+            Some(limit) => {
+                spans.extend(bar(total, limit, true));
+                spans.push(Span::styled(format!(" of {}", mins(limit)), muted));
+            }
+            None => spans.push(Span::styled("no budget set ([cost] budget_mins)", muted)),
+        }
+        lines.push(Line::from(spans));
+        if let Some(wall) = self.orchestration.total_elapsed_mins {
+            lines.push(Line::from(Span::styled(
+                format!("  {:<10}{:>7}", "wall clock", mins(wall)),
+                muted,
+            )));
+        }
+        lines
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Cost · {} ", self.orchestration.feature_name));
+        frame.render_widget(Paragraph::new(self.lines()).block(block), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+    use tina_data::test_util::list_entry;
+    use tina_data::PhaseRecord;
+
+    fn orchestration() -> MonitorOrchestration {
+        let mut entry = list_entry("orch-1", "executing", "2026-02-07T10:00:00Z");
+        entry.record.total_phases = 2.0;
+        entry.record.current_phase = 2.0;
+        entry.record.total_elapsed_mins = Some(90.0);
+        let mut orch = MonitorOrchestration::from_list_entry(entry);
+        orch.phases = vec![PhaseRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: "1".to_string(),
+            status: "complete".to_string(),
+            plan_path: None,
+            git_range: None,
+            planning_mins: Some(10.0),
+            execution_mins: Some(40.0),
+            review_mins: Some(10.0),
+            started_at: None,
+            completed_at: None,
+            risk_score: None,
+            risk_signals: None,
+        }];
+        orch
+    }
+
+    fn rendered(budget: &CostConfig) -> String {
+        let orch = orchestration();
+        let panel = CostPanel::new(&orch, budget, 1);
+        let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+        terminal
+            .draw(|frame| panel.render(frame, frame.area()))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn renders_phase_role_and_total_spend() {
+        let content = rendered(&CostConfig::default());
+        assert!(content.contains("Cost · auth"));
+        assert!(content.contains("Phase 1"));
+        assert!(content.contains("executor"));
+        assert!(content.contains("no budget set"));
+    }
+
+    #[test]
+    fn renders_budget_bars_when_configured() {
+        let content = rendered(&CostConfig {
+            budget_mins: Some(120.0),
+            phase_budget_mins: Some(50.0),
+        });
+        assert!(content.contains(" 50%"), "total is half the budget");
+        assert!(content.contains("120%"), "phase is over its budget");
+        assert!(!content.contains("no budget set"));
+    }
+
+    #[test]
+    fn bar_fills_to_share() {
+        let text: String = bar(5.0, 10.0, false)
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert_eq!(text, format!("{}{}  50%", "█".repeat(10), "░".repeat(10)));
+    }
+}
@@ -0,0 +1,191 @@
+//! Multi-orchestration dashboard view
+//!
+//! Wall-display mode: one card per active orchestration, laid out side by
+//! side, showing phase and task progress, elapsed time, and anything that
+//! needs a human (blocked status, stuck tasks).
+
+use chrono::Utc;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::dashboard::Dashboard;
+use crate::data::{MonitorOrchestration, MonitorOrchestrationStatus};
+use crate::theme;
+use crate::tui::app::App;
+use crate::tui::widgets::{progress_bar, status_indicator};
+
+/// Minimum card width; the grid fits as many columns as this allows
+const CARD_WIDTH: u16 = 36;
+/// Card height including borders
+const CARD_HEIGHT: u16 = 7;
+/// Width of the progress bars inside a card
+const BAR_WIDTH: usize = 16;
+
+/// Number of card columns that fit in `width`
+pub fn columns_for_width(width: u16, cards: usize) -> usize {
+    let fit = (width / CARD_WIDTH).max(1) as usize;
+    fit.min(cards.max(1))
+}
+
+/// Render the dashboard view
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let indices = app.dashboard_indices();
+    if indices.is_empty() {
+        let empty = Paragraph::new("No active orchestrations")
+            .style(Style::default().fg(theme::current().muted));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let columns = columns_for_width(area.width, indices.len());
+    let visible_rows = (area.height / CARD_HEIGHT).max(1) as usize;
+    let selected_pos = indices
+        .iter()
+        .position(|&i| i == app.selected_index)
+        .unwrap_or(0);
+    // Scroll by whole rows so the selected card stays on screen
+    let first_row = (selected_pos / columns).saturating_sub(visible_rows - 1);
+
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(CARD_HEIGHT); visible_rows])
+        .split(area);
+    let now = Utc::now();
+
+    for (row, row_area) in row_areas.iter().enumerate() {
+        let start = (first_row + row) * columns;
+        if start >= indices.len() {
+            break;
+        }
+        let cells = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, columns as u32); columns])
+            .split(*row_area);
+        for (cell, &orch_index) in cells.iter().zip(&indices[start..]) {
+            let orch = &app.orchestrations[orch_index];
+            render_card(
+                frame,
+                *cell,
+                orch,
+                orch_index == app.selected_index,
+                app.stuck_tasks.threshold_mins,
+                now,
+            );
+        }
+    }
+}
+
+fn render_card(
+    frame: &mut Frame,
+    area: Rect,
+    orch: &MonitorOrchestration,
+    selected: bool,
+    stuck_threshold_mins: i64,
+    now: chrono::DateTime<Utc>,
+) {
+    let theme = theme::current();
+    let blocked = orch.status == MonitorOrchestrationStatus::Blocked;
+    let border_color = if selected {
+        theme.focused_border
+    } else if blocked {
+        theme.status_blocked
+    } else {
+        theme.border
+    };
+
+    let phases_done = if orch.status == MonitorOrchestrationStatus::Complete {
+        orch.total_phases
+    } else {
+        orch.current_phase.saturating_sub(1)
+    };
+    let elapsed = orch
+        .elapsed_mins(now)
+        .map(Dashboard::format_duration)
+        .unwrap_or_else(|| "-".to_string());
+    let stuck = orch.stuck_tasks(stuck_threshold_mins, now).len();
+
+    let mut alerts = Vec::new();
+    if blocked {
+        alerts.push(Span::styled(
+            "BLOCKED",
+            Style::default()
+                .fg(theme.error)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if stuck > 0 {
+        if !alerts.is_empty() {
+            alerts.push(Span::raw("  "));
+        }
+        alerts.push(Span::styled(
+            format!("{} stuck", stuck),
+            Style::default().fg(theme.warning),
+        ));
+    }
+    if alerts.is_empty() {
+        alerts.push(Span::styled("ok", Style::default().fg(theme.muted)));
+    }
+
+    let label = Style::default().fg(theme.muted);
+    let lines = vec![
+        Line::from(vec![
+            status_indicator::render(&orch.status),
+            Span::styled(format!("  {}", orch.node_name), label),
+        ]),
+        Line::from(vec![
+            Span::styled("Phase ", label),
+            progress_bar::render_styled(
+                phases_done as usize,
+                orch.total_phases as usize,
+                BAR_WIDTH,
+            ),
+            Span::raw(format!(" {}/{}", orch.current_phase, orch.total_phases)),
+        ]),
+        Line::from(vec![
+            Span::styled("Tasks ", label),
+            progress_bar::render_styled(orch.tasks_completed(), orch.tasks_total(), BAR_WIDTH),
+            Span::raw(format!(
+                " {}/{}",
+                orch.tasks_completed(),
+                orch.tasks_total()
+            )),
+        ]),
+        Line::from(vec![Span::styled("Elapsed ", label), Span::raw(elapsed)]),
+        Line::from(alerts),
+    ];
+
+    let title_style = if selected {
+        Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.text)
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color))
+        .title(Span::styled(
+            format!(" {} ", orch.feature_name),
+            title_style,
+        ));
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_columns_for_width_fits_cards() {
+        assert_eq!(columns_for_width(120, 5), 3);
+        assert_eq!(columns_for_width(120, 2), 2);
+        assert_eq!(columns_for_width(20, 5), 1);
+        assert_eq!(columns_for_width(120, 0), 1);
+    }
+}
@@ -0,0 +1,572 @@
+//! Patch rendering for the full diff view
+//!
+//! File headers and hunk markers are dimmed, code is syntax highlighted by
+//! file extension, and within each removed/added line pair the words that
+//! actually changed get a background so small edits stand out.
+
+use std::cell::RefCell;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{FontStyle, Theme as SyntaxTheme, ThemeSet};
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+
+use crate::theme;
+
+/// Lines with more tokens than this skip word-level diffing
+const MAX_WORD_DIFF_TOKENS: usize = 200;
+
+fn syntax_set() -> &'static SyntaxSet {
+    static SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn syntax_theme() -> &'static SyntaxTheme {
+    static THEME: OnceLock<SyntaxTheme> = OnceLock::new();
+    THEME.get_or_init(|| {
+        ThemeSet::load_defaults()
+            .themes
+            .remove("base16-eighties.dark")
+            .unwrap_or_default()
+    })
+}
+
+thread_local! {
+    /// The diff view is rebuilt every frame; keep the last rendering
+    static CACHE: RefCell<Option<(String, Vec<Line<'static>>)>> = const { RefCell::new(None) };
+}
+
+/// Highlight `patch`, reusing the previous result when it hasn't changed
+pub fn highlight_patch_cached(patch: &str) -> Vec<Line<'static>> {
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if let Some((cached, lines)) = cache.as_ref() {
+            if cached == patch {
+                return lines.clone();
+            }
+        }
+        let lines = highlight_patch(patch);
+        *cache = Some((patch.to_string(), lines.clone()));
+        lines
+    })
+}
+
+/// A unit of patch output, styled but not yet laid out
+enum PatchItem {
+    /// Stat summary, file headers, metadata and hunk headers
+    Meta(Line<'static>),
+    /// Unchanged line with its old and new line numbers
+    Context(u32, u32, Line<'static>),
+    /// A run of removals and the additions that replaced them
+    Change {
+        removed: Vec<(u32, Line<'static>)>,
+        added: Vec<(u32, Line<'static>)>,
+    },
+}
+
+/// Render `git diff` output (optionally preceded by `--stat`) as styled lines
+pub fn highlight_patch(patch: &str) -> Vec<Line<'static>> {
+    let mut out = Vec::new();
+    for item in walk_patch(patch) {
+        match item {
+            PatchItem::Meta(line) | PatchItem::Context(_, _, line) => out.push(line),
+            PatchItem::Change { removed, added } => {
+                out.extend(removed.into_iter().map(|(_, line)| line));
+                out.extend(added.into_iter().map(|(_, line)| line));
+            }
+        }
+    }
+    out
+}
+
+/// Render `patch` as aligned old/new columns: context lines sit side by
+/// side, removals face the additions that replaced them, and blank rows pad
+/// whichever side is shorter. Code lines are prefixed with line numbers.
+pub fn side_by_side(patch: &str) -> (Vec<Line<'static>>, Vec<Line<'static>>) {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    for item in walk_patch(patch) {
+        match item {
+            PatchItem::Meta(line) => {
+                left.push(line.clone());
+                right.push(line);
+            }
+            PatchItem::Context(old, new, line) => {
+                left.push(numbered(old, line.clone()));
+                right.push(numbered(new, line));
+            }
+            PatchItem::Change { removed, added } => {
+                let rows = removed.len().max(added.len());
+                let mut removed = removed.into_iter();
+                let mut added = added.into_iter();
+                for _ in 0..rows {
+                    left.push(
+                        removed
+                            .next()
+                            .map_or_else(Line::default, |(n, l)| numbered(n, l)),
+                    );
+                    right.push(
+                        added
+                            .next()
+                            .map_or_else(Line::default, |(n, l)| numbered(n, l)),
+                    );
+                }
+            }
+        }
+    }
+    (left, right)
+}
+
+thread_local! {
+    static SIDE_BY_SIDE_CACHE: RefCell<Option<(String, SideBySideLines)>> = const { RefCell::new(None) };
+}
+
+type SideBySideLines = (Vec<Line<'static>>, Vec<Line<'static>>);
+
+/// [`side_by_side`], reusing the previous result when `patch` hasn't changed
+pub fn side_by_side_cached(patch: &str) -> SideBySideLines {
+    SIDE_BY_SIDE_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if let Some((cached, lines)) = cache.as_ref() {
+            if cached == patch {
+                return lines.clone();
+            }
+        }
+        let lines = side_by_side(patch);
+        *cache = Some((patch.to_string(), lines.clone()));
+        lines
+    })
+}
+
+fn numbered(number: u32, mut line: Line<'static>) -> Line<'static> {
+    line.spans.insert(
+        0,
+        Span::styled(
+            format!("{:>4} ", number),
+            Style::default().fg(theme::current().muted),
+        ),
+    );
+    line
+}
+
+fn walk_patch(patch: &str) -> Vec<PatchItem> {
+    let theme = theme::current();
+    let lines: Vec<&str> = patch.lines().collect();
+    let mut out = Vec::with_capacity(lines.len());
+    let mut highlighter: Option<HighlightLines> = None;
+    let mut in_hunk = false;
+    let (mut old_line, mut new_line) = (0u32, 0u32);
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        if let Some(paths) = line.strip_prefix("diff --git ") {
+            highlighter = Some(HighlightLines::new(syntax_for(paths), syntax_theme()));
+            in_hunk = false;
+            out.push(PatchItem::Meta(Line::from(Span::styled(
+                line.to_string(),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ))));
+            i += 1;
+            continue;
+        }
+        if line.starts_with("@@") {
+            in_hunk = true;
+            (old_line, new_line) = hunk_starts(line);
+            out.push(PatchItem::Meta(Line::from(Span::styled(
+                line.to_string(),
+                Style::default().fg(theme.info),
+            ))));
+            i += 1;
+            continue;
+        }
+        let Some(hl) = highlighter.as_mut().filter(|_| in_hunk) else {
+            // --stat summary or file metadata (index, ---/+++, modes)
+            out.push(PatchItem::Meta(Line::from(Span::styled(
+                line.to_string(),
+                Style::default().fg(theme.muted),
+            ))));
+            i += 1;
+            continue;
+        };
+
+        if line.starts_with('-') || line.starts_with('+') {
+            // Pair a run of removals with the additions that follow it
+            let removed_end = run_end(&lines, i, '-');
+            let added_end = run_end(&lines, removed_end, '+');
+            let removed = &lines[i..removed_end];
+            let added = &lines[removed_end..added_end];
+            let mut removed_emphasis = vec![Vec::new(); removed.len()];
+            let mut added_emphasis = vec![Vec::new(); added.len()];
+            for (pair, (old, new)) in removed.iter().zip(added).enumerate() {
+                if let Some((old_changed, new_changed)) = word_diff(&old[1..], &new[1..]) {
+                    removed_emphasis[pair] = old_changed;
+                    added_emphasis[pair] = new_changed;
+                }
+            }
+            let mut removed_lines = Vec::with_capacity(removed.len());
+            for (line, emphasis) in removed.iter().zip(&removed_emphasis) {
+                removed_lines.push((old_line, code_line(hl, line, emphasis)));
+                old_line += 1;
+            }
+            let mut added_lines = Vec::with_capacity(added.len());
+            for (line, emphasis) in added.iter().zip(&added_emphasis) {
+                added_lines.push((new_line, code_line(hl, line, emphasis)));
+                new_line += 1;
+            }
+            out.push(PatchItem::Change {
+                removed: removed_lines,
+                added: added_lines,
+            });
+            i = added_end;
+            continue;
+        }
+
+        if line.starts_with('\\') {
+            // "\ No newline at end of file"
+            out.push(PatchItem::Meta(Line::from(Span::styled(
+                line.to_string(),
+                Style::default().fg(theme.muted),
+            ))));
+        } else {
+            out.push(PatchItem::Context(
+                old_line,
+                new_line,
+                code_line(hl, line, &[]),
+            ));
+            old_line += 1;
+            new_line += 1;
+        }
+        i += 1;
+    }
+
+    out
+}
+
+/// Old and new start lines from a `@@ -a,b +c,d @@` hunk header
+fn hunk_starts(header: &str) -> (u32, u32) {
+    let mut parts = header.split_whitespace().skip(1);
+    let mut start = |prefix: char| {
+        parts
+            .next()
+            .and_then(|p| p.strip_prefix(prefix))
+            .and_then(|p| p.split(',').next())
+            .and_then(|n| n.parse().ok())
+            .unwrap_or(0)
+    };
+    let old = start('-');
+    let new = start('+');
+    (old, new)
+}
+
+/// Index just past the run of lines starting with `marker` at `start`
+fn run_end(lines: &[&str], start: usize, marker: char) -> usize {
+    lines[start..]
+        .iter()
+        .position(|l| !l.starts_with(marker))
+        .map_or(lines.len(), |n| start + n)
+}
+
+/// Syntax for the `b/` path of a `diff --git a/x b/x` header
+fn syntax_for(paths: &str) -> &'static SyntaxReference {
+    let ps = syntax_set();
+    let path = paths.rsplit(' ').next().unwrap_or(paths);
+    let path = path.strip_prefix("b/").unwrap_or(path);
+    Path::new(path)
+        .extension()
+        .and_then(|ext| ps.find_syntax_by_extension(&ext.to_string_lossy()))
+        .unwrap_or_else(|| ps.find_syntax_plain_text())
+}
+
+/// A hunk line: colored marker, syntax-highlighted code, emphasized words
+fn code_line(hl: &mut HighlightLines, line: &str, emphasis: &[Range<usize>]) -> Line<'static> {
+    let theme = theme::current();
+    let (marker, code) = line.split_at(line.chars().next().map_or(0, char::len_utf8));
+    let (marker_style, emphasis_bg) = match marker {
+        "+" => (Style::default().fg(theme.success), theme.diff_added_bg),
+        "-" => (Style::default().fg(theme.error), theme.diff_removed_bg),
+        _ => (Style::default().fg(theme.muted), theme.selection_bg),
+    };
+
+    let mut spans = vec![Span::styled(marker.to_string(), marker_style)];
+    let with_newline = format!("{}\n", code);
+    let regions = match hl.highlight_line(&with_newline, syntax_set()) {
+        Ok(regions) => regions
+            .into_iter()
+            .map(|(style, text)| (convert_style(style), text.trim_end_matches('\n')))
+            .collect(),
+        Err(_) => vec![(Style::default().fg(theme.text), code)],
+    };
+
+    let mut offset = 0;
+    for (style, text) in regions {
+        let range = offset..offset + text.len();
+        offset = range.end;
+        for (piece, emphasized) in split_by_emphasis(text, range, emphasis) {
+            let style = if emphasized {
+                style.bg(emphasis_bg).add_modifier(Modifier::BOLD)
+            } else {
+                style
+            };
+            spans.push(Span::styled(piece.to_string(), style));
+        }
+    }
+    Line::from(spans)
+}
+
+fn convert_style(style: syntect::highlighting::Style) -> Style {
+    let fg = style.foreground;
+    let mut converted = Style::default();
+    if !theme::current().plain {
+        converted = converted.fg(Color::Rgb(fg.r, fg.g, fg.b));
+    }
+    if style.font_style.contains(FontStyle::BOLD) {
+        converted = converted.add_modifier(Modifier::BOLD);
+    }
+    if style.font_style.contains(FontStyle::ITALIC) {
+        converted = converted.add_modifier(Modifier::ITALIC);
+    }
+    converted
+}
+
+/// Split `text` (covering byte range `range` of the line) at the edges of
+/// the emphasized ranges
+fn split_by_emphasis<'a>(
+    text: &'a str,
+    range: Range<usize>,
+    emphasis: &[Range<usize>],
+) -> Vec<(&'a str, bool)> {
+    let mut cuts = vec![range.start, range.end];
+    for e in emphasis {
+        for edge in [e.start, e.end] {
+            if range.start < edge && edge < range.end {
+                cuts.push(edge);
+            }
+        }
+    }
+    cuts.sort_unstable();
+    cuts.dedup();
+
+    cuts.windows(2)
+        .filter(|w| w[0] < w[1])
+        .map(|w| {
+            let emphasized = emphasis.iter().any(|e| e.start <= w[0] && w[1] <= e.end);
+            (&text[w[0] - range.start..w[1] - range.start], emphasized)
+        })
+        .collect()
+}
+
+/// Split a line into words, whitespace runs, and single punctuation chars
+fn tokenize(line: &str) -> Vec<Range<usize>> {
+    #[derive(PartialEq)]
+    enum Class {
+        Word,
+        Space,
+        Other,
+    }
+    let class = |c: char| {
+        if c.is_alphanumeric() || c == '_' {
+            Class::Word
+        } else if c.is_whitespace() {
+            Class::Space
+        } else {
+            Class::Other
+        }
+    };
+
+    let mut tokens: Vec<Range<usize>> = Vec::new();
+    let mut prev: Option<Class> = None;
+    for (i, c) in line.char_indices() {
+        let current = class(c);
+        let extends = current != Class::Other && prev.as_ref() == Some(&current);
+        match tokens.last_mut() {
+            Some(last) if extends => last.end = i + c.len_utf8(),
+            _ => tokens.push(i..i + c.len_utf8()),
+        }
+        prev = Some(current);
+    }
+    tokens
+}
+
+/// Changed byte ranges in the old and new line.
+pub type WordDiff = (Vec<Range<usize>>, Vec<Range<usize>>);
+
+/// Byte ranges of the tokens that differ between `old` and `new`.
+///
+/// Returns `None` when the lines share nothing but whitespace (a rewrite,
+/// where emphasizing everything is just noise) or are too long to diff.
+pub fn word_diff(old: &str, new: &str) -> Option<WordDiff> {
+    let old_tokens = tokenize(old);
+    let new_tokens = tokenize(new);
+    if old_tokens.len() > MAX_WORD_DIFF_TOKENS || new_tokens.len() > MAX_WORD_DIFF_TOKENS {
+        return None;
+    }
+    let old_text = |r: &Range<usize>| &old[r.clone()];
+    let new_text = |r: &Range<usize>| &new[r.clone()];
+
+    // Longest common subsequence over tokens
+    let (n, m) = (old_tokens.len(), new_tokens.len());
+    let mut lcs = vec![vec![0u16; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old_text(&old_tokens[i]) == new_text(&new_tokens[j]) {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut old_same = vec![false; n];
+    let mut new_same = vec![false; m];
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old_text(&old_tokens[i]) == new_text(&new_tokens[j]) {
+            old_same[i] = true;
+            new_same[j] = true;
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    let shares_content = old_tokens
+        .iter()
+        .zip(&old_same)
+        .any(|(t, same)| *same && !old_text(t).trim().is_empty());
+    if !shares_content {
+        return None;
+    }
+
+    Some((
+        changed_ranges(&old_tokens, &old_same),
+        changed_ranges(&new_tokens, &new_same),
+    ))
+}
+
+/// Merge adjacent changed tokens into ranges
+fn changed_ranges(tokens: &[Range<usize>], same: &[bool]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (token, same) in tokens.iter().zip(same) {
+        if *same {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(last) if last.end == token.start => last.end = token.end,
+            _ => ranges.push(token.clone()),
+        }
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_word_diff_marks_changed_tokens() {
+        let old = "let count = items.len();";
+        let new = "let total = items.len() + 1;";
+        let (old_changed, new_changed) = word_diff(old, new).unwrap();
+
+        let old_words: Vec<&str> = old_changed.iter().map(|r| &old[r.clone()]).collect();
+        let new_words: Vec<&str> = new_changed.iter().map(|r| &new[r.clone()]).collect();
+        assert_eq!(old_words, vec!["count"]);
+        assert_eq!(new_words, vec!["total", " + 1"]);
+    }
+
+    #[test]
+    fn test_word_diff_skips_rewrites() {
+        assert!(word_diff("foo bar", "baz qux").is_none());
+    }
+
+    #[test]
+    fn test_split_by_emphasis() {
+        let pieces = split_by_emphasis("count = 1", 4..13, &[4..9, 12..20]);
+        assert_eq!(pieces, vec![("count", true), (" = ", false), ("1", true)]);
+    }
+
+    #[test]
+    fn test_highlight_patch_preserves_text_and_emphasizes_changes() {
+        let patch = "\
+ src/lib.rs | 2 +-
+diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,2 +1,2 @@
+ fn main() {
+-    let count = 1;
++    let total = 1;
+";
+        let lines = highlight_patch(patch);
+        assert_eq!(lines.len(), patch.lines().count());
+        for (line, expected) in lines.iter().zip(patch.lines()) {
+            assert_eq!(text(line), expected);
+        }
+
+        let theme = theme::current();
+        let added = &lines[8];
+        assert_eq!(added.spans[0].style.fg, Some(theme.success));
+        let emphasized: Vec<&str> = added
+            .spans
+            .iter()
+            .filter(|s| s.style.bg == Some(theme.diff_added_bg))
+            .map(|s| s.content.as_ref())
+            .collect();
+        assert_eq!(emphasized, vec!["total"]);
+    }
+
+    #[test]
+    fn test_side_by_side_aligns_changes() {
+        let patch = "\
+diff --git a/a.rs b/a.rs
+@@ -10,3 +10,4 @@
+ keep
+-old
++new
++extra
+ tail
+";
+        let (left, right) = side_by_side(patch);
+        assert_eq!(left.len(), right.len());
+        let left: Vec<String> = left.iter().map(text).collect();
+        let right: Vec<String> = right.iter().map(text).collect();
+        assert_eq!(
+            left[2..],
+            ["  10  keep", "  11 -old", "", "  12  tail"].map(String::from)
+        );
+        assert_eq!(
+            right[2..],
+            ["  10  keep", "  11 +new", "  12 +extra", "  13  tail"].map(String::from)
+        );
+    }
+
+    #[test]
+    fn test_hunk_starts() {
+        assert_eq!(hunk_starts("@@ -10,3 +12,4 @@ fn main()"), (10, 12));
+        assert_eq!(hunk_starts("@@ -1 +1 @@"), (1, 1));
+    }
+
+    #[test]
+    fn test_metadata_lines_are_not_treated_as_code() {
+        let patch = "diff --git a/x.rs b/x.rs\n--- a/x.rs\n+++ b/x.rs\n";
+        let lines = highlight_patch(patch);
+        let muted = theme::current().muted;
+        assert_eq!(lines[1].spans[0].style.fg, Some(muted));
+        assert_eq!(lines[2].spans[0].style.fg, Some(muted));
+    }
+}
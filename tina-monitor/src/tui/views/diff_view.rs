@@ -2,16 +2,55 @@
 //!
 //! Displays git diff statistics for a range with file list and full diff view.
 
-use crate::git::diff::{get_diff_stats, get_full_diff, DiffStat};
+use super::diff_highlight::{highlight_patch_cached, side_by_side_cached};
+use crate::git::diff::{get_diff_stats, get_full_diff, get_full_diff_for_files, DiffStat};
+use crate::theme;
+use crate::types::SupervisorState;
 use anyhow::Result;
 use ratatui::{
     layout::{Constraint, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 use std::path::Path;
+use tina_session::ignore::IgnoreRules;
+
+/// Horizontal columns scroll this many cells per key press
+const HSCROLL_STEP: u16 = 8;
+
+/// Side-by-side layout state for the full diff
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SideBySide {
+    pub enabled: bool,
+    /// Horizontal scrolling applies to the new (right) column when true
+    pub focus_right: bool,
+    pub left_scroll: u16,
+    pub right_scroll: u16,
+}
+
+impl SideBySide {
+    fn focused_scroll(&mut self) -> &mut u16 {
+        if self.focus_right {
+            &mut self.right_scroll
+        } else {
+            &mut self.left_scroll
+        }
+    }
+
+    /// Scroll the focused column left
+    pub fn scroll_left(&mut self) {
+        let scroll = self.focused_scroll();
+        *scroll = scroll.saturating_sub(HSCROLL_STEP);
+    }
+
+    /// Scroll the focused column right
+    pub fn scroll_right(&mut self) {
+        let scroll = self.focused_scroll();
+        *scroll = scroll.saturating_add(HSCROLL_STEP);
+    }
+}
 
 /// Diff view modal showing git diff statistics
 pub struct DiffView {
@@ -24,12 +63,20 @@ pub struct DiffView {
     pub full_diff: Option<String>,
     pub show_full: bool,
     pub scroll: u16,
+    /// Monorepo subtree recorded for the orchestration, if any
+    pub scope: Option<std::path::PathBuf>,
+    /// Files hidden by the project's `.tina/ignore`
+    pub ignored: usize,
+    pub side_by_side: SideBySide,
 }
 
 impl DiffView {
     /// Create a new DiffView
     pub fn new<P: AsRef<Path>>(worktree_path: P, range: String, title: String) -> Result<Self> {
-        let stats = get_diff_stats(worktree_path.as_ref(), &range)?;
+        let scope = SupervisorState::local_scope(worktree_path.as_ref());
+        let mut stats = get_diff_stats(worktree_path.as_ref(), &range, scope.as_deref())?;
+        let ignore = IgnoreRules::load(worktree_path.as_ref());
+        let ignored = stats.retain_files(|f| !ignore.is_ignored(Path::new(&f.path), false));
         let mut list_state = ListState::default();
         if !stats.files.is_empty() {
             list_state.select(Some(0));
@@ -45,6 +92,9 @@ impl DiffView {
             full_diff: None,
             show_full: false,
             scroll: 0,
+            scope,
+            ignored,
+            side_by_side: SideBySide::default(),
         })
     }
 
@@ -70,12 +120,23 @@ impl DiffView {
         self.list_state.select(Some(self.selected));
     }
 
-    /// Toggle between file list and full diff view
-    pub fn toggle_full_diff(&mut self) -> Result<()> {
+    /// Load the full diff if it hasn't been loaded yet
+    pub fn load_full_diff(&mut self) -> Result<()> {
         if self.full_diff.is_none() {
-            // Load the full diff on first toggle
-            self.full_diff = Some(get_full_diff(&self.worktree_path, &self.range)?);
+            self.full_diff = Some(if self.ignored > 0 {
+                let files: Vec<&str> = self.stats.files.iter().map(|f| f.path.as_str()).collect();
+                get_full_diff_for_files(&self.worktree_path, &self.range, &files)?
+            } else {
+                get_full_diff(&self.worktree_path, &self.range, self.scope.as_deref())?
+            });
         }
+        Ok(())
+    }
+
+    /// Toggle between file list and full diff view
+    pub fn toggle_full_diff(&mut self) -> Result<()> {
+        // Load the full diff on first toggle
+        self.load_full_diff()?;
         self.show_full = !self.show_full;
         Ok(())
     }
@@ -130,34 +191,43 @@ impl DiffView {
                     .borders(Borders::ALL)
                     .title(self.title.clone()),
             )
-            .highlight_style(
-                Style::default()
-                    .bg(Color::DarkGray)
-                    .add_modifier(Modifier::BOLD),
-            );
+            .highlight_style(theme::current().selection().add_modifier(Modifier::BOLD));
 
         frame.render_stateful_widget(file_list, chunks[0], &mut self.list_state);
 
         // Render summary footer
-        let summary_text = format!(
+        let mut summary_text = format!(
             "{} files changed, +{} insertions, -{} deletions",
             self.stats.files_changed, self.stats.total_insertions, self.stats.total_deletions
         );
+        if self.ignored > 0 {
+            summary_text.push_str(&format!(" ({} ignored)", self.ignored));
+        }
 
         let summary = Paragraph::new(Line::from(vec![Span::styled(
             summary_text,
-            Style::default().fg(Color::Cyan),
+            Style::default().fg(theme::current().accent),
         )]))
         .block(Block::default().borders(Borders::ALL).title("Summary"));
 
         frame.render_widget(summary, chunks[1]);
     }
 
-    /// Render full diff mode
+    /// Render full diff mode with syntax and word-level highlighting
     fn render_full_diff(&self, frame: &mut Frame, area: Rect) {
-        let diff_text = self.full_diff.as_deref().unwrap_or("Loading...");
+        if self.side_by_side.enabled {
+            if let Some(patch) = self.full_diff.as_deref() {
+                self.render_side_by_side(frame, area, patch);
+                return;
+            }
+        }
 
-        let paragraph = Paragraph::new(diff_text)
+        let lines = match self.full_diff.as_deref() {
+            Some(patch) => highlight_patch_cached(patch),
+            None => vec![Line::from("Loading...")],
+        };
+
+        let paragraph = Paragraph::new(lines)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
@@ -168,6 +238,38 @@ impl DiffView {
 
         frame.render_widget(paragraph, area);
     }
+
+    /// Render old and new columns with shared vertical and independent
+    /// horizontal scrolling
+    fn render_side_by_side(&self, frame: &mut Frame, area: Rect, patch: &str) {
+        let (left, right) = side_by_side_cached(patch);
+        let columns = Layout::default()
+            .direction(ratatui::layout::Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+
+        let sbs = self.side_by_side;
+        let panes = [
+            (left, "Old", sbs.left_scroll, !sbs.focus_right),
+            (right, "New", sbs.right_scroll, sbs.focus_right),
+        ];
+        for ((lines, label, hscroll, focused), column) in panes.into_iter().zip(columns.iter()) {
+            let border = if focused {
+                theme::current().focused_border
+            } else {
+                theme::current().border
+            };
+            let paragraph = Paragraph::new(lines)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(border))
+                        .title(format!("{} ({})", self.title, label)),
+                )
+                .scroll((self.scroll, hscroll));
+            frame.render_widget(paragraph, *column);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,81 +1,50 @@
-//! Help modal view showing keybindings
+//! Help modal showing the keybindings for the current view
 
 use crate::overlay::centered_rect;
+use crate::theme;
+use crate::tui::app::App;
+use crate::tui::keymap::KeyContext;
 use ratatui::{
     layout::Alignment,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
-/// Get the help text lines for testing
-fn get_help_text() -> Vec<Line<'static>> {
-    vec![
-        Line::from(vec![Span::styled(
-            "Orchestration List:",
-            Style::default().add_modifier(Modifier::BOLD),
-        )]),
-        Line::from("  j / k / Down / Up    Navigate up/down"),
-        Line::from("  Enter                Expand orchestration details"),
-        Line::from("  g                    Open terminal at worktree (goto)"),
-        Line::from("  p                    View current phase plan"),
-        Line::from("  r                    Refresh data"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Phase Detail:",
-            Style::default().add_modifier(Modifier::BOLD),
-        )]),
-        Line::from("  t / Left             Focus tasks pane"),
-        Line::from("  m / Right            Focus team members pane"),
-        Line::from("  j / k                Navigate within focused pane"),
-        Line::from("  Enter                Open task inspector (when task focused)"),
-        Line::from("  l                    View agent logs (when member focused)"),
-        Line::from("  a                    Attach to agent's tmux pane (when member focused)"),
-        Line::from("  s                    Open send dialog (when member focused)"),
-        Line::from("  c                    View commits for current phase"),
-        Line::from("  d                    View diff stats for current phase"),
-        Line::from("  Esc                  Return to orchestration list"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Task Inspector:",
-            Style::default().add_modifier(Modifier::BOLD),
-        )]),
-        Line::from("  Esc / Enter          Close inspector"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Log Viewer:",
-            Style::default().add_modifier(Modifier::BOLD),
-        )]),
-        Line::from("  f                    Toggle follow mode"),
-        Line::from("  G                    Jump to bottom"),
-        Line::from("  PgUp / PgDn          Scroll page up/down"),
-        Line::from("  Esc                  Close log viewer"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Diff Viewer:",
-            Style::default().add_modifier(Modifier::BOLD),
-        )]),
-        Line::from("  Enter                Toggle full diff view"),
-        Line::from("  Esc                  Close diff viewer"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Global:",
+/// Help lines for `contexts`, followed by the global keys
+fn help_lines(contexts: &[KeyContext]) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for context in contexts.iter().chain([&KeyContext::Global]) {
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(vec![Span::styled(
+            format!("{}:", context.title()),
             Style::default().add_modifier(Modifier::BOLD),
-        )]),
-        Line::from("  ?                    Toggle this help"),
-        Line::from("  q / Ctrl+C           Quit"),
-    ]
+        )]));
+        for binding in context.bindings() {
+            lines.push(Line::from(format!(
+                "  {:<20} {}",
+                binding.label(),
+                binding.action()
+            )));
+        }
+        if *context == KeyContext::MembersPane {
+            lines.push(Line::from("  (● active, ◌ quiet 10m+, ✗ tmux pane gone)"));
+        }
+    }
+    lines
 }
 
-/// Render the help modal
-pub fn render_help(frame: &mut Frame) {
+/// Render the help modal with the keys for the current view and focus
+pub fn render_help(frame: &mut Frame, app: &App) {
     let area = centered_rect(60, 60, frame.area());
 
     // Clear the area first
     frame.render_widget(Clear, area);
 
-    let help_text = get_help_text();
+    let help_text = help_lines(&KeyContext::active(app));
 
     let help = Paragraph::new(help_text)
         .block(
@@ -84,7 +53,7 @@ pub fn render_help(frame: &mut Frame) {
                 .title(" Help ")
                 .title_alignment(Alignment::Center),
         )
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(theme::current().text));
 
     frame.render_widget(help, area);
 }
@@ -92,15 +61,30 @@ pub fn render_help(frame: &mut Frame) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tui::{PaneFocus, PhaseDetailLayout, ViewState};
     use ratatui::layout::Rect;
     use ratatui::{backend::TestBackend, Terminal};
 
+    /// Help for `contexts` as one searchable string
+    fn help_content(contexts: &[KeyContext]) -> String {
+        help_lines(contexts)
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
     #[test]
     fn test_render_help_does_not_panic() {
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
+        let app = App::new_with_orchestrations(Vec::new());
 
-        let result = terminal.draw(|frame| render_help(frame));
+        let result = terminal.draw(|frame| render_help(frame, &app));
         assert!(result.is_ok(), "Help modal should render without panic");
     }
 
@@ -162,8 +146,9 @@ mod tests {
     fn test_render_help_works_on_small_terminal() {
         let backend = TestBackend::new(40, 15);
         let mut terminal = Terminal::new(backend).unwrap();
+        let app = App::new_with_orchestrations(Vec::new());
 
-        let result = terminal.draw(|frame| render_help(frame));
+        let result = terminal.draw(|frame| render_help(frame, &app));
         assert!(result.is_ok(), "Help modal should render on small terminal");
     }
 
@@ -171,8 +156,9 @@ mod tests {
     fn test_help_modal_renders_all_sections() {
         let backend = TestBackend::new(80, 40);
         let mut terminal = Terminal::new(backend).unwrap();
+        let app = App::new_with_orchestrations(Vec::new());
 
-        let result = terminal.draw(|frame| render_help(frame));
+        let result = terminal.draw(|frame| render_help(frame, &app));
         assert!(
             result.is_ok(),
             "Help modal should render all sections without panic"
@@ -185,8 +171,9 @@ mod tests {
         for (width, height) in [(80, 24), (120, 40), (100, 30)] {
             let backend = TestBackend::new(width, height);
             let mut terminal = Terminal::new(backend).unwrap();
+            let app = App::new_with_orchestrations(Vec::new());
 
-            let result = terminal.draw(|frame| render_help(frame));
+            let result = terminal.draw(|frame| render_help(frame, &app));
             assert!(
                 result.is_ok(),
                 "Help modal should fit in terminal of size {}x{}",
@@ -198,18 +185,7 @@ mod tests {
 
     #[test]
     fn test_help_modal_contains_phase6_keybindings() {
-        let help_text = get_help_text();
-
-        // Convert help text to searchable content
-        let content = help_text
-            .iter()
-            .map(|line| {
-                line.spans
-                    .iter()
-                    .map(|span| span.content.as_ref())
-                    .collect::<String>()
-            })
-            .collect::<String>();
+        let content = help_content(&[KeyContext::PhaseDetail]);
 
         // Verify Phase 6 keybindings are documented
         assert!(
@@ -232,17 +208,7 @@ mod tests {
 
     #[test]
     fn test_help_modal_contains_log_viewer_keybindings() {
-        let help_text = get_help_text();
-
-        let content = help_text
-            .iter()
-            .map(|line| {
-                line.spans
-                    .iter()
-                    .map(|span| span.content.as_ref())
-                    .collect::<String>()
-            })
-            .collect::<String>();
+        let content = help_content(&[KeyContext::LogViewer]);
 
         // Verify Log Viewer keybindings
         assert!(content.contains("f"), "Help should document 'f' key");
@@ -261,17 +227,7 @@ mod tests {
 
     #[test]
     fn test_help_modal_contains_diff_viewer_section() {
-        let help_text = get_help_text();
-
-        let content = help_text
-            .iter()
-            .map(|line| {
-                line.spans
-                    .iter()
-                    .map(|span| span.content.as_ref())
-                    .collect::<String>()
-            })
-            .collect::<String>();
+        let content = help_content(&[KeyContext::Diff]);
 
         // Verify Diff Viewer section exists with keybindings
         assert!(
@@ -290,17 +246,7 @@ mod tests {
 
     #[test]
     fn test_help_modal_contains_send_dialog_keybinding() {
-        let help_text = get_help_text();
-
-        let content = help_text
-            .iter()
-            .map(|line| {
-                line.spans
-                    .iter()
-                    .map(|span| span.content.as_ref())
-                    .collect::<String>()
-            })
-            .collect::<String>();
+        let content = help_content(&[KeyContext::MembersPane, KeyContext::PhaseDetail]);
 
         // Verify 's' key for send dialog is documented
         assert!(
@@ -308,8 +254,29 @@ mod tests {
             "Help should document 's' key for send dialog"
         );
         assert!(
-            content.contains("when member focused"),
-            "Help should indicate send dialog requires member focus"
+            content.contains("Team Members Pane"),
+            "Help should show the send dialog under the members pane"
         );
     }
+
+    #[test]
+    fn test_help_shows_only_the_focused_view() {
+        let mut app = App::new_with_orchestrations(Vec::new());
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Tasks,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::TasksDetail,
+            selected_phase: 1,
+        };
+
+        let content = help_content(&KeyContext::active(&app));
+        assert!(content.contains("Tasks Pane:"));
+        assert!(content.contains("Mark/unmark task for bulk actions"));
+        assert!(content.contains("Phase Detail:"));
+        assert!(content.contains("Global:"));
+        assert!(!content.contains("Team Members Pane"));
+        assert!(!content.contains("Log Viewer"));
+        assert!(!content.contains("Orchestration List"));
+    }
 }
@@ -2,19 +2,77 @@
 
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
+use regex::{Regex, RegexBuilder};
+use std::ops::Range;
 use std::time::{Duration, Instant};
 
 use crate::overlay::centered_rect;
+use crate::theme;
 use crate::tmux::capture::capture_pane_content;
+use crate::tmux::PaneStream;
 use crate::tui::app::{App, ViewState};
 
 /// Number of log lines to capture from tmux pane
 pub const LOG_LINES: usize = 100;
+/// Lines kept in memory when streaming the pane's full output
+pub const MAX_STREAM_LINES: usize = 10_000;
+/// Tailing the stream file is cheap, so poll it more often than tmux
+const STREAM_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A committed `/pattern` search and its matches in the captured lines
+pub struct LogSearch {
+    pub pattern: String,
+    regex: Regex,
+    /// Every match as (line index, byte range), in reading order
+    pub matches: Vec<(usize, Range<usize>)>,
+    /// Index into `matches` of the current match
+    pub current: usize,
+}
+
+impl LogSearch {
+    /// Compile `pattern` as a regex, case-insensitive unless it contains
+    /// an uppercase letter
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        let regex = RegexBuilder::new(pattern)
+            .case_insensitive(!pattern.chars().any(char::is_uppercase))
+            .build()?;
+        Ok(Self {
+            pattern: pattern.to_string(),
+            regex,
+            matches: Vec::new(),
+            current: 0,
+        })
+    }
+
+    /// Recompute matches against `lines`, keeping the current index in range
+    fn update(&mut self, lines: &[String]) {
+        self.matches = lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, line)| {
+                self.regex
+                    .find_iter(line)
+                    .filter(|m| !m.is_empty())
+                    .map(move |m| (i, m.range()))
+            })
+            .collect();
+        self.current = self.current.min(self.matches.len().saturating_sub(1));
+    }
+
+    /// "3/17" counter for the footer
+    pub fn counter(&self) -> String {
+        if self.matches.is_empty() {
+            "0/0".to_string()
+        } else {
+            format!("{}/{}", self.current + 1, self.matches.len())
+        }
+    }
+}
 
 /// Log viewer with follow mode and auto-refresh
 pub struct LogViewer {
@@ -34,6 +92,19 @@ pub struct LogViewer {
     pub total_lines: usize,
     /// Scroll offset
     pub scroll_offset: usize,
+    /// `pipe-pane` stream; `None` until started or when falling back to
+    /// polling `capture-pane`
+    stream: Option<PaneStream>,
+    /// Set once streaming fails so we don't retry it every tick
+    stream_failed: bool,
+    /// Pattern being typed after `/`; `None` when not editing
+    pub search_input: Option<String>,
+    /// Committed search, re-run as new lines arrive
+    pub search: Option<LogSearch>,
+    /// Why the last pattern failed to compile
+    pub search_error: Option<String>,
+    /// Content rows shown at the last render, for centering matches
+    visible_height: usize,
 }
 
 impl LogViewer {
@@ -48,9 +119,20 @@ impl LogViewer {
             lines: Vec::new(),
             total_lines: 0,
             scroll_offset: 0,
+            stream: None,
+            stream_failed: false,
+            search_input: None,
+            search: None,
+            search_error: None,
+            visible_height: 0,
         }
     }
 
+    /// Whether output is streamed via `pipe-pane` rather than polled
+    pub fn is_streaming(&self) -> bool {
+        self.stream.is_some()
+    }
+
     /// Check if refresh is needed based on poll interval
     pub fn maybe_refresh(&mut self) -> bool {
         let elapsed = self.last_refresh.elapsed();
@@ -58,11 +140,38 @@ impl LogViewer {
     }
 
     /// Force refresh captured content
+    ///
+    /// Prefers streaming the pane's output, which keeps everything printed
+    /// since the viewer opened; falls back to polling the last `LOG_LINES`.
     pub fn refresh(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        let capture = capture_pane_content(&self.pane_id, LOG_LINES)?;
-        self.lines = capture.lines;
-        self.total_lines = capture.total_lines;
+        if self.stream.is_none() && !self.stream_failed {
+            match PaneStream::start(&self.pane_id) {
+                Ok(stream) => {
+                    self.stream = Some(stream);
+                    self.lines.clear();
+                    self.poll_interval = STREAM_POLL_INTERVAL;
+                }
+                Err(_) => self.stream_failed = true,
+            }
+        }
+
+        let streamed = match &mut self.stream {
+            Some(stream) => stream.read_new_lines().ok(),
+            None => None,
+        };
+        match streamed {
+            Some(new_lines) => self.append_lines(new_lines),
+            None => {
+                self.stream = None;
+                let capture = capture_pane_content(&self.pane_id, LOG_LINES)?;
+                self.lines = capture.lines;
+                self.total_lines = capture.total_lines;
+            }
+        }
         self.last_refresh = Instant::now();
+        if let Some(search) = &mut self.search {
+            search.update(&self.lines);
+        }
 
         // If in follow mode, scroll to bottom
         if self.follow_mode {
@@ -72,6 +181,17 @@ impl LogViewer {
         Ok(())
     }
 
+    /// Append streamed lines, dropping the oldest beyond `MAX_STREAM_LINES`
+    fn append_lines(&mut self, new_lines: Vec<String>) {
+        self.lines.extend(new_lines);
+        let excess = self.lines.len().saturating_sub(MAX_STREAM_LINES);
+        if excess > 0 {
+            self.lines.drain(..excess);
+            self.scroll_offset = self.scroll_offset.saturating_sub(excess);
+        }
+        self.total_lines = self.lines.len();
+    }
+
     /// Toggle follow mode
     pub fn toggle_follow(&mut self) {
         self.follow_mode = !self.follow_mode;
@@ -94,6 +214,160 @@ impl LogViewer {
         self.scroll_offset = (self.scroll_offset + n).min(self.lines.len());
     }
 
+    /// Start typing a search pattern
+    pub fn start_search(&mut self) {
+        self.search_input = Some(String::new());
+        self.search_error = None;
+    }
+
+    /// Abandon the pattern being typed, keeping any committed search
+    pub fn cancel_search_input(&mut self) {
+        self.search_input = None;
+    }
+
+    /// Compile the typed pattern and jump to its first match at or after
+    /// the bottom of the view. An empty pattern clears the search.
+    pub fn commit_search(&mut self) {
+        let Some(pattern) = self.search_input.take() else {
+            return;
+        };
+        if pattern.is_empty() {
+            self.clear_search();
+            return;
+        }
+        match LogSearch::new(&pattern) {
+            Ok(mut search) => {
+                search.update(&self.lines);
+                let top = self.scroll_offset.saturating_sub(self.visible_height);
+                search.current = search
+                    .matches
+                    .iter()
+                    .position(|(line, _)| *line >= top)
+                    .unwrap_or(0);
+                self.search = Some(search);
+                self.search_error = None;
+                self.reveal_current_match();
+            }
+            Err(e) => {
+                self.search = None;
+                self.search_error = Some(e.to_string());
+            }
+        }
+    }
+
+    /// Drop the committed search and its highlighting
+    pub fn clear_search(&mut self) {
+        self.search = None;
+        self.search_error = None;
+    }
+
+    /// Move to the next match, wrapping to the first
+    pub fn next_match(&mut self) {
+        if let Some(search) = &mut self.search {
+            if !search.matches.is_empty() {
+                search.current = (search.current + 1) % search.matches.len();
+            }
+        }
+        self.reveal_current_match();
+    }
+
+    /// Move to the previous match, wrapping to the last
+    pub fn previous_match(&mut self) {
+        if let Some(search) = &mut self.search {
+            if !search.matches.is_empty() {
+                search.current = (search.current + search.matches.len() - 1) % search.matches.len();
+            }
+        }
+        self.reveal_current_match();
+    }
+
+    /// Scroll so the current match sits mid-view, leaving follow mode
+    fn reveal_current_match(&mut self) {
+        let Some(line) = self
+            .search
+            .as_ref()
+            .and_then(|search| search.matches.get(search.current))
+            .map(|(line, _)| *line)
+        else {
+            return;
+        };
+        let half = self.visible_height / 2;
+        self.scroll_offset = (line + 1 + half)
+            .max(self.visible_height)
+            .min(self.lines.len());
+        self.follow_mode = false;
+    }
+
+    /// A captured line with search matches highlighted
+    fn highlighted_line(&self, index: usize) -> Line<'_> {
+        let text = self.lines[index].as_str();
+        let Some(search) = &self.search else {
+            return Line::from(text);
+        };
+        let theme = theme::current();
+        let mut spans = Vec::new();
+        let mut pos = 0;
+        for (i, (_, range)) in search
+            .matches
+            .iter()
+            .enumerate()
+            .filter(|(_, (line, _))| *line == index)
+        {
+            spans.push(Span::raw(&text[pos..range.start]));
+            let style = if i == search.current {
+                Style::default()
+                    .fg(theme.warning)
+                    .add_modifier(Modifier::REVERSED | Modifier::BOLD)
+            } else {
+                Style::default().add_modifier(Modifier::REVERSED)
+            };
+            spans.push(Span::styled(&text[range.clone()], style));
+            pos = range.end;
+        }
+        spans.push(Span::raw(&text[pos..]));
+        Line::from(spans)
+    }
+
+    /// Footer content: the search prompt, search status, or key hints
+    fn footer_line(&self) -> Line<'_> {
+        let theme = theme::current();
+        if let Some(input) = &self.search_input {
+            return Line::from(vec![
+                Span::styled("/", Style::default().fg(theme.highlight)),
+                Span::styled(input.as_str(), Style::default().fg(theme.text)),
+                Span::styled("█", Style::default().fg(theme.muted)),
+            ]);
+        }
+        if let Some(error) = &self.search_error {
+            return Line::from(Span::styled(
+                format!("Invalid pattern: {}", error),
+                Style::default().fg(theme.error),
+            ));
+        }
+        if let Some(search) = &self.search {
+            return Line::from(vec![
+                Span::styled(
+                    format!("/{} ", search.pattern),
+                    Style::default().fg(theme.highlight),
+                ),
+                Span::styled(
+                    format!("[{}]", search.counter()),
+                    Style::default().fg(theme.text),
+                ),
+                Span::styled(
+                    "  [n/N] Next/Prev  [/] New search  [ESC] Clear",
+                    Style::default().fg(theme.muted),
+                ),
+            ]);
+        }
+        let hints = if self.follow_mode {
+            "[j/k] Scroll  [f] Unfollow  [G] Bottom  [/] Search  [w] Save  [ESC] Close"
+        } else {
+            "[j/k] Scroll  [f] Follow  [G] Bottom  [/] Search  [w] Save  [ESC] Close"
+        };
+        Line::from(Span::styled(hints, Style::default().fg(theme.muted)))
+    }
+
     /// Render the log viewer
     pub fn render(&mut self, frame: &mut Frame, area: Rect) {
         // Clear the area first
@@ -107,18 +381,17 @@ impl LogViewer {
 
         // Render log content
         let visible_height = chunks[0].height as usize - 2; // Subtract border
+        self.visible_height = visible_height;
         let start = self.scroll_offset.saturating_sub(visible_height);
         let end = self.scroll_offset.min(self.lines.len());
 
-        let visible_lines: Vec<Line> = self.lines[start..end]
-            .iter()
-            .map(|s| Line::from(s.as_str()))
-            .collect();
+        let visible_lines: Vec<Line> = (start..end).map(|i| self.highlighted_line(i)).collect();
 
+        let live = if self.is_streaming() { " [LIVE]" } else { "" };
         let title = if self.follow_mode {
-            format!(" {} Logs [FOLLOW] ", self.agent_name)
+            format!(" {} Logs{} [FOLLOW] ", self.agent_name, live)
         } else {
-            format!(" {} Logs ", self.agent_name)
+            format!(" {} Logs{} ", self.agent_name, live)
         };
 
         let paragraph = Paragraph::new(visible_lines)
@@ -129,23 +402,14 @@ impl LogViewer {
                     .title_alignment(Alignment::Center),
             )
             .wrap(Wrap { trim: false })
-            .style(Style::default().fg(Color::White));
+            .style(Style::default().fg(theme::current().text));
 
         frame.render_widget(paragraph, chunks[0]);
 
-        // Render footer with keybindings
-        let footer_text = if self.follow_mode {
-            "[j/k] Scroll  [f] Unfollow  [G] Bottom  [PgUp/PgDn] Page  [a] Attach  [ESC] Close"
-        } else {
-            "[j/k] Scroll  [f] Follow  [G] Bottom  [PgUp/PgDn] Page  [a] Attach  [ESC] Close"
-        };
-
-        let footer = Paragraph::new(Line::from(Span::styled(
-            footer_text,
-            Style::default().fg(Color::DarkGray),
-        )))
-        .block(Block::default().borders(Borders::ALL))
-        .alignment(Alignment::Center);
+        // Render footer with search state or keybindings
+        let footer = Paragraph::new(self.footer_line())
+            .block(Block::default().borders(Borders::ALL))
+            .alignment(Alignment::Center);
 
         frame.render_widget(footer, chunks[1]);
     }
@@ -173,7 +437,7 @@ pub fn render(app: &App, frame: &mut Frame) {
         Line::from(""),
         Line::from(Span::styled(
             "[j/k] Scroll  [f] Follow  [a] Attach  [ESC] Close",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme::current().muted),
         )),
     ];
 
@@ -185,7 +449,7 @@ pub fn render(app: &App, frame: &mut Frame) {
                 .title_alignment(Alignment::Center),
         )
         .wrap(Wrap { trim: true })
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(theme::current().text));
 
     frame.render_widget(paragraph, area);
 }
@@ -207,38 +471,17 @@ mod tests {
     use crate::tui::app::{App, ViewState};
     use ratatui::{backend::TestBackend, Terminal};
     use std::time::Duration;
-    use tina_data::OrchestrationListEntry;
+    use tina_data::test_util::list_entry;
 
     #[test]
     fn test_log_viewer_renders_placeholder_when_team_not_loaded() {
         let backend = TestBackend::new(100, 40);
         let mut terminal = Terminal::new(backend).unwrap();
 
-        let entry = OrchestrationListEntry {
-            id: "orch-1".to_string(),
-            node_name: "macbook".to_string(),
-            record: tina_data::OrchestrationRecord {
-                node_id: "node-1".to_string(),
-                project_id: None,
-                feature_name: "test-project".to_string(),
-                spec_doc_path: "design.md".to_string(),
-                branch: "tina/test-project".to_string(),
-                worktree_path: Some("/tmp/test".to_string()),
-                total_phases: 3.0,
-                current_phase: 1.0,
-                status: "idle".to_string(),
-                started_at: "2026-02-07T10:00:00Z".to_string(),
-                completed_at: None,
-                total_elapsed_mins: None,
-                spec_id: None,
-                policy_snapshot: None,
-                policy_snapshot_hash: None,
-                preset_origin: None,
-                spec_only: None,
-                policy_revision: None,
-                updated_at: None,
-            },
-        };
+        let mut entry = list_entry("orch-1", "idle", "2026-02-07T10:00:00Z");
+        entry.record.feature_name = "test-project".to_string();
+        entry.record.branch = "tina/test-project".to_string();
+        entry.record.worktree_path = Some("/tmp/test".to_string());
         let orchestrations = vec![MonitorOrchestration::from_list_entry(entry)];
 
         let mut app = App::new_with_orchestrations(orchestrations);
@@ -434,6 +677,25 @@ mod tests {
         // We can't easily verify this without a valid pane, but the test ensures no panic
     }
 
+    #[test]
+    fn test_refresh_falls_back_when_stream_unavailable() {
+        let mut viewer = LogViewer::new("test-pane".to_string(), "agent-1".to_string());
+        let _ = viewer.refresh();
+        assert!(!viewer.is_streaming());
+        assert_eq!(viewer.poll_interval, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_append_lines_caps_history() {
+        let mut viewer = LogViewer::new("test-pane".to_string(), "agent-1".to_string());
+        viewer.scroll_offset = 10;
+        viewer.append_lines(vec!["line".to_string(); MAX_STREAM_LINES + 5]);
+
+        assert_eq!(viewer.lines.len(), MAX_STREAM_LINES);
+        assert_eq!(viewer.total_lines, MAX_STREAM_LINES);
+        assert_eq!(viewer.scroll_offset, 5, "offset should track dropped lines");
+    }
+
     #[test]
     fn test_follow_mode_scrolls_to_bottom() {
         // Test that follow mode automatically scrolls to bottom
@@ -477,4 +739,124 @@ mod tests {
         );
         assert_eq!(viewer.scroll_offset, 2, "Should scroll up by 1");
     }
+
+    fn viewer_with(lines: &[&str]) -> LogViewer {
+        let mut viewer = LogViewer::new("test-pane".to_string(), "agent-1".to_string());
+        viewer.lines = lines.iter().map(|l| l.to_string()).collect();
+        viewer.scroll_offset = viewer.lines.len();
+        viewer
+    }
+
+    fn search(viewer: &mut LogViewer, pattern: &str) {
+        viewer.start_search();
+        viewer.search_input = Some(pattern.to_string());
+        viewer.commit_search();
+    }
+
+    #[test]
+    fn test_search_smart_case() {
+        let mut viewer = viewer_with(&["Error: a", "error: b", "no problems"]);
+        search(&mut viewer, "error");
+        assert_eq!(viewer.search.as_ref().unwrap().matches.len(), 2);
+
+        search(&mut viewer, "Error");
+        let matches = &viewer.search.as_ref().unwrap().matches;
+        assert_eq!(matches, &vec![(0, 0..5)]);
+    }
+
+    #[test]
+    fn test_search_navigation_wraps_and_counts() {
+        let mut viewer = viewer_with(&["a x x", "b", "c x"]);
+        viewer.visible_height = 2;
+        viewer.scroll_offset = 2;
+        search(&mut viewer, "x");
+        let search_state = viewer.search.as_ref().unwrap();
+        assert_eq!(search_state.matches.len(), 3);
+        assert_eq!(search_state.counter(), "1/3");
+
+        viewer.next_match();
+        viewer.next_match();
+        assert_eq!(viewer.search.as_ref().unwrap().counter(), "3/3");
+        assert_eq!(viewer.scroll_offset, 3, "match line is scrolled into view");
+        viewer.next_match();
+        assert_eq!(viewer.search.as_ref().unwrap().counter(), "1/3");
+        viewer.previous_match();
+        assert_eq!(viewer.search.as_ref().unwrap().counter(), "3/3");
+    }
+
+    #[test]
+    fn test_search_reveal_leaves_follow_mode() {
+        let mut viewer = viewer_with(&["x"; 50]);
+        viewer.follow_mode = true;
+        viewer.visible_height = 10;
+        search(&mut viewer, "x");
+        let search_state = viewer.search.as_ref().unwrap();
+        assert_eq!(
+            search_state.current, 40,
+            "starts at the first visible match"
+        );
+        assert!(!viewer.follow_mode);
+        assert_eq!(viewer.scroll_offset, 46, "match is centered");
+
+        for _ in 0..9 {
+            viewer.next_match();
+        }
+        assert_eq!(viewer.scroll_offset, 50, "last match clamps to the end");
+        viewer.next_match();
+        assert_eq!(
+            viewer.scroll_offset, 10,
+            "first match clamps to a full page"
+        );
+    }
+
+    #[test]
+    fn test_invalid_or_empty_pattern() {
+        let mut viewer = viewer_with(&["line"]);
+        search(&mut viewer, "(");
+        assert!(viewer.search.is_none());
+        assert!(viewer.search_error.is_some());
+
+        search(&mut viewer, "line");
+        assert!(viewer.search.is_some());
+        search(&mut viewer, "");
+        assert!(viewer.search.is_none(), "empty pattern clears the search");
+    }
+
+    #[test]
+    fn test_search_tracks_streamed_lines() {
+        let mut viewer = viewer_with(&["error"]);
+        search(&mut viewer, "error");
+        viewer.append_lines(vec!["another error".to_string()]);
+        if let Some(search) = &mut viewer.search {
+            search.update(&viewer.lines);
+        }
+        assert_eq!(viewer.search.as_ref().unwrap().counter(), "1/2");
+    }
+
+    #[test]
+    fn test_render_shows_prompt_and_counter() {
+        let mut viewer = viewer_with(&["ok", "error here"]);
+        let draw = |viewer: &mut LogViewer| {
+            let mut terminal = Terminal::new(TestBackend::new(100, 12)).unwrap();
+            terminal
+                .draw(|frame| viewer.render(frame, frame.area()))
+                .unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content()
+                .iter()
+                .map(|c| c.symbol())
+                .collect::<String>()
+        };
+
+        viewer.start_search();
+        viewer.search_input = Some("err".to_string());
+        assert!(draw(&mut viewer).contains("/err█"));
+
+        viewer.commit_search();
+        let content = draw(&mut viewer);
+        assert!(content.contains("/err [1/1]"));
+        assert!(content.contains("error here"));
+    }
 }
@@ -0,0 +1,427 @@
+//! Markdown rendering for plans, specs, and task descriptions
+//!
+//! Handles the subset of Markdown our plans actually use: headings, bold and
+//! italic, inline code, bullet and numbered lists, task checkboxes,
+//! blockquotes, rules, and fenced code blocks highlighted by language.
+
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{self, FontStyle, Theme as SyntaxTheme, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+use crate::theme;
+
+fn syntax_set() -> &'static SyntaxSet {
+    static SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn syntax_theme() -> &'static SyntaxTheme {
+    static THEME: OnceLock<SyntaxTheme> = OnceLock::new();
+    THEME.get_or_init(|| {
+        ThemeSet::load_defaults()
+            .themes
+            .remove("base16-eighties.dark")
+            .unwrap_or_default()
+    })
+}
+
+thread_local! {
+    /// Viewers are rebuilt every frame; keep the last rendering
+    static CACHE: RefCell<Option<(String, Vec<Line<'static>>)>> = const { RefCell::new(None) };
+}
+
+/// Render `text` without clipping code, reusing the previous result when it
+/// hasn't changed
+pub fn render_cached(text: &str) -> Vec<Line<'static>> {
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if let Some((cached, lines)) = cache.as_ref() {
+            if cached == text {
+                return lines.clone();
+            }
+        }
+        let lines = render(text, usize::MAX);
+        *cache = Some((text.to_string(), lines.clone()));
+        lines
+    })
+}
+
+/// Render markdown text as styled lines
+///
+/// Code lines longer than `max_width` are truncated; prose is left for the
+/// caller to wrap or clip.
+pub fn render(text: &str, max_width: usize) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut code_block: Option<(String, String)> = None;
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            match code_block.take() {
+                Some((lang, code)) => {
+                    lines.extend(highlight_code(&code, &lang, max_width));
+                    lines.push(muted_line("───".to_string()));
+                }
+                None => {
+                    let lang = trimmed.trim_start_matches('`').trim().to_string();
+                    let label = if lang.is_empty() {
+                        "───".to_string()
+                    } else {
+                        format!("─── {} ───", lang)
+                    };
+                    lines.push(muted_line(label));
+                    code_block = Some((lang, String::new()));
+                }
+            }
+            continue;
+        }
+
+        if let Some((_, code)) = code_block.as_mut() {
+            code.push_str(line);
+            code.push('\n');
+            continue;
+        }
+
+        lines.push(render_block_line(line));
+    }
+
+    // Unclosed code block: still show what we have
+    if let Some((lang, code)) = code_block {
+        lines.extend(highlight_code(&code, &lang, max_width));
+    }
+
+    lines
+}
+
+/// Render a single line outside of a fenced code block
+fn render_block_line(line: &str) -> Line<'static> {
+    let theme = theme::current();
+    let trimmed = line.trim_start();
+    let indent = " ".repeat(line.len() - trimmed.len());
+
+    if let Some((level, heading)) = heading(line) {
+        let style = match level {
+            1 => Style::default().add_modifier(Modifier::BOLD).fg(theme.accent),
+            2 => Style::default().add_modifier(Modifier::BOLD),
+            _ => Style::default().add_modifier(Modifier::UNDERLINED),
+        };
+        return Line::from(Span::styled(heading.to_string(), style));
+    }
+
+    if is_rule(trimmed) {
+        return muted_line("─".repeat(40));
+    }
+
+    if let Some(rest) = trimmed.strip_prefix("> ") {
+        return Line::from(Span::styled(
+            format!("│ {}", rest),
+            Style::default().fg(theme.muted).add_modifier(Modifier::ITALIC),
+        ));
+    }
+
+    if let Some(item) = trimmed
+        .strip_prefix("- ")
+        .or_else(|| trimmed.strip_prefix("* "))
+        .or_else(|| trimmed.strip_prefix("+ "))
+    {
+        if let Some((checked, text)) = checkbox(item) {
+            let (marker, color) = if checked {
+                ("☑ ", theme.success)
+            } else {
+                ("☐ ", theme.muted)
+            };
+            let mut spans = vec![Span::styled(
+                format!("{}  {}", indent, marker),
+                Style::default().fg(color),
+            )];
+            let text_spans = render_inline(text);
+            if checked {
+                spans.extend(text_spans.into_iter().map(|span| {
+                    let style = span
+                        .style
+                        .fg(theme.muted)
+                        .add_modifier(Modifier::CROSSED_OUT);
+                    span.style(style)
+                }));
+            } else {
+                spans.extend(text_spans);
+            }
+            return Line::from(spans);
+        }
+
+        let mut spans = vec![Span::styled(
+            format!("{}  • ", indent),
+            Style::default().fg(theme.highlight),
+        )];
+        spans.extend(render_inline(item));
+        return Line::from(spans);
+    }
+
+    if let Some((number, item)) = ordered_item(trimmed) {
+        let mut spans = vec![Span::styled(
+            format!("{}  {}. ", indent, number),
+            Style::default().fg(theme.highlight),
+        )];
+        spans.extend(render_inline(item));
+        return Line::from(spans);
+    }
+
+    Line::from(render_inline(line))
+}
+
+/// `# Title` → `(1, "Title")`, up to six levels
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    line[level..].strip_prefix(' ').map(|text| (level, text.trim()))
+}
+
+/// `---`, `***`, or `___` on a line of their own
+fn is_rule(line: &str) -> bool {
+    let line = line.trim_end();
+    line.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&c| line.chars().all(|ch| ch == c))
+}
+
+/// `[ ] text` / `[x] text` after a list marker
+fn checkbox(item: &str) -> Option<(bool, &str)> {
+    let rest = item.strip_prefix('[')?;
+    let mut chars = rest.chars();
+    let mark = chars.next()?;
+    let text = chars.as_str().strip_prefix("] ")?;
+    match mark {
+        ' ' => Some((false, text)),
+        'x' | 'X' => Some((true, text)),
+        _ => None,
+    }
+}
+
+/// `12. text` → `("12", "text")`
+fn ordered_item(line: &str) -> Option<(&str, &str)> {
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits == 0 {
+        return None;
+    }
+    let text = line[digits..]
+        .strip_prefix(". ")
+        .or_else(|| line[digits..].strip_prefix(") "))?;
+    Some((&line[..digits], text))
+}
+
+fn muted_line(text: String) -> Line<'static> {
+    Line::from(Span::styled(text, Style::default().fg(theme::current().muted)))
+}
+
+/// Split a line into spans for `code`, **bold**, and *italic* runs
+///
+/// Unclosed markers are kept as literal text.
+pub fn render_inline(line: &str) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = line;
+
+    while let Some(c) = rest.chars().next() {
+        let (marker, style) = match c {
+            '`' => ("`", Style::default().fg(theme::current().accent)),
+            '*' if rest.starts_with("**") => ("**", Style::default().add_modifier(Modifier::BOLD)),
+            '*' | '_' => (
+                &rest[..1],
+                Style::default().add_modifier(Modifier::ITALIC),
+            ),
+            _ => {
+                plain.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+        };
+
+        let body = &rest[marker.len()..];
+        // Intraword underscores (snake_case) and lone markers stay literal
+        let closes = body.find(marker).filter(|&end| {
+            end > 0
+                && !body.starts_with(' ')
+                && !(c == '_' && plain.chars().last().is_some_and(char::is_alphanumeric))
+        });
+        match closes {
+            Some(end) => {
+                if !plain.is_empty() {
+                    spans.push(Span::raw(std::mem::take(&mut plain)));
+                }
+                spans.push(Span::styled(body[..end].to_string(), style));
+                rest = &body[end + marker.len()..];
+            }
+            None => {
+                plain.push_str(marker);
+                rest = body;
+            }
+        }
+    }
+
+    if !plain.is_empty() {
+        spans.push(Span::raw(plain));
+    }
+    spans
+}
+
+/// Highlight a fenced code block by its language tag
+fn highlight_code(code: &str, lang: &str, max_width: usize) -> Vec<Line<'static>> {
+    let ps = syntax_set();
+    let syntax = ps
+        .find_syntax_by_token(lang)
+        .or_else(|| ps.find_syntax_by_extension(lang))
+        .unwrap_or_else(|| ps.find_syntax_plain_text());
+    let mut highlighter = HighlightLines::new(syntax, syntax_theme());
+
+    LinesWithEndings::from(code)
+        .map(|line| {
+            let Ok(highlighted) = highlighter.highlight_line(line, ps) else {
+                return Line::from(Span::styled(
+                    truncate(line.trim_end(), max_width),
+                    Style::default().fg(theme::current().accent),
+                ));
+            };
+            let mut width = 0;
+            let spans: Vec<Span<'static>> = highlighted
+                .into_iter()
+                .filter_map(|(style, text)| {
+                    let text = text.trim_end_matches('\n');
+                    let remaining = max_width.saturating_sub(width);
+                    if remaining == 0 {
+                        return None;
+                    }
+                    let text: String = text.chars().take(remaining).collect();
+                    width += text.chars().count();
+                    Some(Span::styled(text, to_ratatui_style(style)))
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect()
+}
+
+fn to_ratatui_style(style: highlighting::Style) -> Style {
+    let fg = style.foreground;
+    let mut out = Style::default().fg(Color::Rgb(fg.r, fg.g, fg.b));
+    if style.font_style.contains(FontStyle::BOLD) {
+        out = out.add_modifier(Modifier::BOLD);
+    }
+    if style.font_style.contains(FontStyle::ITALIC) {
+        out = out.add_modifier(Modifier::ITALIC);
+    }
+    if style.font_style.contains(FontStyle::UNDERLINE) {
+        out = out.add_modifier(Modifier::UNDERLINED);
+    }
+    out
+}
+
+fn truncate(s: &str, max_len: usize) -> String {
+    if s.chars().count() > max_len {
+        let kept: String = s.chars().take(max_len.saturating_sub(3)).collect();
+        format!("{}...", kept)
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn headings_drop_hashes_and_are_styled() {
+        let lines = render("# Plan\n## Phase 1\n#### Detail", 80);
+        assert_eq!(text(&lines[0]), "Plan");
+        assert!(lines[0].spans[0].style.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(text(&lines[1]), "Phase 1");
+        assert_eq!(text(&lines[2]), "Detail");
+        assert!(lines[2].spans[0]
+            .style
+            .add_modifier
+            .contains(Modifier::UNDERLINED));
+    }
+
+    #[test]
+    fn hashtag_without_space_is_not_a_heading() {
+        let lines = render("#hashtag", 80);
+        assert_eq!(text(&lines[0]), "#hashtag");
+    }
+
+    #[test]
+    fn inline_bold_italic_and_code() {
+        let spans = render_inline("run **now** with `cargo` and *care*");
+        let styled: Vec<_> = spans
+            .iter()
+            .map(|s| (s.content.as_ref(), s.style.add_modifier))
+            .collect();
+        assert!(styled.contains(&("now", Modifier::BOLD)));
+        assert!(styled.contains(&("care", Modifier::ITALIC)));
+        assert!(spans.iter().any(|s| s.content == "cargo"));
+        let joined: String = spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(joined, "run now with cargo and care");
+    }
+
+    #[test]
+    fn snake_case_and_unclosed_markers_stay_literal() {
+        let joined: String = render_inline("call my_fn_name and 2 * 3")
+            .iter()
+            .map(|s| s.content.to_string())
+            .collect();
+        assert_eq!(joined, "call my_fn_name and 2 * 3");
+    }
+
+    #[test]
+    fn checkboxes_render_as_boxes() {
+        let lines = render("- [ ] todo\n- [x] done", 80);
+        assert_eq!(text(&lines[0]), "  ☐ todo");
+        assert_eq!(text(&lines[1]), "  ☑ done");
+        assert!(lines[1].spans[1]
+            .style
+            .add_modifier
+            .contains(Modifier::CROSSED_OUT));
+    }
+
+    #[test]
+    fn lists_keep_nesting_and_numbers() {
+        let lines = render("- top\n  - nested\n3. third", 80);
+        assert_eq!(text(&lines[0]), "  • top");
+        assert_eq!(text(&lines[1]), "    • nested");
+        assert_eq!(text(&lines[2]), "  3. third");
+    }
+
+    #[test]
+    fn fenced_code_is_labelled_and_highlighted() {
+        let lines = render("```rust\nfn main() {}\n```\nafter", 80);
+        assert_eq!(text(&lines[0]), "─── rust ───");
+        assert_eq!(text(&lines[1]), "fn main() {}");
+        assert!(lines[1].spans.len() > 1, "code should be highlighted");
+        assert_eq!(text(&lines[2]), "───");
+        assert_eq!(text(&lines[3]), "after");
+    }
+
+    #[test]
+    fn code_lines_are_clipped_to_width() {
+        let lines = render("```\nabcdefghij\n```", 4);
+        assert_eq!(text(&lines[1]), "abcd");
+    }
+
+    #[test]
+    fn rules_and_quotes() {
+        let lines = render("---\n> note", 80);
+        assert!(text(&lines[0]).starts_with("───"));
+        assert_eq!(text(&lines[1]), "│ note");
+    }
+}
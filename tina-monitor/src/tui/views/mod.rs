@@ -2,13 +2,31 @@
 //!
 //! Views represent different screens or major UI components in the application.
 
+pub mod bulk_tasks;
 pub mod command_modal;
+pub mod commit_detail;
 pub mod commits_view;
+pub mod confirm_retry_phase;
+pub mod confirm_stop_phase;
+pub mod cost_panel;
+pub mod dashboard;
+pub mod diff_highlight;
 pub mod diff_view;
 pub mod help;
 pub mod log_viewer;
+pub mod markdown;
+pub mod notifications;
 pub mod orchestration_list;
 pub mod phase_detail;
+pub mod phase_timeline;
 pub mod plan_viewer;
+pub mod projects;
+pub mod replay;
 pub mod send_dialog;
+pub mod spec_viewer;
+pub mod status_bar;
+pub mod stuck_tasks;
+pub mod task_edit;
+pub mod task_graph;
 pub mod task_inspector;
+pub mod worktree_status;
@@ -0,0 +1,161 @@
+//! Toast stack and notification history overlay
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::data::changes::ChangeKind;
+use crate::overlay::centered_rect;
+use crate::theme;
+use crate::tui::notifications::Toast;
+use tina_data::humanize::format_time;
+
+/// Widest a toast gets, border included
+const TOAST_WIDTH: u16 = 48;
+
+/// Height of one toast: a single message line plus borders
+const TOAST_HEIGHT: u16 = 3;
+
+fn kind_color(kind: ChangeKind) -> Color {
+    let theme = theme::current();
+    match kind {
+        ChangeKind::PhaseComplete | ChangeKind::OrchestrationComplete => theme.success,
+        ChangeKind::Blocked => theme.error,
+        ChangeKind::GatePending | ChangeKind::Connection => theme.warning,
+        ChangeKind::StatusChanged | ChangeKind::TaskComplete | ChangeKind::NewCommit => theme.info,
+    }
+}
+
+fn kind_icon(kind: ChangeKind) -> &'static str {
+    match kind {
+        ChangeKind::PhaseComplete | ChangeKind::OrchestrationComplete => "✓",
+        ChangeKind::Blocked => "✗",
+        ChangeKind::GatePending => "●",
+        ChangeKind::Connection => "○",
+        ChangeKind::StatusChanged | ChangeKind::TaskComplete | ChangeKind::NewCommit => "·",
+    }
+}
+
+/// Stack `toasts` (newest first) down the top-right corner of `area`
+pub fn render_toasts(frame: &mut Frame, area: Rect, toasts: &[&Toast]) {
+    let width = TOAST_WIDTH.min(area.width);
+    let x = area.x + area.width - width;
+    for (i, toast) in toasts.iter().enumerate() {
+        let y = area.y + i as u16 * TOAST_HEIGHT;
+        if y + TOAST_HEIGHT > area.y + area.height {
+            break;
+        }
+        let rect = Rect::new(x, y, width, TOAST_HEIGHT);
+        let color = kind_color(toast.kind);
+        let line = Line::from(vec![
+            Span::styled(
+                format!("{} ", kind_icon(toast.kind)),
+                Style::default().fg(color),
+            ),
+            Span::raw(toast.message.as_str()),
+        ]);
+        let paragraph = Paragraph::new(line)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(color)),
+            )
+            .style(Style::default().fg(theme::current().text));
+        frame.render_widget(Clear, rect);
+        frame.render_widget(paragraph, rect);
+    }
+}
+
+/// Render every notification raised this session, newest first
+pub fn render_history(frame: &mut Frame, history: &[Toast]) {
+    let area = centered_rect(60, 60, frame.area());
+    frame.render_widget(Clear, area);
+
+    let lines: Vec<Line> = if history.is_empty() {
+        vec![Line::from(Span::styled(
+            "No notifications yet",
+            Style::default().fg(theme::current().muted),
+        ))]
+    } else {
+        history
+            .iter()
+            .rev()
+            .map(|toast| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{}  ", format_time(toast.time, "%H:%M:%S")),
+                        Style::default().fg(theme::current().muted),
+                    ),
+                    Span::styled(
+                        format!("{} ", kind_icon(toast.kind)),
+                        Style::default().fg(kind_color(toast.kind)),
+                    ),
+                    Span::raw(toast.message.as_str()),
+                ])
+            })
+            .collect()
+    };
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Notifications (N/Esc to close) ")
+                .title_alignment(Alignment::Center),
+        )
+        .style(Style::default().fg(theme::current().text))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::notifications::Notifications;
+    use ratatui::{backend::TestBackend, Terminal};
+    use std::time::Instant;
+
+    fn buffer_text(terminal: &Terminal<TestBackend>) -> String {
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn toasts_render_in_top_right_corner() {
+        let mut notifications = Notifications::default();
+        notifications.push(ChangeKind::Blocked, "auth: blocked in phase 2".to_string());
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|frame| {
+                let visible = notifications.visible(Instant::now());
+                render_toasts(frame, frame.area(), &visible);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(100 - TOAST_WIDTH, 0)].symbol(), "┌");
+        assert!(buffer_text(&terminal).contains("auth: blocked in phase 2"));
+    }
+
+    #[test]
+    fn history_lists_notifications() {
+        let mut notifications = Notifications::default();
+        notifications.push(ChangeKind::GatePending, "auth: plan gate".to_string());
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal
+            .draw(|frame| render_history(frame, notifications.history()))
+            .unwrap();
+        assert!(buffer_text(&terminal).contains("auth: plan gate"));
+    }
+}
@@ -1,42 +1,113 @@
 //! Orchestration list view
 //!
-//! Displays a list of orchestrations with their current status.
+//! Displays a list of orchestrations with their current status, in the
+//! sort order and columns chosen in the `[list]` config section, split into
+//! collapsible project sections.
 
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::ops::Range;
+
+use chrono::{DateTime, Utc};
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{List, ListItem, ListState},
     Frame,
 };
 
+use crate::config::{ListColumn, ListSort};
+use crate::data::{MonitorOrchestration, MonitorOrchestrationStatus};
+use crate::theme;
 use crate::tui::app::App;
 use crate::tui::widgets::{progress_bar, status_indicator};
+use tina_data::humanize::{format_duration_mins, format_relative};
+
+/// A line of the orchestration list
+#[derive(Debug, Clone, PartialEq)]
+pub enum ListRow {
+    /// Header of the project section spanning `range` of the orchestrations
+    Project {
+        project_id: Option<String>,
+        range: Range<usize>,
+    },
+    /// The orchestration at this index
+    Orchestration(usize),
+}
+
+/// Whether the list is split into project sections: grouping is enabled
+/// and at least one orchestration belongs to a project
+pub fn is_grouped(app: &App) -> bool {
+    app.list.group_by_project
+        && app
+            .orchestrations
+            .iter()
+            .any(|orch| orch.project_id.is_some())
+}
+
+/// The list's lines: every orchestration, under project headers when
+/// grouped, skipping those in collapsed sections
+pub fn rows(app: &App) -> Vec<ListRow> {
+    if !is_grouped(app) {
+        return (0..app.orchestrations.len())
+            .map(ListRow::Orchestration)
+            .collect();
+    }
+    let mut rows = Vec::new();
+    let mut start = 0;
+    while start < app.orchestrations.len() {
+        let range = app.project_section(start);
+        let project_id = app.orchestrations[start].project_id.clone();
+        let collapsed = app.collapsed_projects.contains(&project_id);
+        rows.push(ListRow::Project {
+            project_id,
+            range: range.clone(),
+        });
+        if !collapsed {
+            rows.extend(range.clone().map(ListRow::Orchestration));
+        }
+        start = range.end;
+    }
+    rows
+}
 
 /// Render the orchestration list view
 pub fn render_orchestration_list(frame: &mut Frame, area: Rect, app: &App) {
-    let items: Vec<ListItem> = app
-        .orchestrations
+    let now = Utc::now();
+    let rows = rows(app);
+    let selected = rows.iter().position(|row| match row {
+        ListRow::Orchestration(index) => *index == app.selected_index,
+        ListRow::Project { project_id, range } => {
+            range.contains(&app.selected_index) && app.collapsed_projects.contains(project_id)
+        }
+    });
+    let items: Vec<ListItem> = rows
         .iter()
-        .map(|orch| {
-            let name = truncate_name(&orch.team_name(), 25);
-            let path = shorten_path(&orch.worktree_path, 30);
-            let phase = format!("{}/{}", orch.current_phase, orch.total_phases);
-            let progress = progress_bar::render(orch.tasks_completed(), orch.tasks_total(), 10);
-            let status = status_indicator::render(&orch.status);
+        .map(|row| {
+            let orch = match row {
+                ListRow::Orchestration(index) => &app.orchestrations[*index],
+                ListRow::Project { project_id, range } => {
+                    return project_header(app, project_id, range.clone())
+                }
+            };
+            let mut spans: Vec<Span> = app
+                .list
+                .columns
+                .iter()
+                .flat_map(|column| column_spans(orch, *column, now))
+                .collect();
 
-            let line = Line::from(vec![
-                Span::styled(format!("{:<25} ", name), Style::default()),
-                Span::styled(
-                    format!("{:<30} ", path),
-                    Style::default().fg(Color::DarkGray),
-                ),
-                Span::styled(format!("{:<5} ", phase), Style::default()),
-                Span::raw(progress),
-                Span::raw("  "),
-                status,
-            ]);
-            ListItem::new(line)
+            let stuck = orch.stuck_tasks(app.stuck_tasks.threshold_mins, now).len();
+            if stuck > 0 {
+                spans.push(Span::styled(
+                    format!("  {} stuck", stuck),
+                    Style::default()
+                        .fg(theme::current().error)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -49,11 +120,167 @@ pub fn render_orchestration_list(frame: &mut Frame, area: Rect, app: &App) {
         .highlight_symbol("> ");
 
     let mut state = ListState::default();
-    state.select(Some(app.selected_index));
+    state.select(selected);
 
     frame.render_stateful_widget(list, area, &mut state);
 }
 
+/// A project section header: fold marker, name, and how many of its
+/// orchestrations are in each status
+fn project_header(
+    app: &App,
+    project_id: &Option<String>,
+    range: Range<usize>,
+) -> ListItem<'static> {
+    let theme = theme::current();
+    let collapsed = app.collapsed_projects.contains(project_id);
+    let name = match project_id {
+        Some(id) => app
+            .list_paging
+            .project_names
+            .get(id)
+            .cloned()
+            .unwrap_or_else(|| id.clone()),
+        None => "No project".to_string(),
+    };
+
+    let mut spans = vec![
+        Span::styled(
+            format!("{} {} ", if collapsed { "▸" } else { "▾" }, name),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("({})", range.len()),
+            Style::default().fg(theme.muted),
+        ),
+    ];
+    spans.extend(status_counts(&app.orchestrations[range]));
+    ListItem::new(Line::from(spans))
+}
+
+/// How many of `orchestrations` are in each status, most urgent first,
+/// e.g. "  1 blocked · 2 executing"
+pub(crate) fn status_counts<'a>(
+    orchestrations: impl IntoIterator<Item = &'a MonitorOrchestration>,
+) -> Vec<Span<'static>> {
+    let mut statuses: Vec<&MonitorOrchestrationStatus> = orchestrations
+        .into_iter()
+        .map(|orch| &orch.status)
+        .collect();
+    statuses.sort_by_key(|status| status_rank(status));
+
+    let mut spans = Vec::new();
+    for (i, chunk) in statuses.chunk_by(|a, b| a == b).enumerate() {
+        let label = status_indicator::render(chunk[0]);
+        spans.push(Span::raw(if i == 0 { "  " } else { " · " }));
+        spans.push(Span::styled(
+            format!("{} {}", chunk.len(), label.content.to_lowercase()),
+            label.style,
+        ));
+    }
+    spans
+}
+
+/// Stable-sort orchestrations into project sections, ordered by project
+/// name with orchestrations outside any project last
+pub fn group_by_project(
+    orchestrations: &mut [MonitorOrchestration],
+    project_names: &HashMap<String, String>,
+) {
+    orchestrations.sort_by_cached_key(|orch| match &orch.project_id {
+        Some(id) => (
+            false,
+            project_names.get(id).unwrap_or(id).to_lowercase(),
+            id.clone(),
+        ),
+        None => (true, String::new(), String::new()),
+    });
+}
+
+/// The spans for one column of an orchestration's row, with trailing
+/// padding
+fn column_spans(
+    orch: &MonitorOrchestration,
+    column: ListColumn,
+    now: DateTime<Utc>,
+) -> Vec<Span<'static>> {
+    let muted = Style::default().fg(theme::current().muted);
+    match column {
+        ListColumn::Name => vec![Span::raw(format!(
+            "{:<25} ",
+            truncate_name(&orch.team_name(), 25)
+        ))],
+        ListColumn::Path => vec![Span::styled(
+            format!("{:<30} ", shorten_path(&orch.worktree_path, 30)),
+            muted,
+        )],
+        ListColumn::Phase => vec![Span::raw(format!(
+            "{:<5} ",
+            format!("{}/{}", orch.current_phase, orch.total_phases)
+        ))],
+        ListColumn::Progress => vec![
+            Span::raw(progress_bar::render(
+                orch.tasks_completed(),
+                orch.tasks_total(),
+                10,
+            )),
+            Span::raw("  "),
+        ],
+        ListColumn::Status => {
+            let status = status_indicator::render(&orch.status);
+            let pad = 10usize.saturating_sub(status.content.chars().count());
+            vec![status, Span::raw(" ".repeat(pad))]
+        }
+        ListColumn::Elapsed => {
+            let elapsed = orch
+                .elapsed_mins(now)
+                .map(format_duration_mins)
+                .unwrap_or_default();
+            vec![Span::raw(format!("{:<8} ", elapsed))]
+        }
+        ListColumn::Activity => {
+            let activity = orch
+                .last_activity()
+                .map(|at| format_relative(at, now))
+                .unwrap_or_default();
+            vec![Span::styled(format!("{:<16} ", activity), muted)]
+        }
+    }
+}
+
+/// Sort orchestrations in place by `sort`; ties keep their current order
+pub fn sort(orchestrations: &mut [MonitorOrchestration], sort: ListSort, now: DateTime<Utc>) {
+    match sort {
+        ListSort::Started => orchestrations.sort_by(|a, b| b.started_at.cmp(&a.started_at)),
+        ListSort::Elapsed => {
+            orchestrations.sort_by_key(|orch| std::cmp::Reverse(orch.elapsed_mins(now)))
+        }
+        ListSort::Status => orchestrations.sort_by_key(|orch| status_rank(&orch.status)),
+        ListSort::Progress => orchestrations.sort_by(|a, b| {
+            a.phase_progress()
+                .partial_cmp(&b.phase_progress())
+                .unwrap_or(Ordering::Equal)
+        }),
+        ListSort::Activity => {
+            orchestrations.sort_by_key(|orch| std::cmp::Reverse(orch.last_activity()))
+        }
+    }
+}
+
+/// Status order for [`ListSort::Status`]: what needs attention first
+fn status_rank(status: &MonitorOrchestrationStatus) -> u8 {
+    match status {
+        MonitorOrchestrationStatus::Blocked => 0,
+        MonitorOrchestrationStatus::Reviewing => 1,
+        MonitorOrchestrationStatus::Executing => 2,
+        MonitorOrchestrationStatus::Planning => 3,
+        MonitorOrchestrationStatus::Idle => 4,
+        MonitorOrchestrationStatus::Complete => 5,
+    }
+}
+
 fn truncate_name(name: &str, max_len: usize) -> String {
     let display = name
         .trim_end_matches("-orchestration")
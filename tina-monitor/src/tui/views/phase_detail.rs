@@ -4,6 +4,10 @@
 //! - Screen 1 (OrchPhaseTasks): Orchestrations | Phases | Tasks+Team
 //! - Screen 2 (TasksDetail): Tasks+Team | Task Detail
 
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use chrono::Utc;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -11,167 +15,15 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
     Frame,
 };
-use syntect::easy::HighlightLines;
-use syntect::highlighting::{self, ThemeSet};
-use syntect::parsing::SyntaxSet;
-use syntect::util::LinesWithEndings;
+use tina_data::humanize;
+use tina_session::risk::RiskLevel;
 
-use crate::data::MonitorOrchestration;
-use crate::types::{Agent, Task, TaskStatus};
+use crate::data::{MonitorOrchestration, ReviewStatus};
+use crate::tmux::liveness::{Liveness, PaneActivity};
+use crate::types::{Agent, ContextMetrics, Task, TaskStatus};
 use crate::tui::app::{App, PaneFocus, PhaseDetailLayout, ViewState};
-
-/// Convert syntect color to ratatui color
-fn syntect_to_ratatui_color(color: highlighting::Color) -> Color {
-    Color::Rgb(color.r, color.g, color.b)
-}
-
-/// Highlight code using syntect
-fn highlight_code(
-    code: &str,
-    lang: &str,
-    ps: &SyntaxSet,
-    theme: &syntect::highlighting::Theme,
-    max_width: usize,
-) -> Vec<Line<'static>> {
-    let mut result = Vec::new();
-
-    // Find syntax for the language, fall back to plain text
-    let syntax = ps
-        .find_syntax_by_token(lang)
-        .or_else(|| ps.find_syntax_by_extension(lang))
-        .unwrap_or_else(|| ps.find_syntax_plain_text());
-
-    let mut highlighter = HighlightLines::new(syntax, theme);
-
-    for line in LinesWithEndings::from(code) {
-        let Ok(highlighted) = highlighter.highlight_line(line, ps) else {
-            // Fall back to plain text if highlighting fails
-            result.push(Line::from(Span::styled(
-                truncate(line.trim_end(), max_width),
-                Style::default().fg(Color::Cyan),
-            )));
-            continue;
-        };
-
-        let spans: Vec<Span<'static>> = highlighted
-            .into_iter()
-            .map(|(style, text)| {
-                let fg = syntect_to_ratatui_color(style.foreground);
-                let mut ratatui_style = Style::default().fg(fg);
-
-                if style.font_style.contains(syntect::highlighting::FontStyle::BOLD) {
-                    ratatui_style = ratatui_style.add_modifier(Modifier::BOLD);
-                }
-                if style.font_style.contains(syntect::highlighting::FontStyle::ITALIC) {
-                    ratatui_style = ratatui_style.add_modifier(Modifier::ITALIC);
-                }
-                if style.font_style.contains(syntect::highlighting::FontStyle::UNDERLINE) {
-                    ratatui_style = ratatui_style.add_modifier(Modifier::UNDERLINED);
-                }
-
-                Span::styled(text.trim_end_matches('\n').to_string(), ratatui_style)
-            })
-            .collect();
-
-        result.push(Line::from(spans));
-    }
-
-    result
-}
-
-/// Render markdown text with syntax highlighting
-fn render_markdown(text: &str, max_width: usize) -> Vec<Line<'static>> {
-    // Box to avoid stack overflow in test threads (SyntaxSet is very large)
-    let ps = Box::new(SyntaxSet::load_defaults_newlines());
-    let ts = Box::new(ThemeSet::load_defaults());
-    let theme = &ts.themes["base16-eighties.dark"];
-
-    let mut lines = Vec::new();
-    let mut in_code_block = false;
-    let mut code_block_lang = String::new();
-    let mut code_block_content = String::new();
-
-    for line in text.lines() {
-        if line.trim().starts_with("```") {
-            if in_code_block {
-                // End of code block - highlight accumulated content
-                let highlighted_lines =
-                    highlight_code(&code_block_content, &code_block_lang, &ps, theme, max_width);
-                lines.extend(highlighted_lines);
-
-                in_code_block = false;
-                code_block_lang.clear();
-                code_block_content.clear();
-                lines.push(Line::from(Span::styled(
-                    "───".to_string(),
-                    Style::default().fg(Color::DarkGray),
-                )));
-            } else {
-                // Start of code block
-                in_code_block = true;
-                code_block_lang = line.trim().trim_start_matches('`').to_string();
-                let label = if code_block_lang.is_empty() {
-                    "───".to_string()
-                } else {
-                    format!("─── {} ───", code_block_lang)
-                };
-                lines.push(Line::from(Span::styled(
-                    label,
-                    Style::default().fg(Color::DarkGray),
-                )));
-            }
-            continue;
-        }
-
-        if in_code_block {
-            code_block_content.push_str(line);
-            code_block_content.push('\n');
-            continue;
-        }
-
-        // Headers
-        if line.starts_with("# ") {
-            lines.push(Line::from(Span::styled(
-                line[2..].to_string(),
-                Style::default().add_modifier(Modifier::BOLD).fg(Color::Cyan),
-            )));
-        } else if line.starts_with("## ") {
-            lines.push(Line::from(Span::styled(
-                line[3..].to_string(),
-                Style::default().add_modifier(Modifier::BOLD),
-            )));
-        } else if line.starts_with("### ") {
-            lines.push(Line::from(Span::styled(
-                line[4..].to_string(),
-                Style::default().add_modifier(Modifier::UNDERLINED),
-            )));
-        } else if line.starts_with("- ") || line.starts_with("* ") {
-            // Bullet list
-            lines.push(Line::from(vec![
-                Span::styled("  • ".to_string(), Style::default().fg(Color::Yellow)),
-                Span::raw(line[2..].to_string()),
-            ]));
-        } else if line.starts_with("> ") {
-            // Blockquote
-            lines.push(Line::from(Span::styled(
-                format!("│ {}", &line[2..]),
-                Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
-            )));
-        } else {
-            // Regular text with inline code
-            lines.push(render_inline_code(line));
-        }
-    }
-
-    // Handle unclosed code block
-    if in_code_block && !code_block_content.is_empty() {
-        let highlighted_lines =
-            highlight_code(&code_block_content, &code_block_lang, &ps, theme, max_width);
-        lines.extend(highlighted_lines);
-    }
-
-    lines
-}
+use crate::tui::views::markdown;
+use crate::theme;
 
 /// Render the phase detail view based on current layout
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
@@ -192,6 +44,13 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         _ => return,
     };
 
+    if app.zoomed {
+        render_zoomed_pane(
+            frame, area, app, orchestration, layout, focus, task_index, member_index, selected_phase,
+        );
+        return;
+    }
+
     match layout {
         PhaseDetailLayout::OrchPhaseTasks => {
             render_orch_phase_tasks(frame, area, app, orchestration, focus, task_index, member_index, selected_phase);
@@ -202,6 +61,58 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     }
 }
 
+/// Render only the focused pane, maximized to the full view area
+#[allow(clippy::too_many_arguments)]
+fn render_zoomed_pane(
+    frame: &mut Frame,
+    area: Rect,
+    app: &App,
+    orchestration: &MonitorOrchestration,
+    layout: PhaseDetailLayout,
+    focus: PaneFocus,
+    task_index: usize,
+    member_index: usize,
+    selected_phase: u32,
+) {
+    match (layout, focus) {
+        (_, PaneFocus::Orchestrations) => render_orchestrations_pane(frame, area, app, true),
+        (_, PaneFocus::Phases) => {
+            render_phases_pane(frame, area, app, orchestration, true, selected_phase)
+        }
+        (_, PaneFocus::Detail) => render_task_detail_pane(frame, area, orchestration, true, task_index),
+        (PhaseDetailLayout::OrchPhaseTasks, PaneFocus::Tasks) => {
+            let (tasks, _) = phase_tasks_and_members(app, orchestration, selected_phase);
+            render_tasks_pane_with_data(frame, area, tasks, &app.task_marks, true, task_index, selected_phase);
+        }
+        (PhaseDetailLayout::OrchPhaseTasks, PaneFocus::Members) => {
+            let (_, members) = phase_tasks_and_members(app, orchestration, selected_phase);
+            render_members_pane_with_data(frame, area, members, &orchestration.worktree_path, app.panes.as_ref(), true, member_index, selected_phase);
+        }
+        (PhaseDetailLayout::TasksDetail, PaneFocus::Tasks) => {
+            render_tasks_pane(frame, area, orchestration, &app.task_marks, true, task_index)
+        }
+        (PhaseDetailLayout::TasksDetail, PaneFocus::Members) => {
+            render_members_pane(frame, area, orchestration, app.panes.as_ref(), true, member_index)
+        }
+    }
+}
+
+/// Get phase-specific tasks and members from cache, or fall back to orchestration data
+pub(crate) fn phase_tasks_and_members<'a>(
+    app: &'a App,
+    orchestration: &'a MonitorOrchestration,
+    selected_phase: u32,
+) -> (&'a [Task], &'a [Agent]) {
+    match &app.phase_cache {
+        Some((orch_idx, cached_phase, phase_data))
+            if *orch_idx == app.selected_index && *cached_phase == selected_phase =>
+        {
+            (&phase_data.tasks, &phase_data.members)
+        }
+        _ => (&orchestration.tasks, &orchestration.members),
+    }
+}
+
 /// Render Screen 1: Orchestrations | Phases | Tasks+Team
 fn render_orch_phase_tasks(
     frame: &mut Frame,
@@ -227,7 +138,14 @@ fn render_orch_phase_tasks(
     render_orchestrations_pane(frame, columns[0], app, focus == PaneFocus::Orchestrations);
 
     // Middle: Phase list
-    render_phases_pane(frame, columns[1], orchestration, focus == PaneFocus::Phases, selected_phase);
+    render_phases_pane(
+        frame,
+        columns[1],
+        app,
+        orchestration,
+        focus == PaneFocus::Phases,
+        selected_phase,
+    );
 
     // Right: Tasks+Team (split vertically)
     let right_chunks = Layout::default()
@@ -235,26 +153,17 @@ fn render_orch_phase_tasks(
         .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
         .split(columns[2]);
 
-    // Get phase-specific tasks and members from cache, or fall back to orchestration data
-    let (tasks, members) = if let Some((orch_idx, cached_phase, phase_data)) = &app.phase_cache {
-        if *orch_idx == app.selected_index && *cached_phase == selected_phase {
-            (&phase_data.tasks, &phase_data.members)
-        } else {
-            (&orchestration.tasks, &orchestration.members)
-        }
-    } else {
-        (&orchestration.tasks, &orchestration.members)
-    };
+    let (tasks, members) = phase_tasks_and_members(app, orchestration, selected_phase);
 
-    render_tasks_pane_with_data(frame, right_chunks[0], tasks, focus == PaneFocus::Tasks, task_index, selected_phase);
-    render_members_pane_with_data(frame, right_chunks[1], members, focus == PaneFocus::Members, member_index, selected_phase);
+    render_tasks_pane_with_data(frame, right_chunks[0], tasks, &app.task_marks, focus == PaneFocus::Tasks, task_index, selected_phase);
+    render_members_pane_with_data(frame, right_chunks[1], members, &orchestration.worktree_path, app.panes.as_ref(), focus == PaneFocus::Members, member_index, selected_phase);
 }
 
 /// Render Screen 2: Tasks+Team | Task Detail
 fn render_tasks_detail(
     frame: &mut Frame,
     area: Rect,
-    _app: &App,
+    app: &App,
     orchestration: &MonitorOrchestration,
     focus: PaneFocus,
     task_index: usize,
@@ -272,8 +181,8 @@ fn render_tasks_detail(
         .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
         .split(columns[0]);
 
-    render_tasks_pane(frame, left_chunks[0], orchestration, focus == PaneFocus::Tasks, task_index);
-    render_members_pane(frame, left_chunks[1], orchestration, focus == PaneFocus::Members, member_index);
+    render_tasks_pane(frame, left_chunks[0], orchestration, &app.task_marks, focus == PaneFocus::Tasks, task_index);
+    render_members_pane(frame, left_chunks[1], orchestration, app.panes.as_ref(), focus == PaneFocus::Members, member_index);
 
     // Right: Task detail
     render_task_detail_pane(frame, columns[1], orchestration, focus == PaneFocus::Detail, task_index);
@@ -287,22 +196,16 @@ fn render_orchestrations_pane(frame: &mut Frame, area: Rect, app: &App, is_focus
         .enumerate()
         .map(|(i, orch)| {
             let indicator = if i == app.selected_index { "▶ " } else { "  " };
-            let status_char = match &orch.status {
-                crate::data::MonitorOrchestrationStatus::Executing => "●",
-                crate::data::MonitorOrchestrationStatus::Planning => "◑",
-                crate::data::MonitorOrchestrationStatus::Reviewing => "◎",
-                crate::data::MonitorOrchestrationStatus::Blocked => "✗",
-                crate::data::MonitorOrchestrationStatus::Complete => "✓",
-                crate::data::MonitorOrchestrationStatus::Idle => "○",
-            };
-            let status_color = match &orch.status {
-                crate::data::MonitorOrchestrationStatus::Executing => Color::Green,
-                crate::data::MonitorOrchestrationStatus::Planning => Color::Yellow,
-                crate::data::MonitorOrchestrationStatus::Reviewing => Color::Cyan,
-                crate::data::MonitorOrchestrationStatus::Blocked => Color::Red,
-                crate::data::MonitorOrchestrationStatus::Complete => Color::Blue,
-                crate::data::MonitorOrchestrationStatus::Idle => Color::DarkGray,
+            let (glyph, marker) = match &orch.status {
+                crate::data::MonitorOrchestrationStatus::Executing => ("●", "[EXECUTING]"),
+                crate::data::MonitorOrchestrationStatus::Planning => ("◑", "[PLANNING]"),
+                crate::data::MonitorOrchestrationStatus::Reviewing => ("◎", "[REVIEWING]"),
+                crate::data::MonitorOrchestrationStatus::Blocked => ("✗", "[BLOCKED]"),
+                crate::data::MonitorOrchestrationStatus::Complete => ("✓", "[COMPLETE]"),
+                crate::data::MonitorOrchestrationStatus::Idle => ("○", "[IDLE]"),
             };
+            let status_char = theme::current().marker(glyph, marker);
+            let status_color = theme::current().status(&orch.status);
 
             let title = truncate(&orch.title(), area.width.saturating_sub(8) as usize);
             let style = if i == app.selected_index {
@@ -331,30 +234,112 @@ fn render_orchestrations_pane(frame: &mut Frame, area: Rect, app: &App, is_focus
     frame.render_widget(list, area);
 }
 
+/// Theme color for a task's status (blocked pending tasks are errors)
+fn task_status_color(task: &Task) -> Color {
+    let theme = theme::current();
+    match task.status {
+        TaskStatus::Completed => theme.success,
+        TaskStatus::InProgress => theme.info,
+        TaskStatus::Pending if !task.blocked_by.is_empty() => theme.error,
+        TaskStatus::Pending => theme.muted,
+    }
+}
+
+/// Status glyph for a task, or a text marker under the plain theme
+fn task_indicator(task: &Task) -> &'static str {
+    let (glyph, marker) = match task.status {
+        TaskStatus::Completed => ("✓", "[✓]"),
+        TaskStatus::InProgress => ("▶", "[RUNNING]"),
+        TaskStatus::Pending if !task.blocked_by.is_empty() => ("✗", "[BLOCKED]"),
+        TaskStatus::Pending => ("○", "[ ]"),
+    };
+    theme::current().marker(glyph, marker)
+}
+
 /// Get status indicator and color for a phase
 fn get_phase_status(orchestration: &MonitorOrchestration, phase: u32) -> (&'static str, Color) {
-    if phase < orchestration.current_phase {
+    let theme = theme::current();
+    let (glyph, marker, color) = if phase < orchestration.current_phase {
         // Past phase - assume complete
-        ("✓", Color::Green)
+        ("✓", "[✓]", theme.success)
     } else if phase == orchestration.current_phase {
         match &orchestration.status {
-            crate::data::MonitorOrchestrationStatus::Executing => ("▶", Color::Cyan),
-            crate::data::MonitorOrchestrationStatus::Planning => ("◑", Color::Yellow),
-            crate::data::MonitorOrchestrationStatus::Reviewing => ("◎", Color::Cyan),
-            crate::data::MonitorOrchestrationStatus::Blocked => ("✗", Color::Red),
-            crate::data::MonitorOrchestrationStatus::Complete => ("✓", Color::Green),
-            crate::data::MonitorOrchestrationStatus::Idle => ("○", Color::DarkGray),
+            crate::data::MonitorOrchestrationStatus::Executing => ("▶", "[EXECUTING]", theme.info),
+            crate::data::MonitorOrchestrationStatus::Planning => ("◑", "[PLANNING]", theme.warning),
+            crate::data::MonitorOrchestrationStatus::Reviewing => ("◎", "[REVIEWING]", theme.info),
+            crate::data::MonitorOrchestrationStatus::Blocked => ("✗", "[BLOCKED]", theme.error),
+            crate::data::MonitorOrchestrationStatus::Complete => ("✓", "[✓]", theme.success),
+            crate::data::MonitorOrchestrationStatus::Idle => ("○", "[IDLE]", theme.muted),
         }
     } else {
         // Future phase
-        ("○", Color::DarkGray)
-    }
+        ("○", "[ ]", theme.muted)
+    };
+    (theme.marker(glyph, marker), color)
+}
+
+/// Risk badge for a phase that has a recorded risk score
+fn phase_risk_span(orchestration: &MonitorOrchestration, phase: u32) -> Option<Span<'static>> {
+    let phase_number = phase.to_string();
+    let score = orchestration
+        .phases
+        .iter()
+        .find(|record| record.phase_number == phase_number)?
+        .risk_score?
+        .round() as u32;
+    let theme = theme::current();
+    let color = match RiskLevel::from_score(score) {
+        RiskLevel::High => theme.error,
+        RiskLevel::Medium => theme.warning,
+        RiskLevel::Low => theme.muted,
+    };
+    Some(Span::styled(
+        format!("  risk {}", score),
+        Style::default().fg(color),
+    ))
+}
+
+/// Unresolved review findings for a phase by severity, e.g. "1 p0 2 p2"
+fn phase_findings_span(review: &ReviewStatus, phase: u32) -> Option<Span<'static>> {
+    let counts = review.open_findings.get(&phase)?;
+    let theme = theme::current();
+    let color = if counts.p0 > 0 {
+        theme.error
+    } else if counts.p1 > 0 {
+        theme.warning
+    } else {
+        theme.muted
+    };
+    let text = [("p0", counts.p0), ("p1", counts.p1), ("p2", counts.p2)]
+        .iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(severity, count)| format!("{} {}", count, severity))
+        .collect::<Vec<_>>()
+        .join(" ");
+    Some(Span::styled(format!("  {}", text), Style::default().fg(color)))
+}
+
+/// Gates the orchestration is waiting on, e.g. "review blocked"
+fn waiting_gate_spans(review: &ReviewStatus) -> Vec<Span<'static>> {
+    let theme = theme::current();
+    review
+        .waiting_gates()
+        .map(|(gate, status)| {
+            let color = if status == "blocked" {
+                theme.error
+            } else {
+                theme.warning
+            };
+            Span::styled(format!("  {} {}", gate, status), Style::default().fg(color))
+        })
+        .collect()
 }
 
 /// Render the phases pane as a selectable list
 fn render_phases_pane(
     frame: &mut Frame,
     area: Rect,
+    app: &App,
     orchestration: &MonitorOrchestration,
     is_focused: bool,
     selected_phase: u32,
@@ -365,6 +350,8 @@ fn render_phases_pane(
         .constraints([Constraint::Min(3), Constraint::Length(3)])
         .split(area);
 
+    let review = app.list_paging.reviews.get(&orchestration.id);
+
     // Build phase list
     let items: Vec<ListItem> = (1..=orchestration.total_phases)
         .map(|phase| {
@@ -383,13 +370,21 @@ fn render_phases_pane(
 
             let current_marker = if is_current { " ◀" } else { "" };
 
-            ListItem::new(Line::from(vec![
+            let mut spans = vec![
                 Span::raw(cursor),
                 Span::styled(indicator, Style::default().fg(status_color)),
                 Span::raw(" "),
                 Span::styled(format!("Phase {}", phase), style),
-                Span::styled(current_marker, Style::default().fg(Color::Cyan)),
-            ]))
+                Span::styled(current_marker, Style::default().fg(theme::current().accent)),
+            ];
+            spans.extend(phase_risk_span(orchestration, phase));
+            if let Some(review) = review {
+                spans.extend(phase_findings_span(review, phase));
+                if is_current {
+                    spans.extend(waiting_gate_spans(review));
+                }
+            }
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -416,7 +411,7 @@ fn render_phases_pane(
     let context_paragraph = Paragraph::new(context_content).block(
         Block::default()
             .borders(Borders::TOP)
-            .border_style(Style::default().fg(Color::DarkGray)),
+            .border_style(Style::default().fg(theme::current().border)),
     );
     frame.render_widget(context_paragraph, chunks[1]);
 }
@@ -426,6 +421,7 @@ fn render_tasks_pane(
     frame: &mut Frame,
     area: Rect,
     orchestration: &MonitorOrchestration,
+    marks: &BTreeSet<String>,
     is_focused: bool,
     selected_index: usize,
 ) {
@@ -434,19 +430,8 @@ fn render_tasks_pane(
         .iter()
         .enumerate()
         .map(|(i, task)| {
-            let indicator = match task.status {
-                TaskStatus::Completed => "✓",
-                TaskStatus::InProgress => "▶",
-                TaskStatus::Pending if !task.blocked_by.is_empty() => "✗",
-                TaskStatus::Pending => "○",
-            };
-
-            let status_color = match task.status {
-                TaskStatus::Completed => Color::Green,
-                TaskStatus::InProgress => Color::Cyan,
-                TaskStatus::Pending if !task.blocked_by.is_empty() => Color::Red,
-                TaskStatus::Pending => Color::DarkGray,
-            };
+            let indicator = task_indicator(task);
+            let status_color = task_status_color(task);
 
             let subject = truncate(&task.subject, area.width.saturating_sub(8) as usize);
             let selected_marker = if i == selected_index && is_focused { "▶ " } else { "  " };
@@ -459,6 +444,7 @@ fn render_tasks_pane(
 
             ListItem::new(Line::from(vec![
                 Span::raw(selected_marker),
+                Span::raw(mark_box(marks, &task.id)),
                 Span::styled(indicator, Style::default().fg(status_color)),
                 Span::raw(" "),
                 Span::styled(subject, style),
@@ -467,7 +453,7 @@ fn render_tasks_pane(
         .collect();
 
     let border_style = border_style(is_focused);
-    let title = format!("Tasks ({}) [Tab: switch]", orchestration.tasks.len());
+    let title = format!("Tasks ({}){} [Tab: switch]", orchestration.tasks.len(), marked_count(marks));
     let list = List::new(items).block(
         Block::default()
             .borders(Borders::ALL)
@@ -478,11 +464,104 @@ fn render_tasks_pane(
     frame.render_widget(list, area);
 }
 
+/// Checkbox shown before each task once any task is marked for a bulk action
+fn mark_box(marks: &BTreeSet<String>, task_id: &str) -> &'static str {
+    match (marks.is_empty(), marks.contains(task_id)) {
+        (true, _) => "",
+        (false, true) => "[x] ",
+        (false, false) => "[ ] ",
+    }
+}
+
+/// Title suffix counting the marked tasks
+fn marked_count(marks: &BTreeSet<String>) -> String {
+    if marks.is_empty() {
+        String::new()
+    } else {
+        format!(" · {} marked", marks.len())
+    }
+}
+
+/// Color for agents whose pane is gone or has gone quiet
+fn liveness_color(liveness: Liveness) -> Option<Color> {
+    match liveness {
+        Liveness::Gone => Some(theme::current().error),
+        Liveness::Stalled(_) => Some(theme::current().warning),
+        Liveness::Unknown | Liveness::NoPane | Liveness::Active(_) => None,
+    }
+}
+
+/// Pane state and time since the agent's last output, e.g. "● 2m"
+fn liveness_spans(liveness: Liveness) -> Vec<Span<'static>> {
+    let idle = |d: chrono::Duration| humanize::format_duration_secs(d.num_seconds());
+    let theme = theme::current();
+    let (text, color) = match liveness {
+        Liveness::Unknown => return Vec::new(),
+        Liveness::NoPane => (
+            theme.marker("– no pane", "[NO PANE]").to_string(),
+            theme.muted,
+        ),
+        Liveness::Gone => (theme.marker("✗ gone", "[GONE]").to_string(), theme.error),
+        Liveness::Active(d) if theme.plain => (format!("[ACTIVE {}]", idle(d)), theme.success),
+        Liveness::Active(d) => (format!("● {}", idle(d)), theme.success),
+        Liveness::Stalled(d) if theme.plain => (format!("[STALLED {}]", idle(d)), theme.warning),
+        Liveness::Stalled(d) => (format!("◌ {}", idle(d)), theme.warning),
+    };
+    vec![Span::styled(text, Style::default().fg(color)), Span::raw(" ")]
+}
+
+/// Cells in a member's context gauge bar
+const CONTEXT_GAUGE_CELLS: usize = 8;
+/// Columns the gauge takes up: bar, percentage, and warning marker
+const CONTEXT_GAUGE_WIDTH: u16 = CONTEXT_GAUGE_CELLS as u16 + 7;
+/// Context usage at which the gauge turns yellow
+const CONTEXT_WARN_PCT: u8 = 50;
+/// Context usage at which an agent is close to compaction and flagged
+const CONTEXT_CRITICAL_PCT: u8 = 80;
+
+fn context_color(used_pct: u8) -> Color {
+    if used_pct >= CONTEXT_CRITICAL_PCT {
+        theme::current().error
+    } else if used_pct >= CONTEXT_WARN_PCT {
+        theme::current().warning
+    } else {
+        theme::current().success
+    }
+}
+
+/// Gauge of how much of its context window an agent has consumed, with a
+/// warning marker once it's near the limit
+fn context_gauge_spans(metrics: Option<&ContextMetrics>) -> Vec<Span<'static>> {
+    let Some(metrics) = metrics else {
+        return vec![Span::styled("ctx —", Style::default().fg(theme::current().muted))];
+    };
+
+    let used_pct = metrics.used_pct.min(100);
+    let filled = (used_pct as usize * CONTEXT_GAUGE_CELLS).div_ceil(100);
+    let color = context_color(used_pct);
+    let mut spans = vec![
+        Span::styled("█".repeat(filled), Style::default().fg(color)),
+        Span::styled(
+            "░".repeat(CONTEXT_GAUGE_CELLS - filled),
+            Style::default().fg(theme::current().muted),
+        ),
+        Span::styled(format!(" {:>3}%", used_pct), Style::default().fg(color)),
+    ];
+    if used_pct >= CONTEXT_CRITICAL_PCT {
+        spans.push(Span::styled(
+            " ⚠",
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ));
+    }
+    spans
+}
+
 /// Render the team members pane with selection highlighting
 fn render_members_pane(
     frame: &mut Frame,
     area: Rect,
     orchestration: &MonitorOrchestration,
+    panes: Option<&HashMap<String, PaneActivity>>,
     is_focused: bool,
     selected_index: usize,
 ) {
@@ -502,13 +581,31 @@ fn render_members_pane(
                 Style::default()
             };
 
-            let name = truncate(&member.name, area.width.saturating_sub(20) as usize);
+            let name = truncate(&member.name, area.width.saturating_sub(28 + CONTEXT_GAUGE_WIDTH) as usize);
+            let metrics = member
+                .tmux_pane_id
+                .as_deref()
+                .and_then(|pane| ContextMetrics::load_for_pane(&orchestration.worktree_path, pane));
+            let liveness = Liveness::of(
+                member.tmux_pane_id.as_deref(),
+                panes,
+                metrics.as_ref().map(|m| m.timestamp),
+                Utc::now(),
+            );
+            let style = match liveness_color(liveness) {
+                Some(color) => style.fg(color),
+                None => style,
+            };
 
-            ListItem::new(Line::from(vec![
+            let mut spans = vec![
                 Span::raw(selected_marker),
                 Span::styled(name, style),
-                Span::styled(format!(" ({}/{})", agent_type, model_short), Style::default().fg(Color::DarkGray)),
-            ]))
+                Span::styled(format!(" ({}/{})", agent_type, model_short), Style::default().fg(theme::current().muted)),
+                Span::raw(" "),
+            ];
+            spans.extend(liveness_spans(liveness));
+            spans.extend(context_gauge_spans(metrics.as_ref()));
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -518,7 +615,7 @@ fn render_members_pane(
     if items.is_empty() {
         let paragraph = Paragraph::new(Line::from(Span::styled(
             "No team members",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme::current().muted),
         )))
         .block(
             Block::default()
@@ -543,6 +640,7 @@ fn render_tasks_pane_with_data(
     frame: &mut Frame,
     area: Rect,
     tasks: &[Task],
+    marks: &BTreeSet<String>,
     is_focused: bool,
     selected_index: usize,
     phase: u32,
@@ -551,19 +649,8 @@ fn render_tasks_pane_with_data(
         .iter()
         .enumerate()
         .map(|(i, task)| {
-            let indicator = match task.status {
-                TaskStatus::Completed => "✓",
-                TaskStatus::InProgress => "▶",
-                TaskStatus::Pending if !task.blocked_by.is_empty() => "✗",
-                TaskStatus::Pending => "○",
-            };
-
-            let status_color = match task.status {
-                TaskStatus::Completed => Color::Green,
-                TaskStatus::InProgress => Color::Cyan,
-                TaskStatus::Pending if !task.blocked_by.is_empty() => Color::Red,
-                TaskStatus::Pending => Color::DarkGray,
-            };
+            let indicator = task_indicator(task);
+            let status_color = task_status_color(task);
 
             let subject = truncate(&task.subject, area.width.saturating_sub(8) as usize);
             let selected_marker = if i == selected_index && is_focused { "▶ " } else { "  " };
@@ -576,6 +663,7 @@ fn render_tasks_pane_with_data(
 
             ListItem::new(Line::from(vec![
                 Span::raw(selected_marker),
+                Span::raw(mark_box(marks, &task.id)),
                 Span::styled(indicator, Style::default().fg(status_color)),
                 Span::raw(" "),
                 Span::styled(subject, style),
@@ -584,12 +672,12 @@ fn render_tasks_pane_with_data(
         .collect();
 
     let border_style = border_style(is_focused);
-    let title = format!("Phase {} Tasks ({}) [Tab: switch]", phase, tasks.len());
+    let title = format!("Phase {} Tasks ({}){} [Tab: switch]", phase, tasks.len(), marked_count(marks));
 
     if items.is_empty() {
         let paragraph = Paragraph::new(Line::from(Span::styled(
             "No tasks for this phase",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme::current().muted),
         )))
         .block(
             Block::default()
@@ -610,10 +698,13 @@ fn render_tasks_pane_with_data(
 }
 
 /// Render members pane with provided member list (for phase-specific data)
+#[allow(clippy::too_many_arguments)]
 fn render_members_pane_with_data(
     frame: &mut Frame,
     area: Rect,
     members: &[Agent],
+    worktree: &Path,
+    panes: Option<&HashMap<String, PaneActivity>>,
     is_focused: bool,
     selected_index: usize,
     phase: u32,
@@ -633,13 +724,31 @@ fn render_members_pane_with_data(
                 Style::default()
             };
 
-            let name = truncate(&member.name, area.width.saturating_sub(20) as usize);
+            let name = truncate(&member.name, area.width.saturating_sub(28 + CONTEXT_GAUGE_WIDTH) as usize);
+            let metrics = member
+                .tmux_pane_id
+                .as_deref()
+                .and_then(|pane| ContextMetrics::load_for_pane(worktree, pane));
+            let liveness = Liveness::of(
+                member.tmux_pane_id.as_deref(),
+                panes,
+                metrics.as_ref().map(|m| m.timestamp),
+                Utc::now(),
+            );
+            let style = match liveness_color(liveness) {
+                Some(color) => style.fg(color),
+                None => style,
+            };
 
-            ListItem::new(Line::from(vec![
+            let mut spans = vec![
                 Span::raw(selected_marker),
                 Span::styled(name, style),
-                Span::styled(format!(" ({}/{})", agent_type, model_short), Style::default().fg(Color::DarkGray)),
-            ]))
+                Span::styled(format!(" ({}/{})", agent_type, model_short), Style::default().fg(theme::current().muted)),
+                Span::raw(" "),
+            ];
+            spans.extend(liveness_spans(liveness));
+            spans.extend(context_gauge_spans(metrics.as_ref()));
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -649,7 +758,7 @@ fn render_members_pane_with_data(
     if items.is_empty() {
         let paragraph = Paragraph::new(Line::from(Span::styled(
             "No team members for this phase",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme::current().muted),
         )))
         .block(
             Block::default()
@@ -684,7 +793,7 @@ fn render_task_detail_pane(
         None => {
             let paragraph = Paragraph::new(Line::from(Span::styled(
                 "No task selected",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme::current().muted),
             )))
             .block(
                 Block::default()
@@ -698,10 +807,10 @@ fn render_task_detail_pane(
     };
 
     let status_text = match task.status {
-        TaskStatus::Completed => ("✓ Completed", Color::Green),
-        TaskStatus::InProgress => ("▶ In Progress", Color::Cyan),
-        TaskStatus::Pending if !task.blocked_by.is_empty() => ("✗ Blocked", Color::Red),
-        TaskStatus::Pending => ("○ Pending", Color::DarkGray),
+        TaskStatus::Completed => ("✓ Completed", task_status_color(task)),
+        TaskStatus::InProgress => ("▶ In Progress", task_status_color(task)),
+        TaskStatus::Pending if !task.blocked_by.is_empty() => ("✗ Blocked", task_status_color(task)),
+        TaskStatus::Pending => ("○ Pending", task_status_color(task)),
     };
 
     let mut lines = vec![
@@ -716,21 +825,21 @@ fn render_task_detail_pane(
     if let Some(owner) = &task.owner {
         lines.push(Line::from(vec![
             Span::raw("Owner: "),
-            Span::styled(owner, Style::default().fg(Color::Yellow)),
+            Span::styled(owner, Style::default().fg(theme::current().highlight)),
         ]));
     }
 
     if !task.blocked_by.is_empty() {
         lines.push(Line::from(vec![
             Span::raw("Blocked by: "),
-            Span::styled(task.blocked_by.join(", "), Style::default().fg(Color::Red)),
+            Span::styled(task.blocked_by.join(", "), Style::default().fg(theme::current().error)),
         ]));
     }
 
     if !task.blocks.is_empty() {
         lines.push(Line::from(vec![
             Span::raw("Blocks: "),
-            Span::styled(task.blocks.join(", "), Style::default().fg(Color::DarkGray)),
+            Span::styled(task.blocks.join(", "), Style::default().fg(theme::current().muted)),
         ]));
     }
 
@@ -740,7 +849,7 @@ fn render_task_detail_pane(
 
     // Render description with syntax-highlighted markdown
     let max_width = area.width.saturating_sub(4) as usize;
-    let description_lines = render_markdown(&task.description, max_width);
+    let description_lines = markdown::render(&task.description, max_width);
     lines.extend(description_lines);
 
     let paragraph = Paragraph::new(lines)
@@ -757,10 +866,12 @@ fn render_task_detail_pane(
 
 /// Get border style based on focus state
 fn border_style(is_focused: bool) -> Style {
-    if is_focused {
-        Style::default().fg(Color::Cyan)
+    if is_focused && theme::current().plain {
+        Style::default().add_modifier(Modifier::BOLD)
+    } else if is_focused {
+        Style::default().fg(theme::current().focused_border)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme::current().border)
     }
 }
 
@@ -777,47 +888,6 @@ fn shorten_model(model: &str) -> &str {
     }
 }
 
-/// Render a line with inline `code` formatting
-fn render_inline_code(line: &str) -> Line<'static> {
-    let mut spans = Vec::new();
-    let mut chars = line.chars().peekable();
-    let mut current_text = String::new();
-    let mut in_code = false;
-
-    while let Some(c) = chars.next() {
-        if c == '`' {
-            if in_code {
-                // End of inline code
-                spans.push(Span::styled(
-                    std::mem::take(&mut current_text),
-                    Style::default().fg(Color::Cyan),
-                ));
-                in_code = false;
-            } else {
-                // Start of inline code - flush regular text first
-                if !current_text.is_empty() {
-                    spans.push(Span::raw(std::mem::take(&mut current_text)));
-                }
-                in_code = true;
-            }
-        } else {
-            current_text.push(c);
-        }
-    }
-
-    // Flush remaining text
-    if !current_text.is_empty() {
-        if in_code {
-            // Unclosed backtick - treat as regular text with the backtick
-            spans.push(Span::raw(format!("`{}", current_text)));
-        } else {
-            spans.push(Span::raw(current_text));
-        }
-    }
-
-    Line::from(spans)
-}
-
 /// Truncate a string to a maximum length, adding ellipsis if needed
 fn truncate(s: &str, max_len: usize) -> String {
     if s.len() > max_len {
@@ -830,9 +900,10 @@ fn truncate(s: &str, max_len: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tina_data::test_util::list_entry;
     use crate::data::MonitorOrchestration;
     use crate::types::{Task, TaskStatus};
-    use tina_data::OrchestrationListEntry;
+    
     use ratatui::{backend::TestBackend, Terminal};
 
     fn make_test_task(
@@ -855,31 +926,12 @@ mod tests {
     }
 
     fn make_test_orchestration() -> MonitorOrchestration {
-        let entry = OrchestrationListEntry {
-            id: "orch-1".to_string(),
-            node_name: "macbook".to_string(),
-            record: tina_data::OrchestrationRecord {
-                node_id: "node-1".to_string(),
-                project_id: None,
-                feature_name: "test-project".to_string(),
-                spec_doc_path: "design.md".to_string(),
-                branch: "tina/test-project".to_string(),
-                worktree_path: Some("/test".to_string()),
-                total_phases: 4.0,
-                current_phase: 2.0,
-                status: "executing".to_string(),
-                started_at: "2026-02-07T10:00:00Z".to_string(),
-                completed_at: None,
-                total_elapsed_mins: None,
-                spec_id: None,
-                policy_snapshot: None,
-                policy_snapshot_hash: None,
-                preset_origin: None,
-                spec_only: None,
-                policy_revision: None,
-                updated_at: None,
-            },
-        };
+        let mut entry = list_entry("orch-1", "executing", "2026-02-07T10:00:00Z");
+        entry.record.feature_name = "test-project".to_string();
+        entry.record.branch = "tina/test-project".to_string();
+        entry.record.worktree_path = Some("/test".to_string());
+        entry.record.total_phases = 4.0;
+        entry.record.current_phase = 2.0;
         let mut orch = MonitorOrchestration::from_list_entry(entry);
         orch.tasks = vec![
             make_test_task("1", "Completed task", TaskStatus::Completed, vec![]),
@@ -914,6 +966,40 @@ mod tests {
         assert!(result.is_ok(), "Phase detail should render without panic");
     }
 
+    #[test]
+    fn test_zoomed_render_shows_only_focused_pane() {
+        let backend = TestBackend::new(120, 40);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let orchestration = make_test_orchestration();
+        let mut app = App::new_with_orchestrations(vec![orchestration]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Tasks,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+        app.zoomed = true;
+
+        terminal
+            .draw(|frame| render(frame, frame.area(), &app))
+            .unwrap();
+        let buffer_str = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect::<String>();
+
+        assert!(buffer_str.contains("Blocked task"), "Zoomed tasks pane should be rendered");
+        assert!(
+            !buffer_str.contains("Orchestrations"),
+            "Other panes should be hidden while zoomed"
+        );
+    }
+
     #[test]
     fn test_tasks_pane_renders_all_tasks_with_correct_status_indicators() {
         let backend = TestBackend::new(120, 40);
@@ -994,6 +1080,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_team_pane_shows_agent_liveness() {
+        let backend = TestBackend::new(160, 40);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let agent = |name: &str, pane: Option<&str>| crate::types::Agent {
+            agent_id: name.to_string(),
+            name: name.to_string(),
+            agent_type: None,
+            model: "claude-opus-4".to_string(),
+            joined_at: 0,
+            tmux_pane_id: pane.map(String::from),
+            cwd: "/test".into(),
+            subscriptions: vec![],
+        };
+        let mut orchestration = make_test_orchestration();
+        orchestration.members = vec![
+            agent("alive", Some("%1")),
+            agent("closed", Some("%2")),
+            agent("paneless", None),
+        ];
+        let mut app = App::new_with_orchestrations(vec![orchestration]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Members,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::TasksDetail,
+            selected_phase: 1,
+        };
+        let now = Utc::now().timestamp();
+        app.panes = Some(crate::tmux::liveness::parse_pane_activity(&format!(
+            "%1 0 {}\n",
+            now - 90
+        )));
+
+        terminal
+            .draw(|frame| render(frame, frame.area(), &app))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol().to_string())
+                    .collect()
+            })
+            .collect();
+        let row = |name: &str| rows.iter().find(|r| r.contains(name)).unwrap().clone();
+
+        assert!(row("alive").contains("● 1m"), "{}", row("alive"));
+        assert!(row("closed").contains("✗ gone"));
+        assert!(row("paneless").contains("– no pane"));
+    }
+
     #[test]
     fn test_focused_pane_has_highlighted_border() {
         let backend = TestBackend::new(120, 40);
@@ -1146,6 +1285,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_phases_pane_shows_recorded_risk() {
+        let backend = TestBackend::new(120, 40);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let mut orchestration = make_test_orchestration();
+        orchestration.phases = vec![tina_data::PhaseRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: "1".to_string(),
+            status: "complete".to_string(),
+            plan_path: None,
+            git_range: Some("abc..def".to_string()),
+            planning_mins: None,
+            execution_mins: None,
+            review_mins: None,
+            started_at: None,
+            completed_at: None,
+            risk_score: Some(72.0),
+            risk_signals: None,
+        }];
+        let mut app = App::new_with_orchestrations(vec![orchestration]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Tasks,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+
+        terminal
+            .draw(|frame| render(frame, frame.area(), &app))
+            .unwrap();
+        let buffer_str = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect::<String>();
+
+        assert!(
+            buffer_str.contains("risk 72"),
+            "Phase 1 should show its risk"
+        );
+        assert_eq!(
+            buffer_str.matches("risk ").count(),
+            1,
+            "Only phase 1 is scored"
+        );
+    }
+
+    #[test]
+    fn test_phases_pane_shows_open_findings_and_waiting_gates() {
+        let backend = TestBackend::new(160, 40);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let orchestration = make_test_orchestration();
+        let mut review = ReviewStatus::default();
+        review.open_findings.insert(
+            1,
+            crate::data::review::FindingCounts {
+                p0: 1,
+                p1: 0,
+                p2: 2,
+            },
+        );
+        review
+            .gates
+            .insert("plan".to_string(), "approved".to_string());
+        review
+            .gates
+            .insert("review".to_string(), "blocked".to_string());
+        let mut app = App::new_with_orchestrations(vec![orchestration.clone()]);
+        app.list_paging
+            .reviews
+            .insert(orchestration.id.clone(), review);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Phases,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 1,
+        };
+
+        terminal
+            .draw(|frame| render(frame, frame.area(), &app))
+            .unwrap();
+        let buffer_str = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect::<String>();
+
+        assert!(
+            buffer_str.contains("1 p0 2 p2"),
+            "Phase 1 should count its open findings"
+        );
+        assert!(
+            buffer_str.contains("review blocked"),
+            "The current phase should show the gate it waits on"
+        );
+        assert!(
+            !buffer_str.contains("plan approved"),
+            "Approved gates aren't waited on"
+        );
+    }
+
     #[test]
     fn test_team_pane_handles_context_placeholder() {
         let backend = TestBackend::new(120, 40);
@@ -1177,4 +1425,33 @@ mod tests {
             "Should display placeholder when no context"
         );
     }
+
+    fn gauge_text(used_pct: Option<u8>) -> String {
+        let metrics = used_pct.map(|used_pct| ContextMetrics {
+            used_pct,
+            tokens: 0,
+            max: 200_000,
+            timestamp: chrono::Utc::now(),
+        });
+        context_gauge_spans(metrics.as_ref())
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_context_gauge_fills_and_warns_near_limit() {
+        assert_eq!(gauge_text(None), "ctx —");
+        assert_eq!(gauge_text(Some(0)), "░░░░░░░░   0%");
+        assert_eq!(gauge_text(Some(40)), "████░░░░  40%");
+        assert_eq!(gauge_text(Some(87)), "███████░  87% ⚠");
+        assert_eq!(gauge_text(Some(120)), "████████ 100% ⚠");
+    }
+
+    #[test]
+    fn test_context_gauge_color_thresholds() {
+        assert_eq!(context_color(49), theme::current().success);
+        assert_eq!(context_color(CONTEXT_WARN_PCT), theme::current().warning);
+        assert_eq!(context_color(CONTEXT_CRITICAL_PCT), theme::current().error);
+    }
 }
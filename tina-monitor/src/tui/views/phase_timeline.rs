@@ -0,0 +1,614 @@
+//! Phase timeline (Gantt) modal
+//!
+//! Draws each phase as a horizontal bar on a shared time axis, split into
+//! planning, executing, and reviewing segments, with the idle gaps between
+//! phases called out so slow stretches stand out. Optionally lists the
+//! selected phase's tasks beneath it.
+
+use chrono::{DateTime, Utc};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::dashboard::Dashboard;
+use crate::data::MonitorOrchestration;
+use crate::theme;
+use crate::types::{SupervisorState, TimingStats};
+use tina_data::humanize::{format_time, parse_rfc3339};
+use tina_data::PhaseRecord;
+
+/// Width of the label column left of the bars
+const LABEL_WIDTH: usize = 18;
+/// Width of the duration column right of the bars
+const DURATION_WIDTH: usize = 8;
+
+/// What a stretch of a bar represents
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentKind {
+    Planning,
+    Executing,
+    Reviewing,
+    Task,
+    /// Time between one phase finishing and the next starting
+    Gap,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    pub kind: SegmentKind,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+/// A phase and its segments, in time order
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseSpan {
+    pub phase: String,
+    pub segments: Vec<Segment>,
+}
+
+impl PhaseSpan {
+    fn start(&self) -> Option<DateTime<Utc>> {
+        self.segments.first().map(|s| s.start)
+    }
+
+    fn end(&self) -> Option<DateTime<Utc>> {
+        self.segments.last().map(|s| s.end)
+    }
+}
+
+/// When a task in the selected phase was being worked on
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskSpan {
+    pub label: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+/// One line of the chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineRow {
+    pub label: String,
+    /// Phase this row belongs to (`None` for gaps)
+    pub phase: Option<String>,
+    pub depth: usize,
+    pub segments: Vec<Segment>,
+}
+
+impl TimelineRow {
+    fn duration_mins(&self) -> i64 {
+        self.segments
+            .iter()
+            .map(|s| s.end.signed_duration_since(s.start).num_minutes())
+            .sum()
+    }
+}
+
+/// Split one phase's recorded timestamps into segments. Each segment runs
+/// until the next one starts, the phase completes, or `now`.
+fn segments_from_marks(
+    marks: &[(SegmentKind, Option<DateTime<Utc>>)],
+    completed_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Vec<Segment> {
+    let starts: Vec<(SegmentKind, DateTime<Utc>)> = marks
+        .iter()
+        .filter_map(|(kind, at)| Some((*kind, (*at)?)))
+        .collect();
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, (kind, start))| {
+            let end = starts
+                .get(i + 1)
+                .map(|(_, next)| *next)
+                .or(completed_at)
+                .unwrap_or(now);
+            Segment {
+                kind: *kind,
+                start: *start,
+                end: end.max(*start),
+            }
+        })
+        .collect()
+}
+
+/// Phase spans from local supervisor state, which records when each stage
+/// of a phase began.
+pub fn spans_from_state(state: &SupervisorState, now: DateTime<Utc>) -> Vec<PhaseSpan> {
+    state
+        .phases
+        .iter()
+        .map(|(phase, ps)| PhaseSpan {
+            phase: phase.clone(),
+            segments: segments_from_marks(
+                &[
+                    (SegmentKind::Planning, ps.planning_started_at),
+                    (SegmentKind::Executing, ps.execution_started_at),
+                    (SegmentKind::Reviewing, ps.review_started_at),
+                ],
+                ps.completed_at,
+                now,
+            ),
+        })
+        .filter(|span| !span.segments.is_empty())
+        .collect()
+}
+
+/// Phase spans from Convex phase records, for orchestrations whose state
+/// isn't on this machine. Stage boundaries are reconstructed from the
+/// recorded planning/execution/review minutes.
+pub fn spans_from_records(records: &[PhaseRecord], now: DateTime<Utc>) -> Vec<PhaseSpan> {
+    records
+        .iter()
+        .filter_map(|record| {
+            let start = record.started_at.as_deref().and_then(parse_rfc3339)?;
+            let completed_at = record.completed_at.as_deref().and_then(parse_rfc3339);
+            let mut marks = vec![(SegmentKind::Planning, Some(start))];
+            let mut at = start;
+            for (kind, mins) in [
+                (SegmentKind::Executing, record.planning_mins),
+                (SegmentKind::Reviewing, record.execution_mins),
+            ] {
+                let Some(mins) = mins else { break };
+                at += chrono::Duration::seconds((mins * 60.0) as i64);
+                marks.push((kind, Some(at)));
+            }
+            Some(PhaseSpan {
+                phase: record.phase_number.clone(),
+                segments: segments_from_marks(&marks, completed_at, now),
+            })
+        })
+        .collect()
+}
+
+/// Order phases by start time and interleave gap rows wherever one phase
+/// finished before the next began. Tasks are listed under `selected_phase`.
+pub fn build_rows(
+    mut phases: Vec<PhaseSpan>,
+    selected_phase: &str,
+    tasks: Option<&[TaskSpan]>,
+) -> Vec<TimelineRow> {
+    phases.sort_by_key(|p| p.start());
+    let mut rows = Vec::new();
+    let mut previous_end: Option<DateTime<Utc>> = None;
+
+    for phase in phases {
+        if let (Some(gap_start), Some(gap_end)) = (previous_end, phase.start()) {
+            if gap_end > gap_start {
+                rows.push(TimelineRow {
+                    label: "idle".to_string(),
+                    phase: None,
+                    depth: 0,
+                    segments: vec![Segment {
+                        kind: SegmentKind::Gap,
+                        start: gap_start,
+                        end: gap_end,
+                    }],
+                });
+            }
+        }
+        previous_end = phase.end().max(previous_end);
+
+        let selected = phase.phase == selected_phase;
+        rows.push(TimelineRow {
+            label: format!("Phase {}", phase.phase),
+            phase: Some(phase.phase.clone()),
+            depth: 0,
+            segments: phase.segments,
+        });
+        if selected {
+            for task in tasks.unwrap_or_default() {
+                rows.push(TimelineRow {
+                    label: task.label.clone(),
+                    phase: Some(selected_phase.to_string()),
+                    depth: 1,
+                    segments: vec![Segment {
+                        kind: SegmentKind::Task,
+                        start: task.start,
+                        end: task.end,
+                    }],
+                });
+            }
+        }
+    }
+    rows
+}
+
+/// Map `at` to a column in `0..width` on the axis `start..end`
+fn column(at: DateTime<Utc>, start: DateTime<Utc>, end: DateTime<Utc>, width: usize) -> usize {
+    let total = end.signed_duration_since(start).num_seconds().max(1);
+    let offset = at
+        .signed_duration_since(start)
+        .num_seconds()
+        .clamp(0, total);
+    ((offset as f64 / total as f64) * width as f64).round() as usize
+}
+
+/// Cells of one bar, each segment at least one column wide
+fn bar_cells(
+    segments: &[Segment],
+    axis: (DateTime<Utc>, DateTime<Utc>),
+    width: usize,
+) -> Vec<Option<SegmentKind>> {
+    let mut cells = vec![None; width];
+    for segment in segments {
+        let from = column(segment.start, axis.0, axis.1, width).min(width.saturating_sub(1));
+        let to = column(segment.end, axis.0, axis.1, width)
+            .max(from + 1)
+            .min(width);
+        for cell in &mut cells[from..to] {
+            *cell = Some(segment.kind);
+        }
+    }
+    cells
+}
+
+fn segment_color(kind: SegmentKind) -> Color {
+    let theme = theme::current();
+    match kind {
+        SegmentKind::Planning => theme.status_planning,
+        SegmentKind::Executing => theme.status_executing,
+        SegmentKind::Reviewing => theme.status_reviewing,
+        SegmentKind::Task => theme.info,
+        SegmentKind::Gap => theme.muted,
+    }
+}
+
+/// Collapse cells into spans of same-kind runs
+fn bar_spans(cells: &[Option<SegmentKind>]) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut start = 0;
+    while start < cells.len() {
+        let kind = cells[start];
+        let len = cells[start..].iter().take_while(|c| **c == kind).count();
+        let span = match kind {
+            None => Span::raw(" ".repeat(len)),
+            Some(SegmentKind::Gap) => Span::styled(
+                "░".repeat(len),
+                Style::default().fg(segment_color(SegmentKind::Gap)),
+            ),
+            Some(kind) => Span::styled("█".repeat(len), Style::default().fg(segment_color(kind))),
+        };
+        spans.push(span);
+        start += len;
+    }
+    spans
+}
+
+fn fit(label: &str, width: usize) -> String {
+    let chars: Vec<char> = label.chars().collect();
+    if chars.len() <= width {
+        format!("{:<width$}", label, width = width)
+    } else {
+        let mut cut: String = chars[..width.saturating_sub(1)].iter().collect();
+        cut.push('…');
+        cut
+    }
+}
+
+/// Gantt chart for one orchestration
+pub struct PhaseTimelineView {
+    pub rows: Vec<TimelineRow>,
+    title: String,
+    selected_phase: String,
+    timing: Option<TimingStats>,
+    tasks_shown: bool,
+}
+
+impl PhaseTimelineView {
+    /// Build the chart, preferring the worktree's local supervisor state and
+    /// falling back to the phases recorded in Convex
+    pub fn new(
+        orch: &MonitorOrchestration,
+        selected_phase: u32,
+        tasks: Option<&[TaskSpan]>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let state = SupervisorState::load_local(&orch.worktree_path).ok();
+        let phases = match &state {
+            Some(state) => spans_from_state(state, now),
+            None => spans_from_records(&orch.phases, now),
+        };
+        let selected_phase = selected_phase.to_string();
+
+        Self {
+            rows: build_rows(phases, &selected_phase, tasks),
+            title: format!(" Timeline - {} ", orch.title()),
+            selected_phase,
+            timing: state.map(|s| s.timing),
+            tasks_shown: tasks.is_some(),
+        }
+    }
+
+    fn axis(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let segments = self.rows.iter().flat_map(|row| &row.segments);
+        let start = segments.clone().map(|s| s.start).min()?;
+        let end = segments.map(|s| s.end).max()?;
+        Some((start, end.max(start + chrono::Duration::minutes(1))))
+    }
+
+    fn summary(&self) -> String {
+        let idle: i64 = self
+            .rows
+            .iter()
+            .filter(|row| row.phase.is_none())
+            .map(TimelineRow::duration_mins)
+            .sum();
+        let mut parts = Vec::new();
+        if let Some(timing) = &self.timing {
+            if let Some(active) = timing.active_mins {
+                parts.push(format!("active {}", Dashboard::format_duration(active)));
+            }
+            if let Some(recorded_idle) = timing.idle_mins {
+                parts.push(format!(
+                    "idle {}",
+                    Dashboard::format_duration(recorded_idle)
+                ));
+            }
+        }
+        parts.push(format!(
+            "between phases {}",
+            Dashboard::format_duration(idle)
+        ));
+        parts.join("  ")
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let theme = theme::current();
+        let muted = Style::default().fg(theme.muted);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.title.clone());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let Some(axis) = self.axis() else {
+            frame.render_widget(
+                Paragraph::new("No phase timing recorded yet").style(muted),
+                inner,
+            );
+            return;
+        };
+        let width = (inner.width as usize).saturating_sub(LABEL_WIDTH + DURATION_WIDTH + 2);
+
+        let mut lines = vec![Line::from(vec![
+            Span::styled(
+                "█ planning ",
+                Style::default().fg(segment_color(SegmentKind::Planning)),
+            ),
+            Span::styled(
+                "█ executing ",
+                Style::default().fg(segment_color(SegmentKind::Executing)),
+            ),
+            Span::styled(
+                "█ reviewing ",
+                Style::default().fg(segment_color(SegmentKind::Reviewing)),
+            ),
+            Span::styled(
+                "█ task ",
+                Style::default().fg(segment_color(SegmentKind::Task)),
+            ),
+            Span::styled("░ idle", muted),
+        ])];
+        lines.push(Line::raw(""));
+
+        for row in &self.rows {
+            let selected = row.depth == 0 && row.phase.as_deref() == Some(&self.selected_phase);
+            let marker = if selected { "▸ " } else { "  " };
+            let label = format!("{}{}{}", marker, "  ".repeat(row.depth), row.label);
+            let label_style = if selected {
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else if row.phase.is_none() {
+                muted
+            } else {
+                Style::default()
+            };
+
+            let mut spans = vec![
+                Span::styled(fit(&label, LABEL_WIDTH), label_style),
+                Span::raw(" "),
+            ];
+            spans.extend(bar_spans(&bar_cells(&row.segments, axis, width)));
+            spans.push(Span::styled(
+                format!(
+                    " {:>width$}",
+                    Dashboard::format_duration(row.duration_mins()),
+                    width = DURATION_WIDTH
+                ),
+                muted,
+            ));
+            lines.push(Line::from(spans));
+        }
+        if self.tasks_shown && !self.rows.iter().any(|row| row.depth > 0) {
+            lines.push(Line::styled(
+                format!(
+                    "{}  no task timing recorded for this phase",
+                    " ".repeat(LABEL_WIDTH)
+                ),
+                muted,
+            ));
+        }
+
+        let start = format_time(axis.0, "%b %d %H:%M");
+        let end = format_time(axis.1, "%b %d %H:%M");
+        let padding = width.saturating_sub(start.len() + end.len());
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(
+            format!(
+                "{} {}{}{}",
+                " ".repeat(LABEL_WIDTH),
+                start,
+                " ".repeat(padding),
+                end
+            ),
+            muted,
+        ));
+        lines.push(Line::styled(self.summary(), muted));
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+    use tina_data::test_util::list_entry;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        parse_rfc3339(s).unwrap()
+    }
+
+    fn record(phase: &str, started: &str, completed: Option<&str>) -> PhaseRecord {
+        PhaseRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: phase.to_string(),
+            status: "complete".to_string(),
+            plan_path: None,
+            git_range: None,
+            planning_mins: Some(10.0),
+            execution_mins: Some(30.0),
+            review_mins: None,
+            started_at: Some(started.to_string()),
+            completed_at: completed.map(str::to_string),
+            risk_score: None,
+            risk_signals: None,
+        }
+    }
+
+    #[test]
+    fn test_spans_from_records_rebuild_stage_boundaries() {
+        let now = at("2026-02-13T12:00:00Z");
+        let spans = spans_from_records(
+            &[record(
+                "1",
+                "2026-02-13T10:00:00Z",
+                Some("2026-02-13T10:50:00Z"),
+            )],
+            now,
+        );
+
+        assert_eq!(spans.len(), 1);
+        let kinds: Vec<(SegmentKind, DateTime<Utc>, DateTime<Utc>)> = spans[0]
+            .segments
+            .iter()
+            .map(|s| (s.kind, s.start, s.end))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (
+                    SegmentKind::Planning,
+                    at("2026-02-13T10:00:00Z"),
+                    at("2026-02-13T10:10:00Z")
+                ),
+                (
+                    SegmentKind::Executing,
+                    at("2026-02-13T10:10:00Z"),
+                    at("2026-02-13T10:40:00Z")
+                ),
+                (
+                    SegmentKind::Reviewing,
+                    at("2026-02-13T10:40:00Z"),
+                    at("2026-02-13T10:50:00Z")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unfinished_phase_runs_until_now() {
+        let now = at("2026-02-13T12:00:00Z");
+        let mut open = record("2", "2026-02-13T11:00:00Z", None);
+        open.planning_mins = None;
+        let spans = spans_from_records(&[open], now);
+        assert_eq!(spans[0].segments.len(), 1);
+        assert_eq!(spans[0].segments[0].end, now);
+    }
+
+    #[test]
+    fn test_build_rows_inserts_gaps_and_selected_phase_tasks() {
+        let now = at("2026-02-13T13:00:00Z");
+        let phases = spans_from_records(
+            &[
+                record("2", "2026-02-13T11:30:00Z", Some("2026-02-13T12:30:00Z")),
+                record("1", "2026-02-13T10:00:00Z", Some("2026-02-13T11:00:00Z")),
+            ],
+            now,
+        );
+        let tasks = vec![TaskSpan {
+            label: "Write tests".to_string(),
+            start: at("2026-02-13T11:45:00Z"),
+            end: at("2026-02-13T12:00:00Z"),
+        }];
+
+        let rows = build_rows(phases, "2", Some(&tasks));
+        let labels: Vec<&str> = rows.iter().map(|r| r.label.as_str()).collect();
+        assert_eq!(labels, vec!["Phase 1", "idle", "Phase 2", "Write tests"]);
+        assert_eq!(rows[1].duration_mins(), 30);
+        assert_eq!(rows[3].depth, 1);
+    }
+
+    #[test]
+    fn test_bar_cells_scale_to_width() {
+        let axis = (at("2026-02-13T10:00:00Z"), at("2026-02-13T11:00:00Z"));
+        let segments = vec![
+            Segment {
+                kind: SegmentKind::Planning,
+                start: axis.0,
+                end: at("2026-02-13T10:30:00Z"),
+            },
+            Segment {
+                kind: SegmentKind::Executing,
+                start: at("2026-02-13T10:30:00Z"),
+                end: at("2026-02-13T10:30:10Z"),
+            },
+        ];
+        let cells = bar_cells(&segments, axis, 10);
+        assert_eq!(&cells[..5], &[Some(SegmentKind::Planning); 5]);
+        // Short segments still get one column
+        assert_eq!(cells[5], Some(SegmentKind::Executing));
+        assert_eq!(cells[6], None);
+    }
+
+    #[test]
+    fn test_render_shows_phases_and_gaps() {
+        let mut entry = list_entry("orch-1", "executing", "2026-02-13T10:00:00Z");
+        entry.record.feature_name = "feature".to_string();
+        entry.record.branch = "tina/feature".to_string();
+        entry.record.worktree_path = Some("/nonexistent/worktree".to_string());
+        entry.record.total_phases = 2.0;
+        entry.record.current_phase = 2.0;
+        let mut orch = MonitorOrchestration::from_list_entry(entry);
+        orch.phases = vec![
+            record("1", "2026-02-13T10:00:00Z", Some("2026-02-13T11:00:00Z")),
+            record("2", "2026-02-13T11:30:00Z", Some("2026-02-13T12:30:00Z")),
+        ];
+        let view = PhaseTimelineView::new(&orch, 2, None, at("2026-02-13T13:00:00Z"));
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|frame| view.render(frame, frame.area()))
+            .unwrap();
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+
+        assert!(content.contains("Phase 1"));
+        assert!(content.contains("▸ Phase 2"));
+        assert!(content.contains("idle"));
+        assert!(content.contains("░"));
+        assert!(content.contains("between phases 30m"));
+    }
+}
@@ -1,8 +1,10 @@
-//! Plan viewer modal for displaying implementation plans
+//! Plan viewer modal for displaying implementation plans and design docs
 
+use super::markdown;
+use crate::theme;
 use ratatui::{
     layout::{Alignment, Rect},
-    style::{Color, Style},
+    style::Style,
     text::Line,
     widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
     Frame,
@@ -15,6 +17,8 @@ pub struct PlanViewer {
     pub path: PathBuf,
     /// Loaded markdown content
     pub content: String,
+    /// Content rendered as styled markdown
+    lines: Vec<Line<'static>>,
     /// Current scroll position
     pub scroll: u16,
     /// Total number of lines
@@ -25,15 +29,13 @@ impl PlanViewer {
     /// Create a new PlanViewer by loading the plan file
     pub fn new(path: PathBuf) -> Result<Self, std::io::Error> {
         let content = std::fs::read_to_string(&path)?;
-        let total_lines = if content.is_empty() {
-            0
-        } else {
-            content.lines().count() as u16
-        };
+        let lines = markdown::render_cached(&content);
+        let total_lines = lines.len() as u16;
 
         Ok(Self {
             path,
             content,
+            lines,
             scroll: 0,
             total_lines,
         })
@@ -59,13 +61,12 @@ impl PlanViewer {
             .and_then(|n| n.to_str())
             .unwrap_or("Unknown");
 
-        // Convert content to lines
         let lines: Vec<Line> = self
-            .content
-            .lines()
+            .lines
+            .iter()
             .skip(self.scroll as usize)
             .take(area.height.saturating_sub(2) as usize) // Account for borders
-            .map(|line| Line::from(line.to_string()))
+            .cloned()
             .collect();
 
         let paragraph = Paragraph::new(lines)
@@ -75,7 +76,7 @@ impl PlanViewer {
                     .title(format!(" {} ", filename))
                     .title_alignment(Alignment::Center),
             )
-            .style(Style::default().fg(Color::White));
+            .style(Style::default().fg(theme::current().text));
 
         frame.render_widget(paragraph, area);
 
@@ -282,6 +283,35 @@ mod tests {
         assert!(content.contains(&filename), "Title should contain filename");
     }
 
+    #[test]
+    fn test_plan_viewer_renders_markdown() {
+        use ratatui::{backend::TestBackend, layout::Rect, Terminal};
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        write!(temp_file, "# Phase Plan\n\n- [x] **Write** tests\n- [ ] Ship").unwrap();
+        temp_file.flush().unwrap();
+
+        let viewer = PlanViewer::new(temp_file.path().to_path_buf()).unwrap();
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|frame| viewer.render(frame, Rect::new(0, 0, 80, 24)))
+            .unwrap();
+
+        let content = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect::<String>();
+        assert!(content.contains("Phase Plan"));
+        assert!(!content.contains("# Phase"), "Heading marker should be hidden");
+        assert!(content.contains("☑ Write tests"));
+        assert!(content.contains("☐ Ship"));
+    }
+
     #[test]
     fn test_plan_viewer_render_displays_content() {
         use ratatui::{backend::TestBackend, layout::Rect, Terminal};
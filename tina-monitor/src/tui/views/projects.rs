@@ -0,0 +1,330 @@
+//! Projects view
+//!
+//! Lists Convex projects with how many orchestrations each has and what
+//! state they're in. Enter narrows the orchestration list to a project;
+//! `n` opens a form to create one.
+
+use std::path::Path;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::data::MonitorOrchestration;
+use crate::theme;
+use crate::tui::app::App;
+use crate::tui::views::orchestration_list::status_counts;
+
+/// A row of the projects view
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectRow {
+    /// `None` for orchestrations that belong to no project
+    pub id: Option<String>,
+    pub name: String,
+    pub repo_path: String,
+    /// Orchestrations in the project, including ones not loaded yet
+    pub total: usize,
+    /// Indices of the project's loaded orchestrations
+    pub orchestrations: Vec<usize>,
+}
+
+/// Every known project by name, then a "No project" row when some
+/// orchestrations have none
+pub fn rows(app: &App) -> Vec<ProjectRow> {
+    let members = |id: Option<&str>| -> Vec<usize> {
+        app.orchestrations
+            .iter()
+            .enumerate()
+            .filter(|(_, orch)| orch.project_id.as_deref() == id)
+            .map(|(i, _)| i)
+            .collect()
+    };
+
+    let mut rows: Vec<ProjectRow> = app
+        .list_paging
+        .projects
+        .iter()
+        .map(|project| {
+            let orchestrations = members(Some(&project.id));
+            ProjectRow {
+                id: Some(project.id.clone()),
+                name: project.name.clone(),
+                repo_path: project.repo_path.clone(),
+                total: (project.orchestration_count.unwrap_or(0) as usize)
+                    .max(orchestrations.len()),
+                orchestrations,
+            }
+        })
+        .collect();
+    rows.sort_by_key(|row| row.name.to_lowercase());
+
+    let unassigned = members(None);
+    if !unassigned.is_empty() {
+        rows.push(ProjectRow {
+            id: None,
+            name: "No project".to_string(),
+            repo_path: String::new(),
+            total: unassigned.len(),
+            orchestrations: unassigned,
+        });
+    }
+    rows
+}
+
+/// Render the projects list, with the new project form on top when open
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    app: &App,
+    selected: usize,
+    form: Option<&ProjectForm>,
+) {
+    let theme = theme::current();
+    let rows = rows(app);
+    let name_width = rows.iter().map(|row| row.name.len()).max().unwrap_or(0);
+
+    let items: Vec<ListItem> = rows
+        .iter()
+        .map(|row| {
+            let loaded: Vec<&MonitorOrchestration> = row
+                .orchestrations
+                .iter()
+                .map(|&i| &app.orchestrations[i])
+                .collect();
+            let count = if row.total > loaded.len() {
+                format!("{} ({} loaded)", row.total, loaded.len())
+            } else {
+                row.total.to_string()
+            };
+            let mut spans = vec![
+                Span::styled(
+                    format!("{:<width$}", row.name, width = name_width),
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(
+                        "  {} orchestration{}",
+                        count,
+                        if row.total == 1 { "" } else { "s" }
+                    ),
+                    Style::default().fg(theme.text),
+                ),
+            ];
+            spans.extend(status_counts(loaded));
+            if !row.repo_path.is_empty() {
+                spans.push(Span::styled(
+                    format!("  {}", row.repo_path),
+                    Style::default().fg(theme.muted),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let title = format!(" Projects ({}) ", app.list_paging.projects.len());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(theme.border));
+    if items.is_empty() {
+        let empty = Paragraph::new(Line::from(Span::styled(
+            "No projects yet. Press n to create one.",
+            Style::default().fg(theme.muted),
+        )))
+        .block(block);
+        frame.render_widget(empty, area);
+    } else {
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme.selection().add_modifier(Modifier::BOLD))
+            .highlight_symbol("> ");
+        let mut state = ListState::default();
+        state.select(Some(selected.min(rows.len() - 1)));
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    if let Some(form) = form {
+        let width = area.width.min(70);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + area.height.saturating_sub(9) / 2,
+            width,
+            height: area.height.min(9),
+        };
+        form.render(frame, popup);
+    }
+}
+
+/// Field with keyboard focus in the new project form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectField {
+    Name,
+    RepoPath,
+}
+
+/// What a key press in the form asks the app to do
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProjectFormResult {
+    Consumed,
+    Cancel,
+    Submit,
+}
+
+/// Popup for creating a project, matching the web's name + repo path model
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectForm {
+    pub name: String,
+    pub repo_path: String,
+    pub field: ProjectField,
+    /// Why the last submit failed
+    pub error: Option<String>,
+}
+
+impl ProjectForm {
+    /// A form prefilled for the repository at `repo_path`, named after its
+    /// directory
+    pub fn new(repo_path: &Path) -> Self {
+        Self {
+            name: repo_path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            repo_path: repo_path.display().to_string(),
+            field: ProjectField::Name,
+            error: None,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> ProjectFormResult {
+        let text = match self.field {
+            ProjectField::Name => &mut self.name,
+            ProjectField::RepoPath => &mut self.repo_path,
+        };
+        match key.code {
+            KeyCode::Esc => return ProjectFormResult::Cancel,
+            KeyCode::Enter => return ProjectFormResult::Submit,
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => {
+                self.field = match self.field {
+                    ProjectField::Name => ProjectField::RepoPath,
+                    ProjectField::RepoPath => ProjectField::Name,
+                };
+            }
+            KeyCode::Char(c) => text.push(c),
+            KeyCode::Backspace => {
+                text.pop();
+            }
+            _ => {}
+        }
+        ProjectFormResult::Consumed
+    }
+
+    /// Trimmed name and repo path, or why they can't be submitted
+    pub fn values(&self) -> Result<(&str, &str), String> {
+        let name = self.name.trim();
+        let repo_path = self.repo_path.trim();
+        if name.is_empty() {
+            return Err("A project needs a name".to_string());
+        }
+        if repo_path.is_empty() {
+            return Err("A project needs a repository path".to_string());
+        }
+        Ok((name, repo_path))
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let theme = theme::current();
+        let muted = Style::default().fg(theme.muted);
+        let label = |field, text| {
+            let style = if self.field == field {
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            Span::styled(text, style)
+        };
+        let value = |field, text: &str| {
+            let cursor = if self.field == field { "█" } else { "" };
+            Span::styled(
+                format!("{}{}", text, cursor),
+                Style::default().fg(theme.text),
+            )
+        };
+
+        let mut lines = vec![
+            Line::from(vec![
+                label(ProjectField::Name, "Name       "),
+                value(ProjectField::Name, &self.name),
+            ]),
+            Line::from(vec![
+                label(ProjectField::RepoPath, "Repo path  "),
+                value(ProjectField::RepoPath, &self.repo_path),
+            ]),
+        ];
+        if let Some(error) = &self.error {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                error.clone(),
+                Style::default().fg(theme.error),
+            )));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Tab: field  Enter: create  Esc: cancel",
+            muted,
+        )));
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" New project ")
+            .border_style(Style::default().fg(theme.border));
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(Wrap { trim: false }),
+            area,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_form_is_prefilled_from_the_repo_path_and_edits_the_focused_field() {
+        let mut form = ProjectForm::new(Path::new("/code/acme"));
+        assert_eq!(form.name, "acme");
+        assert_eq!(form.repo_path, "/code/acme");
+
+        form.handle_key(key(KeyCode::Char('2')));
+        form.handle_key(key(KeyCode::Tab));
+        form.handle_key(key(KeyCode::Backspace));
+        assert_eq!(form.values(), Ok(("acme2", "/code/acm")));
+        assert_eq!(
+            form.handle_key(key(KeyCode::Enter)),
+            ProjectFormResult::Submit
+        );
+        assert_eq!(
+            form.handle_key(key(KeyCode::Esc)),
+            ProjectFormResult::Cancel
+        );
+
+        form.name = "  ".to_string();
+        assert!(form.values().is_err());
+    }
+}
@@ -0,0 +1,287 @@
+//! Replay view for stepping through an orchestration's recorded history
+//!
+//! Shows tasks, phases and agents as they stood at the selected event, with
+//! the events leading up to it underneath.
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Gauge, Paragraph},
+    Frame,
+};
+
+use crate::data::replay::{Replay, ReplayState};
+use crate::theme;
+
+/// A loaded replay for one orchestration
+pub struct ReplayViewer {
+    pub feature: String,
+    pub replay: Replay,
+}
+
+impl ReplayViewer {
+    pub fn new(feature: String, replay: Replay) -> Self {
+        Self { feature, replay }
+    }
+
+    /// Render the replay full-screen
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let theme = theme::current();
+        frame.render_widget(Clear, area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Replay · {} ", self.feature))
+            .title_alignment(Alignment::Center);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if self.replay.is_empty() {
+            frame.render_widget(
+                Paragraph::new("No recorded events").style(Style::default().fg(theme.muted)),
+                inner,
+            );
+            return;
+        }
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Min(4),
+                Constraint::Length(8),
+            ])
+            .split(inner);
+        self.render_scrubber(frame, rows[0]);
+
+        let state = self.replay.state();
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+            .split(rows[1]);
+        render_tasks(frame, columns[0], &state);
+        render_phases_and_agents(frame, columns[1], &state);
+        self.render_events(frame, rows[2]);
+    }
+
+    /// Progress through the stream with the cursor's timestamp
+    fn render_scrubber(&self, frame: &mut Frame, area: Rect) {
+        let theme = theme::current();
+        let position = self.replay.cursor() + 1;
+        let total = self.replay.len();
+        let at = self
+            .replay
+            .at()
+            .map(|at| at.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_default();
+        let gauge = Gauge::default()
+            .gauge_style(Style::default().fg(theme.progress).bg(theme.bar_bg))
+            .ratio(position as f64 / total as f64)
+            .label(format!("{}  event {}/{}", at, position, total));
+        frame.render_widget(gauge, area);
+    }
+
+    /// The most recent applied events, the cursor's event highlighted
+    fn render_events(&self, frame: &mut Frame, area: Rect) {
+        let theme = theme::current();
+        let height = area.height.saturating_sub(2) as usize;
+        let applied = self.replay.applied();
+        let skip = applied.len().saturating_sub(height);
+        let last = applied.len().saturating_sub(1);
+        let lines: Vec<Line> = applied
+            .iter()
+            .enumerate()
+            .skip(skip)
+            .map(|(i, (at, event))| {
+                let style = if i == last {
+                    Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.text)
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!("{} ", at.format("%H:%M:%S")),
+                        Style::default().fg(theme.muted),
+                    ),
+                    Span::styled(
+                        format!("P{:<3}", event.phase().unwrap_or("-")),
+                        Style::default().fg(theme.muted),
+                    ),
+                    Span::styled(event.describe(), style),
+                ])
+            })
+            .collect();
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::default().borders(Borders::TOP).title(" Events ")),
+            area,
+        );
+    }
+}
+
+fn status_style(status: &str) -> Style {
+    let theme = theme::current();
+    let color = match status {
+        "completed" | "complete" => theme.status_complete,
+        "in_progress" | "executing" => theme.status_executing,
+        "blocked" => theme.status_blocked,
+        _ => theme.muted,
+    };
+    Style::default().fg(color)
+}
+
+fn render_tasks(frame: &mut Frame, area: Rect, state: &ReplayState) {
+    let theme = theme::current();
+    let lines: Vec<Line> = state
+        .tasks
+        .iter()
+        .map(|task| {
+            Line::from(vec![
+                Span::styled(format!("{:<12}", task.status), status_style(&task.status)),
+                Span::styled(
+                    format!(
+                        "P{} #{} ",
+                        task.phase.as_deref().unwrap_or("-"),
+                        task.task_id
+                    ),
+                    Style::default().fg(theme.muted),
+                ),
+                Span::styled(task.subject.clone(), Style::default().fg(theme.text)),
+                Span::styled(
+                    task.owner
+                        .as_ref()
+                        .map(|owner| format!("  {}", owner))
+                        .unwrap_or_default(),
+                    Style::default().fg(theme.info),
+                ),
+            ])
+        })
+        .collect();
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::RIGHT)
+                .title(format!(" Tasks ({}) ", state.tasks.len())),
+        ),
+        area,
+    );
+}
+
+fn render_phases_and_agents(frame: &mut Frame, area: Rect, state: &ReplayState) {
+    let theme = theme::current();
+    let mut lines: Vec<Line> = state
+        .phases
+        .iter()
+        .map(|(phase, status)| {
+            let marker = if state.current_phase.as_deref() == Some(phase) {
+                "▸"
+            } else {
+                " "
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!("{} Phase {} ", marker, phase),
+                    Style::default().fg(theme.text),
+                ),
+                Span::styled(status.clone(), status_style(status)),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Agents",
+        Style::default().add_modifier(Modifier::BOLD),
+    )));
+    lines.extend(state.agents.iter().map(|agent| {
+        let (label, color) = if agent.shut_down {
+            ("shut down", theme.muted)
+        } else {
+            ("active", theme.success)
+        };
+        Line::from(vec![
+            Span::styled(format!(" {} ", agent.name), Style::default().fg(theme.text)),
+            Span::styled(label, Style::default().fg(color)),
+        ])
+    }));
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::default().title(" Phases ")),
+        area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+    use tina_data::{OrchestrationEventRecord, TaskEventRecord};
+
+    fn rendered(viewer: &ReplayViewer) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+        terminal
+            .draw(|frame| viewer.render(frame, frame.area()))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect()
+    }
+
+    fn task(status: &str, at: &str) -> TaskEventRecord {
+        TaskEventRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: Some("1".to_string()),
+            task_id: "1".to_string(),
+            subject: "Add login".to_string(),
+            description: None,
+            status: status.to_string(),
+            owner: Some("worker-1".to_string()),
+            blocked_by: None,
+            metadata: None,
+            recorded_at: at.to_string(),
+        }
+    }
+
+    #[test]
+    fn renders_state_at_cursor() {
+        let replay = Replay::new(
+            vec![
+                task("in_progress", "2026-02-10T10:05:00Z"),
+                task("completed", "2026-02-10T10:20:00Z"),
+            ],
+            vec![OrchestrationEventRecord {
+                orchestration_id: "orch-1".to_string(),
+                phase_number: Some("1".to_string()),
+                event_type: "phase_started".to_string(),
+                source: "tina-session".to_string(),
+                summary: "Phase 1 started".to_string(),
+                detail: None,
+                recorded_at: "2026-02-10T10:00:00Z".to_string(),
+            }],
+        );
+        let mut viewer = ReplayViewer::new("auth".to_string(), replay);
+        let content = rendered(&viewer);
+        assert!(content.contains("Replay · auth"));
+        assert!(content.contains("event 1/3"));
+        assert!(content.contains("Phase 1 executing"));
+        assert!(!content.contains("Add login"));
+
+        viewer.replay.step(1);
+        let content = rendered(&viewer);
+        assert!(content.contains("in_progress"));
+        assert!(content.contains("Add login"));
+        assert!(content.contains("worker-1 active"));
+
+        viewer.replay.to_end();
+        assert!(rendered(&viewer).contains("completed"));
+    }
+
+    #[test]
+    fn renders_empty_replay() {
+        let viewer = ReplayViewer::new("auth".to_string(), Replay::new(vec![], vec![]));
+        assert!(rendered(&viewer).contains("No recorded events"));
+    }
+}
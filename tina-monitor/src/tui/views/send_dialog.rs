@@ -1,9 +1,11 @@
 //! Send dialog for sending commands to agents
 
 use crate::overlay::centered_rect;
+use crate::overlay::fuzzy::fuzzy_score;
+use crate::theme;
 use ratatui::{
     layout::{Alignment, Rect},
-    style::{Color, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
@@ -11,8 +13,11 @@ use ratatui::{
 
 /// Send dialog state
 pub struct SendDialog {
-    /// Text input buffer
+    /// Text input buffer; may span several lines
     pub input: String,
+    /// Cursor position as bytes before the end of `input`, so replacing the
+    /// input leaves the cursor at the end of the new text
+    pub cursor_from_end: usize,
     /// Pane ID to send to
     pub pane_id: String,
     /// Agent name for display
@@ -23,28 +28,267 @@ pub struct SendDialog {
     pub needs_confirmation: bool,
     /// Whether we're in confirmation state
     pub confirming: bool,
+    /// Commands previously sent to this agent
+    pub history: CommandHistory,
 }
 
+/// Commands previously sent to an agent, recalled with Up/Down or searched
+/// with Ctrl+R
+#[derive(Debug, Clone, Default)]
+pub struct CommandHistory {
+    /// Sent commands, most recent first
+    pub entries: Vec<String>,
+    /// Entry currently recalled into the input while browsing
+    pub position: Option<usize>,
+    /// Input typed before browsing, restored when moving past the newest entry
+    pub draft: String,
+    /// Fuzzy search over the entries, when open
+    pub search: Option<HistorySearch>,
+}
+
+/// Ctrl+R search state
+#[derive(Debug, Clone, Default)]
+pub struct HistorySearch {
+    pub query: String,
+    /// Index into the current matches
+    pub selected: usize,
+}
+
+/// Search matches shown in the dialog
+const SEARCH_RESULTS_SHOWN: usize = 5;
+
+/// Input lines shown at once; longer input scrolls to follow the cursor
+const INPUT_LINES_SHOWN: usize = 8;
+
 impl SendDialog {
     pub fn new(pane_id: String, agent_name: String, needs_confirmation: bool) -> Self {
         Self {
             input: String::new(),
+            cursor_from_end: 0,
             pane_id,
             agent_name,
             quick_action: 0,
             needs_confirmation,
             confirming: false,
+            history: CommandHistory::default(),
         }
     }
 
+    /// Attach previously sent commands, most recent first
+    pub fn with_history(mut self, entries: Vec<String>) -> Self {
+        self.history.entries = entries;
+        self
+    }
+
+    /// Byte offset of the cursor in `input`
+    pub fn cursor(&self) -> usize {
+        self.input.len().saturating_sub(self.cursor_from_end)
+    }
+
+    fn set_cursor(&mut self, offset: usize) {
+        self.cursor_from_end = self.input.len() - offset;
+    }
+
     /// Handle character input
     pub fn handle_char(&mut self, c: char) {
-        self.input.push(c);
+        let cursor = self.cursor();
+        self.input.insert(cursor, c);
+        self.history.position = None;
+    }
+
+    /// Insert a line break at the cursor
+    pub fn insert_newline(&mut self) {
+        self.handle_char('\n');
+    }
+
+    /// Insert pasted text at the cursor, normalizing line endings
+    pub fn insert_str(&mut self, text: &str) {
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        let cursor = self.cursor();
+        self.input.insert_str(cursor, &text);
+        self.history.position = None;
     }
 
-    /// Handle backspace
+    /// Handle backspace, deleting the character before the cursor
     pub fn handle_backspace(&mut self) {
-        self.input.pop();
+        let cursor = self.cursor();
+        if let Some(c) = self.input[..cursor].chars().next_back() {
+            self.input.replace_range(cursor - c.len_utf8()..cursor, "");
+        }
+        self.history.position = None;
+    }
+
+    /// Move the cursor one character left
+    pub fn cursor_left(&mut self) {
+        let cursor = self.cursor();
+        if let Some(c) = self.input[..cursor].chars().next_back() {
+            self.set_cursor(cursor - c.len_utf8());
+        }
+    }
+
+    /// Move the cursor one character right
+    pub fn cursor_right(&mut self) {
+        let cursor = self.cursor();
+        if let Some(c) = self.input[cursor..].chars().next() {
+            self.set_cursor(cursor + c.len_utf8());
+        }
+    }
+
+    /// Move the cursor to the start of its line
+    pub fn cursor_home(&mut self) {
+        let (start, _) = self.cursor_line_bounds();
+        self.set_cursor(start);
+    }
+
+    /// Move the cursor to the end of its line
+    pub fn cursor_end(&mut self) {
+        let (_, end) = self.cursor_line_bounds();
+        self.set_cursor(end);
+    }
+
+    /// Move the cursor to the previous line, keeping its column where the
+    /// line is long enough. Returns false on the first line.
+    pub fn cursor_up(&mut self) -> bool {
+        let (start, _) = self.cursor_line_bounds();
+        if start == 0 {
+            return false;
+        }
+        let column = self.input[start..self.cursor()].chars().count();
+        let prev_end = start - 1;
+        let prev_start = self.input[..prev_end].rfind('\n').map_or(0, |i| i + 1);
+        let offset = column_offset(&self.input[prev_start..prev_end], column);
+        self.set_cursor(prev_start + offset);
+        true
+    }
+
+    /// Move the cursor to the next line, keeping its column where the line
+    /// is long enough. Returns false on the last line.
+    pub fn cursor_down(&mut self) -> bool {
+        let (start, end) = self.cursor_line_bounds();
+        if end == self.input.len() {
+            return false;
+        }
+        let column = self.input[start..self.cursor()].chars().count();
+        let next_start = end + 1;
+        let next_end = self.input[next_start..]
+            .find('\n')
+            .map_or(self.input.len(), |i| next_start + i);
+        let offset = column_offset(&self.input[next_start..next_end], column);
+        self.set_cursor(next_start + offset);
+        true
+    }
+
+    /// Byte range of the line holding the cursor, excluding its newline
+    fn cursor_line_bounds(&self) -> (usize, usize) {
+        let cursor = self.cursor();
+        let start = self.input[..cursor].rfind('\n').map_or(0, |i| i + 1);
+        let end = self.input[cursor..]
+            .find('\n')
+            .map_or(self.input.len(), |i| cursor + i);
+        (start, end)
+    }
+
+    /// Recall the next older history entry into the input
+    pub fn history_older(&mut self) {
+        let history = &mut self.history;
+        if history.entries.is_empty() {
+            return;
+        }
+        let position = match history.position {
+            None => {
+                history.draft = self.input.clone();
+                0
+            }
+            Some(p) => (p + 1).min(history.entries.len() - 1),
+        };
+        history.position = Some(position);
+        self.input = history.entries[position].clone();
+        self.cursor_from_end = 0;
+    }
+
+    /// Recall the next newer history entry, or the draft past the newest
+    pub fn history_newer(&mut self) {
+        let history = &mut self.history;
+        match history.position {
+            None => {}
+            Some(0) => {
+                history.position = None;
+                self.input = std::mem::take(&mut history.draft);
+            }
+            Some(p) => {
+                history.position = Some(p - 1);
+                self.input = history.entries[p - 1].clone();
+            }
+        }
+        self.cursor_from_end = 0;
+    }
+
+    /// Open the Ctrl+R history search
+    pub fn open_history_search(&mut self) {
+        if !self.history.entries.is_empty() {
+            self.history.search = Some(HistorySearch::default());
+        }
+    }
+
+    /// History entries matching the search query, best match first
+    pub fn search_matches(&self) -> Vec<&str> {
+        let Some(search) = &self.history.search else {
+            return Vec::new();
+        };
+        let mut scored: Vec<(i64, &str)> = self
+            .history
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                fuzzy_score(&search.query, entry).map(|score| (score, entry.as_str()))
+            })
+            .collect();
+        // Stable, so equal scores stay most recent first
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    /// Handle character input while searching
+    pub fn search_char(&mut self, c: char) {
+        if let Some(search) = &mut self.history.search {
+            search.query.push(c);
+            search.selected = 0;
+        }
+    }
+
+    /// Handle backspace while searching
+    pub fn search_backspace(&mut self) {
+        if let Some(search) = &mut self.history.search {
+            search.query.pop();
+            search.selected = 0;
+        }
+    }
+
+    /// Move the search selection by `delta`, clamped to the matches
+    pub fn search_move(&mut self, delta: isize) {
+        let count = self.search_matches().len();
+        if let Some(search) = &mut self.history.search {
+            search.selected = search
+                .selected
+                .saturating_add_signed(delta)
+                .min(count.saturating_sub(1));
+        }
+    }
+
+    /// Put the selected search match in the input and close the search
+    pub fn accept_history_search(&mut self) {
+        let selected = self.history.search.as_ref().map_or(0, |s| s.selected);
+        if let Some(entry) = self.search_matches().get(selected) {
+            self.input = entry.to_string();
+            self.cursor_from_end = 0;
+        }
+        self.history.search = None;
+        self.history.position = None;
+    }
+
+    /// Close the search, leaving the input unchanged
+    pub fn close_history_search(&mut self) {
+        self.history.search = None;
     }
 
     /// Set quick action (sets input to the quick action text)
@@ -55,6 +299,7 @@ impl SendDialog {
             2 => "/clear".to_string(),
             _ => String::new(),
         };
+        self.cursor_from_end = 0;
     }
 
     /// Get the command text to send
@@ -69,6 +314,52 @@ impl SendDialog {
     }
 }
 
+/// Byte offset of the `column`th character in `line`, or its end if shorter
+fn column_offset(line: &str, column: usize) -> usize {
+    line.char_indices()
+        .nth(column)
+        .map_or(line.len(), |(i, _)| i)
+}
+
+/// Input lines to display, with the cursor drawn as a reversed cell
+fn input_lines(dialog: &SendDialog) -> Vec<Line<'_>> {
+    let text_style = Style::default().fg(theme::current().text);
+    let cursor_style = text_style.add_modifier(Modifier::REVERSED);
+    let cursor = dialog.cursor();
+
+    let mut lines = Vec::new();
+    let mut cursor_line = 0;
+    let mut start = 0;
+    for (index, line) in dialog.input.split('\n').enumerate() {
+        let end = start + line.len();
+        if (start..=end).contains(&cursor) {
+            cursor_line = index;
+            let (before, rest) = line.split_at(cursor - start);
+            let mut chars = rest.chars();
+            let under = chars.next().map_or(" ".to_string(), |c| c.to_string());
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(before, text_style),
+                Span::styled(under, cursor_style),
+                Span::styled(chars.as_str(), text_style),
+            ]));
+        } else {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(line, text_style),
+            ]));
+        }
+        start = end + 1;
+    }
+
+    let first = (cursor_line + 1).saturating_sub(INPUT_LINES_SHOWN);
+    lines
+        .into_iter()
+        .skip(first)
+        .take(INPUT_LINES_SHOWN)
+        .collect()
+}
+
 /// Render the send dialog
 pub fn render(dialog: &SendDialog, frame: &mut Frame, area: Rect) {
     // Calculate centered rectangle for the dialog
@@ -79,25 +370,93 @@ pub fn render(dialog: &SendDialog, frame: &mut Frame, area: Rect) {
     let mut lines = vec![
         Line::from(""),
         Line::from(vec![
-            Span::styled("Send to: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(&dialog.agent_name, Style::default().fg(Color::Cyan)),
-        ]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("Command: ", Style::default().fg(Color::Yellow)),
-            Span::styled(&dialog.input, Style::default().fg(Color::White)),
+            Span::styled("Send to: ", Style::default().fg(theme::current().muted)),
+            Span::styled(
+                &dialog.agent_name,
+                Style::default().fg(theme::current().accent),
+            ),
         ]),
         Line::from(""),
+        Line::from(Span::styled(
+            "Command:",
+            Style::default().fg(theme::current().highlight),
+        )),
     ];
+    lines.extend(input_lines(dialog));
+    lines.push(Line::from(""));
+
+    if let Some(search) = &dialog.history.search {
+        lines.push(Line::from(vec![
+            Span::styled(
+                "History search: ",
+                Style::default().fg(theme::current().muted),
+            ),
+            Span::styled(&search.query, Style::default().fg(theme::current().text)),
+        ]));
+        let matches = dialog.search_matches();
+        if matches.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  no matches",
+                Style::default().fg(theme::current().muted),
+            )));
+        }
+        for (i, entry) in matches.iter().take(SEARCH_RESULTS_SHOWN).enumerate() {
+            let style = if i == search.selected {
+                Style::default()
+                    .fg(theme::current().accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme::current().text)
+            };
+            let marker = if i == search.selected { "> " } else { "  " };
+            lines.push(Line::from(Span::styled(
+                format!("{}{}", marker, entry),
+                style,
+            )));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("[Enter] ", Style::default().fg(theme::current().success)),
+            Span::raw("Use  "),
+            Span::styled("[Esc] ", Style::default().fg(theme::current().error)),
+            Span::raw("Back"),
+        ]));
+        let paragraph = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Send Command ")
+                    .title_alignment(Alignment::Center),
+            )
+            .style(Style::default().fg(theme::current().text));
+        frame.render_widget(paragraph, dialog_area);
+        return;
+    }
+
+    if !dialog.history.entries.is_empty() {
+        let position = match dialog.history.position {
+            Some(p) => format!("{}/{}", p + 1, dialog.history.entries.len()),
+            None => format!("{} sent", dialog.history.entries.len()),
+        };
+        lines.push(Line::from(vec![
+            Span::styled("History: ", Style::default().fg(theme::current().muted)),
+            Span::raw(position),
+            Span::styled(
+                "  [↑/↓] Recall  [Ctrl+R] Search",
+                Style::default().fg(theme::current().muted),
+            ),
+        ]));
+        lines.push(Line::from(""));
+    }
 
     // Quick actions
     lines.push(Line::from("Quick actions:"));
     lines.push(Line::from(vec![
-        Span::styled("[1] ", Style::default().fg(Color::DarkGray)),
-        Span::styled("/checkpoint", Style::default().fg(Color::Green)),
+        Span::styled("[1] ", Style::default().fg(theme::current().muted)),
+        Span::styled("/checkpoint", Style::default().fg(theme::current().success)),
         Span::raw("  "),
-        Span::styled("[2] ", Style::default().fg(Color::DarkGray)),
-        Span::styled("/clear", Style::default().fg(Color::Red)),
+        Span::styled("[2] ", Style::default().fg(theme::current().muted)),
+        Span::styled("/clear", Style::default().fg(theme::current().error)),
     ]));
     lines.push(Line::from(""));
 
@@ -105,7 +464,7 @@ pub fn render(dialog: &SendDialog, frame: &mut Frame, area: Rect) {
     if dialog.needs_confirmation && !dialog.confirming {
         lines.push(Line::from(Span::styled(
             "⚠ Warning: This will interrupt the agent",
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(theme::current().highlight),
         )));
         lines.push(Line::from(""));
     }
@@ -113,16 +472,18 @@ pub fn render(dialog: &SendDialog, frame: &mut Frame, area: Rect) {
     // Confirmation state or send instructions
     if dialog.confirming {
         lines.push(Line::from(vec![
-            Span::styled("[Enter] ", Style::default().fg(Color::Green)),
+            Span::styled("[Enter] ", Style::default().fg(theme::current().success)),
             Span::raw("Confirm  "),
-            Span::styled("[Esc] ", Style::default().fg(Color::Red)),
+            Span::styled("[Esc] ", Style::default().fg(theme::current().error)),
             Span::raw("Cancel"),
         ]));
     } else {
         lines.push(Line::from(vec![
-            Span::styled("[Enter] ", Style::default().fg(Color::Green)),
+            Span::styled("[Enter] ", Style::default().fg(theme::current().success)),
             Span::raw("Send  "),
-            Span::styled("[Esc] ", Style::default().fg(Color::Red)),
+            Span::styled("[Alt+Enter] ", Style::default().fg(theme::current().muted)),
+            Span::raw("Newline  "),
+            Span::styled("[Esc] ", Style::default().fg(theme::current().error)),
             Span::raw("Cancel"),
         ]));
     }
@@ -134,7 +495,7 @@ pub fn render(dialog: &SendDialog, frame: &mut Frame, area: Rect) {
                 .title(" Send Command ")
                 .title_alignment(Alignment::Center),
         )
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(theme::current().text));
 
     frame.render_widget(paragraph, dialog_area);
 }
@@ -202,11 +563,13 @@ mod tests {
     fn test_is_safe_command_returns_true_for_configured_commands() {
         let dialog_checkpoint = SendDialog {
             input: "/checkpoint".to_string(),
+            cursor_from_end: 0,
             pane_id: "pane_123".to_string(),
             agent_name: "test-agent".to_string(),
             quick_action: 1,
             needs_confirmation: true,
             confirming: false,
+            history: CommandHistory::default(),
         };
 
         let safe_commands = vec![
@@ -219,11 +582,13 @@ mod tests {
 
         let dialog_status = SendDialog {
             input: "/status".to_string(),
+            cursor_from_end: 0,
             pane_id: "pane_123".to_string(),
             agent_name: "test-agent".to_string(),
             quick_action: 0,
             needs_confirmation: true,
             confirming: false,
+            history: CommandHistory::default(),
         };
 
         assert!(dialog_status.is_safe_command(&safe_commands));
@@ -233,11 +598,13 @@ mod tests {
     fn test_is_safe_command_returns_false_for_unknown_commands() {
         let dialog = SendDialog {
             input: "/dangerous".to_string(),
+            cursor_from_end: 0,
             pane_id: "pane_123".to_string(),
             agent_name: "test-agent".to_string(),
             quick_action: 0,
             needs_confirmation: true,
             confirming: false,
+            history: CommandHistory::default(),
         };
 
         let safe_commands = vec!["/checkpoint".to_string(), "/status".to_string()];
@@ -245,6 +612,116 @@ mod tests {
         assert!(!dialog.is_safe_command(&safe_commands));
     }
 
+    fn dialog_with_history() -> SendDialog {
+        SendDialog::new("pane_123".to_string(), "test-agent".to_string(), false).with_history(vec![
+            "/checkpoint".to_string(),
+            "run the tests again".to_string(),
+            "/clear".to_string(),
+        ])
+    }
+
+    #[test]
+    fn test_history_up_down_recalls_entries_and_restores_draft() {
+        let mut dialog = dialog_with_history();
+        dialog.input = "draft".to_string();
+
+        dialog.history_older();
+        assert_eq!(dialog.input, "/checkpoint");
+        dialog.history_older();
+        assert_eq!(dialog.input, "run the tests again");
+        dialog.history_older();
+        dialog.history_older();
+        assert_eq!(dialog.input, "/clear", "Up stops at the oldest entry");
+
+        dialog.history_newer();
+        assert_eq!(dialog.input, "run the tests again");
+        dialog.history_newer();
+        dialog.history_newer();
+        assert_eq!(
+            dialog.input, "draft",
+            "Down past the newest restores the draft"
+        );
+        assert_eq!(dialog.history.position, None);
+    }
+
+    #[test]
+    fn test_history_search_filters_and_accepts_selection() {
+        let mut dialog = dialog_with_history();
+        dialog.open_history_search();
+        assert_eq!(dialog.search_matches().len(), 3);
+
+        for c in "/c".chars() {
+            dialog.search_char(c);
+        }
+        assert_eq!(dialog.search_matches(), vec!["/checkpoint", "/clear"]);
+
+        dialog.search_move(1);
+        dialog.search_move(1);
+        dialog.accept_history_search();
+        assert_eq!(dialog.input, "/clear");
+        assert!(dialog.history.search.is_none());
+    }
+
+    #[test]
+    fn test_history_search_needs_history() {
+        let mut dialog = SendDialog::new("pane_123".to_string(), "test-agent".to_string(), false);
+        dialog.open_history_search();
+        assert!(dialog.history.search.is_none());
+    }
+
+    #[test]
+    fn test_editor_inserts_and_deletes_at_cursor() {
+        let mut dialog = SendDialog::new("pane_123".to_string(), "test-agent".to_string(), false);
+        dialog.input = "helo".to_string();
+
+        dialog.cursor_left();
+        dialog.handle_char('l');
+        assert_eq!(dialog.input, "hello");
+        assert_eq!(dialog.cursor(), 4);
+
+        dialog.cursor_home();
+        dialog.handle_backspace();
+        assert_eq!(dialog.input, "hello", "Backspace at the start is a no-op");
+        dialog.cursor_right();
+        dialog.handle_backspace();
+        assert_eq!(dialog.input, "ello");
+
+        dialog.cursor_end();
+        dialog.insert_newline();
+        dialog.handle_char('é');
+        assert_eq!(dialog.input, "ello\né");
+        dialog.cursor_left();
+        dialog.handle_backspace();
+        assert_eq!(dialog.input, "elloé");
+    }
+
+    #[test]
+    fn test_editor_moves_between_lines_keeping_column() {
+        let mut dialog = SendDialog::new("pane_123".to_string(), "test-agent".to_string(), false);
+        dialog.insert_str("first line\r\nab\rthird line");
+        assert_eq!(dialog.input, "first line\nab\nthird line");
+
+        assert!(!dialog.cursor_down(), "Already on the last line");
+        assert!(dialog.cursor_up());
+        assert_eq!(dialog.cursor(), "first line\nab".len(), "Column clamps");
+        assert!(dialog.cursor_up());
+        assert_eq!(dialog.cursor(), 2);
+        assert!(!dialog.cursor_up(), "Already on the first line");
+        assert!(dialog.cursor_down());
+        assert!(dialog.cursor_down());
+        assert_eq!(dialog.cursor(), "first line\nab\nth".len());
+    }
+
+    #[test]
+    fn test_replacing_input_moves_cursor_to_end() {
+        let mut dialog = dialog_with_history();
+        dialog.input = "draft".to_string();
+        dialog.cursor_home();
+
+        dialog.history_older();
+        assert_eq!(dialog.cursor(), dialog.input.len());
+    }
+
     #[test]
     fn test_render_shows_input_and_quick_actions() {
         // This is a basic smoke test - full rendering tests would require
@@ -257,11 +734,13 @@ mod tests {
 
         let dialog = SendDialog {
             input: "test command".to_string(),
+            cursor_from_end: 0,
             pane_id: "pane_123".to_string(),
             agent_name: "test-agent".to_string(),
             quick_action: 0,
             needs_confirmation: true,
             confirming: false,
+            history: CommandHistory::default(),
         };
 
         terminal
@@ -0,0 +1,237 @@
+//! Spec viewer modal for the orchestration's spec document in Convex
+//!
+//! Shows the source-of-truth spec rather than the local design file, with
+//! every recorded revision selectable.
+
+use ratatui::{
+    layout::{Alignment, Margin, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
+};
+use tina_data::{SpecRecord, SpecRevisionRecord};
+
+use super::markdown;
+use crate::theme;
+
+/// A spec and its revisions, newest first
+pub struct SpecViewer {
+    pub spec: SpecRecord,
+    pub revisions: Vec<SpecRevisionRecord>,
+    /// Index into `revisions` of the revision shown (0 = latest)
+    pub selected: usize,
+    /// Current scroll position
+    pub scroll: u16,
+}
+
+impl SpecViewer {
+    pub fn new(spec: SpecRecord, revisions: Vec<SpecRevisionRecord>) -> Self {
+        Self {
+            spec,
+            revisions,
+            selected: 0,
+            scroll: 0,
+        }
+    }
+
+    /// Markdown of the selected revision; the spec itself when it predates
+    /// revision history
+    pub fn markdown(&self) -> &str {
+        self.revisions
+            .get(self.selected)
+            .map_or(self.spec.markdown.as_str(), |r| r.markdown.as_str())
+    }
+
+    fn total_lines(&self) -> u16 {
+        markdown::render_cached(self.markdown()).len() as u16
+    }
+
+    /// Show the next older revision, if any
+    pub fn older(&mut self) {
+        if self.selected + 1 < self.revisions.len() {
+            self.selected += 1;
+            self.scroll = 0;
+        }
+    }
+
+    /// Show the next newer revision, if any
+    pub fn newer(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+            self.scroll = 0;
+        }
+    }
+
+    /// Scroll down by the given amount
+    pub fn scroll_down(&mut self, amount: u16) {
+        let max_scroll = self.total_lines().saturating_sub(1);
+        self.scroll = self.scroll.saturating_add(amount).min(max_scroll);
+    }
+
+    /// Scroll up by the given amount
+    pub fn scroll_up(&mut self, amount: u16) {
+        self.scroll = self.scroll.saturating_sub(amount);
+    }
+
+    /// "rev 3/5 · 2026-02-11" for the selected revision
+    fn revision_label(&self) -> String {
+        match self.revisions.get(self.selected) {
+            Some(rev) => format!(
+                "rev {}/{} · {}",
+                rev.revision,
+                self.revisions.len(),
+                rev.created_at.get(..10).unwrap_or(&rev.created_at)
+            ),
+            None => "no revision history".to_string(),
+        }
+    }
+
+    /// Render the spec viewer
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let theme = theme::current();
+        let title = match self.revisions.get(self.selected) {
+            Some(rev) => &rev.title,
+            None => &self.spec.title,
+        };
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(
+                    format!("{} ", self.spec.status),
+                    Style::default().fg(theme.highlight),
+                ),
+                Span::styled(self.revision_label(), Style::default().fg(theme.muted)),
+                Span::styled(
+                    if self.selected > 0 { "  (not latest)" } else { "" },
+                    Style::default().fg(theme.warning),
+                ),
+            ]),
+            Line::from(""),
+        ];
+        let body_height = area.height.saturating_sub(4) as usize;
+        lines.extend(
+            markdown::render_cached(self.markdown())
+                .into_iter()
+                .skip(self.scroll as usize)
+                .take(body_height),
+        );
+
+        let paragraph = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" {} · {} ", self.spec.spec_key, title))
+                    .title_alignment(Alignment::Center),
+            )
+            .style(Style::default().fg(theme.text));
+        frame.render_widget(paragraph, area);
+
+        let total_lines = self.total_lines();
+        if total_lines as usize > body_height {
+            let mut scrollbar_state =
+                ScrollbarState::new(total_lines.saturating_sub(body_height as u16) as usize)
+                    .position(self.scroll as usize);
+            frame.render_stateful_widget(
+                Scrollbar::default()
+                    .orientation(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(None)
+                    .end_symbol(None),
+                area.inner(Margin {
+                    vertical: 1,
+                    horizontal: 0,
+                }),
+                &mut scrollbar_state,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn spec(markdown: &str) -> SpecRecord {
+        SpecRecord {
+            id: "spec-1".to_string(),
+            project_id: "proj-1".to_string(),
+            spec_key: "TINA-S4".to_string(),
+            title: "Auth".to_string(),
+            markdown: markdown.to_string(),
+            status: "approved".to_string(),
+            created_at: "2026-02-10T10:00:00Z".to_string(),
+            updated_at: "2026-02-11T10:00:00Z".to_string(),
+            archived_at: None,
+        }
+    }
+
+    fn revision(n: u32, markdown: &str) -> SpecRevisionRecord {
+        SpecRevisionRecord {
+            id: format!("rev-{}", n),
+            spec_id: "spec-1".to_string(),
+            revision: n,
+            title: "Auth".to_string(),
+            markdown: markdown.to_string(),
+            created_at: format!("2026-02-1{}T10:00:00Z", n),
+        }
+    }
+
+    fn rendered(viewer: &SpecViewer) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        terminal
+            .draw(|frame| viewer.render(frame, frame.area()))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn selects_revisions_newest_first() {
+        let mut viewer = SpecViewer::new(
+            spec("# v2"),
+            vec![revision(2, "# Second"), revision(1, "# First")],
+        );
+        assert_eq!(viewer.markdown(), "# Second");
+
+        viewer.newer();
+        assert_eq!(viewer.selected, 0, "already at the latest revision");
+
+        viewer.scroll = 3;
+        viewer.older();
+        assert_eq!(viewer.markdown(), "# First");
+        assert_eq!(viewer.scroll, 0, "switching revisions resets scroll");
+
+        viewer.older();
+        assert_eq!(viewer.selected, 1, "already at the oldest revision");
+    }
+
+    #[test]
+    fn falls_back_to_spec_without_revisions() {
+        let viewer = SpecViewer::new(spec("# Only"), vec![]);
+        assert_eq!(viewer.markdown(), "# Only");
+        assert!(rendered(&viewer).contains("no revision history"));
+    }
+
+    #[test]
+    fn renders_key_revision_and_markdown() {
+        let mut viewer = SpecViewer::new(
+            spec("# v2"),
+            vec![revision(2, "# Second\n- [ ] todo"), revision(1, "# First")],
+        );
+        let content = rendered(&viewer);
+        assert!(content.contains("TINA-S4"));
+        assert!(content.contains("rev 2/2"));
+        assert!(content.contains("☐ todo"));
+
+        viewer.older();
+        let content = rendered(&viewer);
+        assert!(content.contains("rev 1/2"));
+        assert!(content.contains("not latest"));
+        assert!(content.contains("First"));
+    }
+}
@@ -0,0 +1,220 @@
+//! Status bar along the bottom of every view
+//!
+//! Shows what is selected, whether Convex is reachable, whether the
+//! selected orchestration's daemon is still heartbeating, how fresh the
+//! data is, and how many alerts haven't been looked at.
+
+use std::time::Instant;
+
+use chrono::Utc;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+use tina_data::humanize::format_duration_secs;
+
+use crate::theme;
+use crate::tui::app::{App, ViewState};
+
+const SEPARATOR: &str = "  │  ";
+
+/// Phase the current view is about, when it is about one
+fn viewed_phase(view: &ViewState) -> Option<u32> {
+    match view {
+        ViewState::PhaseDetail { selected_phase, .. }
+        | ViewState::PhaseTimeline { selected_phase, .. }
+        | ViewState::CostPanel { selected_phase, .. }
+        | ViewState::WorktreeStatus { selected_phase, .. }
+        | ViewState::TaskGraph { selected_phase, .. }
+        | ViewState::TaskEdit { selected_phase, .. }
+        | ViewState::BulkTaskModel { selected_phase, .. } => Some(*selected_phase),
+        ViewState::ConfirmStopPhase { phase, .. } | ViewState::ConfirmRetryPhase { phase, .. } => {
+            Some(*phase)
+        }
+        _ => None,
+    }
+}
+
+/// Glyph, label, and color for the Convex connection
+fn convex_state(app: &App) -> (&'static str, &'static str, Color) {
+    let theme = theme::current();
+    match &app.live {
+        Some(live) if live.is_connected() => return ("●", "convex live", theme.success),
+        Some(_) if app.refreshed_at.is_some() => {
+            return ("◌", "convex reconnecting", theme.warning)
+        }
+        _ => {}
+    }
+    if app.load_error.is_some() {
+        ("✗", "convex unreachable", theme.error)
+    } else if app.refreshed_at.is_some() {
+        ("●", "convex", theme.success)
+    } else if app.is_refreshing() {
+        ("◌", "convex connecting", theme.muted)
+    } else {
+        ("–", "convex not connected", theme.muted)
+    }
+}
+
+/// The status bar's spans at `now`
+pub fn status_spans(app: &App, now: Instant) -> Vec<Span<'static>> {
+    let theme = theme::current();
+    let muted = Style::default().fg(theme.muted);
+    let mut spans = vec![Span::raw(" ")];
+
+    match app.orchestrations.get(app.selected_index) {
+        Some(orch) => {
+            let phase = viewed_phase(&app.view_state).unwrap_or(orch.current_phase);
+            spans.push(Span::styled(
+                orch.feature_name.clone(),
+                Style::default().fg(theme.text),
+            ));
+            spans.push(Span::styled(
+                format!(" · phase {}/{}", phase, orch.total_phases),
+                muted,
+            ));
+        }
+        None => spans.push(Span::styled("no orchestration selected", muted)),
+    }
+
+    let (glyph, label, color) = convex_state(app);
+    spans.push(Span::styled(SEPARATOR, muted));
+    spans.push(Span::styled(
+        format!("{} {}", glyph, label),
+        Style::default().fg(color),
+    ));
+
+    let node = app
+        .orchestrations
+        .get(app.selected_index)
+        .and_then(|orch| app.list_paging.nodes.get(&orch.node_id));
+    if let Some(node) = node {
+        let age_secs = (Utc::now().timestamp_millis() - node.last_heartbeat as i64) / 1000;
+        let (glyph, color) = if node.status == "online" {
+            ("●", theme.success)
+        } else {
+            ("✗", theme.error)
+        };
+        spans.push(Span::styled(SEPARATOR, muted));
+        spans.push(Span::styled(
+            format!(
+                "{} daemon {} {}",
+                glyph,
+                node.name,
+                format_duration_secs(age_secs)
+            ),
+            Style::default().fg(color),
+        ));
+    }
+
+    if let Some(at) = app.refreshed_at {
+        let secs = now.saturating_duration_since(at).as_secs() as i64;
+        spans.push(Span::styled(SEPARATOR, muted));
+        spans.push(Span::styled(
+            format!("refreshed {} ago", format_duration_secs(secs)),
+            muted,
+        ));
+    }
+
+    if app.pending_copy {
+        spans.push(Span::styled(SEPARATOR, muted));
+        spans.push(Span::styled(
+            "copy: c commit · t task · p path · f file",
+            Style::default().fg(theme.highlight),
+        ));
+    }
+
+    let unread = app.notifications.unread();
+    if unread > 0 {
+        spans.push(Span::styled(SEPARATOR, muted));
+        spans.push(Span::styled(
+            format!(
+                "{} new alert{} (N)",
+                unread,
+                if unread == 1 { "" } else { "s" }
+            ),
+            Style::default().fg(theme.warning),
+        ));
+    }
+    spans
+}
+
+/// Render the status bar into its one-line `area`
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let bar = Paragraph::new(Line::from(status_spans(app, Instant::now())))
+        .style(Style::default().bg(theme::current().bar_bg));
+    frame.render_widget(bar, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::changes::ChangeKind;
+    use crate::data::MonitorOrchestration;
+    use crate::tui::PaneFocus;
+    use crate::tui::PhaseDetailLayout;
+    use std::time::Duration;
+    use tina_data::test_util::list_entry;
+    use tina_data::NodeRecord;
+
+    fn text(app: &App, now: Instant) -> String {
+        status_spans(app, now)
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    fn orchestration() -> MonitorOrchestration {
+        let mut entry = list_entry("orch-1", "executing", "2026-02-07T10:00:00Z");
+        entry.record.worktree_path = Some("/test".to_string());
+        MonitorOrchestration::from_list_entry(entry)
+    }
+
+    #[test]
+    fn shows_selection_connection_daemon_freshness_and_alerts() {
+        let mut app = App::new_with_orchestrations(vec![orchestration()]);
+        app.view_state = ViewState::PhaseDetail {
+            focus: PaneFocus::Phases,
+            task_index: 0,
+            member_index: 0,
+            layout: PhaseDetailLayout::OrchPhaseTasks,
+            selected_phase: 2,
+        };
+        assert_eq!(
+            text(&app, Instant::now()),
+            " auth · phase 2/3  │  – convex not connected"
+        );
+
+        let now = Instant::now();
+        app.refreshed_at = Some(now - Duration::from_secs(12));
+        app.list_paging.nodes.insert(
+            "node-1".to_string(),
+            NodeRecord {
+                id: "node-1".to_string(),
+                name: "macbook".to_string(),
+                os: "darwin".to_string(),
+                status: "offline".to_string(),
+                last_heartbeat: (Utc::now().timestamp_millis() - 5 * 60_000) as f64,
+                registered_at: 0.0,
+                clock_skew_ms: None,
+            },
+        );
+        app.notifications
+            .push(ChangeKind::Blocked, "auth blocked".to_string());
+        app.notifications
+            .push(ChangeKind::Blocked, "auth still blocked".to_string());
+        assert_eq!(
+            text(&app, now),
+            " auth · phase 2/3  │  ● convex  │  ✗ daemon macbook 5m  │  refreshed 12s ago  │  2 new alerts (N)"
+        );
+
+        app.load_error = Some("timed out".to_string());
+        app.notifications.toggle_history();
+        let shown = text(&app, now);
+        assert!(shown.contains("✗ convex unreachable"));
+        assert!(!shown.contains("alert"));
+    }
+}
@@ -0,0 +1,221 @@
+//! Stuck-task overlay
+//!
+//! Lists in-progress tasks across every orchestration that haven't changed
+//! for the configured threshold, longest idle first, with the agent that
+//! owns each one.
+
+use chrono::{DateTime, Utc};
+use ratatui::{
+    layout::{Alignment, Constraint},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::config::DEFAULT_STUCK_TASK_THRESHOLD_MINS;
+use crate::dashboard::Dashboard;
+use crate::data::MonitorOrchestration;
+use crate::overlay::centered_rect;
+use crate::theme;
+
+/// Stuck-task settings and overlay state
+#[derive(Debug, Clone, Copy)]
+pub struct StuckTasks {
+    /// In-progress tasks idle for at least this many minutes are stuck
+    pub threshold_mins: i64,
+    /// Whether the overlay (`S`) is open
+    pub show: bool,
+}
+
+impl Default for StuckTasks {
+    fn default() -> Self {
+        Self {
+            threshold_mins: DEFAULT_STUCK_TASK_THRESHOLD_MINS,
+            show: false,
+        }
+    }
+}
+
+impl StuckTasks {
+    pub fn new(threshold_mins: i64) -> Self {
+        Self {
+            threshold_mins,
+            ..Self::default()
+        }
+    }
+}
+
+/// One stuck task in the overlay
+#[derive(Debug, Clone, PartialEq)]
+pub struct StuckTaskRow {
+    pub feature: String,
+    pub task_id: String,
+    pub subject: String,
+    pub owner: Option<String>,
+    pub idle_mins: i64,
+}
+
+/// Stuck tasks across `orchestrations`, longest idle first
+pub fn collect(
+    orchestrations: &[MonitorOrchestration],
+    threshold_mins: i64,
+    now: DateTime<Utc>,
+) -> Vec<StuckTaskRow> {
+    let mut rows: Vec<StuckTaskRow> = orchestrations
+        .iter()
+        .flat_map(|orch| {
+            orch.stuck_tasks(threshold_mins, now)
+                .into_iter()
+                .map(move |task| StuckTaskRow {
+                    feature: orch.feature_name.clone(),
+                    task_id: task.id.clone(),
+                    subject: task.subject.clone(),
+                    owner: task.owner.clone(),
+                    idle_mins: orch.task_idle_mins(&task.id, now).unwrap_or(0),
+                })
+        })
+        .collect();
+    rows.sort_by_key(|row| std::cmp::Reverse(row.idle_mins));
+    rows
+}
+
+/// Render the stuck-task overlay
+pub fn render(frame: &mut Frame, orchestrations: &[MonitorOrchestration], threshold_mins: i64) {
+    let area = centered_rect(70, 60, frame.area());
+    frame.render_widget(Clear, area);
+
+    let theme = theme::current();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            " Stuck tasks: no updates for {}+ (S/Esc to close) ",
+            Dashboard::format_duration(threshold_mins)
+        ))
+        .title_alignment(Alignment::Center)
+        .border_style(Style::default().fg(theme.error));
+
+    let rows = collect(orchestrations, threshold_mins, Utc::now());
+    if rows.is_empty() {
+        let empty = Paragraph::new(Line::from("No stuck tasks"))
+            .style(Style::default().fg(theme.muted))
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let header = Row::new(["ORCHESTRATION", "TASK", "SUBJECT", "IDLE", "OWNER"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+    let body = rows.into_iter().map(|row| {
+        Row::new(vec![
+            Cell::from(row.feature),
+            Cell::from(format!("#{}", row.task_id)),
+            Cell::from(row.subject),
+            Cell::from(Dashboard::format_duration(row.idle_mins))
+                .style(Style::default().fg(theme.error)),
+            Cell::from(row.owner.unwrap_or_else(|| "-".to_string()))
+                .style(Style::default().fg(theme.muted)),
+        ])
+    });
+    let table = Table::new(
+        body,
+        [
+            Constraint::Length(20),
+            Constraint::Length(6),
+            Constraint::Min(20),
+            Constraint::Length(7),
+            Constraint::Length(16),
+        ],
+    )
+    .header(header)
+    .block(block)
+    .style(Style::default().fg(theme.text));
+    frame.render_widget(table, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Task, TaskStatus};
+    use ratatui::{backend::TestBackend, Terminal};
+    use tina_data::test_util::list_entry;
+
+    fn task(id: &str, owner: Option<&str>) -> Task {
+        Task {
+            id: id.to_string(),
+            subject: format!("Task {}", id),
+            description: String::new(),
+            active_form: None,
+            status: TaskStatus::InProgress,
+            owner: owner.map(str::to_string),
+            blocks: vec![],
+            blocked_by: vec![],
+            metadata: serde_json::Value::Null,
+        }
+    }
+
+    fn orchestration(feature: &str, tasks: &[(&str, &str)]) -> MonitorOrchestration {
+        let mut entry = list_entry(
+            &format!("orch-{}", feature),
+            "executing",
+            "2026-02-07T09:00:00Z",
+        );
+        entry.record.feature_name = feature.to_string();
+        entry.record.branch = format!("tina/{}", feature);
+        entry.record.total_phases = 1.0;
+        let mut orch = MonitorOrchestration::from_list_entry(entry);
+        for (id, at) in tasks {
+            orch.tasks.push(task(id, Some("worker-1")));
+            orch.task_updated_at.insert(id.to_string(), at.to_string());
+        }
+        orch
+    }
+
+    #[test]
+    fn test_collect_orders_longest_idle_first() {
+        let now = DateTime::parse_from_rfc3339("2026-02-07T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let orchestrations = vec![
+            orchestration(
+                "auth",
+                &[("1", "2026-02-07T11:00:00Z"), ("2", "2026-02-07T11:55:00Z")],
+            ),
+            orchestration("billing", &[("5", "2026-02-07T10:00:00Z")]),
+        ];
+
+        let rows = collect(&orchestrations, 30, now);
+
+        assert_eq!(
+            rows,
+            vec![
+                StuckTaskRow {
+                    feature: "billing".to_string(),
+                    task_id: "5".to_string(),
+                    subject: "Task 5".to_string(),
+                    owner: Some("worker-1".to_string()),
+                    idle_mins: 120,
+                },
+                StuckTaskRow {
+                    feature: "auth".to_string(),
+                    task_id: "1".to_string(),
+                    subject: "Task 1".to_string(),
+                    owner: Some("worker-1".to_string()),
+                    idle_mins: 60,
+                },
+            ]
+        );
+        assert_eq!(collect(&orchestrations, 180, now), vec![]);
+    }
+
+    #[test]
+    fn test_render_does_not_panic() {
+        let backend = TestBackend::new(100, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let orchestrations = vec![orchestration("auth", &[("1", "2020-01-01T00:00:00Z")])];
+        terminal
+            .draw(|frame| render(frame, &orchestrations, 30))
+            .unwrap();
+        terminal.draw(|frame| render(frame, &[], 30)).unwrap();
+    }
+}
@@ -0,0 +1,267 @@
+//! Pending task editor popup
+//!
+//! Edits a pending execution task's subject and model override. The edit is
+//! queued as a `task_edit` control-plane action carrying the task revision
+//! the popup was opened at, so it is rejected if someone else changed the
+//! task in the meantime.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use tina_data::ExecutionTaskRecord;
+
+use crate::theme;
+
+/// Models a task can be overridden to, as accepted by the control plane
+pub const MODELS: [&str; 5] = [
+    "opus",
+    "sonnet",
+    "haiku",
+    "gpt-5.3-codex",
+    "gpt-5.3-codex-spark",
+];
+
+/// Field with keyboard focus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskEditField {
+    Subject,
+    Model,
+}
+
+/// What a key press asks the app to do
+#[derive(Debug, PartialEq, Eq)]
+pub enum TaskEditResult {
+    Consumed,
+    Cancel,
+    Submit,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskEditor {
+    /// Task as loaded when the popup opened
+    pub task: ExecutionTaskRecord,
+    pub subject: String,
+    /// Index into [`MODELS`]; `None` leaves the model to the phase policy
+    pub model: Option<usize>,
+    pub field: TaskEditField,
+    /// Why the last submit failed, e.g. a revision conflict
+    pub error: Option<String>,
+    /// Tasks pane row to return to
+    pub task_index: usize,
+}
+
+impl TaskEditor {
+    pub fn new(task: ExecutionTaskRecord, task_index: usize) -> Self {
+        let model = task
+            .model
+            .as_deref()
+            .and_then(|m| MODELS.iter().position(|known| *known == m));
+        Self {
+            subject: task.subject.clone(),
+            model,
+            task,
+            field: TaskEditField::Subject,
+            error: None,
+            task_index,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> TaskEditResult {
+        match (key.code, self.field) {
+            (KeyCode::Esc, _) => return TaskEditResult::Cancel,
+            (KeyCode::Enter, _) => return TaskEditResult::Submit,
+            (KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down, field) => {
+                self.field = match field {
+                    TaskEditField::Subject => TaskEditField::Model,
+                    TaskEditField::Model => TaskEditField::Subject,
+                };
+            }
+            (KeyCode::Char(c), TaskEditField::Subject) => self.subject.push(c),
+            (KeyCode::Backspace, TaskEditField::Subject) => {
+                self.subject.pop();
+            }
+            (KeyCode::Right | KeyCode::Char('l'), TaskEditField::Model) => {
+                self.model = Some(self.model.map_or(0, |i| (i + 1) % MODELS.len()));
+            }
+            (KeyCode::Left | KeyCode::Char('h'), TaskEditField::Model) => {
+                self.model = Some(
+                    self.model
+                        .map_or(MODELS.len() - 1, |i| (i + MODELS.len() - 1) % MODELS.len()),
+                );
+            }
+            _ => {}
+        }
+        TaskEditResult::Consumed
+    }
+
+    /// `task_edit` payload carrying only the fields that changed, or `None`
+    /// when nothing did
+    pub fn payload(&self, feature: &str) -> Option<String> {
+        let subject = self.subject.trim();
+        let model = self.model.map(|i| MODELS[i]);
+        let mut payload = serde_json::json!({
+            "feature": feature,
+            "phaseNumber": self.task.phase_number,
+            "taskNumber": self.task.task_number,
+            "revision": self.task.revision,
+        });
+        let mut changed = false;
+        if !subject.is_empty() && subject != self.task.subject {
+            payload["subject"] = subject.into();
+            changed = true;
+        }
+        if model.is_some() && model != self.task.model.as_deref() {
+            payload["model"] = model.into();
+            changed = true;
+        }
+        changed.then(|| payload.to_string())
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let theme = theme::current();
+        let muted = Style::default().fg(theme.muted);
+        let focused = |field| {
+            if self.field == field {
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            }
+        };
+        let model = self.model.map_or("(phase default)", |i| MODELS[i]);
+        let cursor = if self.field == TaskEditField::Subject {
+            "█"
+        } else {
+            ""
+        };
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!(
+                    "Phase {} · task {} · revision {}",
+                    self.task.phase_number, self.task.task_number, self.task.revision
+                ),
+                muted,
+            )),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Subject  ", focused(TaskEditField::Subject)),
+                Span::styled(
+                    format!("{}{}", self.subject, cursor),
+                    Style::default().fg(theme.text),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Model    ", focused(TaskEditField::Model)),
+                Span::styled(format!("◀ {} ▶", model), Style::default().fg(theme.text)),
+            ]),
+        ];
+        if let Some(error) = &self.error {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                error.clone(),
+                Style::default().fg(theme.error),
+            )));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Tab: field  ←/→: model  Enter: save  Esc: cancel",
+            muted,
+        )));
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Edit pending task ")
+            .border_style(Style::default().fg(theme.border));
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(Wrap { trim: false }),
+            area,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn task() -> ExecutionTaskRecord {
+        ExecutionTaskRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: "2".to_string(),
+            task_number: 3,
+            subject: "Add retries".to_string(),
+            description: None,
+            status: "pending".to_string(),
+            model: Some("sonnet".to_string()),
+            revision: 4,
+        }
+    }
+
+    fn press(editor: &mut TaskEditor, code: KeyCode) -> TaskEditResult {
+        editor.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn unchanged_edit_has_no_payload() {
+        let editor = TaskEditor::new(task(), 0);
+        assert_eq!(editor.model, Some(1));
+        assert_eq!(editor.payload("auth"), None);
+    }
+
+    #[test]
+    fn payload_carries_revision_and_changed_fields() {
+        let mut editor = TaskEditor::new(task(), 0);
+        for _ in 0..3 {
+            press(&mut editor, KeyCode::Backspace);
+        }
+        press(&mut editor, KeyCode::Char('y'));
+        press(&mut editor, KeyCode::Tab);
+        press(&mut editor, KeyCode::Right);
+        assert_eq!(press(&mut editor, KeyCode::Enter), TaskEditResult::Submit);
+
+        let payload: serde_json::Value =
+            serde_json::from_str(&editor.payload("auth").unwrap()).unwrap();
+        assert_eq!(
+            payload,
+            serde_json::json!({
+                "feature": "auth",
+                "phaseNumber": "2",
+                "taskNumber": 3,
+                "revision": 4,
+                "subject": "Add retry",
+                "model": "haiku",
+            })
+        );
+    }
+
+    #[test]
+    fn model_field_cycles_and_subject_ignores_model_keys() {
+        let mut editor = TaskEditor::new(
+            ExecutionTaskRecord {
+                model: None,
+                ..task()
+            },
+            0,
+        );
+        press(&mut editor, KeyCode::Char('l'));
+        assert_eq!(editor.subject, "Add retriesl", "typing goes to the subject");
+        assert_eq!(editor.model, None);
+
+        press(&mut editor, KeyCode::Tab);
+        press(&mut editor, KeyCode::Left);
+        assert_eq!(editor.model, Some(MODELS.len() - 1));
+        press(&mut editor, KeyCode::Right);
+        assert_eq!(editor.model, Some(0));
+        assert_eq!(press(&mut editor, KeyCode::Esc), TaskEditResult::Cancel);
+    }
+}
@@ -0,0 +1,367 @@
+//! Task dependency graph modal
+//!
+//! Draws the current phase's tasks as boxes in dependency columns joined by
+//! arrows, with the critical path and blocked tasks picked out.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::data::task_graph::{GraphNode, Segment, TaskGraph};
+use crate::theme;
+use crate::types::{Task, TaskStatus};
+
+/// Width of a task box, borders included
+const BOX_WIDTH: usize = 26;
+/// Columns between boxes, where arrows are routed
+const GAP: usize = 6;
+/// Rows per node: a three-row box and a blank row
+const ROW_STEP: usize = 4;
+
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+/// How a cell is drawn
+#[derive(Clone, Copy, PartialEq)]
+enum Ink {
+    Plain,
+    Muted,
+    Critical,
+    Blocked,
+    Active,
+    Done,
+}
+
+#[derive(Clone, Copy)]
+struct Cell {
+    /// Line directions meeting in the cell, when it is part of an arrow
+    lines: u8,
+    glyph: Option<char>,
+    ink: Ink,
+}
+
+/// Character grid the graph is drawn on
+struct Canvas {
+    cells: Vec<Vec<Cell>>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        let blank = Cell {
+            lines: 0,
+            glyph: None,
+            ink: Ink::Plain,
+        };
+        Self {
+            cells: vec![vec![blank; width]; height],
+        }
+    }
+
+    fn put(&mut self, x: usize, y: usize, glyph: char, ink: Ink) {
+        if let Some(cell) = self.cells.get_mut(y).and_then(|row| row.get_mut(x)) {
+            cell.glyph = Some(glyph);
+            cell.ink = ink;
+        }
+    }
+
+    fn text(&mut self, x: usize, y: usize, text: &str, ink: Ink) {
+        for (i, c) in text.chars().enumerate() {
+            self.put(x + i, y, c, ink);
+        }
+    }
+
+    fn join(&mut self, x: usize, y: usize, lines: u8, ink: Ink) {
+        if let Some(cell) = self.cells.get_mut(y).and_then(|row| row.get_mut(x)) {
+            cell.lines |= lines;
+            // Critical arrows stay highlighted where others cross them
+            if cell.ink != Ink::Critical {
+                cell.ink = ink;
+            }
+        }
+    }
+
+    fn horizontal(&mut self, y: usize, from: usize, to: usize, ink: Ink) {
+        for x in from..to {
+            self.join(x, y, RIGHT, ink);
+            self.join(x + 1, y, LEFT, ink);
+        }
+    }
+
+    fn vertical(&mut self, x: usize, from: usize, to: usize, ink: Ink) {
+        let (top, bottom) = (from.min(to), from.max(to));
+        for y in top..bottom {
+            self.join(x, y, DOWN, ink);
+            self.join(x, y + 1, UP, ink);
+        }
+    }
+
+    fn lines(&self) -> Vec<Line<'static>> {
+        let theme = theme::current();
+        let style = |ink: Ink| match ink {
+            Ink::Plain => Style::default().fg(theme.text),
+            Ink::Muted => Style::default().fg(theme.muted),
+            Ink::Critical => Style::default()
+                .fg(theme.warning)
+                .add_modifier(Modifier::BOLD),
+            Ink::Blocked => Style::default().fg(theme.error),
+            Ink::Active => Style::default().fg(theme.info),
+            Ink::Done => Style::default().fg(theme.success),
+        };
+        self.cells
+            .iter()
+            .map(|row| {
+                let mut spans: Vec<Span<'static>> = Vec::new();
+                let mut run = String::new();
+                let mut ink = Ink::Plain;
+                for cell in row {
+                    if cell.ink != ink && !run.is_empty() {
+                        spans.push(Span::styled(std::mem::take(&mut run), style(ink)));
+                    }
+                    ink = cell.ink;
+                    run.push(cell.glyph.unwrap_or_else(|| line_glyph(cell.lines)));
+                }
+                spans.push(Span::styled(run.trim_end().to_string(), style(ink)));
+                Line::from(spans)
+            })
+            .collect()
+    }
+}
+
+/// Box-drawing character joining the given directions
+fn line_glyph(lines: u8) -> char {
+    match lines {
+        0 => ' ',
+        l if l == LEFT | RIGHT || l == LEFT || l == RIGHT => '─',
+        l if l == UP | DOWN || l == UP || l == DOWN => '│',
+        l if l == RIGHT | DOWN => '┌',
+        l if l == LEFT | DOWN => '┐',
+        l if l == RIGHT | UP => '└',
+        l if l == LEFT | UP => '┘',
+        l if l == LEFT | RIGHT | DOWN => '┬',
+        l if l == LEFT | RIGHT | UP => '┴',
+        l if l == UP | DOWN | RIGHT => '├',
+        l if l == UP | DOWN | LEFT => '┤',
+        _ => '┼',
+    }
+}
+
+fn column_x(column: usize) -> usize {
+    column * (BOX_WIDTH + GAP)
+}
+
+fn node_y(row: usize) -> usize {
+    row * ROW_STEP
+}
+
+pub struct TaskGraphView<'a> {
+    tasks: &'a [Task],
+    graph: TaskGraph,
+    phase: u32,
+    scroll: (u16, u16),
+}
+
+impl<'a> TaskGraphView<'a> {
+    pub fn new(tasks: &'a [Task], phase: u32, scroll: (u16, u16)) -> Self {
+        Self {
+            tasks,
+            graph: TaskGraph::build(tasks),
+            phase,
+            scroll,
+        }
+    }
+
+    fn task_ink(&self, index: usize) -> Ink {
+        if self.graph.critical_path.contains(&index) {
+            return Ink::Critical;
+        }
+        if self.graph.blocked.contains(&index) {
+            return Ink::Blocked;
+        }
+        match self.tasks[index].status {
+            TaskStatus::Completed => Ink::Done,
+            TaskStatus::InProgress => Ink::Active,
+            TaskStatus::Pending => Ink::Plain,
+        }
+    }
+
+    fn draw_task(&self, canvas: &mut Canvas, index: usize, x: usize, y: usize) {
+        let task = &self.tasks[index];
+        let ink = self.task_ink(index);
+        let inner = BOX_WIDTH - 2;
+        let glyph = match task.status {
+            TaskStatus::Completed => '✓',
+            TaskStatus::InProgress => '▶',
+            TaskStatus::Pending if self.graph.blocked.contains(&index) => '✗',
+            TaskStatus::Pending => '○',
+        };
+        let label: String = format!("{} #{} {}", glyph, task.id, task.subject)
+            .chars()
+            .take(inner)
+            .collect();
+
+        canvas.text(x, y, &format!("┌{}┐", "─".repeat(inner)), ink);
+        canvas.text(x, y + 1, &format!("│{:<inner$}│", label), ink);
+        canvas.text(x, y + 2, &format!("└{}┘", "─".repeat(inner)), ink);
+    }
+
+    fn draw_segment(&self, canvas: &mut Canvas, segment: &Segment) {
+        let ink = if segment.critical {
+            Ink::Critical
+        } else {
+            Ink::Muted
+        };
+        let start = column_x(segment.from.0) + BOX_WIDTH;
+        let end = column_x(segment.to.0) - 1;
+        let middle = start + GAP / 2 - 1;
+        let (from_y, to_y) = (node_y(segment.from.1) + 1, node_y(segment.to.1) + 1);
+
+        canvas.horizontal(from_y, start, middle, ink);
+        canvas.vertical(middle, from_y, to_y, ink);
+        canvas.horizontal(to_y, middle, end, ink);
+        if matches!(
+            self.graph.columns[segment.to.0][segment.to.1],
+            GraphNode::Task(_)
+        ) {
+            canvas.put(end, to_y, '▶', ink);
+        }
+    }
+
+    fn canvas(&self) -> Canvas {
+        let rows = self.graph.columns.iter().map(Vec::len).max().unwrap_or(0);
+        let width = column_x(self.graph.columns.len()).saturating_sub(GAP);
+        let mut canvas = Canvas::new(width, (rows * ROW_STEP).saturating_sub(1));
+
+        for (column, nodes) in self.graph.columns.iter().enumerate() {
+            for (row, node) in nodes.iter().enumerate() {
+                let (x, y) = (column_x(column), node_y(row));
+                match node {
+                    GraphNode::Task(index) => self.draw_task(&mut canvas, *index, x, y),
+                    // Long dependencies run straight through the column
+                    GraphNode::Waypoint => {
+                        let critical = self.graph.segments.iter().any(|s| {
+                            s.critical && (s.to == (column, row) || s.from == (column, row))
+                        });
+                        let ink = if critical { Ink::Critical } else { Ink::Muted };
+                        canvas.horizontal(y + 1, x, x + BOX_WIDTH - 1, ink);
+                    }
+                }
+            }
+        }
+        for segment in &self.graph.segments {
+            self.draw_segment(&mut canvas, segment);
+        }
+        canvas
+    }
+
+    fn lines(&self) -> Vec<Line<'static>> {
+        let theme = theme::current();
+        let muted = Style::default().fg(theme.muted);
+        if self.tasks.is_empty() {
+            return vec![Line::from(Span::styled("No tasks in this phase", muted))];
+        }
+
+        let critical = match self.graph.critical_path.len() {
+            0 => "no unfinished work".to_string(),
+            n => format!("{} task(s)", n),
+        };
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(
+                    "━ critical path",
+                    Style::default()
+                        .fg(theme.warning)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format!(" ({})  ", critical), muted),
+                Span::styled(
+                    format!("✗ blocked ({})", self.graph.blocked.len()),
+                    Style::default().fg(theme.error),
+                ),
+            ]),
+            Line::from(""),
+        ];
+        if self.graph.has_cycle {
+            lines.insert(
+                1,
+                Line::from(Span::styled(
+                    "Dependencies form a cycle; the last column's tasks wait on each other",
+                    Style::default().fg(theme.error),
+                )),
+            );
+        }
+        lines.extend(self.canvas().lines());
+        lines
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Task dependencies · phase {} ", self.phase));
+        frame.render_widget(
+            Paragraph::new(self.lines())
+                .block(block)
+                .scroll(self.scroll),
+            area,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::task_graph::tests::task;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn rendered(tasks: &[Task]) -> Vec<String> {
+        let view = TaskGraphView::new(tasks, 2, (0, 0));
+        let mut terminal = Terminal::new(TestBackend::new(100, 16)).unwrap();
+        terminal
+            .draw(|frame| view.render(frame, frame.area()))
+            .unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn draws_boxes_and_arrows_between_columns() {
+        let rows = rendered(&[
+            task("1", TaskStatus::Completed, &[]),
+            task("2", TaskStatus::Pending, &["1"]),
+            task("3", TaskStatus::Pending, &["1"]),
+        ]);
+        let screen = rows.join("\n");
+        assert!(screen.contains("Task dependencies · phase 2"));
+        assert!(screen.contains("✓ #1 Task 1"));
+        assert!(screen.contains("○ #2 Task 2"));
+        // 1's arrow fans out to both dependents in the next column
+        let arrow_row = rows.iter().find(|r| r.contains("#1")).unwrap();
+        assert!(arrow_row.contains("│──┬──▶│"), "{}", arrow_row);
+        assert!(screen.contains("└──▶"));
+    }
+
+    #[test]
+    fn shows_blocked_tasks_and_empty_phase() {
+        let screen = rendered(&[
+            task("1", TaskStatus::InProgress, &[]),
+            task("2", TaskStatus::Pending, &["1"]),
+        ])
+        .join("\n");
+        assert!(screen.contains("✗ #2 Task 2"));
+        assert!(screen.contains("blocked (1)"));
+        assert!(screen.contains("critical path (2 task(s))"));
+
+        assert!(rendered(&[]).join("\n").contains("No tasks in this phase"));
+    }
+}
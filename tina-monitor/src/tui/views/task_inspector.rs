@@ -3,16 +3,20 @@
 use crate::overlay::centered_rect;
 use ratatui::{
     layout::Alignment,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
 
+use crate::data::timeline::{TimelineEntry, TimelineKind};
+use crate::theme;
 use crate::types::{Task, TaskStatus};
+use tina_data::humanize::{format_duration_secs, format_time};
 
-/// Render the task inspector modal
-pub fn render_task_inspector(frame: &mut Frame, task: &Task) {
+/// Render the task inspector modal, with the task's event timeline when one
+/// was loaded
+pub fn render_task_inspector(frame: &mut Frame, task: &Task, timeline: Option<&[TimelineEntry]>) {
     let area = centered_rect(70, 70, frame.area());
 
     // Clear the area first
@@ -22,9 +26,9 @@ pub fn render_task_inspector(frame: &mut Frame, task: &Task) {
 
     // Status with color coding
     let status_style = match task.status {
-        TaskStatus::Completed => Style::default().fg(Color::Green),
-        TaskStatus::InProgress => Style::default().fg(Color::Yellow),
-        TaskStatus::Pending => Style::default().fg(Color::Gray),
+        TaskStatus::Completed => Style::default().fg(theme::current().success),
+        TaskStatus::InProgress => Style::default().fg(theme::current().warning),
+        TaskStatus::Pending => Style::default().fg(theme::current().muted),
     };
     let status_text = format!("{:?}", task.status);
     lines.push(Line::from(vec![
@@ -95,11 +99,28 @@ pub fn render_task_inspector(frame: &mut Frame, task: &Task) {
         lines.push(Line::from(""));
     }
 
+    // Event timeline (only when one could be loaded from Convex)
+    if let Some(entries) = timeline {
+        lines.push(Line::from(Span::styled(
+            "Timeline:",
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+        if entries.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  No recorded events",
+                Style::default().fg(theme::current().muted),
+            )));
+        } else {
+            lines.extend(timeline_lines(entries));
+        }
+        lines.push(Line::from(""));
+    }
+
     // Close hint
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
         "[ESC] Close",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme::current().muted),
     )));
 
     let paragraph = Paragraph::new(lines)
@@ -110,11 +131,63 @@ pub fn render_task_inspector(frame: &mut Frame, task: &Task) {
                 .title_alignment(Alignment::Center),
         )
         .wrap(Wrap { trim: true })
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(theme::current().text));
 
     frame.render_widget(paragraph, area);
 }
 
+/// One line per timeline entry, with the time elapsed since the previous
+/// entry between them
+fn timeline_lines(entries: &[TimelineEntry]) -> Vec<Line<'static>> {
+    let theme = theme::current();
+    let muted = Style::default().fg(theme.muted);
+    let mut lines = Vec::new();
+
+    for (i, entry) in entries.iter().enumerate() {
+        if i > 0 {
+            let gap = entry.at.signed_duration_since(entries[i - 1].at);
+            lines.push(Line::from(Span::styled(
+                format!("               │ {}", format_gap(gap)),
+                muted,
+            )));
+        }
+
+        let (marker, description) = describe(&entry.kind);
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {} ", format_time(entry.at, "%b %d %H:%M")),
+                muted,
+            ),
+            Span::styled(format!("{} ", marker), Style::default().fg(theme.accent)),
+            Span::raw(description),
+        ]));
+    }
+    lines
+}
+
+fn describe(kind: &TimelineKind) -> (&'static str, String) {
+    let or_none = |value: &Option<String>| value.clone().unwrap_or_else(|| "none".to_string());
+    match kind {
+        TimelineKind::Created { status } => ("●", format!("created ({})", status)),
+        TimelineKind::Status { from, to } => ("●", format!("status {} → {}", from, to)),
+        TimelineKind::Owner { from, to } => {
+            ("○", format!("owner {} → {}", or_none(from), or_none(to)))
+        }
+        TimelineKind::Model { from, to } => {
+            ("○", format!("model {} → {}", or_none(from), or_none(to)))
+        }
+        TimelineKind::Commit {
+            short_hash,
+            subject,
+        } => ("◆", format!("commit {} {}", short_hash, subject)),
+    }
+}
+
+/// Format the time between two entries, e.g. "+45s", "+12m", "+1h 5m"
+fn format_gap(gap: chrono::Duration) -> String {
+    format!("+{}", format_duration_secs(gap.num_seconds()))
+}
+
 /// Truncate a string to a maximum length, adding "..." if truncated
 fn truncate(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
@@ -153,7 +226,7 @@ mod tests {
         let mut terminal = Terminal::new(backend).unwrap();
         let task = make_test_task();
 
-        let result = terminal.draw(|frame| render_task_inspector(frame, &task));
+        let result = terminal.draw(|frame| render_task_inspector(frame, &task, None));
         assert!(
             result.is_ok(),
             "Task inspector modal should render without panic"
@@ -177,7 +250,7 @@ mod tests {
             metadata: json!({"priority": "high", "estimate": "2h"}),
         };
 
-        let result = terminal.draw(|frame| render_task_inspector(frame, &task));
+        let result = terminal.draw(|frame| render_task_inspector(frame, &task, None));
         assert!(result.is_ok(), "Should render task with all details");
 
         // Get the buffer to check content
@@ -228,7 +301,7 @@ mod tests {
             metadata: serde_json::Value::Null,
         };
 
-        let result = terminal.draw(|frame| render_task_inspector(frame, &task_no_metadata));
+        let result = terminal.draw(|frame| render_task_inspector(frame, &task_no_metadata, None));
         assert!(result.is_ok());
 
         let buffer = terminal.backend().buffer();
@@ -248,7 +321,8 @@ mod tests {
             ..task_no_metadata.clone()
         };
 
-        let result = terminal.draw(|frame| render_task_inspector(frame, &task_empty_metadata));
+        let result =
+            terminal.draw(|frame| render_task_inspector(frame, &task_empty_metadata, None));
         assert!(result.is_ok());
 
         let buffer = terminal.backend().buffer();
@@ -268,7 +342,7 @@ mod tests {
             ..task_no_metadata
         };
 
-        let result = terminal.draw(|frame| render_task_inspector(frame, &task_with_metadata));
+        let result = terminal.draw(|frame| render_task_inspector(frame, &task_with_metadata, None));
         assert!(result.is_ok());
 
         let buffer = terminal.backend().buffer();
@@ -298,7 +372,7 @@ mod tests {
                 status,
                 ..make_test_task()
             };
-            let result = terminal.draw(|frame| render_task_inspector(frame, &task));
+            let result = terminal.draw(|frame| render_task_inspector(frame, &task, None));
             assert!(
                 result.is_ok(),
                 "Should render task with status {:?}",
@@ -307,6 +381,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_timeline_renders_entries_and_gaps() {
+        let backend = TestBackend::new(100, 40);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let at = |s: &str| {
+            chrono::DateTime::parse_from_rfc3339(s)
+                .unwrap()
+                .with_timezone(&chrono::Utc)
+        };
+        let timeline = vec![
+            TimelineEntry {
+                at: at("2026-02-13T10:00:00Z"),
+                kind: TimelineKind::Created {
+                    status: "pending".to_string(),
+                },
+            },
+            TimelineEntry {
+                at: at("2026-02-13T10:12:00Z"),
+                kind: TimelineKind::Commit {
+                    short_hash: "abc1234".to_string(),
+                    subject: "Add timeline".to_string(),
+                },
+            },
+        ];
+
+        terminal
+            .draw(|frame| render_task_inspector(frame, &make_test_task(), Some(&timeline)))
+            .unwrap();
+        let content = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect::<String>();
+
+        assert!(content.contains("Timeline:"));
+        assert!(content.contains("created (pending)"));
+        assert!(content.contains("commit abc1234 Add timeline"));
+        assert!(content.contains("│ +12m"));
+    }
+
+    #[test]
+    fn test_format_gap() {
+        assert_eq!(format_gap(chrono::Duration::seconds(45)), "+45s");
+        assert_eq!(format_gap(chrono::Duration::minutes(12)), "+12m");
+        assert_eq!(format_gap(chrono::Duration::minutes(65)), "+1h 5m");
+        assert_eq!(format_gap(chrono::Duration::seconds(-5)), "+0s");
+    }
+
     #[test]
     fn test_truncate_helper() {
         assert_eq!(truncate("short", 10), "short");
@@ -343,7 +467,7 @@ mod tests {
             ..make_test_task()
         };
 
-        let result = terminal.draw(|frame| render_task_inspector(frame, &task));
+        let result = terminal.draw(|frame| render_task_inspector(frame, &task, None));
         assert!(result.is_ok());
 
         let buffer = terminal.backend().buffer();
@@ -368,7 +492,7 @@ mod tests {
             ..make_test_task()
         };
 
-        let result = terminal.draw(|frame| render_task_inspector(frame, &task));
+        let result = terminal.draw(|frame| render_task_inspector(frame, &task, None));
         assert!(result.is_ok(), "Should render multiline description");
 
         let buffer = terminal.backend().buffer();
@@ -396,7 +520,7 @@ mod tests {
             ..make_test_task()
         };
 
-        let result = terminal.draw(|frame| render_task_inspector(frame, &task));
+        let result = terminal.draw(|frame| render_task_inspector(frame, &task, None));
         assert!(result.is_ok());
 
         let buffer = terminal.backend().buffer();
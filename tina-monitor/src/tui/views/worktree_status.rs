@@ -0,0 +1,208 @@
+//! Working-tree status modal
+//!
+//! Shows what in the orchestration's worktree isn't committed yet, and how
+//! the branch compares with its upstream, so work left behind by an agent
+//! is noticed before the worktree is cleaned up.
+
+use std::path::Path;
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::git::status::WorktreeStatus;
+use crate::theme;
+
+pub struct WorktreeStatusView<'a> {
+    feature_name: &'a str,
+    worktree_path: &'a Path,
+    status: &'a Result<WorktreeStatus, String>,
+    scroll: u16,
+}
+
+impl<'a> WorktreeStatusView<'a> {
+    pub fn new(
+        feature_name: &'a str,
+        worktree_path: &'a Path,
+        status: &'a Result<WorktreeStatus, String>,
+        scroll: u16,
+    ) -> Self {
+        Self {
+            feature_name,
+            worktree_path,
+            status,
+            scroll,
+        }
+    }
+
+    fn summary_lines(status: &WorktreeStatus) -> Vec<Line<'static>> {
+        let theme = theme::current();
+        let muted = Style::default().fg(theme.muted);
+        let text = Style::default().fg(theme.text);
+
+        let branch = status.branch.as_deref().unwrap_or("(detached HEAD)");
+        let mut tracking = vec![
+            Span::styled("Branch    ", muted),
+            Span::styled(branch.to_string(), text.add_modifier(Modifier::BOLD)),
+        ];
+        match &status.upstream {
+            Some(upstream) => {
+                tracking.push(Span::styled(format!(" → {}  ", upstream), muted));
+                let style = |n: usize| {
+                    if n > 0 {
+                        Style::default().fg(theme.warning)
+                    } else {
+                        muted
+                    }
+                };
+                tracking.push(Span::styled(
+                    format!("↑{} ahead", status.ahead),
+                    style(status.ahead),
+                ));
+                tracking.push(Span::styled("  ", muted));
+                tracking.push(Span::styled(
+                    format!("↓{} behind", status.behind),
+                    style(status.behind),
+                ));
+            }
+            None => tracking.push(Span::styled("  no upstream (not pushed)", muted)),
+        }
+
+        let counts = format!(
+            "{} staged · {} modified · {} untracked · {} conflicted",
+            status.staged(),
+            status.modified(),
+            status.untracked(),
+            status.conflicted()
+        );
+        let mut lines = vec![
+            Line::from(tracking),
+            Line::from(vec![
+                Span::styled("Changes   ", muted),
+                Span::styled(counts, text),
+            ]),
+            Line::from(""),
+        ];
+        if status.is_clean() {
+            lines.push(Line::from(Span::styled(
+                "Working tree clean",
+                Style::default().fg(theme.success),
+            )));
+        } else {
+            lines.push(Line::from(Span::styled(
+                "Uncommitted work is lost when the worktree is cleaned up",
+                Style::default()
+                    .fg(theme.warning)
+                    .add_modifier(Modifier::BOLD),
+            )));
+        }
+        lines
+    }
+
+    fn lines(&self) -> Vec<Line<'static>> {
+        let theme = theme::current();
+        let muted = Style::default().fg(theme.muted);
+        let mut lines = vec![
+            Line::from(Span::styled(
+                self.worktree_path.display().to_string(),
+                muted,
+            )),
+            Line::from(""),
+        ];
+        let status = match self.status {
+            Ok(status) => status,
+            Err(error) => {
+                lines.push(Line::from(Span::styled(
+                    error.clone(),
+                    Style::default().fg(theme.error),
+                )));
+                return lines;
+            }
+        };
+
+        lines.extend(Self::summary_lines(status));
+        if !status.is_clean() {
+            lines.push(Line::from(""));
+        }
+        for entry in &status.entries {
+            let color = if entry.is_conflicted() {
+                theme.error
+            } else if entry.is_untracked() {
+                theme.muted
+            } else if entry.worktree != '.' {
+                theme.warning
+            } else {
+                theme.success
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {} ", entry.code()), Style::default().fg(color)),
+                Span::styled(entry.path.clone(), Style::default().fg(theme.text)),
+            ]));
+        }
+        lines
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Working tree · {} ", self.feature_name));
+        frame.render_widget(
+            Paragraph::new(self.lines())
+                .block(block)
+                .scroll((self.scroll, 0)),
+            area,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::status::parse_status;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn rendered(status: &Result<WorktreeStatus, String>) -> String {
+        let path = Path::new("/work/auth");
+        let view = WorktreeStatusView::new("auth", path, status, 0);
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|frame| view.render(frame, frame.area()))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn renders_dirty_worktree_with_warning() {
+        let status = parse_status(
+            "# branch.head tina/auth\n# branch.upstream origin/tina/auth\n# branch.ab +3 -0\n\
+             1 .M N... 100644 100644 100644 aaaa bbbb src/lib.rs\n? scratch.txt\n",
+        );
+        let content = rendered(&Ok(status));
+        assert!(content.contains("Working tree · auth"));
+        assert!(content.contains("↑3 ahead"));
+        assert!(content.contains("0 staged · 1 modified · 1 untracked"));
+        assert!(content.contains("Uncommitted work is lost"));
+        assert!(content.contains("src/lib.rs"));
+        assert!(content.contains("?? scratch.txt"));
+    }
+
+    #[test]
+    fn renders_clean_worktree_and_errors() {
+        let content = rendered(&Ok(parse_status("# branch.head tina/auth\n")));
+        assert!(content.contains("Working tree clean"));
+        assert!(content.contains("no upstream"));
+
+        let content = rendered(&Err("Worktree is not on this machine".to_string()));
+        assert!(content.contains("not on this machine"));
+    }
+}
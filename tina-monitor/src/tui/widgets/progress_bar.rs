@@ -1,6 +1,8 @@
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::text::Span;
 
+use crate::theme;
+
 /// Render a text-based progress bar
 /// Example: "████████░░" for 80% complete
 pub fn render(completed: usize, total: usize, width: usize) -> String {
@@ -18,12 +20,13 @@ pub fn render(completed: usize, total: usize, width: usize) -> String {
 pub fn render_styled<'a>(completed: usize, total: usize, width: usize) -> Span<'a> {
     let text = render(completed, total, width);
 
+    let theme = theme::current();
     let color = if total == 0 {
-        Color::DarkGray
+        theme.muted
     } else if completed >= total {
-        Color::Green
+        theme.success
     } else {
-        Color::Blue
+        theme.progress
     };
 
     Span::styled(text, Style::default().fg(color))
@@ -32,6 +35,7 @@ pub fn render_styled<'a>(completed: usize, total: usize, width: usize) -> Span<'
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ratatui::style::Color;
 
     #[test]
     fn test_progress_bar_empty() {
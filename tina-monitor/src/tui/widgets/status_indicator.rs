@@ -1,39 +1,28 @@
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::Span;
 
 use crate::data::MonitorOrchestrationStatus;
+use crate::theme;
 
 /// Render a status indicator span
 pub fn render(status: &MonitorOrchestrationStatus) -> Span<'static> {
+    let style = Style::default().fg(theme::current().status(status));
     match status {
-        MonitorOrchestrationStatus::Executing => Span::styled(
-            "executing".to_string(),
-            Style::default().fg(Color::Green),
-        ),
-        MonitorOrchestrationStatus::Planning => Span::styled(
-            "planning".to_string(),
-            Style::default().fg(Color::Yellow),
-        ),
-        MonitorOrchestrationStatus::Reviewing => Span::styled(
-            "reviewing".to_string(),
-            Style::default().fg(Color::Cyan),
-        ),
-        MonitorOrchestrationStatus::Blocked => Span::styled(
-            "BLOCKED".to_string(),
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-        ),
-        MonitorOrchestrationStatus::Complete => {
-            Span::styled("complete".to_string(), Style::default().fg(Color::Cyan))
-        }
-        MonitorOrchestrationStatus::Idle => {
-            Span::styled("idle".to_string(), Style::default().fg(Color::DarkGray))
+        MonitorOrchestrationStatus::Executing => Span::styled("executing".to_string(), style),
+        MonitorOrchestrationStatus::Planning => Span::styled("planning".to_string(), style),
+        MonitorOrchestrationStatus::Reviewing => Span::styled("reviewing".to_string(), style),
+        MonitorOrchestrationStatus::Blocked => {
+            Span::styled("BLOCKED".to_string(), style.add_modifier(Modifier::BOLD))
         }
+        MonitorOrchestrationStatus::Complete => Span::styled("complete".to_string(), style),
+        MonitorOrchestrationStatus::Idle => Span::styled("idle".to_string(), style),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ratatui::style::Color;
 
     #[test]
     fn test_status_executing() {
@@ -73,7 +62,7 @@ mod tests {
         let status = MonitorOrchestrationStatus::Complete;
         let span = render(&status);
         assert_eq!(span.content, "complete");
-        assert_eq!(span.style.fg, Some(Color::Cyan));
+        assert_eq!(span.style.fg, Some(theme::current().status_complete));
     }
 
     #[test]
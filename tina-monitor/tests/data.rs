@@ -72,6 +72,7 @@ fn orchestration_struct_fields() {
             timing: Default::default(),
             model_policy: Default::default(),
             review_policy: Default::default(),
+            scope: None,
         },
         orchestrator_team: None,
         phase_team: None,
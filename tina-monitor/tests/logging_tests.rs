@@ -100,3 +100,56 @@ fn test_expand_path_handles_tilde() {
         "Should handle tilde expansion without error"
     );
 }
+
+#[test]
+fn test_agent_history_is_newest_first_and_deduplicated() {
+    use tina_monitor::logging::agent_target;
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let log_path = temp_dir.path().join("commands.log");
+
+    let logger = CommandLogger::new(log_path);
+    logger
+        .log(&agent_target("worker", "%1"), "/checkpoint")
+        .unwrap();
+    logger
+        .log(&agent_target("reviewer", "%2"), "/clear")
+        .unwrap();
+    logger
+        .log(&agent_target("worker", "%5"), "keep going")
+        .unwrap();
+    logger
+        .log(&agent_target("worker", "%5"), "/checkpoint")
+        .unwrap();
+    logger.log("editor", "code src/main.rs").unwrap();
+
+    assert_eq!(
+        logger.agent_history("worker"),
+        vec!["/checkpoint".to_string(), "keep going".to_string()]
+    );
+    assert_eq!(logger.agent_history("reviewer"), vec!["/clear".to_string()]);
+    assert!(logger.agent_history("editor").is_empty());
+}
+
+#[test]
+fn test_agent_history_without_log_file_is_empty() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let logger = CommandLogger::new(temp_dir.path().join("missing.log"));
+    assert!(logger.agent_history("worker").is_empty());
+}
+
+#[test]
+fn test_multi_line_commands_log_as_one_line() {
+    use tina_monitor::logging::agent_target;
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let log_path = temp_dir.path().join("commands.log");
+
+    let logger = CommandLogger::new(log_path.clone());
+    let command = "fix the build\nthen run C:\\tests\\new";
+    logger.log(&agent_target("worker", "%1"), command).unwrap();
+
+    let content = fs::read_to_string(&log_path).unwrap();
+    assert_eq!(content.lines().count(), 1);
+    assert_eq!(logger.agent_history("worker"), vec![command.to_string()]);
+}
@@ -8,35 +8,15 @@ use std::fs;
 use tempfile::TempDir;
 use tina_monitor::data::MonitorOrchestration;
 use tina_monitor::tui::{App, PaneFocus, PhaseDetailLayout, ViewState};
-use tina_data::OrchestrationListEntry;
+use tina_data::test_util::list_entry;
 
 /// Helper function to create a test orchestration
 fn make_test_orchestration(name: &str) -> MonitorOrchestration {
-    MonitorOrchestration::from_list_entry(OrchestrationListEntry {
-        id: format!("orch-{}", name),
-        node_name: "test".to_string(),
-        record: tina_data::OrchestrationRecord {
-            node_id: "node-1".to_string(),
-            project_id: None,
-            feature_name: name.to_string(),
-            spec_doc_path: "/test/design.md".to_string(),
-            branch: format!("tina/{}", name),
-            worktree_path: Some("/test".to_string()),
-            total_phases: 3.0,
-            current_phase: 1.0,
-            status: "idle".to_string(),
-            started_at: "2026-02-07T10:00:00Z".to_string(),
-            completed_at: None,
-            total_elapsed_mins: None,
-            spec_id: None,
-            policy_snapshot: None,
-            policy_snapshot_hash: None,
-            preset_origin: None,
-            spec_only: None,
-            policy_revision: None,
-            updated_at: None,
-        },
-    })
+    let mut entry = list_entry(&format!("orch-{}", name), "idle", "2026-02-07T10:00:00Z");
+    entry.record.feature_name = name.to_string();
+    entry.record.branch = format!("tina/{}", name);
+    entry.record.worktree_path = Some("/test".to_string());
+    MonitorOrchestration::from_list_entry(entry)
 }
 
 // ============================================================================
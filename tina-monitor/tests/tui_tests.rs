@@ -4,7 +4,7 @@ use ratatui::{backend::TestBackend, Terminal};
 use std::time::Duration;
 use tina_monitor::data::MonitorOrchestration;
 use tina_monitor::tui::{App, AppResult};
-use tina_data::OrchestrationListEntry;
+use tina_data::test_util::list_entry;
 
 // ============================================================================
 // Module Export Tests
@@ -105,31 +105,11 @@ fn test_empty_state_navigation() {
 // ============================================================================
 
 fn make_test_orchestration(name: &str) -> MonitorOrchestration {
-    MonitorOrchestration::from_list_entry(OrchestrationListEntry {
-        id: format!("orch-{}", name),
-        node_name: "test".to_string(),
-        record: tina_data::OrchestrationRecord {
-            node_id: "node-1".to_string(),
-            project_id: None,
-            feature_name: name.to_string(),
-            spec_doc_path: "/test/design.md".to_string(),
-            branch: format!("tina/{}", name),
-            worktree_path: Some("/test".to_string()),
-            total_phases: 3.0,
-            current_phase: 1.0,
-            status: "idle".to_string(),
-            started_at: "2026-02-07T10:00:00Z".to_string(),
-            completed_at: None,
-            total_elapsed_mins: None,
-            spec_id: None,
-            policy_snapshot: None,
-            policy_snapshot_hash: None,
-            preset_origin: None,
-            spec_only: None,
-            policy_revision: None,
-            updated_at: None,
-        },
-    })
+    let mut entry = list_entry(&format!("orch-{}", name), "idle", "2026-02-07T10:00:00Z");
+    entry.record.feature_name = name.to_string();
+    entry.record.branch = format!("tina/{}", name);
+    entry.record.worktree_path = Some("/test".to_string());
+    MonitorOrchestration::from_list_entry(entry)
 }
 
 /// Test that TUI can handle single orchestration
@@ -267,32 +247,10 @@ fn test_multiple_orchestrations_render() {
 }
 
 // ============================================================================
-// Integration Tests: File Watcher
+// Integration Tests: App Construction
 // ============================================================================
 
-/// Test that FileWatcher can be created
-#[test]
-fn test_file_watcher_can_be_created() {
-    use tina_monitor::watcher::DataWatcher;
-
-    // DataWatcher::new() should either succeed or fail gracefully
-    // (may fail if .claude dirs don't exist, which is OK)
-    let result = DataWatcher::new(None);
-
-    // We don't assert success because it depends on environment
-    // But it should not panic
-    match result {
-        Ok(_watcher) => {
-            // Success case
-        }
-        Err(e) => {
-            // Failure is acceptable if directories don't exist
-            println!("DataWatcher creation failed (acceptable): {}", e);
-        }
-    }
-}
-
-/// Test that App can be constructed without a watcher
+/// Test that App can be constructed from orchestrations alone
 #[test]
 fn test_app_construction_without_watcher() {
     let app = App::new_with_orchestrations(vec![make_test_orchestration("test")]);
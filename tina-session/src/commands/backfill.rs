@@ -0,0 +1,280 @@
+//! Replay commit and plan sync for an orchestration whose daemon missed a
+//! stretch of activity.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+
+use chrono::{DateTime, Utc};
+
+use tina_session::convex::{self, CommitArgs, PlanArgs};
+use tina_session::state::schema::SupervisorState;
+
+/// Field separator for the `git log` format (ASCII unit separator).
+const FIELD_SEP: char = '\x1f';
+
+/// A commit in the backfill range.
+#[derive(Debug, Clone, PartialEq)]
+struct LoggedCommit {
+    sha: String,
+    short_sha: String,
+    authored_at: DateTime<Utc>,
+    subject: String,
+}
+
+/// When a phase started planning.
+#[derive(Debug, Clone, PartialEq)]
+struct PhaseStart {
+    phase: String,
+    started_at: DateTime<Utc>,
+}
+
+pub fn run(feature: &str, since: &str, dry_run: bool) -> anyhow::Result<u8> {
+    let state = SupervisorState::load(feature)?;
+    let worktree = state.worktree_path.clone();
+    let scope = state.scope.as_deref();
+
+    let range = format!("{}..{}", since, state.branch);
+    let commits = list_commits(&worktree, &range, scope)?;
+    let phase_shas = phase_commit_shas(&state, &worktree, scope)?;
+    let starts = phase_starts(&state);
+    let fallback = state.current_phase.to_string();
+
+    let records: Vec<CommitArgs> = commits
+        .iter()
+        .map(|commit| CommitArgs {
+            orchestration_id: String::new(), // filled after lookup
+            phase_number: assign_phase(commit, &phase_shas, &starts, &fallback),
+            sha: commit.sha.clone(),
+            short_sha: Some(commit.short_sha.clone()),
+            subject: Some(commit.subject.clone()),
+            timestamp: Some(commit.authored_at.to_rfc3339()),
+        })
+        .collect();
+    let plans = phase_plans(&state);
+
+    if dry_run {
+        for record in &records {
+            println!(
+                "commit {} phase {} {}",
+                record.short_sha.as_deref().unwrap_or(&record.sha),
+                record.phase_number,
+                record.subject.as_deref().unwrap_or("")
+            );
+        }
+        for plan in &plans {
+            println!("plan   phase {} {}", plan.phase_number, plan.plan_path);
+        }
+        println!(
+            "Would backfill {} commits and {} plans for '{}' (dry run).",
+            records.len(),
+            plans.len(),
+            feature
+        );
+        return Ok(0);
+    }
+
+    let (commit_count, plan_count) = (records.len(), plans.len());
+    convex::run_convex(|mut writer| async move {
+        let orchestration_id = writer
+            .get_by_feature(feature)
+            .await?
+            .map(|o| o.id)
+            .ok_or_else(|| anyhow::anyhow!("No Convex orchestration found for '{}'", feature))?;
+
+        // Convex dedupes commits by SHA and upserts plans by phase, so
+        // overlapping or repeated backfills are harmless.
+        for mut record in records {
+            record.orchestration_id = orchestration_id.clone();
+            writer.record_commit(&record).await?;
+        }
+        for mut plan in plans {
+            plan.orchestration_id = orchestration_id.clone();
+            writer.upsert_plan(&plan).await?;
+        }
+        Ok(())
+    })?;
+
+    println!(
+        "Backfilled {} commits and {} plans for '{}'.",
+        commit_count, plan_count, feature
+    );
+    Ok(0)
+}
+
+fn git(cwd: &Path, args: &[&str], scope: Option<&Path>) -> anyhow::Result<String> {
+    let mut command = Command::new("git");
+    command.current_dir(cwd).args(args);
+    if let Some(scope) = scope {
+        command.arg("--").arg(scope);
+    }
+    let output = command.output()?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Commits in `range`, oldest first.
+fn list_commits(
+    cwd: &Path,
+    range: &str,
+    scope: Option<&Path>,
+) -> anyhow::Result<Vec<LoggedCommit>> {
+    let format = format!("--format=%H{0}%h{0}%aI{0}%s", FIELD_SEP);
+    let output = git(cwd, &["log", "--reverse", &format, range], scope)?;
+    Ok(parse_log(&output))
+}
+
+fn parse_log(output: &str) -> Vec<LoggedCommit> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(4, FIELD_SEP);
+            let sha = fields.next()?.to_string();
+            let short_sha = fields.next()?.to_string();
+            let authored_at = DateTime::parse_from_rfc3339(fields.next()?).ok()?;
+            Some(LoggedCommit {
+                sha,
+                short_sha,
+                authored_at: authored_at.with_timezone(&Utc),
+                subject: fields.next().unwrap_or("").to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Map each SHA in a phase's recorded `git_range` to that phase.
+fn phase_commit_shas(
+    state: &SupervisorState,
+    cwd: &Path,
+    scope: Option<&Path>,
+) -> anyhow::Result<HashMap<String, String>> {
+    let mut shas = HashMap::new();
+    for (phase, phase_state) in &state.phases {
+        let Some(range) = phase_state.git_range.as_deref() else {
+            continue;
+        };
+        for sha in git(cwd, &["rev-list", range], scope)?.lines() {
+            shas.insert(sha.to_string(), phase.clone());
+        }
+    }
+    Ok(shas)
+}
+
+fn phase_starts(state: &SupervisorState) -> Vec<PhaseStart> {
+    let mut starts: Vec<PhaseStart> = state
+        .phases
+        .iter()
+        .filter_map(|(phase, phase_state)| {
+            Some(PhaseStart {
+                phase: phase.clone(),
+                started_at: phase_state.planning_started_at?,
+            })
+        })
+        .collect();
+    starts.sort_by_key(|s| s.started_at);
+    starts
+}
+
+/// Pick the phase a commit belongs to: the phase whose git range contains
+/// it, else the latest phase that had started by the time it was authored,
+/// else `fallback`.
+fn assign_phase(
+    commit: &LoggedCommit,
+    phase_shas: &HashMap<String, String>,
+    starts: &[PhaseStart],
+    fallback: &str,
+) -> String {
+    if let Some(phase) = phase_shas.get(&commit.sha) {
+        return phase.clone();
+    }
+    starts
+        .iter()
+        .rev()
+        .find(|s| s.started_at <= commit.authored_at)
+        .map(|s| s.phase.clone())
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+/// Plans recorded for each phase that still exist on disk.
+fn phase_plans(state: &SupervisorState) -> Vec<PlanArgs> {
+    let mut phases: Vec<(&String, &std::path::PathBuf)> = state
+        .phases
+        .iter()
+        .filter_map(|(phase, phase_state)| Some((phase, phase_state.plan_path.as_ref()?)))
+        .collect();
+    phases.sort();
+
+    phases
+        .into_iter()
+        .filter_map(|(phase, plan_path)| {
+            let path = state.worktree_path.join(plan_path);
+            let content = std::fs::read_to_string(&path).ok()?;
+            Some(PlanArgs {
+                orchestration_id: String::new(), // filled after lookup
+                phase_number: phase.clone(),
+                plan_path: path.to_string_lossy().to_string(),
+                content,
+                last_synced: None,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn commit(sha: &str, authored_at: &str) -> LoggedCommit {
+        LoggedCommit {
+            sha: sha.to_string(),
+            short_sha: sha[..3].to_string(),
+            authored_at: at(authored_at),
+            subject: "work".to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_log_reads_fields_and_skips_malformed_lines() {
+        let output = "abc123\x1fabc\x1f2026-02-13T10:00:00+01:00\x1ffeat: add | things\nbroken\n";
+        let commits = parse_log(output);
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].sha, "abc123");
+        assert_eq!(commits[0].short_sha, "abc");
+        assert_eq!(commits[0].authored_at, at("2026-02-13T09:00:00Z"));
+        assert_eq!(commits[0].subject, "feat: add | things");
+    }
+
+    #[test]
+    fn assign_phase_prefers_git_range_then_start_time() {
+        let starts = vec![
+            PhaseStart {
+                phase: "1".to_string(),
+                started_at: at("2026-02-13T10:00:00Z"),
+            },
+            PhaseStart {
+                phase: "2".to_string(),
+                started_at: at("2026-02-13T12:00:00Z"),
+            },
+        ];
+        let phase_shas = HashMap::from([("ranged".to_string(), "1".to_string())]);
+
+        let assign = |c: &LoggedCommit| assign_phase(c, &phase_shas, &starts, "9");
+        assert_eq!(assign(&commit("ranged", "2026-02-13T12:30:00Z")), "1");
+        assert_eq!(assign(&commit("aaa111", "2026-02-13T10:30:00Z")), "1");
+        assert_eq!(assign(&commit("bbb222", "2026-02-13T12:30:00Z")), "2");
+        // Between phases: belongs to the last phase that had started
+        assert_eq!(assign(&commit("ccc333", "2026-02-13T11:30:00Z")), "1");
+        // Before any phase started
+        assert_eq!(assign(&commit("ddd444", "2026-02-13T09:00:00Z")), "9");
+    }
+}
@@ -2,7 +2,11 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 
+use tina_session::detectors::{self, Severity};
 use tina_session::error::SessionError;
+use tina_session::ignore::IgnoreRules;
+use tina_session::project_config::ProjectLayout;
+use tina_session::state::schema::SupervisorState;
 
 /// Extract function names and their line counts from Rust source code.
 /// Returns vector of (function_name, line_count) tuples.
@@ -55,15 +59,17 @@ fn extract_rust_function_lengths(code: &str) -> Vec<(String, u32)> {
 fn check_function_lengths(
     dir: &Path,
     max_lines: u32,
+    ignore: &IgnoreRules,
 ) -> anyhow::Result<Vec<(String, String, u32)>> {
     let mut violations = Vec::new();
-    check_function_lengths_recursive(dir, max_lines, &mut violations)?;
+    check_function_lengths_recursive(dir, max_lines, ignore, &mut violations)?;
     Ok(violations)
 }
 
 fn check_function_lengths_recursive(
     dir: &Path,
     max_lines: u32,
+    ignore: &IgnoreRules,
     violations: &mut Vec<(String, String, u32)>,
 ) -> anyhow::Result<()> {
     for entry in fs::read_dir(dir)? {
@@ -75,9 +81,12 @@ fn check_function_lengths_recursive(
                 continue;
             }
         }
+        if ignore.is_ignored(&path, path.is_dir()) {
+            continue;
+        }
 
         if path.is_dir() {
-            check_function_lengths_recursive(&path, max_lines, violations)?;
+            check_function_lengths_recursive(&path, max_lines, ignore, violations)?;
         } else if path.extension().and_then(|e| e.to_str()) == Some("rs") {
             if let Ok(contents) = fs::read_to_string(&path) {
                 for (fn_name, line_count) in extract_rust_function_lengths(&contents) {
@@ -101,16 +110,28 @@ pub fn complexity(
         anyhow::bail!(SessionError::DirectoryNotFound(cwd.display().to_string()));
     }
 
+    // Generated files listed in .tina/ignore don't count against budgets
+    let ignore = IgnoreRules::load(cwd);
+
+    // Monorepo orchestrations only budget their own subtree
+    let root = SupervisorState::scoped_root(cwd);
+    let cwd = root.as_path();
+    if !cwd.exists() {
+        anyhow::bail!(SessionError::DirectoryNotFound(cwd.display().to_string()));
+    }
+
     println!("Checking complexity in {}...", cwd.display());
 
     // Try to run tokei for line counts on src/ directory
     let src_dir = cwd.join("src");
     let tokei_path = if src_dir.exists() { &src_dir } else { cwd };
 
-    let output = Command::new("tokei")
-        .args(["--output", "json"])
-        .arg(tokei_path)
-        .output();
+    let mut tokei = Command::new("tokei");
+    tokei.args(["--output", "json"]);
+    for glob in ignore.exclude_globs() {
+        tokei.args(["--exclude", &glob]);
+    }
+    let output = tokei.arg(tokei_path).output();
 
     match output {
         Ok(o) if o.status.success() => {
@@ -142,7 +163,7 @@ pub fn complexity(
     // Check individual file sizes (in src/ if it exists)
     let mut violations = Vec::new();
     let check_dir = if src_dir.exists() { &src_dir } else { cwd };
-    check_file_sizes(check_dir, max_file_lines, &mut violations)?;
+    check_file_sizes(check_dir, max_file_lines, &ignore, &mut violations)?;
 
     if !violations.is_empty() {
         println!("FAIL: Files exceeding {} lines:", max_file_lines);
@@ -153,7 +174,7 @@ pub fn complexity(
     }
 
     // Check function lengths
-    let fn_violations = check_function_lengths(check_dir, max_function_lines)?;
+    let fn_violations = check_function_lengths(check_dir, max_function_lines, &ignore)?;
     if !fn_violations.is_empty() {
         println!("FAIL: Functions exceeding {} lines:", max_function_lines);
         for (path, fn_name, lines) in &fn_violations {
@@ -169,6 +190,7 @@ pub fn complexity(
 fn check_file_sizes(
     dir: &Path,
     max_lines: u32,
+    ignore: &IgnoreRules,
     violations: &mut Vec<(String, u32)>,
 ) -> anyhow::Result<()> {
     for entry in fs::read_dir(dir)? {
@@ -181,9 +203,12 @@ fn check_file_sizes(
                 continue;
             }
         }
+        if ignore.is_ignored(&path, path.is_dir()) {
+            continue;
+        }
 
         if path.is_dir() {
-            check_file_sizes(&path, max_lines, violations)?;
+            check_file_sizes(&path, max_lines, ignore, violations)?;
         } else if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
             // Check source files
             if matches!(ext, "rs" | "ts" | "tsx" | "js" | "jsx" | "py" | "go") {
@@ -353,6 +378,82 @@ fn check_subcommand_surface(binary: &Path, command_path: &[&str]) -> anyhow::Res
     Ok(status.success())
 }
 
+pub fn detectors(cwd: &Path, base: &str) -> anyhow::Result<u8> {
+    if !cwd.exists() {
+        anyhow::bail!(SessionError::DirectoryNotFound(cwd.display().to_string()));
+    }
+
+    let configured = detectors::load_detectors(cwd)?;
+    if configured.is_empty() {
+        println!("PASS: No detectors configured");
+        return Ok(0);
+    }
+
+    let state = SupervisorState::load_local(cwd).ok();
+    let policy = state
+        .as_ref()
+        .map(|s| s.review_policy.clone())
+        .unwrap_or_default();
+    let scope = state.and_then(|s| s.scope);
+    let input = detectors::collect_input(cwd, base, scope.as_deref(), policy.detector_scope)?;
+    println!(
+        "Running {} detector(s) on {} changed file(s)...",
+        configured.len(),
+        input.files.len()
+    );
+
+    let mut failed = false;
+    for detector in &configured {
+        let findings = match detectors::run_detector(detector, cwd, &input) {
+            Ok(findings) => findings,
+            Err(e) => {
+                println!("FAIL: {}: {}", detector.name, e);
+                failed = true;
+                continue;
+            }
+        };
+        if findings.is_empty() {
+            println!("PASS: {}", detector.name);
+            continue;
+        }
+
+        let blocking = findings.iter().any(|f| f.severity == Severity::Error);
+        let label = if blocking && policy.hard_block_detectors {
+            failed = true;
+            "FAIL"
+        } else {
+            "WARN"
+        };
+        println!(
+            "{}: {} ({} finding(s))",
+            label,
+            detector.name,
+            findings.len()
+        );
+        for finding in &findings {
+            let location = match (&finding.file, finding.line) {
+                (Some(file), Some(line)) => format!("{}:{} ", file, line),
+                (Some(file), None) => format!("{} ", file),
+                _ => String::new(),
+            };
+            let rule = finding
+                .rule
+                .as_ref()
+                .map(|r| format!("[{}] ", r))
+                .unwrap_or_default();
+            println!(
+                "  {}{}: {}{}",
+                location,
+                finding.severity.as_str(),
+                rule,
+                finding.message
+            );
+        }
+    }
+
+    Ok(if failed { 1 } else { 0 })
+}
+
 pub fn doctor() -> anyhow::Result<u8> {
     println!("Running tina-session doctor...");
 
@@ -552,7 +653,7 @@ impl Foo {
         long_fn.push_str("}\n");
         fs::write(src.join("main.rs"), long_fn).unwrap();
 
-        let violations = check_function_lengths(&src, 50).unwrap();
+        let violations = check_function_lengths(&src, 50, &IgnoreRules::default()).unwrap();
         assert_eq!(violations.len(), 1);
         assert!(violations[0].0.contains("main.rs"));
         assert_eq!(violations[0].1, "very_long_function");
@@ -568,10 +669,57 @@ impl Foo {
         let short_fn = "fn short() {\n    println!(\"hi\");\n}\n";
         fs::write(src.join("main.rs"), short_fn).unwrap();
 
-        let violations = check_function_lengths(&src, 50).unwrap();
+        let violations = check_function_lengths(&src, 50, &IgnoreRules::default()).unwrap();
         assert!(violations.is_empty());
     }
 
+    #[test]
+    fn test_complexity_only_checks_recorded_scope() {
+        let temp = TempDir::new().unwrap();
+        let worktree = temp.path();
+        let mut state = SupervisorState::new(
+            "svc",
+            PathBuf::from("/spec.md"),
+            worktree.to_path_buf(),
+            "tina/svc",
+            1,
+        );
+        state.scope = Some(PathBuf::from("services/svc"));
+        let local_path = SupervisorState::local_path(worktree);
+        fs::create_dir_all(local_path.parent().unwrap()).unwrap();
+        fs::write(&local_path, serde_json::to_string(&state).unwrap()).unwrap();
+
+        fs::create_dir_all(worktree.join("services/svc")).unwrap();
+        fs::write(worktree.join("services/svc/lib.rs"), "fn ok() {}\n").unwrap();
+        fs::create_dir_all(worktree.join("legacy")).unwrap();
+        fs::write(worktree.join("legacy/big.rs"), "// line\n".repeat(50)).unwrap();
+
+        assert_eq!(complexity(worktree, 10, 100_000, 50).unwrap(), 0);
+
+        fs::write(worktree.join("services/svc/big.rs"), "// line\n".repeat(50)).unwrap();
+        assert_eq!(complexity(worktree, 10, 100_000, 50).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_detectors_pass_without_config() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(detectors(temp.path(), "main").unwrap(), 0);
+    }
+
+    #[test]
+    fn test_complexity_skips_ignored_files() {
+        let temp = TempDir::new().unwrap();
+        let worktree = temp.path();
+        fs::create_dir_all(worktree.join("src/gen")).unwrap();
+        fs::write(worktree.join("src/lib.rs"), "fn ok() {}\n").unwrap();
+        fs::write(worktree.join("src/gen/api.pb.rs"), "// line\n".repeat(50)).unwrap();
+        assert_eq!(complexity(worktree, 10, 100_000, 50).unwrap(), 1);
+
+        fs::create_dir_all(worktree.join(".tina")).unwrap();
+        fs::write(worktree.join(".tina/ignore"), "*.pb.rs\n").unwrap();
+        assert_eq!(complexity(worktree, 10, 100_000, 50).unwrap(), 0);
+    }
+
     #[test]
     fn test_plan_validation_requires_complexity_budget_table() {
         let temp = TempDir::new().unwrap();
@@ -618,6 +766,37 @@ Some text but no table.
         assert_eq!(result, 0, "Should pass with budget table");
     }
 
+    #[test]
+    fn test_plan_validation_honors_configured_plans_dir() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join(".tina")).unwrap();
+        fs::write(
+            temp.path().join(".tina").join("config.toml"),
+            "[layout]\nplans_dir = \"design/plans\"\n",
+        )
+        .unwrap();
+
+        let plan_body = r#"
+### Task 1: Something
+**Model:** haiku
+
+### Complexity Budget
+
+| Metric | Limit |
+|--------|-------|
+| Max lines per file | 400 |
+"#;
+        let plans_dir = temp.path().join("design").join("plans");
+        fs::create_dir_all(&plans_dir).unwrap();
+        let inside = plans_dir.join("plan.md");
+        fs::write(&inside, plan_body).unwrap();
+        let outside = temp.path().join("plan.md");
+        fs::write(&outside, plan_body).unwrap();
+
+        assert_eq!(plan(&inside).unwrap(), 0);
+        assert_eq!(plan(&outside).unwrap(), 1);
+    }
+
     #[test]
     fn test_plan_validation_accepts_sonnet() {
         let temp = TempDir::new().unwrap();
@@ -764,6 +943,19 @@ pub fn plan(path: &Path) -> anyhow::Result<u8> {
 
     println!("Validating plan: {}", path.display());
 
+    // Projects that configure a layout expect plans to live in their plans dir
+    let canonical = fs::canonicalize(path)?;
+    if let Some((root, layout)) = ProjectLayout::discover(&canonical)? {
+        let plans_dir = layout.plans_dir(&root);
+        if !canonical.starts_with(&plans_dir) {
+            println!(
+                "FAIL: Plan is outside the project's plans directory ({})",
+                plans_dir.display()
+            );
+            return Ok(1);
+        }
+    }
+
     let contents = fs::read_to_string(path)?;
 
     // Check for model specifications
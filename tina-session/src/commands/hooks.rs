@@ -0,0 +1,203 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Marker line identifying hooks written by tina-session.
+const HOOK_MARKER: &str = "# tina-managed hook";
+
+/// Git hooks that notify the daemon.
+const HOOK_EVENTS: [&str; 2] = ["post-commit", "post-checkout"];
+
+/// Suffix for a pre-existing hook that the tina hook chains to.
+const BACKUP_SUFFIX: &str = "pre-tina";
+
+/// Default tina-daemon HTTP port.
+pub const DEFAULT_DAEMON_PORT: u16 = 7842;
+
+/// Shell script for one hook.
+///
+/// Runs any pre-existing hook first and keeps its exit status, then pings the
+/// daemon in the background so git is never slowed down or blocked when the
+/// daemon is not running.
+fn hook_script(event: &str, port: u16) -> String {
+    format!(
+        r#"#!/bin/sh
+{marker} ({event}). Installed by `tina-session hooks install`.
+# Notifies tina-daemon so commits sync immediately.
+status=0
+backup="$0.{suffix}"
+if [ -x "$backup" ]; then
+    "$backup" "$@"
+    status=$?
+fi
+root=$(git rev-parse --show-toplevel 2>/dev/null)
+if [ -n "$root" ] && command -v curl >/dev/null 2>&1; then
+    curl -fsS -m 2 -X POST \
+        --data-urlencode "event={event}" \
+        --data-urlencode "worktree=$root" \
+        "http://127.0.0.1:{port}/hooks/git" >/dev/null 2>&1 &
+fi
+exit $status
+"#,
+        marker = HOOK_MARKER,
+        event = event,
+        suffix = BACKUP_SUFFIX,
+        port = port,
+    )
+}
+
+fn is_tina_hook(path: &Path) -> bool {
+    fs::read_to_string(path)
+        .map(|content| content.contains(HOOK_MARKER))
+        .unwrap_or(false)
+}
+
+/// Resolve the hooks directory for a worktree (honours `core.hooksPath` and
+/// linked worktrees sharing the main repository's hooks).
+fn resolve_hooks_dir(cwd: &Path) -> anyhow::Result<PathBuf> {
+    let output = Command::new("git")
+        .current_dir(cwd)
+        .args(["rev-parse", "--git-path", "hooks"])
+        .output()?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "{} is not a git worktree: {}",
+            cwd.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let hooks = PathBuf::from(String::from_utf8(output.stdout)?.trim());
+    Ok(if hooks.is_absolute() {
+        hooks
+    } else {
+        cwd.join(hooks)
+    })
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> anyhow::Result<()> {
+    Ok(())
+}
+
+/// Install the hooks into `hooks_dir`. Returns the installed hook paths.
+fn install_into(hooks_dir: &Path, port: u16) -> anyhow::Result<Vec<PathBuf>> {
+    fs::create_dir_all(hooks_dir)?;
+    let mut installed = Vec::new();
+
+    for event in HOOK_EVENTS {
+        let hook = hooks_dir.join(event);
+        if hook.exists() && !is_tina_hook(&hook) {
+            let backup = hooks_dir.join(format!("{}.{}", event, BACKUP_SUFFIX));
+            if backup.exists() {
+                anyhow::bail!(
+                    "{} exists and is not tina-managed, and {} is already taken",
+                    hook.display(),
+                    backup.display()
+                );
+            }
+            fs::rename(&hook, &backup)?;
+        }
+        fs::write(&hook, hook_script(event, port))?;
+        make_executable(&hook)?;
+        installed.push(hook);
+    }
+
+    Ok(installed)
+}
+
+/// Remove tina hooks from `hooks_dir`, restoring any chained originals.
+fn uninstall_from(hooks_dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut removed = Vec::new();
+
+    for event in HOOK_EVENTS {
+        let hook = hooks_dir.join(event);
+        if !hook.exists() || !is_tina_hook(&hook) {
+            continue;
+        }
+        fs::remove_file(&hook)?;
+        let backup = hooks_dir.join(format!("{}.{}", event, BACKUP_SUFFIX));
+        if backup.exists() {
+            fs::rename(&backup, &hook)?;
+        }
+        removed.push(hook);
+    }
+
+    Ok(removed)
+}
+
+pub fn install(cwd: &Path, port: u16) -> anyhow::Result<u8> {
+    let hooks_dir = resolve_hooks_dir(cwd)?;
+    for hook in install_into(&hooks_dir, port)? {
+        println!("Installed {}", hook.display());
+    }
+    Ok(0)
+}
+
+pub fn uninstall(cwd: &Path) -> anyhow::Result<u8> {
+    let hooks_dir = resolve_hooks_dir(cwd)?;
+    let removed = uninstall_from(&hooks_dir)?;
+    if removed.is_empty() {
+        println!("No tina hooks installed in {}", hooks_dir.display());
+    }
+    for hook in removed {
+        println!("Removed {}", hook.display());
+    }
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_hook_script_pings_daemon_and_chains_backup() {
+        let script = hook_script("post-commit", 9999);
+        assert!(script.starts_with("#!/bin/sh\n"));
+        assert!(script.contains(HOOK_MARKER));
+        assert!(script.contains("http://127.0.0.1:9999/hooks/git"));
+        assert!(script.contains("event=post-commit"));
+        assert!(script.contains("$0.pre-tina"));
+    }
+
+    #[test]
+    fn test_install_backs_up_foreign_hook_and_is_idempotent() {
+        let tmp = TempDir::new().unwrap();
+        let hooks = tmp.path();
+        fs::write(hooks.join("post-commit"), "#!/bin/sh\necho mine\n").unwrap();
+
+        install_into(hooks, 7842).unwrap();
+        install_into(hooks, 7842).unwrap();
+
+        assert!(is_tina_hook(&hooks.join("post-commit")));
+        assert!(is_tina_hook(&hooks.join("post-checkout")));
+        assert_eq!(
+            fs::read_to_string(hooks.join("post-commit.pre-tina")).unwrap(),
+            "#!/bin/sh\necho mine\n"
+        );
+    }
+
+    #[test]
+    fn test_uninstall_restores_backup() {
+        let tmp = TempDir::new().unwrap();
+        let hooks = tmp.path();
+        fs::write(hooks.join("post-commit"), "#!/bin/sh\necho mine\n").unwrap();
+        install_into(hooks, 7842).unwrap();
+
+        let removed = uninstall_from(hooks).unwrap();
+        assert_eq!(removed.len(), 2);
+        assert_eq!(
+            fs::read_to_string(hooks.join("post-commit")).unwrap(),
+            "#!/bin/sh\necho mine\n"
+        );
+        assert!(!hooks.join("post-checkout").exists());
+        assert!(!hooks.join("post-commit.pre-tina").exists());
+    }
+}
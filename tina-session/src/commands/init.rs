@@ -23,12 +23,18 @@ set -e
 TINA_DIR="${PWD}/.claude/tina"
 mkdir -p "$TINA_DIR"
 INPUT=$(cat)
-echo "$INPUT" | jq '{
+METRICS=$(echo "$INPUT" | jq '{
   used_pct: (.context_window.used_percentage // 0),
   tokens: (.context_window.total_input_tokens // 0),
   max: (.context_window.context_window_size // 200000),
   timestamp: now | todate
-}' > "$TINA_DIR/context-metrics.json"
+}')
+echo "$METRICS" > "$TINA_DIR/context-metrics.json"
+# Per-agent copy, keyed by tmux pane, for the monitor's context gauges
+if [ -n "$TMUX_PANE" ]; then
+  mkdir -p "$TINA_DIR/context"
+  echo "$METRICS" > "$TINA_DIR/context/${TMUX_PANE#%}.json"
+fi
 echo "ctx:$(echo "$INPUT" | jq -r '.context_window.used_percentage // 0 | floor')%"
 "#;
 const CLAUDE_READY_TIMEOUT_SECS: u64 = 60;
@@ -40,6 +46,7 @@ pub fn run(
     spec_id: Option<&str>,
     branch: &str,
     total_phases: u32,
+    scope: Option<&Path>,
     review_enforcement: Option<&str>,
     detector_scope: Option<&str>,
     architect_mode: Option<&str>,
@@ -55,6 +62,7 @@ pub fn run(
         spec_id,
         branch,
         total_phases,
+        scope,
         review_enforcement,
         detector_scope,
         architect_mode,
@@ -73,6 +81,7 @@ pub fn run_with_options(
     spec_id: Option<&str>,
     branch: &str,
     total_phases: u32,
+    scope: Option<&Path>,
     review_enforcement: Option<&str>,
     detector_scope: Option<&str>,
     architect_mode: Option<&str>,
@@ -101,6 +110,10 @@ pub fn run_with_options(
     }
 
     let cwd_abs = fs::canonicalize(cwd)?;
+    let scope = scope
+        .map(|scope| normalize_scope(&cwd_abs, scope))
+        .transpose()?
+        .flatten();
 
     // Resolve spec source: either a local file or a Convex spec ID
     let (spec_doc_path, resolved_spec_id, spec_markdown) =
@@ -206,6 +219,7 @@ pub fn run_with_options(
         allow_rare_override,
         require_fix_first,
     )?;
+    state.scope = scope.clone();
     state.save()?;
 
     // Write orchestration record to Convex
@@ -255,6 +269,9 @@ pub fn run_with_options(
     if let Some(did) = resolved_spec_id.as_deref() {
         output["spec_id"] = serde_json::Value::String(did.to_string());
     }
+    if let Some(scope) = scope.as_deref() {
+        output["scope"] = serde_json::Value::String(scope.display().to_string());
+    }
     if let Some(session_name) = orchestration_tmux_session {
         output["tmux_session_name"] = serde_json::Value::String(session_name);
     }
@@ -263,6 +280,37 @@ pub fn run_with_options(
     Ok(0)
 }
 
+/// Validate `--scope` and make it relative to the project root.
+///
+/// Returns `None` when the scope is the root itself (no scoping).
+fn normalize_scope(repo_root: &Path, scope: &Path) -> anyhow::Result<Option<PathBuf>> {
+    let absolute = if scope.is_absolute() {
+        scope.to_path_buf()
+    } else {
+        repo_root.join(scope)
+    };
+    let canonical = fs::canonicalize(&absolute)
+        .map_err(|_| SessionError::DirectoryNotFound(absolute.display().to_string()))?;
+    if !canonical.is_dir() {
+        anyhow::bail!(SessionError::DirectoryNotFound(format!(
+            "{} is not a directory",
+            canonical.display()
+        )));
+    }
+    let relative = canonical.strip_prefix(repo_root).map_err(|_| {
+        anyhow::anyhow!(
+            "--scope {} is outside the project root {}",
+            scope.display(),
+            repo_root.display()
+        )
+    })?;
+    if relative.as_os_str().is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(relative.to_path_buf()))
+    }
+}
+
 /// Resolve the spec source to an absolute path, optional spec ID, and optional markdown.
 ///
 /// When `--spec-doc` is provided, validates and canonicalizes the path.
@@ -878,6 +926,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
 
         assert!(result.is_ok());
@@ -936,6 +985,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
 
         // worktree cleanup below
@@ -1002,6 +1052,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
 
         // worktree cleanup below
@@ -1043,6 +1094,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         assert!(result.is_err());
     }
@@ -1064,10 +1116,30 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_normalize_scope() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = fs::canonicalize(temp_dir.path()).unwrap();
+        fs::create_dir_all(root.join("services").join("auth")).unwrap();
+
+        assert_eq!(
+            normalize_scope(&root, Path::new("services/auth")).unwrap(),
+            Some(PathBuf::from("services/auth"))
+        );
+        assert_eq!(
+            normalize_scope(&root, &root.join("services")).unwrap(),
+            Some(PathBuf::from("services"))
+        );
+        assert_eq!(normalize_scope(&root, Path::new(".")).unwrap(), None);
+        assert!(normalize_scope(&root, Path::new("missing")).is_err());
+        assert!(normalize_scope(&root.join("services"), Path::new("..")).is_err());
+    }
+
     #[test]
     fn test_ensure_gitignored_creates_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -1378,6 +1450,7 @@ Supervisor state tracks progress.
             None,
             None,
             None,
+            None,
         );
 
         assert!(
@@ -1459,6 +1532,7 @@ Supervisor state tracks progress.
             None,
             None,
             None,
+            None,
         );
 
         assert!(
@@ -1547,6 +1621,7 @@ Supervisor state tracks progress.
             None,
             None,
             None,
+            None,
         );
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
@@ -1575,6 +1650,7 @@ Supervisor state tracks progress.
             None,
             None,
             None,
+            None,
         );
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
@@ -1,4 +1,5 @@
 pub mod attach;
+pub mod backfill;
 pub mod capture;
 pub mod check;
 pub mod cleanup;
@@ -6,12 +7,15 @@ pub mod config;
 pub mod daemon;
 pub mod exec_codex;
 pub mod exists;
+pub mod hooks;
 pub mod init;
 pub mod list;
 pub mod name;
 pub mod orchestrate;
+pub mod projections;
 pub mod register_team;
 pub mod review;
+pub mod risk;
 pub mod runtime_context;
 pub mod send;
 pub mod start;
@@ -19,5 +23,7 @@ pub mod state;
 pub mod state_sync;
 pub mod status;
 pub mod stop;
+pub mod token;
+pub mod triage;
 pub mod wait;
 pub mod work;
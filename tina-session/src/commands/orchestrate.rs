@@ -1,5 +1,7 @@
 use std::path::{Path, PathBuf};
 
+use tina_session::policy::PolicyScript;
+use tina_session::project_config::ProjectLayout;
 use tina_session::state::orchestrate::{advance_state, next_action, Action, AdvanceEvent};
 use tina_session::telemetry::TelemetryContext;
 
@@ -18,7 +20,10 @@ pub fn next(feature: &str) -> anyhow::Result<u8> {
         None,
     );
 
-    let action = next_action(&state)?;
+    let mut action = next_action(&state)?;
+    if let Some(policy) = PolicyScript::discover(&state.worktree_path)? {
+        policy.apply_model(&state, &mut action)?;
+    }
 
     // Record telemetry (best-effort)
     if let Err(e) = record_next_telemetry(&ctx, &state, &action) {
@@ -63,8 +68,15 @@ pub fn advance(
         phase_number.clone(),
     );
 
-    let event = parse_event(event, normalized_plan_path.as_deref(), git_range, issues)?;
-    let action = advance_state(&mut state, phase, event.clone())?;
+    let mut event = parse_event(event, normalized_plan_path.as_deref(), git_range, issues)?;
+    let policy = PolicyScript::discover(&state.worktree_path)?;
+    if let Some(policy) = &policy {
+        event = policy.review_event(&state, phase, event)?;
+    }
+    let mut action = advance_state(&mut state, phase, event.clone())?;
+    if let Some(policy) = &policy {
+        policy.apply_model(&state, &mut action)?;
+    }
 
     state.save()?;
 
@@ -75,6 +87,13 @@ pub fn advance(
         eprintln!("Warning: Failed to sync to Convex: {}", e);
     }
 
+    // Score the phase once its commits are known (non-fatal)
+    if matches!(event, AdvanceEvent::ExecuteComplete { .. }) {
+        if let Err(e) = super::risk::record_for_phase(feature, &state, phase) {
+            eprintln!("Warning: Failed to record phase risk: {}", e);
+        }
+    }
+
     println!("{}", serde_json::to_string(&action)?);
     Ok(0)
 }
@@ -101,7 +120,7 @@ fn resolve_plan_path(plan_path: &Path, worktree_path: &Path) -> anyhow::Result<P
         );
     }
 
-    let plans_dir = canonical_worktree.join("docs").join("plans");
+    let plans_dir = ProjectLayout::load(&canonical_worktree)?.plans_dir(&canonical_worktree);
     if !canonical.starts_with(&plans_dir) {
         anyhow::bail!(
             "Invalid --plan-path '{}': path must be under '{}'",
@@ -174,7 +193,7 @@ fn parse_event(
     }
 }
 
-fn event_from_action(
+pub(crate) fn event_from_action(
     phase: &str,
     action: &Action,
     event: Option<&AdvanceEvent>,
@@ -582,6 +601,30 @@ mod tests {
         assert_eq!(resolved, plan.canonicalize().expect("canonical plan"));
     }
 
+    #[test]
+    fn resolve_plan_path_honors_configured_plans_dir() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let worktree = tmp.path().join("worktree");
+        fs::create_dir_all(worktree.join(".tina")).expect("create config dir");
+        fs::write(
+            worktree.join(".tina").join("config.toml"),
+            "[layout]\nplans_dir = \"design/plans\"\n",
+        )
+        .expect("write config");
+        let plans_dir = worktree.join("design").join("plans");
+        fs::create_dir_all(&plans_dir).expect("create plans dir");
+        let plan = plans_dir.join("phase-1.md");
+        fs::write(&plan, "# plan").expect("write plan");
+        fs::create_dir_all(worktree.join("docs").join("plans")).expect("create docs dir");
+        let default_plan = worktree.join("docs").join("plans").join("phase-1.md");
+        fs::write(&default_plan, "# plan").expect("write default plan");
+
+        let resolved = resolve_plan_path(Path::new("design/plans/phase-1.md"), &worktree)
+            .expect("resolved path");
+        assert_eq!(resolved, plan.canonicalize().expect("canonical plan"));
+        assert!(resolve_plan_path(&default_plan, &worktree).is_err());
+    }
+
     #[test]
     fn resolve_plan_path_rejects_missing_file() {
         let tmp = tempfile::tempdir().expect("tempdir");
@@ -0,0 +1,510 @@
+//! Rebuild phase and task projections from the stored event stream.
+//!
+//! Phase documents are upserted alongside each orchestrator event, and the
+//! current-task view is folded from task events. Re-deriving both from the
+//! events alone and comparing against what's stored catches drift in the
+//! documents and gaps in the event log.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::Utc;
+use serde_json::Value;
+
+use tina_data::{PhaseRecord, TaskEventRecord};
+use tina_session::convex::{self, EventArgs, PhaseArgs};
+use tina_session::state::history::{task_key, tasks_at};
+
+/// `source` on orchestration events recorded by the state machine.
+const ORCHESTRATE_SOURCE: &str = "tina-session orchestrate";
+/// Upper bound on orchestration events fetched from Convex.
+const EVENT_FETCH_LIMIT: i64 = 10_000;
+
+/// A phase projection folded from orchestrator events.
+#[derive(Debug, Clone, PartialEq)]
+struct DerivedPhase {
+    status: String,
+    plan_path: Option<String>,
+    git_range: Option<String>,
+    started_at: String,
+    completed_at: Option<String>,
+    /// Status to return to when a blocked phase is retried.
+    before_blocked: Option<String>,
+}
+
+impl DerivedPhase {
+    fn new(started_at: &str) -> Self {
+        Self {
+            status: "planning".to_string(),
+            plan_path: None,
+            git_range: None,
+            started_at: started_at.to_string(),
+            completed_at: None,
+            before_blocked: None,
+        }
+    }
+}
+
+/// One way a stored projection disagrees with the events.
+#[derive(Debug, Clone, PartialEq)]
+struct Divergence {
+    subject: String,
+    field: &'static str,
+    stored: String,
+    derived: String,
+}
+
+fn detail_str(detail: &Value, key: &str) -> Option<String> {
+    detail.get(key)?.as_str().map(str::to_string)
+}
+
+/// Fold orchestrator events into per-phase projections.
+///
+/// Matches on event type and the structured `detail` each transition
+/// records (see `orchestrate::event_from_action`), not on summaries.
+fn derive_phases(events: &[EventArgs]) -> BTreeMap<String, DerivedPhase> {
+    let mut sorted: Vec<&EventArgs> = events
+        .iter()
+        .filter(|event| event.source == ORCHESTRATE_SOURCE)
+        .collect();
+    sorted.sort_by(|a, b| a.recorded_at.cmp(&b.recorded_at));
+
+    let mut phases: BTreeMap<String, DerivedPhase> = BTreeMap::new();
+    for event in sorted {
+        let Some(key) = event.phase_number.as_deref() else {
+            continue;
+        };
+        let detail: Value = event
+            .detail
+            .as_deref()
+            .and_then(|d| serde_json::from_str(d).ok())
+            .unwrap_or(Value::Null);
+        let at = event.recorded_at.as_str();
+        let phase = phases
+            .entry(key.to_string())
+            .or_insert_with(|| DerivedPhase::new(at));
+
+        match event.event_type.as_str() {
+            "phase_completed" => {
+                if let Some(plan_path) = detail_str(&detail, "plan_path") {
+                    phase.status = "executing".to_string();
+                    phase.plan_path = Some(plan_path);
+                } else if let Some(git_range) = detail_str(&detail, "git_range") {
+                    phase.status = "reviewing".to_string();
+                    phase.git_range = Some(git_range);
+                } else {
+                    phase.status = "complete".to_string();
+                    phase.completed_at = Some(at.to_string());
+                }
+            }
+            "retry" => {
+                if let Some(remediation) = detail_str(&detail, "remediation_phase") {
+                    phase.status = "complete".to_string();
+                    phase.completed_at = Some(at.to_string());
+                    phases
+                        .entry(remediation)
+                        .or_insert_with(|| DerivedPhase::new(at));
+                } else if detail.get("strategy").and_then(Value::as_str) == Some("in_phase_repair")
+                {
+                    phase.status = "planning".to_string();
+                } else if let Some(status) = phase.before_blocked.take() {
+                    phase.status = status;
+                }
+            }
+            "error" => {
+                if phase.status != "blocked" {
+                    phase.before_blocked = Some(phase.status.clone());
+                }
+                phase.status = "blocked".to_string();
+            }
+            _ => {}
+        }
+    }
+    phases
+}
+
+/// Compare stored phase documents against the derived projections.
+fn diff_phases(
+    stored: &[PhaseRecord],
+    derived: &BTreeMap<String, DerivedPhase>,
+) -> Vec<Divergence> {
+    let stored: BTreeMap<&str, &PhaseRecord> = stored
+        .iter()
+        .map(|phase| (phase.phase_number.as_str(), phase))
+        .collect();
+    let keys: BTreeSet<&str> = stored
+        .keys()
+        .copied()
+        .chain(derived.keys().map(String::as_str))
+        .collect();
+    let missing = || "-".to_string();
+
+    let mut divergences = Vec::new();
+    for key in keys {
+        let subject = format!("phase {}", key);
+        match (stored.get(key), derived.get(key)) {
+            (Some(doc), Some(phase)) => {
+                let fields = [
+                    ("status", Some(&doc.status), Some(&phase.status)),
+                    (
+                        "plan_path",
+                        doc.plan_path.as_ref(),
+                        phase.plan_path.as_ref(),
+                    ),
+                    (
+                        "git_range",
+                        doc.git_range.as_ref(),
+                        phase.git_range.as_ref(),
+                    ),
+                ];
+                for (field, stored, derived) in fields {
+                    if stored != derived {
+                        divergences.push(Divergence {
+                            subject: subject.clone(),
+                            field,
+                            stored: stored.cloned().unwrap_or_else(missing),
+                            derived: derived.cloned().unwrap_or_else(missing),
+                        });
+                    }
+                }
+            }
+            // Planning starts aren't recorded per phase, so a phase still
+            // planning legitimately has no events yet.
+            (Some(doc), None) if doc.status != "planning" => divergences.push(Divergence {
+                subject,
+                field: "document",
+                stored: doc.status.clone(),
+                derived: "no events".to_string(),
+            }),
+            (None, Some(phase)) => divergences.push(Divergence {
+                subject,
+                field: "document",
+                stored: "missing".to_string(),
+                derived: phase.status.clone(),
+            }),
+            _ => {}
+        }
+    }
+    divergences
+}
+
+/// Compare the stored current-task view against a fold of every task event.
+fn diff_tasks(stored: &[TaskEventRecord], derived: &[TaskEventRecord]) -> Vec<Divergence> {
+    let stored: BTreeMap<String, &TaskEventRecord> =
+        stored.iter().map(|task| (task_key(task), task)).collect();
+    let derived: BTreeMap<String, &TaskEventRecord> =
+        derived.iter().map(|task| (task_key(task), task)).collect();
+    let keys: BTreeSet<&String> = stored.keys().chain(derived.keys()).collect();
+
+    let mut divergences = Vec::new();
+    for key in keys {
+        let subject = format!("task {}", key);
+        let owner = |task: &TaskEventRecord| task.owner.clone().unwrap_or_else(|| "-".to_string());
+        match (stored.get(key), derived.get(key)) {
+            (Some(doc), Some(task)) => {
+                for (field, stored, derived) in [
+                    ("status", doc.status.clone(), task.status.clone()),
+                    ("owner", owner(doc), owner(task)),
+                ] {
+                    if stored != derived {
+                        divergences.push(Divergence {
+                            subject: subject.clone(),
+                            field,
+                            stored,
+                            derived,
+                        });
+                    }
+                }
+            }
+            (stored, derived) => divergences.push(Divergence {
+                subject,
+                field: "document",
+                stored: stored.map_or("missing".to_string(), |t| t.status.clone()),
+                derived: derived.map_or("no events".to_string(), |t| t.status.clone()),
+            }),
+        }
+    }
+    divergences
+}
+
+/// Phase args that bring a stored phase in line with the events, keeping
+/// the stored timing breakdown.
+fn phase_args(key: &str, phase: &DerivedPhase, doc: Option<&PhaseRecord>) -> PhaseArgs {
+    PhaseArgs {
+        orchestration_id: String::new(), // filled after lookup
+        phase_number: key.to_string(),
+        status: phase.status.clone(),
+        plan_path: phase.plan_path.clone(),
+        git_range: phase.git_range.clone(),
+        planning_mins: doc.and_then(|d| d.planning_mins),
+        execution_mins: doc.and_then(|d| d.execution_mins),
+        review_mins: doc.and_then(|d| d.review_mins),
+        started_at: doc
+            .and_then(|d| d.started_at.clone())
+            .or_else(|| Some(phase.started_at.clone())),
+        completed_at: phase.completed_at.clone(),
+        risk_score: None,
+        risk_signals: None,
+    }
+}
+
+pub fn rebuild(feature: &str, dry_run: bool) -> anyhow::Result<u8> {
+    let (orchestration_id, events, stored_phases, stored_tasks, task_events) =
+        convex::run_convex(|mut writer| async move {
+            let orchestration_id = writer
+                .get_by_feature(feature)
+                .await?
+                .map(|o| o.id)
+                .ok_or_else(|| {
+                    anyhow::anyhow!("No Convex orchestration found for '{}'", feature)
+                })?;
+            let detail = writer
+                .get_orchestration_detail(&orchestration_id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("Orchestration '{}' has no detail", feature))?;
+            let events = writer
+                .list_events(&orchestration_id, EVENT_FETCH_LIMIT)
+                .await?;
+            let task_events = writer.list_all_task_events(&orchestration_id).await?;
+            Ok((
+                orchestration_id,
+                events,
+                detail.phases,
+                detail.tasks,
+                task_events,
+            ))
+        })?;
+
+    let derived_phases = derive_phases(&events);
+    let phase_divergences = diff_phases(&stored_phases, &derived_phases);
+    let task_divergences = diff_tasks(&stored_tasks, &tasks_at(&task_events, Utc::now()));
+
+    println!(
+        "Rebuilt {} phases from {} events and {} tasks from {} task events.",
+        derived_phases.len(),
+        events.len(),
+        stored_tasks.len(),
+        task_events.len()
+    );
+    if phase_divergences.is_empty() && task_divergences.is_empty() {
+        println!("Projections match the event stream.");
+        return Ok(0);
+    }
+
+    println!();
+    println!("Divergences (stored → derived):");
+    for d in phase_divergences.iter().chain(&task_divergences) {
+        println!("  {} {}: {} → {}", d.subject, d.field, d.stored, d.derived);
+    }
+
+    // Only phases the events describe can be rewritten; a document with no
+    // events behind it points at a gap in the log, not drift.
+    let fixes: Vec<PhaseArgs> = phase_divergences
+        .iter()
+        .map(|d| d.subject.trim_start_matches("phase "))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter_map(|key| {
+            let phase = derived_phases.get(key)?;
+            let doc = stored_phases.iter().find(|d| d.phase_number == key);
+            Some(phase_args(key, phase, doc))
+        })
+        .collect();
+
+    println!();
+    if dry_run {
+        println!("Would rewrite {} phase documents (dry run).", fixes.len());
+        return Ok(1);
+    }
+
+    let fixed = fixes.len();
+    if fixed > 0 {
+        convex::run_convex(|mut writer| async move {
+            for mut args in fixes {
+                args.orchestration_id = orchestration_id.clone();
+                writer.upsert_phase(&args).await?;
+            }
+            Ok(())
+        })?;
+    }
+    println!("Rewrote {} phase documents.", fixed);
+    if !task_divergences.is_empty() {
+        // The current-task view is folded on read from a bounded window of
+        // recent events, so there's no document to rewrite.
+        println!(
+            "{} task divergences: the current-task view is missing events outside its scan window.",
+            task_divergences.len()
+        );
+        return Ok(1);
+    }
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::orchestrate::event_from_action;
+    use tina_session::state::orchestrate::Action;
+
+    fn recorded(phase: &str, action: &Action, at: &str) -> EventArgs {
+        let (event_type, summary, detail) = event_from_action(phase, action, None);
+        EventArgs {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: Some(phase.to_string()),
+            event_type,
+            source: ORCHESTRATE_SOURCE.to_string(),
+            summary,
+            detail,
+            recorded_at: at.to_string(),
+        }
+    }
+
+    fn doc(phase: &str, status: &str, plan: Option<&str>, range: Option<&str>) -> PhaseRecord {
+        PhaseRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: phase.to_string(),
+            status: status.to_string(),
+            plan_path: plan.map(str::to_string),
+            git_range: range.map(str::to_string),
+            planning_mins: Some(12.0),
+            execution_mins: None,
+            review_mins: None,
+            started_at: None,
+            completed_at: None,
+            risk_score: None,
+            risk_signals: None,
+        }
+    }
+
+    fn task(id: &str, status: &str, owner: Option<&str>) -> TaskEventRecord {
+        TaskEventRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: Some("1".to_string()),
+            task_id: id.to_string(),
+            subject: format!("Task {}", id),
+            description: None,
+            status: status.to_string(),
+            owner: owner.map(str::to_string),
+            blocked_by: None,
+            metadata: None,
+            recorded_at: "2026-03-01T10:00:00Z".to_string(),
+        }
+    }
+
+    fn phase_one_events() -> Vec<EventArgs> {
+        vec![
+            recorded(
+                "1",
+                &Action::SpawnExecutor {
+                    phase: "1".to_string(),
+                    plan_path: "docs/plans/p1.md".to_string(),
+                    model: None,
+                },
+                "2026-03-01T09:30:00Z",
+            ),
+            recorded(
+                "1",
+                &Action::SpawnReviewer {
+                    phase: "1".to_string(),
+                    git_range: "abc..def".to_string(),
+                    model: None,
+                    secondary_model: None,
+                },
+                "2026-03-01T10:30:00Z",
+            ),
+        ]
+    }
+
+    #[test]
+    fn derive_phases_follows_recorded_transitions() {
+        let mut events = phase_one_events();
+        let phases = derive_phases(&events);
+        assert_eq!(phases["1"].status, "reviewing");
+        assert_eq!(phases["1"].plan_path.as_deref(), Some("docs/plans/p1.md"));
+        assert_eq!(phases["1"].git_range.as_deref(), Some("abc..def"));
+
+        events.push(recorded(
+            "1",
+            &Action::Error {
+                phase: "1".to_string(),
+                reason: "review crashed".to_string(),
+                retry_count: 0,
+                can_retry: true,
+            },
+            "2026-03-01T10:40:00Z",
+        ));
+        assert_eq!(derive_phases(&events)["1"].status, "blocked");
+
+        events.push(recorded(
+            "1",
+            &Action::Remediate {
+                phase: "1".to_string(),
+                remediation_phase: "1.5".to_string(),
+                issues: vec!["missing tests".to_string()],
+            },
+            "2026-03-01T11:00:00Z",
+        ));
+        let phases = derive_phases(&events);
+        assert_eq!(phases["1"].status, "complete");
+        assert_eq!(
+            phases["1"].completed_at.as_deref(),
+            Some("2026-03-01T11:00:00Z")
+        );
+        assert_eq!(phases["1.5"].status, "planning");
+    }
+
+    #[test]
+    fn diff_phases_reports_field_drift_and_log_gaps() {
+        let derived = derive_phases(&phase_one_events());
+        let stored = vec![
+            doc("1", "executing", Some("docs/plans/p1.md"), None),
+            doc("2", "executing", None, None),
+            doc("3", "planning", None, None),
+        ];
+        let divergences: Vec<String> = diff_phases(&stored, &derived)
+            .into_iter()
+            .map(|d| format!("{} {}: {} -> {}", d.subject, d.field, d.stored, d.derived))
+            .collect();
+        assert_eq!(
+            divergences,
+            vec![
+                "phase 1 status: executing -> reviewing",
+                "phase 1 git_range: - -> abc..def",
+                "phase 2 document: executing -> no events",
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_tasks_compares_status_owner_and_presence() {
+        let stored = vec![
+            task("1", "in_progress", Some("worker")),
+            task("2", "pending", None),
+        ];
+        let derived = vec![
+            task("1", "completed", Some("worker")),
+            task("3", "pending", None),
+        ];
+        let divergences: Vec<String> = diff_tasks(&stored, &derived)
+            .into_iter()
+            .map(|d| format!("{} {}: {} -> {}", d.subject, d.field, d.stored, d.derived))
+            .collect();
+        assert_eq!(
+            divergences,
+            vec![
+                "task 1/1 status: in_progress -> completed",
+                "task 1/2 document: pending -> no events",
+                "task 1/3 document: missing -> pending",
+            ]
+        );
+    }
+
+    #[test]
+    fn phase_args_keep_stored_timing() {
+        let derived = derive_phases(&phase_one_events());
+        let stored = doc("1", "executing", None, None);
+        let args = phase_args("1", &derived["1"], Some(&stored));
+        assert_eq!(args.status, "reviewing");
+        assert_eq!(args.planning_mins, Some(12.0));
+        assert_eq!(args.started_at.as_deref(), Some("2026-03-01T09:30:00Z"));
+    }
+}
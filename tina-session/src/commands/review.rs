@@ -1,5 +1,9 @@
+use std::path::Path;
+
 use serde_json::json;
 use tina_session::convex;
+use tina_session::policy::{FindingContext, PolicyScript};
+use tina_session::project_config;
 
 /// Start a new review for a phase or orchestration.
 pub fn start(
@@ -81,16 +85,32 @@ pub fn add_finding(
     author: &str,
     json_mode: bool,
 ) -> Result<u8, anyhow::Error> {
-    let rid = review_id.to_string();
+    let mut finding = FindingContext {
+        review_id: review_id.to_string(),
+        file: file.to_string(),
+        line,
+        commit: commit.to_string(),
+        severity: severity.to_string(),
+        gate: gate.to_string(),
+        summary: summary.to_string(),
+        body: body.to_string(),
+        source: source.to_string(),
+        author: author.to_string(),
+    };
+    if let Some(policy) = PolicyScript::discover(&std::env::current_dir()?)? {
+        policy.on_finding_added(&mut finding)?;
+    }
+
+    let rid = finding.review_id.clone();
     let oid = orchestration_id.to_string();
-    let f = file.to_string();
-    let c = commit.to_string();
-    let sev = severity.to_string();
-    let g = gate.to_string();
-    let sum = summary.to_string();
-    let b = body.to_string();
-    let src = source.to_string();
-    let auth = author.to_string();
+    let f = finding.file.clone();
+    let c = finding.commit.clone();
+    let sev = finding.severity.clone();
+    let g = finding.gate.clone();
+    let sum = finding.summary.clone();
+    let b = finding.body.clone();
+    let src = finding.source.clone();
+    let auth = finding.author.clone();
 
     let thread_id = convex::run_convex(|mut writer| async move {
         writer
@@ -254,6 +274,10 @@ pub fn run_checks(feature: &str, review_id: &str, json_mode: bool) -> Result<u8,
 }
 
 /// Approve a gate.
+///
+/// Each distinct `decided_by` counts once toward the gate's quorum
+/// (`[gates.<gate>] required_approvals` in the project config); the gate
+/// stays pending until enough approvals are in.
 pub fn gate_approve(
     feature: &str,
     gate: &str,
@@ -262,33 +286,43 @@ pub fn gate_approve(
     json_mode: bool,
 ) -> Result<u8, anyhow::Error> {
     let orch = load_orchestration(feature)?;
+    let required = configured_quorum(&orch, gate)?;
     let g = gate.to_string();
     let db = decided_by.to_string();
     let sum = summary.to_string();
 
-    let gate_id = convex::run_convex(|mut writer| async move {
+    let record = convex::run_convex(|mut writer| async move {
         writer
-            .upsert_review_gate(&orch.id, &g, "approved", "human", Some(&db), &sum)
+            .record_gate_decision(&orch.id, &g, &db, "approved", &sum, required)
             .await
     })?;
+    let approvals = record.approvals();
+    let required = record.required_approvals.unwrap_or(1);
 
     if json_mode {
         println!(
             "{}",
             json!({
                 "ok": true,
-                "gateId": gate_id,
+                "gateId": record.id,
                 "gate": gate,
-                "status": "approved",
+                "status": record.status,
+                "approvals": approvals,
+                "requiredApprovals": required,
             })
         );
-    } else {
+    } else if record.status == "approved" {
         println!("Approved gate: {}", gate);
+    } else {
+        println!(
+            "Recorded approval for gate {} ({}/{} approvals)",
+            gate, approvals, required
+        );
     }
     Ok(0)
 }
 
-/// Block a gate.
+/// Block a gate. A block discards approvals collected so far.
 pub fn gate_block(
     feature: &str,
     gate: &str,
@@ -297,13 +331,14 @@ pub fn gate_block(
     json_mode: bool,
 ) -> Result<u8, anyhow::Error> {
     let orch = load_orchestration(feature)?;
+    let required = configured_quorum(&orch, gate)?;
     let g = gate.to_string();
     let r = reason.to_string();
     let db = decided_by.to_string();
 
-    let gate_id = convex::run_convex(|mut writer| async move {
+    let record = convex::run_convex(|mut writer| async move {
         writer
-            .upsert_review_gate(&orch.id, &g, "blocked", "review-agent", Some(&db), &r)
+            .record_gate_decision(&orch.id, &g, &db, "blocked", &r, required)
             .await
     })?;
 
@@ -312,7 +347,7 @@ pub fn gate_block(
             "{}",
             json!({
                 "ok": true,
-                "gateId": gate_id,
+                "gateId": record.id,
                 "gate": gate,
                 "status": "blocked",
             })
@@ -331,6 +366,18 @@ fn load_orchestration(feature: &str) -> anyhow::Result<convex::OrchestrationReco
         .ok_or_else(|| anyhow::anyhow!("Orchestration not found for feature: {}", feature))
 }
 
+/// The gate's quorum from the project config in the orchestration's
+/// worktree, or `None` to keep whatever the gate already requires.
+fn configured_quorum(
+    orch: &convex::OrchestrationRecord,
+    gate: &str,
+) -> anyhow::Result<Option<u32>> {
+    match orch.worktree_path.as_deref().map(Path::new) {
+        Some(root) if root.is_dir() => project_config::required_approvals(root, gate).map(Some),
+        _ => Ok(None),
+    }
+}
+
 // --- Check execution helpers ---
 
 fn execute_shell_command(command: &str, cwd: &str) -> (i32, String) {
@@ -0,0 +1,86 @@
+//! Score a phase's risk from its git range and record it on the phase.
+
+use tina_session::convex;
+use tina_session::risk::{self, RiskAssessment};
+use tina_session::state::schema::SupervisorState;
+
+pub fn run(feature: &str, phase: &str, json: bool, record: bool) -> anyhow::Result<u8> {
+    let state = SupervisorState::load(feature)?;
+    let assessment = assess_phase(&state, phase)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&assessment)?);
+    } else {
+        print_assessment(phase, &assessment);
+    }
+
+    if record {
+        record_assessment(feature, phase, &assessment)?;
+    }
+    Ok(0)
+}
+
+/// Score a phase and record it, for callers that treat risk as best effort
+/// (e.g. `orchestrate advance` on `execute_complete`).
+pub fn record_for_phase(feature: &str, state: &SupervisorState, phase: &str) -> anyhow::Result<()> {
+    let assessment = assess_phase(state, phase)?;
+    record_assessment(feature, phase, &assessment)
+}
+
+fn assess_phase(state: &SupervisorState, phase: &str) -> anyhow::Result<RiskAssessment> {
+    let phase_state = state
+        .phases
+        .get(phase)
+        .ok_or_else(|| anyhow::anyhow!("Phase {} not found for '{}'", phase, state.feature))?;
+    let range = phase_state
+        .git_range
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("Phase {} has no git range yet", phase))?;
+    risk::assess(
+        &state.worktree_path,
+        range,
+        state.scope.as_deref(),
+        state.review_policy.detector_scope,
+    )
+}
+
+fn print_assessment(phase: &str, assessment: &RiskAssessment) {
+    let signals = &assessment.signals;
+    println!(
+        "Phase {} risk: {} ({})",
+        phase,
+        assessment.score,
+        assessment.level.as_str()
+    );
+    println!(
+        "  diff:          {} lines in {} files",
+        signals.lines_changed, signals.files_changed
+    );
+    println!("  test lines:    {}", signals.test_lines);
+    println!("  out of scope:  {} files", signals.files_outside_scope);
+    println!("  new deps:      {}", signals.new_dependencies);
+    println!(
+        "  detectors:     {} errors, {} warnings",
+        signals.detector_errors, signals.detector_warnings
+    );
+}
+
+fn record_assessment(
+    feature: &str,
+    phase: &str,
+    assessment: &RiskAssessment,
+) -> anyhow::Result<()> {
+    let signals = serde_json::to_string(&assessment.signals)?;
+    let score = assessment.score as f64;
+    convex::run_convex_write(|mut writer| async move {
+        let orchestration_id = writer
+            .get_by_feature(feature)
+            .await?
+            .map(|o| o.id)
+            .ok_or_else(|| anyhow::anyhow!("No Convex orchestration found for '{}'", feature))?;
+        writer
+            .record_phase_risk(&orchestration_id, phase, score, &signals)
+            .await?;
+        Ok(())
+    })
+}
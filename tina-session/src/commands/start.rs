@@ -5,6 +5,7 @@ use std::process::Command;
 use tina_session::claude;
 use tina_session::convex;
 use tina_session::error::SessionError;
+use tina_session::project_config::ProjectLayout;
 use tina_session::session::naming::session_name;
 use tina_session::state::schema::SupervisorState;
 use tina_session::tmux;
@@ -215,7 +216,7 @@ fn materialize_plan_from_spec(
         convex::run_convex(|mut writer| async move { writer.get_spec(&spec_id_owned).await })?
             .ok_or_else(|| anyhow::anyhow!("Spec not found in Convex: {}", spec_id))?;
 
-    let plans_dir = cwd.join("docs").join("plans");
+    let plans_dir = ProjectLayout::load(cwd)?.plans_dir(cwd);
     fs::create_dir_all(&plans_dir)?;
 
     let safe_feature = feature.replace('/', "-");
@@ -1,12 +1,14 @@
 use std::path::Path;
 
-use chrono::Utc;
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
 
+use tina_session::state::history::{diff_states, state_at, tasks_at};
 use tina_session::state::schema::{OrchestrationStatus, PhaseState, PhaseStatus, SupervisorState};
 use tina_session::state::timing::duration_mins;
 use tina_session::state::transitions::validate_transition;
 
 use crate::commands::state_sync::{orchestration_args_from_state, phase_args_from_state};
+use tina_data::humanize::{format_absolute, format_duration_mins};
 use tina_session::convex;
 
 pub fn update(
@@ -311,7 +313,7 @@ pub fn show(feature: &str, phase: Option<&str>, json: bool) -> anyhow::Result<u8
                 println!("  Blocked: {}", reason);
             }
             if let Some(mins) = phase_state.duration_mins {
-                println!("  Duration: {} mins", mins);
+                println!("  Duration: {}", format_duration_mins(mins));
             }
         } else {
             println!("Phase {} (not started)", phase_key);
@@ -328,12 +330,7 @@ pub fn show(feature: &str, phase: Option<&str>, json: bool) -> anyhow::Result<u8
         for i in 1..=state.total_phases {
             let key = i.to_string();
             if let Some(ps) = state.phases.get(&key) {
-                let status_icon = match ps.status {
-                    PhaseStatus::Complete => "✓",
-                    PhaseStatus::Executing | PhaseStatus::Reviewing => "▶",
-                    PhaseStatus::Blocked => "✗",
-                    _ => "○",
-                };
+                let status_icon = phase_icon(ps.status);
                 println!("  {} Phase {}: {}", status_icon, i, ps.status);
             } else {
                 println!("  ○ Phase {}: pending", i);
@@ -343,12 +340,7 @@ pub fn show(feature: &str, phase: Option<&str>, json: bool) -> anyhow::Result<u8
         for key in state.phases.keys() {
             if key.contains('.') {
                 if let Some(ps) = state.phases.get(key) {
-                    let status_icon = match ps.status {
-                        PhaseStatus::Complete => "✓",
-                        PhaseStatus::Executing | PhaseStatus::Reviewing => "▶",
-                        PhaseStatus::Blocked => "✗",
-                        _ => "○",
-                    };
+                    let status_icon = phase_icon(ps.status);
                     println!(
                         "  {} Phase {} (remediation): {}",
                         status_icon, key, ps.status
@@ -360,3 +352,185 @@ pub fn show(feature: &str, phase: Option<&str>, json: bool) -> anyhow::Result<u8
 
     Ok(0)
 }
+
+fn phase_icon(status: PhaseStatus) -> &'static str {
+    match status {
+        PhaseStatus::Complete => "✓",
+        PhaseStatus::Executing | PhaseStatus::Reviewing => "▶",
+        PhaseStatus::Blocked => "✗",
+        _ => "○",
+    }
+}
+
+/// Orchestration events shown leading up to the requested instant.
+const RECENT_EVENT_COUNT: usize = 10;
+/// Upper bound on orchestration events fetched from Convex.
+const EVENT_FETCH_LIMIT: i64 = 1000;
+
+/// Parse an `--at` instant: RFC 3339, or a local date/time without an offset
+/// (`2025-03-01T12:00`, `2025-03-01 12:00:30`).
+fn parse_instant(input: &str) -> anyhow::Result<DateTime<Utc>> {
+    if let Ok(at) = DateTime::parse_from_rfc3339(input) {
+        return Ok(at.with_timezone(&Utc));
+    }
+    for format in [
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+    ] {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(input, format) {
+            if let Some(at) = Local.from_local_datetime(&naive).earliest() {
+                return Ok(at.with_timezone(&Utc));
+            }
+        }
+    }
+    anyhow::bail!(
+        "Invalid time '{}'. Use RFC 3339 or local time like 2025-03-01T12:00",
+        input
+    )
+}
+
+/// Show the state as it was at a past instant, and what has changed since.
+///
+/// Phases are rewound from the timestamps in local supervisor state; tasks
+/// and orchestrator events come from the Convex event log when reachable.
+pub fn show_at(feature: &str, at: &str, json: bool) -> anyhow::Result<u8> {
+    let at = parse_instant(at)?;
+    let state = SupervisorState::load(feature)?;
+    let Some(past) = state_at(&state, at) else {
+        anyhow::bail!(
+            "Orchestration '{}' had not started at {} (started {})",
+            feature,
+            at.to_rfc3339(),
+            state.orchestration_started_at.to_rfc3339()
+        );
+    };
+
+    let history = convex::run_convex(|mut writer| async move {
+        let Some(orchestration) = writer.get_by_feature(feature).await? else {
+            return Ok((Vec::new(), Vec::new()));
+        };
+        let task_events = writer.list_all_task_events(&orchestration.id).await?;
+        let events = writer
+            .list_events(&orchestration.id, EVENT_FETCH_LIMIT)
+            .await?;
+        Ok((task_events, events))
+    });
+    let (task_events, events) = match history {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!(
+                "Warning: Convex unavailable, showing phase state only: {}",
+                e
+            );
+            (Vec::new(), Vec::new())
+        }
+    };
+
+    let tasks_then = tasks_at(&task_events, at);
+    let tasks_now = tasks_at(&task_events, Utc::now());
+    let recent: Vec<_> = events
+        .iter()
+        .filter(|event| DateTime::parse_from_rfc3339(&event.recorded_at).is_ok_and(|t| t <= at))
+        .collect();
+    let recent = &recent[recent.len().saturating_sub(RECENT_EVENT_COUNT)..];
+    let changes = diff_states(&past, &state, &tasks_then, &tasks_now);
+
+    if json {
+        let output = serde_json::json!({
+            "at": at.to_rfc3339(),
+            "state": past,
+            "tasks": tasks_then,
+            "recent_events": recent,
+            "changes": changes,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(0);
+    }
+
+    println!(
+        "Orchestration: {} (as of {})",
+        past.feature,
+        format_absolute(at)
+    );
+    println!("  Status: {:?}", past.status);
+    println!("  Phase: {}/{}", past.current_phase, past.total_phases);
+    if let Some(mins) = past.timing.total_elapsed_mins {
+        println!("  Elapsed: {}", format_duration_mins(mins));
+    }
+    println!();
+    println!("Phases:");
+    let mut phase_keys: Vec<&String> = past.phases.keys().collect();
+    phase_keys.sort_by(|a, b| {
+        let num = |k: &str| k.parse::<f64>().unwrap_or(f64::MAX);
+        num(a).total_cmp(&num(b))
+    });
+    for key in phase_keys {
+        let ps = &past.phases[key];
+        println!("  {} Phase {}: {}", phase_icon(ps.status), key, ps.status);
+    }
+
+    if !tasks_then.is_empty() {
+        println!();
+        println!("Tasks:");
+        for task in &tasks_then {
+            println!(
+                "  [{}] {} #{} {}{}",
+                task.status,
+                task.phase_number.as_deref().unwrap_or("-"),
+                task.task_id,
+                task.subject,
+                task.owner
+                    .as_deref()
+                    .map(|owner| format!(" ({})", owner))
+                    .unwrap_or_default()
+            );
+        }
+    }
+
+    if !recent.is_empty() {
+        println!();
+        println!("Recent events:");
+        for event in recent {
+            println!(
+                "  {}  {}  {}",
+                event.recorded_at, event.event_type, event.summary
+            );
+        }
+    }
+
+    println!();
+    if changes.is_empty() {
+        println!("No changes since then.");
+    } else {
+        println!("Changes since then:");
+        for change in &changes {
+            println!("  {}: {} → {}", change.subject, change.then, change.now);
+        }
+    }
+
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_instant_accepts_rfc3339_and_local_times() {
+        assert_eq!(
+            parse_instant("2025-03-01T12:00:00+01:00").unwrap(),
+            Utc.with_ymd_and_hms(2025, 3, 1, 11, 0, 0).unwrap()
+        );
+
+        let local = Local
+            .with_ymd_and_hms(2025, 3, 1, 12, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(parse_instant("2025-03-01T12:00").unwrap(), local);
+        assert_eq!(parse_instant("2025-03-01 12:00:00").unwrap(), local);
+
+        assert!(parse_instant("yesterday").is_err());
+    }
+}
@@ -56,6 +56,8 @@ pub fn phase_args_from_state(phase_key: &str, phase_state: &PhaseState) -> conve
         review_mins: phase_state.breakdown.review_mins.map(|m| m as f64),
         started_at: phase_state.planning_started_at.map(|dt| dt.to_rfc3339()),
         completed_at: phase_state.completed_at.map(|dt| dt.to_rfc3339()),
+        risk_score: None,
+        risk_signals: None,
     }
 }
 
@@ -0,0 +1,98 @@
+//! Manage scoped API tokens for the daemon's HTTP API.
+
+use chrono::Utc;
+
+use tina_session::tokens::{self, TokenScope, TokenStore};
+
+pub fn create(name: Option<&str>, scope: &str, expires: &str, json: bool) -> anyhow::Result<u8> {
+    let scope = TokenScope::parse(scope)?;
+    let expires_in = tokens::parse_expiry(expires)?;
+    let path = tokens::tokens_path();
+
+    let mut store = TokenStore::load(&path)?;
+    let (token, secret) = store.create(name, scope, expires_in, Utc::now());
+    store.save(&path)?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "id": token.id,
+                "name": token.name,
+                "scope": token.scope,
+                "expiresAt": token.expires_at,
+                "token": secret,
+            }))?
+        );
+    } else {
+        println!("Created {} token {}", token.scope.as_str(), token.id);
+        match token.expires_at {
+            Some(at) => println!("Expires: {}", at.format("%Y-%m-%d %H:%M UTC")),
+            None => println!("Expires: never"),
+        }
+        println!();
+        println!("{}", secret);
+        println!();
+        println!("Store this token now; it can't be shown again.");
+    }
+    Ok(0)
+}
+
+pub fn list(json: bool) -> anyhow::Result<u8> {
+    let store = TokenStore::load(&tokens::tokens_path())?;
+
+    if json {
+        let entries: Vec<_> = store
+            .tokens
+            .iter()
+            .map(|t| {
+                serde_json::json!({
+                    "id": t.id,
+                    "name": t.name,
+                    "scope": t.scope,
+                    "createdAt": t.created_at,
+                    "expiresAt": t.expires_at,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(0);
+    }
+
+    if store.tokens.is_empty() {
+        println!("No tokens");
+        return Ok(0);
+    }
+    let now = Utc::now();
+    println!(
+        "{:<10} {:<8} {:<20} {:<18} NAME",
+        "ID", "SCOPE", "CREATED", "EXPIRES"
+    );
+    for token in &store.tokens {
+        let expires = match token.expires_at {
+            Some(_) if token.is_expired(now) => "expired".to_string(),
+            Some(at) => at.format("%Y-%m-%d %H:%M").to_string(),
+            None => "never".to_string(),
+        };
+        println!(
+            "{:<10} {:<8} {:<20} {:<18} {}",
+            token.id,
+            token.scope.as_str(),
+            token.created_at.format("%Y-%m-%d %H:%M"),
+            expires,
+            token.name.as_deref().unwrap_or("-")
+        );
+    }
+    Ok(0)
+}
+
+pub fn revoke(id: &str) -> anyhow::Result<u8> {
+    let path = tokens::tokens_path();
+    let mut store = TokenStore::load(&path)?;
+    if !store.revoke(id) {
+        anyhow::bail!("No token with id '{}'", id);
+    }
+    store.save(&path)?;
+    println!("Revoked token {}", id);
+    Ok(0)
+}
@@ -0,0 +1,451 @@
+//! Blocked-state triage: gather everything relevant to a block into one
+//! report and offer the next step.
+
+use std::collections::BTreeMap;
+use std::io::{BufRead, IsTerminal, Write};
+
+use tina_data::{ReviewCheckRecord, ReviewThreadRecord};
+use tina_session::convex::{self, EventArgs};
+use tina_session::session::naming::session_name;
+use tina_session::state::schema::{OrchestrationStatus, PhaseStatus, SupervisorState};
+use tina_session::tmux;
+
+use crate::commands::{orchestrate, stop};
+
+/// Lines of agent output captured from the blocked phase's session.
+const AGENT_OUTPUT_LINES: u32 = 40;
+/// Lines of a failing check's output shown in the report.
+const CHECK_OUTPUT_LINES: usize = 8;
+/// Upper bound on orchestration events fetched when looking for the last error.
+const EVENT_FETCH_LIMIT: i64 = 1000;
+/// `source` on events recorded by triage actions.
+const TRIAGE_SOURCE: &str = "tina-session triage";
+
+/// Next step an operator can take on a blocked phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriageAction {
+    /// Clear the block and resume the phase where it stopped.
+    Retry,
+    /// Send the phase back to its planner with the collected issues.
+    Replan,
+    /// Leave the block in place and flag it for a human.
+    Escalate,
+    /// Stop the phase session and record the abort.
+    Abort,
+}
+
+impl TriageAction {
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        match input.trim().to_lowercase().as_str() {
+            "r" | "retry" => Ok(Self::Retry),
+            "p" | "replan" => Ok(Self::Replan),
+            "e" | "escalate" => Ok(Self::Escalate),
+            "a" | "abort" => Ok(Self::Abort),
+            other => anyhow::bail!(
+                "Unknown action '{}'. Valid actions: retry, replan, escalate, abort",
+                other
+            ),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Retry => "retry",
+            Self::Replan => "replan",
+            Self::Escalate => "escalate",
+            Self::Abort => "abort",
+        }
+    }
+}
+
+/// Everything known about why a phase is blocked.
+#[derive(Debug, Default)]
+struct TriageReport {
+    phase: String,
+    reason: Option<String>,
+    review_issues: Vec<String>,
+    last_error: Option<EventArgs>,
+    failing_checks: Vec<ReviewCheckRecord>,
+    open_findings: Vec<ReviewThreadRecord>,
+    agent_output: Option<String>,
+}
+
+impl TriageReport {
+    /// Issues to hand the planner on a replan. Commas are replaced since
+    /// `review_gaps` issues are comma-separated.
+    fn issues(&self) -> Vec<String> {
+        let mut issues = Vec::new();
+        issues.extend(self.reason.clone());
+        issues.extend(self.review_issues.iter().cloned());
+        issues.extend(
+            self.failing_checks
+                .iter()
+                .map(|check| format!("check '{}' failed", check.name)),
+        );
+        issues.extend(
+            self.open_findings.iter().map(|finding| {
+                format!("{}:{} {}", finding.file_path, finding.line, finding.summary)
+            }),
+        );
+        issues.into_iter().map(|i| i.replace(',', ";")).collect()
+    }
+}
+
+/// The most recent run of each check, keeping only those that failed.
+fn failing_checks(checks: Vec<ReviewCheckRecord>) -> Vec<ReviewCheckRecord> {
+    let mut latest: BTreeMap<String, ReviewCheckRecord> = BTreeMap::new();
+    for check in checks {
+        let newer = latest
+            .get(&check.name)
+            .is_none_or(|seen| check.started_at >= seen.started_at);
+        if newer {
+            latest.insert(check.name.clone(), check);
+        }
+    }
+    latest
+        .into_values()
+        .filter(|check| check.status == "failed")
+        .collect()
+}
+
+/// Recommend a next step from what the report found.
+fn suggest_action(report: &TriageReport) -> TriageAction {
+    let reason = report.reason.as_deref().unwrap_or_default();
+    if reason.contains("consensus disagreement") {
+        // The state machine refuses to retry these; a human has to decide.
+        TriageAction::Escalate
+    } else if !report.failing_checks.is_empty()
+        || !report.open_findings.is_empty()
+        || !report.review_issues.is_empty()
+    {
+        TriageAction::Replan
+    } else {
+        TriageAction::Retry
+    }
+}
+
+/// The blocked phase, falling back to the current phase.
+fn blocked_phase(state: &SupervisorState) -> String {
+    let mut blocked: Vec<&String> = state
+        .phases
+        .iter()
+        .filter(|(_, phase)| phase.status == PhaseStatus::Blocked)
+        .map(|(key, _)| key)
+        .collect();
+    blocked.sort();
+    blocked
+        .first()
+        .map(|key| key.to_string())
+        .unwrap_or_else(|| state.current_phase.to_string())
+}
+
+fn gather(feature: &str, state: &SupervisorState) -> TriageReport {
+    let phase = blocked_phase(state);
+    let phase_state = state.phases.get(&phase);
+    let mut report = TriageReport {
+        reason: phase_state.and_then(|p| p.blocked_reason.clone()),
+        review_issues: phase_state
+            .map(|p| {
+                p.review_verdicts
+                    .iter()
+                    .flat_map(|v| v.issues.iter().cloned())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        phase,
+        ..TriageReport::default()
+    };
+
+    let session = session_name(feature, &report.phase);
+    if tmux::session_exists(&session) {
+        report.agent_output = tmux::capture_pane_lines(&session, AGENT_OUTPUT_LINES).ok();
+    }
+
+    let phase = report.phase.clone();
+    let remote = convex::run_convex(|mut writer| async move {
+        let Some(orchestration) = writer.get_by_feature(feature).await? else {
+            return Ok(None);
+        };
+        let events = writer
+            .list_events(&orchestration.id, EVENT_FETCH_LIMIT)
+            .await?;
+        let checks = writer.list_review_checks(&orchestration.id).await?;
+        let findings = writer.list_review_threads(&orchestration.id).await?;
+        Ok(Some((events, checks, findings)))
+    });
+    match remote {
+        Ok(Some((events, checks, findings))) => {
+            report.last_error = events
+                .into_iter()
+                .filter(|e| e.event_type == "error" && e.phase_number.as_deref() == Some(&phase))
+                .max_by(|a, b| a.recorded_at.cmp(&b.recorded_at));
+            report.failing_checks = failing_checks(checks);
+            report.open_findings = findings
+                .into_iter()
+                .filter(|f| f.status != "resolved")
+                .collect();
+        }
+        Ok(None) => {}
+        Err(e) => eprintln!("Warning: Convex unavailable, report is local-only: {}", e),
+    }
+    report
+}
+
+fn print_report(feature: &str, state: &SupervisorState, report: &TriageReport) {
+    println!("Triage: {} (phase {})", feature, report.phase);
+    println!("  Status: {:?}", state.status);
+    println!(
+        "  Reason: {}",
+        report.reason.as_deref().unwrap_or("(none recorded)")
+    );
+    if let Some(event) = &report.last_error {
+        println!("  Last error: {} ({})", event.summary, event.recorded_at);
+    }
+
+    if !report.review_issues.is_empty() {
+        println!();
+        println!("Review issues:");
+        for issue in &report.review_issues {
+            println!("  - {}", issue);
+        }
+    }
+
+    println!();
+    if report.failing_checks.is_empty() {
+        println!("Failing checks: none");
+    } else {
+        println!("Failing checks:");
+        for check in &report.failing_checks {
+            println!(
+                "  ✗ {}{}",
+                check.name,
+                check
+                    .comment
+                    .as_deref()
+                    .map(|c| format!(" - {}", c))
+                    .unwrap_or_default()
+            );
+            if let Some(output) = &check.output {
+                for line in output.lines().take(CHECK_OUTPUT_LINES) {
+                    println!("      {}", line);
+                }
+            }
+        }
+    }
+
+    println!();
+    if report.open_findings.is_empty() {
+        println!("Open findings: none");
+    } else {
+        println!("Open findings:");
+        for finding in &report.open_findings {
+            println!(
+                "  [{}] {}:{} {}",
+                finding.severity, finding.file_path, finding.line, finding.summary
+            );
+        }
+    }
+
+    println!();
+    match &report.agent_output {
+        Some(output) => {
+            println!("Last agent output:");
+            for line in output.trim_end().lines() {
+                println!("  │ {}", line);
+            }
+        }
+        None => println!("Last agent output: (no live session)"),
+    }
+}
+
+fn prompt_action(suggested: TriageAction) -> anyhow::Result<Option<TriageAction>> {
+    loop {
+        print!(
+            "\nNext step? [r]etry re[p]lan [e]scalate [a]bort [q]uit (default: {}): ",
+            suggested.name()
+        );
+        std::io::stdout().flush()?;
+        let mut line = String::new();
+        if std::io::stdin().lock().read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        match line.trim() {
+            "" => return Ok(Some(suggested)),
+            "q" | "quit" => return Ok(None),
+            input => match TriageAction::parse(input) {
+                Ok(action) => return Ok(Some(action)),
+                Err(e) => eprintln!("{}", e),
+            },
+        }
+    }
+}
+
+fn record_event(feature: &str, phase: &str, event_type: &str, summary: String, detail: String) {
+    let result = convex::run_convex_write(|mut writer| async move {
+        let orchestration_id = writer
+            .get_by_feature(feature)
+            .await?
+            .map(|o| o.id)
+            .ok_or_else(|| anyhow::anyhow!("No Convex orchestration found for '{}'", feature))?;
+        writer
+            .record_event(&EventArgs {
+                orchestration_id,
+                phase_number: Some(phase.to_string()),
+                event_type: event_type.to_string(),
+                source: TRIAGE_SOURCE.to_string(),
+                summary,
+                detail: Some(detail),
+                recorded_at: chrono::Utc::now().to_rfc3339(),
+            })
+            .await?;
+        Ok(())
+    });
+    if let Err(e) = result {
+        eprintln!("Warning: Failed to record {} event: {}", event_type, e);
+    }
+}
+
+fn apply(feature: &str, report: &TriageReport, action: TriageAction) -> anyhow::Result<u8> {
+    let phase = report.phase.as_str();
+    let detail = serde_json::json!({
+        "reason": report.reason,
+        "issues": report.issues(),
+    })
+    .to_string();
+    match action {
+        TriageAction::Retry => orchestrate::advance(
+            feature,
+            phase,
+            "retry",
+            None,
+            None,
+            Some("operator retry from triage"),
+        ),
+        TriageAction::Replan => {
+            let issues = report.issues().join(",");
+            orchestrate::advance(feature, phase, "review_gaps", None, None, Some(&issues))
+        }
+        TriageAction::Escalate => {
+            record_event(
+                feature,
+                phase,
+                "escalation",
+                format!("Phase {} block escalated for human review", phase),
+                detail,
+            );
+            println!("Escalated phase {}; the block stays in place.", phase);
+            Ok(0)
+        }
+        TriageAction::Abort => {
+            stop::run(feature, phase)?;
+            record_event(
+                feature,
+                phase,
+                "aborted",
+                format!("Phase {} aborted from triage", phase),
+                detail,
+            );
+            Ok(0)
+        }
+    }
+}
+
+pub fn run(feature: &str, action: Option<&str>) -> anyhow::Result<u8> {
+    let state = SupervisorState::load(feature)?;
+    let report = gather(feature, &state);
+    print_report(feature, &state, &report);
+
+    if state.status != OrchestrationStatus::Blocked {
+        println!();
+        println!("'{}' is not blocked; nothing to do.", feature);
+        return Ok(0);
+    }
+
+    let suggested = suggest_action(&report);
+    let action = match action {
+        Some(action) => TriageAction::parse(action)?,
+        None if std::io::stdin().is_terminal() => match prompt_action(suggested)? {
+            Some(action) => action,
+            None => return Ok(0),
+        },
+        None => {
+            println!();
+            println!(
+                "Suggested: tina-session triage --feature {} --action {}",
+                feature,
+                suggested.name()
+            );
+            return Ok(0);
+        }
+    };
+    apply(feature, &report, action)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(name: &str, status: &str, started_at: &str) -> ReviewCheckRecord {
+        ReviewCheckRecord {
+            id: format!("{}-{}", name, started_at),
+            review_id: "review-1".to_string(),
+            orchestration_id: "orch-1".to_string(),
+            name: name.to_string(),
+            kind: "cli".to_string(),
+            command: None,
+            status: status.to_string(),
+            comment: None,
+            output: None,
+            started_at: started_at.to_string(),
+            completed_at: None,
+        }
+    }
+
+    #[test]
+    fn failing_checks_uses_latest_run_of_each_check() {
+        let checks = vec![
+            check("test", "failed", "2026-02-13T10:00:00Z"),
+            check("test", "passed", "2026-02-13T11:00:00Z"),
+            check("clippy", "passed", "2026-02-13T10:00:00Z"),
+            check("clippy", "failed", "2026-02-13T11:00:00Z"),
+        ];
+        let failing = failing_checks(checks);
+        assert_eq!(failing.len(), 1);
+        assert_eq!(failing[0].name, "clippy");
+    }
+
+    #[test]
+    fn suggest_action_follows_what_was_found() {
+        let mut report = TriageReport {
+            reason: Some("executor crashed".to_string()),
+            ..TriageReport::default()
+        };
+        assert_eq!(suggest_action(&report), TriageAction::Retry);
+
+        report.failing_checks = vec![check("test", "failed", "2026-02-13T10:00:00Z")];
+        assert_eq!(suggest_action(&report), TriageAction::Replan);
+
+        report.reason = Some("review consensus disagreement".to_string());
+        assert_eq!(suggest_action(&report), TriageAction::Escalate);
+    }
+
+    #[test]
+    fn issues_are_safe_to_pass_as_review_gaps() {
+        let report = TriageReport {
+            reason: Some("tests failed: a, b".to_string()),
+            failing_checks: vec![check("test", "failed", "2026-02-13T10:00:00Z")],
+            ..TriageReport::default()
+        };
+        assert_eq!(
+            report.issues(),
+            vec!["tests failed: a; b", "check 'test' failed"]
+        );
+    }
+
+    #[test]
+    fn parse_action_accepts_names_and_shortcuts() {
+        assert_eq!(TriageAction::parse("p").unwrap(), TriageAction::Replan);
+        assert_eq!(TriageAction::parse("Abort").unwrap(), TriageAction::Abort);
+        assert!(TriageAction::parse("restart").is_err());
+    }
+}
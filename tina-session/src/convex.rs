@@ -8,6 +8,8 @@ pub type PhaseArgs = tina_data::PhaseRecord;
 pub type EventArgs = tina_data::OrchestrationEventRecord;
 pub type UpsertTeamMemberArgs = tina_data::TeamMemberRecord;
 pub type RegisterTeamArgs = tina_data::RegisterTeamRecord;
+pub type CommitArgs = tina_data::CommitRecord;
+pub type PlanArgs = tina_data::PlanRecord;
 
 pub use tina_data::{CommentRecord, SpecRecord, TicketRecord};
 
@@ -101,11 +103,34 @@ impl ConvexWriter {
         self.client.upsert_phase(phase).await
     }
 
+    /// Attach a risk score and its JSON signals to a phase.
+    pub async fn record_phase_risk(
+        &mut self,
+        orchestration_id: &str,
+        phase_number: &str,
+        risk_score: f64,
+        risk_signals: &str,
+    ) -> anyhow::Result<String> {
+        self.client
+            .record_phase_risk(orchestration_id, phase_number, risk_score, risk_signals)
+            .await
+    }
+
     /// Record an orchestration event.
     pub async fn record_event(&mut self, event: &EventArgs) -> anyhow::Result<String> {
         self.client.record_event(event).await
     }
 
+    /// Record a git commit (no-op if the SHA is already recorded).
+    pub async fn record_commit(&mut self, commit: &CommitArgs) -> anyhow::Result<String> {
+        self.client.record_commit(commit).await
+    }
+
+    /// Upsert a phase plan.
+    pub async fn upsert_plan(&mut self, plan: &PlanArgs) -> anyhow::Result<String> {
+        self.client.upsert_plan(plan).await
+    }
+
     /// Upsert supervisor state JSON for this node/feature pair.
     pub async fn upsert_supervisor_state(
         &mut self,
@@ -180,6 +205,68 @@ impl ConvexWriter {
             .await
     }
 
+    /// List an orchestration's recorded events, oldest first (up to `limit`).
+    pub async fn list_events(
+        &mut self,
+        orchestration_id: &str,
+        limit: i64,
+    ) -> anyhow::Result<Vec<EventArgs>> {
+        self.client
+            .list_events(orchestration_id, None, None, Some(limit))
+            .await
+    }
+
+    /// Orchestration detail: phase documents, current tasks, team members.
+    pub async fn get_orchestration_detail(
+        &mut self,
+        orchestration_id: &str,
+    ) -> anyhow::Result<Option<tina_data::OrchestrationDetailResponse>> {
+        self.client.get_orchestration_detail(orchestration_id).await
+    }
+
+    /// Review findings recorded against an orchestration.
+    pub async fn list_review_threads(
+        &mut self,
+        orchestration_id: &str,
+    ) -> anyhow::Result<Vec<tina_data::ReviewThreadRecord>> {
+        self.client.list_review_threads(orchestration_id).await
+    }
+
+    /// Review check runs recorded against an orchestration.
+    pub async fn list_review_checks(
+        &mut self,
+        orchestration_id: &str,
+    ) -> anyhow::Result<Vec<tina_data::ReviewCheckRecord>> {
+        self.client.list_review_checks(orchestration_id).await
+    }
+
+    /// Every recorded event for every task in an orchestration.
+    pub async fn list_all_task_events(
+        &mut self,
+        orchestration_id: &str,
+    ) -> anyhow::Result<Vec<tina_data::TaskEventRecord>> {
+        let Some(detail) = self
+            .client
+            .get_orchestration_detail(orchestration_id)
+            .await?
+        else {
+            return Ok(Vec::new());
+        };
+        let mut task_ids: Vec<String> = detail.tasks.into_iter().map(|t| t.task_id).collect();
+        task_ids.sort();
+        task_ids.dedup();
+
+        let mut events = Vec::new();
+        for task_id in task_ids {
+            events.extend(
+                self.client
+                    .list_task_events(orchestration_id, &task_id)
+                    .await?,
+            );
+        }
+        Ok(events)
+    }
+
     /// List all orchestrations.
     pub async fn list_orchestrations(&mut self) -> anyhow::Result<Vec<OrchestrationRecord>> {
         let entries = self.client.list_orchestrations().await?;
@@ -476,6 +563,28 @@ impl ConvexWriter {
             )
             .await
     }
+
+    /// Record one approver's decision on a gate.
+    pub async fn record_gate_decision(
+        &mut self,
+        orchestration_id: &str,
+        gate_id: &str,
+        approver: &str,
+        decision: &str,
+        summary: &str,
+        required_approvals: Option<u32>,
+    ) -> anyhow::Result<tina_data::ReviewGateRecord> {
+        self.client
+            .record_gate_decision(
+                orchestration_id,
+                gate_id,
+                approver,
+                decision,
+                summary,
+                required_approvals,
+            )
+            .await
+    }
 }
 
 fn convert_list_entry(entry: tina_data::OrchestrationListEntry) -> OrchestrationRecord {
@@ -0,0 +1,433 @@
+//! External detector plugins.
+//!
+//! Teams add org-specific detectors without forking tina-session by listing
+//! executables in `<project>/.tina/config.toml`:
+//!
+//! ```toml
+//! [[detectors]]
+//! name = "no-raw-sql"
+//! command = "scripts/detect-raw-sql"
+//! args = ["--strict"]
+//! timeout_secs = 30
+//! ```
+//!
+//! Each detector is spawned in the worktree and fed a [`DetectorInput`] as
+//! JSON on stdin: the files changed since the base ref, with their patches.
+//! It must print a [`DetectorOutput`] as JSON on stdout and exit 0; a
+//! non-zero exit or unparseable output counts as a detector failure.
+//!
+//! ```json
+//! {"findings": [{"rule": "raw-sql", "severity": "error",
+//!   "file": "src/db.rs", "line": 42, "message": "use the query builder"}]}
+//! ```
+
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ignore::IgnoreRules;
+use crate::project_config::PROJECT_CONFIG_PATH;
+use crate::state::schema::DetectorScope;
+
+/// Version of the stdin/stdout JSON protocol.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+const DEFAULT_TIMEOUT_SECS: u64 = 60;
+
+fn default_timeout_secs() -> u64 {
+    DEFAULT_TIMEOUT_SECS
+}
+
+/// One `[[detectors]]` entry.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DetectorConfig {
+    pub name: String,
+    /// Executable; paths containing `/` are relative to the worktree.
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DetectorsFile {
+    #[serde(default)]
+    detectors: Vec<DetectorConfig>,
+}
+
+/// Detectors configured for the project rooted at `root` (none when there
+/// is no project config).
+pub fn load_detectors(root: &Path) -> anyhow::Result<Vec<DetectorConfig>> {
+    let path = root.join(PROJECT_CONFIG_PATH);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents = fs::read_to_string(&path)?;
+    let file: DetectorsFile = toml::from_str(&contents)
+        .map_err(|e| anyhow::anyhow!("Invalid project config {}: {}", path.display(), e))?;
+    Ok(file.detectors)
+}
+
+/// A file changed since the base ref.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChangedFile {
+    pub path: String,
+    /// Single-letter git status (`A`, `M`, `D`, `R`, ...).
+    pub status: String,
+    /// Unified diff for this file.
+    pub patch: String,
+}
+
+/// JSON written to a detector's stdin.
+#[derive(Debug, Clone, Serialize)]
+pub struct DetectorInput {
+    pub version: u32,
+    pub worktree: PathBuf,
+    pub base: String,
+    /// Monorepo subtree the orchestration is limited to, if any.
+    pub scope: Option<PathBuf>,
+    /// Comparison scope from the orchestration's review policy.
+    pub detector_scope: DetectorScope,
+    pub files: Vec<ChangedFile>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+    Info,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Info => "info",
+        }
+    }
+}
+
+/// One problem reported by a detector.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Finding {
+    #[serde(default)]
+    pub rule: Option<String>,
+    #[serde(default)]
+    pub severity: Severity,
+    #[serde(default)]
+    pub file: Option<String>,
+    #[serde(default)]
+    pub line: Option<u32>,
+    pub message: String,
+}
+
+/// JSON a detector prints on stdout.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DetectorOutput {
+    #[serde(default)]
+    pub findings: Vec<Finding>,
+}
+
+/// Build the detector input for changes in `worktree` since `base`,
+/// limited to `scope` and skipping files in `.tina/ignore`.
+pub fn collect_input(
+    worktree: &Path,
+    base: &str,
+    scope: Option<&Path>,
+    detector_scope: DetectorScope,
+) -> anyhow::Result<DetectorInput> {
+    let range = format!("{}...HEAD", base);
+    let mut args = vec![
+        "diff".to_string(),
+        "--name-status".to_string(),
+        "--find-renames".to_string(),
+        range.clone(),
+    ];
+    if let Some(scope) = scope {
+        args.push("--".to_string());
+        args.push(scope.to_string_lossy().into_owned());
+    }
+    let name_status = git(worktree, &args)?;
+
+    let ignore = IgnoreRules::load(worktree);
+    let mut files = Vec::new();
+    for line in name_status.lines() {
+        let mut parts = line.split('\t');
+        let (Some(status), Some(first)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        // Renames list old and new paths; report the new one
+        let path = parts.next().unwrap_or(first);
+        if ignore.is_ignored(Path::new(path), false) {
+            continue;
+        }
+        let patch = git(
+            worktree,
+            &[
+                "diff".to_string(),
+                range.clone(),
+                "--".to_string(),
+                path.to_string(),
+            ],
+        )?;
+        files.push(ChangedFile {
+            path: path.to_string(),
+            status: status.chars().take(1).collect(),
+            patch,
+        });
+    }
+
+    Ok(DetectorInput {
+        version: PROTOCOL_VERSION,
+        worktree: worktree.to_path_buf(),
+        base: base.to_string(),
+        scope: scope.map(Path::to_path_buf),
+        detector_scope,
+        files,
+    })
+}
+
+fn git(cwd: &Path, args: &[String]) -> anyhow::Result<String> {
+    let output = Command::new("git").current_dir(cwd).args(args).output()?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Run one detector against `input` and return its findings.
+pub fn run_detector(
+    detector: &DetectorConfig,
+    worktree: &Path,
+    input: &DetectorInput,
+) -> anyhow::Result<Vec<Finding>> {
+    let program = if detector.command.contains('/') {
+        worktree.join(&detector.command)
+    } else {
+        PathBuf::from(&detector.command)
+    };
+    let mut child = Command::new(&program)
+        .args(&detector.args)
+        .current_dir(worktree)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| anyhow::anyhow!("failed to start {}: {}", program.display(), e))?;
+
+    // Feed stdin and drain stdout/stderr on threads so a detector that
+    // writes before reading everything can't deadlock us
+    let payload = serde_json::to_vec(input)?;
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let writer = thread::spawn(move || {
+        let _ = stdin.write_all(&payload);
+    });
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let reader = thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = stdout.read_to_end(&mut buf);
+        buf
+    });
+    let mut stderr = child.stderr.take().expect("stderr is piped");
+    let err_reader = thread::spawn(move || {
+        let mut buf = String::new();
+        let _ = stderr.read_to_string(&mut buf);
+        buf
+    });
+
+    let deadline = Instant::now() + Duration::from_secs(detector.timeout_secs);
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            anyhow::bail!("timed out after {}s", detector.timeout_secs);
+        }
+        thread::sleep(Duration::from_millis(20));
+    };
+
+    let _ = writer.join();
+    let stdout = reader.join().unwrap_or_default();
+    let stderr = err_reader.join().unwrap_or_default();
+    if !status.success() {
+        anyhow::bail!("exited with {}: {}", status, stderr.trim());
+    }
+
+    let output: DetectorOutput =
+        serde_json::from_slice(&stdout).map_err(|e| anyhow::anyhow!("invalid output: {}", e))?;
+    Ok(output.findings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_config(root: &Path, contents: &str) {
+        let path = root.join(PROJECT_CONFIG_PATH);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    #[cfg(unix)]
+    fn write_script(root: &Path, name: &str, body: &str) {
+        use std::os::unix::fs::PermissionsExt;
+        let path = root.join(name);
+        fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    fn input(worktree: &Path) -> DetectorInput {
+        DetectorInput {
+            version: PROTOCOL_VERSION,
+            worktree: worktree.to_path_buf(),
+            base: "main".to_string(),
+            scope: None,
+            detector_scope: DetectorScope::default(),
+            files: vec![ChangedFile {
+                path: "src/db.rs".to_string(),
+                status: "M".to_string(),
+                patch: "+query(\"SELECT 1\")\n".to_string(),
+            }],
+        }
+    }
+
+    fn detector(command: &str) -> DetectorConfig {
+        DetectorConfig {
+            name: "test".to_string(),
+            command: command.to_string(),
+            args: Vec::new(),
+            timeout_secs: 5,
+        }
+    }
+
+    #[test]
+    fn test_load_detectors() {
+        let temp = TempDir::new().unwrap();
+        assert!(load_detectors(temp.path()).unwrap().is_empty());
+
+        write_config(
+            temp.path(),
+            r#"
+[layout]
+plans_dir = "plans"
+
+[[detectors]]
+name = "raw-sql"
+command = "scripts/raw-sql"
+args = ["--strict"]
+"#,
+        );
+        let detectors = load_detectors(temp.path()).unwrap();
+        assert_eq!(detectors.len(), 1);
+        assert_eq!(detectors[0].name, "raw-sql");
+        assert_eq!(detectors[0].args, vec!["--strict"]);
+        assert_eq!(detectors[0].timeout_secs, DEFAULT_TIMEOUT_SECS);
+    }
+
+    #[test]
+    fn test_finding_defaults_to_error_severity() {
+        let output: DetectorOutput =
+            serde_json::from_str(r#"{"findings": [{"message": "bad"}]}"#).unwrap();
+        assert_eq!(output.findings[0].severity, Severity::Error);
+        assert_eq!(output.findings[0].file, None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_detector_reads_stdin_and_parses_findings() {
+        let temp = TempDir::new().unwrap();
+        // Echo the first changed path back as a finding
+        write_script(
+            temp.path(),
+            "detect",
+            r#"path=$(sed 's/.*"path":"\([^"]*\)".*/\1/')
+printf '{"findings":[{"rule":"r","severity":"warning","file":"%s","line":3,"message":"m"}]}' "$path""#,
+        );
+
+        let findings =
+            run_detector(&detector("./detect"), temp.path(), &input(temp.path())).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].file.as_deref(), Some("src/db.rs"));
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert_eq!(findings[0].line, Some(3));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_detector_reports_failures() {
+        let temp = TempDir::new().unwrap();
+        write_script(temp.path(), "fails", "echo boom >&2; exit 3");
+        write_script(temp.path(), "garbage", "cat >/dev/null; echo not json");
+        write_script(temp.path(), "slow", "sleep 5");
+
+        let err = run_detector(&detector("./fails"), temp.path(), &input(temp.path()))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("boom"), "{}", err);
+        assert!(run_detector(&detector("./garbage"), temp.path(), &input(temp.path())).is_err());
+
+        let mut slow = detector("./slow");
+        slow.timeout_secs = 0;
+        let err = run_detector(&slow, temp.path(), &input(temp.path()))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("timed out"), "{}", err);
+        assert!(run_detector(
+            &detector("missing-detector-binary"),
+            temp.path(),
+            &input(temp.path())
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_collect_input_lists_changed_files() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        let run = |args: &[&str]| {
+            let status = Command::new("git")
+                .current_dir(dir)
+                .args(args)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?}", args);
+        };
+        run(&["init", "-q", "-b", "main"]);
+        run(&["config", "user.email", "t@example.com"]);
+        run(&["config", "user.name", "t"]);
+        fs::write(dir.join("a.rs"), "fn a() {}\n").unwrap();
+        run(&["add", "."]);
+        run(&["commit", "-q", "-m", "base"]);
+        run(&["checkout", "-q", "-b", "feature"]);
+        fs::write(dir.join("a.rs"), "fn a() { todo!() }\n").unwrap();
+        fs::write(dir.join("gen.pb.rs"), "// generated\n").unwrap();
+        fs::create_dir_all(dir.join(".tina")).unwrap();
+        fs::write(dir.join(".tina/ignore"), "*.pb.rs\n").unwrap();
+        run(&["add", "."]);
+        run(&["commit", "-q", "-m", "change"]);
+
+        let input = collect_input(dir, "main", None, DetectorScope::TouchedAreaOnly).unwrap();
+        let paths: Vec<&str> = input.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec![".tina/ignore", "a.rs"]);
+        let a = &input.files[1];
+        assert_eq!(a.status, "M");
+        assert!(a.patch.contains("+fn a() { todo!() }"));
+    }
+}
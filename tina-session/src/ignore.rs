@@ -0,0 +1,311 @@
+//! Per-project ignore patterns.
+//!
+//! Generated code (protobufs, snapshots, lockfiles) shouldn't count against
+//! complexity budgets or clutter review diffs. Projects list such paths in
+//! `<project>/.tina/ignore` using gitignore syntax:
+//!
+//! ```text
+//! # generated
+//! *.pb.go
+//! /web/src/__generated__/
+//! **/__snapshots__/
+//! !keep.pb.go
+//! ```
+//!
+//! Supported: comments, `!` negation, trailing `/` for directories, leading
+//! or inner `/` to anchor at the project root, and `*`, `?`, `**`, `[...]`
+//! wildcards. As in git, the last matching pattern wins and files inside an
+//! ignored directory can't be re-included.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Location of the ignore file, relative to the project root.
+pub const IGNORE_FILE: &str = ".tina/ignore";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Pattern {
+    glob: Vec<char>,
+    negate: bool,
+    dir_only: bool,
+    anchored: bool,
+}
+
+impl Pattern {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let (negate, rest) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line.strip_prefix('\\').unwrap_or(line)),
+        };
+        let (dir_only, rest) = match rest.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, rest),
+        };
+        let anchored = rest.contains('/');
+        let rest = rest.strip_prefix('/').unwrap_or(rest);
+        if rest.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            glob: rest.chars().collect(),
+            negate,
+            dir_only,
+            anchored,
+        })
+    }
+
+    fn matches(&self, path: &str, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+        let candidate = if self.anchored {
+            path
+        } else {
+            path.rsplit('/').next().unwrap_or(path)
+        };
+        let text: Vec<char> = candidate.chars().collect();
+        glob_match(&self.glob, &text)
+    }
+}
+
+/// Ignore patterns for one project.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IgnoreRules {
+    root: PathBuf,
+    patterns: Vec<Pattern>,
+}
+
+impl IgnoreRules {
+    /// Load `.tina/ignore` for the project rooted at `root`.
+    ///
+    /// A missing or unreadable file means nothing is ignored.
+    pub fn load(root: &Path) -> Self {
+        let contents = fs::read_to_string(root.join(IGNORE_FILE)).unwrap_or_default();
+        Self::parse(root, &contents)
+    }
+
+    /// Parse gitignore-syntax `contents` for the project rooted at `root`.
+    pub fn parse(root: &Path, contents: &str) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            patterns: contents.lines().filter_map(Pattern::parse).collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Non-negated patterns as written, for tools (like tokei) that take
+    /// their own exclude globs.
+    pub fn exclude_globs(&self) -> Vec<String> {
+        self.patterns
+            .iter()
+            .filter(|p| !p.negate)
+            .map(|p| {
+                let glob: String = p.glob.iter().collect();
+                if p.anchored {
+                    format!("/{}", glob)
+                } else {
+                    glob
+                }
+            })
+            .collect()
+    }
+
+    /// Whether `path` is ignored. `path` may be absolute (under the project
+    /// root) or relative to it; absolute paths outside the root are never
+    /// ignored.
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        if self.patterns.is_empty() {
+            return false;
+        }
+        let relative = if path.is_absolute() {
+            match path.strip_prefix(&self.root) {
+                Ok(relative) => relative,
+                Err(_) => return false,
+            }
+        } else {
+            path
+        };
+
+        let components: Vec<String> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        if components.is_empty() {
+            return false;
+        }
+
+        // An ignored parent directory excludes everything below it
+        let mut prefix = String::new();
+        for (i, component) in components.iter().enumerate() {
+            if i > 0 {
+                prefix.push('/');
+            }
+            prefix.push_str(component);
+            let last = i + 1 == components.len();
+            if self.last_match(&prefix, !last || is_dir) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn last_match(&self, path: &str, is_dir: bool) -> bool {
+        self.patterns
+            .iter()
+            .rev()
+            .find(|p| p.matches(path, is_dir))
+            .is_some_and(|p| !p.negate)
+    }
+}
+
+/// Match gitignore-style wildcards: `*` and `?` stop at `/`, `**` doesn't.
+fn glob_match(pattern: &[char], text: &[char]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some('*') if pattern.get(1) == Some(&'*') => {
+            let rest = &pattern[2..];
+            // `**/` also matches zero directories
+            if rest.first() == Some(&'/') && glob_match(&rest[1..], text) {
+                return true;
+            }
+            (0..=text.len()).any(|i| glob_match(rest, &text[i..]))
+        }
+        Some('*') => {
+            let rest = &pattern[1..];
+            for i in 0..=text.len() {
+                if glob_match(rest, &text[i..]) {
+                    return true;
+                }
+                if text.get(i) == Some(&'/') {
+                    break;
+                }
+            }
+            false
+        }
+        Some('?') => {
+            matches!(text.first(), Some(c) if *c != '/') && glob_match(&pattern[1..], &text[1..])
+        }
+        Some('[') => match (class_match(&pattern[1..], text.first()), text.first()) {
+            (Some((true, len)), Some(_)) => glob_match(&pattern[1 + len..], &text[1..]),
+            (Some(_), _) => false,
+            // Unterminated class: treat `[` literally
+            (None, Some('[')) => glob_match(&pattern[1..], &text[1..]),
+            (None, _) => false,
+        },
+        Some('\\') if pattern.len() > 1 => {
+            text.first() == Some(&pattern[1]) && glob_match(&pattern[2..], &text[1..])
+        }
+        Some(c) => text.first() == Some(c) && glob_match(&pattern[1..], &text[1..]),
+    }
+}
+
+/// Match a `[...]` class body (after the `[`) against `c`. Returns whether
+/// it matched and how many pattern chars the class used, including `]`.
+fn class_match(body: &[char], c: Option<&char>) -> Option<(bool, usize)> {
+    let negated = matches!(body.first(), Some('!') | Some('^'));
+    let start = usize::from(negated);
+    // A `]` right after the opening bracket is literal
+    let close = body
+        .iter()
+        .enumerate()
+        .skip(start + 1)
+        .find(|(_, ch)| **ch == ']')
+        .map(|(i, _)| i)?;
+
+    let c = match c {
+        Some(c) if *c != '/' => *c,
+        _ => return Some((false, close + 1)),
+    };
+    let items = &body[start..close];
+    let mut matched = false;
+    let mut i = 0;
+    while i < items.len() {
+        if i + 2 < items.len() && items[i + 1] == '-' {
+            matched |= (items[i]..=items[i + 2]).contains(&c);
+            i += 3;
+        } else {
+            matched |= items[i] == c;
+            i += 1;
+        }
+    }
+    Some((matched != negated, close + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn parse(contents: &str) -> IgnoreRules {
+        IgnoreRules::parse(Path::new("/repo"), contents)
+    }
+
+    #[test]
+    fn test_unanchored_patterns_match_at_any_depth() {
+        let rules = parse("*.pb.go\n# comment\n\nsnapshots/\n");
+        assert!(rules.is_ignored(Path::new("api/v1/user.pb.go"), false));
+        assert!(rules.is_ignored(Path::new("user.pb.go"), false));
+        assert!(!rules.is_ignored(Path::new("api/user.go"), false));
+        assert!(rules.is_ignored(Path::new("src/snapshots/a.snap"), false));
+        assert!(!rules.is_ignored(Path::new("src/snapshots"), false));
+    }
+
+    #[test]
+    fn test_anchored_patterns_match_from_root() {
+        let rules = parse("/gen\nweb/src/**/*.ts\n");
+        assert!(rules.is_ignored(Path::new("gen/a.rs"), false));
+        assert!(!rules.is_ignored(Path::new("lib/gen/a.rs"), false));
+        assert!(rules.is_ignored(Path::new("web/src/a/b/c.ts"), false));
+        assert!(rules.is_ignored(Path::new("web/src/c.ts"), false));
+        assert!(!rules.is_ignored(Path::new("web/c.ts"), false));
+    }
+
+    #[test]
+    fn test_negation_and_last_match_wins() {
+        let rules = parse("*.snap\n!keep.snap\n");
+        assert!(rules.is_ignored(Path::new("a.snap"), false));
+        assert!(!rules.is_ignored(Path::new("keep.snap"), false));
+
+        // Files inside an ignored directory can't be re-included
+        let rules = parse("gen/\n!gen/keep.rs\n");
+        assert!(rules.is_ignored(Path::new("gen/keep.rs"), false));
+    }
+
+    #[test]
+    fn test_wildcards_and_classes() {
+        let rules = parse("file?.[ch]\n[!a]*.log\n");
+        assert!(rules.is_ignored(Path::new("file1.c"), false));
+        assert!(rules.is_ignored(Path::new("fileX.h"), false));
+        assert!(!rules.is_ignored(Path::new("file10.c"), false));
+        assert!(rules.is_ignored(Path::new("b.log"), false));
+        assert!(!rules.is_ignored(Path::new("a.log"), false));
+    }
+
+    #[test]
+    fn test_absolute_paths_are_relative_to_root() {
+        let rules = parse("*.pb.go\n");
+        assert!(rules.is_ignored(Path::new("/repo/api/user.pb.go"), false));
+        assert!(!rules.is_ignored(Path::new("/elsewhere/user.pb.go"), false));
+    }
+
+    #[test]
+    fn test_load_reads_project_file() {
+        let temp = TempDir::new().unwrap();
+        assert!(IgnoreRules::load(temp.path()).is_empty());
+
+        fs::create_dir_all(temp.path().join(".tina")).unwrap();
+        fs::write(temp.path().join(IGNORE_FILE), "/gen/\n!x\n").unwrap();
+        let rules = IgnoreRules::load(temp.path());
+        assert!(rules.is_ignored(&temp.path().join("gen/a.rs"), false));
+        assert_eq!(rules.exclude_globs(), vec!["/gen".to_string()]);
+    }
+}
@@ -7,10 +7,16 @@ pub mod claude;
 pub mod config;
 pub mod convex;
 pub mod daemon;
+pub mod detectors;
 pub mod error;
+pub mod ignore;
+pub mod policy;
+pub mod project_config;
+pub mod risk;
 pub mod routing;
 pub mod session;
 pub mod state;
 pub mod telemetry;
 pub mod tmux;
+pub mod tokens;
 pub mod watch;
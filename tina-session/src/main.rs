@@ -94,6 +94,11 @@ enum Commands {
         #[arg(long)]
         total_phases: u32,
 
+        /// Confine checks, diffs, and commit sync to this subtree of a
+        /// monorepo (relative to the project root)
+        #[arg(long)]
+        scope: Option<PathBuf>,
+
         /// Review gate enforcement scope.
         #[arg(long, value_parser = ["task_and_phase", "task_only", "phase_only"])]
         review_enforcement: Option<String>,
@@ -294,6 +299,12 @@ enum Commands {
         command: DaemonCommands,
     },
 
+    /// Git hook helpers
+    Hooks {
+        #[command(subcommand)]
+        command: HooksCommands,
+    },
+
     /// Config helpers
     Config {
         #[command(subcommand)]
@@ -384,6 +395,65 @@ enum Commands {
         feature: String,
     },
 
+    /// Replay commit and plan sync to Convex for history the daemon missed
+    Backfill {
+        /// Feature name
+        #[arg(long)]
+        feature: String,
+
+        /// Replay commits after this SHA (exclusive) up to the branch head
+        #[arg(long)]
+        since: String,
+
+        /// Print what would be synced without writing to Convex
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Gather everything behind a blocked orchestration into one report and
+    /// offer the next step (retry, replan, escalate, abort)
+    Triage {
+        /// Feature name
+        #[arg(long)]
+        feature: String,
+
+        /// Apply this action without prompting (retry, replan, escalate, abort)
+        #[arg(long)]
+        action: Option<String>,
+    },
+
+    /// Score a phase's risk from its diff, scope, dependencies, detector
+    /// findings, and test share, and record it on the phase
+    Risk {
+        /// Feature name
+        #[arg(long)]
+        feature: String,
+
+        /// Phase number (must have a recorded git range)
+        #[arg(long)]
+        phase: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Print the score without recording it in Convex
+        #[arg(long)]
+        no_record: bool,
+    },
+
+    /// Event-sourced projection subcommands
+    Projections {
+        #[command(subcommand)]
+        command: ProjectionsCommands,
+    },
+
+    /// Scoped API tokens for the daemon's HTTP API
+    Token {
+        #[command(subcommand)]
+        command: TokenCommands,
+    },
+
     /// Orchestration state machine subcommands
     Orchestrate {
         #[command(subcommand)]
@@ -464,6 +534,11 @@ enum StateCommands {
         #[arg(long)]
         phase: Option<String>,
 
+        /// Reconstruct the state at a past instant (RFC 3339 or local
+        /// "2025-03-01T12:00") and show what has changed since
+        #[arg(long, conflicts_with = "phase")]
+        at: Option<String>,
+
         /// Output format
         #[arg(long, value_enum, default_value = "text")]
         format: OutputFormat,
@@ -505,6 +580,17 @@ enum CheckCommands {
         path: PathBuf,
     },
 
+    /// Run the project's external detectors against the branch diff
+    Detectors {
+        /// Working directory
+        #[arg(long)]
+        cwd: PathBuf,
+
+        /// Base ref to diff against
+        #[arg(long, default_value = "main")]
+        base: String,
+    },
+
     /// Preflight checks for PATH/binary command-surface drift
     Doctor,
 }
@@ -540,6 +626,77 @@ enum DaemonCommands {
     },
 }
 
+#[derive(Subcommand)]
+enum HooksCommands {
+    /// Install post-commit/post-checkout hooks that notify the daemon
+    Install {
+        /// Orchestration worktree directory
+        #[arg(long)]
+        cwd: PathBuf,
+
+        /// Daemon HTTP port
+        #[arg(long, default_value_t = commands::hooks::DEFAULT_DAEMON_PORT)]
+        port: u16,
+    },
+
+    /// Remove tina hooks, restoring any hooks they replaced
+    Uninstall {
+        /// Orchestration worktree directory
+        #[arg(long)]
+        cwd: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum ProjectionsCommands {
+    /// Re-derive phase and task projections from the event stream, report
+    /// divergences from the stored documents, and rewrite drifted phases
+    Rebuild {
+        /// Feature name
+        #[arg(long)]
+        feature: String,
+
+        /// Report divergences without rewriting documents
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum TokenCommands {
+    /// Create a token and print its secret (shown only once)
+    Create {
+        /// `read` tokens can only query; `operate` tokens can also change state
+        #[arg(long, value_parser = ["read", "operate"])]
+        scope: String,
+
+        /// Lifetime, e.g. 30d, 12h, 90m, or `never`
+        #[arg(long, default_value = "30d")]
+        expires: String,
+
+        /// Label for the token, e.g. the CI job using it
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// List tokens (secrets are never shown)
+    List {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Revoke a token by id
+    Revoke {
+        /// Token id (from `token list`)
+        id: String,
+    },
+}
+
 #[derive(Subcommand)]
 enum ConfigCommands {
     /// Print the resolved Convex URL for the selected environment
@@ -1231,7 +1388,8 @@ enum ReviewGateCommands {
         #[arg(long, value_parser = ["plan", "review", "finalize"])]
         gate: String,
 
-        /// Who approved
+        /// Who approved; each approver counts once toward the gate's
+        /// required approvals
         #[arg(long, default_value = "human")]
         decided_by: String,
 
@@ -1295,6 +1453,7 @@ fn run() -> anyhow::Result<u8> {
             spec_id,
             branch,
             total_phases,
+            scope,
             review_enforcement,
             detector_scope,
             architect_mode,
@@ -1312,6 +1471,7 @@ fn run() -> anyhow::Result<u8> {
                     spec_id.as_deref(),
                     &branch,
                     total_phases,
+                    scope.as_deref(),
                     review_enforcement.as_deref(),
                     detector_scope.as_deref(),
                     architect_mode.as_deref(),
@@ -1329,6 +1489,7 @@ fn run() -> anyhow::Result<u8> {
                     spec_id.as_deref(),
                     &branch,
                     total_phases,
+                    scope.as_deref(),
                     review_enforcement.as_deref(),
                     detector_scope.as_deref(),
                     architect_mode.as_deref(),
@@ -1409,8 +1570,12 @@ fn run() -> anyhow::Result<u8> {
             StateCommands::Show {
                 feature,
                 phase,
+                at,
                 format,
             } => {
+                if let Some(at) = at {
+                    return commands::state::show_at(&feature, &at, format == OutputFormat::Json);
+                }
                 if let Some(ref p) = phase {
                     check_phase(p)?;
                 }
@@ -1435,6 +1600,8 @@ fn run() -> anyhow::Result<u8> {
 
             CheckCommands::Plan { path } => commands::check::plan(&path),
 
+            CheckCommands::Detectors { cwd, base } => commands::check::detectors(&cwd, &base),
+
             CheckCommands::Doctor => commands::check::doctor(),
         },
 
@@ -1491,6 +1658,11 @@ fn run() -> anyhow::Result<u8> {
             }
         },
 
+        Commands::Hooks { command } => match command {
+            HooksCommands::Install { cwd, port } => commands::hooks::install(&cwd, port),
+            HooksCommands::Uninstall { cwd } => commands::hooks::uninstall(&cwd),
+        },
+
         Commands::Config { command } => match command {
             ConfigCommands::ConvexUrl { env } => commands::config::convex_url(env.as_deref()),
             ConfigCommands::Show { env } => commands::config::show(env.as_deref()),
@@ -1548,6 +1720,38 @@ fn run() -> anyhow::Result<u8> {
 
         Commands::Cleanup { feature } => commands::cleanup::run(&feature),
 
+        Commands::Backfill {
+            feature,
+            since,
+            dry_run,
+        } => commands::backfill::run(&feature, &since, dry_run),
+
+        Commands::Triage { feature, action } => commands::triage::run(&feature, action.as_deref()),
+
+        Commands::Risk {
+            feature,
+            phase,
+            json,
+            no_record,
+        } => commands::risk::run(&feature, &phase, json, !no_record),
+
+        Commands::Projections { command } => match command {
+            ProjectionsCommands::Rebuild { feature, dry_run } => {
+                commands::projections::rebuild(&feature, dry_run)
+            }
+        },
+
+        Commands::Token { command } => match command {
+            TokenCommands::Create {
+                scope,
+                expires,
+                name,
+                json,
+            } => commands::token::create(name.as_deref(), &scope, &expires, json),
+            TokenCommands::List { json } => commands::token::list(json),
+            TokenCommands::Revoke { id } => commands::token::revoke(&id),
+        },
+
         Commands::Orchestrate { command } => match command {
             OrchestrateCommands::Next { feature } => commands::orchestrate::next(&feature),
 
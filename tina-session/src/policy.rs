@@ -0,0 +1,504 @@
+//! Per-project orchestration policy scripts.
+//!
+//! Projects can adjust orchestration decisions without recompiling by
+//! committing a Lua script at `<project>/.tina/policy.lua`. The script
+//! defines any of these global functions; missing ones keep the built-in
+//! behavior:
+//!
+//! ```lua
+//! -- Called when a review pass is about to complete a phase. Return nil to
+//! -- continue, { block = "reason" } to stop for a human, or
+//! -- { issues = { ... } } to send the phase through remediation instead.
+//! function on_phase_complete(phase)
+//!   if phase.breakdown.execution_mins and phase.breakdown.execution_mins > 240 then
+//!     return { block = "phase " .. phase.phase .. " ran over four hours" }
+//!   end
+//! end
+//!
+//! -- Called before a review finding is recorded. Return nil to keep it, or
+//! -- a table overriding `severity` and/or `gate`.
+//! function on_finding_added(finding)
+//!   if finding.file:match("^migrations/") then
+//!     return { severity = "p0", gate = "review" }
+//!   end
+//! end
+//!
+//! -- Called whenever an agent is about to be spawned. Return a model name
+//! -- to override the configured one, or nil to keep it.
+//! function select_model_for_task(task)
+//!   if task.role == "executor" and task.phase:find("%.") then
+//!     return "opus"
+//!   end
+//! end
+//! ```
+//!
+//! Scripts run in a sandbox with only the `table`, `string`, `math`, and
+//! `utf8` libraries, a memory cap, and a per-call time limit.
+
+use std::cell::Cell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use mlua::{Function, HookTriggers, Lua, LuaOptions, LuaSerdeExt, StdLib, Value};
+use serde::{Deserialize, Serialize};
+
+use crate::state::orchestrate::{review_pass_completes_phase, Action, AdvanceEvent};
+use crate::state::schema::{PhaseState, SupervisorState};
+
+/// Location of the policy script, relative to the project root.
+pub const POLICY_SCRIPT: &str = ".tina/policy.lua";
+
+/// Longest a single hook call may run.
+const HOOK_TIMEOUT: Duration = Duration::from_secs(2);
+/// Memory available to a script.
+const MEMORY_LIMIT_BYTES: usize = 32 * 1024 * 1024;
+/// How often (in VM instructions) the time limit is checked.
+const TIMEOUT_CHECK_INTERVAL: u32 = 10_000;
+
+/// Context passed to `on_phase_complete`.
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseContext<'a> {
+    pub feature: &'a str,
+    pub phase: &'a str,
+    pub total_phases: u32,
+    #[serde(flatten)]
+    pub state: &'a PhaseState,
+}
+
+/// What `on_phase_complete` decided.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhaseDecision {
+    /// Complete the phase as usual.
+    Continue,
+    /// Block the orchestration for a human.
+    Block(String),
+    /// Treat the review as having found these gaps.
+    Remediate(Vec<String>),
+}
+
+/// A review finding as seen by `on_finding_added`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct FindingContext {
+    pub review_id: String,
+    pub file: String,
+    pub line: i64,
+    pub commit: String,
+    pub severity: String,
+    pub gate: String,
+    pub summary: String,
+    pub body: String,
+    pub source: String,
+    pub author: String,
+}
+
+/// Context passed to `select_model_for_task`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskContext<'a> {
+    pub feature: &'a str,
+    /// `validator`, `planner`, `executor`, or `reviewer`.
+    pub role: &'a str,
+    pub phase: &'a str,
+    /// Model that would be used without the hook.
+    pub model: &'a str,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PhaseDecisionTable {
+    block: Option<String>,
+    issues: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FindingOverrides {
+    severity: Option<String>,
+    gate: Option<String>,
+}
+
+/// A loaded policy script.
+pub struct PolicyScript {
+    lua: Lua,
+    path: PathBuf,
+    deadline: Rc<Cell<Instant>>,
+}
+
+impl PolicyScript {
+    /// Load `.tina/policy.lua` for the project rooted at `root`.
+    ///
+    /// Returns `None` when the project has no policy script.
+    pub fn load(root: &Path) -> anyhow::Result<Option<Self>> {
+        let path = root.join(POLICY_SCRIPT);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let source = fs::read_to_string(&path)?;
+        Self::from_source(&source, path).map(Some)
+    }
+
+    /// Find the nearest ancestor of `path` with a policy script and load it.
+    pub fn discover(path: &Path) -> anyhow::Result<Option<Self>> {
+        match path
+            .ancestors()
+            .find(|dir| dir.join(POLICY_SCRIPT).is_file())
+        {
+            Some(root) => Self::load(root),
+            None => Ok(None),
+        }
+    }
+
+    /// Compile a script. `path` is only used in error messages.
+    pub fn from_source(source: &str, path: PathBuf) -> anyhow::Result<Self> {
+        let lua = Lua::new_with(
+            StdLib::TABLE | StdLib::STRING | StdLib::MATH | StdLib::UTF8,
+            LuaOptions::default(),
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to start Lua: {}", e))?;
+        lua.set_memory_limit(MEMORY_LIMIT_BYTES)
+            .map_err(|e| anyhow::anyhow!("Failed to limit Lua memory: {}", e))?;
+
+        let deadline = Rc::new(Cell::new(Instant::now() + HOOK_TIMEOUT));
+        let hook_deadline = Rc::clone(&deadline);
+        lua.set_hook(
+            HookTriggers::new().every_nth_instruction(TIMEOUT_CHECK_INTERVAL),
+            move |_, _| {
+                if Instant::now() > hook_deadline.get() {
+                    return Err(mlua::Error::runtime(format!(
+                        "policy script exceeded {}s time limit",
+                        HOOK_TIMEOUT.as_secs()
+                    )));
+                }
+                Ok(())
+            },
+        );
+
+        let script = Self {
+            lua,
+            path,
+            deadline,
+        };
+        script.reset_deadline();
+        script
+            .lua
+            .load(source)
+            .set_name(script.path.to_string_lossy())
+            .exec()
+            .map_err(|e| script.error("load", e))?;
+        Ok(script)
+    }
+
+    /// Path the script was loaded from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Run `on_phase_complete`.
+    pub fn on_phase_complete(&self, ctx: &PhaseContext) -> anyhow::Result<PhaseDecision> {
+        let value = match self.call("on_phase_complete", ctx)? {
+            None | Some(Value::Nil) => return Ok(PhaseDecision::Continue),
+            Some(value) => value,
+        };
+        let table: PhaseDecisionTable = self
+            .lua
+            .from_value(value)
+            .map_err(|e| self.error("on_phase_complete", e))?;
+        Ok(match (table.block, table.issues) {
+            (Some(reason), _) => PhaseDecision::Block(reason),
+            (None, Some(issues)) if !issues.is_empty() => PhaseDecision::Remediate(issues),
+            _ => PhaseDecision::Continue,
+        })
+    }
+
+    /// Run `on_finding_added`, applying any severity or gate overrides.
+    pub fn on_finding_added(&self, finding: &mut FindingContext) -> anyhow::Result<()> {
+        let value = match self.call("on_finding_added", &*finding)? {
+            None | Some(Value::Nil) => return Ok(()),
+            Some(value) => value,
+        };
+        let overrides: FindingOverrides = self
+            .lua
+            .from_value(value)
+            .map_err(|e| self.error("on_finding_added", e))?;
+        if let Some(severity) = overrides.severity {
+            finding.severity = severity;
+        }
+        if let Some(gate) = overrides.gate {
+            finding.gate = gate;
+        }
+        Ok(())
+    }
+
+    /// Run `select_model_for_task`. Returns the overriding model, if any.
+    pub fn select_model_for_task(&self, task: &TaskContext) -> anyhow::Result<Option<String>> {
+        match self.call("select_model_for_task", task)? {
+            None | Some(Value::Nil) => Ok(None),
+            Some(Value::String(model)) => Ok(Some(model.to_str()?.to_string())),
+            Some(other) => anyhow::bail!(
+                "{}: select_model_for_task must return a string or nil, got {}",
+                self.path.display(),
+                other.type_name()
+            ),
+        }
+    }
+
+    /// Give `on_phase_complete` a say when a review pass would complete
+    /// `phase`, rewriting the event to match its decision.
+    pub fn review_event(
+        &self,
+        state: &SupervisorState,
+        phase: &str,
+        event: AdvanceEvent,
+    ) -> anyhow::Result<AdvanceEvent> {
+        if !matches!(event, AdvanceEvent::ReviewPass) || !review_pass_completes_phase(state, phase)
+        {
+            return Ok(event);
+        }
+        let Some(phase_state) = state.phases.get(phase) else {
+            return Ok(event);
+        };
+        let ctx = PhaseContext {
+            feature: &state.feature,
+            phase,
+            total_phases: state.total_phases,
+            state: phase_state,
+        };
+        Ok(match self.on_phase_complete(&ctx)? {
+            PhaseDecision::Continue => event,
+            PhaseDecision::Block(reason) => AdvanceEvent::Error {
+                reason: format!("policy: {}", reason),
+            },
+            PhaseDecision::Remediate(issues) => AdvanceEvent::ReviewGaps { issues },
+        })
+    }
+
+    /// Let `select_model_for_task` override the model of a spawn action.
+    pub fn apply_model(&self, state: &SupervisorState, action: &mut Action) -> anyhow::Result<()> {
+        let policy = &state.model_policy;
+        let (role, phase, model, configured) = match action {
+            Action::SpawnValidator { model } => {
+                ("validator", "validation", model, &policy.validator)
+            }
+            Action::SpawnPlanner { phase, model, .. } => {
+                ("planner", &**phase, model, &policy.planner)
+            }
+            Action::SpawnExecutor { phase, model, .. } => {
+                ("executor", &**phase, model, &policy.executor)
+            }
+            Action::SpawnReviewer { phase, model, .. } => {
+                ("reviewer", &**phase, model, &policy.reviewer)
+            }
+            _ => return Ok(()),
+        };
+        let current = model.clone().unwrap_or_else(|| configured.clone());
+        let task = TaskContext {
+            feature: &state.feature,
+            role,
+            phase,
+            model: &current,
+        };
+        if let Some(selected) = self.select_model_for_task(&task)? {
+            // Like the state machine, only spell out non-default models
+            *model = (selected != "opus").then_some(selected);
+        }
+        Ok(())
+    }
+
+    fn call<T: Serialize>(&self, name: &str, arg: &T) -> anyhow::Result<Option<Value<'_>>> {
+        let function: Option<Function> = self
+            .lua
+            .globals()
+            .get(name)
+            .map_err(|e| self.error(name, e))?;
+        let Some(function) = function else {
+            return Ok(None);
+        };
+        let arg = self.lua.to_value(arg).map_err(|e| self.error(name, e))?;
+        self.reset_deadline();
+        function
+            .call::<_, Value>(arg)
+            .map(Some)
+            .map_err(|e| self.error(name, e))
+    }
+
+    fn reset_deadline(&self) {
+        self.deadline.set(Instant::now() + HOOK_TIMEOUT);
+    }
+
+    fn error(&self, hook: &str, e: mlua::Error) -> anyhow::Error {
+        anyhow::anyhow!("Policy script {} ({}): {}", self.path.display(), hook, e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::schema::PhaseStatus;
+    use tempfile::TempDir;
+
+    fn script(source: &str) -> PolicyScript {
+        PolicyScript::from_source(source, PathBuf::from("policy.lua")).unwrap()
+    }
+
+    fn state_with_phase(phase: &str) -> SupervisorState {
+        let mut state = SupervisorState::new(
+            "auth",
+            PathBuf::from("/tmp/spec.md"),
+            PathBuf::from("/tmp/worktree"),
+            "tina/auth",
+            3,
+        );
+        let mut phase_state = PhaseState::new();
+        phase_state.status = PhaseStatus::Reviewing;
+        phase_state.git_range = Some("abc..def".to_string());
+        state.phases.insert(phase.to_string(), phase_state);
+        state
+    }
+
+    #[test]
+    fn test_missing_hooks_keep_defaults() {
+        let policy = script("-- no hooks");
+        let state = state_with_phase("1");
+        let event = policy
+            .review_event(&state, "1", AdvanceEvent::ReviewPass)
+            .unwrap();
+        assert!(matches!(event, AdvanceEvent::ReviewPass));
+
+        let mut action = Action::SpawnExecutor {
+            phase: "1".to_string(),
+            plan_path: "plan.md".to_string(),
+            model: Some("sonnet".to_string()),
+        };
+        policy.apply_model(&state, &mut action).unwrap();
+        assert!(
+            matches!(action, Action::SpawnExecutor { model: Some(ref m), .. } if m == "sonnet")
+        );
+    }
+
+    #[test]
+    fn test_on_phase_complete_can_block_or_remediate() {
+        let state = state_with_phase("2");
+        let policy = script(
+            r#"
+            function on_phase_complete(p)
+              if p.phase == "2" and p.git_range == "abc..def" then
+                return { block = "needs sign-off" }
+              end
+            end
+            "#,
+        );
+        match policy
+            .review_event(&state, "2", AdvanceEvent::ReviewPass)
+            .unwrap()
+        {
+            AdvanceEvent::Error { reason } => assert_eq!(reason, "policy: needs sign-off"),
+            other => panic!("expected Error, got {:?}", other),
+        }
+
+        let policy =
+            script(r#"function on_phase_complete(p) return { issues = { "add tests" } } end"#);
+        match policy
+            .review_event(&state, "2", AdvanceEvent::ReviewPass)
+            .unwrap()
+        {
+            AdvanceEvent::ReviewGaps { issues } => assert_eq!(issues, vec!["add tests"]),
+            other => panic!("expected ReviewGaps, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_on_phase_complete_skipped_while_waiting_for_consensus() {
+        let mut state = state_with_phase("1");
+        state.model_policy.review_consensus = true;
+        let policy = script(r#"function on_phase_complete(p) return { block = "no" } end"#);
+        let event = policy
+            .review_event(&state, "1", AdvanceEvent::ReviewPass)
+            .unwrap();
+        assert!(matches!(event, AdvanceEvent::ReviewPass));
+    }
+
+    #[test]
+    fn test_on_finding_added_overrides_fields() {
+        let policy = script(
+            r#"
+            function on_finding_added(f)
+              if f.file:match("^migrations/") then return { severity = "p0" } end
+            end
+            "#,
+        );
+        let mut finding = FindingContext {
+            review_id: "r1".to_string(),
+            file: "migrations/001.sql".to_string(),
+            line: 3,
+            commit: "abc".to_string(),
+            severity: "p2".to_string(),
+            gate: "review".to_string(),
+            summary: "drop column".to_string(),
+            body: String::new(),
+            source: "agent".to_string(),
+            author: "reviewer".to_string(),
+        };
+        policy.on_finding_added(&mut finding).unwrap();
+        assert_eq!(finding.severity, "p0");
+        assert_eq!(finding.gate, "review");
+    }
+
+    #[test]
+    fn test_select_model_for_task_overrides_spawn_model() {
+        let state = state_with_phase("1.5");
+        let policy = script(
+            r#"
+            function select_model_for_task(t)
+              if t.role == "executor" and t.phase:find("%.") then return "opus" end
+              if t.role == "reviewer" then return "haiku" end
+            end
+            "#,
+        );
+
+        let mut action = Action::SpawnExecutor {
+            phase: "1.5".to_string(),
+            plan_path: "plan.md".to_string(),
+            model: Some("sonnet".to_string()),
+        };
+        policy.apply_model(&state, &mut action).unwrap();
+        assert!(matches!(action, Action::SpawnExecutor { model: None, .. }));
+
+        let mut action = Action::SpawnReviewer {
+            phase: "1".to_string(),
+            git_range: "a..b".to_string(),
+            model: None,
+            secondary_model: None,
+        };
+        policy.apply_model(&state, &mut action).unwrap();
+        assert!(matches!(action, Action::SpawnReviewer { model: Some(ref m), .. } if m == "haiku"));
+
+        let policy = script("function select_model_for_task(t) return 42 end");
+        assert!(policy.apply_model(&state, &mut action).is_err());
+    }
+
+    #[test]
+    fn test_sandbox_and_time_limit() {
+        let policy = script("function on_finding_added(f) return io.open('/etc/passwd') end");
+        let mut finding = FindingContext::default();
+        assert!(policy.on_finding_added(&mut finding).is_err());
+
+        let policy = script("function on_finding_added(f) while true do end end");
+        let err = policy.on_finding_added(&mut finding).unwrap_err();
+        assert!(err.to_string().contains("time limit"), "{}", err);
+    }
+
+    #[test]
+    fn test_discover_finds_project_script() {
+        let temp = TempDir::new().unwrap();
+        let nested = temp.path().join("a/b");
+        fs::create_dir_all(&nested).unwrap();
+        assert!(PolicyScript::discover(&nested).unwrap().is_none());
+
+        fs::create_dir_all(temp.path().join(".tina")).unwrap();
+        fs::write(temp.path().join(POLICY_SCRIPT), "x = 1").unwrap();
+        let policy = PolicyScript::discover(&nested).unwrap().unwrap();
+        assert_eq!(policy.path(), temp.path().join(POLICY_SCRIPT));
+
+        fs::write(temp.path().join(POLICY_SCRIPT), "this is not lua").unwrap();
+        assert!(PolicyScript::load(temp.path()).is_err());
+    }
+}
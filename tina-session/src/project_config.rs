@@ -0,0 +1,266 @@
+//! Per-project layout configuration.
+//!
+//! Repos organize their docs differently, so plan and handoff locations can
+//! be overridden in `<project>/.tina/config.toml`:
+//!
+//! ```toml
+//! [layout]
+//! plans_dir = "design/plans"
+//! handoff_path = "notes/phase-{phase}/handoff.md"
+//! ```
+//!
+//! Paths are relative to the project root. Missing keys fall back to the
+//! defaults below.
+//!
+//! Gates can require approval from more than one person:
+//!
+//! ```toml
+//! [gates.finalize]
+//! required_approvals = 2
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// Location of the project config, relative to the project root.
+pub const PROJECT_CONFIG_PATH: &str = ".tina/config.toml";
+
+pub const DEFAULT_PLANS_DIR: &str = "docs/plans";
+pub const DEFAULT_HANDOFF_PATH: &str = ".claude/tina/phase-{phase}/handoff.md";
+
+#[derive(Debug, Default, Deserialize)]
+struct ProjectConfigFile {
+    layout: Option<LayoutFile>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct LayoutFile {
+    plans_dir: Option<PathBuf>,
+    handoff_path: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct GatesFile {
+    #[serde(default)]
+    gates: BTreeMap<String, GateConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct GateConfig {
+    required_approvals: Option<u32>,
+}
+
+/// Distinct approvals `gate` needs in the project rooted at `root`.
+///
+/// Defaults to one when the project has no config or doesn't set a quorum
+/// for the gate.
+pub fn required_approvals(root: &Path, gate: &str) -> anyhow::Result<u32> {
+    let path = root.join(PROJECT_CONFIG_PATH);
+    if !path.exists() {
+        return Ok(1);
+    }
+    let contents = fs::read_to_string(&path)?;
+    parse_required_approvals(&contents, gate)
+        .map_err(|e| anyhow::anyhow!("Invalid project config {}: {}", path.display(), e))
+}
+
+fn parse_required_approvals(contents: &str, gate: &str) -> anyhow::Result<u32> {
+    let file: GatesFile = toml::from_str(contents)?;
+    match file.gates.get(gate).and_then(|g| g.required_approvals) {
+        Some(0) => anyhow::bail!("gates.{}.required_approvals must be at least 1", gate),
+        Some(n) => Ok(n),
+        None => Ok(1),
+    }
+}
+
+/// Where a project keeps its plans and phase handoffs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectLayout {
+    /// Plans directory, relative to the project root.
+    pub plans_dir: PathBuf,
+    /// Handoff file template, relative to the project root. `{phase}` is
+    /// replaced with the phase key.
+    pub handoff_path: String,
+}
+
+impl Default for ProjectLayout {
+    fn default() -> Self {
+        Self {
+            plans_dir: PathBuf::from(DEFAULT_PLANS_DIR),
+            handoff_path: DEFAULT_HANDOFF_PATH.to_string(),
+        }
+    }
+}
+
+impl ProjectLayout {
+    /// Load the layout for the project rooted at `root`.
+    ///
+    /// Returns the defaults when the project has no config file.
+    pub fn load(root: &Path) -> anyhow::Result<Self> {
+        let path = root.join(PROJECT_CONFIG_PATH);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(&path)?;
+        Self::parse(&contents)
+            .map_err(|e| anyhow::anyhow!("Invalid project config {}: {}", path.display(), e))
+    }
+
+    /// Like [`ProjectLayout::load`], but falls back to the defaults when the
+    /// config can't be read or parsed.
+    pub fn load_or_default(root: &Path) -> Self {
+        Self::load(root).unwrap_or_default()
+    }
+
+    /// Find the nearest ancestor of `path` that has a project config and
+    /// load its layout.
+    pub fn discover(path: &Path) -> anyhow::Result<Option<(PathBuf, Self)>> {
+        for dir in path.ancestors() {
+            if dir.join(PROJECT_CONFIG_PATH).is_file() {
+                return Ok(Some((dir.to_path_buf(), Self::load(dir)?)));
+            }
+        }
+        Ok(None)
+    }
+
+    fn parse(contents: &str) -> anyhow::Result<Self> {
+        let file: ProjectConfigFile = toml::from_str(contents)?;
+        let layout = file.layout.unwrap_or_default();
+        let defaults = Self::default();
+
+        let plans_dir = layout.plans_dir.unwrap_or(defaults.plans_dir);
+        if plans_dir.is_absolute() {
+            anyhow::bail!("layout.plans_dir must be relative to the project root");
+        }
+
+        Ok(Self {
+            plans_dir,
+            handoff_path: layout.handoff_path.unwrap_or(defaults.handoff_path),
+        })
+    }
+
+    /// Absolute plans directory for the project rooted at `root`.
+    pub fn plans_dir(&self, root: &Path) -> PathBuf {
+        root.join(&self.plans_dir)
+    }
+
+    /// Absolute handoff path for `phase` in the project rooted at `root`.
+    pub fn handoff_path(&self, root: &Path, phase: &str) -> PathBuf {
+        root.join(self.handoff_path.replace("{phase}", phase))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_config(root: &Path, contents: &str) {
+        let path = root.join(PROJECT_CONFIG_PATH);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn test_load_defaults_without_config() {
+        let temp = TempDir::new().unwrap();
+        let layout = ProjectLayout::load(temp.path()).unwrap();
+
+        assert_eq!(layout, ProjectLayout::default());
+        assert_eq!(
+            layout.plans_dir(temp.path()),
+            temp.path().join("docs").join("plans")
+        );
+        assert_eq!(
+            layout.handoff_path(temp.path(), "2"),
+            temp.path().join(".claude/tina/phase-2/handoff.md")
+        );
+    }
+
+    #[test]
+    fn test_load_overrides_layout() {
+        let temp = TempDir::new().unwrap();
+        write_config(
+            temp.path(),
+            r#"
+[layout]
+plans_dir = "design/plans"
+handoff_path = "notes/{phase}.md"
+"#,
+        );
+
+        let layout = ProjectLayout::load(temp.path()).unwrap();
+        assert_eq!(
+            layout.plans_dir(temp.path()),
+            temp.path().join("design/plans")
+        );
+        assert_eq!(
+            layout.handoff_path(temp.path(), "1.5"),
+            temp.path().join("notes/1.5.md")
+        );
+    }
+
+    #[test]
+    fn test_partial_layout_keeps_defaults() {
+        let layout = ProjectLayout::parse("[layout]\nplans_dir = \"plans\"\n").unwrap();
+        assert_eq!(layout.plans_dir, PathBuf::from("plans"));
+        assert_eq!(layout.handoff_path, DEFAULT_HANDOFF_PATH);
+    }
+
+    #[test]
+    fn test_rejects_absolute_plans_dir() {
+        assert!(ProjectLayout::parse("[layout]\nplans_dir = \"/tmp/plans\"\n").is_err());
+    }
+
+    #[test]
+    fn test_load_or_default_ignores_invalid_config() {
+        let temp = TempDir::new().unwrap();
+        write_config(temp.path(), "not = [valid");
+        assert!(ProjectLayout::load(temp.path()).is_err());
+        assert_eq!(
+            ProjectLayout::load_or_default(temp.path()),
+            ProjectLayout::default()
+        );
+    }
+
+    #[test]
+    fn test_required_approvals_per_gate() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(required_approvals(temp.path(), "finalize").unwrap(), 1);
+
+        write_config(
+            temp.path(),
+            r#"
+[layout]
+plans_dir = "plans"
+
+[gates.finalize]
+required_approvals = 2
+"#,
+        );
+        assert_eq!(required_approvals(temp.path(), "finalize").unwrap(), 2);
+        assert_eq!(required_approvals(temp.path(), "review").unwrap(), 1);
+    }
+
+    #[test]
+    fn test_required_approvals_rejects_zero() {
+        assert!(
+            parse_required_approvals("[gates.plan]\nrequired_approvals = 0\n", "plan").is_err()
+        );
+    }
+
+    #[test]
+    fn test_discover_finds_nearest_config() {
+        let temp = TempDir::new().unwrap();
+        write_config(temp.path(), "[layout]\nplans_dir = \"plans\"\n");
+        let nested = temp.path().join("plans").join("sub");
+        fs::create_dir_all(&nested).unwrap();
+
+        let (root, layout) = ProjectLayout::discover(&nested).unwrap().unwrap();
+        assert_eq!(root, temp.path());
+        assert_eq!(layout.plans_dir, PathBuf::from("plans"));
+    }
+}
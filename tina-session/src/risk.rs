@@ -0,0 +1,446 @@
+//! Per-phase risk scoring.
+//!
+//! Combines cheap signals from a phase's git range into a 0-100 score so
+//! reviewers can spend their attention on the riskiest phases first:
+//!
+//! - diff size (lines added plus removed)
+//! - files touched outside the orchestration's monorepo scope
+//! - dependencies added to known manifests
+//! - detector findings (see [`crate::detectors`])
+//! - how much of the change is tests, a proxy for coverage moving with the
+//!   code
+//!
+//! Each signal contributes a capped number of points, so one huge signal
+//! can't hide the others.
+
+use std::path::Path;
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+use crate::detectors::{self, Severity};
+use crate::state::schema::DetectorScope;
+
+/// Points for diff size, reached at [`DIFF_LINES_FOR_MAX`] changed lines.
+const DIFF_POINTS: f64 = 30.0;
+const DIFF_LINES_FOR_MAX: f64 = 1500.0;
+const OUT_OF_SCOPE_POINTS_EACH: f64 = 5.0;
+const OUT_OF_SCOPE_POINTS_MAX: f64 = 20.0;
+const DEPENDENCY_POINTS_EACH: f64 = 5.0;
+const DEPENDENCY_POINTS_MAX: f64 = 15.0;
+const DETECTOR_ERROR_POINTS: f64 = 10.0;
+const DETECTOR_WARNING_POINTS: f64 = 3.0;
+const DETECTOR_POINTS_MAX: f64 = 20.0;
+/// Points when little or none of the changed code is tests.
+const UNTESTED_POINTS: f64 = 15.0;
+const UNDERTESTED_POINTS: f64 = 8.0;
+/// Test share of changed lines below which a phase counts as untested.
+const UNTESTED_RATIO: f64 = 0.1;
+const UNDERTESTED_RATIO: f64 = 0.25;
+
+/// Scores at or above this are medium risk.
+pub const MEDIUM_RISK: u32 = 30;
+/// Scores at or above this are high risk.
+pub const HIGH_RISK: u32 = 60;
+
+/// Raw inputs to the score, stored alongside it so reviewers can see why.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RiskSignals {
+    pub files_changed: usize,
+    /// Lines added plus removed.
+    pub lines_changed: u64,
+    /// Changed lines in test files.
+    pub test_lines: u64,
+    pub files_outside_scope: usize,
+    pub new_dependencies: usize,
+    pub detector_errors: usize,
+    pub detector_warnings: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+}
+
+impl RiskLevel {
+    pub fn from_score(score: u32) -> Self {
+        if score >= HIGH_RISK {
+            Self::High
+        } else if score >= MEDIUM_RISK {
+            Self::Medium
+        } else {
+            Self::Low
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+}
+
+/// A scored phase.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RiskAssessment {
+    pub score: u32,
+    pub level: RiskLevel,
+    pub signals: RiskSignals,
+}
+
+impl RiskAssessment {
+    pub fn from_signals(signals: RiskSignals) -> Self {
+        let score = score(&signals);
+        Self {
+            score,
+            level: RiskLevel::from_score(score),
+            signals,
+        }
+    }
+}
+
+/// Combine signals into a 0-100 score.
+pub fn score(signals: &RiskSignals) -> u32 {
+    let diff = (signals.lines_changed as f64 / DIFF_LINES_FOR_MAX).min(1.0) * DIFF_POINTS;
+    let scope = (signals.files_outside_scope as f64 * OUT_OF_SCOPE_POINTS_EACH)
+        .min(OUT_OF_SCOPE_POINTS_MAX);
+    let deps =
+        (signals.new_dependencies as f64 * DEPENDENCY_POINTS_EACH).min(DEPENDENCY_POINTS_MAX);
+    let detectors = (signals.detector_errors as f64 * DETECTOR_ERROR_POINTS
+        + signals.detector_warnings as f64 * DETECTOR_WARNING_POINTS)
+        .min(DETECTOR_POINTS_MAX);
+    let tests = if signals.lines_changed == 0 {
+        0.0
+    } else {
+        let ratio = signals.test_lines as f64 / signals.lines_changed as f64;
+        if ratio < UNTESTED_RATIO {
+            UNTESTED_POINTS
+        } else if ratio < UNDERTESTED_RATIO {
+            UNDERTESTED_POINTS
+        } else {
+            0.0
+        }
+    };
+    (diff + scope + deps + detectors + tests).round().min(100.0) as u32
+}
+
+/// Score the changes in `range` (e.g. a phase's `git_range`).
+///
+/// Detectors configured for the project run against the range's base; a
+/// detector that fails to run contributes nothing rather than failing the
+/// assessment.
+pub fn assess(
+    worktree: &Path,
+    range: &str,
+    scope: Option<&Path>,
+    detector_scope: DetectorScope,
+) -> anyhow::Result<RiskAssessment> {
+    let numstat = git(worktree, &["diff", "--numstat", "--no-renames", range])?;
+    let files = parse_numstat(&numstat);
+
+    let mut signals = RiskSignals {
+        files_changed: files.len(),
+        ..RiskSignals::default()
+    };
+    for (path, lines) in &files {
+        signals.lines_changed += lines;
+        if is_test_path(path) {
+            signals.test_lines += lines;
+        }
+        if scope.is_some_and(|scope| !Path::new(path).starts_with(scope)) {
+            signals.files_outside_scope += 1;
+        }
+        if is_manifest(path) {
+            // Whole-file context so section headers are visible in the patch
+            let patch = git(worktree, &["diff", "-U100000", range, "--", path])?;
+            signals.new_dependencies += count_new_dependencies(path, &patch);
+        }
+    }
+
+    let configured = detectors::load_detectors(worktree)?;
+    if !configured.is_empty() {
+        let base = range_base(range);
+        let input = detectors::collect_input(worktree, base, scope, detector_scope)?;
+        for detector in &configured {
+            let Ok(findings) = detectors::run_detector(detector, worktree, &input) else {
+                continue;
+            };
+            for finding in findings {
+                match finding.severity {
+                    Severity::Error => signals.detector_errors += 1,
+                    Severity::Warning => signals.detector_warnings += 1,
+                    Severity::Info => {}
+                }
+            }
+        }
+    }
+
+    Ok(RiskAssessment::from_signals(signals))
+}
+
+fn git(cwd: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git").current_dir(cwd).args(args).output()?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// The start of a `base..head` or `base...head` range.
+fn range_base(range: &str) -> &str {
+    range.split("..").next().unwrap_or(range)
+}
+
+/// `(path, lines added + removed)` per file; binary files count as zero.
+fn parse_numstat(output: &str) -> Vec<(String, u64)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let added = fields.next()?;
+            let removed = fields.next()?;
+            let path = fields.next()?;
+            let lines = added.parse::<u64>().unwrap_or(0) + removed.parse::<u64>().unwrap_or(0);
+            Some((path.to_string(), lines))
+        })
+        .collect()
+}
+
+fn is_test_path(path: &str) -> bool {
+    let path = Path::new(path);
+    let in_test_dir = path.components().any(|c| {
+        matches!(
+            c.as_os_str().to_str(),
+            Some("tests" | "test" | "__tests__" | "spec")
+        )
+    });
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    let stem = name.split('.').next().unwrap_or("");
+    in_test_dir
+        || name.contains(".test.")
+        || name.contains(".spec.")
+        || stem.ends_with("_test")
+        || stem.starts_with("test_")
+}
+
+fn file_name(path: &str) -> &str {
+    Path::new(path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("")
+}
+
+fn is_manifest(path: &str) -> bool {
+    matches!(
+        file_name(path),
+        "Cargo.toml" | "pyproject.toml" | "package.json" | "go.mod" | "requirements.txt"
+    )
+}
+
+/// Count dependencies added in a manifest's full-context patch.
+///
+/// TOML manifests count new `name = ...` lines inside `[*dependencies*]`
+/// tables, `package.json` new entries inside `*dependencies` objects,
+/// `go.mod` new module requirements, and `requirements.txt` new lines.
+pub fn count_new_dependencies(path: &str, patch: &str) -> usize {
+    // Skip the diff header; hunks start at the first "@@"
+    let body = patch
+        .lines()
+        .skip_while(|line| !line.starts_with("@@"))
+        .filter(|line| !line.starts_with("@@") && !line.starts_with('-'));
+
+    let mut count = 0;
+    match file_name(path) {
+        "Cargo.toml" | "pyproject.toml" => {
+            let mut in_deps = false;
+            for line in body {
+                let (added, text) = split_marker(line);
+                let text = text.trim();
+                if text.starts_with('[') {
+                    in_deps = text.contains("dependencies");
+                } else if added && in_deps && is_toml_entry(text) {
+                    count += 1;
+                }
+            }
+        }
+        "package.json" => {
+            let mut in_deps = false;
+            for line in body {
+                let (added, text) = split_marker(line);
+                let text = text.trim();
+                if text.contains("ependencies\"") && text.ends_with('{') {
+                    in_deps = true;
+                } else if in_deps && text.starts_with('}') {
+                    in_deps = false;
+                } else if added && in_deps && text.starts_with('"') {
+                    count += 1;
+                }
+            }
+        }
+        "go.mod" => {
+            for line in body {
+                let (added, text) = split_marker(line);
+                let text = text.trim().trim_start_matches("require").trim();
+                let mut words = text.split_whitespace();
+                let is_requirement = matches!(
+                    (words.next(), words.next()),
+                    (Some(module), Some(version)) if module.contains('.') && version.starts_with('v')
+                );
+                if added && is_requirement {
+                    count += 1;
+                }
+            }
+        }
+        "requirements.txt" => {
+            for line in body {
+                let (added, text) = split_marker(line);
+                let text = text.trim();
+                if added && !text.is_empty() && !text.starts_with('#') {
+                    count += 1;
+                }
+            }
+        }
+        _ => {}
+    }
+    count
+}
+
+/// Split a patch line into (is-added, text without the marker).
+fn split_marker(line: &str) -> (bool, &str) {
+    match line.strip_prefix('+') {
+        Some(text) => (true, text),
+        None => (false, line.strip_prefix(' ').unwrap_or(line)),
+    }
+}
+
+fn is_toml_entry(text: &str) -> bool {
+    match text.split_once('=') {
+        Some((key, _)) => {
+            let key = key.trim().trim_matches('"');
+            !key.is_empty()
+                && key
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn score_caps_each_signal() {
+        assert_eq!(score(&RiskSignals::default()), 0);
+
+        let small_tested = RiskSignals {
+            files_changed: 2,
+            lines_changed: 150,
+            test_lines: 60,
+            ..RiskSignals::default()
+        };
+        assert_eq!(score(&small_tested), 3);
+
+        let everything = RiskSignals {
+            files_changed: 80,
+            lines_changed: 10_000,
+            test_lines: 0,
+            files_outside_scope: 50,
+            new_dependencies: 9,
+            detector_errors: 5,
+            detector_warnings: 5,
+        };
+        assert_eq!(score(&everything), 100);
+        assert_eq!(RiskLevel::from_score(score(&everything)), RiskLevel::High);
+    }
+
+    #[test]
+    fn untested_changes_add_risk() {
+        let untested = RiskSignals {
+            files_changed: 1,
+            lines_changed: 300,
+            ..RiskSignals::default()
+        };
+        let assessment = RiskAssessment::from_signals(untested);
+        assert_eq!(assessment.score, 21);
+        assert_eq!(assessment.level, RiskLevel::Low);
+    }
+
+    #[test]
+    fn parse_numstat_sums_lines_and_skips_binary_counts() {
+        let files = parse_numstat("10\t2\tsrc/lib.rs\n-\t-\tassets/logo.png\n");
+        assert_eq!(
+            files,
+            vec![
+                ("src/lib.rs".to_string(), 12),
+                ("assets/logo.png".to_string(), 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn is_test_path_recognizes_common_layouts() {
+        assert!(is_test_path("tina-session/tests/cli.rs"));
+        assert!(is_test_path("src/components/__tests__/Card.test.tsx"));
+        assert!(is_test_path("pkg/server_test.go"));
+        assert!(is_test_path("app/test_models.py"));
+        assert!(!is_test_path("src/testing_utils.rs"));
+        assert!(!is_test_path("src/lib.rs"));
+    }
+
+    #[test]
+    fn count_new_dependencies_in_cargo_toml() {
+        let patch = "\
+diff --git a/Cargo.toml b/Cargo.toml
+@@ -1,8 +1,10 @@
+ [package]
+-version = \"0.1.0\"
++version = \"0.2.0\"
+
+ [dependencies]
+ serde = \"1\"
++regex = \"1\"
++tokio = { version = \"1\", features = [\"rt\"] }
+
+ [dev-dependencies]
++tempfile = \"3\"
+";
+        assert_eq!(count_new_dependencies("Cargo.toml", patch), 3);
+    }
+
+    #[test]
+    fn count_new_dependencies_in_package_json_and_go_mod() {
+        let package = "\
+@@ -1,6 +1,8 @@
+ {
+   \"name\": \"web\",
++  \"private\": true,
+   \"dependencies\": {
++    \"zod\": \"^3.0.0\",
+     \"react\": \"^18.0.0\"
+   }
+ }
+";
+        assert_eq!(count_new_dependencies("web/package.json", package), 1);
+
+        let go_mod = "\
+@@ -1,4 +1,6 @@
+ module example.com/app
++go 1.22
+ require (
++\tgithub.com/google/uuid v1.6.0
+ )
+";
+        assert_eq!(count_new_dependencies("go.mod", go_mod), 1);
+    }
+}
@@ -0,0 +1,376 @@
+//! Point-in-time reconstruction of orchestration state.
+//!
+//! Supervisor state only holds the latest snapshot, but every phase records
+//! when it entered planning, execution, and review. Rewinding those
+//! timestamps, plus replaying the task event log, recovers what the
+//! orchestrator saw at an earlier instant.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tina_data::TaskEventRecord;
+
+use super::schema::{
+    OrchestrationStatus, PhaseBreakdown, PhaseState, PhaseStatus, SupervisorState,
+};
+use super::timing::duration_mins;
+
+/// Rewind `state` to how it looked at `at`.
+///
+/// Returns `None` if the orchestration hadn't started yet. Phases with no
+/// activity after `at` are returned untouched, so statuses that carry no
+/// timestamp (such as `blocked`) survive when nothing has happened since.
+pub fn state_at(state: &SupervisorState, at: DateTime<Utc>) -> Option<SupervisorState> {
+    if at < state.orchestration_started_at {
+        return None;
+    }
+
+    let mut past = state.clone();
+    if !state.phases.values().any(|phase| changed_after(phase, at)) {
+        return Some(past);
+    }
+
+    past.phases = state
+        .phases
+        .iter()
+        .filter_map(|(key, phase)| Some((key.clone(), phase_at(phase, at)?)))
+        .collect();
+    past.current_phase = past
+        .phases
+        .keys()
+        .filter_map(|key| key.parse::<u32>().ok())
+        .max()
+        .unwrap_or(1);
+    past.status = match past.phases.get(&past.current_phase.to_string()) {
+        None => OrchestrationStatus::Planning,
+        Some(phase) => match phase.status {
+            PhaseStatus::Planning | PhaseStatus::Planned => OrchestrationStatus::Planning,
+            PhaseStatus::Executing => OrchestrationStatus::Executing,
+            PhaseStatus::Reviewing => OrchestrationStatus::Reviewing,
+            PhaseStatus::Blocked => OrchestrationStatus::Blocked,
+            PhaseStatus::Complete if past.current_phase >= past.total_phases => {
+                OrchestrationStatus::Complete
+            }
+            // Between phases: waiting on the next planner
+            PhaseStatus::Complete => OrchestrationStatus::Planning,
+        },
+    };
+    past.timing.gaps.retain(|gap| gap.timestamp <= at);
+    past.timing.total_elapsed_mins = Some(duration_mins(state.orchestration_started_at, at));
+    past.timing.active_mins = None;
+    past.timing.idle_mins = None;
+    Some(past)
+}
+
+fn timestamps(phase: &PhaseState) -> [Option<DateTime<Utc>>; 4] {
+    [
+        phase.planning_started_at,
+        phase.execution_started_at,
+        phase.review_started_at,
+        phase.completed_at,
+    ]
+}
+
+fn changed_after(phase: &PhaseState, at: DateTime<Utc>) -> bool {
+    timestamps(phase).iter().flatten().any(|t| *t > at)
+}
+
+/// A phase as of `at`, or `None` if it hadn't started planning.
+fn phase_at(phase: &PhaseState, at: DateTime<Utc>) -> Option<PhaseState> {
+    if phase.planning_started_at.is_some_and(|t| t > at) {
+        return None;
+    }
+    if !changed_after(phase, at) {
+        return Some(phase.clone());
+    }
+
+    let before = |t: Option<DateTime<Utc>>| t.filter(|t| *t <= at);
+    let execution_started_at = before(phase.execution_started_at);
+    let review_started_at = before(phase.review_started_at);
+    let status = if review_started_at.is_some() {
+        PhaseStatus::Reviewing
+    } else if execution_started_at.is_some() {
+        PhaseStatus::Executing
+    } else {
+        PhaseStatus::Planning
+    };
+
+    Some(PhaseState {
+        // The plan is handed over when execution starts, and the git range
+        // is recorded when execution finishes.
+        plan_path: execution_started_at.and(phase.plan_path.clone()),
+        status,
+        planning_started_at: phase.planning_started_at,
+        execution_started_at,
+        review_started_at,
+        completed_at: None,
+        duration_mins: None,
+        git_range: review_started_at.and(phase.git_range.clone()),
+        blocked_reason: None,
+        breakdown: PhaseBreakdown::default(),
+        review_verdicts: Vec::new(),
+        in_phase_repair_loops: phase.in_phase_repair_loops,
+    })
+}
+
+/// Latest event for each task recorded at or before `at`, by phase then
+/// task id. Tasks created after `at` are left out.
+pub fn tasks_at(events: &[TaskEventRecord], at: DateTime<Utc>) -> Vec<TaskEventRecord> {
+    let mut latest: BTreeMap<(String, String), (DateTime<Utc>, &TaskEventRecord)> = BTreeMap::new();
+    for event in events {
+        let Some(recorded_at) = DateTime::parse_from_rfc3339(&event.recorded_at)
+            .ok()
+            .map(|t| t.with_timezone(&Utc))
+        else {
+            continue;
+        };
+        if recorded_at > at {
+            continue;
+        }
+        let key = (
+            event.phase_number.clone().unwrap_or_default(),
+            event.task_id.clone(),
+        );
+        if latest
+            .get(&key)
+            .is_none_or(|(seen, _)| recorded_at >= *seen)
+        {
+            latest.insert(key, (recorded_at, event));
+        }
+    }
+    latest
+        .into_values()
+        .map(|(_, event)| event.clone())
+        .collect()
+}
+
+/// One field that differs between a past snapshot and the current state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StateChange {
+    /// What changed, e.g. `status`, `phase 2`, `task 2/3`.
+    pub subject: String,
+    pub then: String,
+    pub now: String,
+}
+
+fn orchestration_status_name(status: OrchestrationStatus) -> String {
+    format!("{:?}", status).to_lowercase()
+}
+
+/// Identifies a task across events: `<phase>/<task id>`.
+pub fn task_key(task: &TaskEventRecord) -> String {
+    match task.phase_number.as_deref() {
+        Some(phase) if !phase.is_empty() => format!("{}/{}", phase, task.task_id),
+        _ => task.task_id.clone(),
+    }
+}
+
+/// Everything that changed between `then` and `now`. Phases and tasks that
+/// didn't exist yet show as `not started`.
+pub fn diff_states(
+    then: &SupervisorState,
+    now: &SupervisorState,
+    tasks_then: &[TaskEventRecord],
+    tasks_now: &[TaskEventRecord],
+) -> Vec<StateChange> {
+    const NOT_STARTED: &str = "not started";
+    let mut changes = Vec::new();
+    let mut push = |subject: String, a: String, b: String| {
+        if a != b {
+            changes.push(StateChange {
+                subject,
+                then: a,
+                now: b,
+            });
+        }
+    };
+
+    push(
+        "status".to_string(),
+        orchestration_status_name(then.status),
+        orchestration_status_name(now.status),
+    );
+    push(
+        "current phase".to_string(),
+        then.current_phase.to_string(),
+        now.current_phase.to_string(),
+    );
+
+    let phase_keys: BTreeSet<&String> = then.phases.keys().chain(now.phases.keys()).collect();
+    for key in phase_keys {
+        let status = |state: &SupervisorState| {
+            state
+                .phases
+                .get(key)
+                .map(|phase| phase.status.to_string())
+                .unwrap_or_else(|| NOT_STARTED.to_string())
+        };
+        push(format!("phase {}", key), status(then), status(now));
+    }
+
+    let task_keys: BTreeSet<String> = tasks_then.iter().chain(tasks_now).map(task_key).collect();
+    for key in task_keys {
+        let find = |list: &[TaskEventRecord]| {
+            list.iter()
+                .find(|task| task_key(task) == key)
+                .map(|task| task.status.clone())
+                .unwrap_or_else(|| NOT_STARTED.to_string())
+        };
+        push(format!("task {}", key), find(tasks_then), find(tasks_now));
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn time(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn phase(
+        status: PhaseStatus,
+        planning: &str,
+        execution: Option<&str>,
+        review: Option<&str>,
+        completed: Option<&str>,
+    ) -> PhaseState {
+        PhaseState {
+            plan_path: Some(PathBuf::from("docs/plans/phase-1.md")),
+            status,
+            planning_started_at: Some(time(planning)),
+            execution_started_at: execution.map(time),
+            review_started_at: review.map(time),
+            completed_at: completed.map(time),
+            duration_mins: completed.map(|_| 60),
+            git_range: review.map(|_| "abc..def".to_string()),
+            ..PhaseState::new()
+        }
+    }
+
+    fn sample_state() -> SupervisorState {
+        let mut state = SupervisorState::new(
+            "auth",
+            PathBuf::from("docs/design.md"),
+            PathBuf::from("/repo/.worktrees/auth"),
+            "tina/auth",
+            2,
+        );
+        state.orchestration_started_at = time("2026-03-01T09:00:00Z");
+        state.phases.insert(
+            "1".to_string(),
+            phase(
+                PhaseStatus::Complete,
+                "2026-03-01T09:00:00Z",
+                Some("2026-03-01T09:30:00Z"),
+                Some("2026-03-01T10:30:00Z"),
+                Some("2026-03-01T11:00:00Z"),
+            ),
+        );
+        state.phases.insert(
+            "2".to_string(),
+            phase(
+                PhaseStatus::Executing,
+                "2026-03-01T11:00:00Z",
+                Some("2026-03-01T11:20:00Z"),
+                None,
+                None,
+            ),
+        );
+        state.current_phase = 2;
+        state.status = OrchestrationStatus::Executing;
+        state
+    }
+
+    fn task(phase: &str, id: &str, status: &str, at: &str) -> TaskEventRecord {
+        TaskEventRecord {
+            orchestration_id: "orch-1".to_string(),
+            phase_number: Some(phase.to_string()),
+            task_id: id.to_string(),
+            subject: format!("Task {}", id),
+            description: None,
+            status: status.to_string(),
+            owner: None,
+            blocked_by: None,
+            metadata: None,
+            recorded_at: at.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_state_at_rewinds_phases_and_status() {
+        let state = sample_state();
+
+        let past = state_at(&state, time("2026-03-01T10:00:00Z")).unwrap();
+        assert_eq!(past.current_phase, 1);
+        assert_eq!(past.status, OrchestrationStatus::Executing);
+        assert!(!past.phases.contains_key("2"));
+        let phase1 = &past.phases["1"];
+        assert_eq!(phase1.status, PhaseStatus::Executing);
+        assert_eq!(phase1.review_started_at, None);
+        assert_eq!(phase1.git_range, None);
+        assert!(phase1.plan_path.is_some());
+        assert_eq!(past.timing.total_elapsed_mins, Some(60));
+
+        let planning = state_at(&state, time("2026-03-01T09:10:00Z")).unwrap();
+        assert_eq!(planning.phases["1"].status, PhaseStatus::Planning);
+        assert_eq!(planning.phases["1"].plan_path, None);
+        assert_eq!(planning.status, OrchestrationStatus::Planning);
+    }
+
+    #[test]
+    fn test_state_at_bounds() {
+        let mut state = sample_state();
+        assert!(state_at(&state, time("2026-03-01T08:00:00Z")).is_none());
+
+        // Nothing happened after the instant: state comes back as-is
+        state.status = OrchestrationStatus::Blocked;
+        let latest = state_at(&state, time("2026-03-02T00:00:00Z")).unwrap();
+        assert_eq!(latest.status, OrchestrationStatus::Blocked);
+        assert_eq!(latest.phases["2"].status, PhaseStatus::Executing);
+    }
+
+    #[test]
+    fn test_tasks_at_takes_latest_event_per_task() {
+        let events = vec![
+            task("1", "1", "pending", "2026-03-01T09:30:00Z"),
+            task("1", "1", "in_progress", "2026-03-01T09:40:00Z"),
+            task("1", "1", "completed", "2026-03-01T10:10:00Z"),
+            task("1", "2", "pending", "2026-03-01T10:05:00Z"),
+        ];
+        let tasks = tasks_at(&events, time("2026-03-01T10:00:00Z"));
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].status, "in_progress");
+    }
+
+    #[test]
+    fn test_diff_states_lists_changes() {
+        let now = sample_state();
+        let then = state_at(&now, time("2026-03-01T10:00:00Z")).unwrap();
+        let events = vec![
+            task("1", "1", "in_progress", "2026-03-01T09:40:00Z"),
+            task("1", "1", "completed", "2026-03-01T10:10:00Z"),
+        ];
+        let tasks_then = tasks_at(&events, time("2026-03-01T10:00:00Z"));
+        let tasks_now = tasks_at(&events, time("2026-03-02T00:00:00Z"));
+
+        let changes = diff_states(&then, &now, &tasks_then, &tasks_now);
+        let summary: Vec<String> = changes
+            .iter()
+            .map(|c| format!("{}: {} -> {}", c.subject, c.then, c.now))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                "current phase: 1 -> 2",
+                "phase 1: executing -> complete",
+                "phase 2: not started -> executing",
+                "task 1/1: in_progress -> completed",
+            ]
+        );
+    }
+}
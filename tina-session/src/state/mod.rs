@@ -1,3 +1,4 @@
+pub mod history;
 pub mod orchestrate;
 pub mod schema;
 pub mod timing;
@@ -11,6 +11,7 @@ use std::path::{Path, PathBuf};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
+use crate::project_config::ProjectLayout;
 use crate::state::schema::{
     OrchestrationStatus, PhaseState, PhaseStatus, ReviewVerdict, SupervisorState,
 };
@@ -167,9 +168,10 @@ fn consensus_secondary_model(state: &SupervisorState) -> Option<String> {
     }
 }
 
-/// Find a plan file in docs/plans following the naming convention.
+/// Find a plan file in the project's plans directory following the naming
+/// convention.
 fn find_plan_in_docs(worktree_path: &Path, feature: &str, phase: &str) -> Option<PathBuf> {
-    let plans_dir = worktree_path.join("docs").join("plans");
+    let plans_dir = ProjectLayout::load_or_default(worktree_path).plans_dir(worktree_path);
     let suffix = format!("-{}-phase-{}.md", feature, phase);
     let entries = fs::read_dir(&plans_dir).ok()?;
     for entry in entries.flatten() {
@@ -678,6 +680,18 @@ pub fn advance_state(
     }
 }
 
+/// Whether a review pass for `phase` would complete it, rather than wait for
+/// the second consensus reviewer or flag a disagreement.
+pub fn review_pass_completes_phase(state: &SupervisorState, phase: &str) -> bool {
+    let Some(phase_state) = state.phases.get(phase) else {
+        return false;
+    };
+    if !state.model_policy.review_consensus {
+        return true;
+    }
+    matches!(phase_state.review_verdicts.as_slice(), [first] if first.result == "pass")
+}
+
 /// Handle review gaps: create remediation or error if depth exceeded.
 fn handle_review_gaps(
     state: &mut SupervisorState,
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -97,6 +97,24 @@ pub struct ContextMetrics {
     pub timestamp: DateTime<Utc>,
 }
 
+impl ContextMetrics {
+    /// Where the statusline script records metrics for the agent running in
+    /// tmux pane `pane_id` (e.g. `%12`).
+    pub fn pane_path(worktree_path: &Path, pane_id: &str) -> PathBuf {
+        worktree_path
+            .join(".claude")
+            .join("tina")
+            .join("context")
+            .join(format!("{}.json", pane_id.trim_start_matches('%')))
+    }
+
+    /// Latest metrics for the agent in `pane_id`, if its statusline has run.
+    pub fn load_for_pane(worktree_path: &Path, pane_id: &str) -> Option<Self> {
+        let json = fs::read_to_string(Self::pane_path(worktree_path, pane_id)).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+}
+
 /// Overall orchestration status.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -455,6 +473,12 @@ pub struct SupervisorState {
 
     #[serde(default)]
     pub review_policy: ReviewPolicy,
+
+    /// Subtree of a monorepo this orchestration is confined to, relative to
+    /// the worktree root. Checks, diffs, and commit sync only consider paths
+    /// under it. `None` means the whole repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<PathBuf>,
 }
 
 impl SupervisorState {
@@ -481,6 +505,7 @@ impl SupervisorState {
             timing: TimingStats::default(),
             model_policy: ModelPolicy::default(),
             review_policy: ReviewPolicy::default(),
+            scope: None,
         }
     }
 
@@ -510,6 +535,7 @@ impl SupervisorState {
             timing: TimingStats::default(),
             model_policy: ModelPolicy::default(),
             review_policy: ReviewPolicy::default(),
+            scope: None,
         }
     }
 
@@ -548,14 +574,44 @@ impl SupervisorState {
         .map_err(|e| SessionError::ConvexError(e.to_string()))?;
 
         // Keep a local copy for teammates/tools that read supervisor-state.json directly.
-        let local_dir = self.worktree_path.join(".claude").join("tina");
-        fs::create_dir_all(&local_dir).map_err(|e| SessionError::IoError(e.to_string()))?;
-        fs::write(local_dir.join("supervisor-state.json"), &json)
-            .map_err(|e| SessionError::IoError(e.to_string()))?;
+        let local_path = Self::local_path(&self.worktree_path);
+        if let Some(local_dir) = local_path.parent() {
+            fs::create_dir_all(local_dir).map_err(|e| SessionError::IoError(e.to_string()))?;
+        }
+        fs::write(&local_path, &json).map_err(|e| SessionError::IoError(e.to_string()))?;
 
         Ok(())
     }
 
+    /// Path of the local state copy kept inside a worktree.
+    pub fn local_path(worktree_path: &Path) -> PathBuf {
+        worktree_path
+            .join(".claude")
+            .join("tina")
+            .join("supervisor-state.json")
+    }
+
+    /// Load the local state copy from a worktree (no Convex round trip).
+    pub fn load_local(worktree_path: &Path) -> Result<Self> {
+        let json = fs::read_to_string(Self::local_path(worktree_path))
+            .map_err(|e| SessionError::IoError(e.to_string()))?;
+        serde_json::from_str(&json).map_err(|e| SessionError::IoError(e.to_string()))
+    }
+
+    /// Monorepo scope recorded for the orchestration in `worktree_path`.
+    pub fn local_scope(worktree_path: &Path) -> Option<PathBuf> {
+        Self::load_local(worktree_path).ok()?.scope
+    }
+
+    /// Absolute directory checks should consider: the scoped subtree when
+    /// one is recorded, otherwise the worktree itself.
+    pub fn scoped_root(worktree_path: &Path) -> PathBuf {
+        match Self::local_scope(worktree_path) {
+            Some(scope) => worktree_path.join(scope),
+            None => worktree_path.to_path_buf(),
+        }
+    }
+
     /// Get or create phase state for a phase number.
     pub fn get_or_create_phase(&mut self, phase: u32) -> Result<&mut PhaseState> {
         if phase > self.total_phases {
@@ -935,4 +991,66 @@ mod tests {
         let deserialized: ContextMetrics = serde_json::from_str(&json).expect("deserialize");
         assert_eq!(deserialized, metrics);
     }
+
+    #[test]
+    fn test_context_metrics_load_for_pane() {
+        let temp = tempfile::TempDir::new().unwrap();
+        assert_eq!(ContextMetrics::load_for_pane(temp.path(), "%3"), None);
+
+        let path = ContextMetrics::pane_path(temp.path(), "%3");
+        assert!(path.ends_with(".claude/tina/context/3.json"));
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(
+            &path,
+            r#"{"used_pct": 87, "tokens": 174000, "max": 200000, "timestamp": "2026-02-13T10:00:00Z"}"#,
+        )
+        .unwrap();
+
+        let metrics = ContextMetrics::load_for_pane(temp.path(), "%3").unwrap();
+        assert_eq!(metrics.used_pct, 87);
+        assert_eq!(metrics.max, 200000);
+    }
+
+    #[test]
+    fn test_scoped_root_reads_local_state_scope() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let worktree = temp.path();
+        assert_eq!(SupervisorState::scoped_root(worktree), worktree);
+
+        let mut state = SupervisorState::new(
+            "auth",
+            PathBuf::from("/spec.md"),
+            worktree.to_path_buf(),
+            "tina/auth",
+            1,
+        );
+        state.scope = Some(PathBuf::from("services/auth"));
+        let local_path = SupervisorState::local_path(worktree);
+        fs::create_dir_all(local_path.parent().unwrap()).unwrap();
+        fs::write(&local_path, serde_json::to_string(&state).unwrap()).unwrap();
+
+        assert_eq!(
+            SupervisorState::local_scope(worktree),
+            Some(PathBuf::from("services/auth"))
+        );
+        assert_eq!(
+            SupervisorState::scoped_root(worktree),
+            worktree.join("services/auth")
+        );
+    }
+
+    #[test]
+    fn test_scope_omitted_from_json_when_unset() {
+        let state = SupervisorState::new(
+            "auth",
+            PathBuf::from("/spec.md"),
+            PathBuf::from("/worktree"),
+            "tina/auth",
+            1,
+        );
+        let json = serde_json::to_string(&state).unwrap();
+        assert!(!json.contains("\"scope\""));
+        let parsed: SupervisorState = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.scope, None);
+    }
 }
@@ -0,0 +1,256 @@
+//! Scoped API tokens for the daemon's HTTP API.
+//!
+//! Tokens are created with `tina-session token create` and stored hashed in
+//! `~/.config/tina/tokens.json`, next to the shared config file. The daemon
+//! re-reads the store on each request, so new and revoked tokens take effect
+//! without a restart.
+//!
+//! A `read` token can only make read requests; an `operate` token can also
+//! make requests that change state (sessions, terminal input, actions).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Prefix on every token secret, so leaked tokens are easy to grep for.
+pub const TOKEN_PREFIX: &str = "tina_";
+
+/// What a token is allowed to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenScope {
+    /// Read-only requests
+    Read,
+    /// Read requests plus requests that change state
+    Operate,
+}
+
+impl TokenScope {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "read" => Ok(Self::Read),
+            "operate" => Ok(Self::Operate),
+            other => anyhow::bail!("Unknown token scope '{}' (expected read or operate)", other),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Operate => "operate",
+        }
+    }
+
+    /// Whether a token with this scope may make a request needing `required`.
+    pub fn allows(&self, required: TokenScope) -> bool {
+        *self >= required
+    }
+}
+
+/// A stored token. Only the SHA-256 of the secret is kept.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiToken {
+    /// Short public id, used to list and revoke the token
+    pub id: String,
+    /// Optional label, e.g. the CI job using it
+    #[serde(default)]
+    pub name: Option<String>,
+    pub scope: TokenScope,
+    pub token_hash: String,
+    pub created_at: DateTime<Utc>,
+    /// `None` means the token never expires
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl ApiToken {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|at| at <= now)
+    }
+}
+
+/// The token store file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TokenStore {
+    #[serde(default)]
+    pub tokens: Vec<ApiToken>,
+}
+
+/// Default token store location.
+pub fn tokens_path() -> PathBuf {
+    dirs::config_dir()
+        .expect("Could not determine config directory")
+        .join("tina")
+        .join("tokens.json")
+}
+
+/// SHA-256 of a token secret (hex-encoded).
+pub fn hash_token(secret: &str) -> String {
+    hex::encode(Sha256::digest(secret.as_bytes()))
+}
+
+/// Parse an expiry such as `30d`, `12h`, or `90m`. `never` means no expiry.
+pub fn parse_expiry(s: &str) -> anyhow::Result<Option<Duration>> {
+    let s = s.trim();
+    if s == "never" {
+        return Ok(None);
+    }
+    let split = s.len().saturating_sub(1);
+    let (amount, unit) = s.split_at(split);
+    let amount: i64 =
+        amount.parse().ok().filter(|n| *n > 0).ok_or_else(|| {
+            anyhow::anyhow!("Invalid expiry '{}' (expected e.g. 30d, 12h, 90m)", s)
+        })?;
+    let duration = match unit {
+        "d" => Duration::days(amount),
+        "h" => Duration::hours(amount),
+        "m" => Duration::minutes(amount),
+        _ => anyhow::bail!("Invalid expiry '{}' (expected e.g. 30d, 12h, 90m)", s),
+    };
+    Ok(Some(duration))
+}
+
+impl TokenStore {
+    /// Load the store at `path`; a missing file is an empty store.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)?;
+        Self::parse(&contents)
+            .map_err(|e| anyhow::anyhow!("Invalid token store {}: {}", path.display(), e))
+    }
+
+    pub fn parse(contents: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(contents)?)
+    }
+
+    /// Write the store to `path`, readable only by the owner.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+        }
+        Ok(())
+    }
+
+    /// Add a token and return it with its secret. The secret is not stored
+    /// and can't be recovered later.
+    pub fn create(
+        &mut self,
+        name: Option<&str>,
+        scope: TokenScope,
+        expires_in: Option<Duration>,
+        now: DateTime<Utc>,
+    ) -> (ApiToken, String) {
+        let secret = format!(
+            "{}{}{}",
+            TOKEN_PREFIX,
+            uuid::Uuid::new_v4().simple(),
+            uuid::Uuid::new_v4().simple()
+        );
+        let token = ApiToken {
+            id: uuid::Uuid::new_v4().simple().to_string()[..8].to_string(),
+            name: name.map(str::to_string),
+            scope,
+            token_hash: hash_token(&secret),
+            created_at: now,
+            expires_at: expires_in.map(|d| now + d),
+        };
+        self.tokens.push(token.clone());
+        (token, secret)
+    }
+
+    /// Remove the token with `id`. Returns whether one was removed.
+    pub fn revoke(&mut self, id: &str) -> bool {
+        let before = self.tokens.len();
+        self.tokens.retain(|t| t.id != id);
+        self.tokens.len() != before
+    }
+
+    /// The unexpired token whose secret is `secret`, if any.
+    pub fn authenticate(&self, secret: &str, now: DateTime<Utc>) -> Option<&ApiToken> {
+        let hash = hash_token(secret);
+        self.tokens
+            .iter()
+            .find(|t| t.token_hash == hash && !t.is_expired(now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_scopes_are_ordered() {
+        assert!(TokenScope::Operate.allows(TokenScope::Read));
+        assert!(TokenScope::Operate.allows(TokenScope::Operate));
+        assert!(TokenScope::Read.allows(TokenScope::Read));
+        assert!(!TokenScope::Read.allows(TokenScope::Operate));
+        assert_eq!(TokenScope::parse("read").unwrap(), TokenScope::Read);
+        assert!(TokenScope::parse("admin").is_err());
+    }
+
+    #[test]
+    fn test_parse_expiry() {
+        assert_eq!(parse_expiry("30d").unwrap(), Some(Duration::days(30)));
+        assert_eq!(parse_expiry("12h").unwrap(), Some(Duration::hours(12)));
+        assert_eq!(parse_expiry("90m").unwrap(), Some(Duration::minutes(90)));
+        assert_eq!(parse_expiry("never").unwrap(), None);
+        assert!(parse_expiry("0d").is_err());
+        assert!(parse_expiry("30").is_err());
+        assert!(parse_expiry("d").is_err());
+        assert!(parse_expiry("").is_err());
+    }
+
+    #[test]
+    fn test_create_authenticate_and_revoke() {
+        let now = Utc::now();
+        let mut store = TokenStore::default();
+        let (token, secret) =
+            store.create(Some("ci"), TokenScope::Read, Some(Duration::days(1)), now);
+
+        assert!(secret.starts_with(TOKEN_PREFIX));
+        assert_ne!(token.token_hash, secret);
+        assert_eq!(store.authenticate(&secret, now).unwrap().id, token.id);
+        assert!(store.authenticate("tina_wrong", now).is_none());
+        // Expired tokens no longer authenticate
+        assert!(store
+            .authenticate(&secret, now + Duration::days(2))
+            .is_none());
+
+        assert!(store.revoke(&token.id));
+        assert!(!store.revoke(&token.id));
+        assert!(store.authenticate(&secret, now).is_none());
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("tina").join("tokens.json");
+        assert_eq!(TokenStore::load(&path).unwrap(), TokenStore::default());
+
+        let mut store = TokenStore::default();
+        let (_, secret) = store.create(None, TokenScope::Operate, None, Utc::now());
+        store.save(&path).unwrap();
+
+        let loaded = TokenStore::load(&path).unwrap();
+        assert_eq!(loaded, store);
+        assert!(!fs::read_to_string(&path).unwrap().contains(&secret));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+}
@@ -3,6 +3,7 @@ import { Option } from "effect"
 import type { Phase, TaskEvent, TeamMember } from "@/schemas"
 import { cn } from "@/lib/utils"
 import { optionNullableText, optionText } from "@/lib/option-display"
+import { formatDurationMins } from "@/lib/time"
 import { useCreateSession } from "@/hooks/useCreateSession"
 import { QuicklookDialog } from "@/components/QuicklookDialog"
 import { toStatusBadgeStatus } from "@/components/ui/status-styles"
@@ -38,19 +39,19 @@ export function PhaseQuicklook({ orchestrationId, phase, tasks, teamMembers, lea
             <div className={phaseStyles.timingItem}>
               <span className={styles.label}>Planning:</span>
               <span className={styles.value}>
-                {optionText(phase.planningMins, (mins) => `${mins} min`)}
+                {optionText(phase.planningMins, formatDurationMins)}
               </span>
             </div>
             <div className={phaseStyles.timingItem}>
               <span className={styles.label}>Execution:</span>
               <span className={styles.value}>
-                {optionText(phase.executionMins, (mins) => `${mins} min`)}
+                {optionText(phase.executionMins, formatDurationMins)}
               </span>
             </div>
             <div className={phaseStyles.timingItem}>
               <span className={styles.label}>Review:</span>
               <span className={styles.value}>
-                {optionText(phase.reviewMins, (mins) => `${mins} min`)}
+                {optionText(phase.reviewMins, formatDurationMins)}
               </span>
             </div>
           </div>
@@ -33,6 +33,7 @@ function mapPhaseToCard(
     taskCount: tasks.length,
     completedCount,
     teamCount,
+    riskScore: Option.getOrUndefined(phase.riskScore),
   }
 }
 
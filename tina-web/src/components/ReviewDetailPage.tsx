@@ -49,11 +49,22 @@ function resolveDiffBase(gitRange: string | undefined): string {
   return "origin/main"
 }
 
+function approvalProgress(gate: ReviewGate): string | null {
+  const required = Option.getOrElse(gate.requiredApprovals, () => 1)
+  if (required <= 1 || gate.status !== "pending") return null
+  const approvals = Option.getOrElse(gate.decisions, () => []).filter(
+    (d) => d.decision === "approved",
+  ).length
+  return `${approvals}/${required} approvals`
+}
+
 function GateIndicator({ gate }: { gate: ReviewGate }) {
   const statusClass = styles[gate.status] ?? ""
+  const progress = approvalProgress(gate)
   return (
     <span className={`${styles.gateIndicator} ${statusClass}`}>
       {gate.gateId}: {gate.status}
+      {progress && ` (${progress})`}
     </span>
   )
 }
@@ -5,6 +5,7 @@ import { useMutation } from "convex/react"
 import { api } from "@convex/_generated/api"
 import type { Id } from "@convex/_generated/dataModel"
 import { generateIdempotencyKey } from "@/lib/utils"
+import { formatDurationMins, formatLocalTimestamp } from "@/lib/time"
 import { controlBtnClass } from "@/lib/control-plane-styles"
 import { useFocusable } from "@/hooks/useFocusable"
 import { MonoText } from "@/components/ui/mono-text"
@@ -42,8 +43,10 @@ export function StatusSection({ detail }: StatusSectionProps) {
     ? Math.min(100, Math.max(0, (detail.currentPhase / detail.totalPhases) * 100))
     : 0
 
-  const elapsedTime = Option.getOrElse(detail.totalElapsedMins, () => "--")
-  const elapsedDisplay = elapsedTime === "--" ? "--" : `${elapsedTime}m`
+  const elapsedDisplay = Option.match(detail.totalElapsedMins, {
+    onNone: () => "--",
+    onSome: formatDurationMins,
+  })
 
   const canPause = PAUSABLE_STATUSES.has(detail.status) && !pendingAction
   const canResume = RESUMABLE_STATUSES.has(detail.status) && !pendingAction
@@ -95,7 +98,12 @@ export function StatusSection({ detail }: StatusSectionProps) {
         </div>
 
         <div className="flex justify-end">
-          <MonoText className="text-[8px] text-muted-foreground">ELAPSED: {elapsedDisplay}</MonoText>
+          <MonoText
+            className="text-[8px] text-muted-foreground"
+            title={`Started ${formatLocalTimestamp(detail.startedAt)}`}
+          >
+            ELAPSED: {elapsedDisplay}
+          </MonoText>
         </div>
 
         {actionError && (
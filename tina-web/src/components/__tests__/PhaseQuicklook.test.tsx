@@ -125,9 +125,9 @@ describe("PhaseQuicklook", () => {
   })
 
   it.each([
-    ["Planning:", "10 min"],
-    ["Execution:", "25 min"],
-    ["Review:", "5 min"],
+    ["Planning:", "10m"],
+    ["Execution:", "25m"],
+    ["Review:", "5m"],
   ])("shows timing row %s %s", (label, value) => {
     renderQuicklook()
 
@@ -2,7 +2,7 @@ import { describe, it, expect, vi, beforeEach } from "vitest"
 import { render, screen } from "@testing-library/react"
 import { userEvent } from "@testing-library/user-event"
 import { PhaseTimelinePanel } from "../PhaseTimelinePanel"
-import { buildPhase, buildPhaseTimelineDetail } from "@/test/builders/domain"
+import { buildPhase, buildPhaseTimelineDetail, some } from "@/test/builders/domain"
 import {
   type SelectionStateMock,
 } from "@/test/harness/hooks"
@@ -129,6 +129,20 @@ describe("PhaseTimelinePanel", () => {
     }
   })
 
+  it("shows the risk score only for phases that have one", () => {
+    const detail = buildPhaseTimelineDetail({
+      phases: [
+        buildPhase({ _id: "phase1", orchestrationId: "orch1", phaseNumber: "1", status: "complete", riskScore: some(72) }),
+        buildPhase({ _id: "phase2", _creationTime: 1234567891, orchestrationId: "orch1", phaseNumber: "2", status: "executing" }),
+      ],
+      phaseTasks: {},
+    })
+
+    const { container } = renderTimelineView({ detail })
+    expect(phaseById(container, "phase1")).toHaveTextContent("risk 72")
+    expect(phaseById(container, "phase2")).not.toHaveTextContent("risk")
+  })
+
   it("highlights selected phase", () => {
     const { container } = renderTimelineView({ selection: { phaseId: "phase2" } })
     expect(phaseById(container, "phase2")).toHaveAttribute("aria-current", "step")
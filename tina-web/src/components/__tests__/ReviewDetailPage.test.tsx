@@ -114,6 +114,36 @@ describe("ReviewDetailPage", () => {
     expect(screen.getByText("finalize: blocked")).toBeInTheDocument()
   })
 
+  it("shows approval progress on gates that need more than one approval", () => {
+    installAppRuntimeQueryMock(mockUseTypedQuery, {
+      states: {
+        "reviews.detail": querySuccess(buildReviewSummary()),
+        "reviewGates.list": querySuccess([
+          buildReviewGate({
+            gateId: "finalize",
+            status: "pending",
+            requiredApprovals: some(2),
+            decisions: some([
+              {
+                approver: "alice",
+                decision: "approved",
+                decidedAt: "2026-02-10T10:00:00Z",
+                summary: none<string>(),
+              },
+            ]),
+          }),
+        ]),
+      },
+      detailFallback: querySuccess(buildOrchestrationDetail()),
+    })
+
+    renderPage()
+
+    expect(
+      screen.getByText("finalize: pending (1/2 approvals)"),
+    ).toBeInTheDocument()
+  })
+
   it("shows all three tabs with Commits + Conversation active by default", () => {
     installAppRuntimeQueryMock(mockUseTypedQuery, {
       states: {
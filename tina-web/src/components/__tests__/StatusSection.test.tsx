@@ -100,6 +100,12 @@ describe("StatusSection", () => {
     expect(screen.getByText(/44m/)).toBeInTheDocument()
   })
 
+  it("shows long elapsed times in hours and minutes", () => {
+    renderStatus({ totalElapsedMins: some(134) })
+
+    expect(screen.getByText(/2h 14m/)).toBeInTheDocument()
+  })
+
   it("shows '--' for elapsed time when not available", () => {
     renderStatus({ totalElapsedMins: none<number>() })
 
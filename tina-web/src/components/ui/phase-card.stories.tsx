@@ -71,3 +71,15 @@ export const Blocked: Story = {
     teamCount: 3,
   },
 };
+
+export const HighRisk: Story = {
+  args: {
+    phaseNumber: 2,
+    name: "Storage migration",
+    status: "complete",
+    taskCount: 6,
+    completedCount: 6,
+    teamCount: 3,
+    riskScore: 72,
+  },
+};
@@ -8,6 +8,14 @@ interface PhaseCardProps extends React.HTMLAttributes<HTMLDivElement> {
   taskCount: number;
   completedCount: number;
   teamCount: number;
+  /** 0-100 risk score, when one has been computed for the phase */
+  riskScore?: number;
+}
+
+function riskTone(score: number) {
+  if (score >= 60) return "text-status-blocked border-status-blocked/30";
+  if (score >= 30) return "text-status-warning border-status-warning/30";
+  return "text-muted-foreground/80 border-border";
 }
 
 function PhaseCard({
@@ -17,6 +25,7 @@ function PhaseCard({
   taskCount,
   completedCount,
   teamCount,
+  riskScore,
   className,
   ...props
 }: PhaseCardProps) {
@@ -65,9 +74,22 @@ function PhaseCard({
           <h3 className={cn("font-semibold text-xs truncate", nameTone)}>
             P{phaseNumber} {name}
           </h3>
-          <span className="text-[8px] font-medium uppercase tracking-wide text-muted-foreground/65 shrink-0">
-            {statusLabel(status)}
-          </span>
+          <div className="flex items-center gap-1.5 shrink-0">
+            {riskScore !== undefined && (
+              <span
+                className={cn(
+                  "text-[8px] font-semibold uppercase tracking-wide border rounded px-1",
+                  riskTone(riskScore)
+                )}
+                title="Phase risk score (0-100)"
+              >
+                risk {Math.round(riskScore)}
+              </span>
+            )}
+            <span className="text-[8px] font-medium uppercase tracking-wide text-muted-foreground/65">
+              {statusLabel(status)}
+            </span>
+          </div>
         </div>
         <p className="text-2xs text-muted-foreground/80 font-medium">
           {taskCount} tasks | {completedCount} done | {teamCount} team
@@ -1,5 +1,11 @@
 import { describe, it, expect, vi, beforeEach, afterEach } from "vitest"
 import { fetchDaemon } from "../useDaemonQuery"
+import {
+  daemonHeaders,
+  getDaemonToken,
+  setDaemonToken,
+  withDaemonToken,
+} from "../../lib/daemon"
 
 describe("fetchDaemon", () => {
   const originalFetch = globalThis.fetch
@@ -64,6 +70,35 @@ describe("fetchDaemon", () => {
     expect(result).toEqual(responseData)
   })
 
+  it("sends the stored token", async () => {
+    setDaemonToken("tina_abc")
+    vi.mocked(globalThis.fetch).mockResolvedValue(
+      new Response(JSON.stringify([]), { status: 200 }),
+    )
+
+    await fetchDaemon("/diff", { worktree: "/tmp" })
+    setDaemonToken(undefined)
+
+    const init = vi.mocked(globalThis.fetch).mock.calls[0][1] as RequestInit
+    expect(init.headers).toEqual({ Authorization: "Bearer tina_abc" })
+  })
+
+  it("asks for a token once when the daemon requires one", async () => {
+    const prompt = vi.spyOn(window, "prompt").mockReturnValue(" tina_abc ")
+    vi.mocked(globalThis.fetch)
+      .mockResolvedValueOnce(new Response("API token required", { status: 401 }))
+      .mockResolvedValueOnce(new Response(JSON.stringify([]), { status: 200 }))
+
+    await fetchDaemon("/diff", { worktree: "/tmp" })
+
+    expect(prompt).toHaveBeenCalledTimes(1)
+    expect(getDaemonToken()).toBe("tina_abc")
+    const init = vi.mocked(globalThis.fetch).mock.calls[1][1] as RequestInit
+    expect(init.headers).toEqual({ Authorization: "Bearer tina_abc" })
+    setDaemonToken(undefined)
+    prompt.mockRestore()
+  })
+
   it("does not set body or content-type for GET requests", async () => {
     vi.mocked(globalThis.fetch).mockResolvedValue(
       new Response(JSON.stringify([]), { status: 200 }),
@@ -89,3 +124,25 @@ describe("fetchDaemon", () => {
     expect(calledUrl).toContain("shas=abc123%2Cdef456")
   })
 })
+
+describe("daemon token", () => {
+  it("adds a bearer header only when a token is set", () => {
+    expect(daemonHeaders({ "Content-Type": "application/json" }, "tina_abc")).toEqual({
+      "Content-Type": "application/json",
+      Authorization: "Bearer tina_abc",
+    })
+    expect(daemonHeaders({}, undefined)).toEqual({})
+  })
+
+  it("passes the token in the query string for WebSockets", () => {
+    expect(withDaemonToken("ws://localhost:7842/ws/terminal/%251", "tina_abc")).toBe(
+      "ws://localhost:7842/ws/terminal/%251?access_token=tina_abc",
+    )
+    expect(withDaemonToken("http://h/x?a=1", "tina_abc")).toBe(
+      "http://h/x?a=1&access_token=tina_abc",
+    )
+    expect(withDaemonToken("ws://h/ws/terminal/%251", undefined)).toBe(
+      "ws://h/ws/terminal/%251",
+    )
+  })
+})
@@ -1,5 +1,10 @@
 import { useQuery } from "@tanstack/react-query"
-import { DAEMON_BASE } from "../lib/daemon"
+import {
+  DAEMON_BASE,
+  daemonHeaders,
+  getDaemonToken,
+  promptForDaemonToken,
+} from "../lib/daemon"
 
 // Types matching tina-daemon/src/git.rs serialization
 export type FileStatus = "added" | "modified" | "deleted" | "renamed"
@@ -49,6 +54,7 @@ export async function fetchDaemon<T>(
   params: Record<string, string>,
   method: string = "GET",
   body?: unknown,
+  retryUnauthorized: boolean = true,
 ): Promise<T> {
   const url = new URL(path, DAEMON_BASE)
   if (method === "GET") {
@@ -56,12 +62,23 @@ export async function fetchDaemon<T>(
       url.searchParams.set(k, v)
     }
   }
+  const token = getDaemonToken()
   const init: RequestInit = { method }
   if (body !== undefined) {
-    init.headers = { "Content-Type": "application/json" }
+    init.headers = daemonHeaders({ "Content-Type": "application/json" }, token)
     init.body = JSON.stringify(body)
+  } else if (token) {
+    init.headers = daemonHeaders({}, token)
   }
   const resp = await fetch(url.toString(), init)
+  // Retry once with a new token; only ask if no other request already set one
+  if (
+    resp.status === 401 &&
+    retryUnauthorized &&
+    (getDaemonToken() !== token || promptForDaemonToken())
+  ) {
+    return fetchDaemon<T>(path, params, method, body, false)
+  }
   if (!resp.ok) {
     throw new Error(`Daemon ${path}: ${resp.status} ${await resp.text()}`)
   }
@@ -3,11 +3,11 @@ import { Terminal } from "xterm"
 import { AttachAddon } from "@xterm/addon-attach"
 import { FitAddon } from "@xterm/addon-fit"
 import { WebglAddon } from "@xterm/addon-webgl"
-import { DAEMON_BASE } from "../lib/daemon"
+import { DAEMON_BASE, withDaemonToken } from "../lib/daemon"
 
 function buildWsUrl(paneId: string): string {
   const base = DAEMON_BASE.replace(/^http/, "ws")
-  return `${base}/ws/terminal/${encodeURIComponent(paneId)}`
+  return withDaemonToken(`${base}/ws/terminal/${encodeURIComponent(paneId)}`)
 }
 
 function encodeResizeMessage(cols: number, rows: number): ArrayBuffer {
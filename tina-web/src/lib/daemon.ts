@@ -1,6 +1,66 @@
 export const DAEMON_BASE =
   import.meta.env.VITE_DAEMON_URL ?? "http://localhost:7842"
 
+export const DAEMON_TOKEN_STORAGE_KEY = "tina.daemon.token"
+
+/**
+ * API token from `tina-session token create`, for daemons that require one.
+ * Kept in this browser's localStorage rather than baked into the build.
+ */
+export function getDaemonToken(): string | undefined {
+  try {
+    return window.localStorage.getItem(DAEMON_TOKEN_STORAGE_KEY) || undefined
+  } catch {
+    return undefined
+  }
+}
+
+export function setDaemonToken(token: string | undefined) {
+  try {
+    if (token) {
+      window.localStorage.setItem(DAEMON_TOKEN_STORAGE_KEY, token)
+    } else {
+      window.localStorage.removeItem(DAEMON_TOKEN_STORAGE_KEY)
+    }
+  } catch {
+    // Ignore storage failures (private mode/quota/security settings).
+  }
+}
+
+/**
+ * Ask for a token after the daemon turned a request away, and store it.
+ * Returns whether one was entered.
+ */
+export function promptForDaemonToken(): boolean {
+  const token = window
+    .prompt("The tina daemon requires an API token (tina-session token create):")
+    ?.trim()
+  if (!token) return false
+  setDaemonToken(token)
+  return true
+}
+
+/** `headers` plus the daemon's Authorization header, when a token is set. */
+export function daemonHeaders(
+  headers: Record<string, string> = {},
+  token: string | undefined = getDaemonToken(),
+): Record<string, string> {
+  return token ? { ...headers, Authorization: `Bearer ${token}` } : headers
+}
+
+/**
+ * `url` with the token in its query string. Browsers can't set headers on
+ * WebSockets, so the daemon also accepts `?access_token=`.
+ */
+export function withDaemonToken(
+  url: string,
+  token: string | undefined = getDaemonToken(),
+): string {
+  if (!token) return url
+  const sep = url.includes("?") ? "&" : "?"
+  return `${url}${sep}access_token=${encodeURIComponent(token)}`
+}
+
 export interface CreateSessionResponse {
   sessionName: string
   tmuxPaneId: string
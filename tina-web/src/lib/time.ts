@@ -42,3 +42,15 @@ export function formatLocalTimestamp(isoTimestamp: string): string {
   if (parsed === null) return "--"
   return new Date(parsed).toLocaleString()
 }
+
+/** Duration in minutes keeping the two largest units: "45m", "2h 14m", "3d 4h". */
+export function formatDurationMins(mins: number): string {
+  const total = Math.max(0, Math.round(mins))
+  const days = Math.floor(total / (24 * 60))
+  const hours = Math.floor(total / 60) % 24
+  const minutes = total % 60
+
+  if (days > 0) return hours > 0 ? `${days}d ${hours}h` : `${days}d`
+  if (hours > 0) return minutes > 0 ? `${hours}h ${minutes}m` : `${hours}h`
+  return `${minutes}m`
+}
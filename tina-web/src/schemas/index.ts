@@ -18,6 +18,6 @@ export { NodeSummary } from "./node"
 export { TimelineEntry } from "./timeline"
 export { ReviewSummary } from "./review"
 export { ReviewThread } from "./reviewThread"
-export { ReviewGate } from "./reviewGate"
+export { GateDecision, ReviewGate } from "./reviewGate"
 export { ReviewCheck } from "./reviewCheck"
 export { TerminalTarget } from "./terminalTarget"
@@ -9,6 +9,14 @@ export const NodeSummary = Schema.Struct({
   lastHeartbeat: Schema.Number,
   registeredAt: Schema.Number,
   authTokenHash: Schema.String,
+  cpuLoad: Schema.optional(Schema.Number),
+  cpuCount: Schema.optional(Schema.Number),
+  memoryUsedBytes: Schema.optional(Schema.Number),
+  memoryTotalBytes: Schema.optional(Schema.Number),
+  diskFreeBytes: Schema.optional(Schema.Number),
+  diskTotalBytes: Schema.optional(Schema.Number),
+  tmuxSessions: Schema.optional(Schema.Number),
+  clockSkewMs: Schema.optional(Schema.Number),
 })
 
 export type NodeSummary = typeof NodeSummary.Type
@@ -16,6 +16,8 @@ export const Phase = Schema.Struct({
   reviewMins: optionalNumber,
   startedAt: optionalString,
   completedAt: optionalString,
+  riskScore: optionalNumber,
+  riskSignals: optionalString,
 })
 
 export type Phase = typeof Phase.Type
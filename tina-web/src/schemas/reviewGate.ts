@@ -1,5 +1,18 @@
 import { Schema } from "effect"
-import { optionalString, orchestrationScopedDocumentFields } from "./common"
+import {
+  optionalNumber,
+  optionalString,
+  orchestrationScopedDocumentFields,
+} from "./common"
+
+export const GateDecision = Schema.Struct({
+  approver: Schema.String,
+  decision: Schema.String,
+  decidedAt: Schema.String,
+  summary: optionalString,
+})
+
+export type GateDecision = typeof GateDecision.Type
 
 export const ReviewGate = Schema.Struct({
   ...orchestrationScopedDocumentFields,
@@ -9,6 +22,8 @@ export const ReviewGate = Schema.Struct({
   decidedBy: optionalString,
   decidedAt: optionalString,
   summary: Schema.String,
+  requiredApprovals: optionalNumber,
+  decisions: Schema.optionalWith(Schema.Array(GateDecision), { as: "Option" }),
 })
 
 export type ReviewGate = typeof ReviewGate.Type
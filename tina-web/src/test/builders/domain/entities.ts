@@ -7,6 +7,7 @@ import type {
   Phase,
   ProjectSummary,
   ReviewCheck,
+  GateDecision,
   ReviewGate,
   ReviewSummary,
   ReviewThread,
@@ -74,6 +75,8 @@ export function buildPhase(overrides: Partial<Phase> = {}): Phase {
     reviewMins: none<number>(),
     startedAt: none<string>(),
     completedAt: none<string>(),
+    riskScore: none<number>(),
+    riskSignals: none<string>(),
     ...overrides,
   }
 }
@@ -209,6 +212,8 @@ export function buildReviewGate(
     decidedBy: none<string>(),
     decidedAt: none<string>(),
     summary: "Awaiting review",
+    requiredApprovals: none<number>(),
+    decisions: none<readonly GateDecision[]>(),
     ...overrides,
   }
 }
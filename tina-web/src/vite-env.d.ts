@@ -5,6 +5,7 @@ interface ImportMetaEnv {
   readonly VITE_CONVEX_URL_PROD?: string;
   readonly VITE_CONVEX_URL_DEV?: string;
   readonly VITE_TINA_ENV?: "prod" | "dev";
+  readonly VITE_DAEMON_URL?: string;
 }
 
 interface ImportMeta {